@@ -0,0 +1,406 @@
+//! Alert rule evaluation.
+//!
+//! Backends only load the metric series; the comparison runs here so the
+//! scheduler and the evaluate endpoint agree on when a rule triggers.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, DurationRound, NaiveDateTime, NaiveTime, Utc};
+
+use crate::analytics::{
+    AlertBaseline, AlertConditionType, AlertEvaluationResult, AlertGranularity, AlertRule,
+};
+
+/// Seasonal baselines need at least two full weeks of history.
+pub const MIN_SEASONAL_LOOKBACK_DAYS: i64 = 14;
+pub const MAX_LOOKBACK_DAYS: i64 = 90;
+pub const MAX_COOLDOWN_MINUTES: i64 = 7 * 24 * 60;
+const SEASON_DAYS: i64 = 7;
+/// Holt-Winters smoothing factors for level, trend and season.
+const HW_ALPHA: f64 = 0.3;
+const HW_BETA: f64 = 0.05;
+const HW_GAMMA: f64 = 0.3;
+/// Lower bound on a seasonal band's spread, as a share of the expected value,
+/// so a handful of identical weeks does not turn every wobble into an alert.
+const MIN_RELATIVE_SPREAD: f64 = 0.05;
+
+/// Whether the baseline compares against the same point in prior weeks.
+pub fn is_seasonal(baseline: &AlertBaseline) -> bool {
+    !matches!(baseline, AlertBaseline::Flat)
+}
+
+impl AlertGranularity {
+    pub fn step(self) -> Duration {
+        match self {
+            Self::Day => Duration::days(1),
+            Self::Hour => Duration::hours(1),
+        }
+    }
+}
+
+/// The bucket a rule evaluates and the baseline buckets before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlertWindow {
+    /// Start of the evaluated bucket.
+    pub current: NaiveDateTime,
+    /// Start of the oldest baseline bucket.
+    pub start: NaiveDateTime,
+    pub step: Duration,
+    /// For daily seasonal rules, every day only counts activity before this
+    /// time of day, so a partial today compares like-for-like.
+    pub until: Option<NaiveTime>,
+}
+
+impl AlertWindow {
+    /// Daily rules evaluate today so far; hourly rules the last complete hour.
+    pub fn for_rule(rule: &AlertRule, now: DateTime<Utc>) -> Self {
+        let step = rule.granularity.step();
+        let (current, until) = match rule.granularity {
+            AlertGranularity::Day => (
+                now.date_naive().and_time(NaiveTime::MIN),
+                is_seasonal(&rule.baseline).then(|| now.time()),
+            ),
+            AlertGranularity::Hour => {
+                let hour = now.duration_trunc(step).unwrap_or(now).naive_utc();
+                (hour - step, None)
+            }
+        };
+        Self {
+            current,
+            start: current - Duration::days(rule.lookback_days),
+            step,
+            until,
+        }
+    }
+
+    /// Exclusive end of the evaluated bucket.
+    pub fn end(&self) -> NaiveDateTime {
+        self.current + self.step
+    }
+
+    /// Start of every bucket from `start` to `current`, oldest first.
+    pub fn buckets(&self) -> impl Iterator<Item = NaiveDateTime> + '_ {
+        let count = (self.current - self.start).num_seconds() / self.step.num_seconds();
+        (0..=count).rev().map(move |back| self.current - self.step * back as i32)
+    }
+}
+
+/// Evaluate `rule` for `window.current` against its series, keyed by bucket
+/// start. Returns `None` when the baseline has too little history (or no
+/// spread) to judge.
+pub fn evaluate_alert_rule(
+    rule: &AlertRule,
+    series: &HashMap<NaiveDateTime, f64>,
+    window: &AlertWindow,
+) -> Option<AlertEvaluationResult> {
+    let value_at = |bucket: NaiveDateTime| series.get(&bucket).copied().unwrap_or(0.0);
+    let current_value = value_at(window.current);
+    let result = |triggered: bool| AlertEvaluationResult {
+        alert_id: rule.id.clone(),
+        triggered,
+        metric_value: current_value,
+        baseline_mean: None,
+        baseline_stddev: None,
+        z_score: None,
+        expected_value: None,
+        lower_bound: None,
+        upper_bound: None,
+    };
+
+    match rule.condition_type {
+        AlertConditionType::ThresholdAbove => {
+            return Some(result(current_value >= rule.threshold_value));
+        }
+        AlertConditionType::ThresholdBelow => {
+            return Some(result(current_value <= rule.threshold_value));
+        }
+        AlertConditionType::Spike | AlertConditionType::Drop | AlertConditionType::Anomaly => {}
+    }
+
+    // Oldest first, excluding the evaluated bucket.
+    let history: Vec<f64> = window
+        .buckets()
+        .filter(|bucket| *bucket != window.current)
+        .map(value_at)
+        .collect();
+    let season = (Duration::days(SEASON_DAYS).num_seconds() / window.step.num_seconds()) as usize;
+    let (expected, spread) = match rule.baseline {
+        AlertBaseline::Flat => {
+            let mean_value = mean(&history)?;
+            (mean_value, stddev(&history, mean_value)?)
+        }
+        AlertBaseline::SameWeekday => {
+            let same_weekday: Vec<f64> = history
+                .iter()
+                .rev()
+                .skip(season - 1)
+                .step_by(season)
+                .copied()
+                .collect();
+            let mean_value = mean(&same_weekday)?;
+            let spread = stddev(&same_weekday, mean_value)?;
+            (
+                mean_value,
+                spread.max(mean_value.abs() * MIN_RELATIVE_SPREAD),
+            )
+        }
+        AlertBaseline::HoltWinters => {
+            let (forecast, spread) = holt_winters(&history, season)?;
+            (forecast, spread.max(forecast.abs() * MIN_RELATIVE_SPREAD))
+        }
+    };
+    if spread <= f64::EPSILON {
+        return None;
+    }
+
+    let z = (current_value - expected) / spread;
+    let band = rule.threshold_value * spread;
+    let triggered = match rule.condition_type {
+        AlertConditionType::Spike => z >= rule.threshold_value,
+        AlertConditionType::Drop => z <= -rule.threshold_value,
+        _ => z.abs() >= rule.threshold_value,
+    };
+    Some(AlertEvaluationResult {
+        baseline_mean: Some(expected),
+        baseline_stddev: Some(spread),
+        z_score: Some(z),
+        expected_value: Some(expected),
+        lower_bound: Some(expected - band),
+        upper_bound: Some(expected + band),
+        ..result(triggered)
+    })
+}
+
+/// Whether `rule` triggered less than `cooldown_minutes` before `now`.
+pub fn in_cooldown(rule: &AlertRule, now: DateTime<Utc>) -> bool {
+    let Some(last) = rule
+        .last_triggered_at
+        .as_deref()
+        .and_then(|raw| NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M:%S%.f").ok())
+    else {
+        return false;
+    };
+    now.naive_utc() < last + Duration::minutes(rule.cooldown_minutes)
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+fn stddev(values: &[f64], mean_value: f64) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let variance = values
+        .iter()
+        .map(|v| {
+            let diff = *v - mean_value;
+            diff * diff
+        })
+        .sum::<f64>()
+        / values.len() as f64;
+    Some(variance.sqrt())
+}
+
+/// One-step-ahead additive Holt-Winters forecast with a weekly season of
+/// `season` buckets. Returns the forecast and the root-mean-square error of
+/// the in-sample forecasts after the first season.
+fn holt_winters(values: &[f64], season: usize) -> Option<(f64, f64)> {
+    if values.len() < 2 * season {
+        return None;
+    }
+    let first_week = mean(&values[..season])?;
+    let second_week = mean(&values[season..2 * season])?;
+    let mut level = first_week;
+    let mut trend = (second_week - first_week) / season as f64;
+    let mut seasonal: Vec<f64> = values[..season].iter().map(|v| v - first_week).collect();
+
+    let mut squared_error = 0.0;
+    for (t, &value) in values.iter().enumerate().skip(season) {
+        let component = seasonal[t % season];
+        let error = value - (level + trend + component);
+        squared_error += error * error;
+
+        let previous_level = level;
+        level = HW_ALPHA * (value - component) + (1.0 - HW_ALPHA) * (level + trend);
+        trend = HW_BETA * (level - previous_level) + (1.0 - HW_BETA) * trend;
+        seasonal[t % season] = HW_GAMMA * (value - level) + (1.0 - HW_GAMMA) * component;
+    }
+    let forecast = level + trend + seasonal[values.len() % season];
+    let rmse = (squared_error / (values.len() - season) as f64).sqrt();
+    Some((forecast, rmse))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone};
+
+    use crate::analytics::{AlertFilters, AlertMetric, NotificationChannel};
+
+    use super::*;
+
+    fn rule(condition_type: AlertConditionType, baseline: AlertBaseline) -> AlertRule {
+        AlertRule {
+            id: "alert_1".to_string(),
+            website_id: "site_1".to_string(),
+            name: "Traffic".to_string(),
+            metric: AlertMetric::Pageviews,
+            condition_type,
+            threshold_value: 3.0,
+            lookback_days: 28,
+            baseline,
+            event_name: None,
+            filters: AlertFilters::default(),
+            granularity: AlertGranularity::Day,
+            cooldown_minutes: 60,
+            channel: NotificationChannel::Email,
+            target: "ops@example.com".to_string(),
+            signing_secret: "whsec_test".to_string(),
+            is_active: true,
+            is_firing: false,
+            firing_episode: 0,
+            last_triggered_at: None,
+            created_at: "2026-10-01 00:00:00".to_string(),
+        }
+    }
+
+    /// Evaluate a daily rule at noon on `today`.
+    fn evaluate(
+        rule: &AlertRule,
+        series: &HashMap<NaiveDateTime, f64>,
+        today: NaiveDate,
+    ) -> Option<AlertEvaluationResult> {
+        let now = Utc.from_utc_datetime(&today.and_hms_opt(12, 0, 0).expect("noon"));
+        evaluate_alert_rule(rule, series, &AlertWindow::for_rule(rule, now))
+    }
+
+    /// Four weeks of weekday traffic around 1000 with quiet weekends, then a
+    /// normal Monday.
+    fn weekly_series(today: NaiveDate, today_value: f64) -> HashMap<NaiveDateTime, f64> {
+        let mut series = HashMap::new();
+        for offset in 1..=28 {
+            let day = today - Duration::days(offset);
+            let weekend = offset % 7 == 1 || offset % 7 == 2;
+            let wobble = (offset % 3) as f64 * 10.0;
+            series.insert(
+                day.and_time(NaiveTime::MIN),
+                if weekend { 200.0 } else { 1000.0 } + wobble,
+            );
+        }
+        series.insert(today.and_time(NaiveTime::MIN), today_value);
+        series
+    }
+
+    #[test]
+    fn weekday_baselines_do_not_flag_a_normal_monday() {
+        // 2026-10-12 is a Monday; the two days before it are the weekend.
+        let today = NaiveDate::from_ymd_opt(2026, 10, 12).expect("date");
+        let series = weekly_series(today, 1010.0);
+
+        let flat = evaluate(
+            &rule(AlertConditionType::Anomaly, AlertBaseline::Flat),
+            &series,
+            today,
+        )
+        .expect("flat result");
+        let same_weekday = evaluate(
+            &rule(AlertConditionType::Anomaly, AlertBaseline::SameWeekday),
+            &series,
+            today,
+        )
+        .expect("same weekday result");
+        let holt_winters = evaluate(
+            &rule(AlertConditionType::Anomaly, AlertBaseline::HoltWinters),
+            &series,
+            today,
+        )
+        .expect("holt-winters result");
+
+        assert!(flat.expected_value.expect("expected") < 900.0);
+        assert!(!same_weekday.triggered);
+        assert!((same_weekday.expected_value.expect("expected") - 1010.0).abs() < 20.0);
+        assert!(!holt_winters.triggered);
+        let lower = holt_winters.lower_bound.expect("lower");
+        let upper = holt_winters.upper_bound.expect("upper");
+        assert!(lower < 1010.0 && 1010.0 < upper);
+    }
+
+    #[test]
+    fn anomalies_trigger_in_both_directions() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 12).expect("date");
+        for (value, z_sign) in [(200.0, -1.0), (3000.0, 1.0)] {
+            for baseline in [AlertBaseline::SameWeekday, AlertBaseline::HoltWinters] {
+                let result = evaluate(
+                    &rule(AlertConditionType::Anomaly, baseline.clone()),
+                    &weekly_series(today, value),
+                    today,
+                )
+                .expect("result");
+                assert!(result.triggered, "{baseline:?} at {value}");
+                assert!(result.z_score.expect("z") * z_sign > 0.0);
+            }
+        }
+
+        let drop_rule = rule(AlertConditionType::Drop, AlertBaseline::SameWeekday);
+        let spike = evaluate(&drop_rule, &weekly_series(today, 3000.0), today).expect("result");
+        assert!(!spike.triggered, "drop rules ignore spikes");
+    }
+
+    #[test]
+    fn thresholds_and_short_histories() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 12).expect("date");
+        let series = weekly_series(today, 1010.0);
+        let above = evaluate(
+            &rule(AlertConditionType::ThresholdAbove, AlertBaseline::Flat),
+            &series,
+            today,
+        )
+        .expect("result");
+        assert!(above.triggered);
+        assert_eq!(above.expected_value, None);
+
+        let mut short = rule(AlertConditionType::Anomaly, AlertBaseline::HoltWinters);
+        short.lookback_days = 10;
+        assert!(evaluate(&short, &series, today).is_none());
+    }
+
+    #[test]
+    fn hourly_rules_compare_the_last_complete_hour_with_the_same_hour() {
+        let mut hourly = rule(AlertConditionType::Drop, AlertBaseline::SameWeekday);
+        hourly.granularity = AlertGranularity::Hour;
+        hourly.lookback_days = 14;
+        let now = Utc.with_ymd_and_hms(2026, 10, 12, 10, 25, 0).unwrap();
+        let window = AlertWindow::for_rule(&hourly, now);
+        let nine_am = |month: u32, day: u32| {
+            NaiveDate::from_ymd_opt(2026, month, day)
+                .and_then(|d| d.and_hms_opt(9, 0, 0))
+                .expect("hour")
+        };
+        assert_eq!(window.current, nine_am(10, 12));
+        assert_eq!(window.buckets().count(), 14 * 24 + 1);
+
+        // Busy at 09:00 on Mondays, quiet at every other hour.
+        let mut series: HashMap<NaiveDateTime, f64> =
+            window.buckets().map(|bucket| (bucket, 5.0)).collect();
+        series.insert(nine_am(9, 28), 100.0);
+        series.insert(nine_am(10, 5), 100.0);
+        series.insert(nine_am(10, 12), 20.0);
+        let result = evaluate_alert_rule(&hourly, &series, &window).expect("result");
+        assert_eq!(result.expected_value, Some(100.0));
+        assert!(result.triggered, "a quiet Monday morning hour is a drop");
+    }
+
+    #[test]
+    fn cooldown_counts_from_the_last_trigger() {
+        let mut cooling = rule(AlertConditionType::Spike, AlertBaseline::Flat);
+        let now = Utc.with_ymd_and_hms(2026, 10, 12, 10, 0, 0).unwrap();
+        assert!(!in_cooldown(&cooling, now));
+        cooling.last_triggered_at = Some("2026-10-12 09:30:00.123".to_string());
+        assert!(in_cooldown(&cooling, now));
+        cooling.cooldown_minutes = 15;
+        assert!(!in_cooldown(&cooling, now));
+    }
+}
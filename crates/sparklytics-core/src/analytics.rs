@@ -12,22 +12,251 @@ pub struct AnalyticsFilter {
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
     pub timezone: Option<String>,
-    pub filter_country: Option<String>,
-    pub filter_page: Option<String>,
-    pub filter_referrer: Option<String>,
-    pub filter_browser: Option<String>,
-    pub filter_os: Option<String>,
-    pub filter_device: Option<String>,
-    pub filter_language: Option<String>,
-    pub filter_utm_source: Option<String>,
-    pub filter_utm_medium: Option<String>,
-    pub filter_utm_campaign: Option<String>,
-    pub filter_region: Option<String>,
-    pub filter_city: Option<String>,
-    pub filter_hostname: Option<String>,
+    pub filter_country: Option<DimensionFilter>,
+    pub filter_page: Option<DimensionFilter>,
+    pub filter_referrer: Option<DimensionFilter>,
+    pub filter_browser: Option<DimensionFilter>,
+    pub filter_os: Option<DimensionFilter>,
+    pub filter_device: Option<DimensionFilter>,
+    pub filter_language: Option<DimensionFilter>,
+    pub filter_utm_source: Option<DimensionFilter>,
+    pub filter_utm_medium: Option<DimensionFilter>,
+    pub filter_utm_campaign: Option<DimensionFilter>,
+    pub filter_region: Option<DimensionFilter>,
+    pub filter_city: Option<DimensionFilter>,
+    pub filter_hostname: Option<DimensionFilter>,
+    /// Predicates on the custom `event_data` JSON payload, AND-ed together.
+    pub filter_properties: Vec<PropertyFilter>,
     pub include_bots: bool,
 }
 
+/// Maximum number of values accepted by a single `in` dimension filter.
+pub const MAX_DIMENSION_FILTER_VALUES: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DimensionFilterOp {
+    Is,
+    IsNot,
+    Contains,
+    NotContains,
+    Regex,
+    In,
+}
+
+impl DimensionFilterOp {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Is => "is",
+            Self::IsNot => "is_not",
+            Self::Contains => "contains",
+            Self::NotContains => "not_contains",
+            Self::Regex => "regex",
+            Self::In => "in",
+        }
+    }
+
+    fn from_prefix(raw: &str) -> Option<Self> {
+        match raw {
+            "is" => Some(Self::Is),
+            "is_not" => Some(Self::IsNot),
+            "contains" => Some(Self::Contains),
+            "not_contains" => Some(Self::NotContains),
+            "regex" => Some(Self::Regex),
+            "in" => Some(Self::In),
+            _ => None,
+        }
+    }
+}
+
+/// Operator and operand(s) applied to one `filter_*` dimension.
+///
+/// Encoded as `<op>:<value>` in query strings and saved reports, e.g.
+/// `is_not:US`, `in:Chrome,Firefox` or `regex:^/blog/`. A value without a
+/// recognised operator prefix keeps the legacy meaning of the dimension
+/// (`contains` for page, `is` everywhere else). Negated operators match rows
+/// where the dimension is NULL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DimensionFilter {
+    pub op: DimensionFilterOp,
+    pub values: Vec<String>,
+}
+
+impl DimensionFilter {
+    pub fn new(op: DimensionFilterOp, value: impl Into<String>) -> Self {
+        Self {
+            op,
+            values: vec![value.into()],
+        }
+    }
+
+    pub fn is(value: impl Into<String>) -> Self {
+        Self::new(DimensionFilterOp::Is, value)
+    }
+
+    pub fn parse(raw: &str, default_op: DimensionFilterOp) -> Result<Self> {
+        let (op, operand) = match raw.split_once(':') {
+            Some((prefix, rest)) => match DimensionFilterOp::from_prefix(prefix) {
+                Some(op) => (op, rest),
+                None => (default_op, raw),
+            },
+            None => (default_op, raw),
+        };
+        let values: Vec<String> = match op {
+            DimensionFilterOp::In => operand
+                .split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect(),
+            _ => vec![operand.trim().to_string()],
+        };
+        if values.iter().all(String::is_empty) {
+            return Err(anyhow!("{} filter requires a value", op.as_str()));
+        }
+        if values.len() > MAX_DIMENSION_FILTER_VALUES {
+            return Err(anyhow!(
+                "in filter accepts at most {MAX_DIMENSION_FILTER_VALUES} values"
+            ));
+        }
+        if op == DimensionFilterOp::Regex {
+            for pattern in &values {
+                regex::Regex::new(pattern).map_err(|e| anyhow!("invalid regex: {e}"))?;
+            }
+        }
+        Ok(Self { op, values })
+    }
+}
+
+/// Maximum number of property predicates accepted on a single query.
+pub const MAX_PROPERTY_FILTERS: usize = 10;
+
+/// Maximum number of values accepted by a single `in` property predicate.
+pub const MAX_PROPERTY_FILTER_VALUES: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyFilterOp {
+    Eq,
+    Neq,
+    Contains,
+    In,
+    Exists,
+}
+
+/// Predicate on a top-level key of an event's `event_data` JSON.
+///
+/// Property values are compared as strings, so `{"logged_in": true}` matches
+/// `value: "true"` and `{"seats": 5}` matches `value: "5"`. Events whose
+/// payload lacks the key match `neq` but not `eq`, `contains` or `in`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PropertyFilter {
+    pub key: String,
+    pub op: PropertyFilterOp,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_scalar_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub value: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_scalar_strings",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub values: Vec<String>,
+}
+
+impl PropertyFilter {
+    pub fn validate(&self) -> Result<()> {
+        let key = self.key.trim();
+        if key.is_empty() {
+            return Err(anyhow!("property filter key is required"));
+        }
+        if key.len() > 128 {
+            return Err(anyhow!(
+                "property filter key must be at most 128 characters"
+            ));
+        }
+        match self.op {
+            PropertyFilterOp::Eq | PropertyFilterOp::Neq | PropertyFilterOp::Contains => {
+                if self.value.is_none() {
+                    return Err(anyhow!("property filter '{key}' requires a value"));
+                }
+            }
+            PropertyFilterOp::In => {
+                if self.values.is_empty() {
+                    return Err(anyhow!("property filter '{key}' requires values"));
+                }
+                if self.values.len() > MAX_PROPERTY_FILTER_VALUES {
+                    return Err(anyhow!(
+                        "property filter '{key}' accepts at most {MAX_PROPERTY_FILTER_VALUES} values"
+                    ));
+                }
+            }
+            PropertyFilterOp::Exists => {}
+        }
+        Ok(())
+    }
+
+    /// Parse the `filter_properties` query parameter: a JSON array of
+    /// property filters, e.g. `[{"key":"plan","op":"eq","value":"pro"}]`.
+    pub fn parse_list(raw: Option<&str>) -> Result<Vec<Self>> {
+        let Some(raw) = raw.map(str::trim).filter(|raw| !raw.is_empty()) else {
+            return Ok(Vec::new());
+        };
+        let filters: Vec<Self> = serde_json::from_str(raw)
+            .map_err(|e| anyhow!("filter_properties must be a JSON array of filters: {e}"))?;
+        Self::validate_list(&filters)?;
+        Ok(filters)
+    }
+
+    pub fn validate_list(filters: &[Self]) -> Result<()> {
+        if filters.len() > MAX_PROPERTY_FILTERS {
+            return Err(anyhow!(
+                "filter_properties accepts at most {MAX_PROPERTY_FILTERS} filters"
+            ));
+        }
+        filters.iter().try_for_each(Self::validate)
+    }
+}
+
+fn scalar_to_string(value: serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(s) => Some(s),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        serde_json::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn deserialize_optional_scalar_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(value) => scalar_to_string(value)
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom("value must be a string, number or boolean")),
+    }
+}
+
+fn deserialize_scalar_strings<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<Vec<serde_json::Value>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|value| {
+            scalar_to_string(value).ok_or_else(|| {
+                serde::de::Error::custom("values must be strings, numbers or booleans")
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum CompareMode {
@@ -377,6 +606,48 @@ pub struct ExportRow {
     pub created_at: String,
 }
 
+/// Output format for `GET /api/websites/:id/export`.
+///
+/// CSV is buffered (and cached) in memory; NDJSON and Parquet are written by
+/// the backend to a file and streamed to the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Ndjson,
+    Parquet,
+}
+
+impl ExportFormat {
+    pub fn parse(raw: Option<&str>) -> Result<Self> {
+        match raw.map(str::trim) {
+            None | Some("") | Some("csv") => Ok(Self::Csv),
+            Some("ndjson") => Ok(Self::Ndjson),
+            Some("parquet") => Ok(Self::Parquet),
+            Some(other) => Err(anyhow!(
+                "unsupported format: {other}; must be one of: csv, ndjson, parquet"
+            )),
+        }
+    }
+
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Ndjson => "ndjson",
+            Self::Parquet => "parquet",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Ndjson => "application/x-ndjson",
+            Self::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct EventNameRow {
     pub event_name: String,
@@ -552,31 +823,81 @@ pub struct GoalStats {
     pub trend_pct: Option<f64>,
 }
 
+/// Days before a conversion whose touchpoints are eligible for credit.
+pub const DEFAULT_ATTRIBUTION_LOOKBACK_DAYS: u32 = 30;
+pub const MAX_ATTRIBUTION_LOOKBACK_DAYS: u32 = 90;
+
+/// Half-life of a touchpoint's weight in the `time_decay` model.
+pub const DEFAULT_TIME_DECAY_HALF_LIFE_DAYS: f64 = 7.0;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum AttributionModel {
     FirstTouch,
     #[default]
     LastTouch,
+    /// Equal credit to every touchpoint.
+    Linear,
+    /// Credit halves every `half_life_days` before the conversion.
+    TimeDecay,
+    /// U-shaped: 40% first, 40% last, 20% split across the middle touches.
+    PositionBased,
+}
+
+impl AttributionModel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FirstTouch => "first_touch",
+            Self::LastTouch => "last_touch",
+            Self::Linear => "linear",
+            Self::TimeDecay => "time_decay",
+            Self::PositionBased => "position_based",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "first_touch" => Ok(Self::FirstTouch),
+            "last_touch" => Ok(Self::LastTouch),
+            "linear" => Ok(Self::Linear),
+            "time_decay" => Ok(Self::TimeDecay),
+            "position_based" => Ok(Self::PositionBased),
+            _ => Err(anyhow!(
+                "model must be one of: first_touch, last_touch, linear, time_decay, position_based"
+            )),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttributionQuery {
     pub goal_id: String,
     pub model: AttributionModel,
+    /// How far before a conversion the multi-touch models look for touches.
+    /// First- and last-touch only look at the converting session.
+    pub lookback_days: u32,
+    /// Only used by [`AttributionModel::TimeDecay`].
+    pub half_life_days: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttributionRow {
     pub channel: String,
+    /// Conversions whose touchpoint path included this channel.
     pub conversions: i64,
+    /// Fractional conversions credited to this channel by the model. Equals
+    /// `conversions` for the single-touch models.
+    pub credit: f64,
     pub revenue: f64,
     pub share: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttributionTotals {
+    /// Conversions that credited at least one touch passing the filters.
     pub conversions: i64,
+    /// Revenue credited by the model to touches passing the filters; the
+    /// full conversion revenue when no filter is set.
     pub revenue: f64,
 }
 
@@ -584,6 +905,8 @@ pub struct AttributionTotals {
 pub struct AttributionResponse {
     pub goal_id: String,
     pub model: AttributionModel,
+    pub lookback_days: u32,
+    pub half_life_days: Option<f64>,
     pub rows: Vec<AttributionRow>,
     pub totals: AttributionTotals,
 }
@@ -603,6 +926,65 @@ pub enum StepType {
     Event,
 }
 
+/// Upper bound for a funnel's conversion window (90 days).
+pub const MAX_FUNNEL_CONVERSION_WINDOW_MINUTES: u32 = 90 * 24 * 60;
+
+/// Which events a funnel run may span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FunnelScope {
+    /// All steps must happen within one session.
+    #[default]
+    Session,
+    /// Steps may span any of a visitor's sessions in the date range.
+    Visitor,
+}
+
+impl FunnelScope {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Session => "session",
+            Self::Visitor => "visitor",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "session" => Ok(Self::Session),
+            "visitor" => Ok(Self::Visitor),
+            _ => Err(anyhow!("scope must be one of: session, visitor")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FunnelOrdering {
+    /// Each step must happen after the previous one; unrelated events in
+    /// between are allowed.
+    #[default]
+    Strict,
+    /// Reaching step N means having completed steps 1..=N in any order.
+    AnyOrder,
+}
+
+impl FunnelOrdering {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Strict => "strict",
+            Self::AnyOrder => "any_order",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "strict" => Ok(Self::Strict),
+            "any_order" => Ok(Self::AnyOrder),
+            _ => Err(anyhow!("ordering must be one of: strict, any_order")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FunnelStep {
     pub id: String,
@@ -612,6 +994,9 @@ pub struct FunnelStep {
     pub match_value: String,
     pub match_operator: MatchOperator,
     pub label: String,
+    /// `event_data` constraints; only allowed on event steps.
+    #[serde(default)]
+    pub property_filters: Vec<PropertyFilter>,
     pub created_at: String,
 }
 
@@ -620,6 +1005,11 @@ pub struct Funnel {
     pub id: String,
     pub website_id: String,
     pub name: String,
+    /// Maximum minutes from the first step to each later step; `None` means
+    /// no limit beyond the scope and date range.
+    pub conversion_window_minutes: Option<u32>,
+    pub scope: FunnelScope,
+    pub ordering: FunnelOrdering,
     pub steps: Vec<FunnelStep>,
     pub created_at: String,
     pub updated_at: String,
@@ -641,18 +1031,28 @@ pub struct CreateFunnelStepRequest {
     pub match_value: String,
     pub match_operator: Option<MatchOperator>,
     pub label: Option<String>,
+    #[serde(default)]
+    pub property_filters: Vec<PropertyFilter>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateFunnelRequest {
     pub name: String,
     pub steps: Vec<CreateFunnelStepRequest>,
+    pub conversion_window_minutes: Option<u32>,
+    pub scope: Option<FunnelScope>,
+    pub ordering: Option<FunnelOrdering>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateFunnelRequest {
     pub name: Option<String>,
     pub steps: Option<Vec<CreateFunnelStepRequest>>,
+    /// `null` removes the window.
+    #[serde(default, deserialize_with = "deserialize_optional_nullable")]
+    pub conversion_window_minutes: Option<Option<u32>>,
+    pub scope: Option<FunnelScope>,
+    pub ordering: Option<FunnelOrdering>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -666,6 +1066,48 @@ pub struct FunnelStepResult {
     pub conversion_rate_from_previous: f64,
 }
 
+/// Upper bounds (exclusive, in seconds) of the time-to-convert histogram
+/// buckets; the last bucket is open-ended.
+pub const FUNNEL_TIME_BUCKET_BOUNDS_SECONDS: &[i64] = &[60, 600, 3_600, 86_400];
+
+/// Maximum number of segments returned by a funnel breakdown.
+pub const MAX_FUNNEL_BREAKDOWN_SEGMENTS: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunnelTimeBucket {
+    pub min_seconds: i64,
+    /// `None` for the open-ended last bucket.
+    pub max_seconds: Option<i64>,
+    pub count: i64,
+}
+
+/// Time taken to move from one step to the next, over the sessions (or
+/// visitors) that made the transition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunnelStepTiming {
+    pub from_step_order: u32,
+    pub to_step_order: u32,
+    pub converted: i64,
+    pub median_seconds: Option<f64>,
+    pub p90_seconds: Option<f64>,
+    pub histogram: Vec<FunnelTimeBucket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunnelBreakdownSegment {
+    /// Dimension value on the first step-1 match; `(none)` when unset.
+    pub value: String,
+    pub total_sessions_entered: i64,
+    pub final_conversion_rate: f64,
+    pub steps: Vec<FunnelStepResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunnelBreakdown {
+    pub dimension: String,
+    pub segments: Vec<FunnelBreakdownSegment>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FunnelResults {
     pub funnel_id: String,
@@ -673,6 +1115,131 @@ pub struct FunnelResults {
     pub total_sessions_entered: i64,
     pub final_conversion_rate: f64,
     pub steps: Vec<FunnelStepResult>,
+    #[serde(default)]
+    pub step_timings: Vec<FunnelStepTiming>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<FunnelBreakdown>,
+}
+
+/// Custom event name the tracker sends when a visitor is shown a variant.
+/// Its `event_data` must carry `experiment_id` and `variant`.
+pub const EXPERIMENT_EXPOSURE_EVENT: &str = "$experiment_exposure";
+
+/// Exposures and conversions each variant needs before a p-value is reported.
+pub const EXPERIMENT_MIN_SAMPLE_PER_VARIANT: i64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ExperimentStatus {
+    #[default]
+    Draft,
+    Running,
+    Paused,
+    Completed,
+}
+
+impl ExperimentStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Running => "running",
+            Self::Paused => "paused",
+            Self::Completed => "completed",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "draft" => Ok(Self::Draft),
+            "running" => Ok(Self::Running),
+            "paused" => Ok(Self::Paused),
+            "completed" => Ok(Self::Completed),
+            _ => Err(anyhow!(
+                "status must be one of: draft, running, paused, completed"
+            )),
+        }
+    }
+
+    /// Experiments move forward only: a draft can start, a running experiment
+    /// can pause and resume, and anything but a draft can complete.
+    pub fn can_transition_to(self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (Self::Draft, Self::Running)
+                | (Self::Running, Self::Paused)
+                | (Self::Paused, Self::Running)
+                | (Self::Running, Self::Completed)
+                | (Self::Paused, Self::Completed)
+        ) || self == next
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Experiment {
+    pub id: String,
+    pub website_id: String,
+    pub name: String,
+    pub status: ExperimentStatus,
+    pub variants: Vec<String>,
+    pub weights: Vec<u32>,
+    pub goal_id: String,
+    pub planned_duration_days: u32,
+    pub started_at: Option<String>,
+    pub ended_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateExperimentRequest {
+    pub name: String,
+    pub variants: Vec<String>,
+    /// Percent of traffic per variant; defaults to an even split.
+    pub weights: Option<Vec<u32>>,
+    pub goal_id: String,
+    pub planned_duration_days: u32,
+}
+
+/// Variants, weights, goal and duration can only change while the
+/// experiment is still a draft.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateExperimentRequest {
+    pub name: Option<String>,
+    pub status: Option<ExperimentStatus>,
+    pub variants: Option<Vec<String>>,
+    pub weights: Option<Vec<u32>>,
+    pub goal_id: Option<String>,
+    pub planned_duration_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentVariantResult {
+    pub variant: String,
+    pub weight: u32,
+    pub exposures: i64,
+    pub conversions: i64,
+    pub conversion_rate: f64,
+    /// 95% Wilson score interval for `conversion_rate`.
+    pub confidence_interval: [f64; 2],
+    /// Relative change in conversion rate against the first (control) variant.
+    pub lift: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentResults {
+    pub experiment_id: String,
+    pub goal_id: String,
+    pub status: ExperimentStatus,
+    pub variants: Vec<ExperimentVariantResult>,
+    pub min_sample_per_variant: i64,
+    pub sample_size_reached: bool,
+    /// When the planned duration ends; significance is withheld before then
+    /// unless the experiment was completed manually.
+    pub planned_end_at: Option<String>,
+    pub duration_reached: bool,
+    pub p_value: Option<f64>,
+    pub significant: bool,
+    pub winner: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -873,6 +1440,12 @@ pub enum SubscriptionSchedule {
 pub enum NotificationChannel {
     Email,
     Webhook,
+    /// Slack incoming webhook; messages are rendered as Block Kit blocks.
+    Slack,
+    /// Microsoft Teams incoming webhook or workflow; rendered as an Adaptive Card.
+    Teams,
+    /// Discord channel webhook; rendered as an embed.
+    Discord,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -882,6 +1455,105 @@ pub enum AlertMetric {
     Visitors,
     Conversions,
     ConversionRate,
+    /// Custom events named by the rule's `event_name`.
+    CustomEvents,
+    /// Percentage of sessions with a single pageview.
+    BounceRate,
+    /// Average session length in seconds.
+    SessionDuration,
+    /// Percentage of events flagged as bot traffic.
+    BotShare,
+}
+
+/// Size of the buckets an alert rule compares.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertGranularity {
+    /// Today so far against prior days.
+    #[default]
+    Day,
+    /// The last complete hour against prior hours.
+    Hour,
+}
+
+/// Dimension and property filters narrowing the traffic an alert rule
+/// watches, encoded like a saved report's (`DE`, `contains:/checkout`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertFilters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_country: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_page: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_referrer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_browser: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_os: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_device: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_utm_source: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_utm_medium: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_utm_campaign: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_region: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_hostname: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filter_properties: Vec<PropertyFilter>,
+}
+
+impl AlertFilters {
+    /// Parse into an [`AnalyticsFilter`] for `start_date..=end_date`.
+    pub fn to_analytics_filter(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        include_bots: bool,
+    ) -> Result<AnalyticsFilter> {
+        fn parse(field: &str, raw: &Option<String>) -> Result<Option<DimensionFilter>> {
+            let Some(raw) = raw.as_deref().map(str::trim).filter(|raw| !raw.is_empty()) else {
+                return Ok(None);
+            };
+            let default_op = if field == "filter_page" {
+                DimensionFilterOp::Contains
+            } else {
+                DimensionFilterOp::Is
+            };
+            DimensionFilter::parse(raw, default_op)
+                .map(Some)
+                .map_err(|e| anyhow!("{field}: {e}"))
+        }
+
+        PropertyFilter::validate_list(&self.filter_properties)?;
+        Ok(AnalyticsFilter {
+            start_date,
+            end_date,
+            timezone: None,
+            filter_country: parse("filter_country", &self.filter_country)?,
+            filter_page: parse("filter_page", &self.filter_page)?,
+            filter_referrer: parse("filter_referrer", &self.filter_referrer)?,
+            filter_browser: parse("filter_browser", &self.filter_browser)?,
+            filter_os: parse("filter_os", &self.filter_os)?,
+            filter_device: parse("filter_device", &self.filter_device)?,
+            filter_language: parse("filter_language", &self.filter_language)?,
+            filter_utm_source: parse("filter_utm_source", &self.filter_utm_source)?,
+            filter_utm_medium: parse("filter_utm_medium", &self.filter_utm_medium)?,
+            filter_utm_campaign: parse("filter_utm_campaign", &self.filter_utm_campaign)?,
+            filter_region: parse("filter_region", &self.filter_region)?,
+            filter_city: parse("filter_city", &self.filter_city)?,
+            filter_hostname: parse("filter_hostname", &self.filter_hostname)?,
+            filter_properties: self.filter_properties.clone(),
+            include_bots,
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -891,6 +1563,23 @@ pub enum AlertConditionType {
     Drop,
     ThresholdAbove,
     ThresholdBelow,
+    /// Outside the expected band in either direction.
+    Anomaly,
+}
+
+/// What spike, drop and anomaly conditions compare today's value against.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertBaseline {
+    /// Mean and spread of every day in the lookback window.
+    #[default]
+    Flat,
+    /// The same weekday in each prior week of the lookback window, up to the
+    /// same time of day.
+    SameWeekday,
+    /// Additive Holt-Winters forecast with a weekly season, up to the same
+    /// time of day.
+    HoltWinters,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -904,6 +1593,9 @@ pub enum NotificationSourceType {
 #[serde(rename_all = "snake_case")]
 pub enum NotificationDeliveryStatus {
     Sent,
+    /// Failed, with another attempt scheduled at `next_attempt_at`.
+    Retrying,
+    /// Failed for good: out of attempts, or not retryable.
     Failed,
 }
 
@@ -916,6 +1608,10 @@ pub struct ReportSubscription {
     pub timezone: String,
     pub channel: NotificationChannel,
     pub target: String,
+    /// HMAC-SHA256 key for the signature header on webhook deliveries.
+    /// Never serialized; only the create and rotate-secret responses add it.
+    #[serde(skip_serializing, default)]
+    pub signing_secret: String,
     pub is_active: bool,
     pub last_run_at: Option<String>,
     pub next_run_at: String,
@@ -950,11 +1646,32 @@ pub struct AlertRule {
     pub name: String,
     pub metric: AlertMetric,
     pub condition_type: AlertConditionType,
+    /// Standard deviations from the expected value for spike, drop and
+    /// anomaly conditions; the raw value for thresholds.
     pub threshold_value: f64,
     pub lookback_days: i64,
+    pub baseline: AlertBaseline,
+    /// Event counted by the `custom_events` metric.
+    pub event_name: Option<String>,
+    pub filters: AlertFilters,
+    pub granularity: AlertGranularity,
+    /// Minimum time between two triggered notifications.
+    pub cooldown_minutes: i64,
     pub channel: NotificationChannel,
     pub target: String,
+    /// HMAC-SHA256 key for the signature header on webhook deliveries.
+    /// Never serialized; only the create and rotate-secret responses add it.
+    #[serde(skip_serializing, default)]
+    pub signing_secret: String,
     pub is_active: bool,
+    /// Whether the last notification sent was a trigger rather than a
+    /// resolution.
+    pub is_firing: bool,
+    /// Number of times the rule has started firing. Keys the notifications
+    /// of one firing episode so a re-trigger after a resolve is not deduped.
+    #[serde(default)]
+    pub firing_episode: i64,
+    pub last_triggered_at: Option<String>,
     pub created_at: String,
 }
 
@@ -965,6 +1682,11 @@ pub struct CreateAlertRuleRequest {
     pub condition_type: AlertConditionType,
     pub threshold_value: f64,
     pub lookback_days: Option<i64>,
+    pub baseline: Option<AlertBaseline>,
+    pub event_name: Option<String>,
+    pub filters: Option<AlertFilters>,
+    pub granularity: Option<AlertGranularity>,
+    pub cooldown_minutes: Option<i64>,
     pub channel: NotificationChannel,
     pub target: String,
 }
@@ -976,6 +1698,12 @@ pub struct UpdateAlertRuleRequest {
     pub condition_type: Option<AlertConditionType>,
     pub threshold_value: Option<f64>,
     pub lookback_days: Option<i64>,
+    pub baseline: Option<AlertBaseline>,
+    #[serde(default, deserialize_with = "deserialize_optional_nullable")]
+    pub event_name: Option<Option<String>>,
+    pub filters: Option<AlertFilters>,
+    pub granularity: Option<AlertGranularity>,
+    pub cooldown_minutes: Option<i64>,
     pub channel: Option<NotificationChannel>,
     #[serde(default, deserialize_with = "deserialize_optional_nullable")]
     pub target: Option<Option<String>>,
@@ -990,6 +1718,11 @@ pub struct AlertEvaluationResult {
     pub baseline_mean: Option<f64>,
     pub baseline_stddev: Option<f64>,
     pub z_score: Option<f64>,
+    /// Value the baseline predicts for today; `None` for threshold conditions.
+    pub expected_value: Option<f64>,
+    /// Edges of the band outside which the rule triggers.
+    pub lower_bound: Option<f64>,
+    pub upper_bound: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1000,6 +1733,12 @@ pub struct NotificationDelivery {
     pub idempotency_key: String,
     pub status: NotificationDeliveryStatus,
     pub error_message: Option<String>,
+    /// `None` for deliveries recorded without a send (e.g. report errors).
+    pub channel: Option<NotificationChannel>,
+    pub target: Option<String>,
+    pub attempt_count: i64,
+    pub next_attempt_at: Option<String>,
+    /// Time of the latest attempt.
     pub delivered_at: String,
 }
 
@@ -1054,6 +1793,8 @@ pub struct ReportConfig {
     pub filter_region: Option<String>,
     pub filter_city: Option<String>,
     pub filter_hostname: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filter_properties: Vec<PropertyFilter>,
 }
 
 impl Default for ReportConfig {
@@ -1082,6 +1823,7 @@ impl Default for ReportConfig {
             filter_region: None,
             filter_city: None,
             filter_hostname: None,
+            filter_properties: Vec::new(),
         }
     }
 }
@@ -1145,6 +1887,36 @@ pub struct ReportRunResult {
 mod tests {
     use super::*;
 
+    #[test]
+    fn property_filters_parse_scalar_values() {
+        let filters = PropertyFilter::parse_list(Some(
+            r#"[{"key":"plan","op":"eq","value":"pro"},
+                {"key":"logged_in","op":"eq","value":true},
+                {"key":"seats","op":"in","values":[1,2,"3"]},
+                {"key":"experiment","op":"exists"}]"#,
+        ))
+        .expect("valid filters");
+
+        assert_eq!(filters.len(), 4);
+        assert_eq!(filters[1].value.as_deref(), Some("true"));
+        assert_eq!(filters[2].values, vec!["1", "2", "3"]);
+        assert_eq!(filters[3].op, PropertyFilterOp::Exists);
+        assert!(PropertyFilter::parse_list(None).expect("empty").is_empty());
+    }
+
+    #[test]
+    fn property_filters_reject_missing_values_and_unknown_ops() {
+        assert!(PropertyFilter::parse_list(Some(r#"[{"key":"plan","op":"eq"}]"#)).is_err());
+        assert!(
+            PropertyFilter::parse_list(Some(r#"[{"key":"plan","op":"in","values":[]}]"#)).is_err()
+        );
+        assert!(PropertyFilter::parse_list(Some(r#"[{"key":" ","op":"exists"}]"#)).is_err());
+        assert!(
+            PropertyFilter::parse_list(Some(r#"[{"key":"plan","op":"gt","value":"1"}]"#)).is_err()
+        );
+        assert!(PropertyFilter::parse_list(Some("plan=pro")).is_err());
+    }
+
     #[test]
     fn previous_year_uses_calendar_alignment() {
         let start = NaiveDate::from_ymd_opt(2025, 3, 1).expect("valid date");
@@ -1218,6 +1990,7 @@ pub trait AnalyticsBackend: Send + Sync + 'static {
         visitor_id: &str,
         referrer_domain: Option<&str>,
         url: &str,
+        utm_campaign: Option<&str>,
         now: DateTime<Utc>,
     ) -> anyhow::Result<String>;
 
@@ -1280,6 +2053,21 @@ pub trait AnalyticsBackend: Send + Sync + 'static {
         end: NaiveDate,
     ) -> anyhow::Result<Vec<ExportRow>>;
 
+    /// Write the same rows as [`AnalyticsBackend::export_events`] to `path`
+    /// without an in-memory row cap. Returns the number of rows written.
+    ///
+    /// Only [`ExportFormat::Ndjson`] and [`ExportFormat::Parquet`] are
+    /// supported; CSV stays on the buffered path.
+    async fn export_events_to_file(
+        &self,
+        website_id: &str,
+        tenant_id: Option<&str>,
+        start: NaiveDate,
+        end: NaiveDate,
+        format: ExportFormat,
+        path: &std::path::Path,
+    ) -> anyhow::Result<u64>;
+
     async fn get_event_names(
         &self,
         website_id: &str,
@@ -1436,8 +2224,58 @@ pub trait AnalyticsBackend: Send + Sync + 'static {
         tenant_id: Option<&str>,
         funnel_id: &str,
         filter: &AnalyticsFilter,
+        breakdown: Option<&str>,
     ) -> anyhow::Result<FunnelResults>;
 
+    async fn list_experiments(
+        &self,
+        website_id: &str,
+        tenant_id: Option<&str>,
+    ) -> anyhow::Result<Vec<Experiment>>;
+
+    async fn get_experiment(
+        &self,
+        website_id: &str,
+        tenant_id: Option<&str>,
+        experiment_id: &str,
+    ) -> anyhow::Result<Option<Experiment>>;
+
+    async fn create_experiment(
+        &self,
+        website_id: &str,
+        tenant_id: Option<&str>,
+        req: CreateExperimentRequest,
+    ) -> anyhow::Result<Experiment>;
+
+    async fn update_experiment(
+        &self,
+        website_id: &str,
+        tenant_id: Option<&str>,
+        experiment_id: &str,
+        req: UpdateExperimentRequest,
+    ) -> anyhow::Result<Option<Experiment>>;
+
+    async fn delete_experiment(
+        &self,
+        website_id: &str,
+        tenant_id: Option<&str>,
+        experiment_id: &str,
+    ) -> anyhow::Result<bool>;
+
+    async fn count_running_experiments(
+        &self,
+        website_id: &str,
+        tenant_id: Option<&str>,
+    ) -> anyhow::Result<i64>;
+
+    async fn get_experiment_results(
+        &self,
+        website_id: &str,
+        tenant_id: Option<&str>,
+        experiment_id: &str,
+        include_bots: bool,
+    ) -> anyhow::Result<ExperimentResults>;
+
     async fn get_journey(
         &self,
         website_id: &str,
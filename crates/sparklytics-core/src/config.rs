@@ -33,6 +33,113 @@ pub enum AuthMode {
     /// Holds the plaintext password value read from `SPARKLYTICS_PASSWORD`.
     Password(String),
     Local,
+    /// OpenID Connect single sign-on, configured by `SPARKLYTICS_OIDC_*`.
+    Oidc(OidcConfig),
+}
+
+/// Identity provider settings for `SPARKLYTICS_AUTH=oidc`.
+#[derive(Clone, PartialEq)]
+pub struct OidcConfig {
+    /// `SPARKLYTICS_OIDC_ISSUER`, without a trailing slash. Discovery is read
+    /// from `<issuer>/.well-known/openid-configuration`.
+    pub issuer: String,
+    /// `SPARKLYTICS_OIDC_CLIENT_ID`.
+    pub client_id: String,
+    /// `SPARKLYTICS_OIDC_CLIENT_SECRET`; unset for public clients, which rely
+    /// on PKCE alone.
+    pub client_secret: Option<String>,
+    /// `SPARKLYTICS_OIDC_SCOPES`, default `openid email profile`.
+    pub scopes: String,
+    /// `SPARKLYTICS_OIDC_ROLE_CLAIM`: ID token claim holding the user's
+    /// groups, default `groups`.
+    pub role_claim: String,
+    /// `SPARKLYTICS_OIDC_ROLE_MAP`: comma-separated `group=role` pairs, e.g.
+    /// `analytics-admins=admin,analytics=viewer`. Roles are `owner`, `admin`
+    /// or `viewer`; the highest matching role wins.
+    pub role_map: Vec<(String, String)>,
+    /// `SPARKLYTICS_OIDC_DEFAULT_ROLE` for users matching no group. Unset
+    /// means such users cannot sign in.
+    pub default_role: Option<String>,
+}
+
+impl std::fmt::Debug for OidcConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcConfig")
+            .field("issuer", &self.issuer)
+            .field("client_id", &self.client_id)
+            .field(
+                "client_secret",
+                &self.client_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .field("scopes", &self.scopes)
+            .field("role_claim", &self.role_claim)
+            .field("role_map", &self.role_map)
+            .field("default_role", &self.default_role)
+            .finish()
+    }
+}
+
+const OIDC_ROLES: [&str; 3] = ["owner", "admin", "viewer"];
+
+impl OidcConfig {
+    fn from_env_with<F>(get_var: &F) -> Result<Self, String>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let var = |key: &str| {
+            get_var(key)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let issuer = var("SPARKLYTICS_OIDC_ISSUER")
+            .ok_or_else(|| "SPARKLYTICS_OIDC_ISSUER required when AUTH=oidc".to_string())?
+            .trim_end_matches('/')
+            .to_string();
+        let client_id = var("SPARKLYTICS_OIDC_CLIENT_ID")
+            .ok_or_else(|| "SPARKLYTICS_OIDC_CLIENT_ID required when AUTH=oidc".to_string())?;
+
+        let mut role_map = Vec::new();
+        for pair in var("SPARKLYTICS_OIDC_ROLE_MAP")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+        {
+            let (group, role) = pair
+                .rsplit_once('=')
+                .map(|(group, role)| (group.trim(), role.trim()))
+                .filter(|(group, role)| !group.is_empty() && OIDC_ROLES.contains(role))
+                .ok_or_else(|| {
+                    format!("SPARKLYTICS_OIDC_ROLE_MAP entries must be group=owner|admin|viewer, got {pair}")
+                })?;
+            role_map.push((group.to_string(), role.to_string()));
+        }
+        let default_role = var("SPARKLYTICS_OIDC_DEFAULT_ROLE");
+        if let Some(role) = default_role.as_deref() {
+            if !OIDC_ROLES.contains(&role) {
+                return Err(format!(
+                    "SPARKLYTICS_OIDC_DEFAULT_ROLE must be owner, admin or viewer, got {role}"
+                ));
+            }
+        }
+        if role_map.is_empty() && default_role.is_none() {
+            return Err(
+                "SPARKLYTICS_OIDC_ROLE_MAP or SPARKLYTICS_OIDC_DEFAULT_ROLE required when AUTH=oidc"
+                    .to_string(),
+            );
+        }
+
+        Ok(Self {
+            issuer,
+            client_id,
+            client_secret: var("SPARKLYTICS_OIDC_CLIENT_SECRET"),
+            scopes: var("SPARKLYTICS_OIDC_SCOPES")
+                .unwrap_or_else(|| "openid email profile".to_string()),
+            role_claim: var("SPARKLYTICS_OIDC_ROLE_CLAIM").unwrap_or_else(|| "groups".to_string()),
+            role_map,
+            default_role,
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -85,6 +192,7 @@ impl Config {
                         })?;
                         AuthMode::Password(pw)
                     }
+                    "oidc" => AuthMode::Oidc(OidcConfig::from_env_with(&get_var)?),
                     _ => AuthMode::Local,
                 }
             },
@@ -144,7 +252,7 @@ impl Config {
 mod tests {
     use std::collections::HashMap;
 
-    use super::Config;
+    use super::{AuthMode, Config};
 
     #[test]
     fn trims_trailing_slash_from_public_url() {
@@ -228,4 +336,57 @@ mod tests {
 
         assert_eq!(cfg.bootstrap_password, None);
     }
+
+    #[test]
+    fn parses_oidc_settings() {
+        let vars = HashMap::from([
+            ("SPARKLYTICS_AUTH", "oidc".to_string()),
+            (
+                "SPARKLYTICS_OIDC_ISSUER",
+                "https://idp.example.com/".to_string(),
+            ),
+            ("SPARKLYTICS_OIDC_CLIENT_ID", "sparklytics".to_string()),
+            (
+                "SPARKLYTICS_OIDC_ROLE_MAP",
+                "analytics-admins=admin, analytics=viewer".to_string(),
+            ),
+        ]);
+
+        let cfg = Config::from_env_with(|key| vars.get(key).cloned()).expect("config");
+
+        let AuthMode::Oidc(oidc) = cfg.auth_mode else {
+            panic!("expected oidc auth mode");
+        };
+        assert_eq!(oidc.issuer, "https://idp.example.com");
+        assert_eq!(oidc.scopes, "openid email profile");
+        assert_eq!(oidc.role_claim, "groups");
+        assert_eq!(
+            oidc.role_map,
+            vec![
+                ("analytics-admins".to_string(), "admin".to_string()),
+                ("analytics".to_string(), "viewer".to_string()),
+            ]
+        );
+        assert_eq!(oidc.default_role, None);
+    }
+
+    #[test]
+    fn rejects_oidc_role_map_with_unknown_role() {
+        let vars = HashMap::from([
+            ("SPARKLYTICS_AUTH", "oidc".to_string()),
+            (
+                "SPARKLYTICS_OIDC_ISSUER",
+                "https://idp.example.com".to_string(),
+            ),
+            ("SPARKLYTICS_OIDC_CLIENT_ID", "sparklytics".to_string()),
+            ("SPARKLYTICS_OIDC_ROLE_MAP", "staff=superuser".to_string()),
+        ]);
+
+        let err = Config::from_env_with(|key| vars.get(key).cloned()).expect_err("bad role");
+
+        assert_eq!(
+            err,
+            "SPARKLYTICS_OIDC_ROLE_MAP entries must be group=owner|admin|viewer, got staff=superuser"
+        );
+    }
 }
@@ -41,6 +41,48 @@ pub enum CollectOrBatch {
     Batch(Vec<CollectPayload>),
 }
 
+/// One event sent by a trusted backend to
+/// `POST /api/websites/{id}/events/ingest`.
+///
+/// Unlike [`CollectPayload`], the website comes from the path and the
+/// end user's IP, User-Agent and event time are supplied explicitly instead
+/// of being taken from the HTTP request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerIngestPayload {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub url: String,
+    pub referrer: Option<String>,
+    pub screen: Option<String>,
+    pub language: Option<String>,
+    pub event_name: Option<String>,
+    pub event_data: Option<serde_json::Value>,
+    pub utm_source: Option<String>,
+    pub utm_medium: Option<String>,
+    pub utm_campaign: Option<String>,
+    pub utm_term: Option<String>,
+    pub utm_content: Option<String>,
+    /// Same rules as [`CollectPayload::visitor_id`]. Required unless `ip` is set.
+    pub visitor_id: Option<String>,
+    /// When the event happened (RFC 3339). Defaults to the time of receipt.
+    pub timestamp: Option<DateTime<Utc>>,
+    /// End-user IP used for GeoIP, bot overrides and the derived visitor ID.
+    pub ip: Option<String>,
+    /// End-user User-Agent used for device parsing and bot classification.
+    pub user_agent: Option<String>,
+    /// Events repeating a key already accepted for the website are skipped.
+    pub idempotency_key: Option<String>,
+}
+
+/// Accepts either a single event or a batch array at the server ingest endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ServerIngestOrBatch {
+    Single(Box<ServerIngestPayload>),
+    Batch(Vec<ServerIngestPayload>),
+}
+
 /// The enriched, stored version of an event — mirrors the DuckDB `events` table columns exactly.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
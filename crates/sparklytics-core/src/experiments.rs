@@ -0,0 +1,352 @@
+//! Significance testing for A/B experiments.
+//!
+//! Backends only count exposures and conversions per variant; the statistics
+//! run here so every backend reports identical numbers.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+
+use crate::analytics::{
+    Experiment, ExperimentResults, ExperimentStatus, ExperimentVariantResult,
+    EXPERIMENT_MIN_SAMPLE_PER_VARIANT,
+};
+
+const SIGNIFICANCE_LEVEL: f64 = 0.05;
+const Z_95: f64 = 1.96;
+
+/// Default traffic split: equal integer percentages, with the remainder
+/// going to the last variant so the weights always sum to 100.
+pub fn even_split_weights(variant_count: usize) -> Vec<u32> {
+    if variant_count == 0 {
+        return Vec::new();
+    }
+    let count = variant_count as u32;
+    let mut weights = vec![100 / count; variant_count];
+    if let Some(last) = weights.last_mut() {
+        *last += 100 % count;
+    }
+    weights
+}
+
+/// Pearson chi-squared test of independence across all variants.
+///
+/// `data` holds `(conversions, exposures)` per variant. Degenerate inputs
+/// (fewer than two variants, an empty variant, no conversions at all or
+/// everyone converting) return 1.0, i.e. "not significant".
+pub fn chi_squared_p_value(data: &[(i64, i64)]) -> f64 {
+    if data.len() < 2 {
+        return 1.0;
+    }
+    let total_conv: i64 = data.iter().map(|&(c, _)| c).sum();
+    let total_exp: i64 = data.iter().map(|&(_, e)| e).sum();
+    if total_exp <= 0 || total_conv <= 0 {
+        return 1.0;
+    }
+    let expected_rate = total_conv as f64 / total_exp as f64;
+
+    let mut chi_sq = 0.0;
+    for &(conversions, exposures) in data {
+        if exposures <= 0 {
+            return 1.0;
+        }
+        let exp_conv = expected_rate * exposures as f64;
+        let exp_no_conv = (1.0 - expected_rate) * exposures as f64;
+        if exp_conv < 1e-9 || exp_no_conv < 1e-9 {
+            return 1.0;
+        }
+        chi_sq += (conversions as f64 - exp_conv).powi(2) / exp_conv;
+        chi_sq += ((exposures - conversions) as f64 - exp_no_conv).powi(2) / exp_no_conv;
+    }
+
+    chi_squared_survival(chi_sq, data.len() - 1)
+}
+
+/// 95% Wilson score interval for a conversion rate.
+pub fn wilson_interval(conversions: i64, exposures: i64) -> [f64; 2] {
+    if exposures <= 0 {
+        return [0.0, 0.0];
+    }
+    let n = exposures as f64;
+    let p = conversions as f64 / n;
+    let z2 = Z_95 * Z_95;
+    let denominator = 1.0 + z2 / n;
+    let center = (p + z2 / (2.0 * n)) / denominator;
+    let margin = Z_95 * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denominator;
+    [(center - margin).max(0.0), (center + margin).min(1.0)]
+}
+
+/// Assemble per-variant results from raw `(conversions, exposures)` counts.
+///
+/// Counts for variants that are not part of the experiment definition are
+/// ignored. A p-value is only reported once every variant reaches the
+/// minimum sample and the planned duration has elapsed (or the experiment
+/// was completed), because chi-squared is a fixed-horizon test.
+pub fn build_experiment_results(
+    experiment: &Experiment,
+    counts: &HashMap<String, (i64, i64)>,
+    now: DateTime<Utc>,
+) -> ExperimentResults {
+    let variants: Vec<ExperimentVariantResult> = experiment
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(idx, variant)| {
+            let (conversions, exposures) = counts.get(variant).copied().unwrap_or((0, 0));
+            ExperimentVariantResult {
+                variant: variant.clone(),
+                weight: experiment.weights.get(idx).copied().unwrap_or(0),
+                exposures,
+                conversions,
+                conversion_rate: rate(conversions, exposures),
+                confidence_interval: wilson_interval(conversions, exposures),
+                lift: None,
+            }
+        })
+        .collect();
+    let variants = with_lift(variants);
+
+    let sample_size_reached = !variants.is_empty()
+        && variants.iter().all(|v| {
+            v.exposures >= EXPERIMENT_MIN_SAMPLE_PER_VARIANT
+                && v.conversions >= EXPERIMENT_MIN_SAMPLE_PER_VARIANT
+        });
+
+    let planned_end = experiment
+        .started_at
+        .as_deref()
+        .and_then(parse_timestamp)
+        .map(|started| started + Duration::days(i64::from(experiment.planned_duration_days)));
+    let duration_reached = experiment.status == ExperimentStatus::Completed
+        || planned_end.is_some_and(|end| end <= now);
+
+    let p_value = (sample_size_reached && duration_reached).then(|| {
+        let data: Vec<(i64, i64)> = variants
+            .iter()
+            .map(|v| (v.conversions, v.exposures))
+            .collect();
+        chi_squared_p_value(&data)
+    });
+    let significant = p_value.is_some_and(|p| p < SIGNIFICANCE_LEVEL);
+    let winner = if significant {
+        variants
+            .iter()
+            .max_by(|a, b| a.conversion_rate.total_cmp(&b.conversion_rate))
+            .map(|v| v.variant.clone())
+    } else {
+        None
+    };
+
+    ExperimentResults {
+        experiment_id: experiment.id.clone(),
+        goal_id: experiment.goal_id.clone(),
+        status: experiment.status,
+        variants,
+        min_sample_per_variant: EXPERIMENT_MIN_SAMPLE_PER_VARIANT,
+        sample_size_reached,
+        planned_end_at: planned_end.map(|end| end.format("%Y-%m-%d %H:%M:%S").to_string()),
+        duration_reached,
+        p_value,
+        significant,
+        winner,
+    }
+}
+
+fn rate(conversions: i64, exposures: i64) -> f64 {
+    if exposures <= 0 {
+        0.0
+    } else {
+        conversions as f64 / exposures as f64
+    }
+}
+
+fn with_lift(mut variants: Vec<ExperimentVariantResult>) -> Vec<ExperimentVariantResult> {
+    let Some(control_rate) = variants.first().map(|v| v.conversion_rate) else {
+        return variants;
+    };
+    if control_rate > 0.0 {
+        for variant in variants.iter_mut().skip(1) {
+            variant.lift = Some((variant.conversion_rate - control_rate) / control_rate);
+        }
+    }
+    variants
+}
+
+fn parse_timestamp(raw: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M:%S%.f")
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+/// Survival function `P(X >= x)` of the chi-squared distribution.
+///
+/// Uses the closed forms for one and two degrees of freedom and the
+/// recurrence `Q(k + 2, x) = Q(k, x) + (x/2)^(k/2) e^(-x/2) / Γ(k/2 + 1)`.
+fn chi_squared_survival(x: f64, degrees_of_freedom: usize) -> f64 {
+    if x <= 0.0 || degrees_of_freedom == 0 {
+        return 1.0;
+    }
+    let half_x = x / 2.0;
+    let (mut k, mut survival, mut term) = if degrees_of_freedom % 2 == 1 {
+        (
+            1usize,
+            erfc(half_x.sqrt()),
+            (2.0 * x / std::f64::consts::PI).sqrt() * (-half_x).exp(),
+        )
+    } else {
+        (2usize, (-half_x).exp(), half_x * (-half_x).exp())
+    };
+    while k < degrees_of_freedom {
+        survival += term;
+        k += 2;
+        term *= half_x / (k as f64 / 2.0);
+    }
+    survival.clamp(0.0, 1.0)
+}
+
+/// Complementary error function (Numerical Recipes `erfcc`, fractional
+/// error below 1.2e-7).
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let ans = t * poly.exp();
+    if x >= 0.0 {
+        ans
+    } else {
+        2.0 - ans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn experiment(status: ExperimentStatus, started_at: Option<&str>) -> Experiment {
+        Experiment {
+            id: "exp_1".to_string(),
+            website_id: "site_1".to_string(),
+            name: "Pricing".to_string(),
+            status,
+            variants: vec!["control".to_string(), "higher-price".to_string()],
+            weights: vec![50, 50],
+            goal_id: "goal_1".to_string(),
+            planned_duration_days: 14,
+            started_at: started_at.map(str::to_string),
+            ended_at: None,
+            created_at: "2026-02-01 00:00:00".to_string(),
+            updated_at: "2026-02-01 00:00:00".to_string(),
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn even_split_weights_sum_to_one_hundred() {
+        assert_eq!(even_split_weights(2), vec![50, 50]);
+        assert_eq!(even_split_weights(3), vec![33, 33, 34]);
+        assert_eq!(even_split_weights(4), vec![25, 25, 25, 25]);
+        assert!(even_split_weights(0).is_empty());
+    }
+
+    #[test]
+    fn chi_squared_survival_matches_reference_values() {
+        assert_close(chi_squared_survival(3.841_459, 1), 0.05, 1e-5);
+        assert_close(chi_squared_survival(5.991_465, 2), 0.05, 1e-5);
+        assert_close(chi_squared_survival(7.814_728, 3), 0.05, 1e-5);
+        assert_close(chi_squared_survival(6.634_897, 1), 0.01, 1e-5);
+        assert_close(chi_squared_survival(9.487_729, 4), 0.05, 1e-5);
+        assert_close(chi_squared_survival(11.070_498, 5), 0.05, 1e-5);
+        assert_close(chi_squared_survival(12.591_587, 6), 0.05, 1e-5);
+        assert_close(chi_squared_survival(15.086_272, 5), 0.01, 1e-5);
+    }
+
+    #[test]
+    fn chi_squared_p_value_detects_difference() {
+        let p = chi_squared_p_value(&[(89, 1245), (124, 1198)]);
+        assert!(p < 0.05, "p = {p}");
+        let p = chi_squared_p_value(&[(100, 1000), (101, 1000)]);
+        assert!(p > 0.5, "p = {p}");
+    }
+
+    #[test]
+    fn chi_squared_p_value_guards_degenerate_inputs() {
+        assert_eq!(chi_squared_p_value(&[(0, 100), (0, 100)]), 1.0);
+        assert_eq!(chi_squared_p_value(&[(10, 100), (0, 0)]), 1.0);
+        assert_eq!(chi_squared_p_value(&[(10, 100)]), 1.0);
+    }
+
+    #[test]
+    fn wilson_interval_brackets_rate() {
+        let [low, high] = wilson_interval(89, 1245);
+        assert_close(low, 0.0583, 1e-3);
+        assert_close(high, 0.0873, 1e-3);
+        assert_eq!(wilson_interval(0, 0), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn results_withhold_p_value_until_sample_and_duration_are_reached() {
+        let now = Utc::now();
+        let started = (now - Duration::days(30))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        let mut counts = HashMap::new();
+        counts.insert("control".to_string(), (120, 1500));
+        counts.insert("higher-price".to_string(), (40, 1500));
+        counts.insert("unknown".to_string(), (500, 500));
+
+        let results = build_experiment_results(
+            &experiment(ExperimentStatus::Running, Some(&started)),
+            &counts,
+            now,
+        );
+        assert_eq!(results.variants.len(), 2);
+        assert!(!results.sample_size_reached);
+        assert!(results.duration_reached);
+        assert!(results.p_value.is_none());
+        assert!(!results.significant);
+
+        counts.insert("higher-price".to_string(), (200, 1500));
+        let results = build_experiment_results(
+            &experiment(ExperimentStatus::Running, Some(&started)),
+            &counts,
+            now,
+        );
+        assert!(results.sample_size_reached);
+        assert!(results.significant);
+        assert_eq!(results.winner.as_deref(), Some("higher-price"));
+        let lift = results.variants[1].lift.expect("lift");
+        assert_close(lift, 200.0 / 120.0 - 1.0, 1e-9);
+
+        let recent = (now - Duration::days(3))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        let results = build_experiment_results(
+            &experiment(ExperimentStatus::Running, Some(&recent)),
+            &counts,
+            now,
+        );
+        assert!(!results.duration_reached);
+        assert!(results.p_value.is_none());
+
+        let results = build_experiment_results(
+            &experiment(ExperimentStatus::Completed, Some(&recent)),
+            &counts,
+            now,
+        );
+        assert!(results.duration_reached);
+        assert!(results.p_value.is_some());
+    }
+}
@@ -1,6 +1,9 @@
+pub mod alerts;
 pub mod analytics;
 pub mod billing;
 pub mod config;
 pub mod error;
 pub mod event;
+pub mod experiments;
+pub mod session;
 pub mod visitor;
@@ -0,0 +1,155 @@
+//! Per-website rules deciding when an event starts a new session.
+//!
+//! The server's session cache and the storage backends both call
+//! [`SessionRules::continues`] so a visitor is split identically whichever
+//! layer resolves the session.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+/// Inactivity timeout applied when a website has no override.
+pub const DEFAULT_SESSION_TIMEOUT_MINUTES: u32 = 30;
+/// Bounds accepted for `session_timeout_minutes`.
+pub const MIN_SESSION_TIMEOUT_MINUTES: i64 = 1;
+pub const MAX_SESSION_TIMEOUT_MINUTES: i64 = 24 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SessionRules {
+    pub timeout_minutes: u32,
+    /// Start a new session when the local date changes in `timezone`.
+    pub split_at_midnight: bool,
+    /// Start a new session when an event carries a `utm_campaign` other than
+    /// the one the session started with.
+    pub split_on_campaign_change: bool,
+    pub timezone: Tz,
+}
+
+impl Default for SessionRules {
+    fn default() -> Self {
+        Self {
+            timeout_minutes: DEFAULT_SESSION_TIMEOUT_MINUTES,
+            split_at_midnight: false,
+            split_on_campaign_change: false,
+            timezone: Tz::UTC,
+        }
+    }
+}
+
+impl SessionRules {
+    /// Build rules from the stored website columns. A missing or out-of-range
+    /// timeout falls back to the default, and an unknown timezone to UTC.
+    pub fn from_website(
+        timeout_minutes: Option<i64>,
+        split_at_midnight: bool,
+        split_on_campaign_change: bool,
+        timezone: &str,
+    ) -> Self {
+        let timeout_minutes = timeout_minutes
+            .filter(|m| (MIN_SESSION_TIMEOUT_MINUTES..=MAX_SESSION_TIMEOUT_MINUTES).contains(m))
+            .map_or(DEFAULT_SESSION_TIMEOUT_MINUTES, |m| m as u32);
+        Self {
+            timeout_minutes,
+            split_at_midnight,
+            split_on_campaign_change,
+            timezone: timezone.parse().unwrap_or(Tz::UTC),
+        }
+    }
+
+    fn timeout(&self) -> chrono::Duration {
+        chrono::Duration::minutes(i64::from(self.timeout_minutes))
+    }
+
+    /// Range of `last_seen` values an event at `at` can continue, exclusive
+    /// at both ends. Backdated events may land before a live session's
+    /// `last_seen`, so the window extends both ways.
+    pub fn continuation_window(&self, at: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+        (at - self.timeout(), at + self.timeout())
+    }
+
+    /// Whether an event at `at` with `campaign` belongs to a session last seen
+    /// at `last_seen` that started with `session_campaign`. `at` may precede
+    /// `last_seen` for backdated events.
+    ///
+    /// Events without a campaign never split on campaign, so internal
+    /// navigation after a campaign landing stays in the same session.
+    pub fn continues(
+        &self,
+        last_seen: DateTime<Utc>,
+        session_campaign: Option<&str>,
+        at: DateTime<Utc>,
+        campaign: Option<&str>,
+    ) -> bool {
+        if (at - last_seen).abs() >= self.timeout() {
+            return false;
+        }
+        if self.split_at_midnight
+            && last_seen.with_timezone(&self.timezone).date_naive()
+                != at.with_timezone(&self.timezone).date_naive()
+        {
+            return false;
+        }
+        if self.split_on_campaign_change {
+            if let Some(campaign) = campaign {
+                if session_campaign != Some(campaign) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(h: u32, m: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 10, h, m, 0)
+            .single()
+            .expect("valid time")
+    }
+
+    #[test]
+    fn default_rules_use_thirty_minute_timeout() {
+        let rules = SessionRules::default();
+        assert!(rules.continues(at(10, 0), None, at(10, 29), None));
+        assert!(!rules.continues(at(10, 0), None, at(10, 30), None));
+    }
+
+    #[test]
+    fn backdated_events_only_continue_nearby_sessions() {
+        let rules = SessionRules::default();
+        assert!(rules.continues(at(10, 30), None, at(10, 1), None));
+        assert!(!rules.continues(at(10, 30), None, at(10, 0), None));
+        assert!(!rules.continues(at(22, 0), None, at(9, 0), None));
+    }
+
+    #[test]
+    fn from_website_clamps_invalid_values_to_defaults() {
+        let rules = SessionRules::from_website(Some(0), false, false, "Not/AZone");
+        assert_eq!(rules.timeout_minutes, DEFAULT_SESSION_TIMEOUT_MINUTES);
+        assert_eq!(rules.timezone, Tz::UTC);
+        let rules = SessionRules::from_website(Some(120), false, false, "Europe/Warsaw");
+        assert_eq!(rules.timeout_minutes, 120);
+        assert!(rules.continues(at(10, 0), None, at(11, 59), None));
+    }
+
+    #[test]
+    fn midnight_split_uses_website_timezone() {
+        // 22:50 and 23:10 UTC straddle midnight in Warsaw (UTC+1) only.
+        let warsaw = SessionRules::from_website(None, true, false, "Europe/Warsaw");
+        assert!(!warsaw.continues(at(22, 50), None, at(23, 10), None));
+        let utc = SessionRules::from_website(None, true, false, "UTC");
+        assert!(utc.continues(at(22, 50), None, at(23, 10), None));
+    }
+
+    #[test]
+    fn campaign_split_only_on_a_different_campaign() {
+        let rules = SessionRules::from_website(None, false, true, "UTC");
+        assert!(rules.continues(at(10, 0), Some("spring"), at(10, 5), None));
+        assert!(rules.continues(at(10, 0), Some("spring"), at(10, 5), Some("spring")));
+        assert!(!rules.continues(at(10, 0), Some("spring"), at(10, 5), Some("summer")));
+        assert!(!rules.continues(at(10, 0), None, at(10, 5), Some("summer")));
+    }
+}
@@ -1,47 +1,27 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
-use chrono::{DateTime, Duration, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use serde_json::json;
 
+use sparklytics_core::alerts::{evaluate_alert_rule, AlertWindow};
 use sparklytics_core::analytics::{
-    AlertConditionType, AlertEvaluationResult, AnalyticsBackend, AnalyticsFilter, AttributionQuery,
+    AlertEvaluationResult, AnalyticsBackend, AnalyticsFilter, AttributionQuery,
     AttributionResponse, CampaignLink, ComparisonRange, CreateCampaignLinkRequest,
-    CreateFunnelRequest, CreateGoalRequest, CreateReportRequest, CreateTrackingPixelRequest,
-    EventNamesResult, EventPropertiesResult, ExportRow, Funnel, FunnelResults, FunnelSummary, Goal,
+    CreateExperimentRequest, CreateFunnelRequest, CreateGoalRequest, CreateReportRequest,
+    CreateTrackingPixelRequest, EventNamesResult, EventPropertiesResult, Experiment,
+    ExperimentResults, ExportFormat, ExportRow, Funnel, FunnelResults, FunnelSummary, Goal,
     GoalStats, JourneyQuery, JourneyResponse, LinkStatsResponse, MetricsPage, PixelStatsResponse,
     RealtimeEvent, RealtimePagination, RealtimeResult, ReportPayload, ReportType, RetentionQuery,
     RetentionResponse, RevenueSummary, SavedReport, SavedReportSummary, SessionDetailResponse,
     SessionsQuery, SessionsResponse, StatsResult, TimeseriesResult, TrackingPixel,
-    UpdateCampaignLinkRequest, UpdateFunnelRequest, UpdateGoalRequest, UpdateReportRequest,
-    UpdateTrackingPixelRequest,
+    UpdateCampaignLinkRequest, UpdateExperimentRequest, UpdateFunnelRequest, UpdateGoalRequest,
+    UpdateReportRequest, UpdateTrackingPixelRequest,
 };
 use sparklytics_core::event::Event;
 
 use crate::DuckDbBackend;
 
-fn mean(values: &[f64]) -> Option<f64> {
-    if values.is_empty() {
-        return None;
-    }
-    Some(values.iter().sum::<f64>() / values.len() as f64)
-}
-
-fn stddev(values: &[f64], mean_value: f64) -> Option<f64> {
-    if values.len() < 2 {
-        return None;
-    }
-    let variance = values
-        .iter()
-        .map(|v| {
-            let diff = *v - mean_value;
-            diff * diff
-        })
-        .sum::<f64>()
-        / values.len() as f64;
-    Some(variance.sqrt())
-}
-
 fn map_realtime_event(event: crate::queries::realtime::RealtimeEvent) -> RealtimeEvent {
     RealtimeEvent {
         event_type: event.event_type,
@@ -88,7 +68,7 @@ impl AnalyticsBackend for DuckDbBackend {
         url: &str,
     ) -> anyhow::Result<String> {
         use chrono::Utc;
-        self.get_or_create_session_at(website_id, visitor_id, url, Utc::now())
+        self.get_or_create_session_at(website_id, visitor_id, url, None, Utc::now())
             .await
     }
 
@@ -98,9 +78,10 @@ impl AnalyticsBackend for DuckDbBackend {
         visitor_id: &str,
         _referrer_domain: Option<&str>,
         url: &str,
+        utm_campaign: Option<&str>,
         now: DateTime<Utc>,
     ) -> anyhow::Result<String> {
-        self.get_or_create_session_at(website_id, visitor_id, url, now)
+        self.get_or_create_session_at(website_id, visitor_id, url, utm_campaign, now)
             .await
     }
 
@@ -215,6 +196,19 @@ impl AnalyticsBackend for DuckDbBackend {
         Ok(rows.into_iter().map(map_export_row).collect())
     }
 
+    async fn export_events_to_file(
+        &self,
+        website_id: &str,
+        _tenant_id: Option<&str>,
+        start: NaiveDate,
+        end: NaiveDate,
+        format: ExportFormat,
+        path: &std::path::Path,
+    ) -> anyhow::Result<u64> {
+        self.export_events_to_file_raw(website_id, start, end, format, path)
+            .await
+    }
+
     async fn get_event_names(
         &self,
         website_id: &str,
@@ -350,59 +344,18 @@ impl AnalyticsBackend for DuckDbBackend {
             return Ok(vec![]);
         }
 
-        let today = Utc::now().date_naive();
+        let now = Utc::now();
         let mut results = Vec::with_capacity(active_rules.len());
         for rule in active_rules {
-            let baseline_start = today - Duration::days(rule.lookback_days);
-            let series = self
-                .get_daily_alert_metric_series(website_id, &rule.metric, baseline_start, today)
-                .await?;
-            let daily_map: HashMap<NaiveDate, f64> = series.into_iter().collect();
-            let current_value = daily_map.get(&today).copied().unwrap_or(0.0);
-
-            let (triggered, baseline_mean, baseline_stddev, z_score) = match rule.condition_type {
-                AlertConditionType::ThresholdAbove => {
-                    (current_value >= rule.threshold_value, None, None, None)
-                }
-                AlertConditionType::ThresholdBelow => {
-                    (current_value <= rule.threshold_value, None, None, None)
-                }
-                AlertConditionType::Spike | AlertConditionType::Drop => {
-                    let mut baseline = Vec::with_capacity(rule.lookback_days as usize);
-                    for offset in 1..=rule.lookback_days {
-                        let day = today - Duration::days(offset);
-                        baseline.push(daily_map.get(&day).copied().unwrap_or(0.0));
-                    }
-                    let Some(baseline_mean) = mean(&baseline) else {
-                        continue;
-                    };
-                    let baseline_stddev = stddev(&baseline, baseline_mean).unwrap_or(0.0);
-                    if baseline_stddev <= f64::EPSILON {
-                        continue;
-                    }
-                    let z = (current_value - baseline_mean) / baseline_stddev;
-                    let triggered = match rule.condition_type {
-                        AlertConditionType::Spike => z >= rule.threshold_value,
-                        AlertConditionType::Drop => z <= -rule.threshold_value,
-                        _ => false,
-                    };
-                    (
-                        triggered,
-                        Some(baseline_mean),
-                        Some(baseline_stddev),
-                        Some(z),
-                    )
-                }
-            };
-
-            results.push(AlertEvaluationResult {
-                alert_id: rule.id,
-                triggered,
-                metric_value: current_value,
-                baseline_mean,
-                baseline_stddev,
-                z_score,
-            });
+            let window = AlertWindow::for_rule(&rule, now);
+            let series: HashMap<NaiveDateTime, f64> = self
+                .get_alert_metric_series(&rule, &window)
+                .await?
+                .into_iter()
+                .collect();
+            if let Some(result) = evaluate_alert_rule(&rule, &series, &window) {
+                results.push(result);
+            }
         }
 
         Ok(results)
@@ -533,9 +486,80 @@ impl AnalyticsBackend for DuckDbBackend {
         _tenant_id: Option<&str>,
         funnel_id: &str,
         filter: &AnalyticsFilter,
+        breakdown: Option<&str>,
     ) -> anyhow::Result<FunnelResults> {
         crate::queries::funnel_results::get_funnel_results_inner(
-            self, website_id, funnel_id, filter,
+            self, website_id, funnel_id, filter, breakdown,
+        )
+        .await
+    }
+
+    async fn list_experiments(
+        &self,
+        website_id: &str,
+        _tenant_id: Option<&str>,
+    ) -> anyhow::Result<Vec<Experiment>> {
+        crate::queries::experiments::list_experiments_inner(self, website_id).await
+    }
+
+    async fn get_experiment(
+        &self,
+        website_id: &str,
+        _tenant_id: Option<&str>,
+        experiment_id: &str,
+    ) -> anyhow::Result<Option<Experiment>> {
+        crate::queries::experiments::get_experiment_inner(self, website_id, experiment_id).await
+    }
+
+    async fn create_experiment(
+        &self,
+        website_id: &str,
+        _tenant_id: Option<&str>,
+        req: CreateExperimentRequest,
+    ) -> anyhow::Result<Experiment> {
+        crate::queries::experiments::create_experiment_inner(self, website_id, req).await
+    }
+
+    async fn update_experiment(
+        &self,
+        website_id: &str,
+        _tenant_id: Option<&str>,
+        experiment_id: &str,
+        req: UpdateExperimentRequest,
+    ) -> anyhow::Result<Option<Experiment>> {
+        crate::queries::experiments::update_experiment_inner(self, website_id, experiment_id, req)
+            .await
+    }
+
+    async fn delete_experiment(
+        &self,
+        website_id: &str,
+        _tenant_id: Option<&str>,
+        experiment_id: &str,
+    ) -> anyhow::Result<bool> {
+        crate::queries::experiments::delete_experiment_inner(self, website_id, experiment_id).await
+    }
+
+    async fn count_running_experiments(
+        &self,
+        website_id: &str,
+        _tenant_id: Option<&str>,
+    ) -> anyhow::Result<i64> {
+        crate::queries::experiments::count_running_experiments_inner(self, website_id).await
+    }
+
+    async fn get_experiment_results(
+        &self,
+        website_id: &str,
+        _tenant_id: Option<&str>,
+        experiment_id: &str,
+        include_bots: bool,
+    ) -> anyhow::Result<ExperimentResults> {
+        crate::queries::experiments::get_experiment_results_inner(
+            self,
+            website_id,
+            experiment_id,
+            include_bots,
         )
         .await
     }
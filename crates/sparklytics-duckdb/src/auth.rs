@@ -1,21 +1,46 @@
 use anyhow::Result;
-pub use sparklytics_metadata::ApiKeyRecord;
+pub use sparklytics_metadata::{ApiKeyAccess, ApiKeyRecord, ApiKeyScope};
 
 use crate::backend::rand_hex;
 use crate::DuckDbBackend;
 
 #[cfg(test)]
-use std::sync::{
-    atomic::{AtomicUsize, Ordering},
-    Mutex,
-};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[cfg(test)]
 static AUTH_WRITE_FAIL_AFTER: AtomicUsize = AtomicUsize::new(usize::MAX);
 #[cfg(test)]
 static AUTH_WRITE_COUNT: AtomicUsize = AtomicUsize::new(0);
 #[cfg(test)]
-static AUTH_WRITE_TEST_LOCK: Mutex<()> = Mutex::new(());
+static AUTH_WRITE_TEST_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+const API_KEY_COLUMNS: &str = "id, name, key_prefix, \
+     CAST(created_at AS VARCHAR), \
+     CAST(last_used_at AS VARCHAR), \
+     CAST(revoked_at AS VARCHAR), \
+     access, website_ids, CAST(expires_at AS VARCHAR)";
+
+fn map_api_key_row(row: &duckdb::Row<'_>) -> duckdb::Result<ApiKeyRecord> {
+    let access: Option<String> = row.get(6)?;
+    let website_ids: Option<String> = row.get(7)?;
+    Ok(ApiKeyRecord {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        key_prefix: row.get(2)?,
+        created_at: row.get(3)?,
+        last_used_at: row.get(4)?,
+        revoked_at: row.get(5)?,
+        scope: ApiKeyScope {
+            // Unreadable scopes fail closed: read-only, no websites.
+            access: access
+                .as_deref()
+                .map_or(Some(ApiKeyAccess::Write), ApiKeyAccess::parse)
+                .unwrap_or(ApiKeyAccess::Read),
+            website_ids: website_ids.map(|ids| serde_json::from_str(&ids).unwrap_or_default()),
+            expires_at: row.get(8)?,
+        },
+    })
+}
 
 impl DuckDbBackend {
     fn get_setting_tx(tx: &duckdb::Transaction<'_>, key: &str) -> Result<Option<String>> {
@@ -120,27 +145,18 @@ impl DuckDbBackend {
         Ok(result.is_some())
     }
 
-    /// Look up an API key by its hash. Returns None if not found or revoked.
+    /// Look up an API key by its hash. Returns None if not found, revoked or
+    /// expired.
     pub async fn lookup_api_key(&self, key_hash: &str) -> Result<Option<ApiKeyRecord>> {
         let conn = self.conn.lock().await;
+        let sql = format!(
+            "SELECT {API_KEY_COLUMNS} FROM local_api_keys \
+             WHERE key_hash = ?1 AND revoked_at IS NULL \
+             AND (expires_at IS NULL OR expires_at > CAST(NOW() AS TIMESTAMP))"
+        );
         let result = conn
-            .prepare(
-                "SELECT id, name, key_prefix, \
-                 CAST(created_at AS VARCHAR), \
-                 CAST(last_used_at AS VARCHAR), \
-                 CAST(revoked_at AS VARCHAR) \
-                 FROM local_api_keys WHERE key_hash = ?1 AND revoked_at IS NULL",
-            )?
-            .query_row(duckdb::params![key_hash], |row| {
-                Ok(ApiKeyRecord {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    key_prefix: row.get(2)?,
-                    created_at: row.get(3)?,
-                    last_used_at: row.get(4)?,
-                    revoked_at: row.get(5)?,
-                })
-            })
+            .prepare(&sql)?
+            .query_row(duckdb::params![key_hash], map_api_key_row)
             .ok();
         Ok(result)
     }
@@ -162,12 +178,27 @@ impl DuckDbBackend {
         name: &str,
         hash: &str,
         prefix: &str,
+        scope: &ApiKeyScope,
     ) -> Result<()> {
+        let website_ids = scope
+            .website_ids
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
         let conn = self.conn.lock().await;
         conn.execute(
-            "INSERT INTO local_api_keys (id, name, key_hash, key_prefix, created_at) \
-             VALUES (?1, ?2, ?3, ?4, CURRENT_TIMESTAMP)",
-            duckdb::params![id, name, hash, prefix],
+            "INSERT INTO local_api_keys \
+             (id, name, key_hash, key_prefix, created_at, access, website_ids, expires_at) \
+             VALUES (?1, ?2, ?3, ?4, CURRENT_TIMESTAMP, ?5, ?6, CAST(?7 AS TIMESTAMP))",
+            duckdb::params![
+                id,
+                name,
+                hash,
+                prefix,
+                scope.access.as_str(),
+                website_ids,
+                scope.expires_at
+            ],
         )?;
         Ok(())
     }
@@ -191,23 +222,12 @@ impl DuckDbBackend {
             .prepare("SELECT COUNT(*) FROM local_api_keys")?
             .query_row([], |row| row.get(0))?;
 
-        let mut stmt = conn.prepare(
-            "SELECT id, name, key_prefix, \
-             CAST(created_at AS VARCHAR), \
-             CAST(last_used_at AS VARCHAR), \
-             CAST(revoked_at AS VARCHAR) \
-             FROM local_api_keys ORDER BY created_at DESC LIMIT ?1 OFFSET ?2",
-        )?;
-        let rows = stmt.query_map(duckdb::params![limit, offset], |row| {
-            Ok(ApiKeyRecord {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                key_prefix: row.get(2)?,
-                created_at: row.get(3)?,
-                last_used_at: row.get(4)?,
-                revoked_at: row.get(5)?,
-            })
-        })?;
+        let sql = format!(
+            "SELECT {API_KEY_COLUMNS} FROM local_api_keys \
+             ORDER BY created_at DESC LIMIT ?1 OFFSET ?2"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(duckdb::params![limit, offset], map_api_key_row)?;
 
         let mut keys = Vec::new();
         for row in rows {
@@ -273,7 +293,7 @@ mod tests {
 
     #[tokio::test]
     async fn complete_admin_setup_rolls_back_on_write_failure() {
-        let _guard = AUTH_WRITE_TEST_LOCK.lock().expect("test lock");
+        let _guard = AUTH_WRITE_TEST_LOCK.lock().await;
         let db = DuckDbBackend::open_in_memory().expect("db");
         DuckDbBackend::inject_auth_write_failure_after(1);
 
@@ -297,7 +317,7 @@ mod tests {
 
     #[tokio::test]
     async fn complete_password_change_rolls_back_on_write_failure() {
-        let _guard = AUTH_WRITE_TEST_LOCK.lock().expect("test lock");
+        let _guard = AUTH_WRITE_TEST_LOCK.lock().await;
         let db = DuckDbBackend::open_in_memory().expect("db");
         db.set_setting("admin_password_hash", "old_hash")
             .await
@@ -334,7 +354,7 @@ mod tests {
 
     #[tokio::test]
     async fn complete_admin_setup_refuses_to_overwrite_existing_admin() {
-        let _guard = AUTH_WRITE_TEST_LOCK.lock().expect("test lock");
+        let _guard = AUTH_WRITE_TEST_LOCK.lock().await;
         let db = DuckDbBackend::open_in_memory().expect("db");
         db.set_setting("admin_password_hash", "existing_hash")
             .await
@@ -1,14 +1,19 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
-use anyhow::Result;
-use chrono::{DateTime, Utc};
-use duckdb::Connection;
-use tokio::sync::Mutex;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use duckdb::{Connection, InterruptHandle};
+use tokio::sync::{Mutex, OwnedMutexGuard};
 use tracing::info;
 
 use sparklytics_core::event::Event;
 
-use crate::schema::{init_sql, MIGRATIONS_TABLE_SQL};
+use crate::migrations::{self, MIGRATIONS};
+use crate::rollups::invalidate_rollups_from;
+use crate::schema::init_sql;
 
 /// Generate a cryptographically random hex string of `n` bytes (2n hex chars).
 pub(crate) fn rand_hex(n: usize) -> String {
@@ -18,12 +23,117 @@ pub(crate) fn rand_hex(n: usize) -> String {
     hex::encode(buf)
 }
 
+/// Insert `events` on `conn` (normally an open transaction) and reopen any
+/// rolled-up days they land in. `import_id` marks rows written by a
+/// historical import.
+pub(crate) fn insert_event_rows(
+    conn: &Connection,
+    events: &[Event],
+    import_id: Option<&str>,
+) -> Result<()> {
+    let mut stmt = conn.prepare(
+        r#"INSERT INTO events (
+                id, website_id, tenant_id, session_id, visitor_id,
+                event_type, url, referrer_url, referrer_domain,
+                event_name, event_data,
+                country, region, city,
+                browser, browser_version, os, os_version, device_type,
+                screen, language,
+                utm_source, utm_medium, utm_campaign, utm_term, utm_content,
+                link_id, pixel_id, source_ip, user_agent, is_bot, bot_score, bot_reason,
+                created_at, import_id
+            ) VALUES (
+                ?1,  ?2,  ?3,  ?4,  ?5,
+                ?6,  ?7,  ?8,  ?9,
+                ?10, ?11,
+                ?12, ?13, ?14,
+                ?15, ?16, ?17, ?18, ?19,
+                ?20, ?21,
+                ?22, ?23, ?24, ?25, ?26,
+                ?27, ?28, ?29, ?30, ?31, ?32, ?33,
+                ?34, ?35
+            )"#,
+    )?;
+
+    for event in events {
+        stmt.execute(duckdb::params![
+            event.id,
+            event.website_id,
+            event.tenant_id,
+            event.session_id,
+            event.visitor_id,
+            event.event_type,
+            event.url,
+            event.referrer_url,
+            event.referrer_domain,
+            event.event_name,
+            event.event_data,
+            event.country,
+            event.region,
+            event.city,
+            event.browser,
+            event.browser_version,
+            event.os,
+            event.os_version,
+            event.device_type,
+            event.screen,
+            event.language,
+            event.utm_source,
+            event.utm_medium,
+            event.utm_campaign,
+            event.utm_term,
+            event.utm_content,
+            event.link_id,
+            event.pixel_id,
+            event.source_ip,
+            event.user_agent,
+            event.is_bot,
+            event.bot_score,
+            event.bot_reason,
+            event.created_at.to_rfc3339(),
+            import_id,
+        ])?;
+    }
+    drop(stmt);
+
+    // Late events for closed days (delayed flushes, backfills) reopen
+    // those days so the next rollup refresh picks them up.
+    let today = Utc::now().date_naive();
+    let mut earliest_closed: HashMap<&str, NaiveDate> = HashMap::new();
+    for event in events {
+        let day = event.created_at.date_naive();
+        if day < today {
+            earliest_closed
+                .entry(event.website_id.as_str())
+                .and_modify(|earliest| *earliest = (*earliest).min(day))
+                .or_insert(day);
+        }
+    }
+    for (website_id, day) in earliest_closed {
+        invalidate_rollups_from(conn, website_id, day)?;
+    }
+    Ok(())
+}
+
+/// Number of cloned connections reserved for analytics reads.
+pub const READ_POOL_SIZE: usize = 4;
+
+/// Default per-query timeout for [`DuckDbBackend::run_read`] callers that have
+/// no tighter budget of their own.
+pub const DEFAULT_READ_QUERY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Error marker returned when a read query is interrupted by its timeout.
+pub const QUERY_TIMEOUT_MARKER: &str = "query_timeout";
+
 /// A DuckDB backend for Sparklytics.
 ///
 /// DuckDB is single-writer: concurrent reads are fine, but concurrent writes
-/// cause contention. We wrap the connection in `Arc<Mutex<_>>` so the async
-/// runtime serialises all writes through the buffer-flush task while still
-/// allowing the struct to be cheaply cloned and shared across Axum handlers.
+/// cause contention. The writer connection is wrapped in `Arc<Mutex<_>>` so the
+/// async runtime serialises all writes (event flushes, session updates, CRUD)
+/// while still allowing the struct to be cheaply cloned and shared across Axum
+/// handlers. Analytics reads go through a small pool of connections cloned
+/// from the writer, so a slow journey or retention query no longer blocks
+/// ingestion or other dashboards.
 ///
 /// Memory and thread limits are enforced by [`init_sql`] at open time.
 /// The memory limit is configurable via `SPARKLYTICS_DUCKDB_MEMORY`
@@ -33,6 +143,26 @@ pub(crate) fn rand_hex(n: usize) -> String {
 #[derive(Clone)]
 pub struct DuckDbBackend {
     pub(crate) conn: Arc<Mutex<Connection>>,
+    pub(crate) readers: Arc<[Arc<Mutex<Connection>>]>,
+    next_reader: Arc<AtomicUsize>,
+}
+
+/// Interrupts the query running on a pooled connection if the future waiting
+/// for it is dropped (client disconnect) before the query finished.
+struct InterruptOnDrop(Option<Arc<InterruptHandle>>);
+
+impl InterruptOnDrop {
+    fn disarm(&mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for InterruptOnDrop {
+    fn drop(&mut self) {
+        if let Some(handle) = self.0.take() {
+            handle.interrupt();
+        }
+    }
 }
 
 impl DuckDbBackend {
@@ -40,21 +170,21 @@ impl DuckDbBackend {
     ///
     /// `memory_limit` is a DuckDB size string such as `"1GB"` or `"512MB"`.
     /// It is read from `Config.duckdb_memory_limit` at the call site.
-    /// Runs [`MIGRATIONS_TABLE_SQL`] then the schema init SQL on the connection
-    /// so all tables and indexes are created if they do not already exist.
+    /// Applies the connection settings, then any pending [`MIGRATIONS`].
+    /// Fails when the file was migrated by a newer release.
     pub fn open(path: &str, memory_limit: &str) -> Result<Self> {
-        let conn = Connection::open(path)?;
-        conn.execute_batch(MIGRATIONS_TABLE_SQL)?;
+        let mut conn = Connection::open(path)?;
         conn.execute_batch(&init_sql(memory_limit))?;
+        for id in migrations::run(&mut conn, MIGRATIONS)? {
+            info!("Applied schema migration {}", id);
+        }
         // Seed settings (daily_salt, install_id, etc.) if this is a fresh database.
         Self::seed_settings_sync(&conn)?;
         info!(
-            "DuckDB opened at {} with memory_limit={}, threads=2",
-            path, memory_limit
+            "DuckDB opened at {} with memory_limit={}, threads=2, read_pool={}",
+            path, memory_limit, READ_POOL_SIZE
         );
-        Ok(Self {
-            conn: Arc::new(Mutex::new(conn)),
-        })
+        Self::with_read_pool(conn)
     }
 
     /// Open an **in-memory** DuckDB database.
@@ -62,15 +192,66 @@ impl DuckDbBackend {
     /// Intended for unit tests only — data is discarded when the struct is
     /// dropped. Uses a 1GB memory limit (tests are not memory-constrained).
     pub fn open_in_memory() -> Result<Self> {
-        let conn = Connection::open_in_memory()?;
-        conn.execute_batch(MIGRATIONS_TABLE_SQL)?;
+        let mut conn = Connection::open_in_memory()?;
         conn.execute_batch(&init_sql("1GB"))?;
+        migrations::run(&mut conn, MIGRATIONS)?;
         Self::seed_settings_sync(&conn)?;
+        Self::with_read_pool(conn)
+    }
+
+    /// Wrap the writer connection and clone [`READ_POOL_SIZE`] read
+    /// connections to the same database from it.
+    fn with_read_pool(conn: Connection) -> Result<Self> {
+        let readers = (0..READ_POOL_SIZE)
+            .map(|_| Ok(Arc::new(Mutex::new(conn.try_clone()?))))
+            .collect::<Result<Vec<_>>>()?;
         Ok(Self {
             conn: Arc::new(Mutex::new(conn)),
+            readers: readers.into(),
+            next_reader: Arc::new(AtomicUsize::new(0)),
         })
     }
 
+    /// Check out a pooled read connection.
+    ///
+    /// Prefers an idle connection; when all are busy, queues on them in
+    /// round-robin order. Never use the returned connection for writes.
+    pub(crate) async fn reader(&self) -> OwnedMutexGuard<Connection> {
+        for reader in self.readers.iter() {
+            if let Ok(guard) = Arc::clone(reader).try_lock_owned() {
+                return guard;
+            }
+        }
+        let idx = self.next_reader.fetch_add(1, Ordering::Relaxed) % self.readers.len();
+        Arc::clone(&self.readers[idx]).lock_owned().await
+    }
+
+    /// Run a read-only query on a pooled connection off the async runtime.
+    ///
+    /// The query is interrupted when `timeout` elapses (returning an error
+    /// containing [`QUERY_TIMEOUT_MARKER`]) or when the returned future is
+    /// dropped, e.g. because the HTTP client disconnected.
+    pub(crate) async fn run_read<T, F>(&self, timeout: Duration, query: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> Result<T> + Send + 'static,
+    {
+        let conn = self.reader().await;
+        let interrupt = conn.interrupt_handle();
+        let mut cancel = InterruptOnDrop(Some(Arc::clone(&interrupt)));
+        let task = tokio::task::spawn_blocking(move || query(&conn));
+
+        let result = tokio::time::timeout(timeout, task).await;
+        cancel.disarm();
+        match result {
+            Ok(joined) => joined.map_err(|e| anyhow!("read query task failed: {e}"))?,
+            Err(_) => {
+                interrupt.interrupt();
+                Err(anyhow!(QUERY_TIMEOUT_MARKER))
+            }
+        }
+    }
+
     /// Seed the `settings` table with initial values if they don't already exist.
     ///
     /// Uses `INSERT OR IGNORE` so re-runs on every startup are safe.
@@ -154,69 +335,7 @@ impl DuckDbBackend {
         // throughput (one fsync instead of N).
         let tx = conn.transaction()?;
 
-        let mut stmt = tx.prepare(
-            r#"INSERT INTO events (
-                    id, website_id, tenant_id, session_id, visitor_id,
-                    event_type, url, referrer_url, referrer_domain,
-                    event_name, event_data,
-                    country, region, city,
-                    browser, browser_version, os, os_version, device_type,
-                    screen, language,
-                    utm_source, utm_medium, utm_campaign, utm_term, utm_content,
-                    link_id, pixel_id, source_ip, user_agent, is_bot, bot_score, bot_reason,
-                    created_at
-                ) VALUES (
-                    ?1,  ?2,  ?3,  ?4,  ?5,
-                    ?6,  ?7,  ?8,  ?9,
-                    ?10, ?11,
-                    ?12, ?13, ?14,
-                    ?15, ?16, ?17, ?18, ?19,
-                    ?20, ?21,
-                    ?22, ?23, ?24, ?25, ?26,
-                    ?27, ?28, ?29, ?30, ?31, ?32, ?33,
-                    ?34
-                )"#,
-        )?;
-
-        for event in events {
-            stmt.execute(duckdb::params![
-                event.id,
-                event.website_id,
-                event.tenant_id,
-                event.session_id,
-                event.visitor_id,
-                event.event_type,
-                event.url,
-                event.referrer_url,
-                event.referrer_domain,
-                event.event_name,
-                event.event_data,
-                event.country,
-                event.region,
-                event.city,
-                event.browser,
-                event.browser_version,
-                event.os,
-                event.os_version,
-                event.device_type,
-                event.screen,
-                event.language,
-                event.utm_source,
-                event.utm_medium,
-                event.utm_campaign,
-                event.utm_term,
-                event.utm_content,
-                event.link_id,
-                event.pixel_id,
-                event.source_ip,
-                event.user_agent,
-                event.is_bot,
-                event.bot_score,
-                event.bot_reason,
-                event.created_at.to_rfc3339(),
-            ])?;
-        }
-        drop(stmt);
+        insert_event_rows(&tx, events, None)?;
 
         tx.commit()?;
         tracing::debug!("Inserted {} events into DuckDB", events.len());
@@ -253,17 +372,26 @@ impl DuckDbBackend {
         self.conn.lock().await
     }
 
-    /// Resolve the active session for a visitor or create a new one at `now`.
+    /// Resolve the active session for a visitor or create a new one at `now`,
+    /// applying the website's session rules.
     pub async fn get_or_create_session_at(
         &self,
         website_id: &str,
         visitor_id: &str,
         url: &str,
+        utm_campaign: Option<&str>,
         now: DateTime<Utc>,
     ) -> Result<String> {
-        crate::session::get_or_create_session_inner(self, visitor_id, website_id, url, now)
-            .await
-            .map(|r| r.session_id)
+        crate::session::get_or_create_session_inner(
+            self,
+            visitor_id,
+            website_id,
+            url,
+            utm_campaign,
+            now,
+        )
+        .await
+        .map(|r| r.session_id)
     }
 
     /// Increment pageview_count for an existing session by `additional_pageviews`.
@@ -313,3 +441,88 @@ impl DuckDbBackend {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DuckDbBackend, QUERY_TIMEOUT_MARKER, READ_POOL_SIZE};
+
+    const SLOW_QUERY: &str = "SELECT COUNT(*) FROM range(1000000000) a, range(1000000000) b";
+
+    #[tokio::test]
+    async fn run_read_interrupts_query_after_timeout() {
+        let db = DuckDbBackend::open_in_memory().expect("in-memory DuckDB");
+        let error = db
+            .run_read(Duration::from_millis(100), |conn| {
+                let count: i64 = conn.query_row(SLOW_QUERY, [], |row| row.get(0))?;
+                Ok(count)
+            })
+            .await
+            .expect_err("slow query should time out");
+        assert_eq!(error.to_string(), QUERY_TIMEOUT_MARKER);
+
+        let one = db
+            .run_read(Duration::from_secs(5), |conn| {
+                let one: i64 = conn.query_row("SELECT 1", [], |row| row.get(0))?;
+                Ok(one)
+            })
+            .await
+            .expect("pool still usable");
+        assert_eq!(one, 1);
+    }
+
+    #[tokio::test]
+    async fn dropping_run_read_future_interrupts_query() {
+        let db = DuckDbBackend::open_in_memory().expect("in-memory DuckDB");
+        let task = {
+            let db = db.clone();
+            tokio::spawn(async move {
+                db.run_read(Duration::from_secs(600), |conn| {
+                    let count: i64 = conn.query_row(SLOW_QUERY, [], |row| row.get(0))?;
+                    Ok(count)
+                })
+                .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        task.abort();
+
+        // Every pooled connection comes back once the interrupted query stops.
+        let all_readers = tokio::time::timeout(Duration::from_secs(10), async {
+            let mut guards = Vec::new();
+            for _ in 0..READ_POOL_SIZE {
+                guards.push(db.reader().await);
+            }
+            guards
+        })
+        .await
+        .expect("readers released");
+        assert_eq!(all_readers.len(), READ_POOL_SIZE);
+    }
+
+    #[tokio::test]
+    async fn reads_proceed_while_writer_is_held() {
+        let db = DuckDbBackend::open_in_memory().expect("in-memory DuckDB");
+        db.conn_for_test()
+            .await
+            .execute_batch(
+                "CREATE TABLE pool_probe (id INTEGER); INSERT INTO pool_probe VALUES (7);",
+            )
+            .expect("seed table");
+
+        let _writer = db.conn_for_test().await;
+        let value = tokio::time::timeout(
+            Duration::from_secs(5),
+            db.run_read(Duration::from_secs(5), |conn| {
+                let value: i64 =
+                    conn.query_row("SELECT id FROM pool_probe", [], |row| row.get(0))?;
+                Ok(value)
+            }),
+        )
+        .await
+        .expect("read not blocked by writer")
+        .expect("read succeeds");
+        assert_eq!(value, 7);
+    }
+}
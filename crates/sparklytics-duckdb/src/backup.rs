@@ -0,0 +1,165 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::{anyhow, bail, Context, Result};
+use duckdb::{AccessMode, Config, Connection};
+use tokio::sync::OwnedMutexGuard;
+
+use crate::migrations::SCHEMA_VERSION;
+use crate::DuckDbBackend;
+
+/// Numbers the catalog each snapshot attaches its target under, since
+/// attached databases are shared by every connection.
+static NEXT_SNAPSHOT: AtomicUsize = AtomicUsize::new(0);
+
+/// A database snapshot pinned by [`DuckDbBackend::begin_snapshot`] that has
+/// not been written out yet. Dropping it discards the snapshot.
+pub struct PendingSnapshot {
+    conn: Option<OwnedMutexGuard<Connection>>,
+    dest: PathBuf,
+    catalog: String,
+    source_catalog: String,
+}
+
+impl DuckDbBackend {
+    /// Write a consistent copy of the database to the new file `dest` and
+    /// return its size in bytes.
+    pub async fn snapshot_to(&self, dest: &Path) -> Result<u64> {
+        self.begin_snapshot(dest).await?.write().await
+    }
+
+    /// Pin the current state of the database for a copy to the new file
+    /// `dest`. Writes committed after this returns are not part of the copy.
+    ///
+    /// Runs on one pooled read connection: `dest` is attached and a read
+    /// transaction is opened, so [`PendingSnapshot::write`] copies a single
+    /// MVCC snapshot while the writer and the other readers keep serving
+    /// ingest and queries.
+    pub async fn begin_snapshot(&self, dest: &Path) -> Result<PendingSnapshot> {
+        if dest.exists() {
+            bail!("{} already exists", dest.display());
+        }
+        let target = dest
+            .to_str()
+            .ok_or_else(|| anyhow!("snapshot path is not valid UTF-8"))?
+            .replace('\'', "''");
+
+        let catalog = format!(
+            "sparklytics_snapshot_{}",
+            NEXT_SNAPSHOT.fetch_add(1, Ordering::Relaxed)
+        );
+
+        let conn = self.reader().await;
+        let dest = dest.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            let source: Option<String> = conn.query_row(
+                "SELECT path FROM duckdb_databases() WHERE database_name = current_database()",
+                [],
+                |row| row.get(0),
+            )?;
+            if source.is_none() {
+                bail!("in-memory databases cannot be backed up");
+            }
+            let source_catalog: String =
+                conn.query_row("SELECT current_database()", [], |row| row.get(0))?;
+
+            // ATTACH does not accept bound parameters for the target.
+            conn.execute_batch(&format!("ATTACH '{target}' AS {catalog}"))?;
+            let mut snapshot = PendingSnapshot {
+                conn: Some(conn),
+                dest,
+                catalog,
+                source_catalog,
+            };
+            // The transaction's snapshot is taken when it first reads.
+            snapshot
+                .connection()
+                .execute_batch("BEGIN TRANSACTION; SELECT COUNT(*) FROM _migrations;")
+                .context("failed to pin the database snapshot")?;
+            Ok(snapshot)
+        })
+        .await
+        .map_err(|e| anyhow!("snapshot task failed: {e}"))?
+    }
+}
+
+impl PendingSnapshot {
+    fn connection(&mut self) -> &Connection {
+        self.conn.as_deref().expect("snapshot connection")
+    }
+
+    /// Copy the pinned snapshot into the destination file and return its
+    /// size in bytes.
+    pub async fn write(mut self) -> Result<u64> {
+        tokio::task::spawn_blocking(move || {
+            let copy = format!(
+                "COPY FROM DATABASE \"{}\" TO {}; COMMIT;",
+                self.source_catalog.replace('"', "\"\""),
+                self.catalog
+            );
+            let copied = self.connection().execute_batch(&copy);
+            if copied.is_err() {
+                let _ = self.connection().execute_batch("ROLLBACK");
+            }
+            let detach = format!("DETACH {}", self.catalog);
+            let detached = self.connection().execute_batch(&detach);
+            self.conn = None;
+            copied.context("failed to copy the database")?;
+            detached?;
+            Ok(std::fs::metadata(&self.dest)?.len())
+        })
+        .await
+        .map_err(|e| anyhow!("snapshot task failed: {e}"))?
+    }
+}
+
+impl Drop for PendingSnapshot {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            let _ = conn.execute_batch(&format!("ROLLBACK; DETACH {}", self.catalog));
+        }
+    }
+}
+
+/// Latest schema version recorded in `_migrations` of the database file at
+/// `path`, or `None` when the file has no `_migrations` table.
+///
+/// Opens the file read-only, which fails while another process (such as a
+/// running server) holds it.
+pub fn read_schema_version(path: &Path) -> Result<Option<String>> {
+    let config = Config::default().access_mode(AccessMode::ReadOnly)?;
+    let conn = Connection::open_with_flags(path, config)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    let has_table: bool = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM information_schema.tables WHERE table_name = '_migrations'",
+        [],
+        |row| row.get(0),
+    )?;
+    if !has_table {
+        return Ok(None);
+    }
+    let version: Option<String> =
+        conn.query_row("SELECT max(id) FROM _migrations", [], |row| row.get(0))?;
+    Ok(version)
+}
+
+/// Check that the database file at `path` is a Sparklytics database this
+/// binary can open, returning its schema version.
+///
+/// Older versions are accepted; [`DuckDbBackend::open`] brings them up to
+/// date. Files written by a newer binary are rejected.
+pub fn validate_snapshot(path: &Path) -> Result<String> {
+    let Some(version) = read_schema_version(path)? else {
+        bail!(
+            "{} is not a Sparklytics database: it has no _migrations table",
+            path.display()
+        );
+    };
+    if version.as_str() > SCHEMA_VERSION {
+        bail!(
+            "{} has schema version {version}, newer than this binary ({SCHEMA_VERSION}); upgrade Sparklytics before restoring it",
+            path.display()
+        );
+    }
+    Ok(version)
+}
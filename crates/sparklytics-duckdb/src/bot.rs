@@ -11,6 +11,7 @@ use sparklytics_core::analytics::{
     BotReportTopUserAgent, BotSummary, CreateBotListEntryRequest, UpdateBotPolicyRequest,
 };
 
+use crate::rollups::invalidate_rollups_from;
 use crate::DuckDbBackend;
 
 const DEFAULT_POLICY_MODE: BotPolicyMode = BotPolicyMode::Balanced;
@@ -924,6 +925,9 @@ impl DuckDbBackend {
                AND s.session_id = agg.session_id",
             duckdb::params![website_id, start, end],
         )?;
+        // Reclassified events change the bot split of any rolled-up day in
+        // the window.
+        invalidate_rollups_from(&conn, website_id, start_date.date_naive())?;
         Ok(())
     }
 
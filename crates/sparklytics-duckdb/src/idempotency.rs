@@ -0,0 +1,48 @@
+use anyhow::Result;
+
+use crate::DuckDbBackend;
+
+/// How long a claimed server-ingest idempotency key blocks duplicates.
+pub const IDEMPOTENCY_KEY_TTL_DAYS: i64 = 7;
+
+impl DuckDbBackend {
+    /// Claim idempotency keys for `website_id`, returning one flag per key:
+    /// `true` when the key was newly claimed, `false` when it was already
+    /// claimed (including earlier in the same slice).
+    pub async fn claim_idempotency_keys(
+        &self,
+        website_id: &str,
+        keys: &[String],
+    ) -> Result<Vec<bool>> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        let mut claimed = Vec::with_capacity(keys.len());
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO ingest_idempotency_keys (website_id, idempotency_key, created_at)
+                 VALUES (?1, ?2, CURRENT_TIMESTAMP)
+                 ON CONFLICT DO NOTHING",
+            )?;
+            for key in keys {
+                claimed.push(stmt.execute(duckdb::params![website_id, key])? > 0);
+            }
+        }
+        tx.commit()?;
+        Ok(claimed)
+    }
+
+    /// Release keys whose events were not accepted so a retry can reuse them.
+    pub async fn release_idempotency_keys(&self, website_id: &str, keys: &[String]) -> Result<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "DELETE FROM ingest_idempotency_keys WHERE website_id = ?1 AND idempotency_key = ?2",
+        )?;
+        for key in keys {
+            stmt.execute(duckdb::params![website_id, key])?;
+        }
+        Ok(())
+    }
+}
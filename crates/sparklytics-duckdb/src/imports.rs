@@ -0,0 +1,186 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use duckdb::Connection;
+use serde::Serialize;
+
+use sparklytics_core::event::Event;
+
+use crate::backend::insert_event_rows;
+use crate::rollups::invalidate_rollups_from;
+use crate::DuckDbBackend;
+
+/// One historical import run as stored in the `imports` table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportRecord {
+    pub id: String,
+    pub website_id: String,
+    pub source: String,
+    pub file_name: Option<String>,
+    /// `completed` or `rolled_back`.
+    pub status: String,
+    pub event_count: i64,
+    pub session_count: i64,
+    pub skipped_count: i64,
+    pub first_event_at: Option<String>,
+    pub last_event_at: Option<String>,
+    pub created_at: String,
+    pub rolled_back_at: Option<String>,
+}
+
+/// Events mapped from an export, ready to be written by
+/// [`DuckDbBackend::import_events`].
+#[derive(Debug, Clone)]
+pub struct NewImport<'a> {
+    pub id: &'a str,
+    pub website_id: &'a str,
+    pub source: &'a str,
+    pub file_name: Option<&'a str>,
+    /// Every event must belong to `website_id` and carry its synthesised
+    /// `session_id`.
+    pub events: &'a [Event],
+    pub skipped_count: i64,
+}
+
+const IMPORT_COLUMNS: &str =
+    "id, website_id, source, file_name, status, event_count, session_count, skipped_count, \
+     CAST(first_event_at AS VARCHAR), CAST(last_event_at AS VARCHAR), \
+     CAST(created_at AS VARCHAR), CAST(rolled_back_at AS VARCHAR)";
+
+fn map_import_row(row: &duckdb::Row<'_>) -> duckdb::Result<ImportRecord> {
+    Ok(ImportRecord {
+        id: row.get(0)?,
+        website_id: row.get(1)?,
+        source: row.get(2)?,
+        file_name: row.get(3)?,
+        status: row.get(4)?,
+        event_count: row.get(5)?,
+        session_count: row.get(6)?,
+        skipped_count: row.get(7)?,
+        first_event_at: row.get(8)?,
+        last_event_at: row.get(9)?,
+        created_at: row.get(10)?,
+        rolled_back_at: row.get(11)?,
+    })
+}
+
+fn load_import(
+    conn: &Connection,
+    website_id: &str,
+    import_id: &str,
+) -> Result<Option<ImportRecord>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {IMPORT_COLUMNS} FROM imports WHERE website_id = ?1 AND id = ?2"
+    ))?;
+    let mut rows = stmt.query_map(duckdb::params![website_id, import_id], map_import_row)?;
+    Ok(rows.next().transpose()?)
+}
+
+impl DuckDbBackend {
+    /// Write an import in one transaction: its `imports` row, the events
+    /// tagged with the import id, and one session per distinct `session_id`
+    /// derived from those events.
+    pub async fn import_events(&self, import: &NewImport<'_>) -> Result<ImportRecord> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+
+        tx.execute(
+            "INSERT INTO imports (id, website_id, source, file_name, skipped_count, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, CURRENT_TIMESTAMP)",
+            duckdb::params![
+                import.id,
+                import.website_id,
+                import.source,
+                import.file_name,
+                import.skipped_count
+            ],
+        )?;
+        insert_event_rows(&tx, import.events, Some(import.id))?;
+        let session_count = tx.execute(
+            "INSERT INTO sessions
+                 (session_id, website_id, tenant_id, visitor_id, first_seen, last_seen,
+                  pageview_count, entry_page, is_bot, bot_score, utm_campaign, import_id)
+             SELECT session_id, website_id, ANY_VALUE(tenant_id), ANY_VALUE(visitor_id),
+                    MIN(created_at), MAX(created_at), COUNT(*), arg_min(url, created_at),
+                    BOOL_OR(is_bot), MAX(bot_score), arg_min(utm_campaign, created_at), ?2
+             FROM events
+             WHERE website_id = ?1 AND import_id = ?2
+             GROUP BY session_id, website_id
+             ON CONFLICT (session_id) DO NOTHING",
+            duckdb::params![import.website_id, import.id],
+        )?;
+        tx.execute(
+            "UPDATE imports
+             SET event_count = s.events,
+                 session_count = ?3,
+                 first_event_at = s.first_at,
+                 last_event_at = s.last_at
+             FROM (
+                 SELECT COUNT(*) AS events, MIN(created_at) AS first_at, MAX(created_at) AS last_at
+                 FROM events
+                 WHERE website_id = ?1 AND import_id = ?2
+             ) s
+             WHERE imports.id = ?2",
+            duckdb::params![import.website_id, import.id, session_count as i64],
+        )?;
+        let record = load_import(&tx, import.website_id, import.id)?.ok_or_else(|| {
+            anyhow::anyhow!("import {} vanished inside its transaction", import.id)
+        })?;
+        tx.commit()?;
+        Ok(record)
+    }
+
+    /// Imports of `website_id`, newest first.
+    pub async fn list_imports(&self, website_id: &str) -> Result<Vec<ImportRecord>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {IMPORT_COLUMNS} FROM imports
+             WHERE website_id = ?1
+             ORDER BY created_at DESC, id DESC"
+        ))?;
+        let rows = stmt.query_map(duckdb::params![website_id], map_import_row)?;
+        Ok(rows.collect::<duckdb::Result<Vec<_>>>()?)
+    }
+
+    /// Delete the events and sessions written by an import and mark it
+    /// `rolled_back`. Rolling back an import twice is a no-op.
+    ///
+    /// Returns `None` if the import does not exist for `website_id`.
+    pub async fn rollback_import(
+        &self,
+        website_id: &str,
+        import_id: &str,
+    ) -> Result<Option<ImportRecord>> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        let Some(record) = load_import(&tx, website_id, import_id)? else {
+            return Ok(None);
+        };
+        if record.status == "rolled_back" {
+            return Ok(Some(record));
+        }
+
+        for table in ["events", "sessions"] {
+            tx.execute(
+                &format!("DELETE FROM {table} WHERE website_id = ?1 AND import_id = ?2"),
+                duckdb::params![website_id, import_id],
+            )?;
+        }
+        // The import reopened rolled-up days when it was written; they change
+        // again now that its events are gone.
+        let first_day = record
+            .first_event_at
+            .as_deref()
+            .and_then(|ts| NaiveDate::parse_from_str(ts.get(..10)?, "%Y-%m-%d").ok());
+        if let Some(day) = first_day {
+            invalidate_rollups_from(&tx, website_id, day)?;
+        }
+        tx.execute(
+            "UPDATE imports SET status = 'rolled_back', rolled_back_at = CURRENT_TIMESTAMP
+             WHERE id = ?1",
+            duckdb::params![import_id],
+        )?;
+        let record = load_import(&tx, website_id, import_id)?;
+        tx.commit()?;
+        Ok(record)
+    }
+}
@@ -2,12 +2,20 @@ pub mod acquisition;
 pub mod analytics_impl;
 pub mod auth;
 pub mod backend;
+pub mod backup;
 pub mod bot;
+pub mod data_retention;
+pub mod idempotency;
+pub mod imports;
+pub mod migrations;
 pub mod notifications;
 pub mod queries;
+pub mod rollups;
 pub mod schema;
 pub mod session;
 pub mod share;
+pub mod two_factor;
+pub mod users;
 pub mod website;
 
 pub use backend::DuckDbBackend;
@@ -0,0 +1,584 @@
+//! Ordered, checksummed schema migrations.
+//!
+//! Every schema change is a numbered [`Migration`] appended to
+//! [`MIGRATIONS`]. [`run`] applies the ones a database has not seen yet, each
+//! in its own transaction, and records its id and a sha256 of its SQL in
+//! `_migrations`. A database that has applied a migration this binary does
+//! not know was written by a newer release and is refused rather than
+//! silently downgraded.
+
+use anyhow::{anyhow, bail, Result};
+use duckdb::Connection;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::schema::{BASELINE_SCHEMA_SQL, MIGRATIONS_TABLE_SQL};
+
+/// One schema change. Ids sort in application order.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    pub id: &'static str,
+    pub description: &'static str,
+    pub up: &'static str,
+    /// SQL that undoes `up`, or `None` when the migration is irreversible.
+    pub down: Option<&'static str>,
+}
+
+impl Migration {
+    /// Hex sha256 of the up SQL, stored in `_migrations.checksum`.
+    pub fn checksum(&self) -> String {
+        hex::encode(Sha256::digest(self.up.as_bytes()))
+    }
+}
+
+/// Every migration this binary knows, oldest first. Append only: applied
+/// migrations are checksummed, so editing one breaks existing databases.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        id: "0001_initial",
+        description: "Baseline schema",
+        up: BASELINE_SCHEMA_SQL,
+        down: None,
+    },
+    Migration {
+        id: "0002_users",
+        description: "User accounts, website grants and invites",
+        up: USERS_UP,
+        down: Some(USERS_DOWN),
+    },
+    Migration {
+        id: "0003_api_key_scopes",
+        description: "API key access level, website scope and expiry",
+        up: API_KEY_SCOPES_UP,
+        down: Some(API_KEY_SCOPES_DOWN),
+    },
+    Migration {
+        id: "0004_user_identities",
+        description: "Single sign-on identities linked to user accounts",
+        up: USER_IDENTITIES_UP,
+        down: Some(USER_IDENTITIES_DOWN),
+    },
+    Migration {
+        id: "0005_totp_factors",
+        description: "TOTP second factors and recovery codes",
+        up: TOTP_FACTORS_UP,
+        down: Some(TOTP_FACTORS_DOWN),
+    },
+    Migration {
+        id: "0006_webhook_retries",
+        description: "Webhook signing secrets and notification delivery retries",
+        up: WEBHOOK_RETRIES_UP,
+        down: Some(WEBHOOK_RETRIES_DOWN),
+    },
+    Migration {
+        id: "0007_alert_baselines",
+        description: "Seasonal baselines for alert rules",
+        up: ALERT_BASELINES_UP,
+        down: Some(ALERT_BASELINES_DOWN),
+    },
+    Migration {
+        id: "0008_alert_scopes",
+        description: "Alert filters, hourly rules, cooldowns and resolve state",
+        up: ALERT_SCOPES_UP,
+        down: Some(ALERT_SCOPES_DOWN),
+    },
+    Migration {
+        id: "0009_rollup_dimension_counts",
+        description: "Pre-aggregated dimension rollups with visitor sketches",
+        up: ROLLUP_DIMENSION_COUNTS_UP,
+        down: Some(ROLLUP_DIMENSION_COUNTS_DOWN),
+    },
+    Migration {
+        id: "0010_alert_firing_episodes",
+        description: "Per-rule firing episode counter for alert notification keys",
+        up: ALERT_FIRING_EPISODES_UP,
+        down: Some(ALERT_FIRING_EPISODES_DOWN),
+    },
+    Migration {
+        id: "0011_rollup_exact_dimensions",
+        description: "Exact dimension rollups with visitor lists and cross-day sessions",
+        up: ROLLUP_EXACT_DIMENSIONS_UP,
+        down: Some(ROLLUP_EXACT_DIMENSIONS_DOWN),
+    },
+];
+
+const USERS_UP: &str = r#"
+-- Self-hosted user accounts. The account created by POST /api/auth/setup
+-- stays in settings.admin_password_hash and is not a row here.
+CREATE TABLE users (
+    id              VARCHAR PRIMARY KEY,           -- 'usr_' + 21 random chars
+    email           VARCHAR NOT NULL UNIQUE,       -- lowercased
+    name            VARCHAR,
+    password_hash   VARCHAR NOT NULL,              -- argon2id
+    role            VARCHAR NOT NULL,              -- 'owner' | 'admin' | 'viewer'
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_login_at   TIMESTAMP,
+    disabled_at     TIMESTAMP                      -- NULL = active
+);
+
+-- Websites a viewer may read. Admins and owners can access every website.
+CREATE TABLE website_grants (
+    user_id     VARCHAR NOT NULL,
+    website_id  VARCHAR NOT NULL,
+    created_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX idx_website_grants_user ON website_grants(user_id);
+
+CREATE TABLE user_invites (
+    id           VARCHAR PRIMARY KEY,              -- 'inv_' + 21 random chars
+    email        VARCHAR NOT NULL,                 -- lowercased
+    role         VARCHAR NOT NULL,
+    website_ids  VARCHAR NOT NULL DEFAULT '[]',    -- JSON array of website ids to grant
+    token_hash   VARCHAR(64) NOT NULL UNIQUE,      -- sha256(token); never stored raw
+    invited_by   VARCHAR,                          -- user id of the inviter
+    created_at   TIMESTAMP NOT NULL,
+    expires_at   TIMESTAMP NOT NULL,
+    accepted_at  TIMESTAMP,
+    revoked_at   TIMESTAMP
+);
+"#;
+
+const USERS_DOWN: &str = r#"
+DROP TABLE user_invites;
+DROP TABLE website_grants;
+DROP TABLE users;
+"#;
+
+const API_KEY_SCOPES_UP: &str = r#"
+-- Existing keys keep full write access to every website.
+ALTER TABLE local_api_keys ADD COLUMN access VARCHAR DEFAULT 'write';  -- 'read' | 'write'
+ALTER TABLE local_api_keys ADD COLUMN website_ids VARCHAR;             -- JSON array; NULL = every website
+ALTER TABLE local_api_keys ADD COLUMN expires_at TIMESTAMP;            -- NULL = never expires
+"#;
+
+const API_KEY_SCOPES_DOWN: &str = r#"
+-- DuckDB cannot drop columns from a table that has indexes.
+DROP INDEX idx_local_api_keys_hash;
+ALTER TABLE local_api_keys DROP COLUMN expires_at;
+ALTER TABLE local_api_keys DROP COLUMN website_ids;
+ALTER TABLE local_api_keys DROP COLUMN access;
+CREATE INDEX idx_local_api_keys_hash ON local_api_keys(key_hash);
+"#;
+
+const USER_IDENTITIES_UP: &str = r#"
+-- Accounts signed in through OIDC. Their users.password_hash is '', which
+-- never verifies, so they cannot use password login.
+CREATE TABLE user_identities (
+    issuer      VARCHAR NOT NULL,
+    subject     VARCHAR NOT NULL,                  -- the provider's `sub` claim
+    user_id     VARCHAR NOT NULL,
+    created_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (issuer, subject)
+);
+CREATE INDEX idx_user_identities_user ON user_identities(user_id);
+"#;
+
+const USER_IDENTITIES_DOWN: &str = r#"
+DROP TABLE user_identities;
+"#;
+
+const TOTP_FACTORS_UP: &str = r#"
+-- account_id is 'admin' for the setup admin, otherwise a users.id.
+CREATE TABLE totp_factors (
+    account_id      VARCHAR PRIMARY KEY,
+    secret          VARCHAR NOT NULL,              -- base32
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    confirmed_at    TIMESTAMP,                     -- NULL = enrollment not verified yet
+    last_used_step  BIGINT                         -- unix time / 30 of the last accepted code
+);
+
+CREATE TABLE totp_recovery_codes (
+    account_id  VARCHAR NOT NULL,
+    code_hash   VARCHAR NOT NULL,                  -- sha256 hex
+    created_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    used_at     TIMESTAMP,
+    PRIMARY KEY (account_id, code_hash)
+);
+"#;
+
+const TOTP_FACTORS_DOWN: &str = r#"
+DROP TABLE totp_recovery_codes;
+DROP TABLE totp_factors;
+"#;
+
+const WEBHOOK_RETRIES_UP: &str = r#"
+-- Key for the X-Sparklytics-Signature HMAC on webhook deliveries.
+ALTER TABLE report_subscriptions ADD COLUMN signing_secret VARCHAR;
+ALTER TABLE alert_rules ADD COLUMN signing_secret VARCHAR;
+UPDATE report_subscriptions
+SET signing_secret = 'whsec_' || replace(CAST(gen_random_uuid() AS VARCHAR), '-', '');
+UPDATE alert_rules
+SET signing_secret = 'whsec_' || replace(CAST(gen_random_uuid() AS VARCHAR), '-', '');
+
+-- What was sent, so failed deliveries can be retried and replayed.
+ALTER TABLE notification_deliveries ADD COLUMN channel VARCHAR;
+ALTER TABLE notification_deliveries ADD COLUMN target VARCHAR;
+ALTER TABLE notification_deliveries ADD COLUMN payload VARCHAR;           -- JSON
+ALTER TABLE notification_deliveries ADD COLUMN attempt_count INTEGER DEFAULT 1;
+ALTER TABLE notification_deliveries ADD COLUMN next_attempt_at TIMESTAMP; -- set while status = 'retrying'
+"#;
+
+const WEBHOOK_RETRIES_DOWN: &str = r#"
+UPDATE notification_deliveries SET status = 'failed' WHERE status = 'retrying';
+-- DuckDB cannot drop columns from a table that has indexes.
+DROP INDEX idx_notification_deliveries_source;
+DROP INDEX idx_alert_rules_website;
+DROP INDEX idx_report_subscriptions_website;
+DROP INDEX idx_report_subscriptions_due;
+ALTER TABLE notification_deliveries DROP COLUMN next_attempt_at;
+ALTER TABLE notification_deliveries DROP COLUMN attempt_count;
+ALTER TABLE notification_deliveries DROP COLUMN payload;
+ALTER TABLE notification_deliveries DROP COLUMN target;
+ALTER TABLE notification_deliveries DROP COLUMN channel;
+ALTER TABLE alert_rules DROP COLUMN signing_secret;
+ALTER TABLE report_subscriptions DROP COLUMN signing_secret;
+CREATE INDEX idx_notification_deliveries_source
+    ON notification_deliveries(source_type, source_id, delivered_at DESC);
+CREATE INDEX idx_alert_rules_website ON alert_rules(website_id);
+CREATE INDEX idx_report_subscriptions_website ON report_subscriptions(website_id);
+CREATE INDEX idx_report_subscriptions_due ON report_subscriptions(is_active, next_run_at);
+"#;
+
+const ALERT_BASELINES_UP: &str = r#"
+ALTER TABLE alert_rules ADD COLUMN baseline VARCHAR DEFAULT 'flat'; -- 'flat' | 'same_weekday' | 'holt_winters'
+"#;
+
+const ALERT_BASELINES_DOWN: &str = r#"
+-- Older binaries know neither anomaly rules nor seasonal baselines; keep the
+-- rules but switch them off.
+UPDATE alert_rules SET condition_type = 'spike', is_active = FALSE
+WHERE condition_type = 'anomaly';
+UPDATE alert_rules SET is_active = FALSE WHERE baseline <> 'flat';
+-- DuckDB cannot drop columns from a table that has indexes.
+DROP INDEX idx_alert_rules_website;
+ALTER TABLE alert_rules DROP COLUMN baseline;
+CREATE INDEX idx_alert_rules_website ON alert_rules(website_id);
+"#;
+
+const ALERT_SCOPES_UP: &str = r#"
+ALTER TABLE alert_rules ADD COLUMN event_name VARCHAR;                -- custom_events metric only
+ALTER TABLE alert_rules ADD COLUMN filters VARCHAR;                   -- JSON AlertFilters
+ALTER TABLE alert_rules ADD COLUMN granularity VARCHAR DEFAULT 'day'; -- 'day' | 'hour'
+ALTER TABLE alert_rules ADD COLUMN cooldown_minutes INTEGER DEFAULT 60;
+ALTER TABLE alert_rules ADD COLUMN is_firing BOOLEAN DEFAULT FALSE;
+ALTER TABLE alert_rules ADD COLUMN last_triggered_at TIMESTAMP;
+"#;
+
+const ALERT_SCOPES_DOWN: &str = r#"
+-- Older binaries cannot evaluate these rules; keep them but switch them off.
+UPDATE alert_rules SET metric = 'pageviews', is_active = FALSE
+WHERE metric IN ('custom_events', 'bounce_rate', 'session_duration', 'bot_share');
+UPDATE alert_rules SET is_active = FALSE
+WHERE granularity <> 'day' OR (filters IS NOT NULL AND filters <> '{}');
+-- DuckDB cannot drop columns from a table that has indexes.
+DROP INDEX idx_alert_rules_website;
+ALTER TABLE alert_rules DROP COLUMN last_triggered_at;
+ALTER TABLE alert_rules DROP COLUMN is_firing;
+ALTER TABLE alert_rules DROP COLUMN cooldown_minutes;
+ALTER TABLE alert_rules DROP COLUMN granularity;
+ALTER TABLE alert_rules DROP COLUMN filters;
+ALTER TABLE alert_rules DROP COLUMN event_name;
+CREATE INDEX idx_alert_rules_website ON alert_rules(website_id);
+"#;
+
+const ROLLUP_DIMENSION_COUNTS_UP: &str = r#"
+-- One row per day, dimension value and bot flag instead of one per session.
+-- Rollups are derived data: clearing rollup_state makes the next refresh
+-- rebuild every website from its first event.
+DROP TABLE rollup_daily_dimensions;
+CREATE TABLE rollup_daily_dimensions (
+    website_id        VARCHAR NOT NULL,
+    day               DATE NOT NULL,
+    dimension         VARCHAR NOT NULL,          -- metrics type, e.g. 'page', 'referrer'
+    value             VARCHAR NOT NULL,
+    is_bot            BOOLEAN NOT NULL DEFAULT FALSE,
+    pageviews         BIGINT NOT NULL,           -- event_type = 'pageview' only
+    sessions          BIGINT NOT NULL,           -- sessions with this value on this day
+    bounced_sessions  BIGINT NOT NULL,           -- of which with at most one pageview
+    duration_seconds  BIGINT NOT NULL,           -- summed over sessions lasting > 0s
+    timed_sessions    BIGINT NOT NULL,           -- sessions lasting > 0s
+    visitor_hashes    UBIGINT[] NOT NULL         -- smallest md5_number_upper(visitor_id) values, ascending
+);
+CREATE INDEX idx_rollup_daily_dimensions_website_dim_day
+    ON rollup_daily_dimensions(website_id, dimension, day);
+DELETE FROM rollup_daily_sessions;
+DELETE FROM rollup_state;
+"#;
+
+const ROLLUP_DIMENSION_COUNTS_DOWN: &str = r#"
+DROP TABLE rollup_daily_dimensions;
+CREATE TABLE rollup_daily_dimensions (
+    website_id   VARCHAR NOT NULL,
+    day          DATE NOT NULL,
+    dimension    VARCHAR NOT NULL,
+    value        VARCHAR NOT NULL,
+    session_id   VARCHAR NOT NULL,
+    visitor_id   VARCHAR NOT NULL,
+    is_bot       BOOLEAN NOT NULL DEFAULT FALSE,
+    pageviews    BIGINT NOT NULL,
+    first_at     TIMESTAMP NOT NULL,
+    last_at      TIMESTAMP NOT NULL
+);
+CREATE INDEX idx_rollup_daily_dimensions_website_dim_day
+    ON rollup_daily_dimensions(website_id, dimension, day);
+DELETE FROM rollup_daily_sessions;
+DELETE FROM rollup_state;
+"#;
+
+const ALERT_FIRING_EPISODES_UP: &str = r#"
+ALTER TABLE alert_rules ADD COLUMN firing_episode BIGINT DEFAULT 0; -- bumped each time a rule starts firing
+"#;
+
+const ALERT_FIRING_EPISODES_DOWN: &str = r#"
+-- DuckDB cannot drop columns from a table that has indexes.
+DROP INDEX idx_alert_rules_website;
+ALTER TABLE alert_rules DROP COLUMN firing_episode;
+CREATE INDEX idx_alert_rules_website ON alert_rules(website_id);
+"#;
+
+const ROLLUP_EXACT_DIMENSIONS_UP: &str = r#"
+-- Visitor lists replace the sketches so distinct counts stay exact, and
+-- sessions that cross a day boundary keep session grain so queries count
+-- each of them once.
+DROP TABLE rollup_daily_dimensions;
+CREATE TABLE rollup_daily_dimensions (
+    website_id        VARCHAR NOT NULL,
+    day               DATE NOT NULL,
+    dimension         VARCHAR NOT NULL,          -- metrics type, e.g. 'page', 'referrer'
+    value             VARCHAR NOT NULL,
+    is_bot            BOOLEAN NOT NULL DEFAULT FALSE,
+    pageviews         BIGINT NOT NULL,           -- event_type = 'pageview' only
+    sessions          BIGINT NOT NULL,           -- sessions contained in this day
+    bounced_sessions  BIGINT NOT NULL,           -- of which with at most one pageview
+    duration_seconds  BIGINT NOT NULL,           -- summed over sessions lasting > 0s
+    timed_sessions    BIGINT NOT NULL,           -- sessions lasting > 0s
+    visitor_ids       VARCHAR[] NOT NULL         -- every visitor with this value on this day
+);
+CREATE INDEX idx_rollup_daily_dimensions_website_dim_day
+    ON rollup_daily_dimensions(website_id, dimension, day);
+CREATE TABLE rollup_daily_dimension_sessions (
+    website_id   VARCHAR NOT NULL,
+    day          DATE NOT NULL,
+    dimension    VARCHAR NOT NULL,
+    value        VARCHAR NOT NULL,
+    session_id   VARCHAR NOT NULL,
+    visitor_id   VARCHAR NOT NULL,
+    is_bot       BOOLEAN NOT NULL DEFAULT FALSE,
+    pageviews    BIGINT NOT NULL,
+    first_at     TIMESTAMP NOT NULL,
+    last_at      TIMESTAMP NOT NULL
+);
+CREATE INDEX idx_rollup_daily_dimension_sessions_website_dim_day
+    ON rollup_daily_dimension_sessions(website_id, dimension, day);
+DELETE FROM rollup_daily_sessions;
+DELETE FROM rollup_state;
+"#;
+
+const ROLLUP_EXACT_DIMENSIONS_DOWN: &str = r#"
+DROP TABLE rollup_daily_dimension_sessions;
+DROP TABLE rollup_daily_dimensions;
+CREATE TABLE rollup_daily_dimensions (
+    website_id        VARCHAR NOT NULL,
+    day               DATE NOT NULL,
+    dimension         VARCHAR NOT NULL,
+    value             VARCHAR NOT NULL,
+    is_bot            BOOLEAN NOT NULL DEFAULT FALSE,
+    pageviews         BIGINT NOT NULL,
+    sessions          BIGINT NOT NULL,
+    bounced_sessions  BIGINT NOT NULL,
+    duration_seconds  BIGINT NOT NULL,
+    timed_sessions    BIGINT NOT NULL,
+    visitor_hashes    UBIGINT[] NOT NULL
+);
+CREATE INDEX idx_rollup_daily_dimensions_website_dim_day
+    ON rollup_daily_dimensions(website_id, dimension, day);
+DELETE FROM rollup_daily_sessions;
+DELETE FROM rollup_state;
+"#;
+
+/// Id of the newest migration, i.e. the schema version this binary writes.
+pub const SCHEMA_VERSION: &str = MIGRATIONS[MIGRATIONS.len() - 1].id;
+
+/// A migration as reported by [`status`].
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationStatus {
+    pub id: String,
+    pub description: String,
+    /// When it was applied; `None` while pending.
+    pub applied_at: Option<String>,
+    pub reversible: bool,
+    /// `false` when the recorded checksum differs from this binary's SQL.
+    pub checksum_matches: bool,
+}
+
+/// Schema state of a database relative to this binary.
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaStatus {
+    /// Newest applied migration, or `None` for an empty database.
+    pub current_version: Option<String>,
+    pub latest_version: String,
+    pub migrations: Vec<MigrationStatus>,
+    /// Applied migrations this binary does not know, i.e. written by a newer
+    /// release.
+    pub unknown: Vec<String>,
+}
+
+struct AppliedRow {
+    id: String,
+    applied_at: String,
+    checksum: Option<String>,
+}
+
+fn applied_rows(conn: &Connection) -> Result<Vec<AppliedRow>> {
+    let mut stmt = conn
+        .prepare("SELECT id, CAST(applied_at AS VARCHAR), checksum FROM _migrations ORDER BY id")?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(AppliedRow {
+                id: row.get(0)?,
+                applied_at: row.get(1)?,
+                checksum: row.get(2)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// Report which of `migrations` the database has applied.
+///
+/// Creates `_migrations` if it is missing, so it works on a fresh file.
+pub fn status(conn: &Connection, migrations: &[Migration]) -> Result<SchemaStatus> {
+    conn.execute_batch(MIGRATIONS_TABLE_SQL)?;
+    let applied = applied_rows(conn)?;
+    let report = migrations
+        .iter()
+        .map(|migration| {
+            let row = applied.iter().find(|row| row.id == migration.id);
+            MigrationStatus {
+                id: migration.id.to_string(),
+                description: migration.description.to_string(),
+                applied_at: row.map(|row| row.applied_at.clone()),
+                reversible: migration.down.is_some(),
+                checksum_matches: row
+                    .and_then(|row| row.checksum.as_deref())
+                    .is_none_or(|checksum| checksum == migration.checksum()),
+            }
+        })
+        .collect();
+    Ok(SchemaStatus {
+        current_version: applied.last().map(|row| row.id.clone()),
+        latest_version: migrations
+            .last()
+            .map(|m| m.id.to_string())
+            .unwrap_or_default(),
+        migrations: report,
+        unknown: applied
+            .iter()
+            .filter(|row| !migrations.iter().any(|m| m.id == row.id))
+            .map(|row| row.id.clone())
+            .collect(),
+    })
+}
+
+/// Fail unless every applied migration is known and unchanged.
+fn verify(status: &SchemaStatus) -> Result<()> {
+    if let Some(newest) = status.unknown.last() {
+        bail!(
+            "database schema version {newest} is newer than this binary ({}); upgrade Sparklytics or restore a backup taken by this version",
+            status.latest_version
+        );
+    }
+    if let Some(changed) = status.migrations.iter().find(|m| !m.checksum_matches) {
+        bail!(
+            "migration {} was changed after it was applied to this database",
+            changed.id
+        );
+    }
+    Ok(())
+}
+
+/// Migrations from `migrations` that `run` would apply, in order.
+pub fn pending<'a>(conn: &Connection, migrations: &'a [Migration]) -> Result<Vec<&'a Migration>> {
+    let status = status(conn, migrations)?;
+    verify(&status)?;
+    Ok(migrations
+        .iter()
+        .zip(&status.migrations)
+        .filter(|(_, s)| s.applied_at.is_none())
+        .map(|(m, _)| m)
+        .collect())
+}
+
+/// Apply pending migrations and return their ids.
+///
+/// Each migration runs in its own transaction together with its
+/// `_migrations` row. Rows recorded before checksums existed get theirs
+/// filled in.
+pub fn run(conn: &mut Connection, migrations: &[Migration]) -> Result<Vec<String>> {
+    let pending = pending(conn, migrations)?;
+    for migration in migrations {
+        conn.execute(
+            "UPDATE _migrations SET checksum = ?1 WHERE id = ?2 AND checksum IS NULL",
+            duckdb::params![migration.checksum(), migration.id],
+        )?;
+    }
+
+    let mut applied = Vec::with_capacity(pending.len());
+    for migration in pending {
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration.up)
+            .map_err(|e| anyhow!("migration {} failed: {e}", migration.id))?;
+        tx.execute(
+            "INSERT INTO _migrations (id, checksum) VALUES (?1, ?2)",
+            duckdb::params![migration.id, migration.checksum()],
+        )?;
+        tx.commit()?;
+        applied.push(migration.id.to_string());
+    }
+    Ok(applied)
+}
+
+/// Undo applied migrations newer than `target`, newest first, and return
+/// their ids.
+///
+/// Refuses before changing anything if one of them has no down SQL.
+pub fn rollback_to(
+    conn: &mut Connection,
+    migrations: &[Migration],
+    target: &str,
+) -> Result<Vec<String>> {
+    let status = status(conn, migrations)?;
+    verify(&status)?;
+    if !status
+        .migrations
+        .iter()
+        .any(|m| m.id == target && m.applied_at.is_some())
+    {
+        bail!("migration {target} is not applied to this database");
+    }
+
+    let to_undo: Vec<&Migration> = migrations
+        .iter()
+        .zip(&status.migrations)
+        .filter(|(m, s)| m.id > target && s.applied_at.is_some())
+        .map(|(m, _)| m)
+        .rev()
+        .collect();
+    if let Some(irreversible) = to_undo.iter().find(|m| m.down.is_none()) {
+        bail!("migration {} cannot be rolled back", irreversible.id);
+    }
+
+    let mut undone = Vec::with_capacity(to_undo.len());
+    for migration in to_undo {
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration.down.unwrap_or_default())
+            .map_err(|e| anyhow!("rolling back migration {} failed: {e}", migration.id))?;
+        tx.execute(
+            "DELETE FROM _migrations WHERE id = ?1",
+            duckdb::params![migration.id],
+        )?;
+        tx.commit()?;
+        undone.push(migration.id.to_string());
+    }
+    Ok(undone)
+}
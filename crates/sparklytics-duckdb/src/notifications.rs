@@ -1,13 +1,18 @@
 use anyhow::{anyhow, Result};
-use chrono::{DateTime, Duration, Months, NaiveDate, Utc};
+use chrono::{DateTime, Duration, Months, NaiveDateTime, Timelike, Utc};
 use rand::Rng;
 use sparklytics_core::analytics::{
-    AlertConditionType, AlertMetric, AlertRule, CreateAlertRuleRequest,
-    CreateReportSubscriptionRequest, NotificationChannel, NotificationDelivery,
-    NotificationDeliveryStatus, NotificationSourceType, ReportSubscription, SubscriptionSchedule,
-    UpdateAlertRuleRequest, UpdateReportSubscriptionRequest,
+    AlertBaseline, AlertConditionType, AlertFilters, AlertGranularity, AlertMetric, AlertRule,
+    CreateAlertRuleRequest, CreateReportSubscriptionRequest, NotificationChannel,
+    NotificationDelivery, NotificationDeliveryStatus, NotificationSourceType, ReportSubscription,
+    SubscriptionSchedule, UpdateAlertRuleRequest, UpdateReportSubscriptionRequest,
 };
 
+use sparklytics_core::alerts::{AlertWindow, MAX_COOLDOWN_MINUTES, MAX_LOOKBACK_DAYS};
+
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
+use crate::queries::bot_filters::append_session_bot_filter;
+use crate::queries::event_filters::append_event_filters;
 use crate::DuckDbBackend;
 
 fn random_alnum(len: usize) -> String {
@@ -36,6 +41,10 @@ fn generate_delivery_id() -> String {
     format!("ntf_{}", random_alnum(21))
 }
 
+fn generate_signing_secret() -> String {
+    format!("whsec_{}", random_alnum(32))
+}
+
 fn schedule_to_str(schedule: &SubscriptionSchedule) -> &'static str {
     match schedule {
         SubscriptionSchedule::Daily => "daily",
@@ -57,6 +66,9 @@ fn channel_to_str(channel: &NotificationChannel) -> &'static str {
     match channel {
         NotificationChannel::Email => "email",
         NotificationChannel::Webhook => "webhook",
+        NotificationChannel::Slack => "slack",
+        NotificationChannel::Teams => "teams",
+        NotificationChannel::Discord => "discord",
     }
 }
 
@@ -64,6 +76,9 @@ fn channel_from_str(raw: &str) -> Result<NotificationChannel> {
     match raw {
         "email" => Ok(NotificationChannel::Email),
         "webhook" => Ok(NotificationChannel::Webhook),
+        "slack" => Ok(NotificationChannel::Slack),
+        "teams" => Ok(NotificationChannel::Teams),
+        "discord" => Ok(NotificationChannel::Discord),
         _ => Err(anyhow!("invalid channel: {raw}")),
     }
 }
@@ -74,6 +89,10 @@ fn alert_metric_to_str(metric: &AlertMetric) -> &'static str {
         AlertMetric::Visitors => "visitors",
         AlertMetric::Conversions => "conversions",
         AlertMetric::ConversionRate => "conversion_rate",
+        AlertMetric::CustomEvents => "custom_events",
+        AlertMetric::BounceRate => "bounce_rate",
+        AlertMetric::SessionDuration => "session_duration",
+        AlertMetric::BotShare => "bot_share",
     }
 }
 
@@ -83,6 +102,10 @@ fn alert_metric_from_str(raw: &str) -> Result<AlertMetric> {
         "visitors" => Ok(AlertMetric::Visitors),
         "conversions" => Ok(AlertMetric::Conversions),
         "conversion_rate" => Ok(AlertMetric::ConversionRate),
+        "custom_events" => Ok(AlertMetric::CustomEvents),
+        "bounce_rate" => Ok(AlertMetric::BounceRate),
+        "session_duration" => Ok(AlertMetric::SessionDuration),
+        "bot_share" => Ok(AlertMetric::BotShare),
         _ => Err(anyhow!("invalid alert metric: {raw}")),
     }
 }
@@ -93,6 +116,7 @@ fn condition_to_str(condition: &AlertConditionType) -> &'static str {
         AlertConditionType::Drop => "drop",
         AlertConditionType::ThresholdAbove => "threshold_above",
         AlertConditionType::ThresholdBelow => "threshold_below",
+        AlertConditionType::Anomaly => "anomaly",
     }
 }
 
@@ -102,10 +126,43 @@ fn condition_from_str(raw: &str) -> Result<AlertConditionType> {
         "drop" => Ok(AlertConditionType::Drop),
         "threshold_above" => Ok(AlertConditionType::ThresholdAbove),
         "threshold_below" => Ok(AlertConditionType::ThresholdBelow),
+        "anomaly" => Ok(AlertConditionType::Anomaly),
         _ => Err(anyhow!("invalid alert condition: {raw}")),
     }
 }
 
+fn baseline_to_str(baseline: &AlertBaseline) -> &'static str {
+    match baseline {
+        AlertBaseline::Flat => "flat",
+        AlertBaseline::SameWeekday => "same_weekday",
+        AlertBaseline::HoltWinters => "holt_winters",
+    }
+}
+
+fn baseline_from_str(raw: &str) -> Result<AlertBaseline> {
+    match raw {
+        "flat" => Ok(AlertBaseline::Flat),
+        "same_weekday" => Ok(AlertBaseline::SameWeekday),
+        "holt_winters" => Ok(AlertBaseline::HoltWinters),
+        _ => Err(anyhow!("invalid alert baseline: {raw}")),
+    }
+}
+
+fn granularity_to_str(granularity: AlertGranularity) -> &'static str {
+    match granularity {
+        AlertGranularity::Day => "day",
+        AlertGranularity::Hour => "hour",
+    }
+}
+
+fn granularity_from_str(raw: &str) -> Result<AlertGranularity> {
+    match raw {
+        "day" => Ok(AlertGranularity::Day),
+        "hour" => Ok(AlertGranularity::Hour),
+        _ => Err(anyhow!("invalid alert granularity: {raw}")),
+    }
+}
+
 fn source_type_to_str(source_type: &NotificationSourceType) -> &'static str {
     match source_type {
         NotificationSourceType::Subscription => "subscription",
@@ -124,6 +181,7 @@ fn source_type_from_str(raw: &str) -> Result<NotificationSourceType> {
 fn status_to_str(status: &NotificationDeliveryStatus) -> &'static str {
     match status {
         NotificationDeliveryStatus::Sent => "sent",
+        NotificationDeliveryStatus::Retrying => "retrying",
         NotificationDeliveryStatus::Failed => "failed",
     }
 }
@@ -131,6 +189,7 @@ fn status_to_str(status: &NotificationDeliveryStatus) -> &'static str {
 fn status_from_str(raw: &str) -> Result<NotificationDeliveryStatus> {
     match raw {
         "sent" => Ok(NotificationDeliveryStatus::Sent),
+        "retrying" => Ok(NotificationDeliveryStatus::Retrying),
         "failed" => Ok(NotificationDeliveryStatus::Failed),
         _ => Err(anyhow!("invalid delivery status: {raw}")),
     }
@@ -186,6 +245,7 @@ fn map_report_subscription_row(row: &duckdb::Row<'_>) -> Result<ReportSubscripti
         timezone: row.get(4)?,
         channel,
         target: row.get(6)?,
+        signing_secret: row.get(11)?,
         is_active: row.get(7)?,
         last_run_at: row.get(8)?,
         next_run_at: row.get(9)?,
@@ -227,6 +287,38 @@ fn map_alert_rule_row(row: &duckdb::Row<'_>) -> Result<AlertRule, duckdb::Error>
             )),
         )
     })?;
+    let baseline = match row.get::<_, Option<String>>(12)? {
+        Some(raw) => baseline_from_str(&raw).map_err(|e| {
+            duckdb::Error::FromSqlConversionFailure(
+                12,
+                duckdb::types::Type::Text,
+                Box::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    e.to_string(),
+                )),
+            )
+        })?,
+        None => AlertBaseline::Flat,
+    };
+    let filters = match row.get::<_, Option<String>>(14)? {
+        Some(raw) => serde_json::from_str(&raw).map_err(|e| {
+            duckdb::Error::FromSqlConversionFailure(14, duckdb::types::Type::Text, Box::new(e))
+        })?,
+        None => AlertFilters::default(),
+    };
+    let granularity = match row.get::<_, Option<String>>(15)? {
+        Some(raw) => granularity_from_str(&raw).map_err(|e| {
+            duckdb::Error::FromSqlConversionFailure(
+                15,
+                duckdb::types::Type::Text,
+                Box::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    e.to_string(),
+                )),
+            )
+        })?,
+        None => AlertGranularity::Day,
+    };
     Ok(AlertRule {
         id: row.get(0)?,
         website_id: row.get(1)?,
@@ -235,18 +327,77 @@ fn map_alert_rule_row(row: &duckdb::Row<'_>) -> Result<AlertRule, duckdb::Error>
         condition_type,
         threshold_value: row.get(5)?,
         lookback_days: row.get(6)?,
+        baseline,
+        event_name: row.get(13)?,
+        filters,
+        granularity,
+        cooldown_minutes: row.get::<_, Option<i64>>(16)?.unwrap_or(60),
         channel,
         target: row.get(8)?,
+        signing_secret: row.get(11)?,
         is_active: row.get(9)?,
+        is_firing: row.get::<_, Option<bool>>(17)?.unwrap_or(false),
+        firing_episode: row.get::<_, Option<i64>>(19)?.unwrap_or(0),
+        last_triggered_at: row.get(18)?,
         created_at: row.get(10)?,
     })
 }
 
+/// Columns read by [`map_alert_rule_row`].
+const ALERT_RULE_COLUMNS: &str = r#"
+    id, website_id, name, metric, condition_type,
+    threshold_value, lookback_days, channel, target,
+    is_active, CAST(created_at AS VARCHAR), signing_secret, baseline,
+    event_name, filters, granularity, cooldown_minutes,
+    is_firing, CAST(last_triggered_at AS VARCHAR), firing_episode
+"#;
+
+/// `filters` column value: `NULL` when the rule watches all traffic.
+fn filters_to_json(filters: &AlertFilters) -> Result<Option<String>> {
+    if *filters == AlertFilters::default() {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::to_string(filters)?))
+}
+
+/// Give up on retries of a deleted subscription or alert; without its
+/// signing secret there is nothing to send them with.
+fn cancel_pending_retries(
+    conn: &duckdb::Connection,
+    source_type: &str,
+    source_id: &str,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE notification_deliveries
+         SET status = 'failed', next_attempt_at = NULL
+         WHERE source_type = ?1 AND source_id = ?2 AND status = 'retrying'",
+        duckdb::params![source_type, source_id],
+    )?;
+    Ok(())
+}
+
+/// Columns read by [`map_notification_delivery_row`], for a query that
+/// aliases `notification_deliveries` as `d`.
+const DELIVERY_COLUMNS: &str = r#"
+    d.id,
+    d.source_type,
+    d.source_id,
+    d.idempotency_key,
+    d.status,
+    d.error_message,
+    CAST(d.delivered_at AS VARCHAR),
+    d.channel,
+    d.attempt_count,
+    CAST(d.next_attempt_at AS VARCHAR),
+    d.target
+"#;
+
 fn map_notification_delivery_row(
     row: &duckdb::Row<'_>,
 ) -> Result<NotificationDelivery, duckdb::Error> {
     let source_type_raw: String = row.get(1)?;
     let status_raw: String = row.get(4)?;
+    let channel_raw: Option<String> = row.get(7)?;
     let source_type = source_type_from_str(&source_type_raw).map_err(|e| {
         duckdb::Error::FromSqlConversionFailure(
             1,
@@ -267,6 +418,21 @@ fn map_notification_delivery_row(
             )),
         )
     })?;
+    let channel = channel_raw
+        .as_deref()
+        .map(channel_from_str)
+        .transpose()
+        .map_err(|e| {
+            duckdb::Error::FromSqlConversionFailure(
+                7,
+                duckdb::types::Type::Text,
+                Box::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    e.to_string(),
+                )),
+            )
+        })?;
+    let attempt_count: Option<i64> = row.get(8)?;
     Ok(NotificationDelivery {
         id: row.get(0)?,
         source_type,
@@ -274,10 +440,57 @@ fn map_notification_delivery_row(
         idempotency_key: row.get(3)?,
         status,
         error_message: row.get(5)?,
+        channel,
+        target: row.get(10)?,
+        attempt_count: attempt_count.unwrap_or(1),
+        next_attempt_at: row.get(9)?,
         delivered_at: row.get(6)?,
     })
 }
 
+/// What gets sent on a notification attempt.
+#[derive(Debug, Clone)]
+pub struct NotificationSend<'a> {
+    pub channel: &'a NotificationChannel,
+    pub target: &'a str,
+    /// JSON body, stored so the delivery can be retried or replayed.
+    pub payload: &'a str,
+}
+
+/// A recorded delivery together with what is needed to send it again.
+#[derive(Debug, Clone)]
+pub struct ResendableDelivery {
+    pub delivery: NotificationDelivery,
+    pub channel: NotificationChannel,
+    pub target: String,
+    pub payload: String,
+    /// Current secret of the subscription or alert that produced it.
+    pub signing_secret: String,
+}
+
+fn map_resendable_delivery_row(
+    row: &duckdb::Row<'_>,
+) -> Result<Option<ResendableDelivery>, duckdb::Error> {
+    let delivery = map_notification_delivery_row(row)?;
+    let payload: Option<String> = row.get(11)?;
+    let signing_secret: Option<String> = row.get(12)?;
+    let (Some(channel), Some(target), Some(payload), Some(signing_secret)) = (
+        delivery.channel.clone(),
+        delivery.target.clone(),
+        payload,
+        signing_secret,
+    ) else {
+        return Ok(None);
+    };
+    Ok(Some(ResendableDelivery {
+        delivery,
+        channel,
+        target,
+        payload,
+        signing_secret,
+    }))
+}
+
 impl DuckDbBackend {
     pub async fn list_report_subscriptions(
         &self,
@@ -297,7 +510,8 @@ impl DuckDbBackend {
                 is_active,
                 CAST(last_run_at AS VARCHAR),
                 CAST(next_run_at AS VARCHAR),
-                CAST(created_at AS VARCHAR)
+                CAST(created_at AS VARCHAR),
+                signing_secret
             FROM report_subscriptions
             WHERE website_id = ?1
             ORDER BY created_at DESC, id DESC
@@ -329,7 +543,8 @@ impl DuckDbBackend {
                 is_active,
                 CAST(last_run_at AS VARCHAR),
                 CAST(next_run_at AS VARCHAR),
-                CAST(created_at AS VARCHAR)
+                CAST(created_at AS VARCHAR),
+                signing_secret
             FROM report_subscriptions
             WHERE website_id = ?1 AND id = ?2
             "#,
@@ -355,9 +570,10 @@ impl DuckDbBackend {
         conn.execute(
             r#"
             INSERT INTO report_subscriptions (
-                id, website_id, report_id, schedule, timezone, channel, target, is_active, next_run_at, created_at
+                id, website_id, report_id, schedule, timezone, channel, target, is_active, next_run_at, created_at,
+                signing_secret
             ) VALUES (
-                ?1, ?2, ?3, ?4, ?5, ?6, ?7, TRUE, CAST(?8 AS TIMESTAMP), CURRENT_TIMESTAMP
+                ?1, ?2, ?3, ?4, ?5, ?6, ?7, TRUE, CAST(?8 AS TIMESTAMP), CURRENT_TIMESTAMP, ?9
             )
             "#,
             duckdb::params![
@@ -369,6 +585,7 @@ impl DuckDbBackend {
                 channel_to_str(&req.channel),
                 req.target,
                 next_run.to_rfc3339(),
+                generate_signing_secret(),
             ],
         )?;
         drop(conn);
@@ -433,6 +650,25 @@ impl DuckDbBackend {
             .await
     }
 
+    /// Replace the webhook signing secret of a subscription.
+    pub async fn rotate_report_subscription_secret(
+        &self,
+        website_id: &str,
+        subscription_id: &str,
+    ) -> Result<Option<ReportSubscription>> {
+        let conn = self.conn.lock().await;
+        let rows = conn.execute(
+            "UPDATE report_subscriptions SET signing_secret = ?1 WHERE website_id = ?2 AND id = ?3",
+            duckdb::params![generate_signing_secret(), website_id, subscription_id],
+        )?;
+        drop(conn);
+        if rows == 0 {
+            return Ok(None);
+        }
+        self.get_report_subscription(website_id, subscription_id)
+            .await
+    }
+
     pub async fn delete_report_subscription(
         &self,
         website_id: &str,
@@ -443,6 +679,9 @@ impl DuckDbBackend {
             "DELETE FROM report_subscriptions WHERE website_id = ?1 AND id = ?2",
             duckdb::params![website_id, subscription_id],
         )?;
+        if rows > 0 {
+            cancel_pending_retries(&conn, "subscription", subscription_id)?;
+        }
         Ok(rows > 0)
     }
 
@@ -466,7 +705,8 @@ impl DuckDbBackend {
                 is_active,
                 CAST(last_run_at AS VARCHAR),
                 CAST(next_run_at AS VARCHAR),
-                CAST(created_at AS VARCHAR)
+                CAST(created_at AS VARCHAR),
+                signing_secret
             FROM report_subscriptions
             WHERE is_active = TRUE
               AND next_run_at <= CAST(?1 AS TIMESTAMP)
@@ -520,17 +760,14 @@ impl DuckDbBackend {
 
     pub async fn list_alert_rules(&self, website_id: &str) -> Result<Vec<AlertRule>> {
         let conn = self.conn.lock().await;
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             r#"
-            SELECT
-                id, website_id, name, metric, condition_type,
-                threshold_value, lookback_days, channel, target,
-                is_active, CAST(created_at AS VARCHAR)
+            SELECT {ALERT_RULE_COLUMNS}
             FROM alert_rules
             WHERE website_id = ?1
             ORDER BY created_at DESC, id DESC
-            "#,
-        )?;
+            "#
+        ))?;
         let mut out = Vec::new();
         for row in stmt.query_map(duckdb::params![website_id], map_alert_rule_row)? {
             out.push(row?);
@@ -544,16 +781,13 @@ impl DuckDbBackend {
         alert_id: &str,
     ) -> Result<Option<AlertRule>> {
         let conn = self.conn.lock().await;
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             r#"
-            SELECT
-                id, website_id, name, metric, condition_type,
-                threshold_value, lookback_days, channel, target,
-                is_active, CAST(created_at AS VARCHAR)
+            SELECT {ALERT_RULE_COLUMNS}
             FROM alert_rules
             WHERE website_id = ?1 AND id = ?2
-            "#,
-        )?;
+            "#
+        ))?;
         Ok(stmt
             .query_row(duckdb::params![website_id, alert_id], map_alert_rule_row)
             .ok())
@@ -565,15 +799,24 @@ impl DuckDbBackend {
         req: CreateAlertRuleRequest,
     ) -> Result<AlertRule> {
         let id = generate_alert_id();
-        let lookback_days = req.lookback_days.unwrap_or(7).clamp(1, 30);
+        let lookback_days = req.lookback_days.unwrap_or(7).clamp(1, MAX_LOOKBACK_DAYS);
+        let baseline = req.baseline.unwrap_or_default();
+        let filters = filters_to_json(&req.filters.unwrap_or_default())?;
+        let granularity = req.granularity.unwrap_or_default();
+        let cooldown_minutes = req
+            .cooldown_minutes
+            .unwrap_or(60)
+            .clamp(0, MAX_COOLDOWN_MINUTES);
         let conn = self.conn.lock().await;
         conn.execute(
             r#"
             INSERT INTO alert_rules (
                 id, website_id, name, metric, condition_type, threshold_value,
-                lookback_days, channel, target, is_active, created_at
+                lookback_days, channel, target, is_active, created_at, signing_secret,
+                baseline, event_name, filters, granularity, cooldown_minutes, is_firing
             ) VALUES (
-                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, TRUE, CURRENT_TIMESTAMP
+                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, TRUE, CURRENT_TIMESTAMP, ?10, ?11,
+                ?12, ?13, ?14, ?15, FALSE
             )
             "#,
             duckdb::params![
@@ -585,7 +828,13 @@ impl DuckDbBackend {
                 req.threshold_value,
                 lookback_days,
                 channel_to_str(&req.channel),
-                req.target
+                req.target,
+                generate_signing_secret(),
+                baseline_to_str(&baseline),
+                req.event_name,
+                filters,
+                granularity_to_str(granularity),
+                cooldown_minutes
             ],
         )?;
         drop(conn);
@@ -611,13 +860,23 @@ impl DuckDbBackend {
         let lookback_days = req
             .lookback_days
             .unwrap_or(existing.lookback_days)
-            .clamp(1, 30);
+            .clamp(1, MAX_LOOKBACK_DAYS);
+        let baseline = req.baseline.unwrap_or(existing.baseline);
+        let event_name = req.event_name.unwrap_or(existing.event_name);
+        let filters = filters_to_json(&req.filters.unwrap_or(existing.filters))?;
+        let granularity = req.granularity.unwrap_or(existing.granularity);
+        let cooldown_minutes = req
+            .cooldown_minutes
+            .unwrap_or(existing.cooldown_minutes)
+            .clamp(0, MAX_COOLDOWN_MINUTES);
         let channel = req.channel.unwrap_or(existing.channel);
         let target = req
             .target
             .unwrap_or(Some(existing.target))
             .unwrap_or_default();
         let is_active = req.is_active.unwrap_or(existing.is_active);
+        // A paused rule starts afresh: no resolve for a trigger it sent before.
+        let is_firing = existing.is_firing && is_active;
 
         let conn = self.conn.lock().await;
         conn.execute(
@@ -630,8 +889,14 @@ impl DuckDbBackend {
                 lookback_days = ?5,
                 channel = ?6,
                 target = ?7,
-                is_active = ?8
-            WHERE website_id = ?9 AND id = ?10
+                is_active = ?8,
+                baseline = ?9,
+                event_name = ?10,
+                filters = ?11,
+                granularity = ?12,
+                cooldown_minutes = ?13,
+                is_firing = ?14
+            WHERE website_id = ?15 AND id = ?16
             "#,
             duckdb::params![
                 name,
@@ -642,6 +907,12 @@ impl DuckDbBackend {
                 channel_to_str(&channel),
                 target,
                 is_active,
+                baseline_to_str(&baseline),
+                event_name,
+                filters,
+                granularity_to_str(granularity),
+                cooldown_minutes,
+                is_firing,
                 website_id,
                 alert_id,
             ],
@@ -650,30 +921,72 @@ impl DuckDbBackend {
         self.get_alert_rule(website_id, alert_id).await
     }
 
+    /// Record that a rule triggered (`firing = true`, at `at`) or resolved.
+    /// Starting to fire opens a new firing episode.
+    pub async fn set_alert_rule_firing(
+        &self,
+        alert_id: &str,
+        firing: bool,
+        at: DateTime<Utc>,
+    ) -> Result<()> {
+        let conn = self.conn.lock().await;
+        conn.execute(
+            r#"
+            UPDATE alert_rules
+            SET firing_episode = CASE WHEN ?1 AND NOT COALESCE(is_firing, FALSE)
+                    THEN COALESCE(firing_episode, 0) + 1
+                    ELSE firing_episode END,
+                is_firing = ?1,
+                last_triggered_at = CASE WHEN ?1 THEN CAST(?2 AS TIMESTAMP) ELSE last_triggered_at END
+            WHERE id = ?3
+            "#,
+            duckdb::params![firing, at.to_rfc3339(), alert_id],
+        )?;
+        Ok(())
+    }
+
+    /// Replace the webhook signing secret of an alert rule.
+    pub async fn rotate_alert_rule_secret(
+        &self,
+        website_id: &str,
+        alert_id: &str,
+    ) -> Result<Option<AlertRule>> {
+        let conn = self.conn.lock().await;
+        let rows = conn.execute(
+            "UPDATE alert_rules SET signing_secret = ?1 WHERE website_id = ?2 AND id = ?3",
+            duckdb::params![generate_signing_secret(), website_id, alert_id],
+        )?;
+        drop(conn);
+        if rows == 0 {
+            return Ok(None);
+        }
+        self.get_alert_rule(website_id, alert_id).await
+    }
+
     pub async fn delete_alert_rule(&self, website_id: &str, alert_id: &str) -> Result<bool> {
         let conn = self.conn.lock().await;
         let rows = conn.execute(
             "DELETE FROM alert_rules WHERE website_id = ?1 AND id = ?2",
             duckdb::params![website_id, alert_id],
         )?;
+        if rows > 0 {
+            cancel_pending_retries(&conn, "alert", alert_id)?;
+        }
         Ok(rows > 0)
     }
 
     pub async fn list_active_alert_rules(&self, limit: i64) -> Result<Vec<AlertRule>> {
         let bounded_limit = limit.clamp(1, 1000);
         let conn = self.conn.lock().await;
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             r#"
-            SELECT
-                id, website_id, name, metric, condition_type,
-                threshold_value, lookback_days, channel, target,
-                is_active, CAST(created_at AS VARCHAR)
+            SELECT {ALERT_RULE_COLUMNS}
             FROM alert_rules
             WHERE is_active = TRUE
             ORDER BY created_at ASC
             LIMIT ?1
-            "#,
-        )?;
+            "#
+        ))?;
         let mut out = Vec::new();
         for row in stmt.query_map(duckdb::params![bounded_limit], map_alert_rule_row)? {
             out.push(row?);
@@ -681,116 +994,157 @@ impl DuckDbBackend {
         Ok(out)
     }
 
-    pub async fn get_daily_alert_metric_series(
+    /// Values of the rule's metric, restricted by its filters, for every
+    /// bucket of `window` that has activity, keyed by bucket start.
+    pub async fn get_alert_metric_series(
         &self,
-        website_id: &str,
-        metric: &AlertMetric,
-        start_date: NaiveDate,
-        end_date: NaiveDate,
-    ) -> Result<Vec<(NaiveDate, f64)>> {
-        let start = start_date
-            .and_hms_opt(0, 0, 0)
-            .expect("valid start")
-            .and_utc()
-            .to_rfc3339();
-        let end_exclusive = (end_date + Duration::days(1))
-            .and_hms_opt(0, 0, 0)
-            .expect("valid end")
-            .and_utc()
-            .to_rfc3339();
-        let conn = self.conn.lock().await;
+        rule: &AlertRule,
+        window: &AlertWindow,
+    ) -> Result<Vec<(NaiveDateTime, f64)>> {
+        // Bot share has to see the bots; every other metric ignores them,
+        // like the dashboard does.
+        let include_bots = matches!(rule.metric, AlertMetric::BotShare);
+        let filter = rule.filters.to_analytics_filter(
+            window.start.date(),
+            window.current.date(),
+            include_bots,
+        )?;
+        let unit = match rule.granularity {
+            AlertGranularity::Day => "day",
+            AlertGranularity::Hour => "hour",
+        };
 
-        let sql = match metric {
-            AlertMetric::Pageviews => {
-                r#"
-                SELECT
-                    CAST(CAST(date_trunc('day', created_at) AS DATE) AS VARCHAR) AS period_day,
-                    CAST(COUNT(*) AS DOUBLE) AS metric_value
-                FROM events
-                WHERE website_id = ?1
-                  AND created_at >= CAST(?2 AS TIMESTAMP)
-                  AND created_at < CAST(?3 AS TIMESTAMP)
-                  AND event_type = 'pageview'
-                GROUP BY period_day
-                ORDER BY period_day
-                "#
+        let rule = rule.clone();
+        let window = *window;
+        self.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+            let mut params: Vec<Box<dyn duckdb::types::ToSql>> = vec![
+                Box::new(rule.website_id.clone()),
+                Box::new(window.start.and_utc().to_rfc3339()),
+                Box::new(window.end().and_utc().to_rfc3339()),
+            ];
+            let mut param_idx = 4;
+            let mut filter_sql = String::new();
+            if let Some(until) = window.until {
+                filter_sql.push_str(&format!(
+                    " AND date_diff('second', date_trunc('day', e.created_at), e.created_at) < ?{param_idx}"
+                ));
+                params.push(Box::new(i64::from(until.num_seconds_from_midnight())));
+                param_idx += 1;
             }
-            AlertMetric::Visitors => {
-                r#"
-                SELECT
-                    CAST(CAST(date_trunc('day', created_at) AS DATE) AS VARCHAR) AS period_day,
-                    CAST(COUNT(DISTINCT visitor_id) AS DOUBLE) AS metric_value
-                FROM events
-                WHERE website_id = ?1
-                  AND created_at >= CAST(?2 AS TIMESTAMP)
-                  AND created_at < CAST(?3 AS TIMESTAMP)
-                GROUP BY period_day
-                ORDER BY period_day
-                "#
-            }
-            AlertMetric::Conversions => {
-                r#"
-                SELECT
-                    CAST(CAST(date_trunc('day', created_at) AS DATE) AS VARCHAR) AS period_day,
-                    CAST(COUNT(*) AS DOUBLE) AS metric_value
-                FROM events
-                WHERE website_id = ?1
-                  AND created_at >= CAST(?2 AS TIMESTAMP)
-                  AND created_at < CAST(?3 AS TIMESTAMP)
-                  AND event_name = 'goal_conversion'
-                GROUP BY period_day
-                ORDER BY period_day
-                "#
-            }
-            AlertMetric::ConversionRate => {
+            append_event_filters(&filter, "e.", &mut filter_sql, &mut params, &mut param_idx);
+            let mut session_filter_sql = String::new();
+            append_session_bot_filter(&mut session_filter_sql, include_bots, "s.");
+
+            let value_sql = match rule.metric {
+                AlertMetric::Pageviews => r#"
+                    SELECT bucket, CAST(COUNT(*) AS DOUBLE)
+                    FROM filtered_events
+                    WHERE event_type = 'pageview'
+                    GROUP BY bucket
+                    "#
+                .to_string(),
+                AlertMetric::Visitors => r#"
+                    SELECT bucket, CAST(COUNT(DISTINCT visitor_id) AS DOUBLE)
+                    FROM filtered_events
+                    GROUP BY bucket
+                    "#
+                .to_string(),
+                AlertMetric::Conversions => r#"
+                    SELECT bucket, CAST(COUNT(*) AS DOUBLE)
+                    FROM filtered_events
+                    WHERE event_name = 'goal_conversion'
+                    GROUP BY bucket
+                    "#
+                .to_string(),
+                AlertMetric::CustomEvents => {
+                    let event_name = rule
+                        .event_name
+                        .clone()
+                        .ok_or_else(|| anyhow!("custom_events alerts require an event_name"))?;
+                    params.push(Box::new(event_name));
+                    format!(
+                        r#"
+                        SELECT bucket, CAST(COUNT(*) AS DOUBLE)
+                        FROM filtered_events
+                        WHERE event_type = 'event' AND event_name = ?{param_idx}
+                        GROUP BY bucket
+                        "#
+                    )
+                }
+                AlertMetric::ConversionRate => r#"
+                    SELECT
+                        bucket,
+                        COUNT(*) FILTER (WHERE event_name = 'goal_conversion') * 100.0
+                            / COUNT(DISTINCT session_id)
+                    FROM filtered_events
+                    GROUP BY bucket
+                    "#
+                .to_string(),
+                AlertMetric::BotShare => r#"
+                    SELECT bucket, COUNT(*) FILTER (WHERE is_bot) * 100.0 / COUNT(*)
+                    FROM filtered_events
+                    GROUP BY bucket
+                    "#
+                .to_string(),
+                AlertMetric::BounceRate | AlertMetric::SessionDuration => {
+                    let aggregate = if matches!(rule.metric, AlertMetric::BounceRate) {
+                        "AVG(CASE WHEN s.pageview_count = 1 THEN 100.0 ELSE 0.0 END)"
+                    } else {
+                        "AVG(EPOCH(s.last_seen - s.first_seen))"
+                    };
+                    // Sessions active in a bucket, judged on their whole length.
+                    format!(
+                        r#"
+                        SELECT b.bucket, CAST({aggregate} AS DOUBLE)
+                        FROM (SELECT DISTINCT bucket, session_id FROM filtered_events) b
+                        JOIN sessions s ON s.session_id = b.session_id
+                        WHERE 1 = 1
+                          {session_filter_sql}
+                        GROUP BY b.bucket
+                        "#
+                    )
+                }
+            };
+            let sql = format!(
                 r#"
-                WITH conv AS (
+                WITH filtered_events AS (
                     SELECT
-                        CAST(date_trunc('day', created_at) AS DATE) AS period_day,
-                        CAST(COUNT(*) AS DOUBLE) AS conversions
-                    FROM events
-                    WHERE website_id = ?1
-                      AND created_at >= CAST(?2 AS TIMESTAMP)
-                      AND created_at < CAST(?3 AS TIMESTAMP)
-                      AND event_name = 'goal_conversion'
-                    GROUP BY period_day
+                        date_trunc('{unit}', e.created_at) AS bucket,
+                        e.event_type,
+                        e.event_name,
+                        e.session_id,
+                        e.visitor_id,
+                        e.is_bot
+                    FROM events e
+                    WHERE e.website_id = ?1
+                      AND e.created_at >= CAST(?2 AS TIMESTAMP)
+                      AND e.created_at < CAST(?3 AS TIMESTAMP)
+                      {filter_sql}
                 ),
-                sess AS (
-                    SELECT
-                        CAST(date_trunc('day', first_seen) AS DATE) AS period_day,
-                        CAST(COUNT(*) AS DOUBLE) AS sessions
-                    FROM sessions
-                    WHERE website_id = ?1
-                      AND first_seen >= CAST(?2 AS TIMESTAMP)
-                      AND first_seen < CAST(?3 AS TIMESTAMP)
-                    GROUP BY period_day
-                )
-                SELECT
-                    CAST(COALESCE(conv.period_day, sess.period_day) AS VARCHAR) AS period_day,
-                    CASE
-                        WHEN COALESCE(sess.sessions, 0) = 0 THEN 0.0
-                        ELSE (COALESCE(conv.conversions, 0) / sess.sessions) * 100.0
-                    END AS metric_value
-                FROM conv
-                FULL OUTER JOIN sess ON conv.period_day = sess.period_day
-                ORDER BY period_day
+                bucket_values(bucket, metric_value) AS ({value_sql})
+                SELECT strftime(bucket, '%Y-%m-%d %H:%M:%S'), metric_value
+                FROM bucket_values
+                ORDER BY bucket
                 "#
-            }
-        };
+            );
 
-        let mut stmt = conn.prepare(sql)?;
-        let mut out = Vec::new();
-        for row in stmt.query_map(duckdb::params![website_id, start, end_exclusive], |row| {
-            let day: String = row.get(0)?;
-            let value: f64 = row.get(1)?;
-            Ok((day, value))
-        })? {
-            let (day_raw, value) = row?;
-            let day = NaiveDate::parse_from_str(&day_raw, "%Y-%m-%d")
-                .map_err(|e| anyhow!("invalid date in alert metric series: {e}"))?;
-            out.push((day, value));
-        }
-        Ok(out)
+            let mut stmt = conn.prepare(&sql)?;
+            let param_refs: Vec<&dyn duckdb::types::ToSql> =
+                params.iter().map(|param| param.as_ref()).collect();
+            let mut out = Vec::new();
+            for row in stmt.query_map(param_refs.as_slice(), |row| {
+                let bucket: String = row.get(0)?;
+                let value: f64 = row.get(1)?;
+                Ok((bucket, value))
+            })? {
+                let (bucket_raw, value) = row?;
+                let bucket = NaiveDateTime::parse_from_str(&bucket_raw, "%Y-%m-%d %H:%M:%S")
+                    .map_err(|e| anyhow!("invalid bucket in alert metric series: {e}"))?;
+                out.push((bucket, value));
+            }
+            Ok(out)
+        })
+        .await
     }
 
     pub async fn list_notification_deliveries_for_website(
@@ -800,16 +1154,9 @@ impl DuckDbBackend {
     ) -> Result<Vec<NotificationDelivery>> {
         let bounded_limit = limit.clamp(1, 200);
         let conn = self.conn.lock().await;
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             r#"
-            SELECT
-                d.id,
-                d.source_type,
-                d.source_id,
-                d.idempotency_key,
-                d.status,
-                d.error_message,
-                CAST(d.delivered_at AS VARCHAR)
+            SELECT {DELIVERY_COLUMNS}
             FROM notification_deliveries d
             LEFT JOIN report_subscriptions s
               ON d.source_type = 'subscription' AND d.source_id = s.id
@@ -818,8 +1165,8 @@ impl DuckDbBackend {
             WHERE s.website_id = ?1 OR a.website_id = ?1
             ORDER BY d.delivered_at DESC, d.id DESC
             LIMIT ?2
-            "#,
-        )?;
+            "#
+        ))?;
         let mut out = Vec::new();
         for row in stmt.query_map(
             duckdb::params![website_id, bounded_limit],
@@ -838,6 +1185,7 @@ impl DuckDbBackend {
         Ok(exists > 0)
     }
 
+    /// Record a delivery that was never sent (e.g. the report failed to run).
     pub async fn create_notification_delivery(
         &self,
         source_type: NotificationSourceType,
@@ -845,8 +1193,60 @@ impl DuckDbBackend {
         idempotency_key: &str,
         status: NotificationDeliveryStatus,
         error_message: Option<&str>,
+    ) -> Result<NotificationDelivery> {
+        self.insert_notification_delivery(
+            source_type,
+            source_id,
+            idempotency_key,
+            None,
+            status,
+            error_message,
+            None,
+        )
+        .await
+    }
+
+    /// Record the first attempt of a delivery that was sent. `next_attempt_at`
+    /// is only kept for [`NotificationDeliveryStatus::Retrying`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_sent_notification_delivery(
+        &self,
+        source_type: NotificationSourceType,
+        source_id: &str,
+        idempotency_key: &str,
+        send: NotificationSend<'_>,
+        status: NotificationDeliveryStatus,
+        error_message: Option<&str>,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<NotificationDelivery> {
+        self.insert_notification_delivery(
+            source_type,
+            source_id,
+            idempotency_key,
+            Some(send),
+            status,
+            error_message,
+            next_attempt_at,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn insert_notification_delivery(
+        &self,
+        source_type: NotificationSourceType,
+        source_id: &str,
+        idempotency_key: &str,
+        send: Option<NotificationSend<'_>>,
+        status: NotificationDeliveryStatus,
+        error_message: Option<&str>,
+        next_attempt_at: Option<DateTime<Utc>>,
     ) -> Result<NotificationDelivery> {
         let id = generate_delivery_id();
+        let next_attempt_at = match status {
+            NotificationDeliveryStatus::Retrying => next_attempt_at.map(|at| at.to_rfc3339()),
+            _ => None,
+        };
         let conn = self.conn.lock().await;
         conn.execute(
             r#"
@@ -857,9 +1257,14 @@ impl DuckDbBackend {
                 idempotency_key,
                 status,
                 error_message,
-                delivered_at
+                delivered_at,
+                channel,
+                target,
+                payload,
+                attempt_count,
+                next_attempt_at
             ) VALUES (
-                ?1, ?2, ?3, ?4, ?5, ?6, CURRENT_TIMESTAMP
+                ?1, ?2, ?3, ?4, ?5, ?6, CURRENT_TIMESTAMP, ?7, ?8, ?9, 1, CAST(?10 AS TIMESTAMP)
             )
             "#,
             duckdb::params![
@@ -869,23 +1274,112 @@ impl DuckDbBackend {
                 idempotency_key,
                 status_to_str(&status),
                 error_message,
+                send.as_ref().map(|s| channel_to_str(s.channel)),
+                send.as_ref().map(|s| s.target),
+                send.as_ref().map(|s| s.payload),
+                next_attempt_at,
             ],
         )?;
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {DELIVERY_COLUMNS} FROM notification_deliveries d WHERE d.id = ?1"
+        ))?;
+        Ok(stmt.query_row(duckdb::params![id], map_notification_delivery_row)?)
+    }
+
+    /// Retrying deliveries whose next attempt is due, oldest first.
+    pub async fn list_due_notification_retries(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<ResendableDelivery>> {
+        let bounded_limit = limit.clamp(1, 1000);
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(&format!(
             r#"
-            SELECT
-                id,
-                source_type,
-                source_id,
-                idempotency_key,
-                status,
-                error_message,
-                CAST(delivered_at AS VARCHAR)
-            FROM notification_deliveries
-            WHERE id = ?1
+            SELECT {DELIVERY_COLUMNS}, d.payload, COALESCE(s.signing_secret, a.signing_secret)
+            FROM notification_deliveries d
+            LEFT JOIN report_subscriptions s
+              ON d.source_type = 'subscription' AND d.source_id = s.id
+            LEFT JOIN alert_rules a
+              ON d.source_type = 'alert' AND d.source_id = a.id
+            WHERE d.status = 'retrying'
+              AND d.next_attempt_at <= CAST(?1 AS TIMESTAMP)
+            ORDER BY d.next_attempt_at ASC, d.id ASC
+            LIMIT ?2
+            "#
+        ))?;
+        let mut out = Vec::new();
+        for row in stmt.query_map(
+            duckdb::params![now.to_rfc3339(), bounded_limit],
+            map_resendable_delivery_row,
+        )? {
+            if let Some(delivery) = row? {
+                out.push(delivery);
+            }
+        }
+        Ok(out)
+    }
+
+    /// A delivery of one of the website's subscriptions or alerts, if it can
+    /// be sent again. `None` when it does not exist or predates stored payloads.
+    pub async fn get_resendable_delivery(
+        &self,
+        website_id: &str,
+        delivery_id: &str,
+    ) -> Result<Option<ResendableDelivery>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(&format!(
+            r#"
+            SELECT {DELIVERY_COLUMNS}, d.payload, COALESCE(s.signing_secret, a.signing_secret)
+            FROM notification_deliveries d
+            LEFT JOIN report_subscriptions s
+              ON d.source_type = 'subscription' AND d.source_id = s.id
+            LEFT JOIN alert_rules a
+              ON d.source_type = 'alert' AND d.source_id = a.id
+            WHERE d.id = ?1 AND (s.website_id = ?2 OR a.website_id = ?2)
+            "#
+        ))?;
+        let mut rows = stmt.query_map(
+            duckdb::params![delivery_id, website_id],
+            map_resendable_delivery_row,
+        )?;
+        Ok(rows.next().transpose()?.flatten())
+    }
+
+    /// Record another attempt of an existing delivery.
+    pub async fn record_notification_delivery_attempt(
+        &self,
+        delivery_id: &str,
+        status: NotificationDeliveryStatus,
+        error_message: Option<&str>,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<NotificationDelivery> {
+        let next_attempt_at = match status {
+            NotificationDeliveryStatus::Retrying => next_attempt_at.map(|at| at.to_rfc3339()),
+            _ => None,
+        };
+        let conn = self.conn.lock().await;
+        conn.execute(
+            r#"
+            UPDATE notification_deliveries
+            SET status = ?1,
+                error_message = ?2,
+                attempt_count = COALESCE(attempt_count, 1) + 1,
+                next_attempt_at = CAST(?3 AS TIMESTAMP),
+                delivered_at = CURRENT_TIMESTAMP
+            WHERE id = ?4
             "#,
+            duckdb::params![
+                status_to_str(&status),
+                error_message,
+                next_attempt_at,
+                delivery_id
+            ],
         )?;
-        Ok(stmt.query_row(duckdb::params![id], map_notification_delivery_row)?)
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {DELIVERY_COLUMNS} FROM notification_deliveries d WHERE d.id = ?1"
+        ))?;
+        Ok(stmt.query_row(duckdb::params![delivery_id], map_notification_delivery_row)?)
     }
 
     pub async fn count_goal_conversions_for_range(
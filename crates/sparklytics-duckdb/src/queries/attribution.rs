@@ -7,7 +7,9 @@ use sparklytics_core::analytics::{
     AttributionTotals, GoalType, GoalValueMode, MatchOperator, RevenueSummary,
 };
 
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
 use crate::queries::bot_filters::append_event_bot_filter;
+use crate::queries::event_filters::append_event_filters;
 use crate::DuckDbBackend;
 
 #[derive(Debug, Clone)]
@@ -23,6 +25,9 @@ struct GoalDefinition {
 #[derive(Debug, Clone)]
 struct EventRow {
     session_id: String,
+    visitor_id: String,
+    created_at_ms: i64,
+    in_period: bool,
     event_type: String,
     url: String,
     event_name: Option<String>,
@@ -30,6 +35,8 @@ struct EventRow {
     utm_source: Option<String>,
     utm_medium: Option<String>,
     referrer_domain: Option<String>,
+    /// Whether the event passes the request's dimension and property filters.
+    matches_filter: bool,
 }
 
 fn goal_type_from_str(raw: &str) -> Result<GoalType> {
@@ -57,83 +64,6 @@ fn value_mode_from_str(raw: &str) -> Result<GoalValueMode> {
     }
 }
 
-fn append_event_filters(
-    filter: &AnalyticsFilter,
-    filter_sql: &mut String,
-    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
-    param_idx: &mut usize,
-) {
-    append_event_bot_filter(filter_sql, filter.include_bots, "e.");
-    if let Some(ref country) = filter.filter_country {
-        filter_sql.push_str(&format!(" AND e.country = ?{}", *param_idx));
-        params.push(Box::new(country.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref page) = filter.filter_page {
-        filter_sql.push_str(&format!(" AND e.url LIKE ?{}", *param_idx));
-        params.push(Box::new(format!("%{}%", page)));
-        *param_idx += 1;
-    }
-    if let Some(ref referrer) = filter.filter_referrer {
-        filter_sql.push_str(&format!(" AND e.referrer_domain = ?{}", *param_idx));
-        params.push(Box::new(referrer.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref browser) = filter.filter_browser {
-        filter_sql.push_str(&format!(" AND e.browser = ?{}", *param_idx));
-        params.push(Box::new(browser.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref os) = filter.filter_os {
-        filter_sql.push_str(&format!(" AND e.os = ?{}", *param_idx));
-        params.push(Box::new(os.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref device) = filter.filter_device {
-        filter_sql.push_str(&format!(" AND e.device_type = ?{}", *param_idx));
-        params.push(Box::new(device.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref language) = filter.filter_language {
-        filter_sql.push_str(&format!(" AND e.language = ?{}", *param_idx));
-        params.push(Box::new(language.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_source) = filter.filter_utm_source {
-        filter_sql.push_str(&format!(" AND e.utm_source = ?{}", *param_idx));
-        params.push(Box::new(utm_source.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_medium) = filter.filter_utm_medium {
-        filter_sql.push_str(&format!(" AND e.utm_medium = ?{}", *param_idx));
-        params.push(Box::new(utm_medium.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_campaign) = filter.filter_utm_campaign {
-        filter_sql.push_str(&format!(" AND e.utm_campaign = ?{}", *param_idx));
-        params.push(Box::new(utm_campaign.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref region) = filter.filter_region {
-        filter_sql.push_str(&format!(" AND e.region = ?{}", *param_idx));
-        params.push(Box::new(region.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref city) = filter.filter_city {
-        filter_sql.push_str(&format!(" AND e.city = ?{}", *param_idx));
-        params.push(Box::new(city.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref hostname) = filter.filter_hostname {
-        filter_sql.push_str(&format!(
-            " AND lower(regexp_extract(e.url, '^https?://([^/?#]+)', 1)) = lower(?{})",
-            *param_idx
-        ));
-        params.push(Box::new(hostname.clone()));
-        *param_idx += 1;
-    }
-}
-
 fn path_with_query(url: &str) -> String {
     if let Some(scheme_idx) = url.find("://") {
         let rest = &url[(scheme_idx + 3)..];
@@ -297,70 +227,182 @@ fn fetch_goal(
     })
 }
 
+#[derive(Debug, Clone)]
+struct Touch {
+    channel: String,
+    at_ms: i64,
+    /// Any of the touch's events passes the request's filters.
+    matches_filter: bool,
+}
+
+const DAY_MS: f64 = 86_400_000.0;
+
+/// Share of one conversion credited to each touch, in path order. The
+/// weights always sum to 1 for a non-empty path.
+fn touch_weights(
+    model: &AttributionModel,
+    touches: &[Touch],
+    conversion_ms: i64,
+    half_life_days: f64,
+) -> Vec<f64> {
+    let n = touches.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut weights = vec![0.0; n];
+    match model {
+        AttributionModel::FirstTouch => weights[0] = 1.0,
+        AttributionModel::LastTouch => weights[n - 1] = 1.0,
+        AttributionModel::Linear => weights.fill(1.0 / n as f64),
+        AttributionModel::TimeDecay => {
+            for (weight, touch) in weights.iter_mut().zip(touches) {
+                let age_days = (conversion_ms - touch.at_ms).max(0) as f64 / DAY_MS;
+                *weight = 0.5_f64.powf(age_days / half_life_days);
+            }
+            let total: f64 = weights.iter().sum();
+            if total > 0.0 {
+                weights.iter_mut().for_each(|w| *w /= total);
+            } else {
+                weights.fill(1.0 / n as f64);
+            }
+        }
+        AttributionModel::PositionBased => match n {
+            1 => weights[0] = 1.0,
+            2 => weights.fill(0.5),
+            _ => {
+                let middle = 0.2 / (n - 2) as f64;
+                weights.fill(middle);
+                weights[0] = 0.4;
+                weights[n - 1] = 0.4;
+            }
+        },
+    }
+    weights
+}
+
+/// The single-touch models credit a channel from the converting session;
+/// only the multi-touch models follow a visitor across sessions.
+fn spans_sessions(model: &AttributionModel) -> bool {
+    !matches!(
+        model,
+        AttributionModel::FirstTouch | AttributionModel::LastTouch
+    )
+}
+
+/// Walk each journey's events in time order, building a touchpoint path
+/// (consecutive events from the same channel collapse into one touch) and
+/// crediting every goal match inside the reporting period. A journey is the
+/// converting session for first/last touch, and the visitor's touches from
+/// the preceding `lookback_days` for the multi-touch models.
+///
+/// Filters select touches, not journeys: only credit landing on a touch
+/// that passes them counts, so totals are the conversions and revenue each
+/// model credits to the filtered traffic.
 fn aggregate_rows(
     rows: Vec<EventRow>,
     goal: &GoalDefinition,
-    model: &AttributionModel,
+    query: &AttributionQuery,
 ) -> (Vec<AttributionRow>, AttributionTotals) {
-    let mut sessions: BTreeMap<String, Vec<EventRow>> = BTreeMap::new();
+    let multi_touch = spans_sessions(&query.model);
+    let mut journeys: BTreeMap<String, Vec<EventRow>> = BTreeMap::new();
     for row in rows {
-        sessions
-            .entry(row.session_id.clone())
-            .or_default()
-            .push(row);
+        let key = if multi_touch {
+            row.visitor_id.clone()
+        } else {
+            row.session_id.clone()
+        };
+        journeys.entry(key).or_default().push(row);
     }
 
-    let mut by_channel: HashMap<String, (i64, f64)> = HashMap::new();
+    let lookback_ms = multi_touch.then(|| i64::from(query.lookback_days) * 86_400_000);
+    let mut by_channel: HashMap<String, (i64, f64, f64)> = HashMap::new();
     let mut total_conversions: i64 = 0;
+    let mut total_credit: f64 = 0.0;
     let mut total_revenue: f64 = 0.0;
 
-    for events in sessions.values() {
-        let mut touch_channels: Vec<String> = Vec::with_capacity(events.len());
+    for events in journeys.values() {
+        let mut touches: Vec<Touch> = Vec::with_capacity(events.len());
 
         for row in events {
             let channel = channel_for_event(row);
-            touch_channels.push(channel.clone());
+            match touches.last_mut() {
+                Some(last) if last.channel == channel => {
+                    last.at_ms = row.created_at_ms;
+                    last.matches_filter |= row.matches_filter;
+                }
+                _ => touches.push(Touch {
+                    channel,
+                    at_ms: row.created_at_ms,
+                    matches_filter: row.matches_filter,
+                }),
+            }
 
-            if !is_goal_match(goal, row) {
+            if !row.in_period || !is_goal_match(goal, row) {
                 continue;
             }
 
-            let attributed_channel = match model {
-                AttributionModel::FirstTouch => {
-                    touch_channels.first().cloned().unwrap_or(channel.clone())
+            let first_eligible = match lookback_ms {
+                Some(lookback_ms) => {
+                    let window_start = row.created_at_ms - lookback_ms;
+                    touches
+                        .iter()
+                        .position(|touch| touch.at_ms >= window_start)
+                        .unwrap_or(touches.len() - 1)
                 }
-                AttributionModel::LastTouch => touch_channels.last().cloned().unwrap_or(channel),
+                None => 0,
             };
+            let path = &touches[first_eligible..];
+            let weights =
+                touch_weights(&query.model, path, row.created_at_ms, query.half_life_days);
 
             let revenue = parse_revenue(goal, row.event_data.as_deref());
-            let entry = by_channel.entry(attributed_channel).or_insert((0, 0.0));
-            entry.0 += 1;
-            entry.1 += revenue;
+            let mut credited: HashMap<&str, f64> = HashMap::new();
+            for (touch, weight) in path.iter().zip(weights) {
+                if touch.matches_filter {
+                    *credited.entry(touch.channel.as_str()).or_insert(0.0) += weight;
+                }
+            }
+            let conversion_credit: f64 = credited.values().sum();
+            if conversion_credit <= 0.0 {
+                continue;
+            }
+            for (channel, weight) in credited {
+                let entry = by_channel
+                    .entry(channel.to_string())
+                    .or_insert((0, 0.0, 0.0));
+                if weight > 0.0 {
+                    entry.0 += 1;
+                }
+                entry.1 += weight;
+                entry.2 += revenue * weight;
+            }
             total_conversions += 1;
-            total_revenue += revenue;
+            total_credit += conversion_credit;
+            total_revenue += revenue * conversion_credit;
         }
     }
 
     let mut rows = by_channel
         .into_iter()
-        .map(|(channel, (conversions, revenue))| AttributionRow {
+        .filter(|(_, (conversions, _, _))| *conversions > 0)
+        .map(|(channel, (conversions, credit, revenue))| AttributionRow {
             channel,
             conversions,
+            credit,
             revenue,
-            share: if total_conversions == 0 {
-                0.0
+            share: if total_credit > 0.0 {
+                credit / total_credit
             } else {
-                conversions as f64 / total_conversions as f64
+                0.0
             },
         })
         .collect::<Vec<_>>();
 
     rows.sort_by(|a, b| {
-        b.conversions.cmp(&a.conversions).then_with(|| {
-            b.revenue
-                .partial_cmp(&a.revenue)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        })
+        b.credit
+            .total_cmp(&a.credit)
+            .then_with(|| b.revenue.total_cmp(&a.revenue))
+            .then_with(|| a.channel.cmp(&b.channel))
     });
 
     (
@@ -378,40 +420,80 @@ pub async fn get_attribution_inner(
     filter: &AnalyticsFilter,
     query: &AttributionQuery,
 ) -> Result<AttributionResponse> {
-    let conn = db.conn.lock().await;
-    let goal = fetch_goal(&conn, website_id, &query.goal_id)?;
+    let website_id = website_id.to_string();
+    let filter = filter.clone();
+    let query = query.clone();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        attribution_with_conn(conn, &website_id, &filter, &query)
+    })
+    .await
+}
+
+fn attribution_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    filter: &AnalyticsFilter,
+    query: &AttributionQuery,
+) -> Result<AttributionResponse> {
+    let goal = fetch_goal(conn, website_id, &query.goal_id)?;
 
     let start_str = filter.start_date.format("%Y-%m-%d").to_string();
     let end_str = (filter.end_date + chrono::Duration::days(1))
         .format("%Y-%m-%d")
         .to_string();
+    let lookback_days = if spans_sessions(&query.model) {
+        i64::from(query.lookback_days)
+    } else {
+        0
+    };
+    let lookback_start_str = (filter.start_date - chrono::Duration::days(lookback_days))
+        .format("%Y-%m-%d")
+        .to_string();
 
     let mut params: Vec<Box<dyn duckdb::types::ToSql>> = vec![
         Box::new(website_id.to_string()),
         Box::new(start_str),
         Box::new(end_str),
+        Box::new(lookback_start_str),
     ];
+    let mut bot_sql = String::new();
+    append_event_bot_filter(&mut bot_sql, filter.include_bots, "e.");
     let mut filter_sql = String::new();
-    let mut param_idx = 4;
-    append_event_filters(filter, &mut filter_sql, &mut params, &mut param_idx);
+    let mut param_idx = 5;
+    append_event_filters(filter, "e.", &mut filter_sql, &mut params, &mut param_idx);
 
+    // Touchpoints may precede the reporting period by up to `lookback_days`,
+    // but only visitors active inside the period can convert in it. Bots are
+    // dropped outright; the other filters only mark which touches can take
+    // credit, so journeys keep their full paths.
     let sql = format!(
         r#"
         SELECT
             e.session_id,
+            e.visitor_id,
+            epoch_ms(e.created_at) AS created_at_ms,
+            e.created_at >= ?2 AS in_period,
             e.event_type,
             e.url,
             e.event_name,
             e.event_data,
             e.utm_source,
             e.utm_medium,
-            e.referrer_domain
+            e.referrer_domain,
+            (TRUE {filter_sql}) AS matches_filter
         FROM events e
         WHERE e.website_id = ?1
-          AND e.created_at >= ?2
+          AND e.created_at >= ?4
           AND e.created_at < ?3
-          {filter_sql}
-        ORDER BY e.session_id ASC, e.created_at ASC, e.id ASC
+          AND e.visitor_id IN (
+              SELECT visitor_id
+              FROM events
+              WHERE website_id = ?1
+                AND created_at >= ?2
+                AND created_at < ?3
+          )
+          {bot_sql}
+        ORDER BY e.visitor_id ASC, e.created_at ASC, e.id ASC
         "#
     );
 
@@ -420,13 +502,17 @@ pub async fn get_attribution_inner(
     let rows = stmt.query_map(param_refs.as_slice(), |row| {
         Ok(EventRow {
             session_id: row.get(0)?,
-            event_type: row.get(1)?,
-            url: row.get(2)?,
-            event_name: row.get(3)?,
-            event_data: row.get(4)?,
-            utm_source: row.get(5)?,
-            utm_medium: row.get(6)?,
-            referrer_domain: row.get(7)?,
+            visitor_id: row.get(1)?,
+            created_at_ms: row.get(2)?,
+            in_period: row.get(3)?,
+            event_type: row.get(4)?,
+            url: row.get(5)?,
+            event_name: row.get(6)?,
+            event_data: row.get(7)?,
+            utm_source: row.get(8)?,
+            utm_medium: row.get(9)?,
+            referrer_domain: row.get(10)?,
+            matches_filter: row.get(11)?,
         })
     })?;
 
@@ -435,11 +521,14 @@ pub async fn get_attribution_inner(
         events.push(row?);
     }
 
-    let (rows, totals) = aggregate_rows(events, &goal, &query.model);
+    let (rows, totals) = aggregate_rows(events, &goal, query);
 
     Ok(AttributionResponse {
         goal_id: query.goal_id.clone(),
         model: query.model.clone(),
+        lookback_days: query.lookback_days,
+        half_life_days: (query.model == AttributionModel::TimeDecay)
+            .then_some(query.half_life_days),
         rows,
         totals,
     })
@@ -462,7 +551,20 @@ pub async fn get_revenue_summary_inner(
 
 #[cfg(test)]
 mod tests {
-    use super::path_with_query;
+    use super::{path_with_query, touch_weights, Touch, DAY_MS};
+    use sparklytics_core::analytics::AttributionModel;
+
+    fn path(ages_days: &[f64]) -> Vec<Touch> {
+        ages_days
+            .iter()
+            .enumerate()
+            .map(|(idx, age)| Touch {
+                channel: format!("c{idx}"),
+                at_ms: -(age * DAY_MS) as i64,
+                matches_filter: true,
+            })
+            .collect()
+    }
 
     #[test]
     fn path_with_query_handles_query_without_explicit_path() {
@@ -473,4 +575,39 @@ mod tests {
     fn path_with_query_preserves_relative_query_values() {
         assert_eq!(path_with_query("?utm=abc"), "/?utm=abc");
     }
+
+    #[test]
+    fn position_based_weights_are_u_shaped() {
+        let weights = touch_weights(
+            &AttributionModel::PositionBased,
+            &path(&[3.0, 2.0, 1.0, 0.0]),
+            0,
+            7.0,
+        );
+        assert_eq!(weights, vec![0.4, 0.1, 0.1, 0.4]);
+        let weights = touch_weights(&AttributionModel::PositionBased, &path(&[1.0, 0.0]), 0, 7.0);
+        assert_eq!(weights, vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn time_decay_halves_weight_per_half_life() {
+        let weights = touch_weights(&AttributionModel::TimeDecay, &path(&[7.0, 0.0]), 0, 7.0);
+        assert!((weights[0] - 1.0 / 3.0).abs() < 1e-9);
+        assert!((weights[1] - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn every_model_distributes_one_conversion() {
+        let touches = path(&[20.0, 9.0, 4.0, 0.5, 0.0]);
+        for model in [
+            AttributionModel::FirstTouch,
+            AttributionModel::LastTouch,
+            AttributionModel::Linear,
+            AttributionModel::TimeDecay,
+            AttributionModel::PositionBased,
+        ] {
+            let total: f64 = touch_weights(&model, &touches, 0, 7.0).iter().sum();
+            assert!((total - 1.0).abs() < 1e-9, "{model:?} sums to {total}");
+        }
+    }
 }
@@ -0,0 +1,146 @@
+use sparklytics_core::analytics::{AnalyticsFilter, DimensionFilter, DimensionFilterOp};
+
+use crate::queries::bot_filters::append_event_bot_filter;
+use crate::queries::property_filters::append_event_property_filters;
+
+/// Append the bot, dimension and `event_data` property predicates of
+/// `filter` for an events relation whose columns are qualified with
+/// `column_prefix` (e.g. `"e."`).
+pub fn append_event_filters(
+    filter: &AnalyticsFilter,
+    column_prefix: &str,
+    filter_sql: &mut String,
+    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
+    param_idx: &mut usize,
+) {
+    append_event_bot_filter(filter_sql, filter.include_bots, column_prefix);
+
+    let p = column_prefix;
+    let dimensions = [
+        (&filter.filter_country, format!("{p}country")),
+        (&filter.filter_page, format!("{p}url")),
+        (&filter.filter_referrer, format!("{p}referrer_domain")),
+        (&filter.filter_browser, format!("{p}browser")),
+        (&filter.filter_os, format!("{p}os")),
+        (&filter.filter_device, format!("{p}device_type")),
+        (&filter.filter_language, format!("{p}language")),
+        (&filter.filter_utm_source, format!("{p}utm_source")),
+        (&filter.filter_utm_medium, format!("{p}utm_medium")),
+        (&filter.filter_utm_campaign, format!("{p}utm_campaign")),
+        (&filter.filter_region, format!("{p}region")),
+        (&filter.filter_city, format!("{p}city")),
+    ];
+    for (dimension, column_expr) in dimensions {
+        if let Some(dimension) = dimension {
+            append_dimension_filter(
+                filter_sql,
+                params,
+                param_idx,
+                &column_expr,
+                dimension,
+                false,
+            );
+        }
+    }
+    if let Some(ref hostname) = filter.filter_hostname {
+        // Host compared case-insensitively with any port stripped from both
+        // the URL and exact-match values, so `localhost:3000` and `localhost`
+        // select the same events.
+        let column_expr = format!(
+            "lower(regexp_replace(regexp_extract({p}url, '^https?://([^/?#]+)', 1), ':[0-9]+$', ''))"
+        );
+        let hostname = match hostname.op {
+            DimensionFilterOp::Is | DimensionFilterOp::IsNot | DimensionFilterOp::In => {
+                DimensionFilter {
+                    op: hostname.op,
+                    values: hostname
+                        .values
+                        .iter()
+                        .map(|v| strip_port(v).to_string())
+                        .collect(),
+                }
+            }
+            _ => hostname.clone(),
+        };
+        append_dimension_filter(filter_sql, params, param_idx, &column_expr, &hostname, true);
+    }
+
+    append_event_property_filters(
+        filter_sql,
+        params,
+        param_idx,
+        &filter.filter_properties,
+        column_prefix,
+    );
+}
+
+/// `host:port` -> `host`; hosts without a numeric port are returned as-is.
+fn strip_port(host: &str) -> &str {
+    match host.rsplit_once(':') {
+        Some((host, port))
+            if !host.is_empty() && !port.is_empty() && port.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            host
+        }
+        _ => host,
+    }
+}
+
+/// Append one dimension predicate. Multiple values are OR-ed for the positive
+/// operators and NOR-ed for the negated ones, so `is` with several values is
+/// equivalent to `in`.
+fn append_dimension_filter(
+    filter_sql: &mut String,
+    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
+    param_idx: &mut usize,
+    column_expr: &str,
+    filter: &DimensionFilter,
+    lowercase_values: bool,
+) {
+    let mut bind = |value: &str| {
+        let value = if lowercase_values && filter.op != DimensionFilterOp::Regex {
+            value.to_lowercase()
+        } else {
+            value.to_string()
+        };
+        params.push(Box::new(value));
+        let placeholder = format!("?{}", *param_idx);
+        *param_idx += 1;
+        placeholder
+    };
+
+    let matches: Vec<String> = match filter.op {
+        DimensionFilterOp::Is | DimensionFilterOp::IsNot | DimensionFilterOp::In => {
+            let placeholders: Vec<String> = filter.values.iter().map(|v| bind(v)).collect();
+            if placeholders.is_empty() {
+                Vec::new()
+            } else {
+                vec![format!("{column_expr} IN ({})", placeholders.join(", "))]
+            }
+        }
+        DimensionFilterOp::Contains | DimensionFilterOp::NotContains => filter
+            .values
+            .iter()
+            .map(|v| format!("position({} in {column_expr}) > 0", bind(v)))
+            .collect(),
+        DimensionFilterOp::Regex => filter
+            .values
+            .iter()
+            .map(|v| format!("regexp_matches({column_expr}, {})", bind(v)))
+            .collect(),
+    };
+
+    let negated = matches!(
+        filter.op,
+        DimensionFilterOp::IsNot | DimensionFilterOp::NotContains
+    );
+    match (matches.is_empty(), negated) {
+        (true, true) => {}
+        (true, false) => filter_sql.push_str(" AND FALSE"),
+        (false, false) => filter_sql.push_str(&format!(" AND ({})", matches.join(" OR "))),
+        (false, true) => filter_sql.push_str(&format!(
+            " AND ({column_expr} IS NULL OR NOT ({}))",
+            matches.join(" OR ")
+        )),
+    }
+}
@@ -8,7 +8,8 @@ use sparklytics_core::analytics::{
     TimeseriesPoint, TimeseriesResult,
 };
 
-use crate::queries::bot_filters::append_event_bot_filter;
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
+use crate::queries::event_filters::append_event_filters;
 use crate::DuckDbBackend;
 
 use super::timeseries::auto_granularity;
@@ -17,100 +18,24 @@ const EVENT_NAMES_LIMIT: i64 = 200;
 const EVENT_PROPERTIES_LIMIT: i64 = 500;
 const EVENT_PROPERTIES_SAMPLE_LIMIT: i64 = 10_000;
 
-fn append_dimension_filters(
-    filter: &AnalyticsFilter,
-    column_prefix: &str,
-    filter_sql: &mut String,
-    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
-    param_idx: &mut usize,
-) {
-    append_event_bot_filter(filter_sql, filter.include_bots, column_prefix);
-    if let Some(ref country) = filter.filter_country {
-        filter_sql.push_str(&format!(" AND {column_prefix}country = ?{}", *param_idx));
-        params.push(Box::new(country.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref page) = filter.filter_page {
-        filter_sql.push_str(&format!(" AND {column_prefix}url LIKE ?{}", *param_idx));
-        params.push(Box::new(format!("%{}%", page)));
-        *param_idx += 1;
-    }
-    if let Some(ref referrer) = filter.filter_referrer {
-        filter_sql.push_str(&format!(
-            " AND {column_prefix}referrer_domain = ?{}",
-            *param_idx
-        ));
-        params.push(Box::new(referrer.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref browser) = filter.filter_browser {
-        filter_sql.push_str(&format!(" AND {column_prefix}browser = ?{}", *param_idx));
-        params.push(Box::new(browser.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref os) = filter.filter_os {
-        filter_sql.push_str(&format!(" AND {column_prefix}os = ?{}", *param_idx));
-        params.push(Box::new(os.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref device) = filter.filter_device {
-        filter_sql.push_str(&format!(
-            " AND {column_prefix}device_type = ?{}",
-            *param_idx
-        ));
-        params.push(Box::new(device.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref language) = filter.filter_language {
-        filter_sql.push_str(&format!(" AND {column_prefix}language = ?{}", *param_idx));
-        params.push(Box::new(language.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_source) = filter.filter_utm_source {
-        filter_sql.push_str(&format!(" AND {column_prefix}utm_source = ?{}", *param_idx));
-        params.push(Box::new(utm_source.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_medium) = filter.filter_utm_medium {
-        filter_sql.push_str(&format!(" AND {column_prefix}utm_medium = ?{}", *param_idx));
-        params.push(Box::new(utm_medium.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_campaign) = filter.filter_utm_campaign {
-        filter_sql.push_str(&format!(
-            " AND {column_prefix}utm_campaign = ?{}",
-            *param_idx
-        ));
-        params.push(Box::new(utm_campaign.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref region) = filter.filter_region {
-        filter_sql.push_str(&format!(" AND {column_prefix}region = ?{}", *param_idx));
-        params.push(Box::new(region.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref city) = filter.filter_city {
-        filter_sql.push_str(&format!(" AND {column_prefix}city = ?{}", *param_idx));
-        params.push(Box::new(city.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref hostname) = filter.filter_hostname {
-        filter_sql.push_str(&format!(
-            " AND lower(regexp_extract({column_prefix}url, '^https?://([^/?#]+)', 1)) = lower(?{})",
-            *param_idx
-        ));
-        params.push(Box::new(hostname.clone()));
-        *param_idx += 1;
-    }
-}
-
 pub async fn get_event_names_inner(
     db: &DuckDbBackend,
     website_id: &str,
     filter: &AnalyticsFilter,
 ) -> Result<EventNamesResult> {
-    let conn = db.conn.lock().await;
+    let website_id = website_id.to_string();
+    let filter = filter.clone();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        event_names_with_conn(conn, &website_id, &filter)
+    })
+    .await
+}
 
+fn event_names_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    filter: &AnalyticsFilter,
+) -> Result<EventNamesResult> {
     let start_str = filter.start_date.format("%Y-%m-%d").to_string();
     let end_str = (filter.end_date + Duration::days(1))
         .format("%Y-%m-%d")
@@ -128,7 +53,7 @@ pub async fn get_event_names_inner(
         Box::new(end_str.clone()),
     ];
     let mut total_param_idx = 4;
-    append_dimension_filters(
+    append_event_filters(
         filter,
         "e.",
         &mut total_filter_sql,
@@ -163,14 +88,14 @@ pub async fn get_event_names_inner(
         Box::new(prev_end_str),
     ];
     let mut rows_param_idx = 7;
-    append_dimension_filters(
+    append_event_filters(
         filter,
         "e.",
         &mut current_filter_sql,
         &mut rows_params,
         &mut rows_param_idx,
     );
-    append_dimension_filters(
+    append_event_filters(
         filter,
         "e.",
         &mut previous_filter_sql,
@@ -245,8 +170,21 @@ pub async fn get_event_properties_inner(
     event_name: &str,
     filter: &AnalyticsFilter,
 ) -> Result<EventPropertiesResult> {
-    let conn = db.conn.lock().await;
+    let website_id = website_id.to_string();
+    let event_name = event_name.to_string();
+    let filter = filter.clone();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        event_properties_with_conn(conn, &website_id, &event_name, &filter)
+    })
+    .await
+}
 
+fn event_properties_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    event_name: &str,
+    filter: &AnalyticsFilter,
+) -> Result<EventPropertiesResult> {
     let start_str = filter.start_date.format("%Y-%m-%d").to_string();
     let end_str = (filter.end_date + Duration::days(1))
         .format("%Y-%m-%d")
@@ -260,7 +198,7 @@ pub async fn get_event_properties_inner(
         Box::new(end_str.clone()),
     ];
     let mut total_param_idx = 5;
-    append_dimension_filters(
+    append_event_filters(
         filter,
         "e.",
         &mut total_filter_sql,
@@ -293,7 +231,7 @@ pub async fn get_event_properties_inner(
         Box::new(end_str),
     ];
     let mut properties_param_idx = 5;
-    append_dimension_filters(
+    append_event_filters(
         filter,
         "e.",
         &mut properties_filter_sql,
@@ -369,6 +307,29 @@ pub async fn get_event_timeseries_inner(
     event_name: &str,
     filter: &AnalyticsFilter,
     granularity: Option<&str>,
+) -> Result<TimeseriesResult> {
+    let website_id = website_id.to_string();
+    let event_name = event_name.to_string();
+    let filter = filter.clone();
+    let granularity = granularity.map(str::to_string);
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        event_timeseries_with_conn(
+            conn,
+            &website_id,
+            &event_name,
+            &filter,
+            granularity.as_deref(),
+        )
+    })
+    .await
+}
+
+fn event_timeseries_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    event_name: &str,
+    filter: &AnalyticsFilter,
+    granularity: Option<&str>,
 ) -> Result<TimeseriesResult> {
     let gran = match granularity {
         Some("hour") => "hour".to_string(),
@@ -377,8 +338,6 @@ pub async fn get_event_timeseries_inner(
         _ => auto_granularity(&filter.start_date, &filter.end_date),
     };
 
-    let conn = db.conn.lock().await;
-
     let start_str = filter.start_date.format("%Y-%m-%d").to_string();
     let end_str = (filter.end_date + Duration::days(1))
         .format("%Y-%m-%d")
@@ -392,7 +351,7 @@ pub async fn get_event_timeseries_inner(
         Box::new(end_str),
     ];
     let mut param_idx = 5;
-    append_dimension_filters(
+    append_event_filters(
         filter,
         "",
         &mut filter_sql,
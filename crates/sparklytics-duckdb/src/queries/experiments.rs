@@ -0,0 +1,439 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use rand::Rng;
+
+use sparklytics_core::analytics::{
+    CreateExperimentRequest, Experiment, ExperimentResults, ExperimentStatus,
+    UpdateExperimentRequest, EXPERIMENT_EXPOSURE_EVENT,
+};
+use sparklytics_core::experiments::{build_experiment_results, even_split_weights};
+
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
+use crate::queries::bot_filters::append_event_bot_filter;
+use crate::queries::goals::{fetch_goal, goal_match_sql};
+use crate::DuckDbBackend;
+
+const MAX_RUNNING_EXPERIMENTS_PER_WEBSITE: i64 = 10;
+
+fn generate_experiment_id() -> String {
+    let mut rng = rand::thread_rng();
+    let chars: String = (0..21)
+        .map(|_| {
+            let idx = rng.gen_range(0..36);
+            if idx < 10 {
+                (b'0' + idx) as char
+            } else {
+                (b'a' + idx - 10) as char
+            }
+        })
+        .collect();
+    format!("exp_{}", chars)
+}
+
+fn map_experiment_row(row: &duckdb::Row<'_>) -> Result<Experiment, duckdb::Error> {
+    let status_raw: String = row.get(3)?;
+    let variants_json: String = row.get(4)?;
+    let weights_json: String = row.get(5)?;
+    let planned_duration_days: i32 = row.get(7)?;
+    Ok(Experiment {
+        id: row.get(0)?,
+        website_id: row.get(1)?,
+        name: row.get(2)?,
+        status: ExperimentStatus::parse(&status_raw).map_err(|_| duckdb::Error::InvalidQuery)?,
+        variants: serde_json::from_str(&variants_json).map_err(|_| duckdb::Error::InvalidQuery)?,
+        weights: serde_json::from_str(&weights_json).map_err(|_| duckdb::Error::InvalidQuery)?,
+        goal_id: row.get(6)?,
+        planned_duration_days: u32::try_from(planned_duration_days)
+            .map_err(|_| duckdb::Error::InvalidQuery)?,
+        started_at: row.get(8)?,
+        ended_at: row.get(9)?,
+        created_at: row.get(10)?,
+        updated_at: row.get(11)?,
+    })
+}
+
+fn get_experiment_by_id(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    experiment_id: &str,
+) -> Result<Option<Experiment>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT
+            id,
+            website_id,
+            name,
+            status,
+            variants_json,
+            weights_json,
+            goal_id,
+            planned_duration_days,
+            CAST(started_at AS VARCHAR),
+            CAST(ended_at AS VARCHAR),
+            CAST(created_at AS VARCHAR),
+            CAST(updated_at AS VARCHAR)
+        FROM experiments
+        WHERE website_id = ?1 AND id = ?2
+        "#,
+    )?;
+    match stmt.query_row(
+        duckdb::params![website_id, experiment_id],
+        map_experiment_row,
+    ) {
+        Ok(experiment) => Ok(Some(experiment)),
+        Err(duckdb::Error::QueryReturnedNoRows) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn count_running(conn: &duckdb::Connection, website_id: &str) -> Result<i64> {
+    let count = conn
+        .prepare("SELECT COUNT(*) FROM experiments WHERE website_id = ?1 AND status = 'running'")?
+        .query_row(duckdb::params![website_id], |row| row.get(0))?;
+    Ok(count)
+}
+
+pub async fn list_experiments_inner(
+    db: &DuckDbBackend,
+    website_id: &str,
+) -> Result<Vec<Experiment>> {
+    let website_id = website_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT
+                id,
+                website_id,
+                name,
+                status,
+                variants_json,
+                weights_json,
+                goal_id,
+                planned_duration_days,
+                CAST(started_at AS VARCHAR),
+                CAST(ended_at AS VARCHAR),
+                CAST(created_at AS VARCHAR),
+                CAST(updated_at AS VARCHAR)
+            FROM experiments
+            WHERE website_id = ?1
+            ORDER BY created_at DESC, id DESC
+            "#,
+        )?;
+        let rows = stmt.query_map(duckdb::params![website_id], map_experiment_row)?;
+
+        let mut experiments = Vec::new();
+        for row in rows {
+            experiments.push(row?);
+        }
+        Ok(experiments)
+    })
+    .await
+}
+
+pub async fn get_experiment_inner(
+    db: &DuckDbBackend,
+    website_id: &str,
+    experiment_id: &str,
+) -> Result<Option<Experiment>> {
+    let website_id = website_id.to_string();
+    let experiment_id = experiment_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        get_experiment_by_id(conn, &website_id, &experiment_id)
+    })
+    .await
+}
+
+pub async fn count_running_experiments_inner(db: &DuckDbBackend, website_id: &str) -> Result<i64> {
+    let website_id = website_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        count_running(conn, &website_id)
+    })
+    .await
+}
+
+pub async fn create_experiment_inner(
+    db: &DuckDbBackend,
+    website_id: &str,
+    req: CreateExperimentRequest,
+) -> Result<Experiment> {
+    let conn = db.conn.lock().await;
+
+    if fetch_goal(&conn, website_id, &req.goal_id)?.is_none() {
+        return Err(anyhow!("goal_not_found"));
+    }
+
+    let duplicate_name_count: i64 = conn
+        .prepare("SELECT COUNT(*) FROM experiments WHERE website_id = ?1 AND name = ?2")?
+        .query_row(duckdb::params![website_id, &req.name], |row| row.get(0))?;
+    if duplicate_name_count > 0 {
+        return Err(anyhow!("duplicate_name"));
+    }
+
+    let weights = req
+        .weights
+        .unwrap_or_else(|| even_split_weights(req.variants.len()));
+    if weights.len() != req.variants.len() {
+        return Err(anyhow!("invalid_weights"));
+    }
+
+    let id = generate_experiment_id();
+    conn.execute(
+        r#"
+        INSERT INTO experiments (
+            id,
+            website_id,
+            name,
+            status,
+            variants_json,
+            weights_json,
+            goal_id,
+            planned_duration_days,
+            created_at,
+            updated_at
+        ) VALUES (?1, ?2, ?3, 'draft', ?4, ?5, ?6, ?7, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+        "#,
+        duckdb::params![
+            id,
+            website_id,
+            req.name,
+            serde_json::to_string(&req.variants)?,
+            serde_json::to_string(&weights)?,
+            req.goal_id,
+            req.planned_duration_days,
+        ],
+    )?;
+
+    get_experiment_by_id(&conn, website_id, &id)?.ok_or_else(|| anyhow!("insert_failed"))
+}
+
+pub async fn update_experiment_inner(
+    db: &DuckDbBackend,
+    website_id: &str,
+    experiment_id: &str,
+    req: UpdateExperimentRequest,
+) -> Result<Option<Experiment>> {
+    let conn = db.conn.lock().await;
+    let Some(existing) = get_experiment_by_id(&conn, website_id, experiment_id)? else {
+        return Ok(None);
+    };
+
+    let changes_definition = req.variants.is_some()
+        || req.weights.is_some()
+        || req.goal_id.is_some()
+        || req.planned_duration_days.is_some();
+    if changes_definition && existing.status != ExperimentStatus::Draft {
+        return Err(anyhow!("not_editable"));
+    }
+
+    let next_status = req.status.unwrap_or(existing.status);
+    if !existing.status.can_transition_to(next_status) {
+        return Err(anyhow!("invalid_status_transition"));
+    }
+    if next_status == ExperimentStatus::Running
+        && existing.status != ExperimentStatus::Running
+        && count_running(&conn, website_id)? >= MAX_RUNNING_EXPERIMENTS_PER_WEBSITE
+    {
+        return Err(anyhow!("limit_exceeded"));
+    }
+
+    let original_name = existing.name.clone();
+    let next_name = req.name.unwrap_or(existing.name);
+    if next_name != original_name {
+        let duplicate_name_count: i64 = conn
+            .prepare(
+                "SELECT COUNT(*) FROM experiments WHERE website_id = ?1 AND name = ?2 AND id != ?3",
+            )?
+            .query_row(
+                duckdb::params![website_id, &next_name, experiment_id],
+                |row| row.get(0),
+            )?;
+        if duplicate_name_count > 0 {
+            return Err(anyhow!("duplicate_name"));
+        }
+    }
+
+    let next_goal_id = req.goal_id.unwrap_or(existing.goal_id);
+    if fetch_goal(&conn, website_id, &next_goal_id)?.is_none() {
+        return Err(anyhow!("goal_not_found"));
+    }
+
+    let variants_changed = req.variants.is_some();
+    let next_variants = req.variants.unwrap_or(existing.variants);
+    let next_weights = match req.weights {
+        Some(weights) => weights,
+        None if variants_changed && existing.weights.len() != next_variants.len() => {
+            even_split_weights(next_variants.len())
+        }
+        None => existing.weights,
+    };
+    if next_weights.len() != next_variants.len() {
+        return Err(anyhow!("invalid_weights"));
+    }
+    let next_duration = req
+        .planned_duration_days
+        .unwrap_or(existing.planned_duration_days);
+
+    let starting = existing.started_at.is_none() && next_status == ExperimentStatus::Running;
+    let ending = existing.ended_at.is_none() && next_status == ExperimentStatus::Completed;
+
+    conn.execute(
+        r#"
+        UPDATE experiments
+        SET
+            name = ?1,
+            status = ?2,
+            variants_json = ?3,
+            weights_json = ?4,
+            goal_id = ?5,
+            planned_duration_days = ?6,
+            started_at = CASE WHEN ?7 THEN CURRENT_TIMESTAMP ELSE started_at END,
+            ended_at = CASE WHEN ?8 THEN CURRENT_TIMESTAMP ELSE ended_at END,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE website_id = ?9 AND id = ?10
+        "#,
+        duckdb::params![
+            next_name,
+            next_status.as_str(),
+            serde_json::to_string(&next_variants)?,
+            serde_json::to_string(&next_weights)?,
+            next_goal_id,
+            next_duration,
+            starting,
+            ending,
+            website_id,
+            experiment_id
+        ],
+    )?;
+
+    get_experiment_by_id(&conn, website_id, experiment_id)
+}
+
+pub async fn delete_experiment_inner(
+    db: &DuckDbBackend,
+    website_id: &str,
+    experiment_id: &str,
+) -> Result<bool> {
+    let conn = db.conn.lock().await;
+    let deleted = conn.execute(
+        "DELETE FROM experiments WHERE website_id = ?1 AND id = ?2",
+        duckdb::params![website_id, experiment_id],
+    )?;
+    Ok(deleted > 0)
+}
+
+/// Count exposures and conversions per variant.
+///
+/// A visitor belongs to the variant of their first exposure, so a visitor
+/// who somehow saw several variants is counted once. A conversion is any
+/// goal match by that visitor at or after the exposure, inside the
+/// experiment's running window.
+pub async fn get_experiment_results_inner(
+    db: &DuckDbBackend,
+    website_id: &str,
+    experiment_id: &str,
+    include_bots: bool,
+) -> Result<ExperimentResults> {
+    let website_id = website_id.to_string();
+    let experiment_id = experiment_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        experiment_results_with_conn(conn, &website_id, &experiment_id, include_bots)
+    })
+    .await
+}
+
+fn experiment_results_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    experiment_id: &str,
+    include_bots: bool,
+) -> Result<ExperimentResults> {
+    let experiment = get_experiment_by_id(conn, website_id, experiment_id)?
+        .ok_or_else(|| anyhow!("Experiment not found"))?;
+    let goal = fetch_goal(conn, website_id, &experiment.goal_id)?
+        .ok_or_else(|| anyhow!("Goal not found"))?;
+
+    let mut counts: HashMap<String, (i64, i64)> = HashMap::new();
+    if let Some(started_at) = experiment.started_at.clone() {
+        let window_end = experiment
+            .ended_at
+            .clone()
+            .unwrap_or_else(|| Utc::now().format("%Y-%m-%d %H:%M:%S%.f").to_string());
+
+        let mut params: Vec<Box<dyn duckdb::types::ToSql>> = vec![
+            Box::new(website_id.to_string()),
+            Box::new(started_at),
+            Box::new(window_end),
+            Box::new(EXPERIMENT_EXPOSURE_EVENT.to_string()),
+            Box::new(experiment.id.clone()),
+        ];
+        let mut bot_sql = String::new();
+        append_event_bot_filter(&mut bot_sql, include_bots, "e.");
+        let match_sql = goal_match_sql(&goal, "e.", &mut params, 6);
+
+        let sql = format!(
+            r#"
+            WITH exposure_events AS (
+                SELECT
+                    e.visitor_id,
+                    e.created_at,
+                    json_extract_string(e.event_data, '$.variant') AS variant
+                FROM events e
+                WHERE e.website_id = ?1
+                  AND e.created_at >= ?2
+                  AND e.created_at <= ?3
+                  AND e.event_type = 'event'
+                  AND e.event_name = ?4
+                  AND json_valid(e.event_data)
+                  AND json_extract_string(e.event_data, '$.experiment_id') = ?5
+                  {bot_sql}
+            ),
+            exposures AS (
+                SELECT
+                    visitor_id,
+                    arg_min(variant, created_at) AS variant,
+                    MIN(created_at) AS exposed_at
+                FROM exposure_events
+                WHERE variant IS NOT NULL
+                GROUP BY visitor_id
+            ),
+            converted AS (
+                SELECT DISTINCT x.visitor_id
+                FROM exposures x
+                JOIN events e
+                  ON e.website_id = ?1
+                 AND e.visitor_id = x.visitor_id
+                 AND e.created_at >= x.exposed_at
+                 AND e.created_at <= ?3
+                WHERE {match_sql}
+                  {bot_sql}
+            )
+            SELECT
+                x.variant,
+                COUNT(c.visitor_id) AS conversions,
+                COUNT(*) AS exposures
+            FROM exposures x
+            LEFT JOIN converted c ON c.visitor_id = x.visitor_id
+            GROUP BY x.variant
+            "#
+        );
+
+        let param_refs: Vec<&dyn duckdb::types::ToSql> =
+            params.iter().map(|p| p.as_ref()).collect();
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(param_refs.as_slice(), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?,
+            ))
+        })?;
+        for row in rows {
+            let (variant, conversions, exposures) = row?;
+            counts.insert(variant, (conversions, exposures));
+        }
+    }
+
+    Ok(build_experiment_results(&experiment, &counts, Utc::now()))
+}
@@ -1,118 +1,58 @@
+use std::time::Duration;
+
 use anyhow::{anyhow, Result};
 use chrono::{LocalResult, TimeZone};
 use chrono_tz::Tz;
 
 use sparklytics_core::analytics::{
-    AnalyticsFilter, Funnel, FunnelResults, FunnelStep, FunnelStepResult, MatchOperator, StepType,
+    AnalyticsFilter, Funnel, FunnelBreakdown, FunnelBreakdownSegment, FunnelOrdering,
+    FunnelResults, FunnelScope, FunnelStep, FunnelStepResult, FunnelStepTiming, FunnelTimeBucket,
+    MatchOperator, StepType, FUNNEL_TIME_BUCKET_BOUNDS_SECONDS, MAX_FUNNEL_BREAKDOWN_SEGMENTS,
 };
 
-use crate::queries::bot_filters::append_event_bot_filter;
+use crate::queries::event_filters::append_event_filters;
+use crate::queries::metrics::metric_column_expr;
+use crate::queries::property_filters::append_event_property_filters;
 use crate::DuckDbBackend;
 
 use super::funnels::get_funnel_inner;
 
-fn append_event_filters(
-    filter: &AnalyticsFilter,
-    filter_sql: &mut String,
+const FUNNEL_RESULTS_QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// SQL predicate for one step against the `scoped_events` alias `e`, pushing
+/// its bound values (match value, then any property constraints).
+fn step_condition_sql(
+    step: &FunnelStep,
     params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
     param_idx: &mut usize,
-) {
-    append_event_bot_filter(filter_sql, filter.include_bots, "e.");
-    if let Some(ref country) = filter.filter_country {
-        filter_sql.push_str(&format!(" AND e.country = ?{}", *param_idx));
-        params.push(Box::new(country.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref page) = filter.filter_page {
-        filter_sql.push_str(&format!(" AND position(?{} in e.url) > 0", *param_idx));
-        params.push(Box::new(page.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref referrer) = filter.filter_referrer {
-        filter_sql.push_str(&format!(" AND e.referrer_domain = ?{}", *param_idx));
-        params.push(Box::new(referrer.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref browser) = filter.filter_browser {
-        filter_sql.push_str(&format!(" AND e.browser = ?{}", *param_idx));
-        params.push(Box::new(browser.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref os) = filter.filter_os {
-        filter_sql.push_str(&format!(" AND e.os = ?{}", *param_idx));
-        params.push(Box::new(os.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref device) = filter.filter_device {
-        filter_sql.push_str(&format!(" AND e.device_type = ?{}", *param_idx));
-        params.push(Box::new(device.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref language) = filter.filter_language {
-        filter_sql.push_str(&format!(" AND e.language = ?{}", *param_idx));
-        params.push(Box::new(language.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_source) = filter.filter_utm_source {
-        filter_sql.push_str(&format!(" AND e.utm_source = ?{}", *param_idx));
-        params.push(Box::new(utm_source.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_medium) = filter.filter_utm_medium {
-        filter_sql.push_str(&format!(" AND e.utm_medium = ?{}", *param_idx));
-        params.push(Box::new(utm_medium.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_campaign) = filter.filter_utm_campaign {
-        filter_sql.push_str(&format!(" AND e.utm_campaign = ?{}", *param_idx));
-        params.push(Box::new(utm_campaign.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref region) = filter.filter_region {
-        filter_sql.push_str(&format!(" AND e.region = ?{}", *param_idx));
-        params.push(Box::new(region.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref city) = filter.filter_city {
-        filter_sql.push_str(&format!(" AND e.city = ?{}", *param_idx));
-        params.push(Box::new(city.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref hostname) = filter.filter_hostname {
-        filter_sql.push_str(&format!(
-            " AND lower(regexp_extract(e.url, '^https?://([^/:?#]+)', 1)) = lower(?{})",
+) -> String {
+    let mut condition = match (&step.step_type, &step.match_operator) {
+        (StepType::PageView, MatchOperator::Equals) => {
+            format!("e.event_type = 'pageview' AND e.url = ?{}", *param_idx)
+        }
+        (StepType::PageView, MatchOperator::Contains) => format!(
+            "e.event_type = 'pageview' AND position(?{} in e.url) > 0",
             *param_idx
-        ));
-        params.push(Box::new(hostname.clone()));
-        *param_idx += 1;
-    }
-}
-
-fn step_condition_sql(step: &FunnelStep, param_idx: usize) -> (String, String) {
-    match (&step.step_type, &step.match_operator) {
-        (StepType::PageView, MatchOperator::Equals) => (
-            format!("e.event_type = 'pageview' AND e.url = ?{}", param_idx),
-            step.match_value.clone(),
         ),
-        (StepType::PageView, MatchOperator::Contains) => (
-            format!(
-                "e.event_type = 'pageview' AND position(?{} in e.url) > 0",
-                param_idx
-            ),
-            step.match_value.clone(),
-        ),
-        (StepType::Event, MatchOperator::Equals) => (
-            format!("e.event_type = 'event' AND e.event_name = ?{}", param_idx),
-            step.match_value.clone(),
-        ),
-        (StepType::Event, MatchOperator::Contains) => (
-            format!(
-                "e.event_type = 'event' AND position(?{} in e.event_name) > 0",
-                param_idx
-            ),
-            step.match_value.clone(),
+        (StepType::Event, MatchOperator::Equals) => {
+            format!("e.event_type = 'event' AND e.event_name = ?{}", *param_idx)
+        }
+        (StepType::Event, MatchOperator::Contains) => format!(
+            "e.event_type = 'event' AND position(?{} in e.event_name) > 0",
+            *param_idx
         ),
-    }
+    };
+    params.push(Box::new(step.match_value.clone()));
+    *param_idx += 1;
+
+    append_event_property_filters(
+        &mut condition,
+        params,
+        param_idx,
+        &step.property_filters,
+        "e.",
+    );
+    condition
 }
 
 fn resolve_timezone(
@@ -166,21 +106,49 @@ fn utc_bounds_for_filter(
     ))
 }
 
-pub(crate) fn build_funnel_query(
-    steps: &[FunnelStep],
+/// CTEs shared by the count, timing and breakdown queries.
+struct FunnelCtes {
+    ctes: Vec<String>,
+    conditions: Vec<String>,
+    next_param_idx: usize,
+}
+
+/// Strict funnels chain one CTE per step, each matching the earliest event
+/// after the previous step. With a conversion window every step-1 match is
+/// kept as a candidate start so a later attempt can still convert when an
+/// earlier one timed out. Any-order funnels take each step's first
+/// occurrence per actor and require steps 1..=N to all be present; with a
+/// window, every event matching a step starts an attempt that only sees the
+/// occurrences inside its window.
+///
+/// `segment_expr` adds a `segment` column to `scoped_events` for breakdowns.
+fn build_funnel_ctes(
+    funnel: &Funnel,
     filter_sql: &str,
+    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
     first_match_param_idx: usize,
-) -> (String, usize) {
+    segment_expr: Option<&str>,
+) -> FunnelCtes {
+    let actor_column = match funnel.scope {
+        FunnelScope::Session => "e.session_id",
+        FunnelScope::Visitor => "e.visitor_id",
+    };
+    let segment_column = segment_expr
+        .map(|expr| {
+            format!(",\n                COALESCE(CAST({expr} AS VARCHAR), '(none)') AS segment")
+        })
+        .unwrap_or_default();
     let mut ctes = Vec::new();
     ctes.push(format!(
         r#"
         scoped_events AS (
             SELECT
-                e.session_id,
+                {actor_column} AS actor_id,
                 e.created_at,
                 e.event_type,
                 e.url,
-                e.event_name
+                e.event_name,
+                e.event_data{segment_column}
             FROM events e
             WHERE e.website_id = ?1
               AND e.created_at >= ?2
@@ -191,49 +159,380 @@ pub(crate) fn build_funnel_query(
     ));
 
     let mut param_idx = first_match_param_idx;
-    for (idx, step) in steps.iter().enumerate() {
-        let step_num = idx + 1;
-        let prev_step_num = idx;
-        let (condition, _) = step_condition_sql(step, param_idx);
-        param_idx += 1;
+    let conditions: Vec<String> = funnel
+        .steps
+        .iter()
+        .map(|step| step_condition_sql(step, params, &mut param_idx))
+        .collect();
+    let window = funnel.conversion_window_minutes;
+
+    match funnel.ordering {
+        FunnelOrdering::Strict => {
+            for (idx, condition) in conditions.iter().enumerate() {
+                let step_num = idx + 1;
+                let prev_step_num = idx;
+                let cte = match (step_num, window) {
+                    (1, None) => format!(
+                        r#"
+                        step_{step_num} AS (
+                            SELECT e.actor_id, MIN(e.created_at) AS matched_at
+                            FROM scoped_events e
+                            WHERE {condition}
+                            GROUP BY e.actor_id
+                        )
+                        "#
+                    ),
+                    (_, None) => format!(
+                        r#"
+                        step_{step_num} AS (
+                            SELECT e.actor_id, MIN(e.created_at) AS matched_at
+                            FROM scoped_events e
+                            JOIN step_{prev_step_num} prev ON prev.actor_id = e.actor_id
+                            WHERE e.created_at > prev.matched_at
+                              AND {condition}
+                            GROUP BY e.actor_id
+                        )
+                        "#
+                    ),
+                    (1, Some(_)) => format!(
+                        r#"
+                        step_{step_num} AS (
+                            SELECT DISTINCT
+                                e.actor_id,
+                                e.created_at AS started_at,
+                                e.created_at AS matched_at
+                            FROM scoped_events e
+                            WHERE {condition}
+                        )
+                        "#
+                    ),
+                    (_, Some(minutes)) => format!(
+                        r#"
+                        step_{step_num} AS (
+                            SELECT prev.actor_id, prev.started_at, MIN(e.created_at) AS matched_at
+                            FROM scoped_events e
+                            JOIN step_{prev_step_num} prev ON prev.actor_id = e.actor_id
+                            WHERE e.created_at > prev.matched_at
+                              AND e.created_at <= prev.started_at + INTERVAL '{minutes} minutes'
+                              AND {condition}
+                            GROUP BY prev.actor_id, prev.started_at
+                        )
+                        "#
+                    ),
+                };
+                ctes.push(cte);
+            }
+        }
+        FunnelOrdering::AnyOrder => {
+            let first_seen: Vec<String> = conditions
+                .iter()
+                .enumerate()
+                .map(|(idx, condition)| {
+                    format!(
+                        "MIN(CASE WHEN {condition} THEN e.created_at END) AS s_{}",
+                        idx + 1
+                    )
+                })
+                .collect();
+            match window {
+                None => ctes.push(format!(
+                    r#"
+                    step_hits AS (
+                        SELECT e.actor_id, {}
+                        FROM scoped_events e
+                        GROUP BY e.actor_id
+                    )
+                    "#,
+                    first_seen.join(", ")
+                )),
+                Some(minutes) => {
+                    let any_step: Vec<String> =
+                        conditions.iter().map(|c| format!("({c})")).collect();
+                    ctes.push(format!(
+                        r#"
+                        attempt_starts AS (
+                            SELECT DISTINCT e.actor_id, e.created_at AS started_at
+                            FROM scoped_events e
+                            WHERE {}
+                        )
+                        "#,
+                        any_step.join(" OR ")
+                    ));
+                    ctes.push(format!(
+                        r#"
+                        step_hits AS (
+                            SELECT a.actor_id, a.started_at, {}
+                            FROM attempt_starts a
+                            JOIN scoped_events e ON e.actor_id = a.actor_id
+                            WHERE e.created_at >= a.started_at
+                              AND e.created_at <= a.started_at + INTERVAL '{minutes} minutes'
+                            GROUP BY a.actor_id, a.started_at
+                        )
+                        "#,
+                        first_seen.join(", ")
+                    ));
+                }
+            }
+        }
+    }
+
+    FunnelCtes {
+        ctes,
+        conditions,
+        next_param_idx: param_idx,
+    }
+}
 
-        let cte = if step_num == 1 {
+/// Predicate for an any-order actor (or windowed attempt) having reached
+/// `step_num`.
+fn any_order_reached_sql(step_num: usize) -> String {
+    let reached: Vec<String> = (1..=step_num)
+        .map(|j| format!("s_{j} IS NOT NULL"))
+        .collect();
+    reached.join(" AND ")
+}
+
+/// Build the step-count query for `funnel`.
+pub(crate) fn build_funnel_query(
+    funnel: &Funnel,
+    filter_sql: &str,
+    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
+    first_match_param_idx: usize,
+) -> (String, usize) {
+    let FunnelCtes {
+        ctes,
+        next_param_idx,
+        ..
+    } = build_funnel_ctes(funnel, filter_sql, params, first_match_param_idx, None);
+    let step_total = funnel.steps.len();
+
+    let count_columns: Vec<String> = match funnel.ordering {
+        FunnelOrdering::Strict => {
+            let count_expr = if funnel.conversion_window_minutes.is_some() {
+                "COUNT(DISTINCT actor_id)"
+            } else {
+                "COUNT(*)"
+            };
+            (1..=step_total)
+                .map(|i| format!("(SELECT {count_expr} FROM step_{i}) AS step_{i}_count"))
+                .collect()
+        }
+        FunnelOrdering::AnyOrder => {
+            let count_expr = if funnel.conversion_window_minutes.is_some() {
+                "COUNT(DISTINCT actor_id)"
+            } else {
+                "COUNT(*)"
+            };
+            (1..=step_total)
+                .map(|i| {
+                    format!(
+                        "(SELECT {count_expr} FROM step_hits WHERE {}) AS step_{i}_count",
+                        any_order_reached_sql(i)
+                    )
+                })
+                .collect()
+        }
+    };
+
+    let sql = format!(
+        "WITH {}\nSELECT {}",
+        ctes.join(","),
+        count_columns.join(", ")
+    );
+    (sql, next_param_idx)
+}
+
+/// One row per actor that matched step 1, with `t_N` holding the time step N
+/// was reached (NULL if it was not). For windowed strict funnels the attempt
+/// that got furthest (earliest on ties) represents the actor.
+fn funnel_actors_cte(funnel: &Funnel) -> String {
+    let step_total = funnel.steps.len();
+    match (funnel.ordering, funnel.conversion_window_minutes) {
+        (FunnelOrdering::Strict, window) => {
+            let columns: Vec<String> = (1..=step_total)
+                .map(|i| format!("s{i}.matched_at AS t_{i}"))
+                .collect();
+            let joins: Vec<String> = (2..=step_total)
+                .map(|i| {
+                    let attempt = if window.is_some() {
+                        format!(" AND s{i}.started_at = s1.started_at")
+                    } else {
+                        String::new()
+                    };
+                    format!("LEFT JOIN step_{i} s{i} ON s{i}.actor_id = s1.actor_id{attempt}")
+                })
+                .collect();
+            let qualify = if window.is_some() {
+                let depth: Vec<String> = (2..=step_total)
+                    .map(|i| format!("CAST(s{i}.matched_at IS NOT NULL AS INTEGER)"))
+                    .collect();
+                format!(
+                    "QUALIFY row_number() OVER (PARTITION BY s1.actor_id ORDER BY {} DESC, s1.started_at) = 1",
+                    depth.join(" + ")
+                )
+            } else {
+                String::new()
+            };
             format!(
                 r#"
-                step_{step_num} AS (
-                    SELECT e.session_id, MIN(e.created_at) AS matched_at
-                    FROM scoped_events e
-                    WHERE {condition}
-                    GROUP BY e.session_id
+                funnel_actors AS (
+                    SELECT s1.actor_id, {}
+                    FROM step_1 s1
+                    {}
+                    {qualify}
                 )
-                "#
+                "#,
+                columns.join(", "),
+                joins.join("\n                    ")
             )
-        } else {
+        }
+        (FunnelOrdering::AnyOrder, window) => {
+            let columns: Vec<String> = (1..=step_total)
+                .map(|i| {
+                    if i == 1 {
+                        "s_1 AS t_1".to_string()
+                    } else {
+                        let reached: Vec<String> = (1..=i).map(|j| format!("s_{j}")).collect();
+                        format!(
+                            "CASE WHEN {} THEN greatest({}) END AS t_{i}",
+                            any_order_reached_sql(i),
+                            reached.join(", ")
+                        )
+                    }
+                })
+                .collect();
+            let qualify = if window.is_some() {
+                let depth: Vec<String> = (2..=step_total)
+                    .map(|i| format!("CAST({} AS INTEGER)", any_order_reached_sql(i)))
+                    .collect();
+                format!(
+                    "QUALIFY row_number() OVER (PARTITION BY actor_id ORDER BY {} DESC, started_at) = 1",
+                    depth.join(" + ")
+                )
+            } else {
+                String::new()
+            };
             format!(
                 r#"
-                step_{step_num} AS (
-                    SELECT e.session_id, MIN(e.created_at) AS matched_at
-                    FROM scoped_events e
-                    JOIN step_{prev_step_num} prev ON prev.session_id = e.session_id
-                    WHERE e.created_at > prev.matched_at
-                      AND {condition}
-                    GROUP BY e.session_id
+                funnel_actors AS (
+                    SELECT actor_id, {}
+                    FROM step_hits
+                    WHERE s_1 IS NOT NULL
+                    {qualify}
                 )
-                "#
+                "#,
+                columns.join(", ")
             )
-        };
-        ctes.push(cte);
+        }
     }
+}
 
-    let count_columns: Vec<String> = (1..=steps.len())
-        .map(|i| format!("(SELECT COUNT(*) FROM step_{i}) AS step_{i}_count"))
+/// Build the time-between-steps query: for every transition, the number of
+/// actors that made it, the median and p90 seconds, then one count per
+/// `FUNNEL_TIME_BUCKET_BOUNDS_SECONDS` bucket.
+pub(crate) fn build_funnel_timing_query(
+    funnel: &Funnel,
+    filter_sql: &str,
+    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
+    first_match_param_idx: usize,
+) -> String {
+    let FunnelCtes { mut ctes, .. } =
+        build_funnel_ctes(funnel, filter_sql, params, first_match_param_idx, None);
+    ctes.push(funnel_actors_cte(funnel));
+
+    let transitions = funnel.steps.len().saturating_sub(1);
+    let durations: Vec<String> = (1..=transitions)
+        .map(|i| {
+            format!(
+                "date_diff('millisecond', t_{i}, t_{}) / 1000.0 AS d_{i}",
+                i + 1
+            )
+        })
         .collect();
-    let sql = format!(
-        "WITH {}\nSELECT {}",
+    ctes.push(format!(
+        r#"
+        transitions AS (
+            SELECT {}
+            FROM funnel_actors
+        )
+        "#,
+        durations.join(", ")
+    ));
+
+    let mut columns = Vec::new();
+    for i in 1..=transitions {
+        columns.push(format!("COUNT(d_{i})"));
+        columns.push(format!("quantile_cont(d_{i}, 0.5)"));
+        columns.push(format!("quantile_cont(d_{i}, 0.9)"));
+        let mut lower = 0;
+        for upper in FUNNEL_TIME_BUCKET_BOUNDS_SECONDS {
+            columns.push(format!(
+                "COUNT(*) FILTER (WHERE d_{i} >= {lower} AND d_{i} < {upper})"
+            ));
+            lower = *upper;
+        }
+        columns.push(format!("COUNT(*) FILTER (WHERE d_{i} >= {lower})"));
+    }
+
+    format!(
+        "WITH {}\nSELECT {}\nFROM transitions",
         ctes.join(","),
-        count_columns.join(", ")
+        columns.join(", ")
+    )
+}
+
+/// Build the per-segment step-count query, one row per dimension value
+/// (largest segments first).
+pub(crate) fn build_funnel_breakdown_query(
+    funnel: &Funnel,
+    filter_sql: &str,
+    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
+    first_match_param_idx: usize,
+    segment_expr: &str,
+) -> String {
+    let FunnelCtes {
+        mut ctes,
+        conditions,
+        ..
+    } = build_funnel_ctes(
+        funnel,
+        filter_sql,
+        params,
+        first_match_param_idx,
+        Some(segment_expr),
     );
-    (sql, param_idx)
+    ctes.push(funnel_actors_cte(funnel));
+    // Condition placeholders are numbered, so the step-1 predicate can be
+    // reused here without binding its values again.
+    let entry_condition = conditions
+        .first()
+        .cloned()
+        .unwrap_or_else(|| "FALSE".to_string());
+    ctes.push(format!(
+        r#"
+        actor_segments AS (
+            SELECT e.actor_id, arg_min(e.segment, e.created_at) AS segment
+            FROM scoped_events e
+            WHERE {entry_condition}
+            GROUP BY e.actor_id
+        )
+        "#
+    ));
+
+    let counts: Vec<String> = (1..=funnel.steps.len())
+        .map(|i| format!("COUNT(fa.t_{i})"))
+        .collect();
+    format!(
+        r#"WITH {}
+SELECT seg.segment, {}
+FROM funnel_actors fa
+JOIN actor_segments seg ON seg.actor_id = fa.actor_id
+GROUP BY seg.segment
+ORDER BY COUNT(fa.t_1) DESC, seg.segment
+LIMIT {MAX_FUNNEL_BREAKDOWN_SEGMENTS}"#,
+        ctes.join(","),
+        counts.join(", ")
+    )
 }
 
 pub(crate) fn compute_funnel_results(funnel: &Funnel, step_counts: &[i64]) -> FunnelResults {
@@ -296,14 +595,55 @@ pub(crate) fn compute_funnel_results(funnel: &Funnel, step_counts: &[i64]) -> Fu
         total_sessions_entered: total_entered,
         final_conversion_rate,
         steps,
+        step_timings: Vec::new(),
+        breakdown: None,
     }
 }
 
+/// Decode one row of `build_funnel_timing_query`.
+fn read_step_timings(
+    funnel: &Funnel,
+    row: &duckdb::Row<'_>,
+) -> duckdb::Result<Vec<FunnelStepTiming>> {
+    let columns_per_transition = 3 + FUNNEL_TIME_BUCKET_BOUNDS_SECONDS.len() + 1;
+    let mut timings = Vec::with_capacity(funnel.steps.len().saturating_sub(1));
+    for (idx, pair) in funnel.steps.windows(2).enumerate() {
+        let base = idx * columns_per_transition;
+        let mut histogram = Vec::with_capacity(FUNNEL_TIME_BUCKET_BOUNDS_SECONDS.len() + 1);
+        let mut lower = 0;
+        for (bucket, upper) in FUNNEL_TIME_BUCKET_BOUNDS_SECONDS
+            .iter()
+            .map(Some)
+            .chain(std::iter::once(None))
+            .enumerate()
+        {
+            histogram.push(FunnelTimeBucket {
+                min_seconds: lower,
+                max_seconds: upper.copied(),
+                count: row.get(base + 3 + bucket)?,
+            });
+            if let Some(upper) = upper {
+                lower = *upper;
+            }
+        }
+        timings.push(FunnelStepTiming {
+            from_step_order: pair[0].step_order,
+            to_step_order: pair[1].step_order,
+            converted: row.get(base)?,
+            median_seconds: row.get(base + 1)?,
+            p90_seconds: row.get(base + 2)?,
+            histogram,
+        });
+    }
+    Ok(timings)
+}
+
 pub async fn get_funnel_results_inner(
     db: &DuckDbBackend,
     website_id: &str,
     funnel_id: &str,
     filter: &AnalyticsFilter,
+    breakdown: Option<&str>,
 ) -> Result<FunnelResults> {
     let funnel = get_funnel_inner(db, website_id, funnel_id)
         .await?
@@ -312,65 +652,140 @@ pub async fn get_funnel_results_inner(
         return Err(anyhow!("funnel has no steps"));
     }
 
-    let conn = db.conn.lock().await;
-    let tz = resolve_timezone(&conn, website_id, filter.timezone.as_deref())?;
-    let (start_str, end_str) = utc_bounds_for_filter(tz, filter.start_date, filter.end_date)?;
+    let segment_expr = match breakdown {
+        Some(dimension) => {
+            Some(metric_column_expr(dimension).ok_or_else(|| anyhow!("invalid_breakdown"))?)
+        }
+        None => None,
+    };
 
-    let mut params: Vec<Box<dyn duckdb::types::ToSql>> = vec![
-        Box::new(website_id.to_string()),
-        Box::new(start_str),
-        Box::new(end_str),
-    ];
-    let mut param_idx = 4;
-    let mut filter_sql = String::new();
-    append_event_filters(filter, &mut filter_sql, &mut params, &mut param_idx);
-
-    let (sql, _next_param_idx) = build_funnel_query(&funnel.steps, &filter_sql, param_idx);
-    for step in &funnel.steps {
-        let (_, value) = step_condition_sql(step, param_idx);
-        params.push(Box::new(value));
-        param_idx += 1;
-    }
+    let website_id = website_id.to_string();
+    let filter = filter.clone();
+    let breakdown = breakdown.map(str::to_string);
+    db.run_read(FUNNEL_RESULTS_QUERY_TIMEOUT, move |conn| {
+        funnel_results_with_conn(
+            conn,
+            &website_id,
+            &funnel,
+            &filter,
+            breakdown.as_deref().zip(segment_expr),
+        )
+    })
+    .await
+}
 
-    let step_count_len = funnel.steps.len();
-    let param_refs: Vec<&dyn duckdb::types::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+/// Run the count, timing and optional breakdown queries for `funnel`.
+/// `breakdown` pairs the requested dimension with its column expression.
+fn funnel_results_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    funnel: &Funnel,
+    filter: &AnalyticsFilter,
+    breakdown: Option<(&str, &str)>,
+) -> Result<FunnelResults> {
+    let tz = resolve_timezone(conn, website_id, filter.timezone.as_deref())?;
+    let (start_str, end_str) = utc_bounds_for_filter(tz, filter.start_date, filter.end_date)?;
 
-    if let Err(error) = conn.execute_batch("SET statement_timeout = '5000ms'") {
-        tracing::warn!(%error, "Could not set DuckDB statement_timeout");
-    }
+    // Each query binds its own copy of the shared range and filter params.
+    let base_params = || {
+        let mut params: Vec<Box<dyn duckdb::types::ToSql>> = vec![
+            Box::new(website_id.to_string()),
+            Box::new(start_str.clone()),
+            Box::new(end_str.clone()),
+        ];
+        let mut param_idx = 4;
+        let mut filter_sql = String::new();
+        append_event_filters(filter, "e.", &mut filter_sql, &mut params, &mut param_idx);
+        (params, filter_sql, param_idx)
+    };
 
-    let step_counts_res: Result<Vec<i64>> = (|| {
-        conn.prepare(&sql)?
-            .query_row(param_refs.as_slice(), |row| {
-                let mut counts = Vec::with_capacity(step_count_len);
-                for idx in 0..step_count_len {
-                    counts.push(row.get::<usize, i64>(idx)?);
-                }
-                Ok(counts)
-            })
-            .map_err(Into::into)
-    })();
+    let (mut params, filter_sql, param_idx) = base_params();
+    let (sql, _next_param_idx) = build_funnel_query(funnel, &filter_sql, &mut params, param_idx);
+    let step_count_len = funnel.steps.len();
 
-    if let Err(error) = conn.execute_batch("RESET statement_timeout") {
-        tracing::warn!(%error, "Could not reset DuckDB statement_timeout");
+    let param_refs: Vec<&dyn duckdb::types::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let step_counts = conn
+        .prepare(&sql)?
+        .query_row(param_refs.as_slice(), |row| {
+            let mut counts = Vec::with_capacity(step_count_len);
+            for idx in 0..step_count_len {
+                counts.push(row.get::<usize, i64>(idx)?);
+            }
+            Ok(counts)
+        })?;
+
+    let (mut timing_params, _, _) = base_params();
+    let timing_sql = build_funnel_timing_query(funnel, &filter_sql, &mut timing_params, param_idx);
+    let timing_refs: Vec<&dyn duckdb::types::ToSql> =
+        timing_params.iter().map(|p| p.as_ref()).collect();
+    let step_timings = conn
+        .prepare(&timing_sql)?
+        .query_row(timing_refs.as_slice(), |row| read_step_timings(funnel, row))?;
+
+    let mut results = compute_funnel_results(funnel, &step_counts);
+    results.step_timings = step_timings;
+
+    if let Some((dimension, segment_expr)) = breakdown {
+        let (mut breakdown_params, _, _) = base_params();
+        let breakdown_sql = build_funnel_breakdown_query(
+            funnel,
+            &filter_sql,
+            &mut breakdown_params,
+            param_idx,
+            segment_expr,
+        );
+        let breakdown_refs: Vec<&dyn duckdb::types::ToSql> =
+            breakdown_params.iter().map(|p| p.as_ref()).collect();
+        let mut stmt = conn.prepare(&breakdown_sql)?;
+        let rows = stmt.query_map(breakdown_refs.as_slice(), |row| {
+            let value: String = row.get(0)?;
+            let mut counts = Vec::with_capacity(step_count_len);
+            for idx in 0..step_count_len {
+                counts.push(row.get::<usize, i64>(idx + 1)?);
+            }
+            Ok((value, counts))
+        })?;
+
+        let mut segments = Vec::new();
+        for row in rows {
+            let (value, counts) = row?;
+            let segment = compute_funnel_results(funnel, &counts);
+            segments.push(FunnelBreakdownSegment {
+                value,
+                total_sessions_entered: segment.total_sessions_entered,
+                final_conversion_rate: segment.final_conversion_rate,
+                steps: segment.steps,
+            });
+        }
+        results.breakdown = Some(FunnelBreakdown {
+            dimension: dimension.to_string(),
+            segments,
+        });
     }
 
-    let step_counts = step_counts_res?;
-
-    Ok(compute_funnel_results(&funnel, &step_counts))
+    Ok(results)
 }
 
 #[cfg(test)]
 mod tests {
-    use sparklytics_core::analytics::{Funnel, FunnelStep, MatchOperator, StepType};
+    use sparklytics_core::analytics::{
+        Funnel, FunnelOrdering, FunnelScope, FunnelStep, MatchOperator, PropertyFilter,
+        PropertyFilterOp, StepType,
+    };
 
-    use super::{build_funnel_query, compute_funnel_results};
+    use super::{
+        build_funnel_breakdown_query, build_funnel_query, build_funnel_timing_query,
+        compute_funnel_results,
+    };
 
     fn sample_funnel() -> Funnel {
         Funnel {
             id: "fun_1".to_string(),
             website_id: "site_1".to_string(),
             name: "Signup".to_string(),
+            conversion_window_minutes: None,
+            scope: FunnelScope::Session,
+            ordering: FunnelOrdering::Strict,
             steps: vec![
                 FunnelStep {
                     id: "fstep_1".to_string(),
@@ -380,6 +795,7 @@ mod tests {
                     match_value: "/pricing".to_string(),
                     match_operator: MatchOperator::Equals,
                     label: "Pricing".to_string(),
+                    property_filters: Vec::new(),
                     created_at: "2026-01-01 00:00:00".to_string(),
                 },
                 FunnelStep {
@@ -390,6 +806,7 @@ mod tests {
                     match_value: "signup_completed".to_string(),
                     match_operator: MatchOperator::Equals,
                     label: "Signup".to_string(),
+                    property_filters: Vec::new(),
                     created_at: "2026-01-01 00:00:00".to_string(),
                 },
             ],
@@ -401,11 +818,86 @@ mod tests {
     #[test]
     fn build_funnel_query_contains_step_ctes() {
         let funnel = sample_funnel();
-        let (sql, next_idx) = build_funnel_query(&funnel.steps, "", 4);
+        let mut params: Vec<Box<dyn duckdb::types::ToSql>> = Vec::new();
+        let (sql, next_idx) = build_funnel_query(&funnel, "", &mut params, 4);
         assert!(sql.contains("step_1 AS"));
         assert!(sql.contains("step_2 AS"));
         assert!(sql.contains("(SELECT COUNT(*) FROM step_2)"));
         assert_eq!(next_idx, 6);
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn build_funnel_query_windowed_visitor_scope_keeps_every_start() {
+        let mut funnel = sample_funnel();
+        funnel.scope = FunnelScope::Visitor;
+        funnel.conversion_window_minutes = Some(30);
+        let mut params: Vec<Box<dyn duckdb::types::ToSql>> = Vec::new();
+        let (sql, _) = build_funnel_query(&funnel, "", &mut params, 4);
+        assert!(sql.contains("e.visitor_id AS actor_id"));
+        assert!(sql.contains("SELECT DISTINCT"));
+        assert!(sql.contains("prev.started_at + INTERVAL '30 minutes'"));
+        assert!(sql.contains("(SELECT COUNT(DISTINCT actor_id) FROM step_2)"));
+    }
+
+    #[test]
+    fn build_funnel_query_any_order_uses_first_occurrence_per_step() {
+        let mut funnel = sample_funnel();
+        funnel.ordering = FunnelOrdering::AnyOrder;
+        funnel.steps[1].property_filters = vec![PropertyFilter {
+            key: "plan".to_string(),
+            op: PropertyFilterOp::Eq,
+            value: Some("pro".to_string()),
+            values: Vec::new(),
+        }];
+        let mut params: Vec<Box<dyn duckdb::types::ToSql>> = Vec::new();
+        let (sql, next_idx) = build_funnel_query(&funnel, "", &mut params, 4);
+        assert!(sql.contains("step_hits AS"));
+        assert!(sql.contains("AS s_2"));
+        assert!(sql.contains("s_1 IS NOT NULL AND s_2 IS NOT NULL"));
+        assert!(sql.contains("json_extract_string(e.event_data, ?6) END) = ?7"));
+        assert_eq!(next_idx, 8);
+        assert_eq!(params.len(), 4);
+    }
+
+    #[test]
+    fn build_funnel_query_windowed_any_order_checks_each_attempt() {
+        let mut funnel = sample_funnel();
+        funnel.ordering = FunnelOrdering::AnyOrder;
+        funnel.conversion_window_minutes = Some(30);
+        let mut params: Vec<Box<dyn duckdb::types::ToSql>> = Vec::new();
+        let (sql, _) = build_funnel_query(&funnel, "", &mut params, 4);
+        assert!(sql.contains("attempt_starts AS"));
+        assert!(sql.contains("e.created_at <= a.started_at + INTERVAL '30 minutes'"));
+        assert!(sql.contains("(SELECT COUNT(DISTINCT actor_id) FROM step_hits WHERE"));
+        assert!(!sql.contains("greatest("));
+    }
+
+    #[test]
+    fn build_funnel_timing_query_has_quantiles_and_buckets_per_transition() {
+        let funnel = sample_funnel();
+        let mut params: Vec<Box<dyn duckdb::types::ToSql>> = Vec::new();
+        let sql = build_funnel_timing_query(&funnel, "", &mut params, 4);
+        assert!(sql.contains("funnel_actors AS"));
+        assert!(sql.contains("LEFT JOIN step_2 s2 ON s2.actor_id = s1.actor_id"));
+        assert!(sql.contains("date_diff('millisecond', t_1, t_2) / 1000.0 AS d_1"));
+        assert!(sql.contains("quantile_cont(d_1, 0.9)"));
+        assert!(sql.contains("COUNT(*) FILTER (WHERE d_1 >= 86400)"));
+        assert!(!sql.contains("d_2"));
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn build_funnel_breakdown_query_segments_on_step_one_match() {
+        let mut funnel = sample_funnel();
+        funnel.conversion_window_minutes = Some(60);
+        let mut params: Vec<Box<dyn duckdb::types::ToSql>> = Vec::new();
+        let sql = build_funnel_breakdown_query(&funnel, "", &mut params, 4, "e.utm_source");
+        assert!(sql.contains("COALESCE(CAST(e.utm_source AS VARCHAR), '(none)') AS segment"));
+        assert!(sql.contains("arg_min(e.segment, e.created_at)"));
+        assert!(sql.contains("QUALIFY row_number() OVER (PARTITION BY s1.actor_id"));
+        assert!(sql.contains("GROUP BY seg.segment"));
+        assert_eq!(params.len(), 2);
     }
 
     #[test]
@@ -3,10 +3,12 @@ use duckdb::Error;
 use rand::Rng;
 
 use sparklytics_core::analytics::{
-    CreateFunnelRequest, CreateFunnelStepRequest, Funnel, FunnelStep, FunnelSummary, MatchOperator,
-    StepType, UpdateFunnelRequest,
+    CreateFunnelRequest, CreateFunnelStepRequest, Funnel, FunnelOrdering, FunnelScope, FunnelStep,
+    FunnelSummary, MatchOperator, PropertyFilter, StepType, UpdateFunnelRequest,
+    MAX_FUNNEL_CONVERSION_WINDOW_MINUTES,
 };
 
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
 use crate::DuckDbBackend;
 
 const MAX_FUNNELS_PER_WEBSITE: i64 = 20;
@@ -101,6 +103,67 @@ fn validate_steps(steps: &[CreateFunnelStepRequest]) -> Result<()> {
                 return Err(anyhow!("validation_error:label"));
             }
         }
+        if !step.property_filters.is_empty() {
+            if step.step_type != StepType::Event {
+                return Err(anyhow!("validation_error:property_filters"));
+            }
+            PropertyFilter::validate_list(&step.property_filters)
+                .map_err(|_| anyhow!("validation_error:property_filters"))?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_conversion_window(minutes: u32) -> Result<()> {
+    if minutes == 0 || minutes > MAX_FUNNEL_CONVERSION_WINDOW_MINUTES {
+        return Err(anyhow!("validation_error:conversion_window_minutes"));
+    }
+    Ok(())
+}
+
+fn insert_funnel_steps(
+    tx: &duckdb::Transaction<'_>,
+    funnel_id: &str,
+    steps: &[CreateFunnelStepRequest],
+) -> Result<()> {
+    for (idx, step) in steps.iter().enumerate() {
+        let step_id = generate_funnel_step_id();
+        let match_operator = step.match_operator.clone().unwrap_or_default();
+        let label = step
+            .label
+            .clone()
+            .unwrap_or_else(|| step.match_value.clone());
+        let property_filters_json = if step.property_filters.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&step.property_filters)?)
+        };
+
+        tx.execute(
+            r#"
+            INSERT INTO funnel_steps (
+                id,
+                funnel_id,
+                step_order,
+                step_type,
+                match_value,
+                match_operator,
+                label,
+                property_filters_json,
+                created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, CURRENT_TIMESTAMP)
+            "#,
+            duckdb::params![
+                step_id,
+                funnel_id,
+                (idx + 1) as i64,
+                step_type_to_str(&step.step_type),
+                &step.match_value,
+                match_op_to_str(&match_operator),
+                label,
+                property_filters_json,
+            ],
+        )?;
     }
     Ok(())
 }
@@ -122,7 +185,8 @@ fn load_funnel_steps(conn: &duckdb::Connection, funnel_id: &str) -> Result<Vec<F
             match_value,
             match_operator,
             label,
-            CAST(created_at AS VARCHAR)
+            CAST(created_at AS VARCHAR),
+            property_filters_json
         FROM funnel_steps
         WHERE funnel_id = ?1
         ORDER BY step_order ASC
@@ -133,6 +197,11 @@ fn load_funnel_steps(conn: &duckdb::Connection, funnel_id: &str) -> Result<Vec<F
         let step_type_raw: String = row.get(3)?;
         let match_op_raw: String = row.get(5)?;
         let step_order: i64 = row.get(2)?;
+        let property_filters_json: Option<String> = row.get(8)?;
+        let property_filters = match property_filters_json {
+            Some(raw) => serde_json::from_str(&raw).map_err(|_| duckdb::Error::InvalidQuery)?,
+            None => Vec::new(),
+        };
         Ok(FunnelStep {
             id: row.get(0)?,
             funnel_id: row.get(1)?,
@@ -143,6 +212,7 @@ fn load_funnel_steps(conn: &duckdb::Connection, funnel_id: &str) -> Result<Vec<F
             match_operator: match_op_from_str(&match_op_raw)
                 .map_err(|_| duckdb::Error::InvalidQuery)?,
             label: row.get(6)?,
+            property_filters,
             created_at: row.get(7)?,
         })
     })?;
@@ -166,7 +236,10 @@ fn get_funnel_with_conn(
             website_id,
             name,
             CAST(created_at AS VARCHAR),
-            CAST(updated_at AS VARCHAR)
+            CAST(updated_at AS VARCHAR),
+            conversion_window_minutes,
+            COALESCE(scope, 'session'),
+            COALESCE(ordering, 'strict')
         FROM funnels
         WHERE website_id = ?1 AND id = ?2
         "#,
@@ -179,6 +252,9 @@ fn get_funnel_with_conn(
             row.get::<_, String>(2)?,
             row.get::<_, String>(3)?,
             row.get::<_, String>(4)?,
+            row.get::<_, Option<i64>>(5)?,
+            row.get::<_, String>(6)?,
+            row.get::<_, String>(7)?,
         ))
     }) {
         Ok(row) => Some(row),
@@ -186,7 +262,7 @@ fn get_funnel_with_conn(
         Err(error) => return Err(error.into()),
     };
 
-    let Some((id, website_id, name, created_at, updated_at)) = row else {
+    let Some((id, website_id, name, created_at, updated_at, window, scope, ordering)) = row else {
         return Ok(None);
     };
 
@@ -195,6 +271,9 @@ fn get_funnel_with_conn(
         id,
         website_id,
         name,
+        conversion_window_minutes: window.map(|minutes| minutes as u32),
+        scope: FunnelScope::parse(&scope)?,
+        ordering: FunnelOrdering::parse(&ordering)?,
         steps,
         created_at,
         updated_at,
@@ -205,41 +284,44 @@ pub async fn list_funnels_inner(
     db: &DuckDbBackend,
     website_id: &str,
 ) -> Result<Vec<FunnelSummary>> {
-    let conn = db.conn.lock().await;
-    let mut stmt = conn.prepare(
-        r#"
-        SELECT
-            f.id,
-            f.website_id,
-            f.name,
-            CAST(COUNT(fs.id) AS BIGINT) AS step_count,
-            CAST(f.created_at AS VARCHAR),
-            CAST(f.updated_at AS VARCHAR)
-        FROM funnels f
-        LEFT JOIN funnel_steps fs ON fs.funnel_id = f.id
-        WHERE f.website_id = ?1
-        GROUP BY f.id, f.website_id, f.name, f.created_at, f.updated_at
-        ORDER BY f.created_at DESC, f.id DESC
-        "#,
-    )?;
-
-    let rows = stmt.query_map(duckdb::params![website_id], |row| {
-        let step_count: i64 = row.get(3)?;
-        Ok(FunnelSummary {
-            id: row.get(0)?,
-            website_id: row.get(1)?,
-            name: row.get(2)?,
-            step_count: step_count as u32,
-            created_at: row.get(4)?,
-            updated_at: row.get(5)?,
-        })
-    })?;
+    let website_id = website_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT
+                f.id,
+                f.website_id,
+                f.name,
+                CAST(COUNT(fs.id) AS BIGINT) AS step_count,
+                CAST(f.created_at AS VARCHAR),
+                CAST(f.updated_at AS VARCHAR)
+            FROM funnels f
+            LEFT JOIN funnel_steps fs ON fs.funnel_id = f.id
+            WHERE f.website_id = ?1
+            GROUP BY f.id, f.website_id, f.name, f.created_at, f.updated_at
+            ORDER BY f.created_at DESC, f.id DESC
+            "#,
+        )?;
 
-    let mut funnels = Vec::new();
-    for row in rows {
-        funnels.push(row?);
-    }
-    Ok(funnels)
+        let rows = stmt.query_map(duckdb::params![website_id], |row| {
+            let step_count: i64 = row.get(3)?;
+            Ok(FunnelSummary {
+                id: row.get(0)?,
+                website_id: row.get(1)?,
+                name: row.get(2)?,
+                step_count: step_count as u32,
+                created_at: row.get(4)?,
+                updated_at: row.get(5)?,
+            })
+        })?;
+
+        let mut funnels = Vec::new();
+        for row in rows {
+            funnels.push(row?);
+        }
+        Ok(funnels)
+    })
+    .await
 }
 
 pub async fn get_funnel_inner(
@@ -247,8 +329,12 @@ pub async fn get_funnel_inner(
     website_id: &str,
     funnel_id: &str,
 ) -> Result<Option<Funnel>> {
-    let conn = db.conn.lock().await;
-    get_funnel_with_conn(&conn, website_id, funnel_id)
+    let website_id = website_id.to_string();
+    let funnel_id = funnel_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        get_funnel_with_conn(conn, &website_id, &funnel_id)
+    })
+    .await
 }
 
 pub async fn create_funnel_inner(
@@ -258,6 +344,9 @@ pub async fn create_funnel_inner(
 ) -> Result<Funnel> {
     validate_name(&req.name)?;
     validate_steps(&req.steps)?;
+    if let Some(minutes) = req.conversion_window_minutes {
+        validate_conversion_window(minutes)?;
+    }
 
     let mut conn = db.conn.lock().await;
 
@@ -283,11 +372,21 @@ pub async fn create_funnel_inner(
             id,
             website_id,
             name,
+            conversion_window_minutes,
+            scope,
+            ordering,
             created_at,
             updated_at
-        ) VALUES (?1, ?2, ?3, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
         "#,
-        duckdb::params![&funnel_id, website_id, &req.name],
+        duckdb::params![
+            &funnel_id,
+            website_id,
+            &req.name,
+            req.conversion_window_minutes.map(i64::from),
+            req.scope.unwrap_or_default().as_str(),
+            req.ordering.unwrap_or_default().as_str(),
+        ],
     ) {
         if is_duplicate_name_constraint(&error) {
             return Err(anyhow!("duplicate_name"));
@@ -295,38 +394,7 @@ pub async fn create_funnel_inner(
         return Err(error.into());
     }
 
-    for (idx, step) in req.steps.iter().enumerate() {
-        let step_id = generate_funnel_step_id();
-        let match_operator = step.match_operator.clone().unwrap_or_default();
-        let label = step
-            .label
-            .clone()
-            .unwrap_or_else(|| step.match_value.clone());
-
-        tx.execute(
-            r#"
-            INSERT INTO funnel_steps (
-                id,
-                funnel_id,
-                step_order,
-                step_type,
-                match_value,
-                match_operator,
-                label,
-                created_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, CURRENT_TIMESTAMP)
-            "#,
-            duckdb::params![
-                step_id,
-                &funnel_id,
-                (idx + 1) as i64,
-                step_type_to_str(&step.step_type),
-                &step.match_value,
-                match_op_to_str(&match_operator),
-                label,
-            ],
-        )?;
-    }
+    insert_funnel_steps(&tx, &funnel_id, &req.steps)?;
 
     tx.commit()?;
 
@@ -340,7 +408,12 @@ pub async fn update_funnel_inner(
     funnel_id: &str,
     req: UpdateFunnelRequest,
 ) -> Result<Option<Funnel>> {
-    if req.name.is_none() && req.steps.is_none() {
+    if req.name.is_none()
+        && req.steps.is_none()
+        && req.conversion_window_minutes.is_none()
+        && req.scope.is_none()
+        && req.ordering.is_none()
+    {
         return get_funnel_inner(db, website_id, funnel_id).await;
     }
 
@@ -350,6 +423,9 @@ pub async fn update_funnel_inner(
     if let Some(steps) = &req.steps {
         validate_steps(steps)?;
     }
+    if let Some(Some(minutes)) = req.conversion_window_minutes {
+        validate_conversion_window(minutes)?;
+    }
 
     let mut conn = db.conn.lock().await;
     let exists: i64 = conn
@@ -391,43 +467,32 @@ pub async fn update_funnel_inner(
         )?;
     }
 
+    if let Some(window) = req.conversion_window_minutes {
+        tx.execute(
+            "UPDATE funnels SET conversion_window_minutes = ?1 WHERE website_id = ?2 AND id = ?3",
+            duckdb::params![window.map(i64::from), website_id, funnel_id],
+        )?;
+    }
+    if let Some(scope) = req.scope {
+        tx.execute(
+            "UPDATE funnels SET scope = ?1 WHERE website_id = ?2 AND id = ?3",
+            duckdb::params![scope.as_str(), website_id, funnel_id],
+        )?;
+    }
+    if let Some(ordering) = req.ordering {
+        tx.execute(
+            "UPDATE funnels SET ordering = ?1 WHERE website_id = ?2 AND id = ?3",
+            duckdb::params![ordering.as_str(), website_id, funnel_id],
+        )?;
+    }
+
     if let Some(steps) = &req.steps {
         tx.execute(
             "DELETE FROM funnel_steps WHERE funnel_id = ?1",
             duckdb::params![funnel_id],
         )?;
 
-        for (idx, step) in steps.iter().enumerate() {
-            let step_id = generate_funnel_step_id();
-            let match_operator = step.match_operator.clone().unwrap_or_default();
-            let label = step
-                .label
-                .clone()
-                .unwrap_or_else(|| step.match_value.clone());
-            tx.execute(
-                r#"
-                INSERT INTO funnel_steps (
-                    id,
-                    funnel_id,
-                    step_order,
-                    step_type,
-                    match_value,
-                    match_operator,
-                    label,
-                    created_at
-                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, CURRENT_TIMESTAMP)
-                "#,
-                duckdb::params![
-                    step_id,
-                    funnel_id,
-                    (idx + 1) as i64,
-                    step_type_to_str(&step.step_type),
-                    &step.match_value,
-                    match_op_to_str(&match_operator),
-                    label,
-                ],
-            )?;
-        }
+        insert_funnel_steps(&tx, funnel_id, steps)?;
     }
 
     tx.commit()?;
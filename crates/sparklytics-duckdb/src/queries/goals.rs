@@ -7,7 +7,8 @@ use sparklytics_core::analytics::{
     UpdateGoalRequest,
 };
 
-use crate::queries::bot_filters::append_event_bot_filter;
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
+use crate::queries::event_filters::append_event_filters;
 use crate::DuckDbBackend;
 
 const MAX_GOALS_PER_WEBSITE: i64 = 50;
@@ -74,83 +75,6 @@ fn value_mode_from_str(raw: &str) -> Result<GoalValueMode> {
     }
 }
 
-fn append_event_filters(
-    filter: &AnalyticsFilter,
-    filter_sql: &mut String,
-    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
-    param_idx: &mut usize,
-) {
-    append_event_bot_filter(filter_sql, filter.include_bots, "e.");
-    if let Some(ref country) = filter.filter_country {
-        filter_sql.push_str(&format!(" AND e.country = ?{}", *param_idx));
-        params.push(Box::new(country.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref page) = filter.filter_page {
-        filter_sql.push_str(&format!(" AND e.url LIKE ?{}", *param_idx));
-        params.push(Box::new(format!("%{}%", page)));
-        *param_idx += 1;
-    }
-    if let Some(ref referrer) = filter.filter_referrer {
-        filter_sql.push_str(&format!(" AND e.referrer_domain = ?{}", *param_idx));
-        params.push(Box::new(referrer.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref browser) = filter.filter_browser {
-        filter_sql.push_str(&format!(" AND e.browser = ?{}", *param_idx));
-        params.push(Box::new(browser.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref os) = filter.filter_os {
-        filter_sql.push_str(&format!(" AND e.os = ?{}", *param_idx));
-        params.push(Box::new(os.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref device) = filter.filter_device {
-        filter_sql.push_str(&format!(" AND e.device_type = ?{}", *param_idx));
-        params.push(Box::new(device.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref language) = filter.filter_language {
-        filter_sql.push_str(&format!(" AND e.language = ?{}", *param_idx));
-        params.push(Box::new(language.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_source) = filter.filter_utm_source {
-        filter_sql.push_str(&format!(" AND e.utm_source = ?{}", *param_idx));
-        params.push(Box::new(utm_source.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_medium) = filter.filter_utm_medium {
-        filter_sql.push_str(&format!(" AND e.utm_medium = ?{}", *param_idx));
-        params.push(Box::new(utm_medium.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_campaign) = filter.filter_utm_campaign {
-        filter_sql.push_str(&format!(" AND e.utm_campaign = ?{}", *param_idx));
-        params.push(Box::new(utm_campaign.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref region) = filter.filter_region {
-        filter_sql.push_str(&format!(" AND e.region = ?{}", *param_idx));
-        params.push(Box::new(region.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref city) = filter.filter_city {
-        filter_sql.push_str(&format!(" AND e.city = ?{}", *param_idx));
-        params.push(Box::new(city.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref hostname) = filter.filter_hostname {
-        filter_sql.push_str(&format!(
-            " AND lower(regexp_extract(e.url, '^https?://([^/?#]+)', 1)) = lower(?{})",
-            *param_idx
-        ));
-        params.push(Box::new(hostname.clone()));
-        *param_idx += 1;
-    }
-}
-
 fn map_goal_row(row: &duckdb::Row<'_>) -> Result<Goal, duckdb::Error> {
     let goal_type_raw: String = row.get(3)?;
     let match_op_raw: String = row.get(5)?;
@@ -173,6 +97,73 @@ fn map_goal_row(row: &duckdb::Row<'_>) -> Result<Goal, duckdb::Error> {
     })
 }
 
+/// SQL predicate matching `goal` against an events relation whose columns
+/// are qualified with `column_prefix`. Binds the match value as `?{param_idx}`.
+pub(crate) fn goal_match_sql(
+    goal: &Goal,
+    column_prefix: &str,
+    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
+    param_idx: usize,
+) -> String {
+    let p = column_prefix;
+    match (&goal.goal_type, &goal.match_operator) {
+        (GoalType::PageView, MatchOperator::Equals) => {
+            params.push(Box::new(goal.match_value.clone()));
+            // Extract path+query from full URL so "/pricing" matches "http://host/pricing"
+            format!(
+                "{p}event_type = 'pageview' AND regexp_extract({p}url, '^https?://[^/?#]+(/[^#]*)?', 1) = ?{}",
+                param_idx
+            )
+        }
+        (GoalType::PageView, MatchOperator::Contains) => {
+            params.push(Box::new(format!("%{}%", goal.match_value)));
+            format!("{p}event_type = 'pageview' AND {p}url LIKE ?{}", param_idx)
+        }
+        (GoalType::Event, MatchOperator::Equals) => {
+            params.push(Box::new(goal.match_value.clone()));
+            format!("{p}event_type = 'event' AND {p}event_name = ?{}", param_idx)
+        }
+        (GoalType::Event, MatchOperator::Contains) => {
+            params.push(Box::new(format!("%{}%", goal.match_value)));
+            format!(
+                "{p}event_type = 'event' AND {p}event_name LIKE ?{}",
+                param_idx
+            )
+        }
+    }
+}
+
+pub(crate) fn fetch_goal(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    goal_id: &str,
+) -> Result<Option<Goal>> {
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT
+            id,
+            website_id,
+            name,
+            goal_type,
+            match_value,
+            match_operator,
+            value_mode,
+            fixed_value,
+            value_property_key,
+            currency,
+            CAST(created_at AS VARCHAR),
+            CAST(updated_at AS VARCHAR)
+        FROM goals
+        WHERE website_id = ?1 AND id = ?2
+        "#,
+    )?;
+    match stmt.query_row(duckdb::params![website_id, goal_id], map_goal_row) {
+        Ok(goal) => Ok(Some(goal)),
+        Err(duckdb::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
 fn query_period_stats(
     conn: &duckdb::Connection,
     website_id: &str,
@@ -193,33 +184,9 @@ fn query_period_stats(
     ];
     let mut param_idx = 4;
     let mut filter_sql = String::new();
-    append_event_filters(filter, &mut filter_sql, &mut params, &mut param_idx);
+    append_event_filters(filter, "e.", &mut filter_sql, &mut params, &mut param_idx);
 
-    let match_sql = match (&goal.goal_type, &goal.match_operator) {
-        (GoalType::PageView, MatchOperator::Equals) => {
-            params.push(Box::new(goal.match_value.clone()));
-            // Extract path+query from full URL so "/pricing" matches "http://host/pricing"
-            format!(
-                "e.event_type = 'pageview' AND regexp_extract(e.url, '^https?://[^/?#]+(/[^#]*)?', 1) = ?{}",
-                param_idx
-            )
-        }
-        (GoalType::PageView, MatchOperator::Contains) => {
-            params.push(Box::new(format!("%{}%", goal.match_value)));
-            format!("e.event_type = 'pageview' AND e.url LIKE ?{}", param_idx)
-        }
-        (GoalType::Event, MatchOperator::Equals) => {
-            params.push(Box::new(goal.match_value.clone()));
-            format!("e.event_type = 'event' AND e.event_name = ?{}", param_idx)
-        }
-        (GoalType::Event, MatchOperator::Contains) => {
-            params.push(Box::new(format!("%{}%", goal.match_value)));
-            format!(
-                "e.event_type = 'event' AND e.event_name LIKE ?{}",
-                param_idx
-            )
-        }
-    };
+    let match_sql = goal_match_sql(goal, "e.", &mut params, param_idx);
 
     let sql = format!(
         r#"
@@ -263,42 +230,48 @@ fn query_period_stats(
 }
 
 pub async fn list_goals_inner(db: &DuckDbBackend, website_id: &str) -> Result<Vec<Goal>> {
-    let conn = db.conn.lock().await;
-    let mut stmt = conn.prepare(
-        r#"
-        SELECT
-            id,
-            website_id,
-            name,
-            goal_type,
-            match_value,
-            match_operator,
-            value_mode,
-            fixed_value,
-            value_property_key,
-            currency,
-            CAST(created_at AS VARCHAR),
-            CAST(updated_at AS VARCHAR)
-        FROM goals
-        WHERE website_id = ?1
-        ORDER BY created_at DESC, id DESC
-        "#,
-    )?;
-    let rows = stmt.query_map(duckdb::params![website_id], map_goal_row)?;
-
-    let mut goals = Vec::new();
-    for row in rows {
-        goals.push(row?);
-    }
-    Ok(goals)
+    let website_id = website_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT
+                id,
+                website_id,
+                name,
+                goal_type,
+                match_value,
+                match_operator,
+                value_mode,
+                fixed_value,
+                value_property_key,
+                currency,
+                CAST(created_at AS VARCHAR),
+                CAST(updated_at AS VARCHAR)
+            FROM goals
+            WHERE website_id = ?1
+            ORDER BY created_at DESC, id DESC
+            "#,
+        )?;
+        let rows = stmt.query_map(duckdb::params![website_id], map_goal_row)?;
+
+        let mut goals = Vec::new();
+        for row in rows {
+            goals.push(row?);
+        }
+        Ok(goals)
+    })
+    .await
 }
 
 pub async fn count_goals_inner(db: &DuckDbBackend, website_id: &str) -> Result<i64> {
-    let conn = db.conn.lock().await;
-    let count = conn
-        .prepare("SELECT COUNT(*) FROM goals WHERE website_id = ?1")?
-        .query_row(duckdb::params![website_id], |row| row.get(0))?;
-    Ok(count)
+    let website_id = website_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        let count = conn
+            .prepare("SELECT COUNT(*) FROM goals WHERE website_id = ?1")?
+            .query_row(duckdb::params![website_id], |row| row.get(0))?;
+        Ok(count)
+    })
+    .await
 }
 
 pub async fn goal_name_exists_inner(
@@ -307,17 +280,24 @@ pub async fn goal_name_exists_inner(
     name: &str,
     exclude_goal_id: Option<&str>,
 ) -> Result<bool> {
-    let conn = db.conn.lock().await;
-    let exists: i64 = if let Some(exclude_id) = exclude_goal_id {
-        conn.prepare("SELECT COUNT(*) FROM goals WHERE website_id = ?1 AND name = ?2 AND id != ?3")?
+    let website_id = website_id.to_string();
+    let name = name.to_string();
+    let exclude_goal_id = exclude_goal_id.map(str::to_string);
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        let exists: i64 = if let Some(exclude_id) = exclude_goal_id {
+            conn.prepare(
+                "SELECT COUNT(*) FROM goals WHERE website_id = ?1 AND name = ?2 AND id != ?3",
+            )?
             .query_row(duckdb::params![website_id, name, exclude_id], |row| {
                 row.get(0)
             })?
-    } else {
-        conn.prepare("SELECT COUNT(*) FROM goals WHERE website_id = ?1 AND name = ?2")?
-            .query_row(duckdb::params![website_id, name], |row| row.get(0))?
-    };
-    Ok(exists > 0)
+        } else {
+            conn.prepare("SELECT COUNT(*) FROM goals WHERE website_id = ?1 AND name = ?2")?
+                .query_row(duckdb::params![website_id, name], |row| row.get(0))?
+        };
+        Ok(exists > 0)
+    })
+    .await
 }
 
 pub async fn create_goal_inner(
@@ -525,34 +505,25 @@ pub async fn get_goal_stats_inner(
     goal_id: &str,
     filter: &AnalyticsFilter,
 ) -> Result<GoalStats> {
-    let conn = db.conn.lock().await;
+    let website_id = website_id.to_string();
+    let goal_id = goal_id.to_string();
+    let filter = filter.clone();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        goal_stats_with_conn(conn, &website_id, &goal_id, &filter)
+    })
+    .await
+}
 
-    let mut stmt = conn.prepare(
-        r#"
-        SELECT
-            id,
-            website_id,
-            name,
-            goal_type,
-            match_value,
-            match_operator,
-            value_mode,
-            fixed_value,
-            value_property_key,
-            currency,
-            CAST(created_at AS VARCHAR),
-            CAST(updated_at AS VARCHAR)
-        FROM goals
-        WHERE website_id = ?1 AND id = ?2
-        "#,
-    )?;
-    let goal = stmt
-        .query_row(duckdb::params![website_id, goal_id], map_goal_row)
-        .ok()
-        .ok_or_else(|| anyhow!("Goal not found"))?;
+fn goal_stats_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    goal_id: &str,
+    filter: &AnalyticsFilter,
+) -> Result<GoalStats> {
+    let goal = fetch_goal(conn, website_id, goal_id)?.ok_or_else(|| anyhow!("Goal not found"))?;
 
     let (conversions, converting_sessions, total_sessions, _) = query_period_stats(
-        &conn,
+        conn,
         website_id,
         &goal,
         filter.start_date,
@@ -564,7 +535,7 @@ pub async fn get_goal_stats_inner(
     let prev_end = filter.start_date - Duration::days(1);
     let prev_start = prev_end - Duration::days(range_days - 1);
     let (prev_conversions, prev_converting_sessions, prev_total_sessions, has_prev_period_data) =
-        query_period_stats(&conn, website_id, &goal, prev_start, prev_end, filter)?;
+        query_period_stats(conn, website_id, &goal, prev_start, prev_end, filter)?;
 
     let conversion_rate = if total_sessions == 0 {
         0.0
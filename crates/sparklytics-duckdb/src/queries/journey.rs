@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use anyhow::{anyhow, Result};
 use chrono::{LocalResult, TimeZone};
 use chrono_tz::Tz;
@@ -7,85 +9,10 @@ use sparklytics_core::analytics::{
     JourneyResponse,
 };
 
-use crate::queries::bot_filters::append_event_bot_filter;
+use crate::queries::event_filters::append_event_filters;
 use crate::DuckDbBackend;
 
-fn append_event_filters(
-    filter: &AnalyticsFilter,
-    filter_sql: &mut String,
-    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
-    param_idx: &mut usize,
-) {
-    append_event_bot_filter(filter_sql, filter.include_bots, "e.");
-    if let Some(ref country) = filter.filter_country {
-        filter_sql.push_str(&format!(" AND e.country = ?{}", *param_idx));
-        params.push(Box::new(country.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref page) = filter.filter_page {
-        filter_sql.push_str(&format!(" AND position(?{} in e.url) > 0", *param_idx));
-        params.push(Box::new(page.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref referrer) = filter.filter_referrer {
-        filter_sql.push_str(&format!(" AND e.referrer_domain = ?{}", *param_idx));
-        params.push(Box::new(referrer.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref browser) = filter.filter_browser {
-        filter_sql.push_str(&format!(" AND e.browser = ?{}", *param_idx));
-        params.push(Box::new(browser.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref os) = filter.filter_os {
-        filter_sql.push_str(&format!(" AND e.os = ?{}", *param_idx));
-        params.push(Box::new(os.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref device) = filter.filter_device {
-        filter_sql.push_str(&format!(" AND e.device_type = ?{}", *param_idx));
-        params.push(Box::new(device.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref language) = filter.filter_language {
-        filter_sql.push_str(&format!(" AND e.language = ?{}", *param_idx));
-        params.push(Box::new(language.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_source) = filter.filter_utm_source {
-        filter_sql.push_str(&format!(" AND e.utm_source = ?{}", *param_idx));
-        params.push(Box::new(utm_source.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_medium) = filter.filter_utm_medium {
-        filter_sql.push_str(&format!(" AND e.utm_medium = ?{}", *param_idx));
-        params.push(Box::new(utm_medium.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_campaign) = filter.filter_utm_campaign {
-        filter_sql.push_str(&format!(" AND e.utm_campaign = ?{}", *param_idx));
-        params.push(Box::new(utm_campaign.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref region) = filter.filter_region {
-        filter_sql.push_str(&format!(" AND e.region = ?{}", *param_idx));
-        params.push(Box::new(region.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref city) = filter.filter_city {
-        filter_sql.push_str(&format!(" AND e.city = ?{}", *param_idx));
-        params.push(Box::new(city.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref hostname) = filter.filter_hostname {
-        filter_sql.push_str(&format!(
-            " AND lower(regexp_extract(e.url, '^https?://([^/:?#]+)', 1)) = lower(?{})",
-            *param_idx
-        ));
-        params.push(Box::new(hostname.clone()));
-        *param_idx += 1;
-    }
-}
+const JOURNEY_QUERY_TIMEOUT: Duration = Duration::from_secs(5);
 
 fn resolve_timezone(
     conn: &duckdb::Connection,
@@ -288,9 +215,22 @@ pub async fn get_journey_inner(
     filter: &AnalyticsFilter,
     query: &JourneyQuery,
 ) -> Result<JourneyResponse> {
-    let conn = db.conn.lock().await;
+    let website_id = website_id.to_string();
+    let filter = filter.clone();
+    let query = query.clone();
+    db.run_read(JOURNEY_QUERY_TIMEOUT, move |conn| {
+        journey_with_conn(conn, &website_id, &filter, &query)
+    })
+    .await
+}
 
-    let tz = resolve_timezone(&conn, website_id, filter.timezone.as_deref())?;
+fn journey_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    filter: &AnalyticsFilter,
+    query: &JourneyQuery,
+) -> Result<JourneyResponse> {
+    let tz = resolve_timezone(conn, website_id, filter.timezone.as_deref())?;
     let (start_str, end_str) = utc_bounds_for_filter(tz, filter.start_date, filter.end_date)?;
 
     let normalized_anchor = match query.anchor_type {
@@ -314,7 +254,7 @@ pub async fn get_journey_inner(
 
     let mut filter_sql = String::new();
     let mut param_idx = 6usize;
-    append_event_filters(filter, &mut filter_sql, &mut params, &mut param_idx);
+    append_event_filters(filter, "e.", &mut filter_sql, &mut params, &mut param_idx);
 
     let max_depth_param_idx = param_idx;
     let clamped_max_depth = query.max_depth.clamp(1, 5);
@@ -323,33 +263,19 @@ pub async fn get_journey_inner(
     let sql = build_journey_sql(&query.direction, &filter_sql, max_depth_param_idx);
     let param_refs: Vec<&dyn duckdb::types::ToSql> = params.iter().map(|p| p.as_ref()).collect();
 
-    if let Err(error) = conn.execute_batch("SET statement_timeout = '5000ms'") {
-        tracing::warn!(%error, "Could not set DuckDB statement_timeout");
+    let mut stmt = conn.prepare(&sql)?;
+    let mapped = stmt.query_map(param_refs.as_slice(), |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, i64>(1)?,
+            row.get::<_, f64>(2).unwrap_or(0.0),
+            row.get::<_, i64>(3)?,
+        ))
+    })?;
+    let mut rows = Vec::new();
+    for row in mapped {
+        rows.push(row?);
     }
-
-    let rows_res: Result<Vec<(String, i64, f64, i64)>> = (|| {
-        let mut stmt = conn.prepare(&sql)?;
-        let rows = stmt.query_map(param_refs.as_slice(), |row| {
-            Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, i64>(1)?,
-                row.get::<_, f64>(2).unwrap_or(0.0),
-                row.get::<_, i64>(3)?,
-            ))
-        })?;
-
-        let mut out = Vec::new();
-        for row in rows {
-            out.push(row?);
-        }
-        Ok(out)
-    })();
-
-    if let Err(error) = conn.execute_batch("RESET statement_timeout") {
-        tracing::warn!(%error, "Could not reset DuckDB statement_timeout");
-    }
-
-    let rows = rows_res?;
     let total_anchor_sessions = rows.first().map(|r| r.3).unwrap_or(0);
 
     let branches = rows
@@ -4,7 +4,9 @@ use sparklytics_core::analytics::{
     AnalyticsFilter, ComparisonRange, MetricRow, MetricsPage, VALID_METRIC_TYPES,
 };
 
-use crate::queries::bot_filters::append_event_bot_filter;
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
+use crate::queries::event_filters::append_event_filters;
+use crate::rollups::{bind_rollup_cutoff, raw_period_start, rollup_union};
 use crate::DuckDbBackend;
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -28,6 +30,28 @@ pub fn is_valid_metric_type(t: &str) -> bool {
     VALID_METRIC_TYPES.contains(&t)
 }
 
+/// Column expression over the `e` events alias for a metrics dimension.
+pub(crate) fn metric_column_expr(metric_type: &str) -> Option<&'static str> {
+    let expr = match metric_type {
+        "page" => "e.url",
+        "referrer" => "COALESCE(e.referrer_domain, '(direct)')",
+        "country" => "e.country",
+        "region" => "e.region",
+        "city" => "e.city",
+        "browser" => "e.browser",
+        "os" => "e.os",
+        "device" => "e.device_type",
+        "language" => "e.language",
+        "screen" => "e.screen",
+        "event_name" => "e.event_name",
+        "utm_source" => "e.utm_source",
+        "utm_medium" => "e.utm_medium",
+        "utm_campaign" => "e.utm_campaign",
+        _ => return None,
+    };
+    Some(expr)
+}
+
 pub async fn get_metrics_inner(
     db: &DuckDbBackend,
     website_id: &str,
@@ -36,12 +60,38 @@ pub async fn get_metrics_inner(
     offset: i64,
     filter: &AnalyticsFilter,
     comparison: Option<&ComparisonRange>,
+) -> Result<(MetricsResult, MetricsPagination)> {
+    let website_id = website_id.to_string();
+    let metric_type = metric_type.to_string();
+    let filter = filter.clone();
+    let comparison = comparison.cloned();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        metrics_with_conn(
+            conn,
+            &website_id,
+            &metric_type,
+            limit,
+            offset,
+            &filter,
+            comparison.as_ref(),
+        )
+    })
+    .await
+}
+
+fn metrics_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    metric_type: &str,
+    limit: i64,
+    offset: i64,
+    filter: &AnalyticsFilter,
+    comparison: Option<&ComparisonRange>,
 ) -> Result<(MetricsResult, MetricsPagination)> {
     if !is_valid_metric_type(metric_type) {
         return Err(anyhow!("invalid metric type: {}", metric_type));
     }
 
-    let conn = db.conn.lock().await;
     let start_str = filter.start_date.format("%Y-%m-%d").to_string();
     let end_str = (filter.end_date + chrono::Duration::days(1))
         .format("%Y-%m-%d")
@@ -67,109 +117,137 @@ pub async fn get_metrics_inner(
     }
 
     let mut extra_filter = String::new();
-    append_event_bot_filter(&mut extra_filter, filter.include_bots, "e.");
-    if let Some(ref country) = filter.filter_country {
-        extra_filter.push_str(&format!(" AND e.country = ?{}", idx));
-        params.push(Box::new(country.clone()));
-        idx += 1;
-    }
-    if let Some(ref page) = filter.filter_page {
-        extra_filter.push_str(&format!(" AND e.url LIKE ?{}", idx));
-        params.push(Box::new(format!("%{}%", page)));
-        idx += 1;
-    }
-    if let Some(ref referrer) = filter.filter_referrer {
-        extra_filter.push_str(&format!(" AND e.referrer_domain = ?{}", idx));
-        params.push(Box::new(referrer.clone()));
-        idx += 1;
-    }
-    if let Some(ref browser) = filter.filter_browser {
-        extra_filter.push_str(&format!(" AND e.browser = ?{}", idx));
-        params.push(Box::new(browser.clone()));
-        idx += 1;
-    }
-    if let Some(ref os) = filter.filter_os {
-        extra_filter.push_str(&format!(" AND e.os = ?{}", idx));
-        params.push(Box::new(os.clone()));
-        idx += 1;
-    }
-    if let Some(ref device) = filter.filter_device {
-        extra_filter.push_str(&format!(" AND e.device_type = ?{}", idx));
-        params.push(Box::new(device.clone()));
-        idx += 1;
-    }
-    if let Some(ref language) = filter.filter_language {
-        extra_filter.push_str(&format!(" AND e.language = ?{}", idx));
-        params.push(Box::new(language.clone()));
-        idx += 1;
-    }
-    if let Some(ref utm_source) = filter.filter_utm_source {
-        extra_filter.push_str(&format!(" AND e.utm_source = ?{}", idx));
-        params.push(Box::new(utm_source.clone()));
-        idx += 1;
-    }
-    if let Some(ref utm_medium) = filter.filter_utm_medium {
-        extra_filter.push_str(&format!(" AND e.utm_medium = ?{}", idx));
-        params.push(Box::new(utm_medium.clone()));
-        idx += 1;
-    }
-    if let Some(ref utm_campaign) = filter.filter_utm_campaign {
-        extra_filter.push_str(&format!(" AND e.utm_campaign = ?{}", idx));
-        params.push(Box::new(utm_campaign.clone()));
-        idx += 1;
-    }
-    if let Some(ref region) = filter.filter_region {
-        extra_filter.push_str(&format!(" AND e.region = ?{}", idx));
-        params.push(Box::new(region.clone()));
-        idx += 1;
-    }
-    if let Some(ref city) = filter.filter_city {
-        extra_filter.push_str(&format!(" AND e.city = ?{}", idx));
-        params.push(Box::new(city.clone()));
-        idx += 1;
-    }
-    if let Some(ref hostname) = filter.filter_hostname {
-        extra_filter.push_str(&format!(
-            " AND lower(regexp_extract(e.url, '^https?://([^/?#]+)', 1)) = lower(?{})",
-            idx
-        ));
-        params.push(Box::new(hostname.clone()));
-        idx += 1;
-    }
+    append_event_filters(filter, "e.", &mut extra_filter, &mut params, &mut idx);
 
-    let column_expr = match metric_type {
-        "page" => "e.url",
-        "referrer" => "COALESCE(e.referrer_domain, '(direct)')",
-        "country" => "e.country",
-        "region" => "e.region",
-        "city" => "e.city",
-        "browser" => "e.browser",
-        "os" => "e.os",
-        "device" => "e.device_type",
-        "language" => "e.language",
-        "screen" => "e.screen",
-        "event_name" => "e.event_name",
-        "utm_source" => "e.utm_source",
-        "utm_medium" => "e.utm_medium",
-        "utm_campaign" => "e.utm_campaign",
-        _ => return Err(anyhow!("invalid metric type")),
-    };
+    let column_expr =
+        metric_column_expr(metric_type).ok_or_else(|| anyhow!("invalid metric type"))?;
 
-    let order_by = match metric_type {
-        "page" | "event_name" => "p.pageviews DESC",
-        _ => "p.visitors DESC",
+    // Closed days come from per-value dimension rollups: counts of sessions
+    // that stay within one day add up, sessions that cross a day boundary
+    // are merged with their raw-event part, and visitor lists union into an
+    // exact distinct count.
+    let cutoff_idx = bind_rollup_cutoff(conn, website_id, filter, &mut params, &mut idx)?;
+    let dimension_predicate = match cutoff_idx {
+        Some(_) => {
+            let predicate = format!(" AND r.dimension = ?{idx}");
+            params.push(Box::new(metric_type.to_string()));
+            idx += 1;
+            predicate
+        }
+        None => String::new(),
     };
-
-    let compare_bind_padding = if comparison.is_some() {
-        " AND (?4 IS NULL OR ?5 IS NULL OR 1=1)"
+    let raw_start = raw_period_start("p.period_start", cutoff_idx);
+    let rollup_sessions_sql = rollup_union(
+        "rollup_daily_dimension_sessions",
+        "p.period_name, r.value AS dim_value, r.session_id, r.visitor_id, r.pageviews, \
+         r.first_at, r.last_at",
+        &dimension_predicate,
+        filter.include_bots,
+        cutoff_idx,
+    );
+    let rollup_counts_sql = rollup_union(
+        "rollup_daily_dimensions",
+        "p.period_name, r.value AS dim_value, r.pageviews, r.sessions, r.bounced_sessions, \
+         r.duration_seconds, r.timed_sessions",
+        &dimension_predicate,
+        filter.include_bots,
+        cutoff_idx,
+    );
+    let rollup_visitors_sql = rollup_union(
+        "rollup_daily_dimensions",
+        "p.period_name, r.value AS dim_value, UNNEST(r.visitor_ids) AS visitor_id",
+        &dimension_predicate,
+        filter.include_bots,
+        cutoff_idx,
+    );
+    let dim_visitors_sql = format!(
+        "SELECT period_name, dim_value, COUNT(DISTINCT visitor_id) AS visitors \
+         FROM ( \
+           SELECT period_name, dim_value, visitor_id FROM raw_sessions \
+           {rollup_visitors_sql} \
+         ) \
+         GROUP BY period_name, dim_value"
+    );
+    let periods_sql = if comparison.is_some() {
+        "SELECT 'primary' AS period_name, CAST(?2 AS TIMESTAMP) AS period_start, CAST(?3 AS TIMESTAMP) AS period_end \
+         UNION ALL \
+         SELECT 'comparison' AS period_name, CAST(?4 AS TIMESTAMP) AS period_start, CAST(?5 AS TIMESTAMP) AS period_end"
     } else {
-        ""
+        "SELECT 'primary' AS period_name, CAST(?2 AS TIMESTAMP) AS period_start, CAST(?3 AS TIMESTAMP) AS period_end"
+    };
+    let dim_counts_sql = format!(
+        "periods AS ({periods_sql}), \
+         raw_sessions AS ( \
+           SELECT p.period_name, \
+                  {column_expr} AS dim_value, \
+                  e.session_id, \
+                  e.visitor_id, \
+                  SUM(CASE WHEN e.event_type = 'pageview' THEN 1 ELSE 0 END) AS pageviews, \
+                  MIN(e.created_at) AS first_at, \
+                  MAX(e.created_at) AS last_at \
+           FROM periods p \
+           JOIN events e \
+             ON e.website_id = ?1 \
+            AND e.created_at >= {raw_start} \
+            AND e.created_at < p.period_end \
+           WHERE {column_expr} IS NOT NULL{extra_filter} \
+           GROUP BY p.period_name, {column_expr}, e.session_id, e.visitor_id \
+         ), \
+         merged_sessions AS ( \
+           SELECT period_name, \
+                  dim_value, \
+                  SUM(pageviews) AS pv_count, \
+                  DATEDIFF('second', MIN(first_at), MAX(last_at)) AS dur_s \
+           FROM ( \
+             SELECT * FROM raw_sessions \
+             {rollup_sessions_sql} \
+           ) \
+           GROUP BY period_name, dim_value, session_id, visitor_id \
+         ), \
+         dim_counts AS ( \
+           SELECT period_name, \
+                  dim_value, \
+                  pv_count AS pageviews, \
+                  1 AS sessions, \
+                  CASE WHEN pv_count <= 1 THEN 1 ELSE 0 END AS bounced_sessions, \
+                  CASE WHEN dur_s > 0 THEN dur_s ELSE 0 END AS duration_seconds, \
+                  CASE WHEN dur_s > 0 THEN 1 ELSE 0 END AS timed_sessions \
+           FROM merged_sessions \
+           {rollup_counts_sql} \
+         )"
+    );
+    let agg_sql = format!(
+        "{dim_counts_sql}, \
+         dim_visitors AS ({dim_visitors_sql}), \
+         agg AS ( \
+           SELECT c.period_name, \
+                  c.dim_value, \
+                  v.visitors, \
+                  CAST(SUM(c.pageviews) AS BIGINT) AS pageviews, \
+                  COALESCE(ROUND(CAST( \
+                    100.0 * SUM(c.bounced_sessions) / NULLIF(SUM(c.sessions), 0) \
+                  AS DOUBLE), 1), 0.0) AS bounce_rate, \
+                  COALESCE(ROUND(CAST( \
+                    SUM(c.duration_seconds) / NULLIF(SUM(c.timed_sessions), 0) \
+                  AS DOUBLE), 1), 0.0) AS avg_duration_seconds \
+           FROM dim_counts c \
+           JOIN dim_visitors v \
+             ON v.period_name = c.period_name \
+            AND v.dim_value = c.dim_value \
+           GROUP BY c.period_name, c.dim_value, v.visitors \
+         )"
+    );
+
+    // The value tie-break keeps pagination stable and rollup-backed results
+    // identical to raw scans.
+    let order_by = match metric_type {
+        "page" | "event_name" => "p.pageviews DESC, p.dim_value ASC",
+        _ => "p.visitors DESC, p.dim_value ASC",
     };
 
     let count_sql = format!(
-        "SELECT COUNT(DISTINCT {column_expr}) FROM events e \
-         WHERE e.website_id = ?1 AND e.created_at >= ?2 AND e.created_at < ?3 \
-         AND {column_expr} IS NOT NULL{compare_bind_padding}{extra_filter}"
+        "WITH {dim_counts_sql} \
+         SELECT COUNT(DISTINCT dim_value) FROM dim_counts WHERE period_name = 'primary'"
     );
 
     let count_refs: Vec<&dyn duckdb::types::ToSql> = params.iter().map(|p| p.as_ref()).collect();
@@ -182,39 +260,7 @@ pub async fn get_metrics_inner(
 
     let data_sql = if comparison.is_some() {
         format!(
-            "WITH periods AS ( \
-               SELECT 'primary' AS period_name, CAST(?2 AS TIMESTAMP) AS period_start, CAST(?3 AS TIMESTAMP) AS period_end \
-               UNION ALL \
-               SELECT 'comparison' AS period_name, CAST(?4 AS TIMESTAMP) AS period_start, CAST(?5 AS TIMESTAMP) AS period_end \
-             ), \
-             sess AS ( \
-               SELECT p.period_name, \
-                      {column_expr} AS dim_value, \
-                      e.session_id, \
-                      e.visitor_id, \
-                      SUM(CASE WHEN e.event_type = 'pageview' THEN 1 ELSE 0 END) AS pv_count, \
-                      CAST(DATEDIFF('second', MIN(e.created_at), MAX(e.created_at)) AS DOUBLE) AS dur_s \
-               FROM periods p \
-               JOIN events e \
-                 ON e.website_id = ?1 \
-                AND e.created_at >= p.period_start \
-                AND e.created_at < p.period_end \
-               WHERE {column_expr} IS NOT NULL{extra_filter} \
-               GROUP BY p.period_name, dim_value, e.session_id, e.visitor_id \
-             ), \
-             agg AS ( \
-               SELECT period_name, \
-                      dim_value, \
-                      COUNT(DISTINCT visitor_id) AS visitors, \
-                      COALESCE(SUM(pv_count), 0) AS pageviews, \
-                      COALESCE(ROUND( \
-                        100.0 * SUM(CASE WHEN pv_count <= 1 THEN 1.0 ELSE 0.0 END) \
-                              / NULLIF(COUNT(*), 0), 1), 0.0) AS bounce_rate, \
-                      COALESCE(ROUND(AVG(CASE WHEN dur_s > 0 THEN dur_s END), 1), 0.0) \
-                        AS avg_duration_seconds \
-               FROM sess \
-               GROUP BY period_name, dim_value \
-             ), \
+            "WITH {agg_sql}, \
              p AS (SELECT * FROM agg WHERE period_name = 'primary'), \
              c AS (SELECT * FROM agg WHERE period_name = 'comparison') \
              SELECT p.dim_value, \
@@ -231,34 +277,15 @@ pub async fn get_metrics_inner(
             idx + 1,
         )
     } else {
-        let non_compare_order_by = match metric_type {
-            "page" | "event_name" => "pageviews DESC",
-            _ => "visitors DESC",
-        };
-
         format!(
-            "WITH sess AS ( \
-               SELECT {column_expr} AS dim_value, \
-                      e.session_id, \
-                      e.visitor_id, \
-                      SUM(CASE WHEN e.event_type = 'pageview' THEN 1 ELSE 0 END) AS pv_count, \
-                      CAST(DATEDIFF('second', MIN(e.created_at), MAX(e.created_at)) AS DOUBLE) AS dur_s \
-               FROM events e \
-               WHERE e.website_id = ?1 AND e.created_at >= ?2 AND e.created_at < ?3 \
-               AND {column_expr} IS NOT NULL{extra_filter} \
-               GROUP BY dim_value, e.session_id, e.visitor_id \
-             ) \
-             SELECT dim_value, \
-                    COUNT(DISTINCT visitor_id) AS visitors, \
-                    COALESCE(SUM(pv_count), 0) AS pageviews, \
-                    COALESCE(ROUND( \
-                      100.0 * SUM(CASE WHEN pv_count <= 1 THEN 1.0 ELSE 0.0 END) \
-                            / NULLIF(COUNT(*), 0), 1), 0.0) AS bounce_rate, \
-                    COALESCE(ROUND(AVG(CASE WHEN dur_s > 0 THEN dur_s END), 1), 0.0) \
-                      AS avg_duration_seconds \
-             FROM sess \
-             GROUP BY dim_value \
-             ORDER BY {non_compare_order_by} \
+            "WITH {agg_sql} \
+             SELECT p.dim_value, \
+                    p.visitors, \
+                    p.pageviews, \
+                    p.bounce_rate, \
+                    p.avg_duration_seconds \
+             FROM agg p \
+             ORDER BY {order_by} \
              LIMIT ?{idx} OFFSET ?{}",
             idx + 1,
         )
@@ -1,11 +1,14 @@
 pub mod attribution;
 pub mod bot_filters;
+pub mod event_filters;
 pub mod events;
+pub mod experiments;
 pub mod funnel_results;
 pub mod funnels;
 pub mod goals;
 pub mod journey;
 pub mod metrics;
+pub mod property_filters;
 pub mod realtime;
 pub mod reports;
 pub mod retention;
@@ -0,0 +1,67 @@
+use sparklytics_core::analytics::{PropertyFilter, PropertyFilterOp};
+
+/// JSON path for a top-level `event_data` key. The key is quoted so dots,
+/// spaces and brackets in property names are matched literally.
+fn property_json_path(key: &str) -> String {
+    format!(
+        "$.\"{}\"",
+        key.trim().replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+/// Append `event_data` property predicates for event aliases.
+///
+/// Payloads that are not valid JSON are treated as having no properties, the
+/// same way the event-properties breakdown skips them.
+pub fn append_event_property_filters(
+    filter_sql: &mut String,
+    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
+    param_idx: &mut usize,
+    filters: &[PropertyFilter],
+    column_prefix: &str,
+) {
+    for filter in filters {
+        let value_expr = format!(
+            "(CASE WHEN json_valid({column_prefix}event_data) \
+             THEN json_extract_string({column_prefix}event_data, ?{}) END)",
+            *param_idx
+        );
+        params.push(Box::new(property_json_path(&filter.key)));
+        *param_idx += 1;
+
+        match filter.op {
+            PropertyFilterOp::Exists => {
+                filter_sql.push_str(&format!(" AND {value_expr} IS NOT NULL"));
+            }
+            PropertyFilterOp::Eq | PropertyFilterOp::Neq | PropertyFilterOp::Contains => {
+                let comparison = match filter.op {
+                    PropertyFilterOp::Eq => format!("{value_expr} = ?{}", *param_idx),
+                    PropertyFilterOp::Neq => {
+                        format!("{value_expr} IS DISTINCT FROM ?{}", *param_idx)
+                    }
+                    _ => format!("position(?{} in {value_expr}) > 0", *param_idx),
+                };
+                filter_sql.push_str(&format!(" AND {comparison}"));
+                params.push(Box::new(filter.value.clone().unwrap_or_default()));
+                *param_idx += 1;
+            }
+            PropertyFilterOp::In if filter.values.is_empty() => {
+                filter_sql.push_str(" AND FALSE");
+            }
+            PropertyFilterOp::In => {
+                let placeholders = filter
+                    .values
+                    .iter()
+                    .map(|value| {
+                        params.push(Box::new(value.clone()));
+                        let placeholder = format!("?{}", *param_idx);
+                        *param_idx += 1;
+                        placeholder
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                filter_sql.push_str(&format!(" AND {value_expr} IN ({placeholders})"));
+            }
+        }
+    }
+}
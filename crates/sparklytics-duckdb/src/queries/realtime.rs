@@ -2,6 +2,7 @@ use anyhow::Result;
 use chrono::Utc;
 use serde::Serialize;
 
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
 use crate::DuckDbBackend;
 
 #[derive(Debug, Clone, Serialize)]
@@ -33,7 +34,18 @@ pub async fn get_realtime_inner(
     website_id: &str,
     include_bots: bool,
 ) -> Result<RealtimeResult> {
-    let conn = db.conn.lock().await;
+    let website_id = website_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        realtime_with_conn(conn, &website_id, include_bots)
+    })
+    .await
+}
+
+fn realtime_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    include_bots: bool,
+) -> Result<RealtimeResult> {
     let now = Utc::now();
     let cutoff = now - chrono::Duration::minutes(30);
     let cutoff_str = cutoff.format("%Y-%m-%d %H:%M:%S%.f").to_string();
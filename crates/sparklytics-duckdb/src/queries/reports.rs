@@ -6,6 +6,7 @@ use sparklytics_core::analytics::{
     UpdateReportRequest,
 };
 
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
 use crate::DuckDbBackend;
 
 const MAX_REPORTS_PER_WEBSITE: i64 = 100;
@@ -85,43 +86,46 @@ pub async fn list_reports_inner(
     db: &DuckDbBackend,
     website_id: &str,
 ) -> Result<Vec<SavedReportSummary>> {
-    let conn = db.conn.lock().await;
-    let mut stmt = conn.prepare(
-        r#"
-        SELECT
-            id,
-            name,
-            description,
-            COALESCE(JSON_EXTRACT_STRING(config_json, '$.report_type'), 'stats') AS report_type,
-            CAST(last_run_at AS VARCHAR),
-            CAST(created_at AS VARCHAR),
-            CAST(updated_at AS VARCHAR)
-        FROM saved_reports
-        WHERE website_id = ?1
-        ORDER BY lower(name) ASC, created_at ASC, id ASC
-        "#,
-    )?;
+    let website_id = website_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT
+                id,
+                name,
+                description,
+                COALESCE(JSON_EXTRACT_STRING(config_json, '$.report_type'), 'stats') AS report_type,
+                CAST(last_run_at AS VARCHAR),
+                CAST(created_at AS VARCHAR),
+                CAST(updated_at AS VARCHAR)
+            FROM saved_reports
+            WHERE website_id = ?1
+            ORDER BY lower(name) ASC, created_at ASC, id ASC
+            "#,
+        )?;
 
-    let rows = stmt.query_map(duckdb::params![website_id], |row| {
-        let report_type_raw: String = row.get(3)?;
-        let report_type =
-            report_type_from_str(&report_type_raw).map_err(|_| duckdb::Error::InvalidQuery)?;
-        Ok(SavedReportSummary {
-            id: row.get(0)?,
-            name: row.get(1)?,
-            description: row.get(2)?,
-            report_type,
-            last_run_at: row.get(4)?,
-            created_at: row.get(5)?,
-            updated_at: row.get(6)?,
-        })
-    })?;
+        let rows = stmt.query_map(duckdb::params![website_id], |row| {
+            let report_type_raw: String = row.get(3)?;
+            let report_type =
+                report_type_from_str(&report_type_raw).map_err(|_| duckdb::Error::InvalidQuery)?;
+            Ok(SavedReportSummary {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                description: row.get(2)?,
+                report_type,
+                last_run_at: row.get(4)?,
+                created_at: row.get(5)?,
+                updated_at: row.get(6)?,
+            })
+        })?;
 
-    let mut reports = Vec::new();
-    for row in rows {
-        reports.push(row?);
-    }
-    Ok(reports)
+        let mut reports = Vec::new();
+        for row in rows {
+            reports.push(row?);
+        }
+        Ok(reports)
+    })
+    .await
 }
 
 pub async fn get_report_inner(
@@ -129,16 +133,23 @@ pub async fn get_report_inner(
     website_id: &str,
     report_id: &str,
 ) -> Result<Option<SavedReport>> {
-    let conn = db.conn.lock().await;
-    get_report_by_id(&conn, website_id, report_id)
+    let website_id = website_id.to_string();
+    let report_id = report_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        get_report_by_id(conn, &website_id, &report_id)
+    })
+    .await
 }
 
 pub async fn count_reports_inner(db: &DuckDbBackend, website_id: &str) -> Result<i64> {
-    let conn = db.conn.lock().await;
-    let count = conn
-        .prepare("SELECT COUNT(*) FROM saved_reports WHERE website_id = ?1")?
-        .query_row(duckdb::params![website_id], |row| row.get(0))?;
-    Ok(count)
+    let website_id = website_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        let count = conn
+            .prepare("SELECT COUNT(*) FROM saved_reports WHERE website_id = ?1")?
+            .query_row(duckdb::params![website_id], |row| row.get(0))?;
+        Ok(count)
+    })
+    .await
 }
 
 pub async fn report_name_exists_inner(
@@ -147,19 +158,24 @@ pub async fn report_name_exists_inner(
     name: &str,
     exclude_report_id: Option<&str>,
 ) -> Result<bool> {
-    let conn = db.conn.lock().await;
-    let exists: i64 = if let Some(exclude_id) = exclude_report_id {
-        conn.prepare(
-            "SELECT COUNT(*) FROM saved_reports WHERE website_id = ?1 AND name = ?2 AND id != ?3",
-        )?
-        .query_row(duckdb::params![website_id, name, exclude_id], |row| {
-            row.get(0)
-        })?
-    } else {
-        conn.prepare("SELECT COUNT(*) FROM saved_reports WHERE website_id = ?1 AND name = ?2")?
-            .query_row(duckdb::params![website_id, name], |row| row.get(0))?
-    };
-    Ok(exists > 0)
+    let website_id = website_id.to_string();
+    let name = name.to_string();
+    let exclude_report_id = exclude_report_id.map(str::to_string);
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        let exists: i64 = if let Some(exclude_id) = exclude_report_id {
+            conn.prepare(
+                "SELECT COUNT(*) FROM saved_reports WHERE website_id = ?1 AND name = ?2 AND id != ?3",
+            )?
+            .query_row(duckdb::params![website_id, name, exclude_id], |row| {
+                row.get(0)
+            })?
+        } else {
+            conn.prepare("SELECT COUNT(*) FROM saved_reports WHERE website_id = ?1 AND name = ?2")?
+                .query_row(duckdb::params![website_id, name], |row| row.get(0))?
+        };
+        Ok(exists > 0)
+    })
+    .await
 }
 
 pub async fn create_report_inner(
@@ -1,8 +1,6 @@
 use std::collections::{BTreeMap, HashMap};
-use std::sync::{
-    atomic::{AtomicU8, Ordering},
-    OnceLock,
-};
+use std::sync::OnceLock;
+use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use chrono::{Datelike, LocalResult, NaiveDate, TimeZone};
@@ -13,17 +11,14 @@ use sparklytics_core::analytics::{
     RetentionResponse, RetentionSummary,
 };
 
-use crate::queries::bot_filters::append_event_bot_filter;
+use crate::backend::QUERY_TIMEOUT_MARKER;
+use crate::queries::event_filters::append_event_filters;
 use crate::DuckDbBackend;
 
 const DEFAULT_RETENTION_STATEMENT_TIMEOUT_MS: u64 = 5_000;
 const MIN_RETENTION_STATEMENT_TIMEOUT_MS: u64 = 100;
 const MAX_RETENTION_STATEMENT_TIMEOUT_MS: u64 = 120_000;
 const RETENTION_QUERY_TIMEOUT_MARKER: &str = "retention_query_timeout";
-const STATEMENT_TIMEOUT_SUPPORT_UNKNOWN: u8 = 0;
-const STATEMENT_TIMEOUT_SUPPORTED: u8 = 1;
-const STATEMENT_TIMEOUT_UNSUPPORTED: u8 = 2;
-static STATEMENT_TIMEOUT_SUPPORT: AtomicU8 = AtomicU8::new(STATEMENT_TIMEOUT_SUPPORT_UNKNOWN);
 
 #[derive(Debug)]
 struct RetentionRawRow {
@@ -73,136 +68,6 @@ fn retention_statement_timeout_ms() -> u64 {
     )
 }
 
-fn is_statement_timeout_error(error: &anyhow::Error) -> bool {
-    let msg = error.to_string().to_ascii_lowercase();
-    msg.contains("statement timeout")
-        || (msg.contains("timeout") && msg.contains("statement"))
-        || msg.contains("retention_query_timeout")
-        || msg.contains("interrupted")
-}
-
-fn apply_statement_timeout_if_supported(conn: &duckdb::Connection, timeout_ms: u64) -> bool {
-    match STATEMENT_TIMEOUT_SUPPORT.load(Ordering::Relaxed) {
-        STATEMENT_TIMEOUT_UNSUPPORTED => return false,
-        STATEMENT_TIMEOUT_SUPPORTED | STATEMENT_TIMEOUT_SUPPORT_UNKNOWN => {}
-        _ => {}
-    }
-
-    let sql = format!("SET statement_timeout = '{}ms'", timeout_ms);
-    match conn.execute_batch(&sql) {
-        Ok(_) => {
-            STATEMENT_TIMEOUT_SUPPORT.store(STATEMENT_TIMEOUT_SUPPORTED, Ordering::Relaxed);
-            true
-        }
-        Err(error) => {
-            let message = error.to_string().to_ascii_lowercase();
-            if message.contains("unrecognized configuration parameter")
-                && message.contains("statement_timeout")
-            {
-                STATEMENT_TIMEOUT_SUPPORT.store(STATEMENT_TIMEOUT_UNSUPPORTED, Ordering::Relaxed);
-                tracing::debug!(
-                    %error,
-                    "DuckDB does not support statement_timeout; retention timeout guard disabled"
-                );
-                false
-            } else {
-                // Keep trying on next request for transient failures.
-                STATEMENT_TIMEOUT_SUPPORT
-                    .store(STATEMENT_TIMEOUT_SUPPORT_UNKNOWN, Ordering::Relaxed);
-                tracing::warn!(%error, "Could not set DuckDB statement_timeout");
-                false
-            }
-        }
-    }
-}
-
-fn reset_statement_timeout_if_supported(conn: &duckdb::Connection) {
-    if STATEMENT_TIMEOUT_SUPPORT.load(Ordering::Relaxed) != STATEMENT_TIMEOUT_SUPPORTED {
-        return;
-    }
-
-    if let Err(error) = conn.execute_batch("RESET statement_timeout") {
-        tracing::warn!(%error, "Could not reset DuckDB statement_timeout");
-    }
-}
-
-fn append_event_filters(
-    filter: &AnalyticsFilter,
-    filter_sql: &mut String,
-    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
-    param_idx: &mut usize,
-) {
-    append_event_bot_filter(filter_sql, filter.include_bots, "e.");
-    if let Some(ref country) = filter.filter_country {
-        filter_sql.push_str(&format!(" AND e.country = ?{}", *param_idx));
-        params.push(Box::new(country.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref page) = filter.filter_page {
-        filter_sql.push_str(&format!(" AND position(?{} in e.url) > 0", *param_idx));
-        params.push(Box::new(page.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref referrer) = filter.filter_referrer {
-        filter_sql.push_str(&format!(" AND e.referrer_domain = ?{}", *param_idx));
-        params.push(Box::new(referrer.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref browser) = filter.filter_browser {
-        filter_sql.push_str(&format!(" AND e.browser = ?{}", *param_idx));
-        params.push(Box::new(browser.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref os) = filter.filter_os {
-        filter_sql.push_str(&format!(" AND e.os = ?{}", *param_idx));
-        params.push(Box::new(os.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref device) = filter.filter_device {
-        filter_sql.push_str(&format!(" AND e.device_type = ?{}", *param_idx));
-        params.push(Box::new(device.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref language) = filter.filter_language {
-        filter_sql.push_str(&format!(" AND e.language = ?{}", *param_idx));
-        params.push(Box::new(language.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_source) = filter.filter_utm_source {
-        filter_sql.push_str(&format!(" AND e.utm_source = ?{}", *param_idx));
-        params.push(Box::new(utm_source.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_medium) = filter.filter_utm_medium {
-        filter_sql.push_str(&format!(" AND e.utm_medium = ?{}", *param_idx));
-        params.push(Box::new(utm_medium.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_campaign) = filter.filter_utm_campaign {
-        filter_sql.push_str(&format!(" AND e.utm_campaign = ?{}", *param_idx));
-        params.push(Box::new(utm_campaign.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref region) = filter.filter_region {
-        filter_sql.push_str(&format!(" AND e.region = ?{}", *param_idx));
-        params.push(Box::new(region.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref city) = filter.filter_city {
-        filter_sql.push_str(&format!(" AND e.city = ?{}", *param_idx));
-        params.push(Box::new(city.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref hostname) = filter.filter_hostname {
-        filter_sql.push_str(&format!(
-            " AND lower(regexp_extract(e.url, '^https?://([^/:?#]+)', 1)) = lower(?{})",
-            *param_idx
-        ));
-        params.push(Box::new(hostname.clone()));
-        *param_idx += 1;
-    }
-}
-
 fn resolve_timezone(
     conn: &duckdb::Connection,
     website_id: &str,
@@ -412,8 +277,30 @@ pub async fn get_retention_inner(
     filter: &AnalyticsFilter,
     query: &RetentionQuery,
 ) -> Result<RetentionResponse> {
-    let conn = db.conn.lock().await;
-    let timezone = resolve_timezone(&conn, website_id, filter.timezone.as_deref())?;
+    let website_id = website_id.to_string();
+    let filter = filter.clone();
+    let query = query.clone();
+    let timeout = Duration::from_millis(retention_statement_timeout_ms());
+    db.run_read(timeout, move |conn| {
+        retention_with_conn(conn, &website_id, &filter, &query)
+    })
+    .await
+    .map_err(|error| {
+        if error.to_string() == QUERY_TIMEOUT_MARKER {
+            anyhow!(RETENTION_QUERY_TIMEOUT_MARKER)
+        } else {
+            error
+        }
+    })
+}
+
+fn retention_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    filter: &AnalyticsFilter,
+    query: &RetentionQuery,
+) -> Result<RetentionResponse> {
+    let timezone = resolve_timezone(conn, website_id, filter.timezone.as_deref())?;
     let (start_str, end_str) = utc_bounds_for_filter(timezone, filter.start_date, filter.end_date)?;
 
     let clamped_periods = clamp_max_periods(&query.granularity, query.max_periods);
@@ -436,7 +323,7 @@ pub async fn get_retention_inner(
 
     let mut filter_sql = String::new();
     let mut param_idx = 7usize;
-    append_event_filters(filter, &mut filter_sql, &mut params, &mut param_idx);
+    append_event_filters(filter, "e.", &mut filter_sql, &mut params, &mut param_idx);
 
     let max_periods_param = param_idx;
     params.push(Box::new(i64::from(clamped_periods)));
@@ -543,43 +430,22 @@ ORDER BY r.cohort_start ASC, r.period_offset ASC
     );
 
     let param_refs: Vec<&dyn duckdb::types::ToSql> = params.iter().map(|p| p.as_ref()).collect();
-    let statement_timeout_ms = retention_statement_timeout_ms();
-    let timeout_guard_applied = apply_statement_timeout_if_supported(&conn, statement_timeout_ms);
-
-    let raw_rows_result: Result<Vec<RetentionRawRow>> = (|| {
-        let mut stmt = conn.prepare(&sql)?;
-        let rows = stmt.query_map(param_refs.as_slice(), |row| {
-            let period_offset = row.get::<_, i64>(2).unwrap_or(0).max(0) as u32;
-            Ok(RetentionRawRow {
-                cohort_start: row.get(0)?,
-                cohort_size: row.get(1)?,
-                period_offset,
-                retained: row.get(3)?,
-                rate: row.get::<_, f64>(4).unwrap_or(0.0),
-            })
-        })?;
-
-        let mut out = Vec::new();
-        for row in rows {
-            out.push(row?);
-        }
-        Ok(out)
-    })();
-
-    if timeout_guard_applied {
-        reset_statement_timeout_if_supported(&conn);
+    let mut stmt = conn.prepare(&sql)?;
+    let mapped = stmt.query_map(param_refs.as_slice(), |row| {
+        let period_offset = row.get::<_, i64>(2).unwrap_or(0).max(0) as u32;
+        Ok(RetentionRawRow {
+            cohort_start: row.get(0)?,
+            cohort_size: row.get(1)?,
+            period_offset,
+            retained: row.get(3)?,
+            rate: row.get::<_, f64>(4).unwrap_or(0.0),
+        })
+    })?;
+    let mut raw_rows = Vec::new();
+    for row in mapped {
+        raw_rows.push(row?);
     }
 
-    let raw_rows = match raw_rows_result {
-        Ok(rows) => rows,
-        Err(error) => {
-            if is_statement_timeout_error(&error) {
-                return Err(anyhow!(RETENTION_QUERY_TIMEOUT_MARKER));
-            }
-            return Err(error);
-        }
-    };
-
     let rows = build_rows(raw_rows, clamped_periods);
     let summary = compute_summary(&rows, &query.granularity, filter.end_date, clamped_periods);
 
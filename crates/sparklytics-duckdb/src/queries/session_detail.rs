@@ -2,6 +2,7 @@ use anyhow::{anyhow, Result};
 
 use sparklytics_core::analytics::{SessionDetailResponse, SessionEventItem, SessionListItem};
 
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
 use crate::DuckDbBackend;
 
 const MAX_EVENTS_PER_SESSION: usize = 2000;
@@ -11,8 +12,19 @@ pub async fn get_session_detail_inner(
     website_id: &str,
     session_id: &str,
 ) -> Result<SessionDetailResponse> {
-    let conn = db.conn.lock().await;
+    let website_id = website_id.to_string();
+    let session_id = session_id.to_string();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        session_detail_with_conn(conn, &website_id, &session_id)
+    })
+    .await
+}
 
+fn session_detail_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    session_id: &str,
+) -> Result<SessionDetailResponse> {
     let summary_sql = r#"
         WITH session_events AS (
             SELECT
@@ -7,7 +7,9 @@ use sparklytics_core::analytics::{
     SessionsResponse,
 };
 
-use crate::queries::bot_filters::{append_event_bot_filter, append_session_bot_filter};
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
+use crate::queries::bot_filters::append_session_bot_filter;
+use crate::queries::event_filters::append_event_filters;
 use crate::DuckDbBackend;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -32,85 +34,23 @@ fn decode_cursor(cursor: &str) -> Result<CursorPayload> {
     serde_json::from_slice::<CursorPayload>(&decoded).map_err(|_| anyhow!("invalid_cursor"))
 }
 
-fn append_event_filters(
+pub async fn get_sessions_inner(
+    db: &DuckDbBackend,
+    website_id: &str,
     filter: &AnalyticsFilter,
-    filter_sql: &mut String,
-    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
-    param_idx: &mut usize,
-) {
-    append_event_bot_filter(filter_sql, filter.include_bots, "e.");
-    if let Some(ref country) = filter.filter_country {
-        filter_sql.push_str(&format!(" AND e.country = ?{}", *param_idx));
-        params.push(Box::new(country.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref page) = filter.filter_page {
-        filter_sql.push_str(&format!(" AND e.url LIKE ?{}", *param_idx));
-        params.push(Box::new(format!("%{}%", page)));
-        *param_idx += 1;
-    }
-    if let Some(ref referrer) = filter.filter_referrer {
-        filter_sql.push_str(&format!(" AND e.referrer_domain = ?{}", *param_idx));
-        params.push(Box::new(referrer.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref browser) = filter.filter_browser {
-        filter_sql.push_str(&format!(" AND e.browser = ?{}", *param_idx));
-        params.push(Box::new(browser.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref os) = filter.filter_os {
-        filter_sql.push_str(&format!(" AND e.os = ?{}", *param_idx));
-        params.push(Box::new(os.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref device) = filter.filter_device {
-        filter_sql.push_str(&format!(" AND e.device_type = ?{}", *param_idx));
-        params.push(Box::new(device.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref language) = filter.filter_language {
-        filter_sql.push_str(&format!(" AND e.language = ?{}", *param_idx));
-        params.push(Box::new(language.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_source) = filter.filter_utm_source {
-        filter_sql.push_str(&format!(" AND e.utm_source = ?{}", *param_idx));
-        params.push(Box::new(utm_source.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_medium) = filter.filter_utm_medium {
-        filter_sql.push_str(&format!(" AND e.utm_medium = ?{}", *param_idx));
-        params.push(Box::new(utm_medium.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref utm_campaign) = filter.filter_utm_campaign {
-        filter_sql.push_str(&format!(" AND e.utm_campaign = ?{}", *param_idx));
-        params.push(Box::new(utm_campaign.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref region) = filter.filter_region {
-        filter_sql.push_str(&format!(" AND e.region = ?{}", *param_idx));
-        params.push(Box::new(region.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref city) = filter.filter_city {
-        filter_sql.push_str(&format!(" AND e.city = ?{}", *param_idx));
-        params.push(Box::new(city.clone()));
-        *param_idx += 1;
-    }
-    if let Some(ref hostname) = filter.filter_hostname {
-        filter_sql.push_str(&format!(
-            " AND lower(regexp_extract(e.url, '^https?://([^/?#]+)', 1)) = lower(?{})",
-            *param_idx
-        ));
-        params.push(Box::new(hostname.clone()));
-        *param_idx += 1;
-    }
+    query: &SessionsQuery,
+) -> Result<SessionsResponse> {
+    let website_id = website_id.to_string();
+    let filter = filter.clone();
+    let query = query.clone();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        sessions_with_conn(conn, &website_id, &filter, &query)
+    })
+    .await
 }
 
-pub async fn get_sessions_inner(
-    db: &DuckDbBackend,
+fn sessions_with_conn(
+    conn: &duckdb::Connection,
     website_id: &str,
     filter: &AnalyticsFilter,
     query: &SessionsQuery,
@@ -125,8 +65,6 @@ pub async fn get_sessions_inner(
         return Err(anyhow!("unsupported sort"));
     }
 
-    let conn = db.conn.lock().await;
-
     let start_str = filter.start_date.format("%Y-%m-%d").to_string();
     let end_str = (filter.end_date + chrono::Duration::days(1))
         .format("%Y-%m-%d")
@@ -139,7 +77,7 @@ pub async fn get_sessions_inner(
         Box::new(end_str),
     ];
     let mut param_idx = 4;
-    append_event_filters(filter, &mut filter_sql, &mut params, &mut param_idx);
+    append_event_filters(filter, "e.", &mut filter_sql, &mut params, &mut param_idx);
     let mut session_filter_sql = String::new();
     append_session_bot_filter(&mut session_filter_sql, filter.include_bots, "s.");
 
@@ -3,7 +3,10 @@ use chrono::NaiveDate;
 
 use sparklytics_core::analytics::{AnalyticsFilter, ComparisonRange, StatsResult};
 
-use crate::queries::bot_filters::{append_event_bot_filter, append_session_bot_filter};
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
+use crate::queries::bot_filters::append_session_bot_filter;
+use crate::queries::event_filters::append_event_filters;
+use crate::rollups::{bind_rollup_cutoff, raw_period_start, rollup_union};
 use crate::DuckDbBackend;
 
 #[derive(Debug, Clone)]
@@ -11,19 +14,7 @@ pub struct StatsParams {
     pub website_id: String,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
-    pub filter_country: Option<String>,
-    pub filter_page: Option<String>,
-    pub filter_referrer: Option<String>,
-    pub filter_browser: Option<String>,
-    pub filter_os: Option<String>,
-    pub filter_device: Option<String>,
-    pub filter_language: Option<String>,
-    pub filter_utm_source: Option<String>,
-    pub filter_utm_medium: Option<String>,
-    pub filter_utm_campaign: Option<String>,
-    pub filter_region: Option<String>,
-    pub filter_city: Option<String>,
-    pub filter_hostname: Option<String>,
+    pub filter: AnalyticsFilter,
     pub include_bots: bool,
     pub comparison: Option<ComparisonRange>,
 }
@@ -38,19 +29,7 @@ impl StatsParams {
             website_id: website_id.to_string(),
             start_date: filter.start_date,
             end_date: filter.end_date,
-            filter_country: filter.filter_country.clone(),
-            filter_page: filter.filter_page.clone(),
-            filter_referrer: filter.filter_referrer.clone(),
-            filter_browser: filter.filter_browser.clone(),
-            filter_os: filter.filter_os.clone(),
-            filter_device: filter.filter_device.clone(),
-            filter_language: filter.filter_language.clone(),
-            filter_utm_source: filter.filter_utm_source.clone(),
-            filter_utm_medium: filter.filter_utm_medium.clone(),
-            filter_utm_campaign: filter.filter_utm_campaign.clone(),
-            filter_region: filter.filter_region.clone(),
-            filter_city: filter.filter_city.clone(),
-            filter_hostname: filter.filter_hostname.clone(),
+            filter: filter.clone(),
             include_bots: filter.include_bots,
             comparison: comparison.cloned(),
         }
@@ -58,8 +37,14 @@ impl StatsParams {
 }
 
 pub async fn get_stats_inner(db: &DuckDbBackend, params: &StatsParams) -> Result<StatsResult> {
-    let conn = db.conn.lock().await;
+    let params = params.clone();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        stats_with_conn(conn, &params)
+    })
+    .await
+}
 
+fn stats_with_conn(conn: &duckdb::Connection, params: &StatsParams) -> Result<StatsResult> {
     let timezone: String = conn
         .prepare("SELECT timezone FROM websites WHERE id = ?1")?
         .query_row(duckdb::params![params.website_id], |row| row.get(0))
@@ -77,7 +62,7 @@ pub async fn get_stats_inner(db: &DuckDbBackend, params: &StatsParams) -> Result
         .map(|range| range.comparison_start)
         .unwrap_or_else(|| prev_end - chrono::Duration::days(range_days - 1));
     let (current, prev) = query_stats_for_ranges(
-        &conn,
+        conn,
         &params.website_id,
         &params.start_date,
         &params.end_date,
@@ -143,77 +128,32 @@ fn query_stats_for_ranges(
         Box::new(prev_end_str),
     ];
     let mut param_idx = 6;
-    append_event_bot_filter(&mut filter_sql, params.include_bots, "e.");
-
-    if let Some(ref country) = params.filter_country {
-        filter_sql.push_str(&format!(" AND e.country = ?{}", param_idx));
-        filter_params.push(Box::new(country.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref page) = params.filter_page {
-        filter_sql.push_str(&format!(" AND e.url LIKE ?{}", param_idx));
-        filter_params.push(Box::new(format!("%{}%", page)));
-        param_idx += 1;
-    }
-    if let Some(ref referrer) = params.filter_referrer {
-        filter_sql.push_str(&format!(" AND e.referrer_domain = ?{}", param_idx));
-        filter_params.push(Box::new(referrer.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref browser) = params.filter_browser {
-        filter_sql.push_str(&format!(" AND e.browser = ?{}", param_idx));
-        filter_params.push(Box::new(browser.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref os) = params.filter_os {
-        filter_sql.push_str(&format!(" AND e.os = ?{}", param_idx));
-        filter_params.push(Box::new(os.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref device) = params.filter_device {
-        filter_sql.push_str(&format!(" AND e.device_type = ?{}", param_idx));
-        filter_params.push(Box::new(device.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref language) = params.filter_language {
-        filter_sql.push_str(&format!(" AND e.language = ?{}", param_idx));
-        filter_params.push(Box::new(language.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref utm_source) = params.filter_utm_source {
-        filter_sql.push_str(&format!(" AND e.utm_source = ?{}", param_idx));
-        filter_params.push(Box::new(utm_source.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref utm_medium) = params.filter_utm_medium {
-        filter_sql.push_str(&format!(" AND e.utm_medium = ?{}", param_idx));
-        filter_params.push(Box::new(utm_medium.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref utm_campaign) = params.filter_utm_campaign {
-        filter_sql.push_str(&format!(" AND e.utm_campaign = ?{}", param_idx));
-        filter_params.push(Box::new(utm_campaign.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref region) = params.filter_region {
-        filter_sql.push_str(&format!(" AND e.region = ?{}", param_idx));
-        filter_params.push(Box::new(region.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref city) = params.filter_city {
-        filter_sql.push_str(&format!(" AND e.city = ?{}", param_idx));
-        filter_params.push(Box::new(city.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref hostname) = params.filter_hostname {
-        filter_sql.push_str(&format!(
-            " AND lower(regexp_extract(e.url, '^https?://([^/?#]+)', 1)) = lower(?{})",
-            param_idx
-        ));
-        filter_params.push(Box::new(hostname.clone()));
-    }
+    append_event_filters(
+        &params.filter,
+        "e.",
+        &mut filter_sql,
+        &mut filter_params,
+        &mut param_idx,
+    );
     let mut session_filter_sql = String::new();
     append_session_bot_filter(&mut session_filter_sql, params.include_bots, "s.");
+    // Closed days come from session-level rollups; `events` keeps pageviews
+    // additive while distinct visitor/session counts stay exact.
+    let cutoff_idx = bind_rollup_cutoff(
+        conn,
+        website_id,
+        &params.filter,
+        &mut filter_params,
+        &mut param_idx,
+    )?;
+    let raw_start = raw_period_start("p.period_start", cutoff_idx);
+    let rollup_sql = rollup_union(
+        "rollup_daily_sessions",
+        "p.period_name, r.session_id, r.visitor_id, r.events",
+        "",
+        params.filter.include_bots,
+        cutoff_idx,
+    );
 
     let sql = format!(
         r#"
@@ -226,19 +166,21 @@ fn query_stats_for_ranges(
             SELECT
                 p.period_name,
                 e.session_id,
-                e.visitor_id
+                e.visitor_id,
+                1 AS events
             FROM periods p
             JOIN events e
               ON e.website_id = ?1
-             AND e.created_at >= p.period_start
+             AND e.created_at >= {raw_start}
              AND e.created_at < p.period_end
             WHERE 1 = 1
               {filter_sql}
+            {rollup_sql}
         ),
         event_stats AS (
             SELECT
                 period_name,
-                COUNT(*) AS pageviews,
+                CAST(SUM(events) AS BIGINT) AS pageviews,
                 COUNT(DISTINCT visitor_id) AS visitors
             FROM filtered_events
             GROUP BY period_name
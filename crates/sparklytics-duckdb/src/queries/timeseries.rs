@@ -7,7 +7,9 @@ use sparklytics_core::analytics::{
     AnalyticsFilter, ComparisonRange, TimeseriesPoint, TimeseriesResult,
 };
 
-use crate::queries::bot_filters::append_event_bot_filter;
+use crate::backend::DEFAULT_READ_QUERY_TIMEOUT;
+use crate::queries::event_filters::append_event_filters;
+use crate::rollups::{bind_rollup_cutoff, raw_period_start, rollup_union};
 use crate::DuckDbBackend;
 
 /// Auto-granularity: ≤2 days -> hour, 3-60 -> day, >60 -> month.
@@ -28,6 +30,29 @@ pub async fn get_timeseries_inner(
     filter: &AnalyticsFilter,
     granularity: Option<&str>,
     comparison: Option<&ComparisonRange>,
+) -> Result<TimeseriesResult> {
+    let website_id = website_id.to_string();
+    let filter = filter.clone();
+    let granularity = granularity.map(str::to_string);
+    let comparison = comparison.cloned();
+    db.run_read(DEFAULT_READ_QUERY_TIMEOUT, move |conn| {
+        timeseries_with_conn(
+            conn,
+            &website_id,
+            &filter,
+            granularity.as_deref(),
+            comparison.as_ref(),
+        )
+    })
+    .await
+}
+
+fn timeseries_with_conn(
+    conn: &duckdb::Connection,
+    website_id: &str,
+    filter: &AnalyticsFilter,
+    granularity: Option<&str>,
+    comparison: Option<&ComparisonRange>,
 ) -> Result<TimeseriesResult> {
     let gran = match granularity {
         Some("hour") => "hour".to_string(),
@@ -36,10 +61,8 @@ pub async fn get_timeseries_inner(
         _ => auto_granularity(&filter.start_date, &filter.end_date),
     };
 
-    let conn = db.conn.lock().await;
-
     if let Some(comparison_range) = comparison {
-        let rows = query_period_buckets(&conn, website_id, filter, &gran, Some(comparison_range))?;
+        let rows = query_period_buckets(conn, website_id, filter, &gran, Some(comparison_range))?;
 
         let primary_buckets = generate_buckets(&filter.start_date, &filter.end_date, &gran);
         let bucket_count = primary_buckets.len();
@@ -100,7 +123,7 @@ pub async fn get_timeseries_inner(
         });
     }
 
-    let rows = query_period_buckets(&conn, website_id, filter, &gran, None)?;
+    let rows = query_period_buckets(conn, website_id, filter, &gran, None)?;
     let mut data_map: HashMap<i64, (i64, i64)> = HashMap::new();
     for (_, idx, pageviews, visitors) in rows {
         data_map.insert(idx, (pageviews, visitors));
@@ -159,83 +182,32 @@ fn query_period_buckets(
         ));
         param_idx = 6;
     }
-    append_event_bot_filter(&mut filter_sql, filter.include_bots, "e.");
-
-    if let Some(ref country) = filter.filter_country {
-        filter_sql.push_str(&format!(" AND e.country = ?{}", param_idx));
-        filter_params.push(Box::new(country.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref page) = filter.filter_page {
-        filter_sql.push_str(&format!(" AND e.url LIKE ?{}", param_idx));
-        filter_params.push(Box::new(format!("%{}%", page)));
-        param_idx += 1;
-    }
-    if let Some(ref referrer) = filter.filter_referrer {
-        filter_sql.push_str(&format!(" AND e.referrer_domain = ?{}", param_idx));
-        filter_params.push(Box::new(referrer.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref browser) = filter.filter_browser {
-        filter_sql.push_str(&format!(" AND e.browser = ?{}", param_idx));
-        filter_params.push(Box::new(browser.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref os) = filter.filter_os {
-        filter_sql.push_str(&format!(" AND e.os = ?{}", param_idx));
-        filter_params.push(Box::new(os.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref device) = filter.filter_device {
-        filter_sql.push_str(&format!(" AND e.device_type = ?{}", param_idx));
-        filter_params.push(Box::new(device.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref language) = filter.filter_language {
-        filter_sql.push_str(&format!(" AND e.language = ?{}", param_idx));
-        filter_params.push(Box::new(language.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref utm_source) = filter.filter_utm_source {
-        filter_sql.push_str(&format!(" AND e.utm_source = ?{}", param_idx));
-        filter_params.push(Box::new(utm_source.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref utm_medium) = filter.filter_utm_medium {
-        filter_sql.push_str(&format!(" AND e.utm_medium = ?{}", param_idx));
-        filter_params.push(Box::new(utm_medium.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref utm_campaign) = filter.filter_utm_campaign {
-        filter_sql.push_str(&format!(" AND e.utm_campaign = ?{}", param_idx));
-        filter_params.push(Box::new(utm_campaign.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref region) = filter.filter_region {
-        filter_sql.push_str(&format!(" AND e.region = ?{}", param_idx));
-        filter_params.push(Box::new(region.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref city) = filter.filter_city {
-        filter_sql.push_str(&format!(" AND e.city = ?{}", param_idx));
-        filter_params.push(Box::new(city.clone()));
-        param_idx += 1;
-    }
-    if let Some(ref hostname) = filter.filter_hostname {
-        filter_sql.push_str(&format!(
-            " AND lower(regexp_extract(e.url, '^https?://([^/?#]+)', 1)) = lower(?{})",
-            param_idx
-        ));
-        filter_params.push(Box::new(hostname.clone()));
-    }
+    append_event_filters(
+        filter,
+        "e.",
+        &mut filter_sql,
+        &mut filter_params,
+        &mut param_idx,
+    );
 
-    let bucket_idx_expr = match granularity {
-        "hour" => "CAST(DATEDIFF('hour', p.period_start, e.created_at) AS BIGINT)",
-        "month" => {
-            "CAST(((EXTRACT(year FROM e.created_at) - EXTRACT(year FROM p.period_start)) * 12 + (EXTRACT(month FROM e.created_at) - EXTRACT(month FROM p.period_start))) AS BIGINT)"
-        }
-        _ => "CAST(DATEDIFF('day', p.period_start, e.created_at) AS BIGINT)",
+    // Rollups are per day, so hourly buckets always read raw events.
+    let cutoff_idx = if granularity == "hour" {
+        None
+    } else {
+        bind_rollup_cutoff(conn, website_id, filter, &mut filter_params, &mut param_idx)?
     };
+    let raw_start = raw_period_start("p.period_start", cutoff_idx);
+    let bucket_idx_expr = bucket_index_expr(granularity, "e.created_at");
+    let rollup_sql = rollup_union(
+        "rollup_daily_sessions",
+        &format!(
+            "p.period_name, {} AS bucket_index, r.visitor_id, r.events",
+            bucket_index_expr(granularity, "CAST(r.day AS TIMESTAMP)")
+        ),
+        "",
+        filter.include_bots,
+        cutoff_idx,
+    );
 
     let sql = if comparison.is_some() {
         format!(
@@ -249,19 +221,21 @@ fn query_period_buckets(
                 SELECT
                     p.period_name,
                     {bucket_idx_expr} AS bucket_index,
-                    e.visitor_id
+                    e.visitor_id,
+                    1 AS events
                 FROM periods p
                 JOIN events e
                   ON e.website_id = ?1
-                 AND e.created_at >= p.period_start
+                 AND e.created_at >= {raw_start}
                  AND e.created_at < p.period_end
                 WHERE 1 = 1
                   {filter_sql}
+                {rollup_sql}
             )
             SELECT
                 period_name,
                 bucket_index,
-                COUNT(*) AS pageviews,
+                CAST(SUM(events) AS BIGINT) AS pageviews,
                 COUNT(DISTINCT visitor_id) AS visitors
             FROM filtered
             GROUP BY period_name, bucket_index
@@ -278,19 +252,21 @@ fn query_period_buckets(
                 SELECT
                     p.period_name,
                     {bucket_idx_expr} AS bucket_index,
-                    e.visitor_id
+                    e.visitor_id,
+                    1 AS events
                 FROM periods p
                 JOIN events e
                   ON e.website_id = ?1
-                 AND e.created_at >= p.period_start
+                 AND e.created_at >= {raw_start}
                  AND e.created_at < p.period_end
                 WHERE 1 = 1
                   {filter_sql}
+                {rollup_sql}
             )
             SELECT
                 period_name,
                 bucket_index,
-                COUNT(*) AS pageviews,
+                CAST(SUM(events) AS BIGINT) AS pageviews,
                 COUNT(DISTINCT visitor_id) AS visitors
             FROM filtered
             GROUP BY period_name, bucket_index
@@ -317,6 +293,18 @@ fn query_period_buckets(
     Ok(out)
 }
 
+/// Zero-based bucket offset of timestamp expression `ts` from the start of
+/// period `p`.
+fn bucket_index_expr(granularity: &str, ts: &str) -> String {
+    match granularity {
+        "hour" => format!("CAST(DATEDIFF('hour', p.period_start, {ts}) AS BIGINT)"),
+        "month" => format!(
+            "CAST(((EXTRACT(year FROM {ts}) - EXTRACT(year FROM p.period_start)) * 12 + (EXTRACT(month FROM {ts}) - EXTRACT(month FROM p.period_start))) AS BIGINT)"
+        ),
+        _ => format!("CAST(DATEDIFF('day', p.period_start, {ts}) AS BIGINT)"),
+    }
+}
+
 impl DuckDbBackend {
     pub async fn get_timeseries(
         &self,
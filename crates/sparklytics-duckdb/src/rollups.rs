@@ -0,0 +1,382 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use duckdb::Connection;
+use serde::Serialize;
+
+use sparklytics_core::analytics::{AnalyticsFilter, VALID_METRIC_TYPES};
+use sparklytics_core::session::DEFAULT_SESSION_TIMEOUT_MINUTES;
+
+use crate::queries::bot_filters::append_event_bot_filter;
+use crate::queries::metrics::metric_column_expr;
+use crate::DuckDbBackend;
+
+/// Work done by a single [`DuckDbBackend::refresh_rollups`] run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RollupRefreshReport {
+    /// Websites whose rollups advanced.
+    pub websites: u64,
+    /// Closed days aggregated across all websites.
+    pub days: u64,
+}
+
+fn format_day(day: NaiveDate) -> String {
+    day.format("%Y-%m-%d").to_string()
+}
+
+fn parse_day(value: &str) -> Result<NaiveDate> {
+    Ok(NaiveDate::parse_from_str(value, "%Y-%m-%d")?)
+}
+
+/// Rollups only carry the bot flag, so any dimension, hostname or property
+/// filter forces a raw-event scan.
+fn filter_supports_rollups(filter: &AnalyticsFilter) -> bool {
+    filter.filter_country.is_none()
+        && filter.filter_page.is_none()
+        && filter.filter_referrer.is_none()
+        && filter.filter_browser.is_none()
+        && filter.filter_os.is_none()
+        && filter.filter_device.is_none()
+        && filter.filter_language.is_none()
+        && filter.filter_utm_source.is_none()
+        && filter.filter_utm_medium.is_none()
+        && filter.filter_utm_campaign.is_none()
+        && filter.filter_region.is_none()
+        && filter.filter_city.is_none()
+        && filter.filter_hostname.is_none()
+        && filter.filter_properties.is_empty()
+}
+
+/// First day that is *not* served from rollups for `website_id`.
+///
+/// Returns `None` when the filter cannot be answered from rollups or nothing
+/// has been rolled up yet. Today is never rolled up, so the cutoff is capped
+/// at `today` even if the stored watermark is ahead of the clock.
+pub(crate) fn rollup_cutoff(
+    conn: &Connection,
+    website_id: &str,
+    filter: &AnalyticsFilter,
+    today: NaiveDate,
+) -> Result<Option<NaiveDate>> {
+    if !filter_supports_rollups(filter) {
+        return Ok(None);
+    }
+    let through: Option<String> = conn
+        .prepare(
+            "SELECT CAST(rolled_up_through AS VARCHAR) FROM rollup_state WHERE website_id = ?1",
+        )?
+        .query_row(duckdb::params![website_id], |row| row.get(0))
+        .ok();
+    let Some(through) = through else {
+        return Ok(None);
+    };
+    Ok(Some((parse_day(&through)? + Duration::days(1)).min(today)))
+}
+
+/// Resolve the rollup cutoff for the current UTC day and bind it as the next
+/// positional parameter. Returns the parameter index to reference from SQL,
+/// or `None` when the query should scan raw events only.
+pub(crate) fn bind_rollup_cutoff(
+    conn: &Connection,
+    website_id: &str,
+    filter: &AnalyticsFilter,
+    params: &mut Vec<Box<dyn duckdb::types::ToSql>>,
+    param_idx: &mut usize,
+) -> Result<Option<usize>> {
+    let Some(cutoff) = rollup_cutoff(conn, website_id, filter, Utc::now().date_naive())? else {
+        return Ok(None);
+    };
+    params.push(Box::new(format_day(cutoff)));
+    let idx = *param_idx;
+    *param_idx += 1;
+    Ok(Some(idx))
+}
+
+/// Lower bound for the raw-event part of a period starting at `period_start`:
+/// days before the cutoff come from rollups instead.
+pub(crate) fn raw_period_start(period_start: &str, cutoff_idx: Option<usize>) -> String {
+    match cutoff_idx {
+        Some(idx) => format!("GREATEST({period_start}, CAST(?{idx} AS TIMESTAMP))"),
+        None => period_start.to_string(),
+    }
+}
+
+/// Predicate selecting the rollup days of `[period_start, period_end)` that
+/// fall before the cutoff.
+fn rollup_period_days(
+    alias: &str,
+    period_start: &str,
+    period_end: &str,
+    cutoff_idx: usize,
+) -> String {
+    format!(
+        "{alias}.day >= CAST({period_start} AS DATE) \
+         AND {alias}.day < LEAST(CAST({period_end} AS DATE), CAST(?{cutoff_idx} AS DATE))"
+    )
+}
+
+/// `UNION ALL` branch reading the pre-cutoff days of each `periods p` row
+/// from rollup `table` (aliased `r`). Empty when `cutoff_idx` is `None`.
+pub(crate) fn rollup_union(
+    table: &str,
+    columns: &str,
+    extra_predicate: &str,
+    include_bots: bool,
+    cutoff_idx: Option<usize>,
+) -> String {
+    let Some(cutoff_idx) = cutoff_idx else {
+        return String::new();
+    };
+    let mut bot_sql = String::new();
+    append_event_bot_filter(&mut bot_sql, include_bots, "r.");
+    let days = rollup_period_days("r", "p.period_start", "p.period_end", cutoff_idx);
+    format!(
+        "
+        UNION ALL
+        SELECT {columns}
+        FROM periods p
+        JOIN {table} r
+          ON r.website_id = ?1
+         AND {days}
+        WHERE 1 = 1{extra_predicate}{bot_sql}"
+    )
+}
+
+/// Per-day rollup tables, all keyed by `website_id` and `day`.
+pub(crate) const ROLLUP_TABLES: [&str; 3] = [
+    "rollup_daily_sessions",
+    "rollup_daily_dimensions",
+    "rollup_daily_dimension_sessions",
+];
+
+/// Drop rollups from `day` onwards so the next refresh rebuilds them from raw
+/// events. Queries fall back to raw events for those days in the meantime.
+///
+/// Called whenever events of an already rolled-up day are inserted or
+/// reclassified.
+pub(crate) fn invalidate_rollups_from(
+    conn: &Connection,
+    website_id: &str,
+    day: NaiveDate,
+) -> Result<()> {
+    let day = format_day(day);
+    let lowered = conn.execute(
+        "UPDATE rollup_state
+         SET rolled_up_through = CAST(?2 AS DATE) - 1,
+             updated_at = CURRENT_TIMESTAMP
+         WHERE website_id = ?1
+           AND rolled_up_through >= CAST(?2 AS DATE)",
+        duckdb::params![website_id, day],
+    )?;
+    if lowered > 0 {
+        for table in ROLLUP_TABLES {
+            conn.execute(
+                &format!("DELETE FROM {table} WHERE website_id = ?1 AND day >= CAST(?2 AS DATE)"),
+                duckdb::params![website_id, day],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Aggregate the closed days `[from, through]` of one website into the rollup
+/// tables and advance its watermark to `through`.
+fn roll_up_days(
+    conn: &mut Connection,
+    website_id: &str,
+    from: Option<NaiveDate>,
+    through: NaiveDate,
+) -> Result<u64> {
+    let tx = conn.transaction()?;
+    let mut days = 0;
+    if let Some(from) = from.filter(|from| *from <= through) {
+        let start = format_day(from);
+        let end = format_day(through + Duration::days(1));
+        for table in ROLLUP_TABLES {
+            tx.execute(
+                &format!("DELETE FROM {table} WHERE website_id = ?1 AND day >= CAST(?2 AS DATE)"),
+                duckdb::params![website_id, start],
+            )?;
+        }
+        tx.execute(
+            "INSERT INTO rollup_daily_sessions
+                 (website_id, day, session_id, visitor_id, is_bot, events)
+             SELECT website_id, CAST(created_at AS DATE), session_id, visitor_id, is_bot, COUNT(*)
+             FROM events
+             WHERE website_id = ?1
+               AND created_at >= CAST(?2 AS TIMESTAMP)
+               AND created_at < CAST(?3 AS TIMESTAMP)
+             GROUP BY website_id, CAST(created_at AS DATE), session_id, visitor_id, is_bot",
+            duckdb::params![website_id, start, end],
+        )?;
+        // Sessions whose counts cannot be summed per day: those with events
+        // on other days, those that may still continue past midnight, and
+        // those mixing bot and human events. Their dimension rows keep
+        // session grain so queries merge each into one session.
+        tx.execute(
+            &format!(
+                "CREATE OR REPLACE TEMP TABLE rollup_open_sessions AS
+                 WITH session_days AS (
+                     SELECT CAST(created_at AS DATE) AS day,
+                            session_id,
+                            MAX(created_at) AS last_at,
+                            COUNT(DISTINCT is_bot) AS bot_flags
+                     FROM events
+                     WHERE website_id = ?1
+                       AND created_at >= CAST(?2 AS TIMESTAMP)
+                       AND created_at < CAST(?3 AS TIMESTAMP)
+                     GROUP BY CAST(created_at AS DATE), session_id
+                 ),
+                 multi_day AS (
+                     SELECT session_id
+                     FROM events
+                     WHERE website_id = ?1
+                       AND session_id IN (SELECT session_id FROM session_days)
+                     GROUP BY session_id
+                     HAVING MIN(CAST(created_at AS DATE)) < MAX(CAST(created_at AS DATE))
+                 )
+                 SELECT day, session_id
+                 FROM session_days
+                 WHERE bot_flags > 1
+                    OR session_id IN (SELECT session_id FROM multi_day)
+                    OR last_at >= CAST(day + 1 AS TIMESTAMP) - to_minutes(CAST(COALESCE(
+                           (SELECT session_timeout_minutes FROM websites WHERE id = ?1),
+                           {DEFAULT_SESSION_TIMEOUT_MINUTES}
+                       ) AS INTEGER))"
+            ),
+            duckdb::params![website_id, start, end],
+        )?;
+        for metric_type in VALID_METRIC_TYPES {
+            let Some(column_expr) = metric_column_expr(metric_type) else {
+                continue;
+            };
+            tx.execute(
+                &format!(
+                    "CREATE OR REPLACE TEMP TABLE rollup_value_sessions AS
+                     SELECT s.*, o.session_id IS NOT NULL AS is_open
+                     FROM (
+                         SELECT e.website_id,
+                                CAST(e.created_at AS DATE) AS day,
+                                {column_expr} AS value,
+                                e.session_id,
+                                e.visitor_id,
+                                e.is_bot,
+                                SUM(CASE WHEN e.event_type = 'pageview' THEN 1 ELSE 0 END) AS pv_count,
+                                DATEDIFF('second', MIN(e.created_at), MAX(e.created_at)) AS dur_s,
+                                MIN(e.created_at) AS first_at,
+                                MAX(e.created_at) AS last_at
+                         FROM events e
+                         WHERE e.website_id = ?1
+                           AND e.created_at >= CAST(?2 AS TIMESTAMP)
+                           AND e.created_at < CAST(?3 AS TIMESTAMP)
+                           AND {column_expr} IS NOT NULL
+                         GROUP BY e.website_id, CAST(e.created_at AS DATE), {column_expr},
+                                  e.session_id, e.visitor_id, e.is_bot
+                     ) s
+                     LEFT JOIN rollup_open_sessions o
+                       ON o.day = s.day
+                      AND o.session_id = s.session_id"
+                ),
+                duckdb::params![website_id, start, end],
+            )?;
+            // Counts cover sessions that start and end on the day; the
+            // visitor list covers every session.
+            tx.execute(
+                "INSERT INTO rollup_daily_dimensions
+                     (website_id, day, dimension, value, is_bot, pageviews, sessions,
+                      bounced_sessions, duration_seconds, timed_sessions, visitor_ids)
+                 SELECT website_id,
+                        day,
+                        ?1,
+                        value,
+                        is_bot,
+                        COALESCE(SUM(pv_count) FILTER (WHERE NOT is_open), 0),
+                        COUNT(*) FILTER (WHERE NOT is_open),
+                        COUNT(*) FILTER (WHERE NOT is_open AND pv_count <= 1),
+                        COALESCE(SUM(dur_s) FILTER (WHERE NOT is_open AND dur_s > 0), 0),
+                        COUNT(*) FILTER (WHERE NOT is_open AND dur_s > 0),
+                        list(DISTINCT visitor_id)
+                 FROM rollup_value_sessions
+                 GROUP BY website_id, day, value, is_bot",
+                duckdb::params![metric_type],
+            )?;
+            tx.execute(
+                "INSERT INTO rollup_daily_dimension_sessions
+                     (website_id, day, dimension, value, session_id, visitor_id, is_bot,
+                      pageviews, first_at, last_at)
+                 SELECT website_id, day, ?1, value, session_id, visitor_id, is_bot,
+                        pv_count, first_at, last_at
+                 FROM rollup_value_sessions
+                 WHERE is_open",
+                duckdb::params![metric_type],
+            )?;
+        }
+        tx.execute_batch(
+            "DROP TABLE rollup_value_sessions;
+             DROP TABLE rollup_open_sessions;",
+        )?;
+        days = (through - from).num_days() as u64 + 1;
+    }
+    tx.execute(
+        "INSERT INTO rollup_state (website_id, rolled_up_through, updated_at)
+         VALUES (?1, CAST(?2 AS DATE), CURRENT_TIMESTAMP)
+         ON CONFLICT (website_id) DO UPDATE
+         SET rolled_up_through = EXCLUDED.rolled_up_through,
+             updated_at = EXCLUDED.updated_at",
+        duckdb::params![website_id, format_day(through)],
+    )?;
+    tx.commit()?;
+    Ok(days)
+}
+
+impl DuckDbBackend {
+    /// Roll every website forward to the last closed UTC day before `now`.
+    ///
+    /// Incremental: each website resumes from its `rollup_state` watermark,
+    /// or from its first event when it has never been rolled up. The writer
+    /// lock is taken per website so ingest flushes can interleave with a long
+    /// initial backfill.
+    pub async fn refresh_rollups(&self, now: DateTime<Utc>) -> Result<RollupRefreshReport> {
+        let last_closed = now.date_naive() - Duration::days(1);
+        let last_closed_str = format_day(last_closed);
+
+        let pending: Vec<(String, Option<String>)> = {
+            let conn = self.conn.lock().await;
+            let mut stmt = conn.prepare(
+                "SELECT w.id, CAST(s.rolled_up_through AS VARCHAR)
+                 FROM websites w
+                 LEFT JOIN rollup_state s ON s.website_id = w.id
+                 WHERE s.rolled_up_through IS NULL
+                    OR s.rolled_up_through < CAST(?1 AS DATE)
+                 ORDER BY w.id",
+            )?;
+            let rows = stmt.query_map(duckdb::params![last_closed_str], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })?;
+            rows.collect::<Result<_, _>>()?
+        };
+
+        let mut report = RollupRefreshReport::default();
+        for (website_id, through) in pending {
+            let mut conn = self.conn.lock().await;
+            let from = match through {
+                Some(through) => Some(parse_day(&through)? + Duration::days(1)),
+                None => conn
+                    .prepare(
+                        "SELECT CAST(CAST(MIN(created_at) AS DATE) AS VARCHAR)
+                         FROM events WHERE website_id = ?1",
+                    )?
+                    .query_row(duckdb::params![website_id], |row| {
+                        row.get::<_, Option<String>>(0)
+                    })?
+                    .map(|day| parse_day(&day))
+                    .transpose()?,
+            };
+            let days = roll_up_days(&mut conn, &website_id, from, last_closed)?;
+            if days > 0 {
+                report.websites += 1;
+                report.days += days;
+            }
+        }
+        Ok(report)
+    }
+}
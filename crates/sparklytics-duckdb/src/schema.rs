@@ -1,8 +1,7 @@
-/// DuckDB initialization SQL.
+/// Per-connection DuckDB settings.
 ///
-/// Executed once at database open time via `Connection::execute_batch`.
-/// All statements use `IF NOT EXISTS` so they are safe to re-run on every
-/// startup (idempotent).
+/// Executed once at database open time via `Connection::execute_batch`,
+/// before [`crate::migrations`] bring the schema up to date.
 ///
 /// `memory_limit` is passed at runtime from `Config.duckdb_memory_limit`
 /// (env `SPARKLYTICS_DUCKDB_MEMORY`, default `"1GB"`). DuckDB accepts any
@@ -15,6 +14,16 @@
 ///     system RAM) is not acceptable for a server process.
 ///   - `SET threads = 2` — limits background thread pool; safe for single-
 ///     writer embedded use.
+pub fn init_sql(memory_limit: &str) -> String {
+    format!("SET memory_limit = '{memory_limit}';\nSET threads = 2;\n")
+}
+
+/// Baseline schema, applied as migration `0001_initial`.
+///
+/// All statements use `IF NOT EXISTS` so databases created before the
+/// migration runner existed are adopted without changes. This SQL is
+/// checksummed in `_migrations`: never edit it — add a numbered migration
+/// to [`crate::migrations::MIGRATIONS`] instead.
 ///
 /// IMPORTANT (CLAUDE.md critical fact #4):
 ///   - Bounce-rate queries MUST use CTEs. Correlated subqueries do not work
@@ -30,11 +39,7 @@
 /// events.website_id FK was removed from the schema for NEW databases (see
 /// events table below). Existing databases retain the FK but delete_website()
 /// handles it correctly via the full-transaction approach.
-pub fn init_sql(memory_limit: &str) -> String {
-    format!(
-        r#"SET memory_limit = '{memory_limit}';
-SET threads = 2;
-
+pub const BASELINE_SCHEMA_SQL: &str = r#"
 -- ===========================================
 -- SETTINGS (self-hosted only)
 -- ===========================================
@@ -59,12 +64,20 @@ CREATE TABLE IF NOT EXISTS websites (
     timezone        VARCHAR(64) NOT NULL DEFAULT 'UTC',  -- IANA timezone string
     ingest_peak_eps INTEGER,                       -- Optional per-website peak ingest limit (events/sec)
     ingest_queue_max_events INTEGER,               -- Optional per-website queue cap (events)
+    retention_days  INTEGER,                       -- Optional per-website override of SPARKLYTICS_RETENTION_DAYS
+    session_timeout_minutes INTEGER,               -- Optional inactivity timeout override (default 30)
+    session_split_at_midnight BOOLEAN NOT NULL DEFAULT FALSE,  -- New session at local midnight (websites.timezone)
+    session_split_on_campaign BOOLEAN NOT NULL DEFAULT FALSE,  -- New session when utm_campaign changes
     share_id        VARCHAR(50) UNIQUE,            -- V1.1: public read-only link (NULL until enabled)
     created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
     updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP  -- Track last modification
 );
 ALTER TABLE websites ADD COLUMN IF NOT EXISTS ingest_peak_eps INTEGER;
 ALTER TABLE websites ADD COLUMN IF NOT EXISTS ingest_queue_max_events INTEGER;
+ALTER TABLE websites ADD COLUMN IF NOT EXISTS retention_days INTEGER;
+ALTER TABLE websites ADD COLUMN IF NOT EXISTS session_timeout_minutes INTEGER;
+ALTER TABLE websites ADD COLUMN IF NOT EXISTS session_split_at_midnight BOOLEAN DEFAULT FALSE;
+ALTER TABLE websites ADD COLUMN IF NOT EXISTS session_split_on_campaign BOOLEAN DEFAULT FALSE;
 CREATE INDEX IF NOT EXISTS idx_websites_tenant   ON websites(tenant_id);
 CREATE INDEX IF NOT EXISTS idx_websites_share_id ON websites(share_id);
 
@@ -93,6 +106,8 @@ CREATE TABLE IF NOT EXISTS sessions (
 ALTER TABLE sessions ADD COLUMN IF NOT EXISTS is_bot BOOLEAN DEFAULT FALSE;
 ALTER TABLE sessions ADD COLUMN IF NOT EXISTS bot_score INTEGER DEFAULT 0;
 ALTER TABLE sessions ADD COLUMN IF NOT EXISTS bot_reason VARCHAR;
+ALTER TABLE sessions ADD COLUMN IF NOT EXISTS utm_campaign VARCHAR;  -- Campaign of the entry event, for campaign-change splits
+ALTER TABLE sessions ADD COLUMN IF NOT EXISTS import_id VARCHAR;     -- Set for sessions synthesised by a historical import
 -- Optimised for "active visitors in last N minutes" query (realtime endpoint)
 CREATE INDEX IF NOT EXISTS idx_sessions_website_visitor
     ON sessions(website_id, visitor_id, last_seen DESC);
@@ -172,6 +187,7 @@ ALTER TABLE events ADD COLUMN IF NOT EXISTS user_agent VARCHAR;
 ALTER TABLE events ADD COLUMN IF NOT EXISTS is_bot BOOLEAN DEFAULT FALSE;
 ALTER TABLE events ADD COLUMN IF NOT EXISTS bot_score INTEGER DEFAULT 0;
 ALTER TABLE events ADD COLUMN IF NOT EXISTS bot_reason VARCHAR;
+ALTER TABLE events ADD COLUMN IF NOT EXISTS import_id VARCHAR;       -- Set for rows written by a historical import
 
 -- Primary query pattern: website + date range
 CREATE INDEX IF NOT EXISTS idx_events_website_time
@@ -238,6 +254,28 @@ ALTER TABLE goals ADD COLUMN IF NOT EXISTS currency VARCHAR DEFAULT 'USD';
 CREATE INDEX IF NOT EXISTS idx_goals_website_id
     ON goals(website_id);
 
+-- ===========================================
+-- EXPERIMENTS (A/B tests measured against a goal)
+-- ===========================================
+CREATE TABLE IF NOT EXISTS experiments (
+    id                    VARCHAR PRIMARY KEY,
+    website_id            VARCHAR NOT NULL,
+    name                  VARCHAR NOT NULL,
+    status                VARCHAR NOT NULL DEFAULT 'draft', -- draft|running|paused|completed
+    variants_json         VARCHAR NOT NULL,
+    weights_json          VARCHAR NOT NULL,
+    goal_id               VARCHAR NOT NULL,
+    planned_duration_days INTEGER NOT NULL,
+    started_at            TIMESTAMP,
+    ended_at              TIMESTAMP,
+    created_at            TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at            TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS idx_experiments_website
+    ON experiments(website_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_experiments_name_website
+    ON experiments(website_id, name);
+
 -- ===========================================
 -- SAVED REPORTS (Insights Builder)
 -- ===========================================
@@ -427,6 +465,9 @@ CREATE INDEX IF NOT EXISTS idx_funnels_website
     ON funnels(website_id);
 CREATE UNIQUE INDEX IF NOT EXISTS idx_funnels_website_name
     ON funnels(website_id, name);
+ALTER TABLE funnels ADD COLUMN IF NOT EXISTS conversion_window_minutes INTEGER;
+ALTER TABLE funnels ADD COLUMN IF NOT EXISTS scope VARCHAR DEFAULT 'session';       -- 'session' | 'visitor'
+ALTER TABLE funnels ADD COLUMN IF NOT EXISTS ordering VARCHAR DEFAULT 'strict';     -- 'strict' | 'any_order'
 
 CREATE TABLE IF NOT EXISTS funnel_steps (
     id              VARCHAR PRIMARY KEY,
@@ -440,6 +481,77 @@ CREATE TABLE IF NOT EXISTS funnel_steps (
 );
 CREATE INDEX IF NOT EXISTS idx_funnel_steps_funnel_order
     ON funnel_steps(funnel_id, step_order);
+ALTER TABLE funnel_steps ADD COLUMN IF NOT EXISTS property_filters_json VARCHAR; -- JSON array of PropertyFilter
+
+-- ===========================================
+-- DAILY ROLLUPS
+-- Derived from events for closed UTC days; rebuilt from rollup_state.rolled_up_through + 1.
+-- Session grain keeps visitor/session distinct counts exact across multi-day ranges.
+-- ===========================================
+CREATE TABLE IF NOT EXISTS rollup_daily_sessions (
+    website_id   VARCHAR NOT NULL,
+    day          DATE NOT NULL,
+    session_id   VARCHAR NOT NULL,
+    visitor_id   VARCHAR NOT NULL,
+    is_bot       BOOLEAN NOT NULL DEFAULT FALSE,
+    events       BIGINT NOT NULL                 -- all event types, matches stats/timeseries "pageviews"
+);
+CREATE INDEX IF NOT EXISTS idx_rollup_daily_sessions_website_day
+    ON rollup_daily_sessions(website_id, day);
+
+CREATE TABLE IF NOT EXISTS rollup_daily_dimensions (
+    website_id   VARCHAR NOT NULL,
+    day          DATE NOT NULL,
+    dimension    VARCHAR NOT NULL,               -- metrics type, e.g. 'page', 'referrer'
+    value        VARCHAR NOT NULL,
+    session_id   VARCHAR NOT NULL,
+    visitor_id   VARCHAR NOT NULL,
+    is_bot       BOOLEAN NOT NULL DEFAULT FALSE,
+    pageviews    BIGINT NOT NULL,                -- event_type = 'pageview' only
+    first_at     TIMESTAMP NOT NULL,
+    last_at      TIMESTAMP NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_rollup_daily_dimensions_website_dim_day
+    ON rollup_daily_dimensions(website_id, dimension, day);
+
+CREATE TABLE IF NOT EXISTS rollup_state (
+    website_id         VARCHAR PRIMARY KEY,
+    rolled_up_through  DATE NOT NULL,            -- last closed day present in the rollup tables
+    updated_at         TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- ===========================================
+-- SERVER INGEST IDEMPOTENCY
+-- Keys claimed by POST /api/websites/:id/events/ingest; kept for 7 days by the retention purge.
+-- ===========================================
+CREATE TABLE IF NOT EXISTS ingest_idempotency_keys (
+    website_id       VARCHAR NOT NULL,
+    idempotency_key  VARCHAR NOT NULL,
+    created_at       TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (website_id, idempotency_key)
+);
+
+-- ===========================================
+-- HISTORICAL IMPORTS
+-- One row per Umami / Plausible / GA4 import. Imported events and sessions
+-- carry the import id in import_id so a rollback can delete exactly them.
+-- ===========================================
+CREATE TABLE IF NOT EXISTS imports (
+    id              VARCHAR PRIMARY KEY,           -- 'imp_' + uuid
+    website_id      VARCHAR NOT NULL,
+    source          VARCHAR NOT NULL,              -- 'umami' | 'plausible' | 'ga4'
+    file_name       VARCHAR,
+    status          VARCHAR NOT NULL DEFAULT 'completed',  -- 'completed' | 'rolled_back'
+    event_count     BIGINT NOT NULL DEFAULT 0,
+    session_count   BIGINT NOT NULL DEFAULT 0,
+    skipped_count   BIGINT NOT NULL DEFAULT 0,     -- source rows that could not be mapped to an event
+    first_event_at  TIMESTAMP,
+    last_event_at   TIMESTAMP,
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    rolled_back_at  TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS idx_imports_website_created
+    ON imports(website_id, created_at DESC);
 
 -- ===========================================
 -- LOCAL API KEYS (self-hosted only)
@@ -471,17 +583,17 @@ CREATE TABLE IF NOT EXISTS login_attempts (
 );
 CREATE INDEX IF NOT EXISTS idx_login_attempts_ip_time
     ON login_attempts(ip_address, attempted_at DESC);
-"#
-    )
-}
+"#;
 
 /// Migrations tracking table SQL.
 ///
-/// Run before INIT_SQL migrations are applied. Tracks which numbered migrations
-/// have been applied so restarts don't re-run them.
+/// Run before [`crate::migrations`] are applied. Tracks which numbered
+/// migrations have been applied, and the checksum of the SQL each one ran,
+/// so restarts don't re-run them and edited migrations are caught.
 pub const MIGRATIONS_TABLE_SQL: &str = r#"
 CREATE TABLE IF NOT EXISTS _migrations (
     id          VARCHAR PRIMARY KEY,
     applied_at  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
 );
+ALTER TABLE _migrations ADD COLUMN IF NOT EXISTS checksum VARCHAR;  -- sha256 of the up SQL; NULL for rows written before checksums
 "#;
@@ -2,6 +2,8 @@ use anyhow::Result;
 use chrono::{DateTime, Utc};
 use sha2::{Digest, Sha256};
 
+use sparklytics_core::session::SessionRules;
+
 use crate::DuckDbBackend;
 
 /// Result of a session lookup/create operation.
@@ -10,8 +12,35 @@ pub struct SessionResult {
     pub is_new: bool,
 }
 
+/// Session rules configured on `website_id`, or the defaults when the
+/// website row is missing.
+fn load_session_rules(conn: &duckdb::Connection, website_id: &str) -> SessionRules {
+    conn.prepare(
+        "SELECT session_timeout_minutes, \
+                COALESCE(session_split_at_midnight, FALSE), \
+                COALESCE(session_split_on_campaign, FALSE), \
+                timezone \
+         FROM websites WHERE id = ?1",
+    )
+    .and_then(|mut stmt| {
+        stmt.query_row(duckdb::params![website_id], |row| {
+            let timezone: String = row.get(3)?;
+            Ok(SessionRules::from_website(
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                &timezone,
+            ))
+        })
+    })
+    .unwrap_or_default()
+}
+
 /// Look up or create a session for the given visitor on the given website.
 ///
+/// The latest session inside the website's inactivity timeout is continued
+/// unless its [`SessionRules`] call for a midnight or campaign split.
+///
 /// Parameter order is intentionally `(visitor_id, website_id, url, now)` to match
 /// existing call sites in this crate.
 pub(crate) async fn get_or_create_session_inner(
@@ -19,45 +48,65 @@ pub(crate) async fn get_or_create_session_inner(
     visitor_id: &str,
     website_id: &str,
     url: &str,
+    utm_campaign: Option<&str>,
     now: DateTime<Utc>,
 ) -> Result<SessionResult> {
     let conn = db.conn.lock().await;
-    let cutoff = now - chrono::Duration::minutes(30);
-    let cutoff_str = cutoff.format("%Y-%m-%d %H:%M:%S%.f").to_string();
+    let rules = load_session_rules(&conn, website_id);
+    let (window_start, window_end) = rules.continuation_window(now);
+    let window_start = window_start.format("%Y-%m-%d %H:%M:%S%.f").to_string();
+    let window_end = window_end.format("%Y-%m-%d %H:%M:%S%.f").to_string();
 
+    // The session last seen closest to `now`; backdated events may fall
+    // before a live session's `last_seen`.
     let mut stmt = conn.prepare(
-        "SELECT session_id, pageview_count FROM sessions \
-         WHERE visitor_id = ?1 AND website_id = ?2 AND last_seen > ?3 \
-         ORDER BY last_seen DESC LIMIT 1",
+        "SELECT session_id, pageview_count, epoch_ms(last_seen), utm_campaign FROM sessions \
+         WHERE visitor_id = ?1 AND website_id = ?2 \
+           AND last_seen > CAST(?3 AS TIMESTAMP) AND last_seen < CAST(?4 AS TIMESTAMP) \
+         ORDER BY abs(epoch_ms(last_seen) - ?5) LIMIT 1",
     )?;
 
     let now_str = now.format("%Y-%m-%d %H:%M:%S%.f").to_string();
 
-    let existing: Option<(String, i32)> = stmt
-        .query_row(duckdb::params![visitor_id, website_id, cutoff_str], |row| {
-            Ok((row.get(0)?, row.get(1)?))
-        })
+    let existing: Option<(String, i32, i64, Option<String>)> = stmt
+        .query_row(
+            duckdb::params![
+                visitor_id,
+                website_id,
+                window_start,
+                window_end,
+                now.timestamp_millis()
+            ],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
         .ok();
 
-    if let Some((session_id, pageview_count)) = existing {
-        conn.execute(
-            "UPDATE sessions SET last_seen = ?1, pageview_count = ?2 WHERE session_id = ?3",
-            duckdb::params![now_str, pageview_count + 1, session_id],
-        )?;
-        return Ok(SessionResult {
-            session_id,
-            is_new: false,
-        });
+    if let Some((session_id, pageview_count, last_seen_ms, session_campaign)) = existing {
+        let last_seen = DateTime::from_timestamp_millis(last_seen_ms).unwrap_or(now);
+        if rules.continues(last_seen, session_campaign.as_deref(), now, utm_campaign) {
+            conn.execute(
+                "UPDATE sessions \
+                 SET first_seen = LEAST(first_seen, CAST(?1 AS TIMESTAMP)), \
+                     last_seen = GREATEST(last_seen, CAST(?1 AS TIMESTAMP)), \
+                     pageview_count = ?2 \
+                 WHERE session_id = ?3",
+                duckdb::params![now_str, pageview_count + 1, session_id],
+            )?;
+            return Ok(SessionResult {
+                session_id,
+                is_new: false,
+            });
+        }
     }
 
     let first_seen_ms = now.timestamp_millis();
     let session_id = compute_session_id(visitor_id, website_id, url, first_seen_ms);
 
     conn.execute(
-        "INSERT INTO sessions (session_id, website_id, tenant_id, visitor_id, first_seen, last_seen, pageview_count, entry_page) \
-         VALUES (?1, ?2, NULL, ?3, ?4, ?5, 1, ?6) \
-         ON CONFLICT (session_id) DO UPDATE SET last_seen = EXCLUDED.last_seen, pageview_count = sessions.pageview_count + 1",
-        duckdb::params![session_id, website_id, visitor_id, now_str, now_str, url],
+        "INSERT INTO sessions (session_id, website_id, tenant_id, visitor_id, first_seen, last_seen, pageview_count, entry_page, utm_campaign) \
+         VALUES (?1, ?2, NULL, ?3, ?4, ?5, 1, ?6, ?7) \
+         ON CONFLICT (session_id) DO UPDATE SET last_seen = GREATEST(sessions.last_seen, EXCLUDED.last_seen), pageview_count = sessions.pageview_count + 1",
+        duckdb::params![session_id, website_id, visitor_id, now_str, now_str, url, utm_campaign],
     )?;
 
     Ok(SessionResult {
@@ -80,7 +129,8 @@ pub(crate) async fn increment_session_pageviews_inner(
     let now_str = now.format("%Y-%m-%d %H:%M:%S%.f").to_string();
     conn.execute(
         "UPDATE sessions
-         SET last_seen = ?1, pageview_count = pageview_count + ?2
+         SET last_seen = GREATEST(last_seen, CAST(?1 AS TIMESTAMP)),
+             pageview_count = pageview_count + ?2
          WHERE session_id = ?3",
         duckdb::params![now_str, additional_pageviews as i64, session_id],
     )?;
@@ -114,7 +164,7 @@ pub(crate) async fn set_session_bot_classification_inner(
 /// Compute a deterministic session ID.
 ///
 /// `session_id = sha256(visitor_id + website_id + entry_page + first_seen_ms)[0:16]`
-fn compute_session_id(
+pub fn compute_session_id(
     visitor_id: &str,
     website_id: &str,
     entry_page: &str,
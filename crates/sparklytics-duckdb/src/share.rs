@@ -1,9 +1,18 @@
-use anyhow::Result;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
 use chrono::NaiveDate;
 use serde::Serialize;
 
+use sparklytics_core::analytics::ExportFormat;
+
 use crate::DuckDbBackend;
 
+/// Upper bound for a streamed NDJSON/Parquet export. Long ranges are allowed,
+/// so this is far above the dashboard read timeout.
+const FILE_EXPORT_QUERY_TIMEOUT: Duration = Duration::from_secs(600);
+
 /// A single row returned by `export_events`.
 ///
 /// `visitor_id` is intentionally omitted — it is a pseudonymous identifier
@@ -93,9 +102,10 @@ impl DuckDbBackend {
     /// query — including all 500K row iterations. While the export runs, every
     /// other DuckDB operation (buffer flushes, analytics queries, session
     /// lookups) is blocked. For large exports this can take 3–8 seconds.
-    /// A proper fix requires streaming via a temp file or DuckDB `COPY TO`,
-    /// but is deferred given the 90-day / 500K-row cap that limits worst-case
-    /// duration. Operators should avoid scheduling exports during peak traffic.
+    /// NDJSON and Parquet exports avoid this via [`Self::export_events_to_file_raw`];
+    /// CSV stays on this path given the 90-day / 500K-row cap that limits
+    /// worst-case duration. Operators should avoid scheduling large CSV
+    /// exports during peak traffic.
     pub async fn export_events_raw(
         &self,
         website_id: &str,
@@ -146,4 +156,59 @@ impl DuckDbBackend {
         }
         Ok(result)
     }
+
+    /// Export raw events for a date range straight to a file with DuckDB
+    /// `COPY ... TO`, returning the number of rows written.
+    ///
+    /// Unlike [`Self::export_events_raw`] no rows pass through Rust, so there
+    /// is no row cap, and the copy runs on a pooled read connection so
+    /// ingestion is never blocked behind it.
+    /// `created_at` keeps its native TIMESTAMP type so warehouse loaders do
+    /// not need to re-parse it. `path` is server-generated; it is still quoted
+    /// because `COPY` does not accept a bound parameter for the target.
+    pub async fn export_events_to_file_raw(
+        &self,
+        website_id: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+        format: ExportFormat,
+        path: &Path,
+    ) -> Result<u64> {
+        let copy_options = match format {
+            ExportFormat::Ndjson => "FORMAT JSON",
+            ExportFormat::Parquet => "FORMAT PARQUET, COMPRESSION ZSTD",
+            ExportFormat::Csv => {
+                return Err(anyhow!("csv exports use export_events_raw"));
+            }
+        };
+        let target = path
+            .to_str()
+            .ok_or_else(|| anyhow!("export path is not valid UTF-8"))?
+            .replace('\'', "''");
+
+        let end_exclusive = end + chrono::Duration::days(1);
+        let start_str = start.format("%Y-%m-%d").to_string();
+        let end_str = end_exclusive.format("%Y-%m-%d").to_string();
+
+        let website_id = website_id.to_string();
+        self.run_read(FILE_EXPORT_QUERY_TIMEOUT, move |conn| {
+            let written = conn.execute(
+                &format!(
+                    r#"COPY (
+                           SELECT id, website_id, event_type, url, referrer_domain, event_name,
+                                  country, browser, os, device_type, language,
+                                  utm_source, utm_medium, utm_campaign, created_at
+                           FROM events
+                           WHERE website_id = ?1
+                             AND created_at >= CAST(?2 AS TIMESTAMP)
+                             AND created_at <  CAST(?3 AS TIMESTAMP)
+                           ORDER BY created_at
+                       ) TO '{target}' ({copy_options})"#
+                ),
+                duckdb::params![website_id, start_str, end_str],
+            )?;
+            Ok(written as u64)
+        })
+        .await
+    }
 }
@@ -0,0 +1,129 @@
+use anyhow::Result;
+use duckdb::Connection;
+
+pub use sparklytics_metadata::TotpFactor;
+
+use crate::DuckDbBackend;
+
+fn insert_recovery_codes(conn: &Connection, account_id: &str, hashes: &[String]) -> Result<()> {
+    conn.execute(
+        "DELETE FROM totp_recovery_codes WHERE account_id = ?1",
+        duckdb::params![account_id],
+    )?;
+    let mut stmt =
+        conn.prepare("INSERT INTO totp_recovery_codes (account_id, code_hash) VALUES (?1, ?2)")?;
+    for hash in hashes {
+        stmt.execute(duckdb::params![account_id, hash])?;
+    }
+    Ok(())
+}
+
+impl DuckDbBackend {
+    pub async fn get_totp_factor(&self, account_id: &str) -> Result<Option<TotpFactor>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT f.account_id, f.secret, CAST(f.confirmed_at AS VARCHAR), f.last_used_step, \
+             (SELECT COUNT(*) FROM totp_recovery_codes c \
+              WHERE c.account_id = f.account_id AND c.used_at IS NULL) \
+             FROM totp_factors f WHERE f.account_id = ?1",
+        )?;
+        let mut rows = stmt.query_map(duckdb::params![account_id], |row| {
+            Ok(TotpFactor {
+                account_id: row.get(0)?,
+                secret: row.get(1)?,
+                confirmed_at: row.get(2)?,
+                last_used_step: row.get(3)?,
+                recovery_codes_remaining: row.get(4)?,
+            })
+        })?;
+        Ok(rows.next().transpose()?)
+    }
+
+    pub async fn begin_totp_enrollment(&self, account_id: &str, secret: &str) -> Result<()> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM totp_recovery_codes WHERE account_id = ?1",
+            duckdb::params![account_id],
+        )?;
+        tx.execute(
+            "DELETE FROM totp_factors WHERE account_id = ?1",
+            duckdb::params![account_id],
+        )?;
+        tx.execute(
+            "INSERT INTO totp_factors (account_id, secret) VALUES (?1, ?2)",
+            duckdb::params![account_id, secret],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub async fn confirm_totp_enrollment(
+        &self,
+        account_id: &str,
+        step: i64,
+        recovery_code_hashes: &[String],
+    ) -> Result<bool> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        let rows = tx.execute(
+            "UPDATE totp_factors \
+             SET confirmed_at = CAST(NOW() AS TIMESTAMP), last_used_step = ?1 \
+             WHERE account_id = ?2 AND confirmed_at IS NULL",
+            duckdb::params![step, account_id],
+        )?;
+        if rows == 0 {
+            return Ok(false);
+        }
+        insert_recovery_codes(&tx, account_id, recovery_code_hashes)?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    pub async fn use_totp_step(&self, account_id: &str, step: i64) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        let rows = conn.execute(
+            "UPDATE totp_factors SET last_used_step = ?1 \
+             WHERE account_id = ?2 AND (last_used_step IS NULL OR last_used_step < ?1)",
+            duckdb::params![step, account_id],
+        )?;
+        Ok(rows > 0)
+    }
+
+    pub async fn use_totp_recovery_code(&self, account_id: &str, code_hash: &str) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        let rows = conn.execute(
+            "UPDATE totp_recovery_codes SET used_at = CAST(NOW() AS TIMESTAMP) \
+             WHERE account_id = ?1 AND code_hash = ?2 AND used_at IS NULL",
+            duckdb::params![account_id, code_hash],
+        )?;
+        Ok(rows > 0)
+    }
+
+    pub async fn replace_totp_recovery_codes(
+        &self,
+        account_id: &str,
+        recovery_code_hashes: &[String],
+    ) -> Result<()> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        insert_recovery_codes(&tx, account_id, recovery_code_hashes)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub async fn delete_totp_factor(&self, account_id: &str) -> Result<bool> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM totp_recovery_codes WHERE account_id = ?1",
+            duckdb::params![account_id],
+        )?;
+        let rows = tx.execute(
+            "DELETE FROM totp_factors WHERE account_id = ?1",
+            duckdb::params![account_id],
+        )?;
+        tx.commit()?;
+        Ok(rows > 0)
+    }
+}
@@ -0,0 +1,413 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use duckdb::Connection;
+use rand::Rng;
+
+pub use sparklytics_metadata::{
+    CreateInviteParams, InviteRecord, SsoUserParams, UpdateUserParams, UserEmailTaken, UserRecord,
+    UserRole,
+};
+
+use crate::DuckDbBackend;
+
+fn random_alnum(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len)
+        .map(|_| {
+            let idx = rng.gen_range(0..36);
+            if idx < 10 {
+                (b'0' + idx) as char
+            } else {
+                (b'a' + idx - 10) as char
+            }
+        })
+        .collect()
+}
+
+fn generate_user_id() -> String {
+    format!("usr_{}", random_alnum(21))
+}
+
+fn generate_invite_id() -> String {
+    format!("inv_{}", random_alnum(21))
+}
+
+/// Stored roles are written by this module; anything unrecognised gets the
+/// least privilege.
+fn parse_role(value: &str) -> UserRole {
+    UserRole::parse(value).unwrap_or(UserRole::Viewer)
+}
+
+const USER_COLUMNS: &str = "id, email, name, role, CAST(created_at AS VARCHAR), \
+     CAST(last_login_at AS VARCHAR), CAST(disabled_at AS VARCHAR)";
+
+fn map_user_row(row: &duckdb::Row<'_>) -> duckdb::Result<UserRecord> {
+    Ok(UserRecord {
+        id: row.get(0)?,
+        email: row.get(1)?,
+        name: row.get(2)?,
+        role: parse_role(&row.get::<_, String>(3)?),
+        website_ids: Vec::new(),
+        created_at: row.get(4)?,
+        last_login_at: row.get(5)?,
+        disabled_at: row.get(6)?,
+    })
+}
+
+const INVITE_COLUMNS: &str = "id, email, role, website_ids, invited_by, \
+     CAST(created_at AS VARCHAR), CAST(expires_at AS VARCHAR), \
+     CAST(accepted_at AS VARCHAR), CAST(revoked_at AS VARCHAR)";
+
+fn map_invite_row(row: &duckdb::Row<'_>) -> duckdb::Result<InviteRecord> {
+    let website_ids: String = row.get(3)?;
+    Ok(InviteRecord {
+        id: row.get(0)?,
+        email: row.get(1)?,
+        role: parse_role(&row.get::<_, String>(2)?),
+        website_ids: serde_json::from_str(&website_ids).unwrap_or_default(),
+        invited_by: row.get(4)?,
+        created_at: row.get(5)?,
+        expires_at: row.get(6)?,
+        accepted_at: row.get(7)?,
+        revoked_at: row.get(8)?,
+    })
+}
+
+fn website_grants(conn: &Connection, user_id: &str) -> Result<Vec<String>> {
+    let mut stmt = conn
+        .prepare("SELECT website_id FROM website_grants WHERE user_id = ?1 ORDER BY website_id")?;
+    let ids = stmt
+        .query_map(duckdb::params![user_id], |row| row.get(0))?
+        .collect::<std::result::Result<Vec<String>, _>>()?;
+    Ok(ids)
+}
+
+fn replace_website_grants(conn: &Connection, user_id: &str, website_ids: &[String]) -> Result<()> {
+    conn.execute(
+        "DELETE FROM website_grants WHERE user_id = ?1",
+        duckdb::params![user_id],
+    )?;
+    let mut unique = website_ids.to_vec();
+    unique.sort();
+    unique.dedup();
+    for website_id in unique {
+        conn.execute(
+            "INSERT INTO website_grants (user_id, website_id) VALUES (?1, ?2)",
+            duckdb::params![user_id, website_id],
+        )?;
+    }
+    Ok(())
+}
+
+fn load_user(conn: &Connection, id: &str) -> Result<Option<UserRecord>> {
+    let sql = format!("SELECT {USER_COLUMNS} FROM users WHERE id = ?1");
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query_map(duckdb::params![id], map_user_row)?;
+    let Some(user) = rows.next().transpose()? else {
+        return Ok(None);
+    };
+    Ok(Some(UserRecord {
+        website_ids: website_grants(conn, &user.id)?,
+        ..user
+    }))
+}
+
+impl DuckDbBackend {
+    /// All user accounts, oldest first, with their website grants.
+    pub async fn list_users(&self) -> Result<Vec<UserRecord>> {
+        let conn = self.conn.lock().await;
+        let sql = format!("SELECT {USER_COLUMNS} FROM users ORDER BY created_at, id");
+        let mut stmt = conn.prepare(&sql)?;
+        let users = stmt
+            .query_map([], map_user_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        users
+            .into_iter()
+            .map(|user| {
+                Ok(UserRecord {
+                    website_ids: website_grants(&conn, &user.id)?,
+                    ..user
+                })
+            })
+            .collect()
+    }
+
+    pub async fn get_user(&self, id: &str) -> Result<Option<UserRecord>> {
+        let conn = self.conn.lock().await;
+        load_user(&conn, id)
+    }
+
+    /// Look up an enabled user by (case-insensitive) email, returning the
+    /// user and their password hash.
+    pub async fn get_user_login(&self, email: &str) -> Result<Option<(UserRecord, String)>> {
+        let conn = self.conn.lock().await;
+        let id_and_hash: Option<(String, String)> = conn
+            .prepare(
+                "SELECT id, password_hash FROM users \
+                 WHERE email = lower(?1) AND disabled_at IS NULL",
+            )?
+            .query_row(duckdb::params![email.trim()], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .ok();
+        let Some((id, hash)) = id_and_hash else {
+            return Ok(None);
+        };
+        Ok(load_user(&conn, &id)?.map(|user| (user, hash)))
+    }
+
+    pub async fn update_user(
+        &self,
+        id: &str,
+        params: UpdateUserParams,
+    ) -> Result<Option<UserRecord>> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        if load_user(&tx, id)?.is_none() {
+            return Ok(None);
+        }
+        if let Some(name) = &params.name {
+            tx.execute(
+                "UPDATE users SET name = ?1 WHERE id = ?2",
+                duckdb::params![name, id],
+            )?;
+        }
+        if let Some(role) = params.role {
+            tx.execute(
+                "UPDATE users SET role = ?1 WHERE id = ?2",
+                duckdb::params![role.as_str(), id],
+            )?;
+        }
+        if let Some(disabled) = params.disabled {
+            tx.execute(
+                "UPDATE users SET disabled_at = CASE WHEN ?1 \
+                 THEN COALESCE(disabled_at, CAST(NOW() AS TIMESTAMP)) ELSE NULL END \
+                 WHERE id = ?2",
+                duckdb::params![disabled, id],
+            )?;
+        }
+        if let Some(website_ids) = &params.website_ids {
+            replace_website_grants(&tx, id, website_ids)?;
+        }
+        let user = load_user(&tx, id)?;
+        tx.commit()?;
+        Ok(user)
+    }
+
+    pub async fn delete_user(&self, id: &str) -> Result<bool> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM website_grants WHERE user_id = ?1",
+            duckdb::params![id],
+        )?;
+        tx.execute(
+            "DELETE FROM user_identities WHERE user_id = ?1",
+            duckdb::params![id],
+        )?;
+        tx.execute(
+            "DELETE FROM totp_recovery_codes WHERE account_id = ?1",
+            duckdb::params![id],
+        )?;
+        tx.execute(
+            "DELETE FROM totp_factors WHERE account_id = ?1",
+            duckdb::params![id],
+        )?;
+        let rows = tx.execute("DELETE FROM users WHERE id = ?1", duckdb::params![id])?;
+        tx.commit()?;
+        Ok(rows > 0)
+    }
+
+    pub async fn set_user_password(&self, id: &str, password_hash: &str) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        let rows = conn.execute(
+            "UPDATE users SET password_hash = ?1 WHERE id = ?2",
+            duckdb::params![password_hash, id],
+        )?;
+        Ok(rows > 0)
+    }
+
+    pub async fn touch_user_login(&self, id: &str) -> Result<()> {
+        let conn = self.conn.lock().await;
+        conn.execute(
+            "UPDATE users SET last_login_at = CAST(NOW() AS TIMESTAMP) WHERE id = ?1",
+            duckdb::params![id],
+        )?;
+        Ok(())
+    }
+
+    /// Find the account linked to an SSO identity. An unlinked identity is
+    /// linked to the account with the same email if the provider verified
+    /// it, or to a new account without a password. The mapped role replaces
+    /// the stored one only on accounts without a password.
+    pub async fn upsert_sso_user(&self, params: SsoUserParams) -> Result<UserRecord> {
+        let email = params.email.trim().to_lowercase();
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+
+        let linked: Option<String> = tx
+            .prepare("SELECT user_id FROM user_identities WHERE issuer = ?1 AND subject = ?2")?
+            .query_row(duckdb::params![params.issuer, params.subject], |row| {
+                row.get(0)
+            })
+            .ok();
+        let user_id = match linked {
+            Some(user_id) => user_id,
+            None => {
+                let existing: Option<String> = tx
+                    .prepare("SELECT id FROM users WHERE email = ?1")?
+                    .query_row(duckdb::params![email], |row| row.get(0))
+                    .ok();
+                let user_id = match existing {
+                    Some(user_id) if params.email_verified => user_id,
+                    // Emails are unique, so an unverified claim to a taken
+                    // address can neither link nor get its own account.
+                    Some(_) => return Err(UserEmailTaken(email).into()),
+                    None => {
+                        let user_id = generate_user_id();
+                        tx.execute(
+                            "INSERT INTO users (id, email, name, password_hash, role, created_at) \
+                             VALUES (?1, ?2, ?3, '', ?4, CAST(NOW() AS TIMESTAMP))",
+                            duckdb::params![user_id, email, params.name, params.role.as_str()],
+                        )?;
+                        user_id
+                    }
+                };
+                tx.execute(
+                    "INSERT INTO user_identities (issuer, subject, user_id) VALUES (?1, ?2, ?3)",
+                    duckdb::params![params.issuer, params.subject, user_id],
+                )?;
+                user_id
+            }
+        };
+        tx.execute(
+            "UPDATE users SET role = CASE WHEN password_hash = '' THEN ?1 ELSE role END, \
+                              name = COALESCE(?2, name) \
+             WHERE id = ?3",
+            duckdb::params![params.role.as_str(), params.name, user_id],
+        )?;
+        let user = load_user(&tx, &user_id)?
+            .ok_or_else(|| anyhow::anyhow!("user {user_id} linked to SSO identity is missing"))?;
+        tx.commit()?;
+        Ok(user)
+    }
+
+    /// Create an invite, revoking any pending invite for the same email.
+    pub async fn create_invite(&self, params: CreateInviteParams) -> Result<InviteRecord> {
+        let id = generate_invite_id();
+        let email = params.email.trim().to_lowercase();
+        let expires_at = (Utc::now() + Duration::days(params.expires_in_days))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        let website_ids = serde_json::to_string(&params.website_ids)?;
+
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "UPDATE user_invites SET revoked_at = CAST(NOW() AS TIMESTAMP) \
+             WHERE email = ?1 AND accepted_at IS NULL AND revoked_at IS NULL",
+            duckdb::params![email],
+        )?;
+        tx.execute(
+            "INSERT INTO user_invites \
+             (id, email, role, website_ids, token_hash, invited_by, created_at, expires_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, CAST(NOW() AS TIMESTAMP), CAST(?7 AS TIMESTAMP))",
+            duckdb::params![
+                id,
+                email,
+                params.role.as_str(),
+                website_ids,
+                params.token_hash,
+                params.invited_by,
+                expires_at
+            ],
+        )?;
+        let sql = format!("SELECT {INVITE_COLUMNS} FROM user_invites WHERE id = ?1");
+        let invite = tx
+            .prepare(&sql)?
+            .query_row(duckdb::params![id], map_invite_row)?;
+        tx.commit()?;
+        Ok(invite)
+    }
+
+    /// Invites that have been neither accepted nor revoked, newest first.
+    /// Expired invites are included so admins can see and replace them.
+    pub async fn list_pending_invites(&self) -> Result<Vec<InviteRecord>> {
+        let conn = self.conn.lock().await;
+        let sql = format!(
+            "SELECT {INVITE_COLUMNS} FROM user_invites \
+             WHERE accepted_at IS NULL AND revoked_at IS NULL \
+             ORDER BY created_at DESC, id"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let invites = stmt
+            .query_map([], map_invite_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(invites)
+    }
+
+    pub async fn revoke_invite(&self, id: &str) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        let rows = conn.execute(
+            "UPDATE user_invites SET revoked_at = CAST(NOW() AS TIMESTAMP) \
+             WHERE id = ?1 AND accepted_at IS NULL AND revoked_at IS NULL",
+            duckdb::params![id],
+        )?;
+        Ok(rows > 0)
+    }
+
+    /// Turn a pending, unexpired invite into a user account with the invited
+    /// role and website grants. Returns `Ok(None)` when no such invite
+    /// matches `token_hash`.
+    pub async fn accept_invite(
+        &self,
+        token_hash: &str,
+        name: Option<&str>,
+        password_hash: &str,
+    ) -> Result<Option<UserRecord>> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        let sql = format!(
+            "SELECT {INVITE_COLUMNS} FROM user_invites \
+             WHERE token_hash = ?1 AND accepted_at IS NULL AND revoked_at IS NULL \
+             AND expires_at > CAST(NOW() AS TIMESTAMP)"
+        );
+        let Some(invite) = tx
+            .prepare(&sql)?
+            .query_row(duckdb::params![token_hash], map_invite_row)
+            .ok()
+        else {
+            return Ok(None);
+        };
+
+        let taken: bool = tx.query_row(
+            "SELECT COUNT(*) > 0 FROM users WHERE email = ?1",
+            duckdb::params![invite.email],
+            |row| row.get(0),
+        )?;
+        if taken {
+            return Err(UserEmailTaken(invite.email).into());
+        }
+
+        let user_id = generate_user_id();
+        tx.execute(
+            "INSERT INTO users (id, email, name, password_hash, role, created_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, CAST(NOW() AS TIMESTAMP))",
+            duckdb::params![
+                user_id,
+                invite.email,
+                name,
+                password_hash,
+                invite.role.as_str()
+            ],
+        )?;
+        replace_website_grants(&tx, &user_id, &invite.website_ids)?;
+        tx.execute(
+            "UPDATE user_invites SET accepted_at = CAST(NOW() AS TIMESTAMP) WHERE id = ?1",
+            duckdb::params![invite.id],
+        )?;
+        let user = load_user(&tx, &user_id)?;
+        tx.commit()?;
+        Ok(user)
+    }
+}
@@ -34,7 +34,7 @@ impl DuckDbBackend {
 
         // Read back the created row to get timestamps.
         let mut stmt = conn.prepare(
-            "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
+            "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, retention_days, session_timeout_minutes, COALESCE(session_split_at_midnight, FALSE), COALESCE(session_split_on_campaign, FALSE), share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
              FROM websites WHERE id = ?1",
         )?;
         let website = stmt.query_row(duckdb::params![id], |row| {
@@ -46,9 +46,13 @@ impl DuckDbBackend {
                 timezone: row.get(4)?,
                 ingest_peak_eps: row.get(5)?,
                 ingest_queue_max_events: row.get(6)?,
-                share_id: row.get(7)?,
-                created_at: row.get(8)?,
-                updated_at: row.get(9)?,
+                retention_days: row.get(7)?,
+                session_timeout_minutes: row.get(8)?,
+                session_split_at_midnight: row.get(9)?,
+                session_split_on_campaign: row.get(10)?,
+                share_id: row.get(11)?,
+                created_at: row.get(12)?,
+                updated_at: row.get(13)?,
             })
         })?;
 
@@ -71,7 +75,7 @@ impl DuckDbBackend {
             cursor
         {
             (
-                "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
+                "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, retention_days, session_timeout_minutes, COALESCE(session_split_at_midnight, FALSE), COALESCE(session_split_on_campaign, FALSE), share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
                  FROM websites WHERE id > ?1 ORDER BY id LIMIT ?2"
                     .to_string(),
                 vec![
@@ -81,7 +85,7 @@ impl DuckDbBackend {
             )
         } else {
             (
-                "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
+                "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, retention_days, session_timeout_minutes, COALESCE(session_split_at_midnight, FALSE), COALESCE(session_split_on_campaign, FALSE), share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
                  FROM websites ORDER BY id LIMIT ?1"
                     .to_string(),
                 vec![Box::new(limit) as Box<dyn duckdb::types::ToSql>],
@@ -100,9 +104,13 @@ impl DuckDbBackend {
                 timezone: row.get(4)?,
                 ingest_peak_eps: row.get(5)?,
                 ingest_queue_max_events: row.get(6)?,
-                share_id: row.get(7)?,
-                created_at: row.get(8)?,
-                updated_at: row.get(9)?,
+                retention_days: row.get(7)?,
+                session_timeout_minutes: row.get(8)?,
+                session_split_at_midnight: row.get(9)?,
+                session_split_on_campaign: row.get(10)?,
+                share_id: row.get(11)?,
+                created_at: row.get(12)?,
+                updated_at: row.get(13)?,
             })
         })?;
 
@@ -126,7 +134,7 @@ impl DuckDbBackend {
     pub async fn get_website(&self, id: &str) -> Result<Option<Website>> {
         let conn = self.conn.lock().await;
         let mut stmt = conn.prepare(
-            "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
+            "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, retention_days, session_timeout_minutes, COALESCE(session_split_at_midnight, FALSE), COALESCE(session_split_on_campaign, FALSE), share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
              FROM websites WHERE id = ?1",
         )?;
         let result = stmt
@@ -139,9 +147,13 @@ impl DuckDbBackend {
                     timezone: row.get(4)?,
                     ingest_peak_eps: row.get(5)?,
                     ingest_queue_max_events: row.get(6)?,
-                    share_id: row.get(7)?,
-                    created_at: row.get(8)?,
-                    updated_at: row.get(9)?,
+                    retention_days: row.get(7)?,
+                    session_timeout_minutes: row.get(8)?,
+                    session_split_at_midnight: row.get(9)?,
+                    session_split_on_campaign: row.get(10)?,
+                    share_id: row.get(11)?,
+                    created_at: row.get(12)?,
+                    updated_at: row.get(13)?,
                 })
             })
             .ok();
@@ -193,11 +205,35 @@ impl DuckDbBackend {
                 duckdb::params![ingest_queue_max_events, id],
             )?;
         }
+        if let Some(retention_days) = params.retention_days {
+            conn.execute(
+                "UPDATE websites SET retention_days = ?1, updated_at = CURRENT_TIMESTAMP WHERE id = ?2",
+                duckdb::params![retention_days, id],
+            )?;
+        }
+        if let Some(session_timeout_minutes) = params.session_timeout_minutes {
+            conn.execute(
+                "UPDATE websites SET session_timeout_minutes = ?1, updated_at = CURRENT_TIMESTAMP WHERE id = ?2",
+                duckdb::params![session_timeout_minutes, id],
+            )?;
+        }
+        if let Some(split_at_midnight) = params.session_split_at_midnight {
+            conn.execute(
+                "UPDATE websites SET session_split_at_midnight = ?1, updated_at = CURRENT_TIMESTAMP WHERE id = ?2",
+                duckdb::params![split_at_midnight, id],
+            )?;
+        }
+        if let Some(split_on_campaign) = params.session_split_on_campaign {
+            conn.execute(
+                "UPDATE websites SET session_split_on_campaign = ?1, updated_at = CURRENT_TIMESTAMP WHERE id = ?2",
+                duckdb::params![split_on_campaign, id],
+            )?;
+        }
 
         // Read back updated row.
         let website = conn
             .prepare(
-                "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
+                "SELECT id, tenant_id, name, domain, timezone, ingest_peak_eps, ingest_queue_max_events, retention_days, session_timeout_minutes, COALESCE(session_split_at_midnight, FALSE), COALESCE(session_split_on_campaign, FALSE), share_id, CAST(created_at AS VARCHAR), CAST(updated_at AS VARCHAR) \
                  FROM websites WHERE id = ?1",
             )?
             .query_row(duckdb::params![id], |row| {
@@ -209,9 +245,13 @@ impl DuckDbBackend {
                     timezone: row.get(4)?,
                     ingest_peak_eps: row.get(5)?,
                     ingest_queue_max_events: row.get(6)?,
-                    share_id: row.get(7)?,
-                    created_at: row.get(8)?,
-                    updated_at: row.get(9)?,
+                    retention_days: row.get(7)?,
+                    session_timeout_minutes: row.get(8)?,
+                    session_split_at_midnight: row.get(9)?,
+                    session_split_on_campaign: row.get(10)?,
+                    share_id: row.get(11)?,
+                    created_at: row.get(12)?,
+                    updated_at: row.get(13)?,
                 })
             })?;
 
@@ -226,7 +266,8 @@ impl DuckDbBackend {
     /// the same MVCC snapshot: when DELETE FROM websites runs, DuckDB sees the
     /// events as already deleted within the current transaction and the FK check
     /// passes. The EXISTS check must be inside the same transaction for this
-    /// to work correctly. Order: events → sessions → saved_reports → goals
+    /// to work correctly. Order: events → sessions → rollups → idempotency keys
+    /// → imports → website grants → saved_reports → experiments → goals
     /// → subscriptions/alerts/deliveries → campaign_links → tracking_pixels
     /// → funnel_steps → funnels → website.
     pub async fn delete_website(&self, id: &str) -> Result<bool> {
@@ -251,10 +292,28 @@ impl DuckDbBackend {
             "DELETE FROM sessions WHERE website_id = ?1",
             duckdb::params![id],
         )?;
+        for table in [
+            "rollup_daily_sessions",
+            "rollup_daily_dimensions",
+            "rollup_daily_dimension_sessions",
+            "rollup_state",
+            "ingest_idempotency_keys",
+            "imports",
+            "website_grants",
+        ] {
+            tx.execute(
+                &format!("DELETE FROM {table} WHERE website_id = ?1"),
+                duckdb::params![id],
+            )?;
+        }
         tx.execute(
             "DELETE FROM saved_reports WHERE website_id = ?1",
             duckdb::params![id],
         )?;
+        tx.execute(
+            "DELETE FROM experiments WHERE website_id = ?1",
+            duckdb::params![id],
+        )?;
         tx.execute(
             "DELETE FROM goals WHERE website_id = ?1",
             duckdb::params![id],
@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use chrono::{NaiveDate, Utc};
 use sparklytics_core::{
-    analytics::{AnalyticsBackend, AnalyticsFilter},
+    analytics::{
+        AnalyticsBackend, AnalyticsFilter, DimensionFilter, DimensionFilterOp, PropertyFilter,
+        PropertyFilterOp,
+    },
     billing::{BillingGate, NullBillingGate},
     event::Event,
 };
@@ -26,6 +29,7 @@ fn base_filter(start_date: NaiveDate, end_date: NaiveDate) -> AnalyticsFilter {
         filter_region: None,
         filter_city: None,
         filter_hostname: None,
+        filter_properties: Vec::new(),
         include_bots: false,
     }
 }
@@ -143,16 +147,16 @@ async fn test_analytics_filter_all_dimensions_accepted() {
 
     let today = Utc::now().date_naive();
     let mut filter = base_filter(today - chrono::Duration::days(1), today);
-    filter.filter_country = Some("PL".to_string());
-    filter.filter_page = Some("/".to_string());
-    filter.filter_referrer = Some("google.com".to_string());
-    filter.filter_browser = Some("Chrome".to_string());
-    filter.filter_os = Some("macOS".to_string());
-    filter.filter_device = Some("desktop".to_string());
-    filter.filter_language = Some("pl".to_string());
-    filter.filter_utm_source = Some("newsletter".to_string());
-    filter.filter_utm_medium = Some("email".to_string());
-    filter.filter_utm_campaign = Some("launch".to_string());
+    filter.filter_country = Some(DimensionFilter::is("PL"));
+    filter.filter_page = Some(DimensionFilter::new(DimensionFilterOp::Contains, "/"));
+    filter.filter_referrer = Some(DimensionFilter::is("google.com"));
+    filter.filter_browser = Some(DimensionFilter::is("Chrome"));
+    filter.filter_os = Some(DimensionFilter::is("macOS"));
+    filter.filter_device = Some(DimensionFilter::is("desktop"));
+    filter.filter_language = Some(DimensionFilter::is("pl"));
+    filter.filter_utm_source = Some(DimensionFilter::is("newsletter"));
+    filter.filter_utm_medium = Some(DimensionFilter::is("email"));
+    filter.filter_utm_campaign = Some(DimensionFilter::is("launch"));
 
     backend
         .get_stats("site_1", None, &filter, None)
@@ -178,9 +182,9 @@ async fn test_timeseries_all_filters_accepted() {
 
     let today = Utc::now().date_naive();
     let mut filter = base_filter(today - chrono::Duration::days(1), today);
-    filter.filter_referrer = Some("google.com".to_string());
-    filter.filter_browser = Some("Chrome".to_string());
-    filter.filter_os = Some("macOS".to_string());
+    filter.filter_referrer = Some(DimensionFilter::is("google.com"));
+    filter.filter_browser = Some(DimensionFilter::is("Chrome"));
+    filter.filter_os = Some(DimensionFilter::is("macOS"));
 
     backend
         .get_timeseries("site_1", None, &filter, None, None)
@@ -414,6 +418,236 @@ async fn test_custom_event_properties_extract_json_pairs() {
     }));
 }
 
+#[tokio::test]
+async fn test_dimension_filter_operators() {
+    let db = Arc::new(DuckDbBackend::open_in_memory().expect("db"));
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed");
+    let backend: Arc<dyn AnalyticsBackend> = db.clone();
+
+    let end = Utc::now().date_naive();
+    let start = end - chrono::Duration::days(1);
+    let ts = chrono::DateTime::<Utc>::from_naive_utc_and_offset(
+        end.and_hms_opt(12, 0, 0).expect("valid datetime"),
+        Utc,
+    );
+    let session = backend
+        .get_or_create_session("site_1", "visitor_1", None, "/")
+        .await
+        .expect("session");
+
+    let event = |url: &str, country: Option<&str>, browser: &str| {
+        let mut event =
+            sample_custom_event("site_1", session.clone(), "visitor_1", "view", None, ts);
+        event.url = url.to_string();
+        event.country = country.map(str::to_string);
+        event.browser = Some(browser.to_string());
+        event
+    };
+    backend
+        .insert_events(&[
+            event("https://example.com/blog/a", Some("US"), "Chrome"),
+            event("https://example.com/pricing", Some("PL"), "Firefox"),
+            event("https://Docs.Example.com:8080/blog/b", None, "Safari"),
+        ])
+        .await
+        .expect("insert");
+
+    let count_with = |configure: &dyn Fn(&mut AnalyticsFilter)| {
+        let backend = Arc::clone(&backend);
+        let mut filter = base_filter(start, end);
+        configure(&mut filter);
+        async move {
+            backend
+                .get_stats("site_1", None, &filter, None)
+                .await
+                .expect("stats")
+                .pageviews
+        }
+    };
+    let dim = |op: DimensionFilterOp, values: &[&str]| DimensionFilter {
+        op,
+        values: values.iter().map(|v| v.to_string()).collect(),
+    };
+
+    assert_eq!(count_with(&|_| {}).await, 3);
+    assert_eq!(
+        count_with(&|f| f.filter_country = Some(dim(DimensionFilterOp::IsNot, &["US"]))).await,
+        2
+    );
+    assert_eq!(
+        count_with(&|f| {
+            f.filter_browser = Some(dim(DimensionFilterOp::In, &["Chrome", "Firefox"]))
+        })
+        .await,
+        2
+    );
+    assert_eq!(
+        count_with(&|f| f.filter_page = Some(dim(DimensionFilterOp::NotContains, &["/blog"])))
+            .await,
+        1
+    );
+    assert_eq!(
+        count_with(&|f| {
+            f.filter_page = Some(dim(
+                DimensionFilterOp::Regex,
+                &["^https://example\\.com/(blog|pricing)"],
+            ))
+        })
+        .await,
+        2
+    );
+    assert_eq!(
+        count_with(&|f| f.filter_hostname = Some(DimensionFilter::is("docs.example.com"))).await,
+        1
+    );
+    assert_eq!(
+        count_with(&|f| f.filter_hostname = Some(DimensionFilter::is("Docs.Example.com:8080")))
+            .await,
+        1
+    );
+    assert_eq!(
+        count_with(&|f| {
+            f.filter_hostname = Some(dim(DimensionFilterOp::IsNot, &["docs.example.com:8080"]))
+        })
+        .await,
+        2
+    );
+    assert_eq!(
+        count_with(&|f| f.filter_country = Some(dim(DimensionFilterOp::Contains, &["P"]))).await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_property_filters_apply_to_stats_and_event_names() {
+    let db = Arc::new(DuckDbBackend::open_in_memory().expect("db"));
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed");
+    let backend: Arc<dyn AnalyticsBackend> = db.clone();
+
+    let end = Utc::now().date_naive();
+    let start = end - chrono::Duration::days(1);
+    let ts = chrono::DateTime::<Utc>::from_naive_utc_and_offset(
+        end.and_hms_opt(12, 0, 0).expect("valid datetime"),
+        Utc,
+    );
+
+    let s1 = backend
+        .get_or_create_session("site_1", "visitor_1", None, "/")
+        .await
+        .expect("session");
+    let s2 = backend
+        .get_or_create_session("site_1", "visitor_2", None, "/")
+        .await
+        .expect("session");
+
+    backend
+        .insert_events(&[
+            sample_custom_event(
+                "site_1",
+                s1.clone(),
+                "visitor_1",
+                "purchase",
+                Some(r#"{"plan":"pro","logged_in":true,"seats":5}"#),
+                ts,
+            ),
+            sample_custom_event(
+                "site_1",
+                s2.clone(),
+                "visitor_2",
+                "purchase",
+                Some(r#"{"plan":"pro-trial","logged_in":false}"#),
+                ts,
+            ),
+            sample_custom_event(
+                "site_1",
+                s2,
+                "visitor_2",
+                "signup",
+                Some(r#"{"plan":"free"}"#),
+                ts,
+            ),
+            sample_custom_event("site_1", s1, "visitor_1", "signup", None, ts),
+        ])
+        .await
+        .expect("insert");
+
+    let pageviews_with = |filters: Vec<PropertyFilter>| {
+        let backend = Arc::clone(&backend);
+        let mut filter = base_filter(start, end);
+        filter.filter_properties = filters;
+        async move {
+            backend
+                .get_stats("site_1", None, &filter, None)
+                .await
+                .expect("stats")
+                .pageviews
+        }
+    };
+    let prop =
+        |key: &str, op: PropertyFilterOp, value: Option<&str>, values: &[&str]| PropertyFilter {
+            key: key.to_string(),
+            op,
+            value: value.map(str::to_string),
+            values: values.iter().map(|v| v.to_string()).collect(),
+        };
+
+    assert_eq!(pageviews_with(vec![]).await, 4);
+    assert_eq!(
+        pageviews_with(vec![prop("plan", PropertyFilterOp::Eq, Some("pro"), &[])]).await,
+        1
+    );
+    assert_eq!(
+        pageviews_with(vec![prop("plan", PropertyFilterOp::Neq, Some("pro"), &[])]).await,
+        3
+    );
+    assert_eq!(
+        pageviews_with(vec![prop(
+            "plan",
+            PropertyFilterOp::Contains,
+            Some("pro"),
+            &[]
+        )])
+        .await,
+        2
+    );
+    assert_eq!(
+        pageviews_with(vec![prop(
+            "plan",
+            PropertyFilterOp::In,
+            None,
+            &["free", "pro-trial"]
+        )])
+        .await,
+        2
+    );
+    assert_eq!(
+        pageviews_with(vec![prop("plan", PropertyFilterOp::Exists, None, &[])]).await,
+        3
+    );
+    assert_eq!(
+        pageviews_with(vec![
+            prop("logged_in", PropertyFilterOp::Eq, Some("true"), &[]),
+            prop("seats", PropertyFilterOp::Eq, Some("5"), &[]),
+        ])
+        .await,
+        1
+    );
+
+    let mut filter = base_filter(start, end);
+    filter.filter_properties = vec![prop("plan", PropertyFilterOp::Contains, Some("pro"), &[])];
+    let names = backend
+        .get_event_names("site_1", None, &filter)
+        .await
+        .expect("event names");
+    assert_eq!(names.rows.len(), 1);
+    assert_eq!(names.rows[0].event_name, "purchase");
+    assert_eq!(names.rows[0].count, 2);
+}
+
 #[tokio::test]
 async fn test_custom_event_timeseries_zero_fills_buckets() {
     let db = Arc::new(DuckDbBackend::open_in_memory().expect("db"));
@@ -493,3 +727,71 @@ async fn test_billing_gate_moved_to_core() {
     assert_eq!(admission.allowed_events, 42);
     assert!(admission.reason.is_none());
 }
+
+#[tokio::test]
+async fn test_session_rules_split_sessions_per_website() {
+    use chrono::TimeZone;
+    use sparklytics_metadata::UpdateWebsiteParams;
+
+    let db = Arc::new(DuckDbBackend::open_in_memory().expect("db"));
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed");
+    let backend: Arc<dyn AnalyticsBackend> = db.clone();
+    let at = |h: u32, m: u32| {
+        Utc.with_ymd_and_hms(2026, 3, 10, h, m, 0)
+            .single()
+            .expect("valid time")
+    };
+
+    // Default rules: 45 minutes of inactivity ends the session.
+    let first = backend
+        .get_or_create_session_at("site_1", "visitor_1", None, "/", None, at(10, 0))
+        .await
+        .expect("session");
+    let idle = backend
+        .get_or_create_session_at("site_1", "visitor_1", None, "/", None, at(10, 45))
+        .await
+        .expect("session");
+    assert_ne!(first, idle);
+
+    db.update_website(
+        "site_1",
+        UpdateWebsiteParams {
+            name: None,
+            domain: None,
+            timezone: None,
+            ingest_peak_eps: None,
+            ingest_queue_max_events: None,
+            retention_days: None,
+            session_timeout_minutes: Some(Some(120)),
+            session_split_at_midnight: None,
+            session_split_on_campaign: Some(true),
+        },
+    )
+    .await
+    .expect("update website")
+    .expect("website exists");
+
+    let long_gap = backend
+        .get_or_create_session_at("site_1", "visitor_1", None, "/", None, at(12, 0))
+        .await
+        .expect("session");
+    assert_eq!(long_gap, idle, "a 75 minute gap fits the 2 hour timeout");
+
+    let campaign = backend
+        .get_or_create_session_at("site_1", "visitor_1", None, "/", Some("spring"), at(12, 5))
+        .await
+        .expect("session");
+    assert_ne!(campaign, idle, "a new campaign starts a new session");
+    let same_campaign = backend
+        .get_or_create_session_at("site_1", "visitor_1", None, "/", Some("spring"), at(12, 10))
+        .await
+        .expect("session");
+    let no_campaign = backend
+        .get_or_create_session_at("site_1", "visitor_1", None, "/pricing", None, at(12, 15))
+        .await
+        .expect("session");
+    assert_eq!(same_campaign, campaign);
+    assert_eq!(no_campaign, campaign);
+}
@@ -0,0 +1,142 @@
+use std::path::PathBuf;
+
+use sparklytics_duckdb::backup::{read_schema_version, validate_snapshot};
+use sparklytics_duckdb::duckdb::Connection;
+use sparklytics_duckdb::migrations::SCHEMA_VERSION;
+use sparklytics_duckdb::DuckDbBackend;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "sparklytics-backup-{name}-{}-{}",
+        std::process::id(),
+        uuid::Uuid::new_v4().simple()
+    ));
+    std::fs::create_dir_all(&dir).expect("create temp dir");
+    dir
+}
+
+#[tokio::test]
+async fn snapshot_copies_a_readable_database() {
+    let dir = temp_dir("snapshot");
+    let db_path = dir.join("sparklytics.db");
+    let db = DuckDbBackend::open(db_path.to_str().expect("utf-8 path"), "1GB").expect("open");
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed website");
+
+    let copy = dir.join("copy.db");
+    let bytes = db.snapshot_to(&copy).await.expect("snapshot");
+    assert!(bytes > 0);
+    // The live database keeps accepting writes after the copy.
+    db.seed_website("site_2", "example.org")
+        .await
+        .expect("seed after snapshot");
+
+    assert_eq!(validate_snapshot(&copy).expect("valid"), SCHEMA_VERSION);
+    let index_count = |path: &PathBuf| -> i64 {
+        let conn = Connection::open(path).expect("open file");
+        conn.query_row("SELECT COUNT(*) FROM duckdb_indexes()", [], |row| {
+            row.get(0)
+        })
+        .expect("count indexes")
+    };
+    drop(db);
+    let live_indexes = index_count(&db_path);
+    assert!(live_indexes > 0);
+    assert_eq!(index_count(&copy), live_indexes);
+    let restored =
+        DuckDbBackend::open(copy.to_str().expect("utf-8 path"), "1GB").expect("open copy");
+    assert!(restored
+        .get_website("site_1")
+        .await
+        .expect("lookup")
+        .is_some());
+    assert!(restored
+        .get_website("site_2")
+        .await
+        .expect("lookup")
+        .is_none());
+}
+
+#[tokio::test]
+async fn pinned_snapshot_excludes_later_writes() {
+    let dir = temp_dir("pinned");
+    let db_path = dir.join("sparklytics.db");
+    let db = DuckDbBackend::open(db_path.to_str().expect("utf-8 path"), "1GB").expect("open");
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed website");
+
+    let copy = dir.join("copy.db");
+    let pending = db.begin_snapshot(&copy).await.expect("pin snapshot");
+    // Writes between pinning and copying land only in the live database.
+    db.seed_website("site_2", "example.org")
+        .await
+        .expect("seed while pinned");
+    assert!(pending.write().await.expect("write snapshot") > 0);
+
+    let restored =
+        DuckDbBackend::open(copy.to_str().expect("utf-8 path"), "1GB").expect("open copy");
+    assert!(restored
+        .get_website("site_1")
+        .await
+        .expect("lookup")
+        .is_some());
+    assert!(restored
+        .get_website("site_2")
+        .await
+        .expect("lookup")
+        .is_none());
+    assert!(db.get_website("site_2").await.expect("lookup").is_some());
+
+    // A dropped snapshot rolls back and returns its connection to the pool.
+    let abandoned = dir.join("abandoned.db");
+    drop(db.begin_snapshot(&abandoned).await.expect("pin snapshot"));
+    assert!(
+        db.snapshot_to(&dir.join("again.db"))
+            .await
+            .expect("snapshot")
+            > 0
+    );
+}
+
+#[tokio::test]
+async fn in_memory_databases_cannot_be_snapshotted() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    let err = db
+        .snapshot_to(&temp_dir("memory").join("copy.db"))
+        .await
+        .expect_err("in-memory snapshot");
+    assert!(err.to_string().contains("in-memory"));
+}
+
+#[test]
+fn validate_rejects_newer_and_foreign_databases() {
+    let dir = temp_dir("validate");
+
+    let newer = dir.join("newer.db");
+    {
+        let conn = Connection::open(&newer).expect("open");
+        conn.execute_batch(
+            "CREATE TABLE _migrations (id VARCHAR PRIMARY KEY);
+             INSERT INTO _migrations VALUES ('0001_initial'), ('9999_future');",
+        )
+        .expect("seed migrations");
+    }
+    assert_eq!(
+        read_schema_version(&newer).expect("read"),
+        Some("9999_future".to_string())
+    );
+    let err = validate_snapshot(&newer).expect_err("newer schema");
+    assert!(err.to_string().contains("newer than this binary"));
+
+    let foreign = dir.join("foreign.db");
+    {
+        let conn = Connection::open(&foreign).expect("open");
+        conn.execute_batch("CREATE TABLE things (id INTEGER);")
+            .expect("create table");
+    }
+    assert_eq!(read_schema_version(&foreign).expect("read"), None);
+    let err = validate_snapshot(&foreign).expect_err("foreign database");
+    assert!(err.to_string().contains("not a Sparklytics database"));
+}
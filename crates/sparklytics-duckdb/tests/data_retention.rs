@@ -0,0 +1,161 @@
+use chrono::{Duration, Utc};
+
+use sparklytics_duckdb::DuckDbBackend;
+
+fn days_ago(days: i64) -> String {
+    (Utc::now() - Duration::days(days))
+        .naive_utc()
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+async fn insert_event_at(db: &DuckDbBackend, website_id: &str, event_id: &str, days: i64) {
+    let created_at = days_ago(days);
+    let conn = db.conn_for_test().await;
+    conn.execute(
+        r#"
+        INSERT INTO events (id, website_id, session_id, visitor_id, event_type, url, created_at)
+        VALUES (?1, ?2, ?3, 'visitor_1', 'pageview', '/', ?4)
+        "#,
+        duckdb::params![event_id, website_id, format!("sess_{event_id}"), created_at],
+    )
+    .expect("insert event");
+    conn.execute(
+        r#"
+        INSERT INTO sessions (session_id, website_id, visitor_id, first_seen, last_seen, entry_page)
+        VALUES (?1, ?2, 'visitor_1', ?3, ?3, '/')
+        "#,
+        duckdb::params![format!("sess_{event_id}"), website_id, created_at],
+    )
+    .expect("insert session");
+}
+
+async fn count(db: &DuckDbBackend, sql: &str) -> i64 {
+    let conn = db.conn_for_test().await;
+    conn.query_row(sql, [], |row| row.get(0)).expect("count")
+}
+
+#[tokio::test]
+async fn purge_removes_rows_older_than_default_retention() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed");
+    insert_event_at(&db, "site_1", "old", 40).await;
+    insert_event_at(&db, "site_1", "fresh", 5).await;
+    {
+        let conn = db.conn_for_test().await;
+        conn.execute(
+            r#"
+            INSERT INTO bot_policy_audit (id, website_id, actor, action, payload, created_at)
+            VALUES ('audit_old', 'site_1', 'admin', 'policy_update', '{}', ?1),
+                   ('audit_new', 'site_1', 'admin', 'policy_update', '{}', ?2)
+            "#,
+            duckdb::params![days_ago(40), days_ago(0)],
+        )
+        .expect("seed audit rows");
+        conn.execute(
+            r#"
+            INSERT INTO alert_rules (id, website_id, name, metric, condition_type, threshold_value, channel, target)
+            VALUES ('alert_1', 'site_1', 'Spike', 'pageviews', 'spike', 2.0, 'webhook', 'https://example.com/hook')
+            "#,
+            [],
+        )
+        .expect("seed alert rule");
+        conn.execute(
+            r#"
+            INSERT INTO notification_deliveries (id, source_type, source_id, idempotency_key, status, delivered_at)
+            VALUES ('del_old', 'alert', 'alert_1', 'k_old', 'sent', ?1),
+                   ('del_new', 'alert', 'alert_1', 'k_new', 'sent', ?2)
+            "#,
+            duckdb::params![days_ago(40), days_ago(0)],
+        )
+        .expect("seed deliveries");
+        conn.execute(
+            r#"
+            INSERT INTO ingest_idempotency_keys (website_id, idempotency_key, created_at)
+            VALUES ('site_1', 'key_old', ?1),
+                   ('site_1', 'key_new', ?2)
+            "#,
+            duckdb::params![days_ago(8), days_ago(1)],
+        )
+        .expect("seed idempotency keys");
+    }
+
+    let report = db.purge_expired_data(30, Utc::now()).await.expect("purge");
+
+    assert_eq!(report.events, 1);
+    assert_eq!(report.sessions, 1);
+    assert_eq!(report.bot_policy_audit, 1);
+    assert_eq!(report.notification_deliveries, 1);
+    assert_eq!(report.idempotency_keys, 1);
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM events").await, 1);
+    assert_eq!(
+        count(&db, "SELECT COUNT(*) FROM events WHERE id = 'fresh'").await,
+        1
+    );
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM sessions").await, 1);
+    assert_eq!(
+        count(
+            &db,
+            "SELECT COUNT(*) FROM notification_deliveries WHERE id = 'del_new'"
+        )
+        .await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn purge_honors_per_website_override() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    db.seed_website("site_short", "short.example.com")
+        .await
+        .expect("seed");
+    db.seed_website("site_long", "long.example.com")
+        .await
+        .expect("seed");
+    {
+        let conn = db.conn_for_test().await;
+        conn.execute_batch(
+            "UPDATE websites SET retention_days = 7 WHERE id = 'site_short';
+             UPDATE websites SET retention_days = 400 WHERE id = 'site_long';",
+        )
+        .expect("set overrides");
+    }
+    insert_event_at(&db, "site_short", "short_10d", 10).await;
+    insert_event_at(&db, "site_long", "long_100d", 100).await;
+
+    let report = db.purge_expired_data(30, Utc::now()).await.expect("purge");
+
+    assert_eq!(report.events, 1);
+    assert_eq!(
+        count(
+            &db,
+            "SELECT COUNT(*) FROM events WHERE website_id = 'site_short'"
+        )
+        .await,
+        0
+    );
+    assert_eq!(
+        count(
+            &db,
+            "SELECT COUNT(*) FROM events WHERE website_id = 'site_long'"
+        )
+        .await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn purge_with_zero_default_keeps_everything() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed");
+    insert_event_at(&db, "site_1", "ancient", 2000).await;
+
+    let report = db.purge_expired_data(0, Utc::now()).await.expect("purge");
+
+    assert_eq!(report.total(), 0);
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM events").await, 1);
+}
@@ -0,0 +1,146 @@
+use chrono::{Duration, NaiveDate, Utc};
+
+use sparklytics_core::event::Event;
+use sparklytics_duckdb::imports::NewImport;
+use sparklytics_duckdb::DuckDbBackend;
+
+fn event(session_id: &str, visitor_id: &str, url: &str, days_ago: i64, minute: i64) -> Event {
+    let created_at = Utc::now() - Duration::days(days_ago) + Duration::minutes(minute);
+    Event {
+        id: uuid::Uuid::new_v4().to_string(),
+        website_id: "site_1".to_string(),
+        tenant_id: None,
+        session_id: session_id.to_string(),
+        visitor_id: visitor_id.to_string(),
+        event_type: "pageview".to_string(),
+        url: url.to_string(),
+        referrer_url: None,
+        referrer_domain: None,
+        event_name: None,
+        event_data: None,
+        country: None,
+        region: None,
+        city: None,
+        browser: None,
+        browser_version: None,
+        os: None,
+        os_version: None,
+        device_type: None,
+        screen: None,
+        language: None,
+        utm_source: None,
+        utm_medium: None,
+        utm_campaign: None,
+        utm_term: None,
+        utm_content: None,
+        link_id: None,
+        pixel_id: None,
+        source_ip: None,
+        user_agent: None,
+        is_bot: false,
+        bot_score: 0,
+        bot_reason: None,
+        created_at,
+    }
+}
+
+async fn count(db: &DuckDbBackend, sql: &str) -> i64 {
+    let conn = db.conn_for_test().await;
+    conn.query_row(sql, [], |row| row.get(0)).expect("count")
+}
+
+#[tokio::test]
+async fn import_synthesises_sessions_and_rolls_back_cleanly() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed website");
+    db.insert_events(&[event("live_sess", "live_visitor", "/live", 0, 0)])
+        .await
+        .expect("insert live event");
+    db.refresh_rollups(Utc::now()).await.expect("refresh");
+
+    let events = vec![
+        event("imp_sess_a", "visitor_a", "/landing", 20, 0),
+        event("imp_sess_a", "visitor_a", "/pricing", 20, 3),
+        event("imp_sess_b", "visitor_b", "/", 10, 0),
+    ];
+    let record = db
+        .import_events(&NewImport {
+            id: "imp_1",
+            website_id: "site_1",
+            source: "plausible",
+            file_name: Some("events.csv"),
+            events: &events,
+            skipped_count: 2,
+        })
+        .await
+        .expect("import");
+    assert_eq!(record.status, "completed");
+    assert_eq!(record.event_count, 3);
+    assert_eq!(record.session_count, 2);
+    assert_eq!(record.skipped_count, 2);
+    assert!(record.first_event_at.is_some());
+    assert_eq!(
+        count(
+            &db,
+            "SELECT pageview_count FROM sessions WHERE session_id = 'imp_sess_a'"
+        )
+        .await,
+        2
+    );
+    assert_eq!(
+        count(
+            &db,
+            "SELECT COUNT(*) FROM sessions WHERE session_id = 'imp_sess_a' AND entry_page = '/landing'"
+        )
+        .await,
+        1
+    );
+    // Closed days were reopened for the next rollup refresh.
+    let first_day: NaiveDate = (Utc::now() - Duration::days(20)).date_naive();
+    assert_eq!(
+        count(
+            &db,
+            &format!(
+                "SELECT COUNT(*) FROM rollup_state WHERE rolled_up_through >= DATE '{first_day}'"
+            )
+        )
+        .await,
+        0
+    );
+
+    let listed = db.list_imports("site_1").await.expect("list");
+    assert_eq!(listed, vec![record]);
+    assert!(db.list_imports("site_2").await.expect("list").is_empty());
+
+    let rolled_back = db
+        .rollback_import("site_1", "imp_1")
+        .await
+        .expect("rollback")
+        .expect("import exists");
+    assert_eq!(rolled_back.status, "rolled_back");
+    assert!(rolled_back.rolled_back_at.is_some());
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM events").await, 1);
+    assert_eq!(
+        count(
+            &db,
+            "SELECT COUNT(*) FROM sessions WHERE import_id IS NOT NULL"
+        )
+        .await,
+        0
+    );
+
+    // Rolling back again is a no-op; unknown imports are reported as missing.
+    let again = db
+        .rollback_import("site_1", "imp_1")
+        .await
+        .expect("rollback again")
+        .expect("import exists");
+    assert_eq!(again, rolled_back);
+    assert!(db
+        .rollback_import("site_2", "imp_1")
+        .await
+        .expect("rollback other website")
+        .is_none());
+}
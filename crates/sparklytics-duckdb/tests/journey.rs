@@ -1,7 +1,7 @@
 use chrono::NaiveDate;
 
 use sparklytics_core::analytics::{
-    AnalyticsBackend, AnalyticsFilter, AnchorType, JourneyDirection, JourneyQuery,
+    AnalyticsBackend, AnalyticsFilter, AnchorType, DimensionFilter, JourneyDirection, JourneyQuery,
 };
 use sparklytics_duckdb::DuckDbBackend;
 
@@ -23,6 +23,7 @@ fn base_filter(day: NaiveDate) -> AnalyticsFilter {
         filter_region: None,
         filter_city: None,
         filter_hostname: None,
+        filter_properties: Vec::new(),
         include_bots: false,
     }
 }
@@ -317,7 +318,7 @@ async fn journey_applies_dimension_filters() {
     .await;
 
     let mut filter = base_filter(day);
-    filter.filter_country = Some("US".to_string());
+    filter.filter_country = Some(DimensionFilter::is("US"));
 
     let query = JourneyQuery {
         anchor_type: AnchorType::Page,
@@ -0,0 +1,195 @@
+use std::path::PathBuf;
+
+use sparklytics_duckdb::duckdb::Connection;
+use sparklytics_duckdb::migrations::{self, Migration, MIGRATIONS, SCHEMA_VERSION};
+use sparklytics_duckdb::DuckDbBackend;
+
+const WITH_WIDGETS: &[Migration] = &[
+    MIGRATIONS[0],
+    Migration {
+        id: "9000_widgets",
+        description: "Widgets table",
+        up: "CREATE TABLE widgets (id VARCHAR PRIMARY KEY);",
+        down: Some("DROP TABLE widgets;"),
+    },
+];
+
+fn temp_db(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "sparklytics-migrations-{name}-{}-{}",
+        std::process::id(),
+        uuid::Uuid::new_v4().simple()
+    ));
+    std::fs::create_dir_all(&dir).expect("create temp dir");
+    dir.join("sparklytics.db")
+}
+
+fn table_exists(conn: &Connection, table: &str) -> bool {
+    conn.query_row(
+        "SELECT COUNT(*) > 0 FROM information_schema.tables WHERE table_name = ?1",
+        [table],
+        |row| row.get(0),
+    )
+    .expect("query information_schema")
+}
+
+#[test]
+fn fresh_database_is_at_the_latest_version() {
+    let path = temp_db("fresh");
+    drop(DuckDbBackend::open(path.to_str().expect("utf-8 path"), "1GB").expect("open"));
+
+    let conn = Connection::open(&path).expect("reopen");
+    let status = migrations::status(&conn, MIGRATIONS).expect("status");
+    assert_eq!(status.current_version.as_deref(), Some(SCHEMA_VERSION));
+    assert_eq!(status.latest_version, SCHEMA_VERSION);
+    assert!(status.unknown.is_empty());
+    assert!(status
+        .migrations
+        .iter()
+        .all(|m| m.applied_at.is_some() && m.checksum_matches));
+    assert!(migrations::pending(&conn, MIGRATIONS)
+        .expect("pending")
+        .is_empty());
+}
+
+#[test]
+fn pending_migrations_apply_in_order_and_roll_back() {
+    let mut conn = Connection::open_in_memory().expect("open");
+    migrations::run(&mut conn, &MIGRATIONS[..1]).expect("baseline");
+
+    let pending = migrations::pending(&conn, WITH_WIDGETS).expect("pending");
+    assert_eq!(
+        pending.iter().map(|m| m.id).collect::<Vec<_>>(),
+        vec!["9000_widgets"]
+    );
+    assert!(!table_exists(&conn, "widgets"), "dry run must not apply");
+
+    let applied = migrations::run(&mut conn, WITH_WIDGETS).expect("run");
+    assert_eq!(applied, vec!["9000_widgets".to_string()]);
+    assert!(table_exists(&conn, "widgets"));
+    assert!(migrations::run(&mut conn, WITH_WIDGETS)
+        .expect("rerun")
+        .is_empty());
+
+    let rolled_back =
+        migrations::rollback_to(&mut conn, WITH_WIDGETS, "0001_initial").expect("rollback");
+    assert_eq!(rolled_back, vec!["9000_widgets".to_string()]);
+    assert!(!table_exists(&conn, "widgets"));
+    let status = migrations::status(&conn, WITH_WIDGETS).expect("status");
+    assert_eq!(status.current_version.as_deref(), Some("0001_initial"));
+
+    let err = migrations::rollback_to(&mut conn, WITH_WIDGETS, "9000_widgets")
+        .expect_err("target not applied");
+    assert!(err.to_string().contains("not applied"), "{err}");
+}
+
+#[test]
+fn irreversible_migrations_block_rollback() {
+    let mut conn = Connection::open_in_memory().expect("open");
+    let irreversible = [
+        Migration {
+            id: "0001_a",
+            description: "a",
+            up: "CREATE TABLE a (id INTEGER);",
+            down: None,
+        },
+        Migration {
+            id: "0002_b",
+            description: "b",
+            up: "CREATE TABLE b (id INTEGER);",
+            down: None,
+        },
+    ];
+    migrations::run(&mut conn, &irreversible).expect("run");
+    let err = migrations::rollback_to(&mut conn, &irreversible, "0001_a").expect_err("no down SQL");
+    assert!(
+        err.to_string().contains("0002_b cannot be rolled back"),
+        "{err}"
+    );
+    assert!(table_exists(&conn, "b"));
+}
+
+#[test]
+fn failed_migration_leaves_no_trace() {
+    let mut conn = Connection::open_in_memory().expect("open");
+    let broken = [Migration {
+        id: "0001_broken",
+        description: "broken",
+        up: "CREATE TABLE half (id INTEGER); SELECT * FROM missing_table;",
+        down: None,
+    }];
+    let err = migrations::run(&mut conn, &broken).expect_err("fails");
+    assert!(err.to_string().contains("0001_broken"), "{err}");
+    assert!(!table_exists(&conn, "half"));
+    assert_eq!(
+        migrations::pending(&conn, &broken).expect("pending").len(),
+        1
+    );
+}
+
+#[test]
+fn open_refuses_databases_from_a_newer_binary() {
+    let path = temp_db("newer");
+    drop(DuckDbBackend::open(path.to_str().expect("utf-8 path"), "1GB").expect("open"));
+    {
+        let conn = Connection::open(&path).expect("reopen");
+        conn.execute(
+            "INSERT INTO _migrations (id, checksum) VALUES ('9999_future', 'x')",
+            [],
+        )
+        .expect("insert future migration");
+    }
+
+    let err = match DuckDbBackend::open(path.to_str().expect("utf-8 path"), "1GB") {
+        Ok(_) => panic!("a newer schema must be refused"),
+        Err(err) => err,
+    };
+    assert!(err.to_string().contains("newer than this binary"), "{err}");
+}
+
+#[test]
+fn checksums_are_backfilled_then_enforced() {
+    let mut conn = Connection::open_in_memory().expect("open");
+    migrations::run(&mut conn, MIGRATIONS).expect("baseline");
+    conn.execute(
+        "UPDATE _migrations SET checksum = NULL WHERE id = ?1",
+        [MIGRATIONS[0].id],
+    )
+    .expect("clear checksum");
+
+    migrations::run(&mut conn, MIGRATIONS).expect("backfill");
+    let checksum: String = conn
+        .query_row(
+            "SELECT checksum FROM _migrations WHERE id = ?1",
+            [MIGRATIONS[0].id],
+            |row| row.get(0),
+        )
+        .expect("checksum");
+    assert_eq!(checksum, MIGRATIONS[0].checksum());
+
+    conn.execute(
+        "UPDATE _migrations SET checksum = 'edited' WHERE id = ?1",
+        [MIGRATIONS[0].id],
+    )
+    .expect("tamper");
+    let err = migrations::run(&mut conn, MIGRATIONS).expect_err("mismatch");
+    assert!(err.to_string().contains("was changed"), "{err}");
+    let status = migrations::status(&conn, MIGRATIONS).expect("status");
+    assert!(!status.migrations[0].checksum_matches);
+}
+
+#[test]
+fn shipped_migrations_roll_back_and_reapply() {
+    let mut conn = Connection::open_in_memory().expect("open");
+    migrations::run(&mut conn, MIGRATIONS).expect("run all");
+
+    let rolled_back = migrations::rollback_to(&mut conn, MIGRATIONS, "0001_initial")
+        .expect("roll back to baseline");
+    assert_eq!(rolled_back.len(), MIGRATIONS.len() - 1);
+    assert!(!table_exists(&conn, "users"));
+
+    let reapplied = migrations::run(&mut conn, MIGRATIONS).expect("reapply");
+    assert_eq!(reapplied.len(), MIGRATIONS.len() - 1);
+    let status = migrations::status(&conn, MIGRATIONS).expect("status");
+    assert_eq!(status.current_version.as_deref(), Some(SCHEMA_VERSION));
+}
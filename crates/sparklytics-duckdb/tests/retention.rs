@@ -1,7 +1,7 @@
 use chrono::NaiveDate;
 
 use sparklytics_core::analytics::{
-    AnalyticsBackend, AnalyticsFilter, RetentionGranularity, RetentionQuery,
+    AnalyticsBackend, AnalyticsFilter, DimensionFilter, RetentionGranularity, RetentionQuery,
 };
 use sparklytics_duckdb::DuckDbBackend;
 
@@ -23,6 +23,7 @@ fn base_filter(start: NaiveDate, end: NaiveDate) -> AnalyticsFilter {
         filter_region: None,
         filter_city: None,
         filter_hostname: None,
+        filter_properties: Vec::new(),
         include_bots: false,
     }
 }
@@ -195,7 +196,7 @@ async fn retention_respects_country_filter_for_cohort_population() {
         NaiveDate::from_ymd_opt(2026, 1, 1).expect("valid"),
         NaiveDate::from_ymd_opt(2026, 1, 10).expect("valid"),
     );
-    filter.filter_country = Some("PL".to_string());
+    filter.filter_country = Some(DimensionFilter::is("PL"));
 
     let result = db
         .get_retention(
@@ -0,0 +1,386 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use sparklytics_core::analytics::{AnalyticsFilter, DimensionFilter, DimensionFilterOp};
+use sparklytics_core::event::Event;
+use sparklytics_duckdb::queries::stats::StatsParams;
+use sparklytics_duckdb::DuckDbBackend;
+
+fn filter(start_date: NaiveDate, end_date: NaiveDate, include_bots: bool) -> AnalyticsFilter {
+    AnalyticsFilter {
+        start_date,
+        end_date,
+        timezone: None,
+        filter_country: None,
+        filter_page: None,
+        filter_referrer: None,
+        filter_browser: None,
+        filter_os: None,
+        filter_device: None,
+        filter_language: None,
+        filter_utm_source: None,
+        filter_utm_medium: None,
+        filter_utm_campaign: None,
+        filter_region: None,
+        filter_city: None,
+        filter_hostname: None,
+        filter_properties: Vec::new(),
+        include_bots,
+    }
+}
+
+fn event(
+    session_id: &str,
+    visitor_id: &str,
+    url: &str,
+    is_bot: bool,
+    created_at: DateTime<Utc>,
+) -> Event {
+    Event {
+        id: uuid::Uuid::new_v4().to_string(),
+        website_id: "site_1".to_string(),
+        tenant_id: None,
+        session_id: session_id.to_string(),
+        visitor_id: visitor_id.to_string(),
+        event_type: "pageview".to_string(),
+        url: url.to_string(),
+        referrer_url: None,
+        referrer_domain: (url == "/").then(|| "google.com".to_string()),
+        event_name: None,
+        event_data: None,
+        country: Some("PL".to_string()),
+        region: None,
+        city: None,
+        browser: Some("Chrome".to_string()),
+        browser_version: None,
+        os: Some("macOS".to_string()),
+        os_version: None,
+        device_type: Some("desktop".to_string()),
+        screen: None,
+        language: Some("pl".to_string()),
+        utm_source: None,
+        utm_medium: None,
+        utm_campaign: None,
+        utm_term: None,
+        utm_content: None,
+        link_id: None,
+        pixel_id: None,
+        source_ip: None,
+        user_agent: None,
+        is_bot,
+        bot_score: if is_bot { 90 } else { 0 },
+        bot_reason: None,
+        created_at,
+    }
+}
+
+fn at(day: NaiveDate, hour: u32, minute: u32) -> DateTime<Utc> {
+    day.and_hms_opt(hour, minute, 0)
+        .expect("valid time")
+        .and_utc()
+}
+
+/// Three closed days plus today. `visitor_a` returns on several days and
+/// `sess_night` straddles midnight into today.
+async fn seed(db: &DuckDbBackend, today: NaiveDate) {
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed website");
+    let d3 = today - Duration::days(3);
+    let d2 = today - Duration::days(2);
+    let d1 = today - Duration::days(1);
+    let events = vec![
+        event("sess_a1", "visitor_a", "/", false, at(d3, 9, 0)),
+        event("sess_a1", "visitor_a", "/pricing", false, at(d3, 9, 5)),
+        event("sess_b1", "visitor_b", "/", false, at(d3, 12, 0)),
+        event("sess_a2", "visitor_a", "/docs", false, at(d2, 10, 0)),
+        event("sess_bot", "visitor_bot", "/", true, at(d2, 11, 0)),
+        event("sess_night", "visitor_c", "/", false, at(d1, 23, 50)),
+        event("sess_a3", "visitor_a", "/pricing", false, at(d1, 8, 0)),
+        event("sess_night", "visitor_c", "/docs", false, at(today, 0, 10)),
+        event("sess_d", "visitor_d", "/", false, at(today, 0, 20)),
+    ];
+    db.insert_events(&events).await.expect("insert events");
+    let conn = db.conn_for_test().await;
+    conn.execute_batch(
+        r#"
+        INSERT INTO sessions (session_id, website_id, visitor_id, first_seen, last_seen, pageview_count, entry_page, is_bot)
+        SELECT session_id, website_id, ANY_VALUE(visitor_id), MIN(created_at), MAX(created_at),
+               COUNT(*), MIN(url), BOOL_OR(is_bot)
+        FROM events
+        GROUP BY session_id, website_id;
+        "#,
+    )
+    .expect("insert sessions");
+}
+
+/// Stats, daily timeseries and page/referrer metrics as one comparable value.
+async fn dashboard(db: &DuckDbBackend, filter: &AnalyticsFilter) -> serde_json::Value {
+    let stats = db
+        .get_stats(&StatsParams::from_filter("site_1", filter, None))
+        .await
+        .expect("stats");
+    let timeseries = db
+        .get_timeseries("site_1", filter, Some("day"), None)
+        .await
+        .expect("timeseries");
+    let (pages, page_pagination) = db
+        .get_metrics("site_1", "page", 10, 0, filter, None)
+        .await
+        .expect("page metrics");
+    let (referrers, _) = db
+        .get_metrics("site_1", "referrer", 10, 0, filter, None)
+        .await
+        .expect("referrer metrics");
+    serde_json::json!({
+        "stats": stats,
+        "timeseries": timeseries,
+        "pages": pages,
+        "pages_total": page_pagination.total,
+        "referrers": referrers,
+    })
+}
+
+async fn count(db: &DuckDbBackend, sql: &str) -> i64 {
+    let conn = db.conn_for_test().await;
+    conn.query_row(sql, [], |row| row.get(0)).expect("count")
+}
+
+#[tokio::test]
+async fn rollups_match_raw_results_and_serve_closed_days() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    let now = Utc::now();
+    let today = now.date_naive();
+    seed(&db, today).await;
+
+    let range = filter(today - Duration::days(6), today, false);
+    let with_bots = filter(today - Duration::days(6), today, true);
+    let raw = dashboard(&db, &range).await;
+    let raw_with_bots = dashboard(&db, &with_bots).await;
+
+    let report = db.refresh_rollups(now).await.expect("refresh");
+    assert_eq!(report.websites, 1);
+    assert_eq!(report.days, 3);
+    assert!(count(&db, "SELECT COUNT(*) FROM rollup_daily_sessions").await > 0);
+    assert_eq!(dashboard(&db, &range).await, raw);
+    assert_eq!(dashboard(&db, &with_bots).await, raw_with_bots);
+
+    // A second run has nothing left to aggregate.
+    let report = db.refresh_rollups(now).await.expect("refresh again");
+    assert_eq!(report.days, 0);
+
+    // With raw closed-day events gone, unfiltered queries still answer from
+    // rollups while dimension-filtered queries fall back to raw events.
+    {
+        let conn = db.conn_for_test().await;
+        conn.execute(
+            "DELETE FROM events WHERE created_at < CAST(?1 AS TIMESTAMP)",
+            duckdb::params![today.format("%Y-%m-%d").to_string()],
+        )
+        .expect("drop raw closed days");
+    }
+    assert_eq!(dashboard(&db, &range).await, raw);
+    assert_eq!(raw["stats"]["visitors"], 4);
+    assert_eq!(raw["stats"]["pageviews"], 8);
+
+    let mut by_country = range.clone();
+    by_country.filter_country = Some(DimensionFilter {
+        op: DimensionFilterOp::Is,
+        values: vec!["PL".to_string()],
+    });
+    let filtered = db
+        .get_stats(&StatsParams::from_filter("site_1", &by_country, None))
+        .await
+        .expect("filtered stats");
+    assert_eq!(filtered.pageviews, 2);
+}
+
+#[tokio::test]
+async fn late_events_reopen_rolled_up_days() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    let now = Utc::now();
+    let today = now.date_naive();
+    seed(&db, today).await;
+    db.refresh_rollups(now).await.expect("refresh");
+
+    let yesterday = today - Duration::days(1);
+    db.insert_events(&[event(
+        "sess_late",
+        "visitor_late",
+        "/late",
+        false,
+        at(yesterday, 15, 0),
+    )])
+    .await
+    .expect("insert late event");
+
+    assert_eq!(
+        count(
+            &db,
+            &format!("SELECT COUNT(*) FROM rollup_daily_sessions WHERE day >= DATE '{yesterday}'"),
+        )
+        .await,
+        0
+    );
+    let range = filter(today - Duration::days(6), today, false);
+    let stats = db
+        .get_stats(&StatsParams::from_filter("site_1", &range, None))
+        .await
+        .expect("stats");
+    assert_eq!(stats.pageviews, 9);
+    assert_eq!(stats.visitors, 5);
+
+    let report = db.refresh_rollups(now).await.expect("refresh");
+    assert_eq!(report.days, 1);
+    let (pages, _) = db
+        .get_metrics("site_1", "page", 10, 0, &range, None)
+        .await
+        .expect("metrics");
+    assert!(pages.rows.iter().any(|row| row.value == "/late"));
+}
+
+/// `dashboard` answered from raw events only, by forgetting the watermark.
+async fn raw_dashboard(db: &DuckDbBackend, filter: &AnalyticsFilter) -> serde_json::Value {
+    {
+        let conn = db.conn_for_test().await;
+        conn.execute("DELETE FROM rollup_state", [])
+            .expect("clear watermark");
+    }
+    dashboard(db, filter).await
+}
+
+#[tokio::test]
+async fn rolled_up_visitor_counts_stay_exact_on_large_days() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed website");
+    let now = Utc::now();
+    let today = now.date_naive();
+    let d1 = today - Duration::days(1);
+    let events: Vec<Event> = (0..3000)
+        .map(|i| {
+            event(
+                &format!("sess_{i}"),
+                &format!("visitor_{i}"),
+                "/",
+                false,
+                at(d1, 12, i % 60),
+            )
+        })
+        .collect();
+    db.insert_events(&events).await.expect("insert events");
+
+    let range = filter(d1, today, false);
+    db.refresh_rollups(now).await.expect("refresh");
+    let rolled_up = dashboard(&db, &range).await;
+    assert_eq!(rolled_up["pages"]["rows"][0]["visitors"], 3000);
+    assert_eq!(rolled_up["pages"]["rows"][0]["pageviews"], 3000);
+    assert_eq!(
+        count(
+            &db,
+            "SELECT COUNT(*) FROM rollup_daily_dimensions WHERE dimension = 'page'"
+        )
+        .await,
+        1
+    );
+    assert_eq!(raw_dashboard(&db, &range).await, rolled_up);
+}
+
+#[tokio::test]
+async fn sessions_across_midnight_count_once_in_rollups() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed website");
+    let now = Utc::now();
+    let today = now.date_naive();
+    let d2 = today - Duration::days(2);
+    let d1 = today - Duration::days(1);
+    db.insert_events(&[
+        // Crosses from one closed day into the next on the same page.
+        event("sess_span", "visitor_a", "/", false, at(d2, 23, 55)),
+        event("sess_span", "visitor_a", "/", false, at(d1, 0, 5)),
+        // Mixes bot and human events.
+        event("sess_mixed", "visitor_b", "/", false, at(d2, 9, 0)),
+        event("sess_mixed", "visitor_b", "/", true, at(d2, 9, 10)),
+        // Still open at midnight; continues after the refresh.
+        event("sess_night", "visitor_c", "/", false, at(d1, 23, 50)),
+        // Stays within one day.
+        event("sess_day", "visitor_d", "/", false, at(d1, 12, 0)),
+    ])
+    .await
+    .expect("insert events");
+
+    db.refresh_rollups(now).await.expect("refresh");
+    assert_eq!(
+        count(
+            &db,
+            "SELECT COUNT(*) FROM rollup_daily_dimension_sessions WHERE dimension = 'page'"
+        )
+        .await,
+        5
+    );
+    db.insert_events(&[event(
+        "sess_night",
+        "visitor_c",
+        "/",
+        false,
+        at(today, 0, 10),
+    )])
+    .await
+    .expect("insert continuation");
+
+    for include_bots in [false, true] {
+        let range = filter(d2, today, include_bots);
+        let rolled_up = dashboard(&db, &range).await;
+        let (pages, _) = db
+            .get_metrics("site_1", "page", 10, 0, &range, None)
+            .await
+            .expect("page metrics");
+        // sess_day bounces, and so does sess_mixed once its bot event is
+        // filtered out.
+        let bounce_rate = if include_bots { 25.0 } else { 50.0 };
+        assert_eq!(pages.rows[0].bounce_rate, bounce_rate);
+        assert_eq!(raw_dashboard(&db, &range).await, rolled_up);
+        db.refresh_rollups(now).await.expect("refresh");
+    }
+}
+
+#[tokio::test]
+async fn retention_expires_raw_events_and_rollups_by_whole_days() {
+    let db = DuckDbBackend::open_in_memory().expect("db");
+    db.seed_website("site_1", "example.com")
+        .await
+        .expect("seed website");
+    let now = Utc::now();
+    let today = now.date_naive();
+    let boundary = (now - Duration::days(30)).date_naive();
+    db.insert_events(&[
+        event(
+            "sess_old",
+            "visitor_a",
+            "/",
+            false,
+            at(boundary - Duration::days(1), 12, 0),
+        ),
+        event("sess_early", "visitor_b", "/", false, at(boundary, 0, 0)),
+        event(
+            "sess_late",
+            "visitor_c",
+            "/docs",
+            false,
+            at(boundary, 23, 59),
+        ),
+    ])
+    .await
+    .expect("insert events");
+    db.refresh_rollups(now).await.expect("refresh");
+
+    let report = db.purge_expired_data(30, now).await.expect("purge");
+    assert_eq!(report.events, 1);
+
+    let range = filter(boundary - Duration::days(1), today, false);
+    let rolled_up = dashboard(&db, &range).await;
+    assert_eq!(rolled_up["stats"]["pageviews"], 2);
+    assert_eq!(raw_dashboard(&db, &range).await, rolled_up);
+}
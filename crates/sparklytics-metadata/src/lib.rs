@@ -1,6 +1,7 @@
 use async_trait::async_trait;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use sparklytics_core::analytics::BotPolicy;
+use sparklytics_core::session::SessionRules;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ApiKeyRecord {
@@ -10,6 +11,166 @@ pub struct ApiKeyRecord {
     pub created_at: String,
     pub last_used_at: Option<String>,
     pub revoked_at: Option<String>,
+    #[serde(flatten)]
+    pub scope: ApiKeyScope,
+}
+
+/// Whether an API key may only read or may also change data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiKeyAccess {
+    Read,
+    Write,
+}
+
+impl ApiKeyAccess {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ApiKeyAccess::Read => "read",
+            ApiKeyAccess::Write => "write",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "read" => Some(ApiKeyAccess::Read),
+            "write" => Some(ApiKeyAccess::Write),
+            _ => None,
+        }
+    }
+}
+
+/// What an API key is allowed to touch. Keys created before scopes existed
+/// have `write` access to every website and never expire.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiKeyScope {
+    pub access: ApiKeyAccess,
+    /// Websites the key may access; `None` means every website.
+    pub website_ids: Option<Vec<String>>,
+    /// UTC timestamp after which the key stops working.
+    pub expires_at: Option<String>,
+}
+
+/// Role of a self-hosted user account, ordered by privilege.
+///
+/// - `viewer`: read-only access to the websites they are granted.
+/// - `admin`: manages every website, API keys and non-owner users.
+/// - `owner`: everything, including owner accounts. The account created by
+///   `POST /api/auth/setup` is always an owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    Viewer,
+    Admin,
+    Owner,
+}
+
+impl UserRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserRole::Viewer => "viewer",
+            UserRole::Admin => "admin",
+            UserRole::Owner => "owner",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "viewer" => Some(UserRole::Viewer),
+            "admin" => Some(UserRole::Admin),
+            "owner" => Some(UserRole::Owner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UserRecord {
+    pub id: String,
+    pub email: String,
+    pub name: Option<String>,
+    pub role: UserRole,
+    /// Websites a viewer may read. Admins and owners can access every website.
+    pub website_ids: Vec<String>,
+    pub created_at: String,
+    pub last_login_at: Option<String>,
+    pub disabled_at: Option<String>,
+}
+
+/// Returned (inside `anyhow::Error`) when accepting an invite, or signing in
+/// with an unverified SSO email, for an email that already has an account.
+#[derive(Debug, Clone)]
+pub struct UserEmailTaken(pub String);
+
+impl std::fmt::Display for UserEmailTaken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a user with email {} already exists", self.0)
+    }
+}
+
+impl std::error::Error for UserEmailTaken {}
+
+#[derive(Debug, Clone)]
+pub struct UpdateUserParams {
+    pub name: Option<String>,
+    pub role: Option<UserRole>,
+    pub website_ids: Option<Vec<String>>,
+    pub disabled: Option<bool>,
+}
+
+/// An identity asserted by the single sign-on provider.
+#[derive(Debug, Clone)]
+pub struct SsoUserParams {
+    pub issuer: String,
+    /// The provider's stable `sub` for the user.
+    pub subject: String,
+    pub email: String,
+    /// Whether the provider vouches for `email` (`email_verified: true`).
+    /// Only verified emails are linked to existing accounts.
+    pub email_verified: bool,
+    pub name: Option<String>,
+    /// Role mapped from the provider's claims. Applied to accounts created
+    /// through SSO; accounts with a local password keep their own role.
+    pub role: UserRole,
+}
+
+/// A TOTP second factor for a login account: the setup admin (`admin`) or a
+/// user id.
+#[derive(Debug, Clone)]
+pub struct TotpFactor {
+    pub account_id: String,
+    /// Base32 shared secret, as shown in the provisioning URI.
+    pub secret: String,
+    /// Set once a code from the authenticator has been verified. Logins only
+    /// require a second factor after that.
+    pub confirmed_at: Option<String>,
+    /// Newest accepted 30-second time step; older or equal steps are replays.
+    pub last_used_step: Option<i64>,
+    pub recovery_codes_remaining: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InviteRecord {
+    pub id: String,
+    pub email: String,
+    pub role: UserRole,
+    pub website_ids: Vec<String>,
+    pub invited_by: Option<String>,
+    pub created_at: String,
+    pub expires_at: String,
+    pub accepted_at: Option<String>,
+    pub revoked_at: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateInviteParams {
+    pub email: String,
+    pub role: UserRole,
+    pub website_ids: Vec<String>,
+    /// sha256 of the invite token; the raw token is only ever emailed.
+    pub token_hash: String,
+    pub invited_by: Option<String>,
+    pub expires_in_days: i64,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -21,11 +182,27 @@ pub struct Website {
     pub timezone: String,
     pub ingest_peak_eps: Option<i64>,
     pub ingest_queue_max_events: Option<i64>,
+    pub retention_days: Option<i64>,
+    pub session_timeout_minutes: Option<i64>,
+    pub session_split_at_midnight: bool,
+    pub session_split_on_campaign: bool,
     pub share_id: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+impl Website {
+    /// Session splitting rules configured for this website.
+    pub fn session_rules(&self) -> SessionRules {
+        SessionRules::from_website(
+            self.session_timeout_minutes,
+            self.session_split_at_midnight,
+            self.session_split_on_campaign,
+            &self.timezone,
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CreateWebsiteParams {
     pub name: String,
@@ -48,6 +225,18 @@ pub struct UpdateWebsiteParams {
     /// - `Some(Some(v))`: set explicit value
     /// - `Some(None)`: clear value (fall back to runtime defaults)
     pub ingest_queue_max_events: Option<Option<i64>>,
+    /// Tri-state:
+    /// - `None`: do not change
+    /// - `Some(Some(v))`: set explicit value
+    /// - `Some(None)`: clear value (fall back to `SPARKLYTICS_RETENTION_DAYS`)
+    pub retention_days: Option<Option<i64>>,
+    /// Tri-state:
+    /// - `None`: do not change
+    /// - `Some(Some(v))`: set explicit value
+    /// - `Some(None)`: clear value (fall back to the 30-minute default)
+    pub session_timeout_minutes: Option<Option<i64>>,
+    pub session_split_at_midnight: Option<bool>,
+    pub session_split_on_campaign: Option<bool>,
 }
 
 /// Storage interface for non-analytics metadata operations.
@@ -79,6 +268,7 @@ pub trait MetadataStore: Send + Sync + 'static {
         name: &str,
         hash: &str,
         prefix: &str,
+        scope: &ApiKeyScope,
     ) -> anyhow::Result<()>;
     async fn revoke_api_key(&self, key_id: &str) -> anyhow::Result<bool>;
     async fn list_api_keys(
@@ -90,6 +280,69 @@ pub trait MetadataStore: Send + Sync + 'static {
     async fn check_login_rate_limit(&self, ip: &str) -> anyhow::Result<bool>;
     async fn prune_login_attempts(&self) -> anyhow::Result<u64>;
 
+    async fn list_users(&self) -> anyhow::Result<Vec<UserRecord>>;
+    async fn get_user(&self, id: &str) -> anyhow::Result<Option<UserRecord>>;
+    /// Look up an enabled user by email for login. Returns the user and
+    /// their password hash.
+    async fn get_user_login(&self, email: &str) -> anyhow::Result<Option<(UserRecord, String)>>;
+    async fn update_user(
+        &self,
+        id: &str,
+        params: UpdateUserParams,
+    ) -> anyhow::Result<Option<UserRecord>>;
+    async fn delete_user(&self, id: &str) -> anyhow::Result<bool>;
+    async fn set_user_password(&self, id: &str, password_hash: &str) -> anyhow::Result<bool>;
+    async fn touch_user_login(&self, id: &str) -> anyhow::Result<()>;
+    /// Find the account linked to an SSO identity, linking an account with
+    /// the same verified email or creating one if needed. The mapped role is
+    /// applied to SSO-only accounts. Fails with [`UserEmailTaken`] when an
+    /// unverified email belongs to another account. Disabled accounts are
+    /// returned as-is for the caller to reject.
+    async fn upsert_sso_user(&self, params: SsoUserParams) -> anyhow::Result<UserRecord>;
+
+    async fn get_totp_factor(&self, account_id: &str) -> anyhow::Result<Option<TotpFactor>>;
+    /// Store a new unconfirmed secret, replacing any previous factor and its
+    /// recovery codes.
+    async fn begin_totp_enrollment(&self, account_id: &str, secret: &str) -> anyhow::Result<()>;
+    /// Confirm a pending factor at `step` and store its recovery code hashes.
+    /// Returns `false` when there is no pending factor.
+    async fn confirm_totp_enrollment(
+        &self,
+        account_id: &str,
+        step: i64,
+        recovery_code_hashes: &[String],
+    ) -> anyhow::Result<bool>;
+    /// Record a code accepted at `step`. Returns `false` (and records
+    /// nothing) if that step or a later one was already used.
+    async fn use_totp_step(&self, account_id: &str, step: i64) -> anyhow::Result<bool>;
+    /// Consume an unused recovery code. Returns `false` if none matches.
+    async fn use_totp_recovery_code(
+        &self,
+        account_id: &str,
+        code_hash: &str,
+    ) -> anyhow::Result<bool>;
+    async fn replace_totp_recovery_codes(
+        &self,
+        account_id: &str,
+        recovery_code_hashes: &[String],
+    ) -> anyhow::Result<()>;
+    async fn delete_totp_factor(&self, account_id: &str) -> anyhow::Result<bool>;
+
+    /// Create an invite, revoking any pending invite for the same email.
+    async fn create_invite(&self, params: CreateInviteParams) -> anyhow::Result<InviteRecord>;
+    /// Invites that have been neither accepted nor revoked.
+    async fn list_pending_invites(&self) -> anyhow::Result<Vec<InviteRecord>>;
+    async fn revoke_invite(&self, id: &str) -> anyhow::Result<bool>;
+    /// Turn a pending, unexpired invite into a user account.
+    ///
+    /// Returns `Ok(None)` when no such invite matches `token_hash`.
+    async fn accept_invite(
+        &self,
+        token_hash: &str,
+        name: Option<&str>,
+        password_hash: &str,
+    ) -> anyhow::Result<Option<UserRecord>>;
+
     async fn create_website(&self, params: CreateWebsiteParams) -> anyhow::Result<Website>;
     async fn list_websites(
         &self,
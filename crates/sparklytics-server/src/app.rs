@@ -47,6 +47,7 @@ fn auth_mode_runtime_value(auth_mode: &AuthMode) -> &'static str {
         AuthMode::None => "none",
         AuthMode::Password(_) => "password",
         AuthMode::Local => "local",
+        AuthMode::Oidc(_) => "oidc",
     }
 }
 
@@ -234,6 +235,20 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/events/timeseries",
                     get(routes::events::get_event_timeseries),
                 )
+                .route(
+                    "/api/websites/{id}/events/ingest",
+                    post(routes::ingest::ingest_events),
+                )
+                .route(
+                    "/api/websites/{id}/imports",
+                    get(routes::imports::list_imports)
+                        .post(routes::imports::create_import)
+                        .layer(DefaultBodyLimit::max(routes::imports::IMPORT_BODY_LIMIT)),
+                )
+                .route(
+                    "/api/websites/{id}/imports/{import_id}/rollback",
+                    post(routes::imports::rollback_import),
+                )
                 .route(
                     "/api/websites/{id}/sessions",
                     get(routes::sessions::list_sessions),
@@ -278,6 +293,21 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/funnels/{funnel_id}/results",
                     get(routes::funnels::get_funnel_results),
                 )
+                .route(
+                    "/api/websites/{id}/experiments",
+                    get(routes::experiments::list_experiments)
+                        .post(routes::experiments::create_experiment),
+                )
+                .route(
+                    "/api/websites/{id}/experiments/{experiment_id}",
+                    get(routes::experiments::get_experiment)
+                        .put(routes::experiments::update_experiment)
+                        .delete(routes::experiments::delete_experiment),
+                )
+                .route(
+                    "/api/websites/{id}/experiments/{experiment_id}/results",
+                    get(routes::experiments::get_experiment_results),
+                )
                 .route(
                     "/api/websites/{id}/journey",
                     get(routes::journey::get_journey),
@@ -358,6 +388,10 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/subscriptions/{subscription_id}/test",
                     post(routes::notifications::test_subscription),
                 )
+                .route(
+                    "/api/websites/{id}/subscriptions/{subscription_id}/rotate-secret",
+                    post(routes::notifications::rotate_subscription_secret),
+                )
                 .route(
                     "/api/websites/{id}/alerts",
                     get(routes::notifications::list_alerts)
@@ -372,10 +406,18 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/alerts/{alert_id}/test",
                     post(routes::notifications::test_alert),
                 )
+                .route(
+                    "/api/websites/{id}/alerts/{alert_id}/rotate-secret",
+                    post(routes::notifications::rotate_alert_secret),
+                )
                 .route(
                     "/api/websites/{id}/notifications/history",
                     get(routes::notifications::notification_history),
                 )
+                .route(
+                    "/api/websites/{id}/notifications/history/{delivery_id}/replay",
+                    post(routes::notifications::replay_delivery),
+                )
                 .route(
                     "/api/websites/{id}/links",
                     get(routes::links::list_links).post(routes::links::create_link),
@@ -412,6 +454,10 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/realtime",
                     get(routes::realtime::get_realtime),
                 )
+                .route(
+                    "/api/websites/{id}/realtime/stream",
+                    get(routes::realtime::stream_realtime),
+                )
                 .route(
                     "/api/websites/{id}/ingest-limits",
                     get(routes::ingest_limits::get_ingest_limits)
@@ -425,6 +471,10 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/export",
                     get(routes::export::export_events),
                 )
+                .route(
+                    "/api/backups",
+                    get(routes::backups::list_backups).post(routes::backups::create_backup),
+                )
                 .route(
                     "/api/admin/limits/plans",
                     get(routes::admin_limits::list_plan_limits),
@@ -451,7 +501,13 @@ pub fn build_app(state: Arc<AppState>) -> Router {
             app = app
                 .route("/api/auth/status", get(auth::handlers::auth_status))
                 .route("/api/auth/setup", post(auth::handlers::auth_setup))
-                .route("/api/auth/login", post(auth::handlers::auth_login));
+                .route("/api/auth/login", post(auth::handlers::auth_login))
+                .route("/api/auth/invites/accept", post(auth::users::accept_invite));
+            if matches!(auth_mode, AuthMode::Oidc(_)) {
+                app = app
+                    .route("/api/auth/oidc/login", get(auth::oidc::oidc_login))
+                    .route("/api/auth/oidc/callback", get(auth::oidc::oidc_callback));
+            }
 
             // Cloud bearer-token routes use their own internal authz checks.
             // Keep these outside cookie/API-key middleware so Clerk Bearer tokens
@@ -502,6 +558,20 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/events/timeseries",
                     get(routes::events::get_event_timeseries),
                 )
+                .route(
+                    "/api/websites/{id}/events/ingest",
+                    post(routes::ingest::ingest_events),
+                )
+                .route(
+                    "/api/websites/{id}/imports",
+                    get(routes::imports::list_imports)
+                        .post(routes::imports::create_import)
+                        .layer(DefaultBodyLimit::max(routes::imports::IMPORT_BODY_LIMIT)),
+                )
+                .route(
+                    "/api/websites/{id}/imports/{import_id}/rollback",
+                    post(routes::imports::rollback_import),
+                )
                 .route(
                     "/api/websites/{id}/sessions",
                     get(routes::sessions::list_sessions),
@@ -546,6 +616,21 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/funnels/{funnel_id}/results",
                     get(routes::funnels::get_funnel_results),
                 )
+                .route(
+                    "/api/websites/{id}/experiments",
+                    get(routes::experiments::list_experiments)
+                        .post(routes::experiments::create_experiment),
+                )
+                .route(
+                    "/api/websites/{id}/experiments/{experiment_id}",
+                    get(routes::experiments::get_experiment)
+                        .put(routes::experiments::update_experiment)
+                        .delete(routes::experiments::delete_experiment),
+                )
+                .route(
+                    "/api/websites/{id}/experiments/{experiment_id}/results",
+                    get(routes::experiments::get_experiment_results),
+                )
                 .route(
                     "/api/websites/{id}/journey",
                     get(routes::journey::get_journey),
@@ -626,6 +711,10 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/subscriptions/{subscription_id}/test",
                     post(routes::notifications::test_subscription),
                 )
+                .route(
+                    "/api/websites/{id}/subscriptions/{subscription_id}/rotate-secret",
+                    post(routes::notifications::rotate_subscription_secret),
+                )
                 .route(
                     "/api/websites/{id}/alerts",
                     get(routes::notifications::list_alerts)
@@ -640,10 +729,18 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/alerts/{alert_id}/test",
                     post(routes::notifications::test_alert),
                 )
+                .route(
+                    "/api/websites/{id}/alerts/{alert_id}/rotate-secret",
+                    post(routes::notifications::rotate_alert_secret),
+                )
                 .route(
                     "/api/websites/{id}/notifications/history",
                     get(routes::notifications::notification_history),
                 )
+                .route(
+                    "/api/websites/{id}/notifications/history/{delivery_id}/replay",
+                    post(routes::notifications::replay_delivery),
+                )
                 .route(
                     "/api/websites/{id}/links",
                     get(routes::links::list_links).post(routes::links::create_link),
@@ -680,6 +777,10 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/realtime",
                     get(routes::realtime::get_realtime),
                 )
+                .route(
+                    "/api/websites/{id}/realtime/stream",
+                    get(routes::realtime::stream_realtime),
+                )
                 .route(
                     "/api/websites/{id}/ingest-limits",
                     get(routes::ingest_limits::get_ingest_limits)
@@ -693,6 +794,10 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/websites/{id}/export",
                     get(routes::export::export_events),
                 )
+                .route(
+                    "/api/backups",
+                    get(routes::backups::list_backups).post(routes::backups::create_backup),
+                )
                 .layer(query_cors)
                 .layer(middleware::from_fn(move |req: Request, next: Next| {
                     let s = auth_state.clone();
@@ -725,6 +830,31 @@ pub fn build_app(state: Arc<AppState>) -> Router {
                     "/api/auth/keys/{id}",
                     axum::routing::delete(auth::handlers::delete_api_key),
                 )
+                .route("/api/users", get(auth::users::list_users))
+                .route(
+                    "/api/users/invites",
+                    get(auth::users::list_invites).post(auth::users::create_invite),
+                )
+                .route(
+                    "/api/users/invites/{id}",
+                    axum::routing::delete(auth::users::revoke_invite),
+                )
+                .route(
+                    "/api/users/{id}",
+                    put(auth::users::update_user).delete(auth::users::delete_user),
+                )
+                .route(
+                    "/api/users/{id}/totp",
+                    axum::routing::delete(auth::totp::reset_user_totp),
+                )
+                .route("/api/auth/totp", get(auth::totp::totp_status))
+                .route("/api/auth/totp/enroll", post(auth::totp::totp_enroll))
+                .route("/api/auth/totp/confirm", post(auth::totp::totp_confirm))
+                .route(
+                    "/api/auth/totp/recovery-codes",
+                    post(auth::totp::totp_regenerate_recovery_codes),
+                )
+                .route("/api/auth/totp/disable", post(auth::totp::totp_disable))
                 .layer(middleware::from_fn(move |req: Request, next: Next| {
                     let s = cookie_state_ready.clone();
                     async move { auth::middleware::require_cookie_auth_ready(s, req, next).await }
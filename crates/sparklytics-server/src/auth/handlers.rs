@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, Query, State},
+    extract::{Extension, Path, Query, State},
     http::{header, HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
@@ -11,11 +11,19 @@ use serde_json::json;
 
 use sparklytics_core::config::AuthMode;
 
-use crate::{error::AppError, routes::collect, state::AppState};
+use crate::{
+    error::AppError,
+    metadata::{ApiKeyAccess, ApiKeyScope},
+    routes::collect,
+    state::AppState,
+};
 
 use super::api_keys::{generate_api_key, generate_key_id};
-use super::jwt::{decode_jwt, encode_jwt};
+use super::jwt::{decode_jwt, encode_jwt, ADMIN_SUBJECT};
+use super::middleware::{validate_cookie_jwt, AuthContext};
 use super::password::{hash_password, validate_password_strength, verify_password};
+use super::totp;
+use super::users::check_websites_exist;
 
 const LOGIN_RATE_LIMIT_RETRY_AFTER_SECONDS: u64 = 15 * 60;
 const DEFAULT_BOOTSTRAP_PASSWORD: &str = "sparklytics";
@@ -35,6 +43,7 @@ pub async fn auth_status(
 ) -> Result<impl IntoResponse, AppError> {
     let (mode_str, setup_required) = match &state.config.auth_mode {
         AuthMode::Password(_) => ("password", false),
+        AuthMode::Oidc(_) => ("oidc", false),
         AuthMode::Local => {
             let configured = state
                 .metadata
@@ -127,10 +136,22 @@ pub async fn auth_setup(
 
 #[derive(Debug, Deserialize)]
 pub struct LoginRequest {
+    /// Set to sign in as a user account (local mode); omit for the admin
+    /// password.
+    #[serde(default)]
+    pub email: Option<String>,
     pub password: String,
+    /// Code from the authenticator app, or a recovery code, for accounts
+    /// with two-factor authentication.
+    #[serde(default)]
+    pub totp_code: Option<String>,
 }
 
-/// `POST /api/auth/login` — Login with password.
+/// `POST /api/auth/login` — Login with password, or email and password for
+/// user accounts.
+///
+/// Accounts with two-factor authentication get 401 `totp_required` until
+/// the request also carries `totp_code`.
 ///
 /// Rate limited: 5 failed attempts per 15 min per IP.
 pub async fn auth_login(
@@ -153,8 +174,14 @@ pub async fn auth_login(
         });
     }
 
-    // Get the expected password hash.
-    let expected_hash = match &state.config.auth_mode {
+    let email = req
+        .email
+        .as_deref()
+        .map(str::trim)
+        .filter(|email| !email.is_empty());
+
+    // Get the expected password hash and the session subject it unlocks.
+    let (expected_hash, subject) = match &state.config.auth_mode {
         AuthMode::Password(pw) => {
             // For password mode, we verify against the env var directly.
             if req.password != *pw {
@@ -172,7 +199,8 @@ pub async fn auth_login(
                 .await
                 .map_err(AppError::Internal)?;
             let (token, expires_at) =
-                encode_jwt(&jwt_secret, state.config.session_days).map_err(AppError::Internal)?;
+                encode_jwt(&jwt_secret, ADMIN_SUBJECT, state.config.session_days)
+                    .map_err(AppError::Internal)?;
 
             state
                 .metadata
@@ -188,16 +216,39 @@ pub async fn auth_login(
                 Json(json!({ "data": { "expires_at": expires_at } })),
             ));
         }
-        AuthMode::Local => {
-            match state
+        AuthMode::Local => match email {
+            Some(email) => match state
                 .metadata
-                .get_setting("admin_password_hash")
+                .get_user_login(email)
                 .await
                 .map_err(AppError::Internal)?
             {
-                Some(hash) => hash,
-                None => return Err(AppError::SetupRequired),
+                Some((user, hash)) => (hash, user.id),
+                None => {
+                    state
+                        .metadata
+                        .record_login_attempt(&client_ip, false)
+                        .await
+                        .map_err(AppError::Internal)?;
+                    return Err(AppError::Unauthorized);
+                }
+            },
+            None => {
+                match state
+                    .metadata
+                    .get_setting("admin_password_hash")
+                    .await
+                    .map_err(AppError::Internal)?
+                {
+                    Some(hash) => (hash, ADMIN_SUBJECT.to_string()),
+                    None => return Err(AppError::SetupRequired),
+                }
             }
+        },
+        AuthMode::Oidc(_) => {
+            return Err(AppError::BadRequest(
+                "password login is disabled; sign in with single sign-on".to_string(),
+            ));
         }
         AuthMode::None => {
             return Err(AppError::NotFound("Auth not enabled".to_string()));
@@ -214,11 +265,32 @@ pub async fn auth_login(
         return Err(AppError::Unauthorized);
     }
 
+    if let Some(factor) = totp::confirmed_factor(&state, &subject).await? {
+        let Some(code) = req.totp_code.as_deref().filter(|c| !c.trim().is_empty()) else {
+            return Err(AppError::TotpRequired);
+        };
+        if !totp::verify_second_factor(&state, &factor, code).await? {
+            state
+                .metadata
+                .record_login_attempt(&client_ip, false)
+                .await
+                .map_err(AppError::Internal)?;
+            return Err(AppError::Unauthorized);
+        }
+    }
+
     state
         .metadata
         .record_login_attempt(&client_ip, true)
         .await
         .map_err(AppError::Internal)?;
+    if subject != ADMIN_SUBJECT {
+        state
+            .metadata
+            .touch_user_login(&subject)
+            .await
+            .map_err(AppError::Internal)?;
+    }
 
     let jwt_secret = state
         .metadata
@@ -226,7 +298,7 @@ pub async fn auth_login(
         .await
         .map_err(AppError::Internal)?;
     let (token, expires_at) =
-        encode_jwt(&jwt_secret, state.config.session_days).map_err(AppError::Internal)?;
+        encode_jwt(&jwt_secret, &subject, state.config.session_days).map_err(AppError::Internal)?;
 
     let cookie = build_session_cookie(&token, state.config.https, state.config.session_days);
     Ok((
@@ -281,10 +353,14 @@ pub async fn auth_session(
         .ok_or(AppError::Unauthorized)?;
 
     let claims = decode_jwt(token, &jwt_secret).map_err(|_| AppError::Unauthorized)?;
+    let ctx = validate_cookie_jwt(&state, token)
+        .await
+        .ok_or(AppError::Unauthorized)?;
 
     let mode_str = match &state.config.auth_mode {
         AuthMode::Password(_) => "password",
         AuthMode::Local => "local",
+        AuthMode::Oidc(_) => "oidc",
         AuthMode::None => "none",
     };
 
@@ -292,6 +368,9 @@ pub async fn auth_session(
         "data": {
             "authenticated": true,
             "mode": mode_str,
+            "user_id": ctx.user_id,
+            "role": ctx.role,
+            "website_ids": ctx.website_ids,
             "expires_at": chrono::DateTime::from_timestamp(claims.exp, 0)
                 .map(|dt| dt.to_rfc3339())
                 .unwrap_or_default(),
@@ -311,14 +390,16 @@ pub struct ChangePasswordRequest {
 
 /// `PUT /api/auth/password` — Change password (local mode only).
 ///
-/// Rotates jwt_secret to invalidate all existing sessions.
+/// For the admin account, rotates jwt_secret to invalidate all existing
+/// sessions. User accounts only change their own password.
 pub async fn auth_change_password(
     State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
     Json(req): Json<ChangePasswordRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     match &state.config.auth_mode {
         AuthMode::Local => {}
-        AuthMode::Password(_) => {
+        AuthMode::Password(_) | AuthMode::Oidc(_) => {
             return Err(AppError::MethodNotAllowed);
         }
         AuthMode::None => {
@@ -326,6 +407,10 @@ pub async fn auth_change_password(
         }
     }
 
+    if let Some(user_id) = ctx.user_id.as_deref().filter(|id| *id != ADMIN_SUBJECT) {
+        return change_user_password(&state, user_id, &req).await;
+    }
+
     // Verify current password.
     let current_hash = state
         .metadata
@@ -361,6 +446,40 @@ pub async fn auth_change_password(
     Ok(Json(json!({ "data": { "ok": true } })))
 }
 
+async fn change_user_password(
+    state: &AppState,
+    user_id: &str,
+    req: &ChangePasswordRequest,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state
+        .metadata
+        .get_user(user_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or(AppError::Unauthorized)?;
+    let (_, current_hash) = state
+        .metadata
+        .get_user_login(&user.email)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or(AppError::Unauthorized)?;
+    if !verify_password(&req.current_password, &current_hash) {
+        return Err(AppError::Unauthorized);
+    }
+
+    validate_password_strength(&req.new_password)
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let new_hash = hash_password(&req.new_password, state.config.argon2_memory_kb)
+        .map_err(AppError::Internal)?;
+    state
+        .metadata
+        .set_user_password(user_id, &new_hash)
+        .await
+        .map_err(AppError::Internal)?;
+
+    Ok(Json(json!({ "data": { "ok": true } })))
+}
+
 // ---------------------------------------------------------------------------
 // GET /api/auth/keys
 // ---------------------------------------------------------------------------
@@ -403,6 +522,11 @@ pub async fn list_api_keys(
 #[derive(Debug, Deserialize)]
 pub struct CreateKeyRequest {
     pub name: String,
+    /// `read` or `write` (default, for compatibility with unscoped keys).
+    pub access: Option<ApiKeyAccess>,
+    /// Restrict the key to these websites; omitted means every website.
+    pub website_ids: Option<Vec<String>>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 /// `POST /api/auth/keys` — Create API key (cookie-only). Returns raw key once.
@@ -413,12 +537,32 @@ pub async fn create_api_key_handler(
     if req.name.is_empty() {
         return Err(AppError::BadRequest("name is required".to_string()));
     }
+    if let Some(website_ids) = &req.website_ids {
+        if website_ids.is_empty() {
+            return Err(AppError::BadRequest(
+                "website_ids must not be empty; omit it for every website".to_string(),
+            ));
+        }
+        check_websites_exist(&state, website_ids).await?;
+    }
+    if req.expires_at.is_some_and(|at| at <= chrono::Utc::now()) {
+        return Err(AppError::BadRequest(
+            "expires_at must be in the future".to_string(),
+        ));
+    }
+    let scope = ApiKeyScope {
+        access: req.access.unwrap_or(ApiKeyAccess::Write),
+        website_ids: req.website_ids,
+        expires_at: req
+            .expires_at
+            .map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string()),
+    };
 
     let key_id = generate_key_id();
     let (raw_key, hash, prefix) = generate_api_key(&state.config.mode);
     state
         .metadata
-        .create_api_key(&key_id, &req.name, &hash, &prefix)
+        .create_api_key(&key_id, &req.name, &hash, &prefix, &scope)
         .await
         .map_err(AppError::Internal)?;
 
@@ -431,6 +575,9 @@ pub async fn create_api_key_handler(
                 "key": raw_key,
                 "prefix": prefix,
                 "created_at": chrono::Utc::now().to_rfc3339(),
+                "access": scope.access,
+                "website_ids": scope.website_ids,
+                "expires_at": req.expires_at.map(|at| at.to_rfc3339()),
             }
         })),
     ))
@@ -462,7 +609,7 @@ pub async fn delete_api_key(
 // Helpers
 // ---------------------------------------------------------------------------
 
-fn build_session_cookie(token: &str, https: bool, session_days: u32) -> String {
+pub(crate) fn build_session_cookie(token: &str, https: bool, session_days: u32) -> String {
     let secure = if https { "; Secure" } else { "" };
     format!(
         "spk_session={}; HttpOnly; SameSite=Strict; Path=/; Max-Age={}{}",
@@ -497,12 +644,7 @@ async fn is_cookie_authenticated(state: &AppState, headers: &HeaderMap) -> bool
         None => return false,
     };
 
-    let jwt_secret = match state.metadata.get_setting("jwt_secret").await {
-        Ok(Some(s)) => s,
-        _ => return false,
-    };
-
-    decode_jwt(&token, &jwt_secret).is_ok()
+    validate_cookie_jwt(state, &token).await.is_some()
 }
 
 pub fn verify_bootstrap_password(
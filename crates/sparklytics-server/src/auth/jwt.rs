@@ -3,6 +3,10 @@ use chrono::{Duration, Utc};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 
+/// JWT subject of the account created by `POST /api/auth/setup` (and of
+/// every session in `password` mode). Other sessions carry a user id.
+pub const ADMIN_SUBJECT: &str = "admin";
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
@@ -10,15 +14,15 @@ pub struct Claims {
     pub iat: i64,
 }
 
-/// Encode a JWT token.
+/// Encode a JWT token for `subject` ([`ADMIN_SUBJECT`] or a user id).
 ///
 /// Returns (token_string, expires_at_rfc3339).
-pub fn encode_jwt(secret: &str, session_days: u32) -> Result<(String, String)> {
+pub fn encode_jwt(secret: &str, subject: &str, session_days: u32) -> Result<(String, String)> {
     let now = Utc::now();
     let exp = now + Duration::days(session_days as i64);
 
     let claims = Claims {
-        sub: "admin".to_string(),
+        sub: subject.to_string(),
         exp: exp.timestamp(),
         iat: now.timestamp(),
     };
@@ -9,17 +9,48 @@ use axum::{
 };
 use serde_json::json;
 
+use crate::error::AppError;
+use crate::metadata::{ApiKeyAccess, ApiKeyRecord, UserRole};
 use crate::state::AppState;
 
 use super::api_keys::hash_api_key;
 use super::handlers::is_password_change_required;
-use super::jwt::decode_jwt;
+use super::jwt::{decode_jwt, ADMIN_SUBJECT};
+use super::permissions::authorize;
 
 /// Auth context injected into request extensions after successful auth.
 #[derive(Debug, Clone)]
 pub struct AuthContext {
     pub auth_type: String,
     pub api_key_id: Option<String>,
+    /// Signed-in account: [`ADMIN_SUBJECT`] for the setup account, otherwise
+    /// a user id. `None` for API keys.
+    pub user_id: Option<String>,
+    /// API keys act as `viewer` with `read` access and `admin` with `write`.
+    pub role: UserRole,
+    /// Websites the caller may access, or `None` for every website.
+    pub website_ids: Option<Vec<String>>,
+}
+
+impl AuthContext {
+    fn for_api_key(key: ApiKeyRecord) -> Self {
+        AuthContext {
+            auth_type: "api_key".to_string(),
+            api_key_id: Some(key.id),
+            user_id: None,
+            role: match key.scope.access {
+                ApiKeyAccess::Read => UserRole::Viewer,
+                ApiKeyAccess::Write => UserRole::Admin,
+            },
+            website_ids: key.scope.website_ids,
+        }
+    }
+
+    pub fn can_access_website(&self, website_id: &str) -> bool {
+        self.website_ids
+            .as_ref()
+            .is_none_or(|ids| ids.iter().any(|id| id == website_id))
+    }
 }
 
 /// Internal: require authentication via Bearer API key or cookie JWT.
@@ -128,10 +159,11 @@ async fn require_auth_impl(
                         }
 
                         let key_id = key_record.id.clone();
-                        request.extensions_mut().insert(AuthContext {
-                            auth_type: "api_key".to_string(),
-                            api_key_id: Some(key_record.id),
-                        });
+                        let ctx = AuthContext::for_api_key(key_record);
+                        if !authorize(&ctx, request.method(), request.uri().path()) {
+                            return AppError::Forbidden.into_response();
+                        }
+                        request.extensions_mut().insert(ctx);
                         let resp = next.run(request).await;
                         // Fire-and-forget: update last_used_at.
                         let metadata = state.metadata.clone();
@@ -176,6 +208,9 @@ async fn require_auth_impl(
                 }
             }
 
+            if !authorize(&ctx, request.method(), request.uri().path()) {
+                return AppError::Forbidden.into_response();
+            }
             request.extensions_mut().insert(ctx);
             return next.run(request).await;
         }
@@ -184,13 +219,32 @@ async fn require_auth_impl(
     unauthorized_response()
 }
 
-async fn validate_cookie_jwt(state: &AppState, token: &str) -> Option<AuthContext> {
+/// Resolve a session cookie to the account it was issued to. Sessions of
+/// deleted or disabled users are rejected.
+pub(crate) async fn validate_cookie_jwt(state: &AppState, token: &str) -> Option<AuthContext> {
     let jwt_secret = state.metadata.get_setting("jwt_secret").await.ok()??;
-    let _claims = decode_jwt(token, &jwt_secret).ok()?;
+    let claims = decode_jwt(token, &jwt_secret).ok()?;
+
+    if claims.sub == ADMIN_SUBJECT {
+        return Some(AuthContext {
+            auth_type: "cookie".to_string(),
+            api_key_id: None,
+            user_id: Some(claims.sub),
+            role: UserRole::Owner,
+            website_ids: None,
+        });
+    }
 
+    let user = state.metadata.get_user(&claims.sub).await.ok()??;
+    if user.disabled_at.is_some() {
+        return None;
+    }
     Some(AuthContext {
         auth_type: "cookie".to_string(),
         api_key_id: None,
+        user_id: Some(user.id),
+        role: user.role,
+        website_ids: (user.role == UserRole::Viewer).then_some(user.website_ids),
     })
 }
 
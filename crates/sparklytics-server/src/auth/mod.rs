@@ -2,4 +2,8 @@ pub mod api_keys;
 pub mod handlers;
 pub mod jwt;
 pub mod middleware;
+pub mod oidc;
 pub mod password;
+pub mod permissions;
+pub mod totp;
+pub mod users;
@@ -0,0 +1,454 @@
+//! OpenID Connect single sign-on (`SPARKLYTICS_AUTH=oidc`).
+//!
+//! Authorization-code flow with PKCE: `/api/auth/oidc/login` redirects to the
+//! identity provider, `/api/auth/oidc/callback` exchanges the code, verifies
+//! the ID token against the provider's JWKS and issues the same
+//! `spk_session` cookie as password login. The account is found by issuer
+//! and subject, linked by verified email or created. Accounts created this
+//! way take their role from the configured claim on every sign-in; accounts
+//! with a local password keep the role set in Sparklytics.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context};
+use axum::{
+    extract::{Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{AppendHeaders, IntoResponse},
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use jsonwebtoken::{decode, decode_header, Algorithm, Validation};
+use rand::RngCore;
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tokio::sync::{Mutex, RwLock};
+
+use sparklytics_core::config::{AuthMode, OidcConfig};
+
+use crate::error::AppError;
+use crate::metadata::{SsoUserParams, UserEmailTaken, UserRole};
+use crate::routes::bearer_jwt::{fetch_jwks_keys, http_client};
+use crate::state::AppState;
+
+use super::handlers::build_session_cookie;
+use super::jwt::encode_jwt;
+
+const CALLBACK_PATH: &str = "/api/auth/oidc/callback";
+const STATE_COOKIE: &str = "spk_oidc_state";
+/// How long a user has to finish signing in at the identity provider.
+const PENDING_LOGIN_TTL: Duration = Duration::from_secs(10 * 60);
+const MAX_PENDING_LOGINS: usize = 10_000;
+const PROVIDER_METADATA_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Subset of the provider's `/.well-known/openid-configuration`.
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+struct CachedProviderMetadata {
+    metadata: ProviderMetadata,
+    expires_at: Instant,
+}
+
+/// A sign-in started by `/login` and not yet completed by `/callback`.
+struct PendingLogin {
+    nonce: String,
+    code_verifier: String,
+    created_at: Instant,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+fn provider_cache() -> &'static RwLock<HashMap<String, CachedProviderMetadata>> {
+    static CACHE: OnceLock<RwLock<HashMap<String, CachedProviderMetadata>>> = OnceLock::new();
+    CACHE.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+fn pending_logins() -> &'static Mutex<HashMap<String, PendingLogin>> {
+    static PENDING: OnceLock<Mutex<HashMap<String, PendingLogin>>> = OnceLock::new();
+    PENDING.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn oidc_config(state: &AppState) -> Result<&OidcConfig, AppError> {
+    match &state.config.auth_mode {
+        AuthMode::Oidc(oidc) => Ok(oidc),
+        _ => Err(AppError::NotFound("OIDC not enabled".to_string())),
+    }
+}
+
+fn random_token() -> String {
+    let mut buf = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut buf);
+    URL_SAFE_NO_PAD.encode(buf)
+}
+
+fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+fn redirect_uri(state: &AppState) -> String {
+    format!("{}{CALLBACK_PATH}", state.config.public_url)
+}
+
+async fn provider_metadata(oidc: &OidcConfig) -> Result<ProviderMetadata, AppError> {
+    let now = Instant::now();
+    if let Some(cached) = provider_cache().read().await.get(&oidc.issuer) {
+        if cached.expires_at > now {
+            return Ok(cached.metadata.clone());
+        }
+    }
+
+    let url = format!("{}/.well-known/openid-configuration", oidc.issuer);
+    let metadata: ProviderMetadata = http_client()
+        .get(&url)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .with_context(|| format!("fetch {url}"))?
+        .json()
+        .await
+        .with_context(|| format!("parse {url}"))?;
+    if metadata.issuer.trim_end_matches('/') != oidc.issuer {
+        return Err(AppError::Internal(anyhow!(
+            "OIDC discovery issuer {} does not match SPARKLYTICS_OIDC_ISSUER {}",
+            metadata.issuer,
+            oidc.issuer
+        )));
+    }
+
+    provider_cache().write().await.insert(
+        oidc.issuer.clone(),
+        CachedProviderMetadata {
+            metadata: metadata.clone(),
+            expires_at: now + PROVIDER_METADATA_TTL,
+        },
+    );
+    Ok(metadata)
+}
+
+/// Highest role mapped from the configured claim, falling back to the
+/// default role. `None` means the user may not sign in.
+pub fn role_for_claims(oidc: &OidcConfig, claims: &Value) -> Option<UserRole> {
+    let values: Vec<&str> = match claims.get(&oidc.role_claim) {
+        Some(Value::String(value)) => vec![value.as_str()],
+        Some(Value::Array(values)) => values.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    oidc.role_map
+        .iter()
+        .filter(|(value, _)| values.contains(&value.as_str()))
+        .filter_map(|(_, role)| UserRole::parse(role))
+        .max()
+        .or_else(|| oidc.default_role.as_deref().and_then(UserRole::parse))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/auth/oidc/login
+// ---------------------------------------------------------------------------
+
+/// `GET /api/auth/oidc/login` — Public. Redirect to the identity provider.
+pub async fn oidc_login(State(state): State<Arc<AppState>>) -> Result<impl IntoResponse, AppError> {
+    let oidc = oidc_config(&state)?;
+    let provider = provider_metadata(oidc).await?;
+
+    let login_state = random_token();
+    let nonce = random_token();
+    let code_verifier = random_token();
+    let code_challenge = pkce_challenge(&code_verifier);
+    {
+        let mut pending = pending_logins().lock().await;
+        pending.retain(|_, login| login.created_at.elapsed() < PENDING_LOGIN_TTL);
+        if pending.len() >= MAX_PENDING_LOGINS {
+            return Err(AppError::RateLimited);
+        }
+        pending.insert(
+            login_state.clone(),
+            PendingLogin {
+                nonce: nonce.clone(),
+                code_verifier,
+                created_at: Instant::now(),
+            },
+        );
+    }
+
+    let mut url = reqwest::Url::parse(&provider.authorization_endpoint)
+        .context("invalid OIDC authorization_endpoint")?;
+    url.query_pairs_mut()
+        .append_pair("response_type", "code")
+        .append_pair("client_id", &oidc.client_id)
+        .append_pair("redirect_uri", &redirect_uri(&state))
+        .append_pair("scope", &oidc.scopes)
+        .append_pair("state", &login_state)
+        .append_pair("nonce", &nonce)
+        .append_pair("code_challenge", &code_challenge)
+        .append_pair("code_challenge_method", "S256");
+
+    Ok((
+        StatusCode::FOUND,
+        [
+            (header::LOCATION, url.to_string()),
+            (
+                header::SET_COOKIE,
+                state_cookie(
+                    &login_state,
+                    state.config.https,
+                    PENDING_LOGIN_TTL.as_secs(),
+                ),
+            ),
+        ],
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/auth/oidc/callback
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+pub struct CallbackQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+/// `GET /api/auth/oidc/callback` — Public. Complete sign-in and set the
+/// session cookie.
+///
+/// 400 for a missing or mismatched `state`, 401 when the code exchange or ID
+/// token verification fails, 403 when no role maps to the user or the
+/// account is disabled.
+pub async fn oidc_callback(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(query): Query<CallbackQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let oidc = oidc_config(&state)?;
+    if let Some(error) = query.error {
+        let description = query.error_description.unwrap_or_default();
+        return Err(AppError::BadRequest(format!(
+            "identity provider returned {error}: {description}"
+        )));
+    }
+    let (Some(code), Some(login_state)) = (query.code, query.state) else {
+        return Err(AppError::BadRequest(
+            "code and state are required".to_string(),
+        ));
+    };
+    let cookie_state = headers
+        .get(header::COOKIE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|cookies| {
+            cookies
+                .split(';')
+                .find_map(|c| c.trim().strip_prefix("spk_oidc_state="))
+        });
+    if cookie_state != Some(login_state.as_str()) {
+        return Err(AppError::BadRequest(
+            "sign-in state does not match this browser; start again".to_string(),
+        ));
+    }
+    let pending = pending_logins()
+        .lock()
+        .await
+        .remove(&login_state)
+        .filter(|login| login.created_at.elapsed() < PENDING_LOGIN_TTL)
+        .ok_or_else(|| AppError::BadRequest("sign-in expired; start again".to_string()))?;
+
+    let provider = provider_metadata(oidc).await?;
+    let id_token = exchange_code(&state, oidc, &provider, &code, &pending).await?;
+    let claims = verify_id_token(oidc, &provider, &id_token, &pending.nonce).await?;
+
+    let role = role_for_claims(oidc, &claims).ok_or(AppError::Forbidden)?;
+    let subject = claims["sub"].as_str().ok_or(AppError::Unauthorized)?;
+    let email = claims["email"]
+        .as_str()
+        .filter(|email| !email.trim().is_empty())
+        .ok_or_else(|| AppError::BadRequest("ID token has no email claim".to_string()))?;
+
+    let user = state
+        .metadata
+        .upsert_sso_user(SsoUserParams {
+            issuer: oidc.issuer.clone(),
+            subject: subject.to_string(),
+            email: email.to_string(),
+            // Linking to an existing account by email is only safe for
+            // addresses the provider has verified.
+            email_verified: claims["email_verified"] == Value::Bool(true),
+            name: claims["name"].as_str().map(str::to_string),
+            role,
+        })
+        .await
+        .map_err(|e| match e.downcast_ref::<UserEmailTaken>() {
+            Some(_) => AppError::Forbidden,
+            None => AppError::Internal(e),
+        })?;
+    if user.disabled_at.is_some() {
+        return Err(AppError::Forbidden);
+    }
+    state
+        .metadata
+        .touch_user_login(&user.id)
+        .await
+        .map_err(AppError::Internal)?;
+
+    let jwt_secret = state
+        .metadata
+        .ensure_jwt_secret()
+        .await
+        .map_err(AppError::Internal)?;
+    let (token, _) =
+        encode_jwt(&jwt_secret, &user.id, state.config.session_days).map_err(AppError::Internal)?;
+
+    Ok((
+        StatusCode::FOUND,
+        AppendHeaders([
+            (header::LOCATION, "/dashboard".to_string()),
+            (
+                header::SET_COOKIE,
+                build_session_cookie(&token, state.config.https, state.config.session_days),
+            ),
+            (header::SET_COOKIE, state_cookie("", state.config.https, 0)),
+        ]),
+    ))
+}
+
+async fn exchange_code(
+    state: &AppState,
+    oidc: &OidcConfig,
+    provider: &ProviderMetadata,
+    code: &str,
+    pending: &PendingLogin,
+) -> Result<String, AppError> {
+    let redirect_uri = redirect_uri(state);
+    let mut request = http_client().post(&provider.token_endpoint).form(&[
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("redirect_uri", redirect_uri.as_str()),
+        ("client_id", oidc.client_id.as_str()),
+        ("code_verifier", pending.code_verifier.as_str()),
+    ]);
+    if let Some(secret) = &oidc.client_secret {
+        request = request.basic_auth(&oidc.client_id, Some(secret));
+    }
+    let response = request.send().await.map_err(|e| {
+        tracing::warn!(error = %e, "OIDC token request failed");
+        AppError::Unauthorized
+    })?;
+    if !response.status().is_success() {
+        tracing::warn!(status = %response.status(), "OIDC token endpoint rejected the code");
+        return Err(AppError::Unauthorized);
+    }
+    let tokens: TokenResponse = response.json().await.map_err(|_| AppError::Unauthorized)?;
+    Ok(tokens.id_token)
+}
+
+async fn verify_id_token(
+    oidc: &OidcConfig,
+    provider: &ProviderMetadata,
+    id_token: &str,
+    nonce: &str,
+) -> Result<Value, AppError> {
+    let header = decode_header(id_token).map_err(|_| AppError::Unauthorized)?;
+    if header.alg != Algorithm::RS256 {
+        return Err(AppError::Unauthorized);
+    }
+    let kid = header.kid.ok_or(AppError::Unauthorized)?;
+    let key = fetch_jwks_keys(&provider.jwks_uri)
+        .await?
+        .remove(&kid)
+        .ok_or(AppError::Unauthorized)?;
+
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.leeway = 30;
+    validation.required_spec_claims.clear();
+    validation.required_spec_claims.extend([
+        "exp".to_string(),
+        "iss".to_string(),
+        "aud".to_string(),
+        "sub".to_string(),
+    ]);
+    validation.set_issuer(&[&oidc.issuer, &provider.issuer]);
+    validation.set_audience(&[&oidc.client_id]);
+
+    let claims = decode::<Value>(id_token, &key, &validation)
+        .map_err(|_| AppError::Unauthorized)?
+        .claims;
+    if claims["nonce"].as_str() != Some(nonce) {
+        return Err(AppError::Unauthorized);
+    }
+    Ok(claims)
+}
+
+/// The state cookie must be `SameSite=Lax`: the callback is a cross-site
+/// navigation from the identity provider.
+fn state_cookie(value: &str, https: bool, max_age_seconds: u64) -> String {
+    let secure = if https { "; Secure" } else { "" };
+    format!(
+        "{STATE_COOKIE}={value}; HttpOnly; SameSite=Lax; Path=/api/auth/oidc; Max-Age={max_age_seconds}{secure}"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn config(default_role: Option<&str>) -> OidcConfig {
+        OidcConfig {
+            issuer: "https://idp.example.com".to_string(),
+            client_id: "sparklytics".to_string(),
+            client_secret: None,
+            scopes: "openid email profile".to_string(),
+            role_claim: "groups".to_string(),
+            role_map: vec![
+                ("analytics".to_string(), "viewer".to_string()),
+                ("analytics-admins".to_string(), "admin".to_string()),
+            ],
+            default_role: default_role.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn picks_the_highest_mapped_role() {
+        let claims = json!({ "groups": ["analytics", "analytics-admins", "other"] });
+        assert_eq!(
+            role_for_claims(&config(None), &claims),
+            Some(UserRole::Admin)
+        );
+        let claims = json!({ "groups": "analytics" });
+        assert_eq!(
+            role_for_claims(&config(None), &claims),
+            Some(UserRole::Viewer)
+        );
+    }
+
+    #[test]
+    fn unmapped_users_get_the_default_role_or_none() {
+        let claims = json!({ "groups": ["other"] });
+        assert_eq!(role_for_claims(&config(None), &claims), None);
+        assert_eq!(
+            role_for_claims(&config(Some("viewer")), &claims),
+            Some(UserRole::Viewer)
+        );
+        assert_eq!(role_for_claims(&config(None), &json!({})), None);
+    }
+
+    #[test]
+    fn pkce_challenge_is_unpadded_base64url_sha256() {
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW-gFWFAjWXk"),
+            "CvGMOVcYy5KuVH-12vqNs0yzdtFXA0-oHKFro7A07Ts"
+        );
+    }
+}
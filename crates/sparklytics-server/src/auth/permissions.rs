@@ -0,0 +1,125 @@
+use axum::http::Method;
+
+use crate::metadata::UserRole;
+
+use super::middleware::AuthContext;
+
+/// Decide whether an authenticated caller may make this request.
+///
+/// - Website routes (`/api/websites/{id}/...`) need access to that website.
+///   Viewers may only read: `GET` plus the report preview/run endpoints,
+///   which are `POST` but change nothing.
+/// - Creating websites, backups, API keys and user management need `admin`
+///   over every website, so website-scoped API keys never qualify.
+///   Owner-only rules for user management live in the handlers.
+pub fn authorize(ctx: &AuthContext, method: &Method, path: &str) -> bool {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["api", "websites"] => is_read(method) || is_instance_admin(ctx),
+        ["api", "websites", website_id, rest @ ..] => {
+            ctx.can_access_website(website_id)
+                && (is_read(method)
+                    || is_read_only_post(method, rest)
+                    || ctx.role >= UserRole::Admin)
+        }
+        ["api", "backups", ..] | ["api", "users", ..] | ["api", "auth", "keys", ..] => {
+            is_instance_admin(ctx)
+        }
+        _ => true,
+    }
+}
+
+fn is_instance_admin(ctx: &AuthContext) -> bool {
+    ctx.role >= UserRole::Admin && ctx.website_ids.is_none()
+}
+
+fn is_read(method: &Method) -> bool {
+    method == Method::GET || method == Method::HEAD
+}
+
+fn is_read_only_post(method: &Method, rest: &[&str]) -> bool {
+    method == Method::POST && matches!(rest, ["reports", "preview"] | ["reports", _, "run"])
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::Method;
+
+    use super::authorize;
+    use crate::auth::middleware::AuthContext;
+    use crate::metadata::UserRole;
+
+    fn ctx(role: UserRole, website_ids: Option<Vec<&str>>) -> AuthContext {
+        AuthContext {
+            auth_type: "cookie".to_string(),
+            api_key_id: None,
+            user_id: Some("usr_test".to_string()),
+            role,
+            website_ids: website_ids.map(|ids| ids.into_iter().map(str::to_string).collect()),
+        }
+    }
+
+    #[test]
+    fn viewers_read_only_their_granted_websites() {
+        let viewer = ctx(UserRole::Viewer, Some(vec!["site_a"]));
+        assert!(authorize(&viewer, &Method::GET, "/api/websites"));
+        assert!(authorize(
+            &viewer,
+            &Method::GET,
+            "/api/websites/site_a/stats"
+        ));
+        assert!(authorize(
+            &viewer,
+            &Method::POST,
+            "/api/websites/site_a/reports/preview"
+        ));
+        assert!(authorize(
+            &viewer,
+            &Method::POST,
+            "/api/websites/site_a/reports/report_1/run"
+        ));
+        assert!(!authorize(
+            &viewer,
+            &Method::GET,
+            "/api/websites/site_b/stats"
+        ));
+        assert!(!authorize(
+            &viewer,
+            &Method::POST,
+            "/api/websites/site_a/goals"
+        ));
+        assert!(!authorize(&viewer, &Method::DELETE, "/api/websites/site_a"));
+        assert!(!authorize(&viewer, &Method::POST, "/api/websites"));
+        assert!(!authorize(&viewer, &Method::GET, "/api/backups"));
+        assert!(!authorize(&viewer, &Method::GET, "/api/users"));
+        assert!(!authorize(&viewer, &Method::GET, "/api/auth/keys"));
+        assert!(authorize(&viewer, &Method::PUT, "/api/auth/password"));
+    }
+
+    #[test]
+    fn admins_manage_everything_but_owner_rules() {
+        let admin = ctx(UserRole::Admin, None);
+        assert!(authorize(&admin, &Method::POST, "/api/websites"));
+        assert!(authorize(&admin, &Method::DELETE, "/api/websites/site_b"));
+        assert!(authorize(&admin, &Method::POST, "/api/backups"));
+        assert!(authorize(&admin, &Method::POST, "/api/users/invites"));
+    }
+
+    #[test]
+    fn website_scoped_writers_stay_inside_their_websites() {
+        let scoped = ctx(UserRole::Admin, Some(vec!["site_a"]));
+        assert!(authorize(
+            &scoped,
+            &Method::POST,
+            "/api/websites/site_a/goals"
+        ));
+        assert!(authorize(&scoped, &Method::GET, "/api/websites"));
+        assert!(!authorize(
+            &scoped,
+            &Method::POST,
+            "/api/websites/site_b/goals"
+        ));
+        assert!(!authorize(&scoped, &Method::POST, "/api/websites"));
+        assert!(!authorize(&scoped, &Method::POST, "/api/backups"));
+    }
+}
@@ -0,0 +1,482 @@
+//! TOTP (RFC 6238) second factor for password logins in local mode.
+//!
+//! Codes are 6-digit HMAC-SHA1 over 30-second steps, which is what every
+//! common authenticator app expects from an `otpauth://` URI. Each step is
+//! accepted at most once per account, and recovery codes are stored as
+//! sha256 hashes and consumed on use.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use hmac::{Hmac, Mac};
+use rand::{Rng, RngCore};
+use serde::Deserialize;
+use serde_json::json;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use sparklytics_core::config::AuthMode;
+
+use crate::{error::AppError, metadata::TotpFactor, state::AppState};
+
+use super::jwt::ADMIN_SUBJECT;
+use super::middleware::AuthContext;
+
+const ISSUER: &str = "Sparklytics";
+const STEP_SECONDS: i64 = 30;
+const DIGITS: u32 = 6;
+/// Steps either side of now that still verify, to allow for clock drift.
+const ALLOWED_DRIFT_STEPS: i64 = 1;
+const SECRET_BYTES: usize = 20;
+const RECOVERY_CODE_COUNT: usize = 10;
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// RFC 4648 base32 without padding, as used in `otpauth://` URIs.
+fn base32_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for &byte in bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+    out
+}
+
+fn base32_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(encoded.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in encoded.bytes().filter(|c| *c != b'=') {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|a| *a == c.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+fn generate_secret() -> String {
+    let mut buf = [0u8; SECRET_BYTES];
+    rand::thread_rng().fill_bytes(&mut buf);
+    base32_encode(&buf)
+}
+
+/// The RFC 4226 HOTP value of `key` at `counter`.
+fn hotp(key: &[u8], counter: u64) -> u32 {
+    let Ok(mut mac) = Hmac::<Sha1>::new_from_slice(key) else {
+        // HMAC accepts keys of any length.
+        return 0;
+    };
+    mac.update(&counter.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+    let offset = usize::from(digest[digest.len() - 1] & 0x0f);
+    let truncated = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    truncated % 10u32.pow(DIGITS)
+}
+
+/// The time step `code` was generated for, if it matches `secret` within
+/// the allowed drift of `now` (unix seconds).
+fn matching_step(secret: &str, code: &str, now: i64) -> Option<i64> {
+    if code.len() != DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let code: u32 = code.parse().ok()?;
+    let key = base32_decode(secret)?;
+    let current = now.div_euclid(STEP_SECONDS);
+    (current - ALLOWED_DRIFT_STEPS..=current + ALLOWED_DRIFT_STEPS)
+        .filter(|step| *step >= 0)
+        .find(|step| hotp(&key, *step as u64) == code)
+}
+
+fn provisioning_uri(secret: &str, account: &str) -> String {
+    let label: String =
+        url::form_urlencoded::byte_serialize(format!("{ISSUER}:{account}").as_bytes()).collect();
+    format!(
+        "otpauth://totp/{}?secret={secret}&issuer={ISSUER}&algorithm=SHA1&digits={DIGITS}&period={STEP_SECONDS}",
+        label.replace('+', "%20"),
+    )
+}
+
+/// Recovery codes look like `k3v9q-x7m2p`; dashes, spaces and case are
+/// ignored when one is entered.
+fn generate_recovery_codes() -> Vec<String> {
+    const ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+    let mut rng = rand::thread_rng();
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let chars: String = (0..10)
+                .map(|_| ALPHABET[rng.gen_range(0..ALPHABET.len())] as char)
+                .collect();
+            format!("{}-{}", &chars[..5], &chars[5..])
+        })
+        .collect()
+}
+
+fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_ascii_lowercase();
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
+
+fn now_unix() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+/// The account's TOTP factor if enrollment has been verified.
+pub(crate) async fn confirmed_factor(
+    state: &AppState,
+    account_id: &str,
+) -> Result<Option<TotpFactor>, AppError> {
+    Ok(state
+        .metadata
+        .get_totp_factor(account_id)
+        .await
+        .map_err(AppError::Internal)?
+        .filter(|factor| factor.confirmed_at.is_some()))
+}
+
+/// Check a code from the authenticator app, or an unused recovery code,
+/// consuming it on success.
+pub(crate) async fn verify_second_factor(
+    state: &AppState,
+    factor: &TotpFactor,
+    code: &str,
+) -> Result<bool, AppError> {
+    let code = code.trim();
+    if code.bytes().all(|b| b.is_ascii_digit()) {
+        let Some(step) = matching_step(&factor.secret, code, now_unix()) else {
+            return Ok(false);
+        };
+        return state
+            .metadata
+            .use_totp_step(&factor.account_id, step)
+            .await
+            .map_err(AppError::Internal);
+    }
+    state
+        .metadata
+        .use_totp_recovery_code(&factor.account_id, &hash_recovery_code(code))
+        .await
+        .map_err(AppError::Internal)
+}
+
+/// Second factors protect password logins, so they are only managed in
+/// local mode.
+fn require_local_account(state: &AppState, ctx: &AuthContext) -> Result<String, AppError> {
+    match &state.config.auth_mode {
+        AuthMode::Local => {}
+        AuthMode::Password(_) | AuthMode::Oidc(_) => return Err(AppError::MethodNotAllowed),
+        AuthMode::None => return Err(AppError::NotFound("Auth not enabled".to_string())),
+    }
+    ctx.user_id.clone().ok_or(AppError::Unauthorized)
+}
+
+async fn issue_recovery_codes(
+    state: &AppState,
+    account_id: &str,
+    step: Option<i64>,
+) -> Result<Vec<String>, AppError> {
+    let codes = generate_recovery_codes();
+    let hashes: Vec<String> = codes.iter().map(|code| hash_recovery_code(code)).collect();
+    match step {
+        Some(step) => {
+            let confirmed = state
+                .metadata
+                .confirm_totp_enrollment(account_id, step, &hashes)
+                .await
+                .map_err(AppError::Internal)?;
+            if !confirmed {
+                return Err(AppError::BadRequest(
+                    "no two-factor enrollment is pending".to_string(),
+                ));
+            }
+        }
+        None => state
+            .metadata
+            .replace_totp_recovery_codes(account_id, &hashes)
+            .await
+            .map_err(AppError::Internal)?,
+    }
+    Ok(codes)
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/auth/totp
+// ---------------------------------------------------------------------------
+
+/// `GET /api/auth/totp` — Two-factor status of the signed-in account.
+pub async fn totp_status(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
+) -> Result<impl IntoResponse, AppError> {
+    let account_id = require_local_account(&state, &ctx)?;
+    let factor = state
+        .metadata
+        .get_totp_factor(&account_id)
+        .await
+        .map_err(AppError::Internal)?;
+    let enabled = factor.as_ref().is_some_and(|f| f.confirmed_at.is_some());
+
+    Ok(Json(json!({
+        "data": {
+            "enabled": enabled,
+            "pending": factor.is_some() && !enabled,
+            "recovery_codes_remaining": factor
+                .filter(|_| enabled)
+                .map(|f| f.recovery_codes_remaining)
+                .unwrap_or(0),
+        }
+    })))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/auth/totp/enroll
+// ---------------------------------------------------------------------------
+
+/// `POST /api/auth/totp/enroll` — Start enrollment. Returns the secret and
+/// the `otpauth://` URI to render as a QR code. Logins are unaffected until
+/// the enrollment is confirmed.
+pub async fn totp_enroll(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
+) -> Result<impl IntoResponse, AppError> {
+    let account_id = require_local_account(&state, &ctx)?;
+    if confirmed_factor(&state, &account_id).await?.is_some() {
+        return Err(AppError::BadRequest(
+            "two-factor authentication is already enabled; disable it first".to_string(),
+        ));
+    }
+
+    let account = if account_id == ADMIN_SUBJECT {
+        ADMIN_SUBJECT.to_string()
+    } else {
+        state
+            .metadata
+            .get_user(&account_id)
+            .await
+            .map_err(AppError::Internal)?
+            .ok_or(AppError::Unauthorized)?
+            .email
+    };
+    let secret = generate_secret();
+    state
+        .metadata
+        .begin_totp_enrollment(&account_id, &secret)
+        .await
+        .map_err(AppError::Internal)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(json!({
+            "data": {
+                "otpauth_uri": provisioning_uri(&secret, &account),
+                "secret": secret,
+            }
+        })),
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/auth/totp/confirm
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+pub struct TotpCodeRequest {
+    pub code: String,
+}
+
+/// `POST /api/auth/totp/confirm` — Verify a code from the newly enrolled
+/// authenticator, turning the second factor on. Returns the recovery codes,
+/// which are never shown again.
+pub async fn totp_confirm(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
+    Json(req): Json<TotpCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let account_id = require_local_account(&state, &ctx)?;
+    let factor = state
+        .metadata
+        .get_totp_factor(&account_id)
+        .await
+        .map_err(AppError::Internal)?
+        .filter(|factor| factor.confirmed_at.is_none())
+        .ok_or_else(|| AppError::BadRequest("no two-factor enrollment is pending".to_string()))?;
+    let step = matching_step(&factor.secret, req.code.trim(), now_unix())
+        .ok_or_else(|| AppError::BadRequest("code is invalid or expired".to_string()))?;
+
+    let recovery_codes = issue_recovery_codes(&state, &account_id, Some(step)).await?;
+    Ok(Json(
+        json!({ "data": { "recovery_codes": recovery_codes } }),
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/auth/totp/recovery-codes
+// ---------------------------------------------------------------------------
+
+/// `POST /api/auth/totp/recovery-codes` — Replace all recovery codes.
+/// Requires a current code from the authenticator.
+pub async fn totp_regenerate_recovery_codes(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
+    Json(req): Json<TotpCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let account_id = require_local_account(&state, &ctx)?;
+    let factor = confirmed_factor(&state, &account_id)
+        .await?
+        .ok_or_else(|| {
+            AppError::BadRequest("two-factor authentication is not enabled".to_string())
+        })?;
+    let code = req.code.trim();
+    if !code.bytes().all(|b| b.is_ascii_digit())
+        || !verify_second_factor(&state, &factor, code).await?
+    {
+        return Err(AppError::BadRequest(
+            "code is invalid or expired".to_string(),
+        ));
+    }
+
+    let recovery_codes = issue_recovery_codes(&state, &account_id, None).await?;
+    Ok(Json(
+        json!({ "data": { "recovery_codes": recovery_codes } }),
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/auth/totp/disable
+// ---------------------------------------------------------------------------
+
+/// `POST /api/auth/totp/disable` — Turn the second factor off. Requires a
+/// code from the authenticator or a recovery code.
+pub async fn totp_disable(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
+    Json(req): Json<TotpCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let account_id = require_local_account(&state, &ctx)?;
+    if let Some(factor) = confirmed_factor(&state, &account_id).await? {
+        if !verify_second_factor(&state, &factor, &req.code).await? {
+            return Err(AppError::BadRequest(
+                "code is invalid or expired".to_string(),
+            ));
+        }
+    }
+
+    state
+        .metadata
+        .delete_totp_factor(&account_id)
+        .await
+        .map_err(AppError::Internal)?;
+    Ok(Json(json!({ "data": { "ok": true } })))
+}
+
+// ---------------------------------------------------------------------------
+// DELETE /api/users/:id/totp
+// ---------------------------------------------------------------------------
+
+/// `DELETE /api/users/:id/totp` — Remove a user's second factor after they
+/// lost their authenticator and recovery codes (admin, cookie-only).
+pub async fn reset_user_totp(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let target = state
+        .metadata
+        .get_user(&user_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+    super::users::check_can_manage(&ctx, &target, None)?;
+
+    let removed = state
+        .metadata
+        .delete_totp_factor(&user_id)
+        .await
+        .map_err(AppError::Internal)?;
+    if !removed {
+        return Err(AppError::NotFound(
+            "User has no two-factor authentication".to_string(),
+        ));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 6238 appendix B, SHA1 variant, truncated to 6 digits.
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn hotp_matches_rfc_6238_vectors() {
+        for (time, expected) in [
+            (59u64, 287_082),
+            (1_111_111_109, 81_804),
+            (1_234_567_890, 5_924),
+            (2_000_000_000, 279_037),
+        ] {
+            assert_eq!(hotp(RFC_SECRET, time / 30), expected, "time {time}");
+        }
+    }
+
+    #[test]
+    fn codes_verify_within_one_step_of_drift() {
+        let secret = base32_encode(RFC_SECRET);
+        assert_eq!(secret, "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+        assert_eq!(base32_decode(&secret).as_deref(), Some(RFC_SECRET));
+
+        let now = 1_111_111_109;
+        assert_eq!(matching_step(&secret, "081804", now), Some(now / 30));
+        assert_eq!(matching_step(&secret, "081804", now + 30), Some(now / 30));
+        assert_eq!(matching_step(&secret, "081804", now + 90), None);
+        assert_eq!(matching_step(&secret, "81804", now), None);
+        assert_eq!(matching_step(&secret, "000000", now), None);
+    }
+
+    #[test]
+    fn recovery_codes_are_normalized_before_hashing() {
+        let codes = generate_recovery_codes();
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        let code = &codes[0];
+        assert_eq!(
+            hash_recovery_code(code),
+            hash_recovery_code(&code.to_uppercase().replace('-', " "))
+        );
+    }
+
+    #[test]
+    fn provisioning_uri_escapes_the_account_label() {
+        assert_eq!(
+            provisioning_uri("ABC", "a+b@example.com"),
+            "otpauth://totp/Sparklytics%3Aa%2Bb%40example.com?secret=ABC&issuer=Sparklytics&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+}
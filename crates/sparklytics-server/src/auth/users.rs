@@ -0,0 +1,343 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Extension, Path, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use sparklytics_core::config::AuthMode;
+
+use crate::{
+    error::AppError,
+    metadata::{CreateInviteParams, UpdateUserParams, UserEmailTaken, UserRecord, UserRole},
+    scheduler::delivery::{is_valid_email, send_email},
+    state::AppState,
+};
+
+use super::handlers::build_session_cookie;
+use super::jwt::encode_jwt;
+use super::middleware::AuthContext;
+use super::password::{hash_password, validate_password_strength};
+
+/// Days an invite link stays valid.
+const INVITE_TTL_DAYS: i64 = 7;
+
+fn hash_invite_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+fn generate_invite_token() -> String {
+    use rand::RngCore;
+    let mut buf = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut buf);
+    hex::encode(buf)
+}
+
+/// Only owners may create, change or remove owner accounts, and nobody may
+/// change their own account through user management.
+pub(super) fn check_can_manage(
+    ctx: &AuthContext,
+    target: &UserRecord,
+    new_role: Option<UserRole>,
+) -> Result<(), AppError> {
+    if ctx.user_id.as_deref() == Some(target.id.as_str()) {
+        return Err(AppError::BadRequest(
+            "you cannot change your own account".to_string(),
+        ));
+    }
+    let touches_owner = target.role == UserRole::Owner || new_role == Some(UserRole::Owner);
+    if touches_owner && ctx.role != UserRole::Owner {
+        return Err(AppError::Forbidden);
+    }
+    Ok(())
+}
+
+pub(super) async fn check_websites_exist(
+    state: &AppState,
+    website_ids: &[String],
+) -> Result<(), AppError> {
+    for website_id in website_ids {
+        let exists = state
+            .metadata
+            .website_exists(website_id)
+            .await
+            .map_err(AppError::Internal)?;
+        if !exists {
+            return Err(AppError::BadRequest(format!(
+                "website {website_id} does not exist"
+            )));
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/users
+// ---------------------------------------------------------------------------
+
+/// `GET /api/users` — List user accounts (admin, cookie-only).
+pub async fn list_users(State(state): State<Arc<AppState>>) -> Result<impl IntoResponse, AppError> {
+    let users = state
+        .metadata
+        .list_users()
+        .await
+        .map_err(AppError::Internal)?;
+    Ok(Json(json!({ "data": users })))
+}
+
+// ---------------------------------------------------------------------------
+// PUT /api/users/:id
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateUserRequest {
+    pub name: Option<String>,
+    pub role: Option<UserRole>,
+    pub website_ids: Option<Vec<String>>,
+    pub disabled: Option<bool>,
+}
+
+/// `PUT /api/users/:id` — Change a user's role, website grants or disabled
+/// state (admin, cookie-only).
+pub async fn update_user(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
+    Path(user_id): Path<String>,
+    Json(req): Json<UpdateUserRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let target = state
+        .metadata
+        .get_user(&user_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+    check_can_manage(&ctx, &target, req.role)?;
+    if let Some(website_ids) = &req.website_ids {
+        check_websites_exist(&state, website_ids).await?;
+    }
+
+    let user = state
+        .metadata
+        .update_user(
+            &user_id,
+            UpdateUserParams {
+                name: req.name,
+                role: req.role,
+                website_ids: req.website_ids,
+                disabled: req.disabled,
+            },
+        )
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+    Ok(Json(json!({ "data": user })))
+}
+
+// ---------------------------------------------------------------------------
+// DELETE /api/users/:id
+// ---------------------------------------------------------------------------
+
+/// `DELETE /api/users/:id` — Delete a user account (admin, cookie-only).
+/// Their sessions stop working immediately.
+pub async fn delete_user(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let target = state
+        .metadata
+        .get_user(&user_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+    check_can_manage(&ctx, &target, None)?;
+
+    state
+        .metadata
+        .delete_user(&user_id)
+        .await
+        .map_err(AppError::Internal)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ---------------------------------------------------------------------------
+// GET /api/users/invites
+// ---------------------------------------------------------------------------
+
+/// `GET /api/users/invites` — Pending invites (admin, cookie-only).
+pub async fn list_invites(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let invites = state
+        .metadata
+        .list_pending_invites()
+        .await
+        .map_err(AppError::Internal)?;
+    Ok(Json(json!({ "data": invites })))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/users/invites
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+pub struct CreateInviteRequest {
+    pub email: String,
+    pub role: UserRole,
+    #[serde(default)]
+    pub website_ids: Vec<String>,
+}
+
+/// `POST /api/users/invites` — Invite someone by email (admin, cookie-only).
+///
+/// Emails the invite link through the notification SMTP settings. The link
+/// is also returned once in `invite_url`, so it can be shared by hand when
+/// SMTP is not configured; `email_error` says why sending failed.
+pub async fn create_invite(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<AuthContext>,
+    Json(req): Json<CreateInviteRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if matches!(state.config.auth_mode, AuthMode::Oidc(_)) {
+        return Err(AppError::BadRequest(
+            "users sign in through the identity provider in oidc mode".to_string(),
+        ));
+    }
+    let email = req.email.trim().to_lowercase();
+    if !is_valid_email(&email) {
+        return Err(AppError::BadRequest("email is invalid".to_string()));
+    }
+    if req.role == UserRole::Owner && ctx.role != UserRole::Owner {
+        return Err(AppError::Forbidden);
+    }
+    let users = state
+        .metadata
+        .list_users()
+        .await
+        .map_err(AppError::Internal)?;
+    if users.iter().any(|user| user.email == email) {
+        return Err(AppError::BadRequest(
+            "a user with this email already exists".to_string(),
+        ));
+    }
+    check_websites_exist(&state, &req.website_ids).await?;
+
+    let token = generate_invite_token();
+    let invite = state
+        .metadata
+        .create_invite(CreateInviteParams {
+            email: email.clone(),
+            role: req.role,
+            website_ids: req.website_ids,
+            token_hash: hash_invite_token(&token),
+            invited_by: ctx.user_id.clone(),
+            expires_in_days: INVITE_TTL_DAYS,
+        })
+        .await
+        .map_err(AppError::Internal)?;
+
+    let invite_url = format!(
+        "{}/invite?token={token}",
+        state.config.public_url.trim_end_matches('/')
+    );
+    let body = format!(
+        "You have been invited to Sparklytics as {role}.\n\n\
+         Accept the invite and choose a password here:\n{invite_url}\n\n\
+         The link expires in {INVITE_TTL_DAYS} days.\n",
+        role = invite.role.as_str(),
+    );
+    let email_error = send_email(&email, "You're invited to Sparklytics", body)
+        .await
+        .err();
+    if let Some(err) = &email_error {
+        tracing::warn!(invite_id = %invite.id, error = %err, "invite email was not sent");
+    }
+
+    let mut data = serde_json::to_value(&invite).map_err(|e| AppError::Internal(e.into()))?;
+    data["invite_url"] = json!(invite_url);
+    data["email_sent"] = json!(email_error.is_none());
+    data["email_error"] = json!(email_error);
+    Ok((StatusCode::CREATED, Json(json!({ "data": data }))))
+}
+
+// ---------------------------------------------------------------------------
+// DELETE /api/users/invites/:id
+// ---------------------------------------------------------------------------
+
+/// `DELETE /api/users/invites/:id` — Revoke a pending invite (admin,
+/// cookie-only).
+pub async fn revoke_invite(
+    State(state): State<Arc<AppState>>,
+    Path(invite_id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let revoked = state
+        .metadata
+        .revoke_invite(&invite_id)
+        .await
+        .map_err(AppError::Internal)?;
+    if !revoked {
+        return Err(AppError::NotFound("Invite not found".to_string()));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/auth/invites/accept
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+pub struct AcceptInviteRequest {
+    pub token: String,
+    pub name: Option<String>,
+    pub password: String,
+}
+
+/// `POST /api/auth/invites/accept` — Public. Create the invited account and
+/// sign it in (local mode only).
+pub async fn accept_invite(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<AcceptInviteRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if !matches!(state.config.auth_mode, AuthMode::Local) {
+        return Err(AppError::BadRequest(
+            "invites are only available in local mode".to_string(),
+        ));
+    }
+    validate_password_strength(&req.password).map_err(|e| AppError::BadRequest(e.to_string()))?;
+    let password_hash =
+        hash_password(&req.password, state.config.argon2_memory_kb).map_err(AppError::Internal)?;
+    let name = req
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty());
+
+    let user = state
+        .metadata
+        .accept_invite(&hash_invite_token(req.token.trim()), name, &password_hash)
+        .await
+        .map_err(|e| match e.downcast_ref::<UserEmailTaken>() {
+            Some(taken) => AppError::BadRequest(taken.to_string()),
+            None => AppError::Internal(e),
+        })?
+        .ok_or_else(|| AppError::NotFound("Invite not found or expired".to_string()))?;
+
+    let jwt_secret = state
+        .metadata
+        .ensure_jwt_secret()
+        .await
+        .map_err(AppError::Internal)?;
+    let (token, expires_at) =
+        encode_jwt(&jwt_secret, &user.id, state.config.session_days).map_err(AppError::Internal)?;
+    let cookie = build_session_cookie(&token, state.config.https, state.config.session_days);
+    Ok((
+        StatusCode::CREATED,
+        [(header::SET_COOKIE, cookie)],
+        Json(json!({ "data": { "user": user, "expires_at": expires_at } })),
+    ))
+}
@@ -0,0 +1,410 @@
+//! Backups of the data directory: a checkpointed copy of the DuckDB file
+//! plus the ingest WAL, so events accepted but not yet persisted survive a
+//! restore.
+//!
+//! Each backup is a directory `sparklytics-YYYYMMDD-HHMMSS` under the backup
+//! destination holding `sparklytics.db`, `ingest-wal/` and a
+//! `manifest.json`. Scheduled backups are off by default and configured with
+//! `SPARKLYTICS_BACKUP_*` environment variables.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{error, info};
+
+use sparklytics_duckdb::backup::{read_schema_version, validate_snapshot};
+use sparklytics_duckdb::migrations::SCHEMA_VERSION;
+use sparklytics_duckdb::DuckDbBackend;
+
+use crate::state::AppState;
+
+/// Database file name inside both the data directory and a backup.
+pub const DATABASE_FILE: &str = "sparklytics.db";
+pub const MANIFEST_FILE: &str = "manifest.json";
+const INGEST_WAL_DIR: &str = "ingest-wal";
+const BACKUP_PREFIX: &str = "sparklytics-";
+const DEFAULT_RETAIN: usize = 7;
+
+/// Contents of a backup's `manifest.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub sparklytics_version: String,
+    pub schema_version: String,
+    pub database_bytes: u64,
+}
+
+/// A backup found on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BackupInfo {
+    pub path: PathBuf,
+    #[serde(flatten)]
+    pub manifest: BackupManifest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupSchedule {
+    Daily,
+    /// Sundays.
+    Weekly,
+}
+
+/// Backup configuration read from the environment.
+#[derive(Debug, Clone)]
+pub struct BackupSettings {
+    /// `SPARKLYTICS_BACKUP_DESTINATION`, default `<data_dir>/backups`.
+    pub dir: PathBuf,
+    /// `SPARKLYTICS_BACKUP_ENABLED` turns on scheduled backups.
+    pub enabled: bool,
+    /// `SPARKLYTICS_BACKUP_SCHEDULE`: `daily` (default) or `weekly`.
+    pub schedule: BackupSchedule,
+    /// `SPARKLYTICS_BACKUP_TIME`: `HH:MM` in UTC, default `01:00`.
+    pub time: NaiveTime,
+    /// `SPARKLYTICS_BACKUP_RETAIN`: backups kept after each scheduled or
+    /// API-triggered backup, default 7.
+    pub retain: usize,
+}
+
+impl BackupSettings {
+    pub fn from_env(data_dir: &str) -> Result<Self> {
+        let var = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let dir = match var("SPARKLYTICS_BACKUP_DESTINATION") {
+            Some(dest) if dest.contains("://") => {
+                bail!("SPARKLYTICS_BACKUP_DESTINATION must be a local directory, got {dest}")
+            }
+            Some(dest) => PathBuf::from(dest),
+            None => Path::new(data_dir).join("backups"),
+        };
+        let enabled = var("SPARKLYTICS_BACKUP_ENABLED").is_some_and(|value| {
+            value == "1" || value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("yes")
+        });
+        let schedule = match var("SPARKLYTICS_BACKUP_SCHEDULE").as_deref() {
+            None | Some("daily") => BackupSchedule::Daily,
+            Some("weekly") => BackupSchedule::Weekly,
+            Some(other) => {
+                bail!("SPARKLYTICS_BACKUP_SCHEDULE must be daily or weekly, got {other}")
+            }
+        };
+        let time = match var("SPARKLYTICS_BACKUP_TIME") {
+            Some(value) => NaiveTime::parse_from_str(&value, "%H:%M")
+                .with_context(|| format!("SPARKLYTICS_BACKUP_TIME must be HH:MM, got {value}"))?,
+            None => NaiveTime::from_hms_opt(1, 0, 0).ok_or_else(|| anyhow!("invalid time"))?,
+        };
+        let retain = match var("SPARKLYTICS_BACKUP_RETAIN") {
+            Some(value) => value
+                .parse::<usize>()
+                .ok()
+                .filter(|retain| *retain > 0)
+                .ok_or_else(|| {
+                    anyhow!("SPARKLYTICS_BACKUP_RETAIN must be a positive number, got {value}")
+                })?,
+            None => DEFAULT_RETAIN,
+        };
+        Ok(Self {
+            dir,
+            enabled,
+            schedule,
+            time,
+            retain,
+        })
+    }
+
+    /// First scheduled run strictly after `now`.
+    pub fn next_run_after(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let mut candidate = now.date_naive().and_time(self.time).and_utc();
+        while candidate <= now
+            || (self.schedule == BackupSchedule::Weekly && candidate.weekday() != Weekday::Sun)
+        {
+            candidate += Duration::days(1);
+        }
+        candidate
+    }
+}
+
+/// Copy the database and the ingest WAL of `data_dir` into `dest`.
+///
+/// `ingest_locks` are held only while the database snapshot is pinned and
+/// the WAL is copied, so the WAL cursor in the backup matches the events in
+/// the database copy; the database copy itself runs after they are released.
+async fn snapshot(
+    db: &DuckDbBackend,
+    data_dir: &Path,
+    dest: &Path,
+    ingest_locks: &[&Mutex<()>],
+) -> Result<u64> {
+    let mut guards = Vec::with_capacity(ingest_locks.len());
+    for lock in ingest_locks {
+        guards.push(lock.lock().await);
+    }
+    let pending = db.begin_snapshot(&dest.join(DATABASE_FILE)).await?;
+    copy_dir(&data_dir.join(INGEST_WAL_DIR), &dest.join(INGEST_WAL_DIR))?;
+    drop(guards);
+    pending.write().await
+}
+
+/// Copy the files of `from` (not recursive) into `to`. A missing `from` is
+/// treated as empty.
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    std::fs::create_dir_all(to)?;
+    let entries = match std::fs::read_dir(from) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            std::fs::copy(entry.path(), to.join(entry.file_name()))
+                .with_context(|| format!("failed to copy {}", entry.path().display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Write a new backup of `db` and `data_dir` into `backups_dir`.
+///
+/// The backup is assembled in a hidden staging directory and renamed into
+/// place once complete, so listings never show a partial backup. Callers
+/// with a running ingest pipeline pass the locks that keep the ingest WAL
+/// still; see [`AppState::create_backup`].
+pub async fn create_backup(
+    db: &DuckDbBackend,
+    data_dir: &Path,
+    backups_dir: &Path,
+    ingest_locks: &[&Mutex<()>],
+) -> Result<BackupInfo> {
+    let created_at = Utc::now();
+    let name = format!("{BACKUP_PREFIX}{}", created_at.format("%Y%m%d-%H%M%S"));
+    let path = backups_dir.join(&name);
+    if path.exists() {
+        bail!("backup {} already exists", path.display());
+    }
+    let staging = backups_dir.join(format!(".{name}.partial"));
+    std::fs::create_dir_all(&staging)
+        .with_context(|| format!("failed to create {}", staging.display()))?;
+
+    let result: Result<BackupInfo> = async {
+        let database_bytes = snapshot(db, data_dir, &staging, ingest_locks).await?;
+        let manifest = BackupManifest {
+            name,
+            created_at,
+            sparklytics_version: env!("CARGO_PKG_VERSION").to_string(),
+            schema_version: SCHEMA_VERSION.to_string(),
+            database_bytes,
+        };
+        std::fs::write(
+            staging.join(MANIFEST_FILE),
+            serde_json::to_vec_pretty(&manifest)?,
+        )?;
+        std::fs::rename(&staging, &path)?;
+        Ok(BackupInfo { path, manifest })
+    }
+    .await;
+    if result.is_err() {
+        let _ = std::fs::remove_dir_all(&staging);
+    }
+    result
+}
+
+fn read_manifest(path: &Path) -> Result<BackupManifest> {
+    let bytes = std::fs::read(path.join(MANIFEST_FILE))
+        .with_context(|| format!("{} has no {MANIFEST_FILE}", path.display()))?;
+    serde_json::from_slice(&bytes)
+        .with_context(|| format!("{} has an unreadable {MANIFEST_FILE}", path.display()))
+}
+
+/// Backups in `backups_dir`, newest first. Directories without a readable
+/// manifest are ignored; a missing `backups_dir` has no backups.
+pub fn list_backups(backups_dir: &Path) -> Result<Vec<BackupInfo>> {
+    let entries = match std::fs::read_dir(backups_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut backups = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let is_backup = entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with(BACKUP_PREFIX)
+            && entry.file_type()?.is_dir();
+        if !is_backup {
+            continue;
+        }
+        if let Ok(manifest) = read_manifest(&entry.path()) {
+            backups.push(BackupInfo {
+                path: entry.path(),
+                manifest,
+            });
+        }
+    }
+    backups.sort_by_key(|b| std::cmp::Reverse(b.manifest.created_at));
+    Ok(backups)
+}
+
+/// Delete all but the newest `retain` backups, returning the removed paths.
+pub fn prune_backups(backups_dir: &Path, retain: usize) -> Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+    for backup in list_backups(backups_dir)?.into_iter().skip(retain) {
+        std::fs::remove_dir_all(&backup.path)
+            .with_context(|| format!("failed to remove {}", backup.path.display()))?;
+        removed.push(backup.path);
+    }
+    Ok(removed)
+}
+
+/// Outcome of [`restore_backup`].
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreReport {
+    pub restored: BackupManifest,
+    /// Where the replaced database and ingest WAL were moved, if there were
+    /// any.
+    pub previous_data: Option<PathBuf>,
+}
+
+/// Replace the database and ingest WAL in `data_dir` with the backup at
+/// `backup`.
+///
+/// The server must be stopped. The backup's schema version is checked via
+/// its `_migrations` table first, and the current files are moved to
+/// `data_dir/pre-restore-<timestamp>` rather than deleted.
+pub fn restore_backup(backup: &Path, data_dir: &Path) -> Result<RestoreReport> {
+    let manifest = read_manifest(backup)?;
+    let backup_db = backup.join(DATABASE_FILE);
+    if !backup_db.is_file() {
+        bail!("{} has no {DATABASE_FILE}", backup.display());
+    }
+    validate_snapshot(&backup_db)?;
+
+    let current_db = data_dir.join(DATABASE_FILE);
+    let current_duckdb_wal = data_dir.join(format!("{DATABASE_FILE}.wal"));
+    let current_ingest_wal = data_dir.join(INGEST_WAL_DIR);
+    if current_db.exists() {
+        read_schema_version(&current_db).with_context(|| {
+            format!(
+                "cannot open {}; stop the server before restoring",
+                current_db.display()
+            )
+        })?;
+    }
+
+    let mut previous_data = None;
+    let existing: Vec<&Path> = [&current_db, &current_duckdb_wal, &current_ingest_wal]
+        .into_iter()
+        .map(PathBuf::as_path)
+        .filter(|path| path.exists())
+        .collect();
+    if !existing.is_empty() {
+        let aside = data_dir.join(format!(
+            "pre-restore-{}",
+            Utc::now().format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::create_dir_all(&aside)?;
+        for path in existing {
+            if let Some(file_name) = path.file_name() {
+                std::fs::rename(path, aside.join(file_name))
+                    .with_context(|| format!("failed to move {} aside", path.display()))?;
+            }
+        }
+        previous_data = Some(aside);
+    }
+
+    std::fs::create_dir_all(data_dir)?;
+    std::fs::copy(&backup_db, &current_db)
+        .with_context(|| format!("failed to copy {}", backup_db.display()))?;
+    copy_dir(&backup.join(INGEST_WAL_DIR), &current_ingest_wal)?;
+    Ok(RestoreReport {
+        restored: manifest,
+        previous_data,
+    })
+}
+
+/// Scheduled backups: sleep until the next configured slot, back up, then
+/// rotate down to `settings.retain`.
+pub async fn run_backup_loop(state: Arc<AppState>, settings: BackupSettings) {
+    info!(
+        dir = %settings.dir.display(),
+        schedule = ?settings.schedule,
+        time = %settings.time,
+        retain = settings.retain,
+        "Scheduled backups enabled"
+    );
+    loop {
+        let now = Utc::now();
+        let wait = (settings.next_run_after(now) - now)
+            .to_std()
+            .unwrap_or_default();
+        tokio::time::sleep(wait).await;
+        match state.create_backup(&settings.dir).await {
+            Ok(backup) => info!(
+                path = %backup.path.display(),
+                database_bytes = backup.manifest.database_bytes,
+                "Backup complete"
+            ),
+            Err(e) => {
+                error!(error = %e, "Scheduled backup failed");
+                continue;
+            }
+        }
+        match prune_backups(&settings.dir, settings.retain) {
+            Ok(removed) if !removed.is_empty() => {
+                info!(removed = removed.len(), "Old backups removed")
+            }
+            Ok(_) => {}
+            Err(e) => error!(error = %e, "Failed to remove old backups"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(schedule: BackupSchedule) -> BackupSettings {
+        BackupSettings {
+            dir: PathBuf::from("/tmp/backups"),
+            enabled: true,
+            schedule,
+            time: NaiveTime::from_hms_opt(1, 0, 0).expect("time"),
+            retain: 7,
+        }
+    }
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).expect("timestamp").to_utc()
+    }
+
+    #[test]
+    fn daily_schedule_runs_at_the_next_slot() {
+        let daily = settings(BackupSchedule::Daily);
+        assert_eq!(
+            daily.next_run_after(at("2026-02-17T00:30:00Z")),
+            at("2026-02-17T01:00:00Z")
+        );
+        assert_eq!(
+            daily.next_run_after(at("2026-02-17T01:00:00Z")),
+            at("2026-02-18T01:00:00Z")
+        );
+    }
+
+    #[test]
+    fn weekly_schedule_runs_on_sundays() {
+        // 2026-02-17 is a Tuesday.
+        let weekly = settings(BackupSchedule::Weekly);
+        assert_eq!(
+            weekly.next_run_after(at("2026-02-17T12:00:00Z")),
+            at("2026-02-22T01:00:00Z")
+        );
+    }
+}
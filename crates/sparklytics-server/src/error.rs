@@ -6,6 +6,10 @@ use axum::{
 use serde_json::json;
 use thiserror::Error;
 
+/// Seconds clients should wait before retrying a read that hit its query
+/// timeout.
+pub const QUERY_TIMEOUT_RETRY_AFTER_SECONDS: u64 = 2;
+
 /// Application-level errors that map directly to HTTP responses.
 ///
 /// Every variant implements [`IntoResponse`] so Axum handlers can use
@@ -36,6 +40,11 @@ pub enum AppError {
     #[error("password change required")]
     PasswordChangeRequired,
 
+    /// The password was right but the account also needs a TOTP or recovery
+    /// code.
+    #[error("two-factor code required")]
+    TotpRequired,
+
     #[error("gone")]
     Gone,
 
@@ -106,6 +115,12 @@ impl IntoResponse for AppError {
                 "Password change required before continuing",
                 None,
             ),
+            AppError::TotpRequired => (
+                StatusCode::UNAUTHORIZED,
+                "totp_required",
+                "Two-factor authentication code required",
+                None,
+            ),
             AppError::Gone => (StatusCode::GONE, "gone", "Setup already completed", None),
             AppError::MethodNotAllowed => (
                 StatusCode::METHOD_NOT_ALLOWED,
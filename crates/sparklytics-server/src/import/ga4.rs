@@ -0,0 +1,177 @@
+//! GA4 BigQuery export rows as newline-delimited JSON.
+//!
+//! One `events_*` row per line, e.g. from `bq extract --destination_format
+//! NEWLINE_DELIMITED_JSON`. `page_view` becomes a pageview and other events
+//! become custom events with their non-standard parameters as `event_data`.
+//! GA4 reports countries by name, so only rows that already carry an ISO
+//! code keep their country.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+
+use sparklytics_metadata::Website;
+
+use super::{base_event, device_type, event_data, set_referrer, ImportSource, ParsedExport};
+
+/// Automatically collected events that describe sessions rather than
+/// something the visitor did.
+const LIFECYCLE_EVENTS: &[&str] = &["session_start", "first_visit", "user_engagement"];
+
+/// Parameters GA4 attaches to every event; everything else is custom data.
+const STANDARD_PARAMS: &[&str] = &[
+    "page_location",
+    "page_referrer",
+    "page_title",
+    "ga_session_id",
+    "ga_session_number",
+    "engagement_time_msec",
+    "session_engaged",
+    "engaged_session_event",
+    "entrances",
+    "batch_ordering_id",
+    "batch_page_id",
+    "ignore_referrer",
+    "debug_mode",
+    "source",
+    "medium",
+    "campaign",
+    "term",
+    "content",
+];
+
+/// Flatten `event_params` (`[{key, value: {string_value | int_value | ...}}]`).
+fn event_params(row: &Value) -> Map<String, Value> {
+    let mut params = Map::new();
+    for param in row["event_params"].as_array().into_iter().flatten() {
+        let Some(key) = param["key"].as_str() else {
+            continue;
+        };
+        let value = &param["value"];
+        let value = if let Some(s) = value["string_value"].as_str() {
+            Value::String(s.to_string())
+        } else if let Some(n) = value["int_value"]
+            .as_i64()
+            .or_else(|| value["int_value"].as_str().and_then(|s| s.parse().ok()))
+        {
+            Value::from(n)
+        } else if let Some(n) = value["double_value"]
+            .as_f64()
+            .or_else(|| value["float_value"].as_f64())
+        {
+            Value::from(n)
+        } else {
+            continue;
+        };
+        params.insert(key.to_string(), value);
+    }
+    params
+}
+
+/// GA4 uses `(not set)` and empty strings for missing values.
+fn text(value: &Value) -> Option<String> {
+    value
+        .as_str()
+        .filter(|s| !s.is_empty() && *s != "(not set)")
+        .map(str::to_string)
+}
+
+fn param_text(params: &Map<String, Value>, key: &str) -> Option<String> {
+    params.get(key).and_then(text)
+}
+
+fn event_time(row: &Value) -> Option<DateTime<Utc>> {
+    let micros = row["event_timestamp"]
+        .as_i64()
+        .or_else(|| row["event_timestamp"].as_str()?.parse().ok())?;
+    DateTime::from_timestamp_micros(micros)
+}
+
+pub(super) fn parse(website: &Website, data: &[u8]) -> Result<ParsedExport> {
+    let mut parsed = ParsedExport {
+        events: Vec::new(),
+        skipped: 0,
+    };
+    let mut json_rows = 0usize;
+    for line in data.split(|byte| *byte == b'\n') {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let Ok(row) = serde_json::from_slice::<Value>(line) else {
+            parsed.skipped += 1;
+            continue;
+        };
+        json_rows += 1;
+        let (Some(name), Some(visitor), Some(created_at)) = (
+            text(&row["event_name"]),
+            text(&row["user_pseudo_id"]).or_else(|| text(&row["user_id"])),
+            event_time(&row),
+        ) else {
+            parsed.skipped += 1;
+            continue;
+        };
+        if LIFECYCLE_EVENTS.contains(&name.as_str()) {
+            parsed.skipped += 1;
+            continue;
+        }
+        let mut params = event_params(&row);
+        let Some(url) = param_text(&params, "page_location") else {
+            parsed.skipped += 1;
+            continue;
+        };
+        let event_type = if name == "page_view" {
+            "pageview"
+        } else {
+            "event"
+        };
+        let mut event = base_event(
+            website,
+            ImportSource::Ga4,
+            &visitor,
+            event_type,
+            url,
+            created_at,
+        );
+        set_referrer(&mut event, param_text(&params, "page_referrer"));
+
+        let traffic = &row["collected_traffic_source"];
+        for (manual, param, field) in [
+            ("manual_source", "source", &mut event.utm_source),
+            ("manual_medium", "medium", &mut event.utm_medium),
+            ("manual_campaign_name", "campaign", &mut event.utm_campaign),
+            ("manual_term", "term", &mut event.utm_term),
+            ("manual_content", "content", &mut event.utm_content),
+        ] {
+            if let Some(value) = text(&traffic[manual]).or_else(|| param_text(&params, param)) {
+                *field = Some(value);
+            }
+        }
+
+        let device = &row["device"];
+        event.browser = text(&device["web_info"]["browser"]).or_else(|| text(&device["browser"]));
+        event.browser_version = text(&device["web_info"]["browser_version"])
+            .or_else(|| text(&device["browser_version"]));
+        event.os = text(&device["operating_system"]);
+        event.os_version = text(&device["operating_system_version"]);
+        event.device_type = text(&device["category"]).and_then(|category| device_type(&category));
+        event.language = text(&device["language"]);
+
+        let geo = &row["geo"];
+        event.country = text(&geo["country"]).filter(|country| {
+            country.len() == 2 && country.chars().all(|c| c.is_ascii_uppercase())
+        });
+        event.region = text(&geo["region"]);
+        event.city = text(&geo["city"]);
+
+        if event_type == "event" {
+            event.event_name = Some(name);
+            params.retain(|key, _| !STANDARD_PARAMS.contains(&key.as_str()));
+            event.event_data = event_data(params);
+        }
+        parsed.events.push(event);
+    }
+    if json_rows == 0 {
+        bail!("no JSON rows found; expected GA4 BigQuery rows as newline-delimited JSON");
+    }
+    Ok(parsed)
+}
@@ -0,0 +1,389 @@
+//! Historical imports from other analytics tools.
+//!
+//! Each source module maps an export into [`Event`]s; this module then
+//! synthesises sessions with the website's [`SessionRules`] so imported data
+//! is split exactly like live traffic. Writing and rolling back imports lives
+//! in `sparklytics_duckdb::imports`.
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sha2::{Digest, Sha256};
+
+use sparklytics_core::{event::Event, session::SessionRules, visitor::extract_referrer_domain};
+use sparklytics_duckdb::DuckDbBackend;
+use sparklytics_duckdb::{imports::ImportRecord, imports::NewImport, session::compute_session_id};
+use sparklytics_metadata::Website;
+
+use crate::routes::collect::{extract_utm_from_url, EVENT_DATA_MAX_BYTES};
+
+pub mod ga4;
+pub mod plausible;
+pub mod umami;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    /// `pg_dump` of an Umami v2 PostgreSQL database.
+    Umami,
+    /// Raw `events_v2` rows exported from Plausible's ClickHouse as CSV.
+    Plausible,
+    /// GA4 BigQuery export rows as newline-delimited JSON.
+    Ga4,
+}
+
+impl ImportSource {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "umami" => Some(Self::Umami),
+            "plausible" => Some(Self::Plausible),
+            "ga4" => Some(Self::Ga4),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Umami => "umami",
+            Self::Plausible => "plausible",
+            Self::Ga4 => "ga4",
+        }
+    }
+}
+
+/// Source-specific knobs.
+#[derive(Debug, Clone, Default)]
+pub struct ImportOptions {
+    /// Umami website UUID to import when a dump holds several websites.
+    pub source_website_id: Option<String>,
+}
+
+/// Events mapped from one export, with sessions already assigned.
+#[derive(Debug, Clone)]
+pub struct ParsedExport {
+    pub events: Vec<Event>,
+    /// Source rows that could not be mapped (unknown event types, missing
+    /// timestamps, ...).
+    pub skipped: usize,
+}
+
+/// Map `data` into events for `website` and assign synthesised sessions.
+///
+/// Errors describe why the file is not a usable export of `source` and are
+/// safe to show to the caller.
+pub fn parse_export(
+    source: ImportSource,
+    website: &Website,
+    data: &[u8],
+    options: &ImportOptions,
+) -> Result<ParsedExport> {
+    let mut parsed = match source {
+        ImportSource::Umami => umami::parse(website, data, options)?,
+        ImportSource::Plausible => plausible::parse(website, data)?,
+        ImportSource::Ga4 => ga4::parse(website, data)?,
+    };
+    if parsed.events.is_empty() {
+        bail!("the file contains no importable events");
+    }
+    assign_sessions(&mut parsed.events, &website.session_rules());
+    Ok(parsed)
+}
+
+/// Write a parsed export as one import of `website`.
+pub async fn store_import(
+    db: &DuckDbBackend,
+    website: &Website,
+    source: ImportSource,
+    file_name: Option<&str>,
+    parsed: &ParsedExport,
+) -> Result<ImportRecord> {
+    let import_id = format!("imp_{}", uuid::Uuid::new_v4().simple());
+    db.import_events(&NewImport {
+        id: &import_id,
+        website_id: &website.id,
+        source: source.as_str(),
+        file_name,
+        events: &parsed.events,
+        skipped_count: parsed.skipped as i64,
+    })
+    .await
+}
+
+/// Split each visitor's events into sessions using the website's rules.
+fn assign_sessions(events: &mut [Event], rules: &SessionRules) {
+    events.sort_by(|a, b| {
+        a.visitor_id
+            .cmp(&b.visitor_id)
+            .then(a.created_at.cmp(&b.created_at))
+    });
+    let mut current: Option<(String, String, DateTime<Utc>, Option<String>)> = None;
+    for event in events.iter_mut() {
+        let continues = current
+            .as_ref()
+            .is_some_and(|(visitor, _, last_seen, campaign)| {
+                *visitor == event.visitor_id
+                    && rules.continues(
+                        *last_seen,
+                        campaign.as_deref(),
+                        event.created_at,
+                        event.utm_campaign.as_deref(),
+                    )
+            });
+        match current.as_mut() {
+            Some((_, session_id, last_seen, _)) if continues => {
+                *last_seen = event.created_at;
+                event.session_id = session_id.clone();
+            }
+            _ => {
+                let session_id = compute_session_id(
+                    &event.visitor_id,
+                    &event.website_id,
+                    &event.url,
+                    event.created_at.timestamp_millis(),
+                );
+                event.session_id = session_id.clone();
+                current = Some((
+                    event.visitor_id.clone(),
+                    session_id,
+                    event.created_at,
+                    event.utm_campaign.clone(),
+                ));
+            }
+        }
+    }
+}
+
+/// Stable visitor id for a source-side identifier, in the same 16-hex format
+/// as live visitor ids.
+fn imported_visitor_id(source: ImportSource, website_id: &str, source_visitor: &str) -> String {
+    let input = format!("{}:{}:{}", source.as_str(), website_id, source_visitor);
+    let hash = Sha256::digest(input.as_bytes());
+    hex::encode(&hash[..8])
+}
+
+/// An event with every optional field empty; sources fill in what they have.
+fn base_event(
+    website: &Website,
+    source: ImportSource,
+    source_visitor: &str,
+    event_type: &str,
+    url: String,
+    created_at: DateTime<Utc>,
+) -> Event {
+    let utm = extract_utm_from_url(&url);
+    Event {
+        id: uuid::Uuid::new_v4().to_string(),
+        website_id: website.id.clone(),
+        tenant_id: website.tenant_id.clone(),
+        session_id: String::new(),
+        visitor_id: imported_visitor_id(source, &website.id, source_visitor),
+        event_type: event_type.to_string(),
+        url,
+        referrer_url: None,
+        referrer_domain: None,
+        event_name: None,
+        event_data: None,
+        country: None,
+        region: None,
+        city: None,
+        browser: None,
+        browser_version: None,
+        os: None,
+        os_version: None,
+        device_type: None,
+        screen: None,
+        language: None,
+        utm_source: utm.get("utm_source").cloned(),
+        utm_medium: utm.get("utm_medium").cloned(),
+        utm_campaign: utm.get("utm_campaign").cloned(),
+        utm_term: utm.get("utm_term").cloned(),
+        utm_content: utm.get("utm_content").cloned(),
+        link_id: None,
+        pixel_id: None,
+        source_ip: None,
+        user_agent: None,
+        is_bot: false,
+        bot_score: 0,
+        bot_reason: None,
+        created_at,
+    }
+}
+
+/// Full page URL from an export's hostname and path, falling back to the
+/// website's domain when the export has no hostname.
+fn page_url(hostname: Option<&str>, website: &Website, path: &str, query: Option<&str>) -> String {
+    if path.starts_with("http://") || path.starts_with("https://") {
+        return path.to_string();
+    }
+    let host = hostname
+        .filter(|host| !host.is_empty())
+        .unwrap_or(&website.domain);
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{path}")
+    };
+    match query.filter(|query| !query.is_empty()) {
+        Some(query) => format!("https://{host}{path}?{}", query.trim_start_matches('?')),
+        None => format!("https://{host}{path}"),
+    }
+}
+
+fn set_referrer(event: &mut Event, referrer: Option<String>) {
+    let Some(referrer) = referrer.filter(|referrer| !referrer.is_empty()) else {
+        return;
+    };
+    let referrer = if referrer.contains("://") {
+        referrer
+    } else {
+        format!("https://{referrer}")
+    };
+    event.referrer_domain = extract_referrer_domain(&referrer);
+    event.referrer_url = Some(referrer);
+}
+
+/// Serialise custom event properties, dropping them if they exceed the
+/// size accepted by `/api/collect`.
+fn event_data(properties: serde_json::Map<String, serde_json::Value>) -> Option<String> {
+    if properties.is_empty() {
+        return None;
+    }
+    let data = serde_json::Value::Object(properties).to_string();
+    (data.len() <= EVENT_DATA_MAX_BYTES).then_some(data)
+}
+
+/// Map the device categories used by other tools onto ours.
+fn device_type(value: &str) -> Option<String> {
+    match value.to_ascii_lowercase().as_str() {
+        "" => None,
+        "mobile" | "smartphone" => Some("mobile".to_string()),
+        "tablet" => Some("tablet".to_string()),
+        _ => Some("desktop".to_string()),
+    }
+}
+
+/// Parse the timestamp formats found in exports: RFC 3339, PostgreSQL
+/// `timestamptz` text (`2024-01-02 03:04:05.678+00`) and naive UTC.
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(ts) = DateTime::parse_from_rfc3339(value) {
+        return Some(ts.with_timezone(&Utc));
+    }
+    if let Ok(ts) = DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f%#z") {
+        return Some(ts.with_timezone(&Utc));
+    }
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+        .ok()
+        .map(|ts| ts.and_utc())
+}
+
+fn non_empty(value: Option<&String>) -> Option<String> {
+    value.filter(|value| !value.is_empty()).cloned()
+}
+
+/// Column lookup by header name for row-oriented sources.
+struct Columns(HashMap<String, usize>);
+
+impl Columns {
+    fn new<'a>(names: impl IntoIterator<Item = &'a str>) -> Self {
+        Self(
+            names
+                .into_iter()
+                .enumerate()
+                .map(|(idx, name)| (name.trim().to_string(), idx))
+                .collect(),
+        )
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    fn get<'r>(&self, row: &'r [Option<String>], name: &str) -> Option<&'r String> {
+        self.0
+            .get(name)
+            .and_then(|idx| row.get(*idx))
+            .and_then(Option::as_ref)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn website() -> Website {
+        Website {
+            id: "site_1".to_string(),
+            tenant_id: None,
+            name: "Example".to_string(),
+            domain: "example.com".to_string(),
+            timezone: "UTC".to_string(),
+            ingest_peak_eps: None,
+            ingest_queue_max_events: None,
+            retention_days: None,
+            session_timeout_minutes: None,
+            session_split_at_midnight: false,
+            session_split_on_campaign: false,
+            share_id: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn sessions_split_per_visitor_after_inactivity() {
+        let website = website();
+        let start = parse_timestamp("2025-03-01 10:00:00").expect("timestamp");
+        let event = |visitor: &str, minutes: i64| {
+            base_event(
+                &website,
+                ImportSource::Plausible,
+                visitor,
+                "pageview",
+                page_url(None, &website, "/", None),
+                start + Duration::minutes(minutes),
+            )
+        };
+        let mut events = vec![event("a", 50), event("b", 5), event("a", 0), event("a", 10)];
+        assign_sessions(&mut events, &website.session_rules());
+
+        let sessions_of = |visitor: &str| {
+            let visitor_id = imported_visitor_id(ImportSource::Plausible, "site_1", visitor);
+            let mut ids: Vec<&str> = events
+                .iter()
+                .filter(|e| e.visitor_id == visitor_id)
+                .map(|e| e.session_id.as_str())
+                .collect();
+            ids.dedup();
+            ids.len()
+        };
+        assert_eq!(sessions_of("a"), 2);
+        assert_eq!(sessions_of("b"), 1);
+    }
+
+    #[test]
+    fn parses_export_timestamp_formats() {
+        let expected = parse_timestamp("2025-03-01T10:00:00Z").expect("rfc3339");
+        assert_eq!(parse_timestamp("2025-03-01 10:00:00+00"), Some(expected));
+        assert_eq!(
+            parse_timestamp("2025-03-01 11:00:00.000+01"),
+            Some(expected)
+        );
+        assert_eq!(parse_timestamp("2025-03-01 10:00:00"), Some(expected));
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn page_url_falls_back_to_website_domain() {
+        let website = website();
+        assert_eq!(
+            page_url(Some("blog.example.com"), &website, "/post", Some("a=1")),
+            "https://blog.example.com/post?a=1"
+        );
+        assert_eq!(
+            page_url(None, &website, "pricing", None),
+            "https://example.com/pricing"
+        );
+    }
+}
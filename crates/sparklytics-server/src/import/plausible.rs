@@ -0,0 +1,140 @@
+//! Plausible raw event exports.
+//!
+//! Expects `events_v2` rows exported from Plausible's ClickHouse with a
+//! header row, e.g. `SELECT * FROM events_v2 WHERE site_id = 1 FORMAT
+//! CSVWithNames`. Plausible's dashboard CSV export only holds daily
+//! aggregates, which cannot be turned back into events, and is rejected.
+//! Region and city are not imported because Plausible stores them as ISO
+//! subdivision codes and GeoNames ids rather than names.
+
+use anyhow::{bail, Context, Result};
+use serde_json::{Map, Value};
+
+use sparklytics_metadata::Website;
+
+use super::{
+    base_event, device_type, event_data, non_empty, page_url, parse_timestamp, set_referrer,
+    Columns, ImportSource, ParsedExport,
+};
+
+/// Parse a ClickHouse `Array(String)` literal such as `['plan','currency']`.
+fn parse_array(value: &str) -> Vec<String> {
+    let inner = value
+        .trim()
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or("");
+    let mut items = Vec::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            continue;
+        }
+        let mut item = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        item.push(escaped);
+                    }
+                }
+                '\'' => break,
+                other => item.push(other),
+            }
+        }
+        items.push(item);
+    }
+    items
+}
+
+pub(super) fn parse(website: &Website, data: &[u8]) -> Result<ParsedExport> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(data);
+    let headers = reader
+        .headers()
+        .context("the Plausible export has no header row")?
+        .clone();
+    let columns = Columns::new(headers.iter());
+    if !columns.has("timestamp") && columns.has("visitors") {
+        bail!(
+            "this is an aggregated Plausible CSV export; export raw events_v2 rows from ClickHouse instead"
+        );
+    }
+    for column in ["name", "timestamp", "pathname", "user_id"] {
+        if !columns.has(column) {
+            bail!("the Plausible export is missing the {column} column");
+        }
+    }
+
+    let mut parsed = ParsedExport {
+        events: Vec::new(),
+        skipped: 0,
+    };
+    for record in reader.records() {
+        let Ok(record) = record else {
+            parsed.skipped += 1;
+            continue;
+        };
+        let row: Vec<Option<String>> = record.iter().map(|field| Some(field.to_string())).collect();
+        let (Some(name), Some(user_id), Some(created_at)) = (
+            non_empty(columns.get(&row, "name")),
+            non_empty(columns.get(&row, "user_id")),
+            columns
+                .get(&row, "timestamp")
+                .and_then(|value| parse_timestamp(value)),
+        ) else {
+            parsed.skipped += 1;
+            continue;
+        };
+        let event_type = if name == "pageview" {
+            "pageview"
+        } else {
+            "event"
+        };
+        let url = page_url(
+            columns.get(&row, "hostname").map(String::as_str),
+            website,
+            columns.get(&row, "pathname").map_or("/", String::as_str),
+            None,
+        );
+        let mut event = base_event(
+            website,
+            ImportSource::Plausible,
+            &user_id,
+            event_type,
+            url,
+            created_at,
+        );
+        if event_type == "event" {
+            event.event_name = Some(name);
+            let keys = columns
+                .get(&row, "meta.key")
+                .map(|value| parse_array(value))
+                .unwrap_or_default();
+            let values = columns
+                .get(&row, "meta.value")
+                .map(|value| parse_array(value))
+                .unwrap_or_default();
+            let props: Map<String, Value> = keys
+                .into_iter()
+                .zip(values.into_iter().map(Value::String))
+                .collect();
+            event.event_data = event_data(props);
+        }
+        set_referrer(&mut event, non_empty(columns.get(&row, "referrer")));
+        event.utm_source = non_empty(columns.get(&row, "utm_source")).or(event.utm_source);
+        event.utm_medium = non_empty(columns.get(&row, "utm_medium")).or(event.utm_medium);
+        event.utm_campaign = non_empty(columns.get(&row, "utm_campaign")).or(event.utm_campaign);
+        event.utm_term = non_empty(columns.get(&row, "utm_term")).or(event.utm_term);
+        event.utm_content = non_empty(columns.get(&row, "utm_content")).or(event.utm_content);
+        event.country = non_empty(columns.get(&row, "country_code")).filter(|code| code != "\0\0");
+        event.browser = non_empty(columns.get(&row, "browser"));
+        event.browser_version = non_empty(columns.get(&row, "browser_version"));
+        event.os = non_empty(columns.get(&row, "operating_system"));
+        event.os_version = non_empty(columns.get(&row, "operating_system_version"));
+        event.device_type = columns
+            .get(&row, "screen_size")
+            .and_then(|value| device_type(value));
+        parsed.events.push(event);
+    }
+    Ok(parsed)
+}
@@ -0,0 +1,306 @@
+//! Umami v2 PostgreSQL dumps (`pg_dump` plain format).
+//!
+//! Reads the `COPY ... FROM stdin;` blocks of `website_event`, `session` and
+//! `event_data`. Umami's `session_id` identifies a visitor, so it becomes the
+//! imported visitor id and sessions are re-synthesised from event times.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Context, Result};
+use serde_json::{Map, Value};
+
+use sparklytics_metadata::Website;
+
+use super::{
+    base_event, device_type, event_data, non_empty, page_url, parse_timestamp, set_referrer,
+    Columns, ImportOptions, ImportSource, ParsedExport,
+};
+
+/// Rows of one `COPY` block.
+struct CopyTable {
+    columns: Columns,
+    rows: Vec<Vec<Option<String>>>,
+}
+
+/// Undo PostgreSQL's COPY text escaping; `\N` is NULL.
+fn unescape(field: &str) -> Option<String> {
+    if field == "\\N" {
+        return None;
+    }
+    if !field.contains('\\') {
+        return Some(field.to_string());
+    }
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('b') => out.push('\u{8}'),
+            Some('f') => out.push('\u{c}'),
+            Some('v') => out.push('\u{b}'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    Some(out)
+}
+
+/// Table name and column list of a `COPY public.t (a, b) FROM stdin;` line.
+fn parse_copy_header(line: &str) -> Option<(String, Vec<String>)> {
+    let rest = line.strip_prefix("COPY ")?.strip_suffix(" FROM stdin;")?;
+    let open = rest.find('(')?;
+    let table = rest[..open].trim();
+    let table = table.rsplit('.').next()?.trim_matches('"').to_string();
+    let columns = rest[open + 1..]
+        .trim_end()
+        .strip_suffix(')')?
+        .split(',')
+        .map(|column| column.trim().trim_matches('"').to_string())
+        .collect();
+    Some((table, columns))
+}
+
+fn read_copy_tables(text: &str, wanted: &[&str]) -> HashMap<String, CopyTable> {
+    let mut tables = HashMap::new();
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let Some((table, columns)) = parse_copy_header(line) else {
+            continue;
+        };
+        let keep = wanted.contains(&table.as_str());
+        let mut rows = Vec::new();
+        for row in lines.by_ref() {
+            if row == "\\." {
+                break;
+            }
+            if keep {
+                rows.push(row.split('\t').map(unescape).collect());
+            }
+        }
+        if keep {
+            let columns = Columns::new(columns.iter().map(String::as_str));
+            tables.insert(table, CopyTable { columns, rows });
+        }
+    }
+    tables
+}
+
+/// Umami stores browser keys such as `chrome` or `edge-chromium`; map the
+/// common ones to the names our UA parser produces.
+fn browser_name(key: &str) -> String {
+    match key {
+        "chrome" | "crios" | "chromium-webview" => "Chrome",
+        "firefox" | "fxios" => "Firefox",
+        "safari" | "ios" | "ios-webview" => "Safari",
+        "edge" | "edge-chromium" | "edge-ios" => "Edge",
+        "opera" | "opera-mini" => "Opera",
+        "samsung" => "Samsung Internet",
+        "yandexbrowser" => "Yandex Browser",
+        "ie" => "Internet Explorer",
+        other => other,
+    }
+    .to_string()
+}
+
+pub(super) fn parse(
+    website: &Website,
+    data: &[u8],
+    options: &ImportOptions,
+) -> Result<ParsedExport> {
+    let text = std::str::from_utf8(data).context("the Umami dump is not valid UTF-8")?;
+    let mut tables = read_copy_tables(text, &["website_event", "session", "event_data"]);
+    let Some(events) = tables.remove("website_event") else {
+        bail!("no COPY block for website_event found; expected a plain-format pg_dump of the Umami database");
+    };
+    for column in [
+        "website_id",
+        "session_id",
+        "created_at",
+        "url_path",
+        "event_type",
+    ] {
+        if !events.columns.has(column) {
+            bail!("website_event is missing the {column} column");
+        }
+    }
+
+    let source_websites: HashSet<&str> = events
+        .rows
+        .iter()
+        .filter_map(|row| events.columns.get(row, "website_id").map(String::as_str))
+        .collect();
+    let source_website_id = match options.source_website_id.as_deref() {
+        Some(id) if source_websites.contains(id) => id.to_string(),
+        Some(id) => bail!("the dump has no events for Umami website {id}"),
+        None if source_websites.is_empty() => bail!("the website_event table is empty"),
+        None if source_websites.len() == 1 => source_websites
+            .iter()
+            .next()
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        None => bail!(
+            "the dump holds {} Umami websites; pass the one to import as source_website_id",
+            source_websites.len()
+        ),
+    };
+
+    let mut sessions: HashMap<String, UmamiSession> = HashMap::new();
+    if let Some(table) = tables.remove("session") {
+        let columns = &table.columns;
+        for row in &table.rows {
+            let Some(session_id) = columns.get(row, "session_id") else {
+                continue;
+            };
+            let field = |name: &str| non_empty(columns.get(row, name));
+            sessions.insert(
+                session_id.clone(),
+                UmamiSession {
+                    hostname: field("hostname"),
+                    browser: field("browser"),
+                    os: field("os"),
+                    device: field("device"),
+                    screen: field("screen"),
+                    language: field("language"),
+                    country: field("country"),
+                    region: field("subdivision1"),
+                    city: field("city"),
+                },
+            );
+        }
+    }
+
+    let mut properties: HashMap<String, Map<String, Value>> = HashMap::new();
+    if let Some(table) = tables.remove("event_data") {
+        for row in &table.rows {
+            let (Some(event_id), Some(key)) = (
+                table.columns.get(row, "website_event_id"),
+                table.columns.get(row, "data_key"),
+            ) else {
+                continue;
+            };
+            let value = if let Some(number) = table
+                .columns
+                .get(row, "number_value")
+                .and_then(|value| value.parse::<f64>().ok())
+            {
+                serde_json::Number::from_f64(number).map_or(Value::Null, Value::Number)
+            } else if let Some(value) = table
+                .columns
+                .get(row, "string_value")
+                .or_else(|| table.columns.get(row, "date_value"))
+            {
+                Value::String(value.clone())
+            } else {
+                continue;
+            };
+            properties
+                .entry(event_id.clone())
+                .or_default()
+                .insert(key.clone(), value);
+        }
+    }
+
+    let columns = &events.columns;
+    let mut parsed = ParsedExport {
+        events: Vec::with_capacity(events.rows.len()),
+        skipped: 0,
+    };
+    for row in &events.rows {
+        if columns.get(row, "website_id").map(String::as_str) != Some(source_website_id.as_str()) {
+            continue;
+        }
+        let (Some(session_id), Some(created_at)) = (
+            columns.get(row, "session_id"),
+            columns
+                .get(row, "created_at")
+                .and_then(|value| parse_timestamp(value)),
+        ) else {
+            parsed.skipped += 1;
+            continue;
+        };
+        let event_type = match columns.get(row, "event_type").map(String::as_str) {
+            Some("1") => "pageview",
+            Some("2") if non_empty(columns.get(row, "event_name")).is_some() => "event",
+            _ => {
+                parsed.skipped += 1;
+                continue;
+            }
+        };
+        let session = sessions.get(session_id.as_str());
+        let hostname = non_empty(columns.get(row, "hostname"))
+            .or_else(|| session.and_then(|s| s.hostname.clone()));
+        let url = page_url(
+            hostname.as_deref(),
+            website,
+            columns.get(row, "url_path").map_or("/", String::as_str),
+            columns.get(row, "url_query").map(String::as_str),
+        );
+        let mut event = base_event(
+            website,
+            ImportSource::Umami,
+            session_id,
+            event_type,
+            url,
+            created_at,
+        );
+        if event_type == "event" {
+            event.event_name = non_empty(columns.get(row, "event_name"));
+            if let Some(props) = columns
+                .get(row, "event_id")
+                .and_then(|id| properties.remove(id))
+            {
+                event.event_data = event_data(props);
+            }
+        }
+        let referrer = non_empty(columns.get(row, "referrer_domain")).map(|domain| {
+            let path = columns.get(row, "referrer_path").map_or("", String::as_str);
+            match non_empty(columns.get(row, "referrer_query")) {
+                Some(query) => format!("https://{domain}{path}?{query}"),
+                None => format!("https://{domain}{path}"),
+            }
+        });
+        set_referrer(&mut event, referrer);
+        for (column, field) in [
+            ("utm_source", &mut event.utm_source),
+            ("utm_medium", &mut event.utm_medium),
+            ("utm_campaign", &mut event.utm_campaign),
+            ("utm_term", &mut event.utm_term),
+            ("utm_content", &mut event.utm_content),
+        ] {
+            if let Some(value) = non_empty(columns.get(row, column)) {
+                *field = Some(value);
+            }
+        }
+        if let Some(session) = session {
+            event.browser = session.browser.as_deref().map(browser_name);
+            event.os = session.os.clone();
+            event.device_type = session.device.as_deref().and_then(device_type);
+            event.screen = session.screen.clone();
+            event.language = session.language.clone();
+            event.country = session.country.clone();
+            event.region = session.region.clone();
+            event.city = session.city.clone();
+        }
+        parsed.events.push(event);
+    }
+    Ok(parsed)
+}
+
+/// Visitor attributes from Umami's `session` table.
+struct UmamiSession {
+    hostname: Option<String>,
+    browser: Option<String>,
+    os: Option<String>,
+    device: Option<String>,
+    screen: Option<String>,
+    language: Option<String>,
+    country: Option<String>,
+    region: Option<String>,
+    city: Option<String>,
+}
@@ -1,9 +1,12 @@
 pub mod app;
 pub mod auth;
+pub mod backup;
 pub mod bot_detection;
 pub mod config;
 pub mod error;
+pub mod import;
 pub mod metadata;
+pub mod realtime_feed;
 pub mod routes;
 pub mod scheduler;
 pub mod state;
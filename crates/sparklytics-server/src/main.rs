@@ -1,12 +1,15 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
 use tracing::info;
 use url::Url;
 
+use sparklytics_duckdb::migrations::{self, MIGRATIONS};
 use sparklytics_server::auth::handlers::{
     bootstrap_password_is_default, effective_bootstrap_password,
 };
+use sparklytics_server::backup::{self, BackupSettings, DATABASE_FILE};
+use sparklytics_server::import::{parse_export, store_import, ImportOptions, ImportSource};
 use sparklytics_server::state::AppState;
 
 /// `sparklytics health` — liveness probe for Docker HEALTHCHECK.
@@ -22,6 +25,201 @@ fn run_health_check() -> ! {
     }
 }
 
+const IMPORT_USAGE: &str = "usage: sparklytics import --website <id> --source <umami|plausible|ga4> [--source-website <uuid>] <file>
+       sparklytics import --website <id> --list
+       sparklytics import --website <id> --rollback <import_id>";
+
+/// `sparklytics import` — load a historical export, list imports, or roll
+/// one back, printing the result as JSON.
+///
+/// Opens the database file directly, so the server must be stopped first:
+/// DuckDB allows only one process to hold the file.
+async fn run_import_command(args: &[String]) -> Result<()> {
+    let mut website_id = None;
+    let mut source = None;
+    let mut source_website_id = None;
+    let mut rollback = None;
+    let mut list = false;
+    let mut file = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--website" => website_id = args.next().cloned(),
+            "--source" => source = args.next().cloned(),
+            "--source-website" => source_website_id = args.next().cloned(),
+            "--rollback" => rollback = args.next().cloned(),
+            "--list" => list = true,
+            other if other.starts_with("--") => {
+                return Err(anyhow!("unknown option {other}\n{IMPORT_USAGE}"));
+            }
+            other => file = Some(other.to_string()),
+        }
+    }
+    let website_id = website_id.ok_or_else(|| anyhow!(IMPORT_USAGE))?;
+
+    let cfg = sparklytics_core::config::Config::from_env().map_err(|e| anyhow!(e))?;
+    let db_path = format!("{}/sparklytics.db", cfg.data_dir);
+    let db = sparklytics_duckdb::DuckDbBackend::open(&db_path, &cfg.duckdb_memory_limit)?;
+    let website = db
+        .get_website(&website_id)
+        .await?
+        .ok_or_else(|| anyhow!("website {website_id} not found"))?;
+
+    let output = if list {
+        serde_json::to_value(db.list_imports(&website_id).await?)?
+    } else if let Some(import_id) = rollback {
+        let record = db
+            .rollback_import(&website_id, &import_id)
+            .await?
+            .ok_or_else(|| anyhow!("import {import_id} not found"))?;
+        serde_json::to_value(record)?
+    } else {
+        let source = source
+            .as_deref()
+            .and_then(ImportSource::parse)
+            .ok_or_else(|| anyhow!(IMPORT_USAGE))?;
+        let path = file.ok_or_else(|| anyhow!(IMPORT_USAGE))?;
+        let data = std::fs::read(&path)?;
+        let file_name = std::path::Path::new(&path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+        let parsed = parse_export(
+            source,
+            &website,
+            &data,
+            &ImportOptions { source_website_id },
+        )?;
+        let record = store_import(&db, &website, source, file_name.as_deref(), &parsed).await?;
+        serde_json::to_value(record)?
+    };
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
+
+/// `sparklytics backup` — back up the data directory into
+/// `SPARKLYTICS_BACKUP_DESTINATION` and rotate old backups.
+///
+/// Asks the server on `localhost:$SPARKLYTICS_PORT` to take the backup so it
+/// is consistent with in-flight ingestion, authenticating with
+/// `SPARKLYTICS_API_KEY` when set. When no server is listening, opens the
+/// database directly instead.
+async fn run_backup_command() -> Result<()> {
+    let cfg = sparklytics_core::config::Config::from_env().map_err(|e| anyhow!(e))?;
+    let settings = BackupSettings::from_env(&cfg.data_dir)?;
+
+    let url = format!("http://localhost:{}/api/backups", cfg.port);
+    let mut request = ureq::post(&url);
+    if let Ok(api_key) = std::env::var("SPARKLYTICS_API_KEY") {
+        request = request.set("Authorization", &format!("Bearer {api_key}"));
+    }
+    let output = match request.call() {
+        Ok(response) => {
+            let body: serde_json::Value = serde_json::from_str(&response.into_string()?)?;
+            body["data"].clone()
+        }
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            return Err(anyhow!(
+                "the running server refused the backup (HTTP {status}): {body}\n\
+                 Set SPARKLYTICS_API_KEY to an API key when auth is enabled."
+            ));
+        }
+        Err(ureq::Error::Transport(_)) => {
+            let db_path = format!("{}/{}", cfg.data_dir, DATABASE_FILE);
+            let db = sparklytics_duckdb::DuckDbBackend::open(&db_path, &cfg.duckdb_memory_limit)?;
+            let created =
+                backup::create_backup(&db, std::path::Path::new(&cfg.data_dir), &settings.dir, &[])
+                    .await?;
+            backup::prune_backups(&settings.dir, settings.retain)?;
+            serde_json::to_value(created)?
+        }
+    };
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
+
+/// `sparklytics list-backups` — backups in `SPARKLYTICS_BACKUP_DESTINATION`,
+/// newest first.
+fn run_list_backups_command() -> Result<()> {
+    let cfg = sparklytics_core::config::Config::from_env().map_err(|e| anyhow!(e))?;
+    let settings = BackupSettings::from_env(&cfg.data_dir)?;
+    let backups = backup::list_backups(&settings.dir)?;
+    println!("{}", serde_json::to_string_pretty(&backups)?);
+    Ok(())
+}
+
+/// `sparklytics restore <path>` — replace the data directory's database and
+/// ingest WAL with a backup. `<path>` is a backup directory or the name of
+/// one in `SPARKLYTICS_BACKUP_DESTINATION`. The server must be stopped.
+fn run_restore_command(args: &[String]) -> Result<()> {
+    let [path] = args else {
+        return Err(anyhow!("usage: sparklytics restore <backup-path>"));
+    };
+    let cfg = sparklytics_core::config::Config::from_env().map_err(|e| anyhow!(e))?;
+    let settings = BackupSettings::from_env(&cfg.data_dir)?;
+    let mut backup_path = std::path::PathBuf::from(path);
+    if !backup_path.exists() && settings.dir.join(path).exists() {
+        backup_path = settings.dir.join(path);
+    }
+    let report = backup::restore_backup(&backup_path, std::path::Path::new(&cfg.data_dir))?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+/// `sparklytics migrate [--status | --dry-run | --rollback-to <id>]` —
+/// inspect or change the schema version of the data directory's database.
+/// Without a flag, applies pending migrations (the server also does this at
+/// startup). The server must be stopped.
+fn run_migrate_command(args: &[String]) -> Result<()> {
+    let cfg = sparklytics_core::config::Config::from_env().map_err(|e| anyhow!(e))?;
+    let db_path = format!("{}/{}", cfg.data_dir, DATABASE_FILE);
+    if !args.is_empty() && !std::path::Path::new(&db_path).exists() {
+        return Err(anyhow!("no database at {db_path}"));
+    }
+    let mut conn = sparklytics_duckdb::duckdb::Connection::open(&db_path)
+        .with_context(|| format!("failed to open {db_path}; stop the server before migrating"))?;
+    conn.execute_batch(&sparklytics_duckdb::schema::init_sql(
+        &cfg.duckdb_memory_limit,
+    ))?;
+
+    match args {
+        [] => {
+            let applied = migrations::run(&mut conn, MIGRATIONS)?;
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({ "applied": applied }))?
+            );
+        }
+        [flag] if flag == "--status" => {
+            let status = migrations::status(&conn, MIGRATIONS)?;
+            println!("{}", serde_json::to_string_pretty(&status)?);
+        }
+        [flag] if flag == "--dry-run" => {
+            for migration in migrations::pending(&conn, MIGRATIONS)? {
+                println!(
+                    "-- {}: {}\n{}\n",
+                    migration.id,
+                    migration.description,
+                    migration.up.trim()
+                );
+            }
+        }
+        [flag, target] if flag == "--rollback-to" => {
+            let rolled_back = migrations::rollback_to(&mut conn, MIGRATIONS, target)?;
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({ "rolled_back": rolled_back }))?
+            );
+        }
+        _ => {
+            return Err(anyhow!(
+                "usage: sparklytics migrate [--status | --dry-run | --rollback-to <id>]"
+            ))
+        }
+    }
+    Ok(())
+}
+
 fn public_url_uses_loopback_host(public_url: &str) -> bool {
     Url::parse(public_url)
         .ok()
@@ -43,6 +241,14 @@ async fn main() -> Result<()> {
     if args.get(1).map(|s| s.as_str()) == Some("health") {
         run_health_check();
     }
+    match args.get(1).map(|s| s.as_str()) {
+        Some("import") => return run_import_command(&args[2..]).await,
+        Some("backup") => return run_backup_command().await,
+        Some("list-backups") => return run_list_backups_command(),
+        Some("restore") => return run_restore_command(&args[2..]),
+        Some("migrate") => return run_migrate_command(&args[2..]),
+        _ => {}
+    }
     // Initialise structured JSON logging. Level controlled via RUST_LOG env var.
     tracing_subscriber::fmt()
         .with_env_filter(
@@ -88,10 +294,11 @@ async fn main() -> Result<()> {
         );
     }
 
-    // Auth initialization for password/local modes.
+    // Auth initialization for password/local/oidc modes.
     match &cfg.auth_mode {
         sparklytics_core::config::AuthMode::Password(_)
-        | sparklytics_core::config::AuthMode::Local => {
+        | sparklytics_core::config::AuthMode::Local
+        | sparklytics_core::config::AuthMode::Oidc(_) => {
             match db.ensure_jwt_secret().await {
                 Ok(_) => info!("JWT secret ready"),
                 Err(e) => tracing::error!(error = %e, "Failed to ensure JWT secret"),
@@ -146,6 +353,31 @@ async fn main() -> Result<()> {
         });
     }
 
+    // Spawn background retention purge task (runs daily at 03:00 UTC).
+    {
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            state.run_retention_purge_loop().await;
+        });
+    }
+
+    // Spawn background daily rollup refresh task.
+    {
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            state.run_rollup_refresh_loop().await;
+        });
+    }
+
+    // Spawn scheduled backups when SPARKLYTICS_BACKUP_ENABLED is set.
+    let backup_settings = BackupSettings::from_env(&cfg.data_dir)?;
+    if backup_settings.enabled {
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            backup::run_backup_loop(state, backup_settings).await;
+        });
+    }
+
     // Spawn notifications scheduler worker.
     {
         let state = Arc::clone(&state);
@@ -5,7 +5,9 @@ use sparklytics_core::analytics::BotPolicy;
 
 use sparklytics_duckdb::DuckDbBackend;
 use sparklytics_metadata::{
-    ApiKeyRecord, CreateWebsiteParams, MetadataStore, UpdateWebsiteParams, Website,
+    ApiKeyRecord, ApiKeyScope, CreateInviteParams, CreateWebsiteParams, InviteRecord,
+    MetadataStore, SsoUserParams, TotpFactor, UpdateUserParams, UpdateWebsiteParams, UserRecord,
+    Website,
 };
 
 pub struct DuckDbMetadataStore {
@@ -70,8 +72,9 @@ impl MetadataStore for DuckDbMetadataStore {
         name: &str,
         hash: &str,
         prefix: &str,
+        scope: &ApiKeyScope,
     ) -> anyhow::Result<()> {
-        self.db.create_api_key(id, name, hash, prefix).await
+        self.db.create_api_key(id, name, hash, prefix, scope).await
     }
 
     async fn revoke_api_key(&self, key_id: &str) -> anyhow::Result<bool> {
@@ -98,6 +101,108 @@ impl MetadataStore for DuckDbMetadataStore {
         self.db.prune_login_attempts().await
     }
 
+    async fn list_users(&self) -> anyhow::Result<Vec<UserRecord>> {
+        self.db.list_users().await
+    }
+
+    async fn get_user(&self, id: &str) -> anyhow::Result<Option<UserRecord>> {
+        self.db.get_user(id).await
+    }
+
+    async fn get_user_login(&self, email: &str) -> anyhow::Result<Option<(UserRecord, String)>> {
+        self.db.get_user_login(email).await
+    }
+
+    async fn update_user(
+        &self,
+        id: &str,
+        params: UpdateUserParams,
+    ) -> anyhow::Result<Option<UserRecord>> {
+        self.db.update_user(id, params).await
+    }
+
+    async fn delete_user(&self, id: &str) -> anyhow::Result<bool> {
+        self.db.delete_user(id).await
+    }
+
+    async fn set_user_password(&self, id: &str, password_hash: &str) -> anyhow::Result<bool> {
+        self.db.set_user_password(id, password_hash).await
+    }
+
+    async fn touch_user_login(&self, id: &str) -> anyhow::Result<()> {
+        self.db.touch_user_login(id).await
+    }
+
+    async fn upsert_sso_user(&self, params: SsoUserParams) -> anyhow::Result<UserRecord> {
+        self.db.upsert_sso_user(params).await
+    }
+
+    async fn get_totp_factor(&self, account_id: &str) -> anyhow::Result<Option<TotpFactor>> {
+        self.db.get_totp_factor(account_id).await
+    }
+
+    async fn begin_totp_enrollment(&self, account_id: &str, secret: &str) -> anyhow::Result<()> {
+        self.db.begin_totp_enrollment(account_id, secret).await
+    }
+
+    async fn confirm_totp_enrollment(
+        &self,
+        account_id: &str,
+        step: i64,
+        recovery_code_hashes: &[String],
+    ) -> anyhow::Result<bool> {
+        self.db
+            .confirm_totp_enrollment(account_id, step, recovery_code_hashes)
+            .await
+    }
+
+    async fn use_totp_step(&self, account_id: &str, step: i64) -> anyhow::Result<bool> {
+        self.db.use_totp_step(account_id, step).await
+    }
+
+    async fn use_totp_recovery_code(
+        &self,
+        account_id: &str,
+        code_hash: &str,
+    ) -> anyhow::Result<bool> {
+        self.db.use_totp_recovery_code(account_id, code_hash).await
+    }
+
+    async fn replace_totp_recovery_codes(
+        &self,
+        account_id: &str,
+        recovery_code_hashes: &[String],
+    ) -> anyhow::Result<()> {
+        self.db
+            .replace_totp_recovery_codes(account_id, recovery_code_hashes)
+            .await
+    }
+
+    async fn delete_totp_factor(&self, account_id: &str) -> anyhow::Result<bool> {
+        self.db.delete_totp_factor(account_id).await
+    }
+
+    async fn create_invite(&self, params: CreateInviteParams) -> anyhow::Result<InviteRecord> {
+        self.db.create_invite(params).await
+    }
+
+    async fn list_pending_invites(&self) -> anyhow::Result<Vec<InviteRecord>> {
+        self.db.list_pending_invites().await
+    }
+
+    async fn revoke_invite(&self, id: &str) -> anyhow::Result<bool> {
+        self.db.revoke_invite(id).await
+    }
+
+    async fn accept_invite(
+        &self,
+        token_hash: &str,
+        name: Option<&str>,
+        password_hash: &str,
+    ) -> anyhow::Result<Option<UserRecord>> {
+        self.db.accept_invite(token_hash, name, password_hash).await
+    }
+
     async fn create_website(&self, params: CreateWebsiteParams) -> anyhow::Result<Website> {
         self.db.create_website(params).await
     }
@@ -1,4 +1,6 @@
 pub mod duckdb;
 pub use sparklytics_metadata::{
-    ApiKeyRecord, CreateWebsiteParams, MetadataStore, UpdateWebsiteParams, Website,
+    ApiKeyAccess, ApiKeyRecord, ApiKeyScope, CreateInviteParams, CreateWebsiteParams, InviteRecord,
+    MetadataStore, SsoUserParams, TotpFactor, UpdateUserParams, UpdateWebsiteParams,
+    UserEmailTaken, UserRecord, UserRole, Website,
 };
@@ -0,0 +1,270 @@
+//! In-process fan-out of freshly ingested events for the realtime stream.
+//!
+//! Events are published from the ingest path as soon as they are accepted,
+//! before they reach DuckDB, and the active-visitor count is derived from the
+//! same in-memory view. Visitors seen before the process started are not
+//! counted until they send another event.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use tokio::sync::broadcast;
+
+use sparklytics_core::{analytics::RealtimeEvent, event::Event};
+
+/// Matches the window of `GET /api/websites/:id/realtime`.
+const ACTIVE_VISITOR_WINDOW_MINUTES: i64 = 30;
+/// Events of one website a slow subscriber may fall behind before it skips
+/// ahead and has to resync.
+const SUBSCRIBER_BUFFER: usize = 1024;
+/// How often a website's visitor map is swept for expired entries.
+const PRUNE_INTERVAL_SECONDS: i64 = 60;
+
+/// One ingested event as delivered to stream subscribers.
+#[derive(Debug, Clone)]
+pub struct RealtimeUpdate {
+    pub website_id: String,
+    pub event: RealtimeEvent,
+    pub is_bot: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ActiveVisitor {
+    last_seen: DateTime<Utc>,
+    is_bot: bool,
+}
+
+struct WebsiteVisitors {
+    visitors: HashMap<String, ActiveVisitor>,
+    pruned_at: DateTime<Utc>,
+}
+
+impl WebsiteVisitors {
+    fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            visitors: HashMap::new(),
+            pruned_at: now,
+        }
+    }
+
+    fn prune(&mut self, now: DateTime<Utc>) {
+        let cutoff = active_cutoff(now);
+        self.visitors
+            .retain(|_, visitor| visitor.last_seen > cutoff);
+        self.pruned_at = now;
+    }
+}
+
+fn active_cutoff(now: DateTime<Utc>) -> DateTime<Utc> {
+    now - Duration::minutes(ACTIVE_VISITOR_WINDOW_MINUTES)
+}
+
+fn realtime_event(event: &Event) -> RealtimeEvent {
+    RealtimeEvent {
+        event_type: event.event_type.clone(),
+        url: event.url.clone(),
+        referrer_domain: event.referrer_domain.clone(),
+        country: event.country.clone(),
+        browser: event.browser.clone(),
+        device_type: event.device_type.clone(),
+        ts: event.created_at.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
+    }
+}
+
+/// A broadcast channel per subscribed website plus the visitors active in
+/// the last 30 minutes.
+///
+/// Publishing never waits: sending on a channel is lock-free, and both map
+/// locks are only held for in-memory updates. Separate channels keep a busy
+/// website from pushing subscribers of quiet ones past their buffer.
+#[derive(Default)]
+pub struct RealtimeFeed {
+    channels: Mutex<HashMap<String, broadcast::Sender<RealtimeUpdate>>>,
+    websites: Mutex<HashMap<String, WebsiteVisitors>>,
+}
+
+impl RealtimeFeed {
+    /// Record accepted events and push them to current subscribers.
+    pub fn publish(&self, events: &[Event], now: DateTime<Utc>) {
+        {
+            let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+            if !channels.is_empty() {
+                for event in events {
+                    if let Some(sender) = channels.get(&event.website_id) {
+                        // Send only fails when every receiver has gone away.
+                        let _ = sender.send(RealtimeUpdate {
+                            website_id: event.website_id.clone(),
+                            event: realtime_event(event),
+                            is_bot: event.is_bot,
+                        });
+                    }
+                }
+                channels.retain(|_, sender| sender.receiver_count() > 0);
+            }
+        }
+
+        let mut websites = self.websites.lock().unwrap_or_else(|e| e.into_inner());
+        for event in events {
+            let website = websites
+                .entry(event.website_id.clone())
+                .or_insert_with(|| WebsiteVisitors::new(now));
+            let visitor =
+                website
+                    .visitors
+                    .entry(event.visitor_id.clone())
+                    .or_insert(ActiveVisitor {
+                        last_seen: event.created_at,
+                        is_bot: event.is_bot,
+                    });
+            visitor.last_seen = visitor.last_seen.max(event.created_at);
+            visitor.is_bot = visitor.is_bot || event.is_bot;
+        }
+        for website in websites.values_mut() {
+            if (now - website.pruned_at).num_seconds() >= PRUNE_INTERVAL_SECONDS {
+                website.prune(now);
+            }
+        }
+        websites.retain(|_, website| !website.visitors.is_empty());
+    }
+
+    /// Subscribe to events of `website_id` ingested from now on.
+    pub fn subscribe(&self, website_id: &str) -> broadcast::Receiver<RealtimeUpdate> {
+        let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+        channels
+            .entry(website_id.to_string())
+            .or_insert_with(|| broadcast::channel(SUBSCRIBER_BUFFER).0)
+            .subscribe()
+    }
+
+    /// Distinct visitors of `website_id` seen in the last 30 minutes.
+    pub fn active_visitors(&self, website_id: &str, include_bots: bool, now: DateTime<Utc>) -> i64 {
+        let websites = self.websites.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(website) = websites.get(website_id) else {
+            return 0;
+        };
+        let cutoff = active_cutoff(now);
+        website
+            .visitors
+            .values()
+            .filter(|visitor| visitor.last_seen > cutoff && (include_bots || !visitor.is_bot))
+            .count() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(website_id: &str, visitor_id: &str, is_bot: bool, created_at: DateTime<Utc>) -> Event {
+        Event {
+            id: uuid::Uuid::new_v4().to_string(),
+            website_id: website_id.to_string(),
+            tenant_id: None,
+            session_id: String::new(),
+            visitor_id: visitor_id.to_string(),
+            event_type: "pageview".to_string(),
+            url: "/".to_string(),
+            referrer_url: None,
+            referrer_domain: None,
+            event_name: None,
+            event_data: None,
+            country: None,
+            region: None,
+            city: None,
+            browser: None,
+            browser_version: None,
+            os: None,
+            os_version: None,
+            device_type: None,
+            screen: None,
+            language: None,
+            utm_source: None,
+            utm_medium: None,
+            utm_campaign: None,
+            utm_term: None,
+            utm_content: None,
+            link_id: None,
+            pixel_id: None,
+            source_ip: None,
+            user_agent: None,
+            is_bot,
+            bot_score: 0,
+            bot_reason: None,
+            created_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn active_visitors_expire_after_window() {
+        let feed = RealtimeFeed::default();
+        let now = Utc::now();
+        feed.publish(
+            &[
+                event("site_1", "a", false, now - Duration::minutes(40)),
+                event("site_1", "b", false, now - Duration::minutes(5)),
+                event("site_1", "b", false, now),
+                event("site_1", "bot", true, now),
+                event("site_2", "c", false, now),
+            ],
+            now,
+        );
+        assert_eq!(feed.active_visitors("site_1", false, now), 1);
+        assert_eq!(feed.active_visitors("site_1", true, now), 2);
+        assert_eq!(
+            feed.active_visitors("site_1", true, now + Duration::minutes(31)),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn subscribers_receive_only_their_websites_events_in_order() {
+        let feed = RealtimeFeed::default();
+        let mut receiver = feed.subscribe("site_1");
+        let now = Utc::now();
+        feed.publish(
+            &[
+                event("site_1", "a", false, now),
+                event("site_2", "c", false, now),
+                event("site_1", "b", true, now),
+            ],
+            now,
+        );
+        let update = receiver.recv().await.expect("update");
+        assert_eq!(update.website_id, "site_1");
+        assert!(!update.is_bot);
+        let update = receiver.recv().await.expect("update");
+        assert_eq!(update.website_id, "site_1");
+        assert!(update.is_bot);
+        assert_eq!(update.event.url, "/");
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn busy_websites_do_not_push_other_subscribers_behind() {
+        let feed = RealtimeFeed::default();
+        let mut quiet = feed.subscribe("site_quiet");
+        let mut busy = feed.subscribe("site_busy");
+        let now = Utc::now();
+        let mut events: Vec<Event> = (0..SUBSCRIBER_BUFFER + 10)
+            .map(|i| event("site_busy", &format!("v{i}"), false, now))
+            .collect();
+        events.push(event("site_quiet", "q", false, now));
+        feed.publish(&events, now);
+
+        let update = quiet.recv().await.expect("quiet update");
+        assert_eq!(update.website_id, "site_quiet");
+        assert!(matches!(
+            busy.recv().await,
+            Err(broadcast::error::RecvError::Lagged(10))
+        ));
+
+        drop(quiet);
+        feed.publish(&[event("site_quiet", "q", false, now)], now);
+        assert!(!feed
+            .channels
+            .lock()
+            .expect("channels")
+            .contains_key("site_quiet"));
+    }
+}
@@ -8,11 +8,17 @@ use axum::{
 use serde::Deserialize;
 use serde_json::json;
 
-use sparklytics_core::analytics::{AnalyticsFilter, AttributionModel, AttributionQuery};
+use sparklytics_core::analytics::{
+    AnalyticsFilter, AttributionModel, AttributionQuery, DEFAULT_ATTRIBUTION_LOOKBACK_DAYS,
+    DEFAULT_TIME_DECAY_HALF_LIFE_DAYS, MAX_ATTRIBUTION_LOOKBACK_DAYS,
+};
 
 use crate::{
-    error::AppError,
-    routes::query::{parse_defaulted_date_range_strict, today_for_optional_timezone},
+    error::{AppError, QUERY_TIMEOUT_RETRY_AFTER_SECONDS},
+    routes::query::{
+        parse_defaulted_date_range_strict, parse_dimension_filter, parse_filter_properties,
+        today_for_optional_timezone,
+    },
     state::AppState,
 };
 
@@ -20,6 +26,8 @@ use crate::{
 pub struct AttributionRequestQuery {
     pub goal_id: String,
     pub model: Option<String>,
+    pub lookback_days: Option<u32>,
+    pub half_life_days: Option<f64>,
     pub start_date: Option<String>,
     pub end_date: Option<String>,
     pub timezone: Option<String>,
@@ -36,17 +44,35 @@ pub struct AttributionRequestQuery {
     pub filter_region: Option<String>,
     pub filter_city: Option<String>,
     pub filter_hostname: Option<String>,
+    pub filter_properties: Option<String>,
     pub include_bots: Option<bool>,
 }
 
 fn parse_model(raw: Option<&str>) -> Result<AttributionModel, AppError> {
     match raw.map(str::trim) {
-        None | Some("") | Some("last_touch") => Ok(AttributionModel::LastTouch),
-        Some("first_touch") => Ok(AttributionModel::FirstTouch),
-        Some(_) => Err(AppError::BadRequest(
-            "model must be one of: first_touch, last_touch".to_string(),
-        )),
+        None | Some("") => Ok(AttributionModel::LastTouch),
+        Some(raw) => AttributionModel::parse(raw).map_err(|e| AppError::BadRequest(e.to_string())),
+    }
+}
+
+fn parse_lookback_days(raw: Option<u32>) -> Result<u32, AppError> {
+    let days = raw.unwrap_or(DEFAULT_ATTRIBUTION_LOOKBACK_DAYS);
+    if days == 0 || days > MAX_ATTRIBUTION_LOOKBACK_DAYS {
+        return Err(AppError::BadRequest(format!(
+            "lookback_days must be between 1 and {MAX_ATTRIBUTION_LOOKBACK_DAYS}"
+        )));
+    }
+    Ok(days)
+}
+
+fn parse_half_life_days(raw: Option<f64>) -> Result<f64, AppError> {
+    let days = raw.unwrap_or(DEFAULT_TIME_DECAY_HALF_LIFE_DAYS);
+    if !days.is_finite() || days <= 0.0 || days > f64::from(MAX_ATTRIBUTION_LOOKBACK_DAYS) {
+        return Err(AppError::BadRequest(format!(
+            "half_life_days must be greater than 0 and at most {MAX_ATTRIBUTION_LOOKBACK_DAYS}"
+        )));
     }
+    Ok(days)
 }
 
 fn build_filter(
@@ -72,30 +98,38 @@ fn build_filter(
         6,
     )?;
     let model = parse_model(query.model.as_deref())?;
+    let lookback_days = parse_lookback_days(query.lookback_days)?;
+    let half_life_days = parse_half_life_days(query.half_life_days)?;
 
     let filter = AnalyticsFilter {
         start_date,
         end_date,
         timezone: normalized_timezone,
-        filter_country: query.filter_country,
-        filter_page: query.filter_page,
-        filter_referrer: query.filter_referrer,
-        filter_browser: query.filter_browser,
-        filter_os: query.filter_os,
-        filter_device: query.filter_device,
-        filter_language: query.filter_language,
-        filter_utm_source: query.filter_utm_source,
-        filter_utm_medium: query.filter_utm_medium,
-        filter_utm_campaign: query.filter_utm_campaign,
-        filter_region: query.filter_region,
-        filter_city: query.filter_city,
-        filter_hostname: query.filter_hostname,
+        filter_country: parse_dimension_filter("filter_country", query.filter_country)?,
+        filter_page: parse_dimension_filter("filter_page", query.filter_page)?,
+        filter_referrer: parse_dimension_filter("filter_referrer", query.filter_referrer)?,
+        filter_browser: parse_dimension_filter("filter_browser", query.filter_browser)?,
+        filter_os: parse_dimension_filter("filter_os", query.filter_os)?,
+        filter_device: parse_dimension_filter("filter_device", query.filter_device)?,
+        filter_language: parse_dimension_filter("filter_language", query.filter_language)?,
+        filter_utm_source: parse_dimension_filter("filter_utm_source", query.filter_utm_source)?,
+        filter_utm_medium: parse_dimension_filter("filter_utm_medium", query.filter_utm_medium)?,
+        filter_utm_campaign: parse_dimension_filter(
+            "filter_utm_campaign",
+            query.filter_utm_campaign,
+        )?,
+        filter_region: parse_dimension_filter("filter_region", query.filter_region)?,
+        filter_city: parse_dimension_filter("filter_city", query.filter_city)?,
+        filter_hostname: parse_dimension_filter("filter_hostname", query.filter_hostname)?,
+        filter_properties: parse_filter_properties(query.filter_properties.as_deref())?,
         include_bots: query.include_bots.unwrap_or(default_include_bots),
     };
 
     let attribution = AttributionQuery {
         goal_id: query.goal_id,
         model,
+        lookback_days,
+        half_life_days,
     };
 
     Ok((filter, attribution))
@@ -118,8 +152,13 @@ pub async fn get_attribution(
         .get_attribution(&website_id, None, &filter, &attribution_query)
         .await
         .map_err(|err| {
-            if err.to_string().contains("Goal not found") {
+            let msg = err.to_string();
+            if msg.contains("Goal not found") {
                 AppError::NotFound("Goal not found".to_string())
+            } else if msg.contains("query_timeout") {
+                AppError::QueryTimeout {
+                    retry_after_seconds: QUERY_TIMEOUT_RETRY_AFTER_SECONDS,
+                }
             } else {
                 AppError::Internal(err)
             }
@@ -145,8 +184,13 @@ pub async fn get_revenue_summary(
         .get_revenue_summary(&website_id, None, &filter, &attribution_query)
         .await
         .map_err(|err| {
-            if err.to_string().contains("Goal not found") {
+            let msg = err.to_string();
+            if msg.contains("Goal not found") {
                 AppError::NotFound("Goal not found".to_string())
+            } else if msg.contains("query_timeout") {
+                AppError::QueryTimeout {
+                    retry_after_seconds: QUERY_TIMEOUT_RETRY_AFTER_SECONDS,
+                }
             } else {
                 AppError::Internal(err)
             }
@@ -169,6 +213,27 @@ mod tests {
         assert!(parse_model(Some("bad")).is_err());
     }
 
+    #[test]
+    fn parse_model_accepts_multi_touch_models() {
+        assert!(matches!(
+            parse_model(Some("time_decay")),
+            Ok(AttributionModel::TimeDecay)
+        ));
+        assert!(matches!(
+            parse_model(Some("position_based")),
+            Ok(AttributionModel::PositionBased)
+        ));
+    }
+
+    #[test]
+    fn lookback_and_half_life_are_bounded() {
+        assert_eq!(parse_lookback_days(None).ok(), Some(30));
+        assert!(parse_lookback_days(Some(0)).is_err());
+        assert!(parse_lookback_days(Some(91)).is_err());
+        assert!(parse_half_life_days(Some(0.0)).is_err());
+        assert_eq!(parse_half_life_days(Some(3.5)).ok(), Some(3.5));
+    }
+
     #[test]
     fn parse_date_range_rejects_invalid_start_date() {
         let result = parse_defaulted_date_range_strict(
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use serde_json::json;
+use tracing::warn;
+
+use sparklytics_core::config::AppMode;
+
+use crate::{
+    backup::{self, BackupSettings},
+    error::AppError,
+    state::AppState,
+};
+
+/// Backups copy the local data directory, which cloud deployments do not
+/// own, so they are only offered to self-hosted installs.
+fn backup_settings(state: &AppState) -> Result<BackupSettings, AppError> {
+    if state.config.mode == AppMode::Cloud {
+        return Err(AppError::NotFound("Not found".to_string()));
+    }
+    BackupSettings::from_env(&state.config.data_dir).map_err(AppError::Internal)
+}
+
+/// `GET /api/backups` — backups in the configured destination, newest first.
+pub async fn list_backups(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let settings = backup_settings(&state)?;
+    let backups = backup::list_backups(&settings.dir).map_err(AppError::Internal)?;
+    Ok(Json(json!({ "data": backups })))
+}
+
+/// `POST /api/backups` — back up the running server now, then rotate old
+/// backups down to `SPARKLYTICS_BACKUP_RETAIN`.
+pub async fn create_backup(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let settings = backup_settings(&state)?;
+    let created = state
+        .create_backup(&settings.dir)
+        .await
+        .map_err(AppError::Internal)?;
+    if let Err(e) = backup::prune_backups(&settings.dir, settings.retain) {
+        warn!(error = %e, "Failed to remove old backups");
+    }
+    Ok((StatusCode::CREATED, Json(json!({ "data": created }))))
+}
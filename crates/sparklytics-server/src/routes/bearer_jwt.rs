@@ -37,7 +37,7 @@ fn jwks_cache() -> &'static RwLock<Option<CachedJwksKeys>> {
     CACHE.get_or_init(|| RwLock::new(None))
 }
 
-fn http_client() -> &'static reqwest::Client {
+pub(crate) fn http_client() -> &'static reqwest::Client {
     static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
     CLIENT.get_or_init(|| {
         reqwest::Client::builder()
@@ -47,7 +47,9 @@ fn http_client() -> &'static reqwest::Client {
     })
 }
 
-async fn fetch_jwks_keys(jwks_url: &str) -> Result<HashMap<String, DecodingKey>, AppError> {
+pub(crate) async fn fetch_jwks_keys(
+    jwks_url: &str,
+) -> Result<HashMap<String, DecodingKey>, AppError> {
     let response = http_client()
         .get(jwks_url)
         .send()
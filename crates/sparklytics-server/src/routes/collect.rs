@@ -14,7 +14,7 @@ use axum::{
 /// Maximum allowed body size for POST /api/collect (100 KB).
 pub const COLLECT_BODY_LIMIT: usize = 102_400;
 /// Maximum allowed size for a single event's `event_data` JSON string (4 KB).
-const EVENT_DATA_MAX_BYTES: usize = 4_096;
+pub(crate) const EVENT_DATA_MAX_BYTES: usize = 4_096;
 use chrono::Utc;
 use serde_json::json;
 
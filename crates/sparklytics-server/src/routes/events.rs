@@ -13,8 +13,8 @@ use sparklytics_core::analytics::AnalyticsFilter;
 use crate::{
     error::AppError,
     routes::query::{
-        normalize_timezone_non_empty, parse_defaulted_date_range_lenient, parse_optional_bool,
-        validate_date_span,
+        normalize_timezone_non_empty, parse_defaulted_date_range_lenient, parse_dimension_filter,
+        parse_filter_properties, parse_optional_bool, validate_date_span,
     },
     state::AppState,
 };
@@ -40,6 +40,7 @@ pub struct EventFilterQuery {
     pub filter_region: Option<String>,
     pub filter_city: Option<String>,
     pub filter_hostname: Option<String>,
+    pub filter_properties: Option<String>,
     pub include_bots: Option<String>,
 }
 
@@ -80,19 +81,29 @@ fn build_filter(
         start_date,
         end_date,
         timezone: normalize_timezone_non_empty(query.timezone.as_deref())?,
-        filter_country: query.filter_country.clone(),
-        filter_page: query.filter_page.clone(),
-        filter_referrer: query.filter_referrer.clone(),
-        filter_browser: query.filter_browser.clone(),
-        filter_os: query.filter_os.clone(),
-        filter_device: query.filter_device.clone(),
-        filter_language: query.filter_language.clone(),
-        filter_utm_source: query.filter_utm_source.clone(),
-        filter_utm_medium: query.filter_utm_medium.clone(),
-        filter_utm_campaign: query.filter_utm_campaign.clone(),
-        filter_region: query.filter_region.clone(),
-        filter_city: query.filter_city.clone(),
-        filter_hostname: query.filter_hostname.clone(),
+        filter_country: parse_dimension_filter("filter_country", query.filter_country.clone())?,
+        filter_page: parse_dimension_filter("filter_page", query.filter_page.clone())?,
+        filter_referrer: parse_dimension_filter("filter_referrer", query.filter_referrer.clone())?,
+        filter_browser: parse_dimension_filter("filter_browser", query.filter_browser.clone())?,
+        filter_os: parse_dimension_filter("filter_os", query.filter_os.clone())?,
+        filter_device: parse_dimension_filter("filter_device", query.filter_device.clone())?,
+        filter_language: parse_dimension_filter("filter_language", query.filter_language.clone())?,
+        filter_utm_source: parse_dimension_filter(
+            "filter_utm_source",
+            query.filter_utm_source.clone(),
+        )?,
+        filter_utm_medium: parse_dimension_filter(
+            "filter_utm_medium",
+            query.filter_utm_medium.clone(),
+        )?,
+        filter_utm_campaign: parse_dimension_filter(
+            "filter_utm_campaign",
+            query.filter_utm_campaign.clone(),
+        )?,
+        filter_region: parse_dimension_filter("filter_region", query.filter_region.clone())?,
+        filter_city: parse_dimension_filter("filter_city", query.filter_city.clone())?,
+        filter_hostname: parse_dimension_filter("filter_hostname", query.filter_hostname.clone())?,
+        filter_properties: parse_filter_properties(query.filter_properties.as_deref())?,
         include_bots,
     })
 }
@@ -0,0 +1,355 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use sparklytics_core::analytics::{
+    CreateExperimentRequest, ExperimentStatus, UpdateExperimentRequest,
+};
+use sparklytics_core::experiments::even_split_weights;
+
+use crate::{error::AppError, state::AppState};
+
+const MIN_VARIANTS: usize = 2;
+const MAX_VARIANTS: usize = 4;
+const MAX_VARIANT_NAME_LEN: usize = 64;
+const MAX_PLANNED_DURATION_DAYS: u32 = 90;
+const MAX_RUNNING_EXPERIMENTS_PER_WEBSITE: i64 = 10;
+
+#[derive(Debug, Deserialize)]
+pub struct ExperimentResultsQuery {
+    pub include_bots: Option<bool>,
+}
+
+fn unprocessable(code: &str, message: &str, field: Option<&str>) -> (StatusCode, Json<Value>) {
+    (
+        StatusCode::UNPROCESSABLE_ENTITY,
+        Json(json!({
+            "error": {
+                "code": code,
+                "message": message,
+                "field": field
+            }
+        })),
+    )
+}
+
+fn validate_name(name: &str) -> Result<(), (StatusCode, Json<Value>)> {
+    if name.trim().is_empty() {
+        return Err(unprocessable(
+            "validation_error",
+            "name must not be empty",
+            Some("name"),
+        ));
+    }
+    if name.len() > 100 {
+        return Err(unprocessable(
+            "validation_error",
+            "name must be 100 characters or fewer",
+            Some("name"),
+        ));
+    }
+    Ok(())
+}
+
+fn normalize_variants(variants: Vec<String>) -> Result<Vec<String>, (StatusCode, Json<Value>)> {
+    if variants.len() < MIN_VARIANTS || variants.len() > MAX_VARIANTS {
+        return Err(unprocessable(
+            "validation_error",
+            "experiments need between 2 and 4 variants",
+            Some("variants"),
+        ));
+    }
+    let variants: Vec<String> = variants.into_iter().map(|v| v.trim().to_string()).collect();
+    if variants
+        .iter()
+        .any(|v| v.is_empty() || v.len() > MAX_VARIANT_NAME_LEN)
+    {
+        return Err(unprocessable(
+            "validation_error",
+            "variant names must be between 1 and 64 characters",
+            Some("variants"),
+        ));
+    }
+    let unique: HashSet<&str> = variants.iter().map(String::as_str).collect();
+    if unique.len() != variants.len() {
+        return Err(unprocessable(
+            "validation_error",
+            "variant names must be unique",
+            Some("variants"),
+        ));
+    }
+    Ok(variants)
+}
+
+fn validate_weights(
+    weights: &[u32],
+    variant_count: usize,
+) -> Result<(), (StatusCode, Json<Value>)> {
+    if weights.len() != variant_count {
+        return Err(unprocessable(
+            "validation_error",
+            "weights must have one entry per variant",
+            Some("weights"),
+        ));
+    }
+    if weights.contains(&0) || weights.iter().sum::<u32>() != 100 {
+        return Err(unprocessable(
+            "validation_error",
+            "weights must be positive and sum to 100",
+            Some("weights"),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_planned_duration(days: u32) -> Result<(), (StatusCode, Json<Value>)> {
+    if days == 0 || days > MAX_PLANNED_DURATION_DAYS {
+        return Err(unprocessable(
+            "validation_error",
+            "planned_duration_days must be between 1 and 90",
+            Some("planned_duration_days"),
+        ));
+    }
+    Ok(())
+}
+
+/// Map the backend's sentinel errors to 422 responses.
+fn backend_error(e: anyhow::Error) -> Result<(StatusCode, Json<Value>), AppError> {
+    let message = e.to_string();
+    if message.contains("duplicate_name") {
+        return Ok(unprocessable(
+            "duplicate_name",
+            "experiment name already exists for this website",
+            Some("name"),
+        ));
+    }
+    if message.contains("goal_not_found") {
+        return Ok(unprocessable(
+            "validation_error",
+            "goal_id does not reference a goal of this website",
+            Some("goal_id"),
+        ));
+    }
+    if message.contains("limit_exceeded") {
+        return Ok(unprocessable(
+            "limit_exceeded",
+            "maximum of 10 running experiments per website reached",
+            Some("status"),
+        ));
+    }
+    if message.contains("invalid_status_transition") {
+        return Ok(unprocessable(
+            "invalid_status_transition",
+            "status change is not allowed from the current status",
+            Some("status"),
+        ));
+    }
+    if message.contains("not_editable") {
+        return Ok(unprocessable(
+            "not_editable",
+            "variants, weights, goal and duration can only change while in draft",
+            None,
+        ));
+    }
+    if message.contains("invalid_weights") {
+        return Ok(unprocessable(
+            "validation_error",
+            "weights must have one entry per variant",
+            Some("weights"),
+        ));
+    }
+    Err(AppError::Internal(e))
+}
+
+pub async fn list_experiments(
+    State(state): State<Arc<AppState>>,
+    Path(website_id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+    let experiments = state
+        .analytics
+        .list_experiments(&website_id, None)
+        .await
+        .map_err(AppError::Internal)?;
+    Ok(Json(json!({ "data": experiments })))
+}
+
+pub async fn get_experiment(
+    State(state): State<Arc<AppState>>,
+    Path((website_id, experiment_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+    let experiment = state
+        .analytics
+        .get_experiment(&website_id, None, &experiment_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("Experiment not found".to_string()))?;
+    Ok(Json(json!({ "data": experiment })))
+}
+
+pub async fn create_experiment(
+    State(state): State<Arc<AppState>>,
+    Path(website_id): Path<String>,
+    Json(mut req): Json<CreateExperimentRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+    if let Err(resp) = validate_name(&req.name) {
+        return Ok(resp);
+    }
+    req.variants = match normalize_variants(req.variants) {
+        Ok(variants) => variants,
+        Err(resp) => return Ok(resp),
+    };
+    let weights = req
+        .weights
+        .take()
+        .unwrap_or_else(|| even_split_weights(req.variants.len()));
+    if let Err(resp) = validate_weights(&weights, req.variants.len()) {
+        return Ok(resp);
+    }
+    req.weights = Some(weights);
+    if let Err(resp) = validate_planned_duration(req.planned_duration_days) {
+        return Ok(resp);
+    }
+
+    match state
+        .analytics
+        .create_experiment(&website_id, None, req)
+        .await
+    {
+        Ok(experiment) => Ok((StatusCode::CREATED, Json(json!({ "data": experiment })))),
+        Err(e) => backend_error(e),
+    }
+}
+
+pub async fn update_experiment(
+    State(state): State<Arc<AppState>>,
+    Path((website_id, experiment_id)): Path<(String, String)>,
+    Json(mut req): Json<UpdateExperimentRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+    let existing = state
+        .analytics
+        .get_experiment(&website_id, None, &experiment_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("Experiment not found".to_string()))?;
+
+    if let Some(ref name) = req.name {
+        if let Err(resp) = validate_name(name) {
+            return Ok(resp);
+        }
+    }
+    if let Some(variants) = req.variants.take() {
+        req.variants = match normalize_variants(variants) {
+            Ok(variants) => Some(variants),
+            Err(resp) => return Ok(resp),
+        };
+    }
+    if let Some(ref weights) = req.weights {
+        let variant_count = req
+            .variants
+            .as_ref()
+            .map_or(existing.variants.len(), Vec::len);
+        if let Err(resp) = validate_weights(weights, variant_count) {
+            return Ok(resp);
+        }
+    }
+    if let Some(days) = req.planned_duration_days {
+        if let Err(resp) = validate_planned_duration(days) {
+            return Ok(resp);
+        }
+    }
+
+    if req.status == Some(ExperimentStatus::Running) && existing.status != ExperimentStatus::Running
+    {
+        let running = state
+            .analytics
+            .count_running_experiments(&website_id, None)
+            .await
+            .map_err(AppError::Internal)?;
+        if running >= MAX_RUNNING_EXPERIMENTS_PER_WEBSITE {
+            return Ok(unprocessable(
+                "limit_exceeded",
+                "maximum of 10 running experiments per website reached",
+                Some("status"),
+            ));
+        }
+    }
+
+    match state
+        .analytics
+        .update_experiment(&website_id, None, &experiment_id, req)
+        .await
+    {
+        Ok(Some(experiment)) => Ok((StatusCode::OK, Json(json!({ "data": experiment })))),
+        Ok(None) => Err(AppError::NotFound("Experiment not found".to_string())),
+        Err(e) => backend_error(e),
+    }
+}
+
+pub async fn delete_experiment(
+    State(state): State<Arc<AppState>>,
+    Path((website_id, experiment_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+
+    let deleted = state
+        .analytics
+        .delete_experiment(&website_id, None, &experiment_id)
+        .await
+        .map_err(AppError::Internal)?;
+    if !deleted {
+        return Err(AppError::NotFound("Experiment not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn get_experiment_results(
+    State(state): State<Arc<AppState>>,
+    Path((website_id, experiment_id)): Path<(String, String)>,
+    Query(query): Query<ExperimentResultsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+    let include_bots = query
+        .include_bots
+        .unwrap_or(state.default_include_bots(&website_id).await);
+
+    let results = state
+        .analytics
+        .get_experiment_results(&website_id, None, &experiment_id, include_bots)
+        .await
+        .map_err(|e| {
+            if e.to_string().contains("Experiment not found") {
+                AppError::NotFound("Experiment not found".to_string())
+            } else if e.to_string().contains("Goal not found") {
+                AppError::NotFound("Goal not found".to_string())
+            } else {
+                AppError::Internal(e)
+            }
+        })?;
+
+    Ok(Json(json!({ "data": results })))
+}
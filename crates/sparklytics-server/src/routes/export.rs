@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use axum::{
-    body::Bytes,
+    body::{Body, Bytes},
     extract::{Path, Query, State},
     http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Response},
@@ -10,16 +10,21 @@ use axum::{
 use chrono::NaiveDate;
 use serde::Deserialize;
 use serde_json::json;
+use tokio_util::io::ReaderStream;
 
+use sparklytics_core::analytics::ExportFormat;
 use sparklytics_core::config::{AppMode, AuthMode};
 
 use super::bearer_jwt::verify_and_decode_bearer_claims;
 use crate::{error::AppError, state::AppState};
 
-/// Maximum date range allowed for export (90 days).
+/// Maximum date range allowed for CSV export (90 days).
 const MAX_EXPORT_DAYS: i64 = 90;
 
-/// Maximum number of rows allowed in a single export (500 000).
+/// Maximum date range allowed for streamed NDJSON / Parquet export (366 days).
+const MAX_STREAMED_EXPORT_DAYS: i64 = 366;
+
+/// Maximum number of rows allowed in a single CSV export (500 000).
 const MAX_EXPORT_ROWS: usize = 500_000;
 
 #[derive(Debug, Deserialize)]
@@ -29,11 +34,14 @@ pub struct ExportQuery {
     pub format: Option<String>,
 }
 
-/// `GET /api/websites/:id/export` — download events as CSV.
+/// `GET /api/websites/:id/export` — download events as CSV, NDJSON or Parquet.
 ///
-/// Date range validation: > 90 days → 400.
-/// Row cap: > 500 000 rows → 400.
-/// Response: `Content-Type: text/csv` with `Content-Disposition: attachment`.
+/// `format=csv` (default): > 90 days → 400, > 500 000 rows → 400; the body is
+/// buffered and cached.
+/// `format=ndjson` / `format=parquet`: > 366 days → 400, no row cap; DuckDB
+/// writes the file and it is streamed back as a chunked body.
+/// Response: `Content-Disposition: attachment` with a format-specific
+/// `Content-Type`.
 #[tracing::instrument(skip(state))]
 pub async fn export_events(
     State(state): State<Arc<AppState>>,
@@ -62,24 +70,33 @@ pub async fn export_events(
         ));
     }
 
+    let format = ExportFormat::parse(q.format.as_deref())
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+    let max_days = match format {
+        ExportFormat::Csv => MAX_EXPORT_DAYS,
+        ExportFormat::Ndjson | ExportFormat::Parquet => MAX_STREAMED_EXPORT_DAYS,
+    };
     let range_days = (end - start).num_days() + 1;
-    if range_days > MAX_EXPORT_DAYS {
+    if range_days > max_days {
         return Err(AppError::BadRequest(format!(
-            "date range too large: {range_days} days (max {MAX_EXPORT_DAYS})"
+            "date range too large: {range_days} days (max {max_days})"
         )));
     }
 
-    // Validate format (only csv supported right now).
-    if let Some(ref fmt) = q.format {
-        if fmt != "csv" {
-            return Err(AppError::BadRequest(format!(
-                "unsupported format: {fmt}; only 'csv' is supported"
-            )));
-        }
+    let filename = format!(
+        "events-{}-{}-{}.{}",
+        website_id,
+        q.start_date,
+        q.end_date,
+        format.file_extension()
+    );
+
+    if format != ExportFormat::Csv {
+        return stream_file_export(&state, &website_id, start, end, format, &filename).await;
     }
 
     let cache_key = state.export_cache_key(&website_id, &q.start_date, &q.end_date);
-    let filename = format!("events-{}-{}-{}.csv", website_id, q.start_date, q.end_date);
 
     if let Some(csv_bytes) = state.get_cached_export_csv(&cache_key).await {
         return build_csv_response(&filename, csv_bytes);
@@ -105,7 +122,7 @@ pub async fn export_events(
     // Guard: row count > 500K → 400
     if rows.len() > MAX_EXPORT_ROWS {
         return Err(AppError::BadRequest(format!(
-            "result set too large: > {MAX_EXPORT_ROWS} rows; narrow the date range"
+            "result set too large: > {MAX_EXPORT_ROWS} rows; narrow the date range or use format=ndjson or format=parquet"
         )));
     }
 
@@ -119,6 +136,67 @@ pub async fn export_events(
     build_csv_response(&filename, csv_bytes)
 }
 
+/// Have the backend write the export to a scratch file under
+/// `{data_dir}/exports`, then stream that file back to the client.
+///
+/// The file is unlinked as soon as it is opened: the open handle keeps the
+/// contents readable for the lifetime of the response stream, and nothing is
+/// left behind if the client disconnects mid-download.
+async fn stream_file_export(
+    state: &AppState,
+    website_id: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+    format: ExportFormat,
+    filename: &str,
+) -> Result<Response, AppError> {
+    let export_dir = std::path::Path::new(&state.config.data_dir).join("exports");
+    tokio::fs::create_dir_all(&export_dir)
+        .await
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("create export dir failed: {e}")))?;
+    let path = export_dir.join(format!(
+        "export-{}.{}",
+        uuid::Uuid::new_v4(),
+        format.file_extension()
+    ));
+
+    let written = {
+        let _permit = state
+            .export_semaphore
+            .acquire()
+            .await
+            .map_err(|_| AppError::Internal(anyhow::anyhow!("export semaphore closed")))?;
+        state
+            .analytics
+            .export_events_to_file(website_id, None, start, end, format, &path)
+            .await
+    };
+    let written = match written {
+        Ok(rows) => rows,
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&path).await;
+            return Err(AppError::Internal(e));
+        }
+    };
+
+    let file = tokio::fs::File::open(&path).await;
+    let _ = tokio::fs::remove_file(&path).await;
+    let file =
+        file.map_err(|e| AppError::Internal(anyhow::anyhow!("open export file failed: {e}")))?;
+
+    tracing::info!(website_id, rows = written, format = ?format, "Streaming export");
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, format.content_type())
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{filename}\""),
+        )
+        .body(Body::from_stream(ReaderStream::new(file)))
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("response build failed: {e}")))
+}
+
 /// Sanitize a CSV field value against formula injection.
 ///
 /// Spreadsheet apps (Excel, Google Sheets, LibreOffice) interpret values that
@@ -199,12 +277,12 @@ fn build_csv(rows: &[sparklytics_core::analytics::ExportRow]) -> anyhow::Result<
 fn build_csv_response(filename: &str, csv_bytes: Bytes) -> Result<Response, AppError> {
     Response::builder()
         .status(StatusCode::OK)
-        .header(header::CONTENT_TYPE, "text/csv; charset=utf-8")
+        .header(header::CONTENT_TYPE, ExportFormat::Csv.content_type())
         .header(
             header::CONTENT_DISPOSITION,
             format!("attachment; filename=\"{filename}\""),
         )
-        .body(axum::body::Body::from(csv_bytes))
+        .body(Body::from(csv_bytes))
         .map_err(|e| AppError::Internal(anyhow::anyhow!("response build failed: {e}")))
 }
 
@@ -16,14 +16,15 @@ use serde::Deserialize;
 use serde_json::{json, Value};
 
 use sparklytics_core::analytics::{
-    AnalyticsFilter, CreateFunnelRequest, CreateFunnelStepRequest, UpdateFunnelRequest,
+    AnalyticsFilter, CreateFunnelRequest, CreateFunnelStepRequest, PropertyFilter, StepType,
+    UpdateFunnelRequest, MAX_FUNNEL_CONVERSION_WINDOW_MINUTES, VALID_METRIC_TYPES,
 };
 
 use crate::{
-    error::AppError,
+    error::{AppError, QUERY_TIMEOUT_RETRY_AFTER_SECONDS},
     routes::query::{
-        normalize_optional_filter, normalize_timezone_non_empty, parse_defaulted_date_range_strict,
-        validate_date_span,
+        normalize_timezone_non_empty, parse_defaulted_date_range_strict, parse_dimension_filter,
+        parse_filter_properties, validate_date_span,
     },
     state::AppState,
 };
@@ -181,6 +182,33 @@ fn validate_steps(steps: &[CreateFunnelStepRequest]) -> Result<(), (StatusCode,
                 ));
             }
         }
+        if !step.property_filters.is_empty() {
+            if step.step_type != StepType::Event {
+                return Err(unprocessable(
+                    "validation_error",
+                    "property_filters are only supported on event steps",
+                    Some("property_filters"),
+                ));
+            }
+            if let Err(e) = PropertyFilter::validate_list(&step.property_filters) {
+                return Err(unprocessable(
+                    "validation_error",
+                    &e.to_string(),
+                    Some("property_filters"),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_conversion_window(minutes: u32) -> Result<(), (StatusCode, Json<Value>)> {
+    if minutes == 0 || minutes > MAX_FUNNEL_CONVERSION_WINDOW_MINUTES {
+        return Err(unprocessable(
+            "validation_error",
+            "conversion_window_minutes must be between 1 and 129600 (90 days)",
+            Some("conversion_window_minutes"),
+        ));
     }
     Ok(())
 }
@@ -203,7 +231,10 @@ pub struct FunnelResultsQuery {
     pub filter_region: Option<String>,
     pub filter_city: Option<String>,
     pub filter_hostname: Option<String>,
+    pub filter_properties: Option<String>,
     pub include_bots: Option<bool>,
+    /// Any metrics dimension (`utm_source`, `country`, ...) to segment by.
+    pub breakdown: Option<String>,
 }
 
 pub async fn list_funnels(
@@ -281,6 +312,11 @@ pub async fn create_funnel(
     if let Err(resp) = validate_steps(&req.steps) {
         return Ok(resp);
     }
+    if let Some(minutes) = req.conversion_window_minutes {
+        if let Err(resp) = validate_conversion_window(minutes) {
+            return Ok(resp);
+        }
+    }
 
     let data = match state.analytics.create_funnel(&website_id, None, req).await {
         Ok(data) => data,
@@ -332,7 +368,12 @@ pub async fn update_funnel(
     )
     .await?;
 
-    if req.name.is_none() && req.steps.is_none() {
+    if req.name.is_none()
+        && req.steps.is_none()
+        && req.conversion_window_minutes.is_none()
+        && req.scope.is_none()
+        && req.ordering.is_none()
+    {
         return Err(AppError::BadRequest(
             "request must include at least one updatable field".to_string(),
         ));
@@ -347,6 +388,11 @@ pub async fn update_funnel(
             return Ok(resp);
         }
     }
+    if let Some(Some(minutes)) = req.conversion_window_minutes {
+        if let Err(resp) = validate_conversion_window(minutes) {
+            return Ok(resp);
+        }
+    }
 
     let data = match state
         .analytics
@@ -433,22 +479,32 @@ pub async fn get_funnel_results(
         DEFAULT_RESULTS_RANGE_DAYS - 1,
     )?;
     validate_date_span(start_date, end_date, MAX_RESULTS_RANGE_DAYS, "date range")?;
-    let filter_country = normalize_optional_filter("filter_country", query.filter_country, 64)?;
-    let filter_page = normalize_optional_filter("filter_page", query.filter_page, 512)?;
-    let filter_referrer = normalize_optional_filter("filter_referrer", query.filter_referrer, 512)?;
-    let filter_browser = normalize_optional_filter("filter_browser", query.filter_browser, 128)?;
-    let filter_os = normalize_optional_filter("filter_os", query.filter_os, 128)?;
-    let filter_device = normalize_optional_filter("filter_device", query.filter_device, 64)?;
-    let filter_language = normalize_optional_filter("filter_language", query.filter_language, 64)?;
-    let filter_utm_source =
-        normalize_optional_filter("filter_utm_source", query.filter_utm_source, 256)?;
-    let filter_utm_medium =
-        normalize_optional_filter("filter_utm_medium", query.filter_utm_medium, 256)?;
+    let filter_country = parse_dimension_filter("filter_country", query.filter_country)?;
+    let filter_page = parse_dimension_filter("filter_page", query.filter_page)?;
+    let filter_referrer = parse_dimension_filter("filter_referrer", query.filter_referrer)?;
+    let filter_browser = parse_dimension_filter("filter_browser", query.filter_browser)?;
+    let filter_os = parse_dimension_filter("filter_os", query.filter_os)?;
+    let filter_device = parse_dimension_filter("filter_device", query.filter_device)?;
+    let filter_language = parse_dimension_filter("filter_language", query.filter_language)?;
+    let filter_utm_source = parse_dimension_filter("filter_utm_source", query.filter_utm_source)?;
+    let filter_utm_medium = parse_dimension_filter("filter_utm_medium", query.filter_utm_medium)?;
     let filter_utm_campaign =
-        normalize_optional_filter("filter_utm_campaign", query.filter_utm_campaign, 256)?;
-    let filter_region = normalize_optional_filter("filter_region", query.filter_region, 128)?;
-    let filter_city = normalize_optional_filter("filter_city", query.filter_city, 128)?;
-    let filter_hostname = normalize_optional_filter("filter_hostname", query.filter_hostname, 255)?;
+        parse_dimension_filter("filter_utm_campaign", query.filter_utm_campaign)?;
+    let filter_region = parse_dimension_filter("filter_region", query.filter_region)?;
+    let filter_city = parse_dimension_filter("filter_city", query.filter_city)?;
+    let filter_hostname = parse_dimension_filter("filter_hostname", query.filter_hostname)?;
+    let breakdown = query
+        .breakdown
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    if let Some(dimension) = breakdown {
+        if !VALID_METRIC_TYPES.contains(&dimension) {
+            return Err(AppError::BadRequest(format!(
+                "invalid breakdown: {dimension}"
+            )));
+        }
+    }
     let include_bots = query
         .include_bots
         .unwrap_or(state.default_include_bots(&website_id).await);
@@ -470,6 +526,7 @@ pub async fn get_funnel_results(
         filter_region,
         filter_city,
         filter_hostname,
+        filter_properties: parse_filter_properties(query.filter_properties.as_deref())?,
         include_bots,
     };
 
@@ -483,12 +540,16 @@ pub async fn get_funnel_results(
 
     let data = state
         .analytics
-        .get_funnel_results(&website_id, None, &funnel_id, &filter)
+        .get_funnel_results(&website_id, None, &funnel_id, &filter, breakdown)
         .await
         .map_err(|e| {
             let msg = e.to_string();
             if msg.contains("Funnel not found") {
                 AppError::NotFound("Funnel not found".to_string())
+            } else if msg.contains("query_timeout") {
+                AppError::QueryTimeout {
+                    retry_after_seconds: QUERY_TIMEOUT_RETRY_AFTER_SECONDS,
+                }
             } else if msg.contains("invalid_timezone")
                 || msg.contains("invalid_timezone_transition")
                 || msg.contains("invalid_date_boundary")
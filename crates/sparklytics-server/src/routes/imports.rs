@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use sparklytics_core::config::AppMode;
+
+use crate::{
+    error::AppError,
+    import::{parse_export, store_import, ImportOptions, ImportSource},
+    state::AppState,
+};
+
+/// Largest export accepted over HTTP. Bigger exports go through the
+/// `sparklytics import` CLI, which reads the file from disk.
+pub const IMPORT_BODY_LIMIT: usize = 100 * 1024 * 1024;
+
+#[derive(Debug, Deserialize)]
+pub struct ImportQuery {
+    /// `umami`, `plausible` or `ga4`.
+    pub source: String,
+    pub file_name: Option<String>,
+    /// Umami website UUID when the dump holds several websites.
+    pub source_website_id: Option<String>,
+}
+
+/// Imports write straight into the events table, bypassing cloud billing
+/// admission, so they are only offered to self-hosted installs.
+fn require_self_hosted(state: &AppState) -> Result<(), AppError> {
+    if state.config.mode == AppMode::Cloud {
+        return Err(AppError::NotFound("Not found".to_string()));
+    }
+    Ok(())
+}
+
+/// `POST /api/websites/:id/imports?source=umami|plausible|ga4` — import a
+/// historical export sent as the raw request body.
+pub async fn create_import(
+    State(state): State<Arc<AppState>>,
+    Path(website_id): Path<String>,
+    Query(query): Query<ImportQuery>,
+    body: Bytes,
+) -> Result<impl IntoResponse, AppError> {
+    require_self_hosted(&state)?;
+    let source = ImportSource::parse(&query.source).ok_or_else(|| {
+        AppError::BadRequest("source must be one of: umami, plausible, ga4".to_string())
+    })?;
+    let website = state
+        .get_website_metadata_cached(&website_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("Website not found".to_string()))?;
+    if body.is_empty() {
+        return Err(AppError::BadRequest("request body is empty".to_string()));
+    }
+
+    let options = ImportOptions {
+        source_website_id: query.source_website_id,
+    };
+    let parse_website = website.clone();
+    let parsed =
+        tokio::task::spawn_blocking(move || parse_export(source, &parse_website, &body, &options))
+            .await
+            .map_err(|e| AppError::Internal(e.into()))?
+            .map_err(|e| AppError::BadRequest(format!("{e:#}")))?;
+
+    let record = store_import(
+        &state.db,
+        &website,
+        source,
+        query.file_name.as_deref(),
+        &parsed,
+    )
+    .await
+    .map_err(AppError::Internal)?;
+    Ok((StatusCode::CREATED, Json(json!({ "data": record }))))
+}
+
+/// `GET /api/websites/:id/imports` — past imports, newest first.
+pub async fn list_imports(
+    State(state): State<Arc<AppState>>,
+    Path(website_id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    require_self_hosted(&state)?;
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+    let imports = state
+        .db
+        .list_imports(&website_id)
+        .await
+        .map_err(AppError::Internal)?;
+    Ok(Json(json!({ "data": imports })))
+}
+
+/// `POST /api/websites/:id/imports/:import_id/rollback` — delete everything
+/// an import wrote. The import record is kept with status `rolled_back`.
+pub async fn rollback_import(
+    State(state): State<Arc<AppState>>,
+    Path((website_id, import_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, AppError> {
+    require_self_hosted(&state)?;
+    let record = state
+        .db
+        .rollback_import(&website_id, &import_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("Import not found".to_string()))?;
+    Ok(Json(json!({ "data": record })))
+}
@@ -0,0 +1,313 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+
+use sparklytics_core::{
+    analytics::BotClassification,
+    config::AppMode,
+    event::{Event, ServerIngestOrBatch, ServerIngestPayload},
+    visitor::{compute_visitor_id, extract_referrer_domain},
+};
+
+use crate::{
+    bot_detection::classify_event,
+    error::AppError,
+    routes::collect::{extract_utm_from_url, lookup_geo, parse_user_agent, EVENT_DATA_MAX_BYTES},
+    state::AppState,
+};
+
+/// Maximum events per server ingest request, matching `/api/collect`.
+const MAX_BATCH_EVENTS: usize = 50;
+/// Oldest accepted `timestamp`, relative to the time of receipt.
+const MAX_BACKDATE_DAYS: i64 = 7;
+/// Tolerated clock skew for `timestamp` values ahead of the server clock.
+const MAX_FUTURE_SKEW_SECONDS: i64 = 300;
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 128;
+
+fn validate_timestamp(
+    timestamp: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, AppError> {
+    let Some(timestamp) = timestamp else {
+        return Ok(now);
+    };
+    if timestamp > now + Duration::seconds(MAX_FUTURE_SKEW_SECONDS) {
+        return Err(AppError::BadRequest(
+            "timestamp must not be in the future".to_string(),
+        ));
+    }
+    if timestamp < now - Duration::days(MAX_BACKDATE_DAYS) {
+        return Err(AppError::BadRequest(format!(
+            "timestamp must be within the last {MAX_BACKDATE_DAYS} days"
+        )));
+    }
+    Ok(timestamp)
+}
+
+fn validate_payload(payload: &ServerIngestPayload) -> Result<(), AppError> {
+    if let Some(data) = &payload.event_data {
+        if data.to_string().len() > EVENT_DATA_MAX_BYTES {
+            return Err(AppError::PayloadTooLarge);
+        }
+    }
+    if let Some(ip) = payload.ip.as_deref() {
+        if ip.parse::<IpAddr>().is_err() {
+            return Err(AppError::BadRequest("ip must be an IP address".to_string()));
+        }
+    }
+    if let Some(key) = payload.idempotency_key.as_deref() {
+        if key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+            return Err(AppError::BadRequest(format!(
+                "idempotency_key must be 1-{MAX_IDEMPOTENCY_KEY_LEN} characters"
+            )));
+        }
+    }
+    let has_visitor_id = payload
+        .visitor_id
+        .as_deref()
+        .is_some_and(|id| !id.is_empty() && id.len() <= 64);
+    if !has_visitor_id && payload.ip.is_none() {
+        return Err(AppError::BadRequest(
+            "visitor_id (max 64 chars) or ip is required".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// `POST /api/websites/:id/events/ingest` — ingest events from a trusted
+/// backend on behalf of end users.
+///
+/// ## Auth
+/// API key or session cookie, like the other website routes.
+///
+/// ## Rules
+/// - Up to 50 events per request, as a single object or an array.
+/// - `timestamp` may be backdated by up to 7 days; omitted means now.
+/// - `ip` and `user_agent` describe the end user and drive GeoIP, device
+///   parsing, bot classification and the derived `visitor_id`.
+/// - Events whose `idempotency_key` was already accepted for this website in
+///   the last 7 days are skipped and reported as `duplicates`.
+///
+/// ## Response
+/// `202 Accepted` with `{ "data": { "accepted", "duplicates", "dropped" } }`.
+pub async fn ingest_events(
+    State(state): State<Arc<AppState>>,
+    Path(website_id): Path<String>,
+    Json(payload): Json<ServerIngestOrBatch>,
+) -> Result<impl IntoResponse, AppError> {
+    let payloads = match payload {
+        ServerIngestOrBatch::Single(p) => vec![*p],
+        ServerIngestOrBatch::Batch(v) => v,
+    };
+    if payloads.len() > MAX_BATCH_EVENTS {
+        return Err(AppError::BatchTooLarge(payloads.len()));
+    }
+    if payloads.is_empty() {
+        return Err(AppError::BadRequest("empty batch".to_string()));
+    }
+
+    let website = state
+        .get_website_metadata_cached(&website_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("Website not found".to_string()))?;
+    if state.config.mode == AppMode::Cloud && website.tenant_id.is_none() {
+        return Err(AppError::OrganizationContextRequired);
+    }
+
+    let now = Utc::now();
+    let mut timestamps = Vec::with_capacity(payloads.len());
+    for payload in &payloads {
+        validate_payload(payload)?;
+        timestamps.push(validate_timestamp(payload.timestamp, now)?);
+    }
+
+    let bot_policy = state
+        .get_bot_policy_cached(&website_id)
+        .await
+        .map_err(AppError::Internal)?;
+
+    let mut events: Vec<(Event, Option<String>)> = Vec::with_capacity(payloads.len());
+    for (idx, (p, created_at)) in payloads.into_iter().zip(timestamps).enumerate() {
+        let ip = p.ip.unwrap_or_default();
+        let user_agent = p.user_agent.unwrap_or_default();
+        let visitor_id = p
+            .visitor_id
+            .filter(|id| !id.is_empty() && id.len() <= 64)
+            .unwrap_or_else(|| compute_visitor_id(&ip, &user_agent));
+        let geo = if ip.is_empty() {
+            None
+        } else {
+            lookup_geo(&state.config.geoip_path, &ip)
+        };
+        let ua_info = parse_user_agent(&user_agent);
+
+        // Without a User-Agent there is nothing to classify; the caller is a
+        // trusted backend, so the event counts as human.
+        let bot_classification = if user_agent.is_empty() {
+            BotClassification::default()
+        } else {
+            let override_decision = state
+                .classify_override_for_request_cached(&website_id, &ip, &user_agent)
+                .await
+                .map_err(AppError::Internal)?;
+            classify_event(
+                &website_id,
+                &visitor_id,
+                &p.url,
+                &user_agent,
+                true,
+                true,
+                &bot_policy,
+                override_decision,
+            )
+        };
+        let url_utm = extract_utm_from_url(&p.url);
+
+        let event = Event {
+            id: uuid::Uuid::new_v4().to_string(),
+            website_id: website_id.clone(),
+            tenant_id: website.tenant_id.clone(),
+            session_id: AppState::pending_session_marker().to_string(),
+            visitor_id,
+            event_type: p.event_type,
+            url: p.url,
+            referrer_url: p.referrer.clone(),
+            referrer_domain: p.referrer.as_deref().and_then(extract_referrer_domain),
+            event_name: p.event_name,
+            event_data: p.event_data.map(|v| v.to_string()),
+            country: geo.as_ref().and_then(|g| g.country.clone()),
+            region: geo.as_ref().and_then(|g| g.region.clone()),
+            city: geo.as_ref().and_then(|g| g.city.clone()),
+            browser: ua_info.as_ref().map(|u| u.browser.clone()),
+            browser_version: ua_info.as_ref().and_then(|u| u.browser_version.clone()),
+            os: ua_info.as_ref().map(|u| u.os.clone()),
+            os_version: ua_info.as_ref().and_then(|u| u.os_version.clone()),
+            device_type: ua_info.as_ref().map(|u| u.device_type.clone()),
+            screen: p.screen,
+            language: p.language,
+            utm_source: p.utm_source.or_else(|| url_utm.get("utm_source").cloned()),
+            utm_medium: p.utm_medium.or_else(|| url_utm.get("utm_medium").cloned()),
+            utm_campaign: p
+                .utm_campaign
+                .or_else(|| url_utm.get("utm_campaign").cloned()),
+            utm_term: p.utm_term.or_else(|| url_utm.get("utm_term").cloned()),
+            utm_content: p
+                .utm_content
+                .or_else(|| url_utm.get("utm_content").cloned()),
+            link_id: None,
+            pixel_id: None,
+            source_ip: (!ip.is_empty()).then_some(ip),
+            user_agent: (!user_agent.is_empty()).then_some(user_agent),
+            is_bot: bot_classification.is_bot,
+            bot_score: bot_classification.bot_score,
+            bot_reason: bot_classification.bot_reason,
+            // Keep batch order stable for events sharing a timestamp.
+            created_at: created_at + Duration::microseconds(idx as i64),
+        };
+        events.push((event, p.idempotency_key));
+    }
+
+    // Claim idempotency keys up front so concurrent retries cannot both pass.
+    let keys: Vec<String> = events.iter().filter_map(|(_, key)| key.clone()).collect();
+    let mut claimed = state
+        .db
+        .claim_idempotency_keys(&website_id, &keys)
+        .await
+        .map_err(AppError::Internal)?
+        .into_iter();
+    let mut duplicates = 0usize;
+    let mut fresh: Vec<(Event, Option<String>)> = Vec::with_capacity(events.len());
+    for (event, key) in events {
+        if key.is_some() && !claimed.next().unwrap_or(false) {
+            duplicates += 1;
+        } else {
+            fresh.push((event, key));
+        }
+    }
+
+    let mut dropped = 0usize;
+    if let Some(tenant_id) = website.tenant_id.as_deref() {
+        if state.config.mode == AppMode::Cloud {
+            let admission = state
+                .billing_gate
+                .admit_events(tenant_id, fresh.len())
+                .await;
+            if admission.allowed_events < fresh.len() {
+                dropped = fresh.len() - admission.allowed_events;
+                let rejected = fresh.split_off(admission.allowed_events);
+                release_keys(
+                    &state,
+                    &website_id,
+                    rejected.into_iter().filter_map(|(_, key)| key).collect(),
+                )
+                .await;
+            }
+        }
+    }
+
+    let website_queue_caps = (state.config.mode == AppMode::SelfHosted).then(|| {
+        let cap = website
+            .ingest_queue_max_events
+            .and_then(|value| usize::try_from(value).ok())
+            .filter(|value| *value > 0)
+            .unwrap_or(state.website_ingest_queue_max_events_default());
+        std::collections::HashMap::from([(website_id.clone(), cap)])
+    });
+    let (to_enqueue, keys): (Vec<Event>, Vec<Option<String>>) = fresh.into_iter().unzip();
+    let outcome = if to_enqueue.is_empty() {
+        None
+    } else {
+        Some(
+            state
+                .enqueue_ingest_events_with_limits(to_enqueue, website_queue_caps.as_ref())
+                .await,
+        )
+    };
+    let accepted = match outcome {
+        None => 0,
+        Some(Ok(outcome)) => outcome.accepted_events,
+        Some(Err(err)) => {
+            release_keys(&state, &website_id, keys.into_iter().flatten().collect()).await;
+            return Err(err);
+        }
+    };
+    // Queue limits admit a prefix of a single-website batch, so the keys of
+    // the rejected tail are released for the caller's retry.
+    if accepted < keys.len() {
+        dropped += keys.len() - accepted;
+        let rejected = keys.into_iter().skip(accepted).flatten().collect();
+        release_keys(&state, &website_id, rejected).await;
+    }
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(json!({
+            "data": {
+                "accepted": accepted,
+                "duplicates": duplicates,
+                "dropped": dropped,
+            }
+        })),
+    ))
+}
+
+/// Release idempotency keys of events that were not enqueued so a retry can
+/// reuse them.
+async fn release_keys(state: &AppState, website_id: &str, keys: Vec<String>) {
+    if let Err(err) = state.db.release_idempotency_keys(website_id, &keys).await {
+        tracing::warn!(
+            website_id,
+            error = %err,
+            "Failed to release idempotency keys of rejected events"
+        );
+    }
+}
@@ -20,7 +20,9 @@ pub struct UpdateIngestLimitsRequest {
     pub queue_max_events: Option<Option<i64>>,
 }
 
-fn deserialize_tri_state<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+pub(crate) fn deserialize_tri_state<'de, D, T>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
 where
     D: Deserializer<'de>,
     T: Deserialize<'de>,
@@ -121,6 +123,10 @@ pub async fn update_ingest_limits(
                 timezone: None,
                 ingest_peak_eps: peak_events_per_sec,
                 ingest_queue_max_events: queue_max_events,
+                retention_days: None,
+                session_timeout_minutes: None,
+                session_split_at_midnight: None,
+                session_split_on_campaign: None,
             },
         )
         .await
@@ -11,9 +11,10 @@ use serde_json::json;
 use sparklytics_core::analytics::{AnalyticsFilter, AnchorType, JourneyDirection, JourneyQuery};
 
 use crate::{
-    error::AppError,
+    error::{AppError, QUERY_TIMEOUT_RETRY_AFTER_SECONDS},
     routes::query::{
-        normalize_optional_filter, normalize_timezone_non_empty, parse_required_date_range,
+        normalize_timezone_non_empty, parse_dimension_filter, parse_filter_properties,
+        parse_required_date_range,
     },
     state::AppState,
 };
@@ -43,6 +44,7 @@ pub struct JourneyQueryParams {
     pub filter_region: Option<String>,
     pub filter_city: Option<String>,
     pub filter_hostname: Option<String>,
+    pub filter_properties: Option<String>,
     pub include_bots: Option<bool>,
 }
 
@@ -110,31 +112,23 @@ pub async fn get_journey(
         start_date,
         end_date,
         timezone: normalize_timezone_non_empty(query.timezone.as_deref())?,
-        filter_country: normalize_optional_filter("filter_country", query.filter_country, 64)?,
-        filter_page: normalize_optional_filter("filter_page", query.filter_page, 512)?,
-        filter_referrer: normalize_optional_filter("filter_referrer", query.filter_referrer, 512)?,
-        filter_browser: normalize_optional_filter("filter_browser", query.filter_browser, 128)?,
-        filter_os: normalize_optional_filter("filter_os", query.filter_os, 128)?,
-        filter_device: normalize_optional_filter("filter_device", query.filter_device, 64)?,
-        filter_language: normalize_optional_filter("filter_language", query.filter_language, 64)?,
-        filter_utm_source: normalize_optional_filter(
-            "filter_utm_source",
-            query.filter_utm_source,
-            256,
-        )?,
-        filter_utm_medium: normalize_optional_filter(
-            "filter_utm_medium",
-            query.filter_utm_medium,
-            256,
-        )?,
-        filter_utm_campaign: normalize_optional_filter(
+        filter_country: parse_dimension_filter("filter_country", query.filter_country)?,
+        filter_page: parse_dimension_filter("filter_page", query.filter_page)?,
+        filter_referrer: parse_dimension_filter("filter_referrer", query.filter_referrer)?,
+        filter_browser: parse_dimension_filter("filter_browser", query.filter_browser)?,
+        filter_os: parse_dimension_filter("filter_os", query.filter_os)?,
+        filter_device: parse_dimension_filter("filter_device", query.filter_device)?,
+        filter_language: parse_dimension_filter("filter_language", query.filter_language)?,
+        filter_utm_source: parse_dimension_filter("filter_utm_source", query.filter_utm_source)?,
+        filter_utm_medium: parse_dimension_filter("filter_utm_medium", query.filter_utm_medium)?,
+        filter_utm_campaign: parse_dimension_filter(
             "filter_utm_campaign",
             query.filter_utm_campaign,
-            256,
         )?,
-        filter_region: normalize_optional_filter("filter_region", query.filter_region, 128)?,
-        filter_city: normalize_optional_filter("filter_city", query.filter_city, 128)?,
-        filter_hostname: normalize_optional_filter("filter_hostname", query.filter_hostname, 255)?,
+        filter_region: parse_dimension_filter("filter_region", query.filter_region)?,
+        filter_city: parse_dimension_filter("filter_city", query.filter_city)?,
+        filter_hostname: parse_dimension_filter("filter_hostname", query.filter_hostname)?,
+        filter_properties: parse_filter_properties(query.filter_properties.as_deref())?,
         include_bots,
     };
 
@@ -158,6 +152,10 @@ pub async fn get_journey(
             let msg = e.to_string();
             if msg.contains("invalid_anchor_value") {
                 AppError::BadRequest("anchor_value is required".to_string())
+            } else if msg.contains("query_timeout") {
+                AppError::QueryTimeout {
+                    retry_after_seconds: QUERY_TIMEOUT_RETRY_AFTER_SECONDS,
+                }
             } else if msg.contains("invalid_timezone")
                 || msg.contains("invalid_timezone_transition")
                 || msg.contains("invalid_date_boundary")
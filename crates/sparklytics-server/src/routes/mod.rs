@@ -1,14 +1,18 @@
 pub mod admin_limits;
 pub mod attribution;
+pub mod backups;
 pub mod bearer_jwt;
 pub mod bot;
 pub mod collect;
 pub mod compare;
 pub mod events;
+pub mod experiments;
 pub mod export;
 pub mod funnels;
 pub mod goals;
 pub mod health;
+pub mod imports;
+pub mod ingest;
 pub mod ingest_limits;
 pub mod journey;
 pub mod links;
@@ -8,18 +8,35 @@ use axum::{
 };
 use chrono::Utc;
 use rand::RngCore;
-use serde::Deserialize;
-use serde_json::json;
-use sparklytics_core::analytics::{
-    CreateAlertRuleRequest, CreateReportSubscriptionRequest, NotificationChannel,
-    NotificationSourceType, UpdateAlertRuleRequest, UpdateReportSubscriptionRequest,
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sparklytics_core::{
+    alerts::{is_seasonal, MAX_COOLDOWN_MINUTES, MIN_SEASONAL_LOOKBACK_DAYS},
+    analytics::{
+        AlertBaseline, AlertFilters, AlertMetric, CreateAlertRuleRequest,
+        CreateReportSubscriptionRequest, NotificationChannel, NotificationDeliveryStatus,
+        NotificationSourceType, UpdateAlertRuleRequest, UpdateReportSubscriptionRequest,
+    },
 };
 
 use crate::{
-    error::AppError, routes::reports::execute_report_config,
-    scheduler::delivery::deliver_and_record, state::AppState,
+    error::AppError,
+    routes::reports::{execute_report_config, report_period, report_trend_with_backend},
+    scheduler::{
+        delivery::{deliver_once_and_record, resend, Destination},
+        website_name,
+    },
+    state::AppState,
 };
 
+/// `data` with its webhook signing secret, which list and get responses
+/// never include.
+fn with_signing_secret(data: &impl Serialize, signing_secret: &str) -> Result<Value, AppError> {
+    let mut value = serde_json::to_value(data).map_err(|e| AppError::Internal(e.into()))?;
+    value["signing_secret"] = json!(signing_secret);
+    Ok(value)
+}
+
 fn validate_timezone(timezone: &str) -> Result<(), AppError> {
     timezone
         .trim()
@@ -42,7 +59,10 @@ fn validate_target(channel: &NotificationChannel, target: &str) -> Result<(), Ap
                 return Err(AppError::BadRequest("invalid email target".to_string()));
             }
         }
-        NotificationChannel::Webhook => {
+        NotificationChannel::Webhook
+        | NotificationChannel::Slack
+        | NotificationChannel::Teams
+        | NotificationChannel::Discord => {
             let url = url::Url::parse(trimmed)
                 .map_err(|_| AppError::BadRequest("invalid webhook target".to_string()))?;
             if url.scheme() != "http" && url.scheme() != "https" {
@@ -50,6 +70,12 @@ fn validate_target(channel: &NotificationChannel, target: &str) -> Result<(), Ap
                     "webhook target must use http or https".to_string(),
                 ));
             }
+            // Chat platforms only hand out https webhook URLs.
+            if *channel != NotificationChannel::Webhook && url.scheme() != "https" {
+                return Err(AppError::BadRequest(
+                    "chat webhook target must use https".to_string(),
+                ));
+            }
             let host = url
                 .host_str()
                 .ok_or_else(|| AppError::BadRequest("webhook target missing host".to_string()))?;
@@ -150,6 +176,7 @@ pub async fn create_subscription(
         .create_report_subscription(&website_id, req)
         .await
         .map_err(AppError::Internal)?;
+    let data = with_signing_secret(&data, &data.signing_secret)?;
     Ok((StatusCode::CREATED, Json(json!({ "data": data }))))
 }
 
@@ -195,6 +222,25 @@ pub async fn update_subscription(
     }
 }
 
+/// `POST /api/websites/:id/subscriptions/:subscription_id/rotate-secret` —
+/// replace the webhook signing secret and return the new one.
+pub async fn rotate_subscription_secret(
+    State(state): State<Arc<AppState>>,
+    Path((website_id, subscription_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+    let data = state
+        .db
+        .rotate_report_subscription_secret(&website_id, &subscription_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("Subscription not found".to_string()))?;
+    let data = with_signing_secret(&data, &data.signing_secret)?;
+    Ok(Json(json!({ "data": data })))
+}
+
 pub async fn delete_subscription(
     State(state): State<Arc<AppState>>,
     Path((website_id, subscription_id)): Path<(String, String)>,
@@ -233,22 +279,39 @@ pub async fn test_subscription(
         .map_err(AppError::Internal)?
         .ok_or_else(|| AppError::NotFound("Report not found".to_string()))?;
     let report_data = execute_report_config(state.as_ref(), &website_id, &report.config).await?;
+    let include_bots = state.default_include_bots(&website_id).await;
+    let trend = report_trend_with_backend(
+        state.analytics.as_ref(),
+        &website_id,
+        &report.config,
+        include_bots,
+    )
+    .await?;
+    let (period_start, period_end) = report_period(&report.config)?;
     let idempotency_key = unique_test_idempotency_key("sub-test", &subscription_id);
     let payload = json!({
         "kind": "report_subscription_test",
         "website_id": website_id,
+        "website_name": website_name(&state, &website_id).await,
         "subscription_id": subscription.id,
         "report_id": subscription.report_id,
+        "report_name": report.name,
+        "report_type": report.config.report_type,
+        "period": { "start_date": period_start, "end_date": period_end },
         "generated_at": Utc::now().to_rfc3339(),
-        "data": report_data
+        "data": report_data,
+        "trend": trend
     });
-    let delivery = deliver_and_record(
+    let delivery = deliver_once_and_record(
         &state,
         NotificationSourceType::Subscription,
         &subscription.id,
         &idempotency_key,
-        subscription.channel,
-        subscription.target,
+        Destination {
+            channel: subscription.channel,
+            target: subscription.target,
+            signing_secret: subscription.signing_secret,
+        },
         payload,
     )
     .await
@@ -256,6 +319,44 @@ pub async fn test_subscription(
     Ok(Json(json!({ "data": delivery })))
 }
 
+/// Seasonal baselines compare against prior weeks, so they need at least
+/// two of them.
+fn validate_lookback(baseline: &AlertBaseline, lookback_days: i64) -> Result<(), AppError> {
+    if is_seasonal(baseline) && lookback_days < MIN_SEASONAL_LOOKBACK_DAYS {
+        return Err(AppError::BadRequest(format!(
+            "lookback_days must be at least {MIN_SEASONAL_LOOKBACK_DAYS} for seasonal baselines"
+        )));
+    }
+    Ok(())
+}
+
+fn validate_scope(
+    metric: &AlertMetric,
+    event_name: Option<&str>,
+    filters: &AlertFilters,
+) -> Result<(), AppError> {
+    if *metric == AlertMetric::CustomEvents && event_name.is_none_or(|name| name.trim().is_empty())
+    {
+        return Err(AppError::BadRequest(
+            "event_name is required for custom_events alerts".to_string(),
+        ));
+    }
+    let today = Utc::now().date_naive();
+    filters
+        .to_analytics_filter(today, today, false)
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+    Ok(())
+}
+
+fn validate_cooldown(cooldown_minutes: i64) -> Result<(), AppError> {
+    if !(0..=MAX_COOLDOWN_MINUTES).contains(&cooldown_minutes) {
+        return Err(AppError::BadRequest(format!(
+            "cooldown_minutes must be between 0 and {MAX_COOLDOWN_MINUTES}"
+        )));
+    }
+    Ok(())
+}
+
 pub async fn list_alerts(
     State(state): State<Arc<AppState>>,
     Path(website_id): Path<String>,
@@ -288,11 +389,24 @@ pub async fn create_alert(
             "threshold_value must be finite".to_string(),
         ));
     }
+    validate_lookback(
+        req.baseline.as_ref().unwrap_or(&AlertBaseline::Flat),
+        req.lookback_days.unwrap_or(7),
+    )?;
+    validate_scope(
+        &req.metric,
+        req.event_name.as_deref(),
+        req.filters.as_ref().unwrap_or(&AlertFilters::default()),
+    )?;
+    if let Some(cooldown_minutes) = req.cooldown_minutes {
+        validate_cooldown(cooldown_minutes)?;
+    }
     let data = state
         .db
         .create_alert_rule(&website_id, req)
         .await
         .map_err(AppError::Internal)?;
+    let data = with_signing_secret(&data, &data.signing_secret)?;
     Ok((StatusCode::CREATED, Json(json!({ "data": data }))))
 }
 
@@ -325,6 +439,35 @@ pub async fn update_alert(
             ));
         }
     }
+    if let Some(cooldown_minutes) = req.cooldown_minutes {
+        validate_cooldown(cooldown_minutes)?;
+    }
+    if req.baseline.is_some()
+        || req.lookback_days.is_some()
+        || req.metric.is_some()
+        || req.event_name.is_some()
+        || req.filters.is_some()
+    {
+        let existing = state
+            .db
+            .get_alert_rule(&website_id, &alert_id)
+            .await
+            .map_err(AppError::Internal)?
+            .ok_or_else(|| AppError::NotFound("Alert not found".to_string()))?;
+        validate_lookback(
+            req.baseline.as_ref().unwrap_or(&existing.baseline),
+            req.lookback_days.unwrap_or(existing.lookback_days),
+        )?;
+        let event_name = match &req.event_name {
+            Some(event_name) => event_name.as_deref(),
+            None => existing.event_name.as_deref(),
+        };
+        validate_scope(
+            req.metric.as_ref().unwrap_or(&existing.metric),
+            event_name,
+            req.filters.as_ref().unwrap_or(&existing.filters),
+        )?;
+    }
     let data = state
         .db
         .update_alert_rule(&website_id, &alert_id, req)
@@ -336,6 +479,25 @@ pub async fn update_alert(
     }
 }
 
+/// `POST /api/websites/:id/alerts/:alert_id/rotate-secret` — replace the
+/// webhook signing secret and return the new one.
+pub async fn rotate_alert_secret(
+    State(state): State<Arc<AppState>>,
+    Path((website_id, alert_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+    let data = state
+        .db
+        .rotate_alert_rule_secret(&website_id, &alert_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("Alert not found".to_string()))?;
+    let data = with_signing_secret(&data, &data.signing_secret)?;
+    Ok(Json(json!({ "data": data })))
+}
+
 pub async fn delete_alert(
     State(state): State<Arc<AppState>>,
     Path((website_id, alert_id)): Path<(String, String)>,
@@ -371,20 +533,28 @@ pub async fn test_alert(
     let payload = json!({
         "kind": "alert_test",
         "website_id": website_id,
+        "website_name": website_name(&state, &website_id).await,
         "alert_id": alert.id,
         "name": alert.name,
         "metric": alert.metric,
+        "event_name": alert.event_name,
+        "filters": alert.filters,
+        "granularity": alert.granularity,
         "condition_type": alert.condition_type,
         "threshold_value": alert.threshold_value,
+        "baseline": alert.baseline,
         "triggered_at": Utc::now().to_rfc3339(),
     });
-    let delivery = deliver_and_record(
+    let delivery = deliver_once_and_record(
         &state,
         NotificationSourceType::Alert,
         &alert.id,
         &idempotency_key,
-        alert.channel,
-        alert.target,
+        Destination {
+            channel: alert.channel,
+            target: alert.target,
+            signing_secret: alert.signing_secret,
+        },
         payload,
     )
     .await
@@ -408,3 +578,32 @@ pub async fn notification_history(
         .map_err(AppError::Internal)?;
     Ok(Json(json!({ "data": data })))
 }
+
+pub async fn replay_delivery(
+    State(state): State<Arc<AppState>>,
+    Path((website_id, delivery_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+    let resendable = state
+        .db
+        .get_resendable_delivery(&website_id, &delivery_id)
+        .await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound("Delivery not found".to_string()))?;
+    let retry = match resendable.delivery.status {
+        NotificationDeliveryStatus::Sent => {
+            return Err(AppError::BadRequest(
+                "only failed deliveries can be replayed".to_string(),
+            ))
+        }
+        // Keep the backoff schedule going if this attempt fails too.
+        NotificationDeliveryStatus::Retrying => true,
+        NotificationDeliveryStatus::Failed => false,
+    };
+    let delivery = resend(&state, resendable, retry)
+        .await
+        .map_err(AppError::Internal)?;
+    Ok(Json(json!({ "data": delivery })))
+}
@@ -1,5 +1,7 @@
 use chrono::NaiveDate;
 
+use sparklytics_core::analytics::{DimensionFilter, DimensionFilterOp, PropertyFilter};
+
 use crate::error::AppError;
 
 pub(crate) fn parse_defaulted_date_range_lenient(
@@ -105,6 +107,43 @@ pub(crate) fn normalize_optional_filter(
     Ok(None)
 }
 
+/// Maximum length of a single value for each `filter_*` dimension.
+fn dimension_filter_max_len(field: &str) -> usize {
+    match field {
+        "filter_page" | "filter_referrer" => 512,
+        "filter_hostname" => 255,
+        "filter_utm_source" | "filter_utm_medium" | "filter_utm_campaign" => 256,
+        "filter_browser" | "filter_os" | "filter_region" | "filter_city" => 128,
+        _ => 64,
+    }
+}
+
+/// Parse a `filter_*` query parameter such as `is_not:US` or
+/// `in:Chrome,Firefox`. Plain values keep their legacy meaning: substring
+/// match for `filter_page`, exact match for every other dimension.
+pub(crate) fn parse_dimension_filter(
+    field: &str,
+    value: Option<String>,
+) -> Result<Option<DimensionFilter>, AppError> {
+    let Some(raw) = normalize_optional_filter(field, value, usize::MAX)? else {
+        return Ok(None);
+    };
+    let default_op = if field == "filter_page" {
+        DimensionFilterOp::Contains
+    } else {
+        DimensionFilterOp::Is
+    };
+    let filter = DimensionFilter::parse(&raw, default_op)
+        .map_err(|e| AppError::BadRequest(format!("{field}: {e}")))?;
+    let max_len = dimension_filter_max_len(field);
+    if filter.values.iter().any(|value| value.len() > max_len) {
+        return Err(AppError::BadRequest(format!(
+            "{field} is too long (max {max_len} characters)"
+        )));
+    }
+    Ok(Some(filter))
+}
+
 pub(crate) fn parse_optional_bool(
     value: Option<&str>,
     field: &str,
@@ -154,13 +193,20 @@ fn validate_date_order(start: NaiveDate, end: NaiveDate) -> Result<(), AppError>
     Ok(())
 }
 
+/// Parse the `filter_properties` query parameter (a JSON array of
+/// `event_data` property predicates).
+pub(crate) fn parse_filter_properties(raw: Option<&str>) -> Result<Vec<PropertyFilter>, AppError> {
+    PropertyFilter::parse_list(raw).map_err(|e| AppError::BadRequest(e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
+    use sparklytics_core::analytics::DimensionFilterOp;
 
     use super::{
-        parse_defaulted_date_range_lenient, parse_optional_bool, parse_required_date_range,
-        validate_date_span,
+        parse_defaulted_date_range_lenient, parse_dimension_filter, parse_optional_bool,
+        parse_required_date_range, validate_date_span,
     };
 
     #[test]
@@ -184,6 +230,52 @@ mod tests {
         assert!(parse_optional_bool(Some("yes"), "include_bots").is_err());
     }
 
+    #[test]
+    fn parse_dimension_filter_keeps_legacy_defaults() {
+        let page = parse_dimension_filter("filter_page", Some("/pricing".to_string()))
+            .expect("page")
+            .expect("some");
+        assert_eq!(page.op, DimensionFilterOp::Contains);
+
+        let country = parse_dimension_filter("filter_country", Some("US".to_string()))
+            .expect("country")
+            .expect("some");
+        assert_eq!(country.op, DimensionFilterOp::Is);
+        assert_eq!(country.values, vec!["US"]);
+
+        let referrer = parse_dimension_filter("filter_referrer", Some("https://x.io".to_string()))
+            .expect("referrer")
+            .expect("some");
+        assert_eq!(referrer.op, DimensionFilterOp::Is);
+        assert_eq!(referrer.values, vec!["https://x.io"]);
+    }
+
+    #[test]
+    fn parse_dimension_filter_reads_operator_prefix() {
+        let browsers = parse_dimension_filter("filter_browser", Some("in:Chrome, Firefox".into()))
+            .expect("browser")
+            .expect("some");
+        assert_eq!(browsers.op, DimensionFilterOp::In);
+        assert_eq!(browsers.values, vec!["Chrome", "Firefox"]);
+
+        let country = parse_dimension_filter("filter_country", Some("is_not:US".into()))
+            .expect("country")
+            .expect("some");
+        assert_eq!(country.op, DimensionFilterOp::IsNot);
+
+        assert!(parse_dimension_filter("filter_page", Some("regex:^/blog/(".into())).is_err());
+        assert!(parse_dimension_filter("filter_os", Some("in:,".into())).is_err());
+        assert!(parse_dimension_filter("filter_os", Some("  ".into())).is_err());
+        assert!(parse_dimension_filter(
+            "filter_country",
+            Some("is:".to_string() + &"A".repeat(65))
+        )
+        .is_err());
+        assert!(parse_dimension_filter("filter_os", None)
+            .expect("none")
+            .is_none());
+    }
+
     #[test]
     fn parse_required_date_range_rejects_reversed_bounds() {
         let result = parse_required_date_range(Some("2026-01-05"), Some("2026-01-01"));
@@ -1,14 +1,34 @@
+use std::convert::Infallible;
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::{
     extract::{Path, Query, State},
-    response::IntoResponse,
+    response::{
+        sse::{Event as SseEvent, KeepAlive, Sse},
+        IntoResponse,
+    },
     Json,
 };
+use chrono::Utc;
+use futures_util::stream::{self, Stream};
 use serde::Deserialize;
 use serde_json::json;
+use tokio::sync::broadcast::{
+    error::{RecvError, TryRecvError},
+    Receiver,
+};
+use tokio::time::{interval, Interval, MissedTickBehavior};
+
+use crate::{
+    error::AppError,
+    realtime_feed::{RealtimeFeed, RealtimeUpdate},
+    state::AppState,
+};
 
-use crate::{error::AppError, state::AppState};
+/// How often the stream re-checks the active-visitor count so visitors
+/// dropping out of the window are reflected without new events.
+const ACTIVE_VISITORS_RECHECK: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Deserialize)]
 pub struct RealtimeQuery {
@@ -36,3 +56,108 @@ pub async fn get_realtime(
 
     Ok(Json(json!({ "data": result })))
 }
+
+struct RealtimeStream {
+    feed: Arc<RealtimeFeed>,
+    website_id: String,
+    include_bots: bool,
+    receiver: Receiver<RealtimeUpdate>,
+    recheck: Interval,
+    active_visitors: Option<i64>,
+}
+
+impl RealtimeStream {
+    fn wants(&self, update: &RealtimeUpdate) -> bool {
+        self.include_bots || !update.is_bot
+    }
+
+    /// Next frame to send: events already received go out first, then an
+    /// `active_visitors` frame whenever the count changed, otherwise the next
+    /// ingested `event`. A `resync` frame reports events dropped because the
+    /// client fell behind. `None` ends the stream.
+    async fn next_frame(&mut self) -> Option<SseEvent> {
+        loop {
+            match self.receiver.try_recv() {
+                Ok(update) if self.wants(&update) => {
+                    let data = serde_json::to_string(&update.event).ok()?;
+                    return Some(SseEvent::default().event("event").data(data));
+                }
+                Ok(_) => continue,
+                Err(TryRecvError::Lagged(skipped)) => return Some(resync_frame(skipped)),
+                Err(TryRecvError::Closed) => return None,
+                Err(TryRecvError::Empty) => {}
+            }
+
+            let active_visitors =
+                self.feed
+                    .active_visitors(&self.website_id, self.include_bots, Utc::now());
+            if self.active_visitors != Some(active_visitors) {
+                self.active_visitors = Some(active_visitors);
+                return Some(
+                    SseEvent::default()
+                        .event("active_visitors")
+                        .data(json!({ "active_visitors": active_visitors }).to_string()),
+                );
+            }
+
+            tokio::select! {
+                update = self.receiver.recv() => match update {
+                    Ok(update) if self.wants(&update) => {
+                        let data = serde_json::to_string(&update.event).ok()?;
+                        return Some(SseEvent::default().event("event").data(data));
+                    }
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(skipped)) => return Some(resync_frame(skipped)),
+                    Err(RecvError::Closed) => return None,
+                },
+                _ = self.recheck.tick() => {}
+            }
+        }
+    }
+}
+
+/// Tells the client it missed `skipped` events and should refetch the
+/// realtime snapshot.
+fn resync_frame(skipped: u64) -> SseEvent {
+    SseEvent::default()
+        .event("resync")
+        .data(json!({ "skipped": skipped }).to_string())
+}
+
+/// `GET /api/websites/:id/realtime/stream` — Server-Sent Events pushed from
+/// the ingest path without querying DuckDB.
+///
+/// Emits an `active_visitors` frame on connect and whenever the count
+/// changes, an `event` frame (same shape as `recent_events` items) for
+/// every accepted event, and a `resync` frame when the client fell too far
+/// behind and events were dropped.
+pub async fn stream_realtime(
+    State(state): State<Arc<AppState>>,
+    Path(website_id): Path<String>,
+    Query(query): Query<RealtimeQuery>,
+) -> Result<Sse<impl Stream<Item = Result<SseEvent, Infallible>>>, AppError> {
+    if !state.is_valid_website(&website_id).await {
+        return Err(AppError::NotFound("Website not found".to_string()));
+    }
+
+    let include_bots = query
+        .include_bots
+        .unwrap_or(state.default_include_bots(&website_id).await);
+    let receiver = state.realtime_feed.subscribe(&website_id);
+    let mut recheck = interval(ACTIVE_VISITORS_RECHECK);
+    recheck.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let realtime = RealtimeStream {
+        feed: Arc::clone(&state.realtime_feed),
+        website_id,
+        include_bots,
+        receiver,
+        recheck,
+        active_visitors: None,
+    };
+
+    let frames = stream::unfold(realtime, |mut realtime| async move {
+        let frame = realtime.next_frame().await?;
+        Some((Ok(frame), realtime))
+    });
+    Ok(Sse::new(frames).keep_alive(KeepAlive::default()))
+}
@@ -10,14 +10,14 @@ use chrono::NaiveDate;
 use serde_json::{json, Value};
 
 use sparklytics_core::analytics::{
-    AnalyticsFilter, CreateReportRequest, DateRangeType, ReportConfig, ReportRunResult, ReportType,
-    UpdateReportRequest, VALID_METRIC_TYPES,
+    AnalyticsFilter, CreateReportRequest, DateRangeType, PropertyFilter, ReportConfig,
+    ReportRunResult, ReportType, UpdateReportRequest, VALID_METRIC_TYPES,
 };
 
 use crate::{
     error::AppError,
     routes::compare::{compare_metadata, metadata_json, resolve_compare_range_for_mode},
-    routes::query::today_for_optional_timezone,
+    routes::query::{parse_dimension_filter, today_for_optional_timezone},
     state::AppState,
 };
 
@@ -107,6 +107,19 @@ fn parse_absolute_range(
     Ok((start, end))
 }
 
+/// The date range a report covers when run now.
+pub(crate) fn report_period(config: &ReportConfig) -> Result<(NaiveDate, NaiveDate), AppError> {
+    match config.date_range_type {
+        DateRangeType::Relative => {
+            let timezone = normalize_timezone(config.timezone.as_deref())?;
+            parse_relative_range(config.relative_days, timezone.as_deref())
+        }
+        DateRangeType::Absolute => {
+            parse_absolute_range(config.start_date.as_deref(), config.end_date.as_deref())
+        }
+    }
+}
+
 fn build_analytics_context(
     config: &ReportConfig,
     include_bots: bool,
@@ -118,13 +131,7 @@ fn build_analytics_context(
     AppError,
 > {
     let timezone = normalize_timezone(config.timezone.as_deref())?;
-
-    let (start_date, end_date) = match config.date_range_type {
-        DateRangeType::Relative => parse_relative_range(config.relative_days, timezone.as_deref())?,
-        DateRangeType::Absolute => {
-            parse_absolute_range(config.start_date.as_deref(), config.end_date.as_deref())?
-        }
-    };
+    let (start_date, end_date) = report_period(config)?;
 
     if matches!(config.report_type, ReportType::Metrics) {
         let Some(metric_type) = config.metric_type.as_deref() else {
@@ -139,6 +146,9 @@ fn build_analytics_context(
         }
     }
 
+    PropertyFilter::validate_list(&config.filter_properties)
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
     let compare_mode = config
         .compare_mode
         .clone()
@@ -156,19 +166,41 @@ fn build_analytics_context(
             start_date,
             end_date,
             timezone,
-            filter_country: config.filter_country.clone(),
-            filter_page: config.filter_page.clone(),
-            filter_referrer: config.filter_referrer.clone(),
-            filter_browser: config.filter_browser.clone(),
-            filter_os: config.filter_os.clone(),
-            filter_device: config.filter_device.clone(),
+            filter_country: parse_dimension_filter(
+                "filter_country",
+                config.filter_country.clone(),
+            )?,
+            filter_page: parse_dimension_filter("filter_page", config.filter_page.clone())?,
+            filter_referrer: parse_dimension_filter(
+                "filter_referrer",
+                config.filter_referrer.clone(),
+            )?,
+            filter_browser: parse_dimension_filter(
+                "filter_browser",
+                config.filter_browser.clone(),
+            )?,
+            filter_os: parse_dimension_filter("filter_os", config.filter_os.clone())?,
+            filter_device: parse_dimension_filter("filter_device", config.filter_device.clone())?,
             filter_language: None,
-            filter_utm_source: config.filter_utm_source.clone(),
-            filter_utm_medium: config.filter_utm_medium.clone(),
-            filter_utm_campaign: config.filter_utm_campaign.clone(),
-            filter_region: config.filter_region.clone(),
-            filter_city: config.filter_city.clone(),
-            filter_hostname: config.filter_hostname.clone(),
+            filter_utm_source: parse_dimension_filter(
+                "filter_utm_source",
+                config.filter_utm_source.clone(),
+            )?,
+            filter_utm_medium: parse_dimension_filter(
+                "filter_utm_medium",
+                config.filter_utm_medium.clone(),
+            )?,
+            filter_utm_campaign: parse_dimension_filter(
+                "filter_utm_campaign",
+                config.filter_utm_campaign.clone(),
+            )?,
+            filter_region: parse_dimension_filter("filter_region", config.filter_region.clone())?,
+            filter_city: parse_dimension_filter("filter_city", config.filter_city.clone())?,
+            filter_hostname: parse_dimension_filter(
+                "filter_hostname",
+                config.filter_hostname.clone(),
+            )?,
+            filter_properties: config.filter_properties.clone(),
             include_bots,
         },
         comparison,
@@ -244,6 +276,27 @@ pub(crate) async fn execute_report_config_with_backend(
     }
 }
 
+/// Daily pageviews and visitors over a stats report's period, for sparklines
+/// in notifications. Other report types already carry their own rows.
+pub(crate) async fn report_trend_with_backend(
+    analytics: &dyn sparklytics_core::analytics::AnalyticsBackend,
+    website_id: &str,
+    config: &ReportConfig,
+    include_bots: bool,
+) -> Result<Option<Value>, AppError> {
+    if !matches!(config.report_type, ReportType::Stats) {
+        return Ok(None);
+    }
+    let (filter, _) = build_analytics_context(config, include_bots)?;
+    let data = analytics
+        .get_timeseries(website_id, None, &filter, Some("day"), None)
+        .await
+        .map_err(AppError::Internal)?;
+    serde_json::to_value(data.series)
+        .map(Some)
+        .map_err(|e| AppError::Internal(e.into()))
+}
+
 pub(crate) async fn execute_report_config(
     state: &AppState,
     website_id: &str,
@@ -466,6 +519,8 @@ pub async fn run_report(
 
 #[cfg(test)]
 mod tests {
+    use sparklytics_core::analytics::DimensionFilterOp;
+
     use super::*;
 
     fn default_config() -> ReportConfig {
@@ -493,6 +548,7 @@ mod tests {
             filter_region: None,
             filter_city: None,
             filter_hostname: None,
+            filter_properties: Vec::new(),
         }
     }
 
@@ -550,6 +606,32 @@ mod tests {
         assert!(matches!(err, AppError::BadRequest(_)));
     }
 
+    #[test]
+    fn dimension_filters_accept_operator_prefixes() {
+        let mut cfg = default_config();
+        cfg.filter_country = Some("is_not:US".to_string());
+        cfg.filter_browser = Some("in:Chrome,Firefox".to_string());
+        cfg.filter_page = Some("/blog".to_string());
+        let (filter, _) = build_analytics_context(&cfg, false).expect("valid filters");
+
+        let country = filter.filter_country.expect("country filter");
+        assert_eq!(country.op, DimensionFilterOp::IsNot);
+        assert_eq!(country.values, vec!["US"]);
+        let browser = filter.filter_browser.expect("browser filter");
+        assert_eq!(browser.op, DimensionFilterOp::In);
+        assert_eq!(browser.values, vec!["Chrome", "Firefox"]);
+        let page = filter.filter_page.expect("page filter");
+        assert_eq!(page.op, DimensionFilterOp::Contains);
+    }
+
+    #[test]
+    fn dimension_filters_reject_invalid_regex() {
+        let mut cfg = default_config();
+        cfg.filter_page = Some("regex:[unclosed".to_string());
+        let err = build_analytics_context(&cfg, false).expect_err("invalid regex should fail");
+        assert!(matches!(err, AppError::BadRequest(_)));
+    }
+
     #[test]
     fn validate_name_rejects_empty_and_too_long() {
         assert!(validate_name("").is_err());
@@ -13,7 +13,8 @@ use sparklytics_core::analytics::{AnalyticsFilter, RetentionGranularity, Retenti
 use crate::{
     error::AppError,
     routes::query::{
-        normalize_optional_filter, normalize_timezone_non_empty, parse_required_date_range,
+        normalize_timezone_non_empty, parse_dimension_filter, parse_filter_properties,
+        parse_required_date_range,
     },
     state::AppState,
 };
@@ -41,6 +42,7 @@ pub struct RetentionParams {
     pub filter_region: Option<String>,
     pub filter_city: Option<String>,
     pub filter_hostname: Option<String>,
+    pub filter_properties: Option<String>,
     pub include_bots: Option<bool>,
 }
 
@@ -124,31 +126,23 @@ pub async fn get_retention(
         start_date,
         end_date,
         timezone: normalize_timezone_non_empty(params.timezone.as_deref())?,
-        filter_country: normalize_optional_filter("filter_country", params.filter_country, 64)?,
-        filter_page: normalize_optional_filter("filter_page", params.filter_page, 512)?,
-        filter_referrer: normalize_optional_filter("filter_referrer", params.filter_referrer, 512)?,
-        filter_browser: normalize_optional_filter("filter_browser", params.filter_browser, 128)?,
-        filter_os: normalize_optional_filter("filter_os", params.filter_os, 128)?,
-        filter_device: normalize_optional_filter("filter_device", params.filter_device, 64)?,
-        filter_language: normalize_optional_filter("filter_language", params.filter_language, 64)?,
-        filter_utm_source: normalize_optional_filter(
-            "filter_utm_source",
-            params.filter_utm_source,
-            256,
-        )?,
-        filter_utm_medium: normalize_optional_filter(
-            "filter_utm_medium",
-            params.filter_utm_medium,
-            256,
-        )?,
-        filter_utm_campaign: normalize_optional_filter(
+        filter_country: parse_dimension_filter("filter_country", params.filter_country)?,
+        filter_page: parse_dimension_filter("filter_page", params.filter_page)?,
+        filter_referrer: parse_dimension_filter("filter_referrer", params.filter_referrer)?,
+        filter_browser: parse_dimension_filter("filter_browser", params.filter_browser)?,
+        filter_os: parse_dimension_filter("filter_os", params.filter_os)?,
+        filter_device: parse_dimension_filter("filter_device", params.filter_device)?,
+        filter_language: parse_dimension_filter("filter_language", params.filter_language)?,
+        filter_utm_source: parse_dimension_filter("filter_utm_source", params.filter_utm_source)?,
+        filter_utm_medium: parse_dimension_filter("filter_utm_medium", params.filter_utm_medium)?,
+        filter_utm_campaign: parse_dimension_filter(
             "filter_utm_campaign",
             params.filter_utm_campaign,
-            256,
         )?,
-        filter_region: normalize_optional_filter("filter_region", params.filter_region, 128)?,
-        filter_city: normalize_optional_filter("filter_city", params.filter_city, 128)?,
-        filter_hostname: normalize_optional_filter("filter_hostname", params.filter_hostname, 255)?,
+        filter_region: parse_dimension_filter("filter_region", params.filter_region)?,
+        filter_city: parse_dimension_filter("filter_city", params.filter_city)?,
+        filter_hostname: parse_dimension_filter("filter_hostname", params.filter_hostname)?,
+        filter_properties: parse_filter_properties(params.filter_properties.as_deref())?,
         include_bots,
     };
 
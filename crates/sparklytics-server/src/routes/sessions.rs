@@ -12,7 +12,13 @@ use sparklytics_core::analytics::{
     AnalyticsFilter, SessionSort, SessionsQuery as BackendSessionsQuery,
 };
 
-use crate::{error::AppError, routes::query::parse_defaulted_date_range_lenient, state::AppState};
+use crate::{
+    error::AppError,
+    routes::query::{
+        parse_defaulted_date_range_lenient, parse_dimension_filter, parse_filter_properties,
+    },
+    state::AppState,
+};
 
 #[derive(Debug, Deserialize)]
 pub struct SessionsQuery {
@@ -34,6 +40,7 @@ pub struct SessionsQuery {
     pub filter_region: Option<String>,
     pub filter_city: Option<String>,
     pub filter_hostname: Option<String>,
+    pub filter_properties: Option<String>,
     pub include_bots: Option<bool>,
 }
 
@@ -65,19 +72,23 @@ pub async fn list_sessions(
         start_date,
         end_date,
         timezone: query.timezone,
-        filter_country: query.filter_country,
-        filter_page: query.filter_page,
-        filter_referrer: query.filter_referrer,
-        filter_browser: query.filter_browser,
-        filter_os: query.filter_os,
-        filter_device: query.filter_device,
-        filter_language: query.filter_language,
-        filter_utm_source: query.filter_utm_source,
-        filter_utm_medium: query.filter_utm_medium,
-        filter_utm_campaign: query.filter_utm_campaign,
-        filter_region: query.filter_region,
-        filter_city: query.filter_city,
-        filter_hostname: query.filter_hostname,
+        filter_country: parse_dimension_filter("filter_country", query.filter_country)?,
+        filter_page: parse_dimension_filter("filter_page", query.filter_page)?,
+        filter_referrer: parse_dimension_filter("filter_referrer", query.filter_referrer)?,
+        filter_browser: parse_dimension_filter("filter_browser", query.filter_browser)?,
+        filter_os: parse_dimension_filter("filter_os", query.filter_os)?,
+        filter_device: parse_dimension_filter("filter_device", query.filter_device)?,
+        filter_language: parse_dimension_filter("filter_language", query.filter_language)?,
+        filter_utm_source: parse_dimension_filter("filter_utm_source", query.filter_utm_source)?,
+        filter_utm_medium: parse_dimension_filter("filter_utm_medium", query.filter_utm_medium)?,
+        filter_utm_campaign: parse_dimension_filter(
+            "filter_utm_campaign",
+            query.filter_utm_campaign,
+        )?,
+        filter_region: parse_dimension_filter("filter_region", query.filter_region)?,
+        filter_city: parse_dimension_filter("filter_city", query.filter_city)?,
+        filter_hostname: parse_dimension_filter("filter_hostname", query.filter_hostname)?,
+        filter_properties: parse_filter_properties(query.filter_properties.as_deref())?,
         include_bots,
     };
 
@@ -91,6 +91,7 @@ fn share_filter(
         filter_region: None,
         filter_city: None,
         filter_hostname: None,
+        filter_properties: Vec::new(),
         include_bots,
     }
 }
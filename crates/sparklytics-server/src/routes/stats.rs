@@ -8,12 +8,14 @@ use axum::{
 use serde::Deserialize;
 use serde_json::json;
 
-use sparklytics_core::analytics::AnalyticsFilter;
+use sparklytics_core::analytics::{AnalyticsFilter, DimensionFilterOp};
 
 use crate::{
     error::AppError,
     routes::compare::{metadata_json, resolve_compare_range},
-    routes::query::parse_defaulted_date_range_lenient,
+    routes::query::{
+        parse_defaulted_date_range_lenient, parse_dimension_filter, parse_filter_properties,
+    },
     state::AppState,
 };
 
@@ -35,6 +37,7 @@ pub struct StatsQuery {
     pub filter_region: Option<String>,
     pub filter_city: Option<String>,
     pub filter_hostname: Option<String>,
+    pub filter_properties: Option<String>,
     pub include_bots: Option<bool>,
     pub compare_mode: Option<String>,
     pub compare_start_date: Option<String>,
@@ -51,8 +54,15 @@ pub async fn get_stats(
         return Err(AppError::NotFound("Website not found".to_string()));
     }
 
-    if let Some(ref country) = query.filter_country {
-        if country.len() != 2 || !country.chars().all(|c| c.is_ascii_uppercase()) {
+    let filter_country = parse_dimension_filter("filter_country", query.filter_country)?;
+    if let Some(ref country) = filter_country {
+        let exact_match = matches!(
+            country.op,
+            DimensionFilterOp::Is | DimensionFilterOp::IsNot | DimensionFilterOp::In
+        );
+        let is_iso_code =
+            |code: &String| code.len() == 2 && code.chars().all(|c| c.is_ascii_uppercase());
+        if exact_match && !country.values.iter().all(is_iso_code) {
             return Err(AppError::BadRequest(
                 "filter_country must be ISO 3166-1 alpha-2 (2 chars)".to_string(),
             ));
@@ -72,19 +82,23 @@ pub async fn get_stats(
         start_date,
         end_date,
         timezone: query.timezone,
-        filter_country: query.filter_country,
-        filter_page: query.filter_page,
-        filter_referrer: query.filter_referrer,
-        filter_browser: query.filter_browser,
-        filter_os: query.filter_os,
-        filter_device: query.filter_device,
-        filter_language: query.filter_language,
-        filter_utm_source: query.filter_utm_source,
-        filter_utm_medium: query.filter_utm_medium,
-        filter_utm_campaign: query.filter_utm_campaign,
-        filter_region: query.filter_region,
-        filter_city: query.filter_city,
-        filter_hostname: query.filter_hostname,
+        filter_country,
+        filter_page: parse_dimension_filter("filter_page", query.filter_page)?,
+        filter_referrer: parse_dimension_filter("filter_referrer", query.filter_referrer)?,
+        filter_browser: parse_dimension_filter("filter_browser", query.filter_browser)?,
+        filter_os: parse_dimension_filter("filter_os", query.filter_os)?,
+        filter_device: parse_dimension_filter("filter_device", query.filter_device)?,
+        filter_language: parse_dimension_filter("filter_language", query.filter_language)?,
+        filter_utm_source: parse_dimension_filter("filter_utm_source", query.filter_utm_source)?,
+        filter_utm_medium: parse_dimension_filter("filter_utm_medium", query.filter_utm_medium)?,
+        filter_utm_campaign: parse_dimension_filter(
+            "filter_utm_campaign",
+            query.filter_utm_campaign,
+        )?,
+        filter_region: parse_dimension_filter("filter_region", query.filter_region)?,
+        filter_city: parse_dimension_filter("filter_city", query.filter_city)?,
+        filter_hostname: parse_dimension_filter("filter_hostname", query.filter_hostname)?,
+        filter_properties: parse_filter_properties(query.filter_properties.as_deref())?,
         include_bots,
     };
 
@@ -2,7 +2,7 @@ use std::sync::Arc;
 use std::{net::IpAddr, str::FromStr};
 
 use axum::{
-    extract::{Path, Query, State},
+    extract::{Extension, Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
@@ -10,9 +10,13 @@ use axum::{
 use serde::Deserialize;
 use serde_json::json;
 
+use sparklytics_core::session::{MAX_SESSION_TIMEOUT_MINUTES, MIN_SESSION_TIMEOUT_MINUTES};
 use sparklytics_metadata::{CreateWebsiteParams, UpdateWebsiteParams};
 
-use crate::{error::AppError, state::AppState};
+use crate::{
+    auth::middleware::AuthContext, error::AppError, routes::ingest_limits::deserialize_tri_state,
+    state::AppState,
+};
 
 fn tracking_snippet(tracking_public_base: &str, website_id: &str) -> String {
     format!(
@@ -62,6 +66,14 @@ pub struct UpdateWebsiteRequest {
     pub name: Option<String>,
     pub domain: Option<String>,
     pub timezone: Option<String>,
+    /// Per-website override of `SPARKLYTICS_RETENTION_DAYS`; `null` clears it.
+    #[serde(default, deserialize_with = "deserialize_tri_state")]
+    pub retention_days: Option<Option<i64>>,
+    /// Inactivity timeout in minutes; `null` restores the 30-minute default.
+    #[serde(default, deserialize_with = "deserialize_tri_state")]
+    pub session_timeout_minutes: Option<Option<i64>>,
+    pub session_split_at_midnight: Option<bool>,
+    pub session_split_on_campaign: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -110,19 +122,25 @@ pub async fn create_website(
     ))
 }
 
-/// `GET /api/websites` — List all websites.
+/// `GET /api/websites` — List all websites, or only the granted ones for
+/// viewer accounts.
 pub async fn list_websites(
     State(state): State<Arc<AppState>>,
+    auth: Option<Extension<AuthContext>>,
     Query(query): Query<ListWebsitesQuery>,
 ) -> Result<impl IntoResponse, AppError> {
     let limit = query.limit.unwrap_or(20).clamp(1, 100);
     let cursor = query.cursor.as_deref();
 
-    let (websites, total, has_more) = state
-        .metadata
-        .list_websites(limit, cursor)
-        .await
-        .map_err(AppError::Internal)?;
+    let granted = auth.and_then(|Extension(ctx)| ctx.website_ids);
+    let (websites, total, has_more) = match granted {
+        Some(website_ids) => list_granted_websites(&state, website_ids, limit, cursor).await?,
+        None => state
+            .metadata
+            .list_websites(limit, cursor)
+            .await
+            .map_err(AppError::Internal)?,
+    };
 
     let next_cursor = if has_more {
         websites.last().map(|w| w.id.clone())
@@ -141,6 +159,36 @@ pub async fn list_websites(
     })))
 }
 
+/// Page through the websites a viewer was granted, in the same id order and
+/// cursor semantics as the unrestricted listing.
+async fn list_granted_websites(
+    state: &AppState,
+    mut website_ids: Vec<String>,
+    limit: i64,
+    cursor: Option<&str>,
+) -> Result<(Vec<sparklytics_metadata::Website>, i64, bool), AppError> {
+    website_ids.sort();
+    let mut websites = Vec::with_capacity(website_ids.len());
+    for website_id in &website_ids {
+        if let Some(website) = state
+            .metadata
+            .get_website(website_id)
+            .await
+            .map_err(AppError::Internal)?
+        {
+            websites.push(website);
+        }
+    }
+    let total = websites.len() as i64;
+    let mut page: Vec<_> = websites
+        .into_iter()
+        .filter(|website| cursor.is_none_or(|cursor| website.id.as_str() > cursor))
+        .collect();
+    let has_more = page.len() as i64 > limit;
+    page.truncate(limit as usize);
+    Ok((page, total, has_more))
+}
+
 /// `PUT /api/websites/:id` — Update a website.
 pub async fn update_website(
     State(state): State<Arc<AppState>>,
@@ -150,6 +198,20 @@ pub async fn update_website(
     if let Some(domain) = req.domain.clone() {
         req.domain = Some(normalize_domain(&domain)?);
     }
+    if let Some(Some(days)) = req.retention_days {
+        if days <= 0 {
+            return Err(AppError::BadRequest(
+                "retention_days must be > 0".to_string(),
+            ));
+        }
+    }
+    if let Some(Some(minutes)) = req.session_timeout_minutes {
+        if !(MIN_SESSION_TIMEOUT_MINUTES..=MAX_SESSION_TIMEOUT_MINUTES).contains(&minutes) {
+            return Err(AppError::BadRequest(format!(
+                "session_timeout_minutes must be between {MIN_SESSION_TIMEOUT_MINUTES} and {MAX_SESSION_TIMEOUT_MINUTES}"
+            )));
+        }
+    }
 
     let result = state
         .metadata
@@ -161,6 +223,10 @@ pub async fn update_website(
                 timezone: req.timezone,
                 ingest_peak_eps: None,
                 ingest_queue_max_events: None,
+                retention_days: req.retention_days,
+                session_timeout_minutes: req.session_timeout_minutes,
+                session_split_at_midnight: req.session_split_at_midnight,
+                session_split_on_campaign: req.session_split_on_campaign,
             },
         )
         .await
@@ -175,6 +241,10 @@ pub async fn update_website(
                     "name": website.name,
                     "domain": website.domain,
                     "timezone": website.timezone,
+                    "retention_days": website.retention_days,
+                    "session_timeout_minutes": website.session_timeout_minutes,
+                    "session_split_at_midnight": website.session_split_at_midnight,
+                    "session_split_on_campaign": website.session_split_on_campaign,
                     "updated_at": website.updated_at,
                 }
             })))
@@ -205,6 +275,10 @@ pub async fn get_website(
             "domain": website.domain,
             "timezone": website.timezone,
             "share_id": website.share_id,
+            "retention_days": website.retention_days,
+            "session_timeout_minutes": website.session_timeout_minutes,
+            "session_split_at_midnight": website.session_split_at_midnight,
+            "session_split_on_campaign": website.session_split_on_campaign,
             "tracking_snippet": snippet,
             "created_at": website.created_at,
             "updated_at": website.updated_at,
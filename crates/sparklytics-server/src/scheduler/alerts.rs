@@ -1,12 +1,18 @@
 use std::{collections::HashMap, sync::Arc};
 
-use chrono::{Duration, NaiveDate, Utc};
+use chrono::{NaiveDateTime, Utc};
 use serde_json::json;
-use sparklytics_core::analytics::{AlertConditionType, NotificationSourceType};
+use sparklytics_core::{
+    alerts::{evaluate_alert_rule, in_cooldown, AlertWindow},
+    analytics::{AlertGranularity, NotificationSourceType},
+};
 
 use crate::state::AppState;
 
-use super::delivery::deliver_and_record;
+use super::delivery::{deliver_and_record, Destination};
+
+/// Most recent buckets charted in an alert's `history`.
+const MAX_HISTORY_POINTS: usize = 7 * 24;
 
 fn max_alert_rules_per_tick() -> i64 {
     std::env::var("SPARKLYTICS_SCHEDULER_MAX_ALERTS_PER_TICK")
@@ -16,97 +22,88 @@ fn max_alert_rules_per_tick() -> i64 {
         .unwrap_or(25)
 }
 
-fn mean(values: &[f64]) -> Option<f64> {
-    if values.is_empty() {
-        return None;
-    }
-    Some(values.iter().sum::<f64>() / values.len() as f64)
-}
-
-fn stddev(values: &[f64], mean_value: f64) -> Option<f64> {
-    if values.len() < 2 {
-        return None;
-    }
-    let variance = values
-        .iter()
-        .map(|v| {
-            let diff = *v - mean_value;
-            diff * diff
-        })
-        .sum::<f64>()
-        / values.len() as f64;
-    Some(variance.sqrt())
-}
-
+/// Evaluate active alert rules, sending a notification when a rule triggers
+/// outside its cooldown and another when a firing rule returns to normal.
 pub async fn run_alert_checks(state: &Arc<AppState>) -> anyhow::Result<usize> {
     let rules = state
         .scheduler_db
         .list_active_alert_rules(max_alert_rules_per_tick())
         .await?;
-    let today = Utc::now().date_naive();
+    let now = Utc::now();
     let mut deliveries = 0usize;
 
     for rule in rules {
-        let baseline_start = today - Duration::days(rule.lookback_days);
-        let series = state
+        let window = AlertWindow::for_rule(&rule, now);
+        let series: HashMap<NaiveDateTime, f64> = state
             .scheduler_db
-            .get_daily_alert_metric_series(&rule.website_id, &rule.metric, baseline_start, today)
-            .await?;
-        let daily_map: HashMap<NaiveDate, f64> = series.into_iter().collect();
-        let current_value = daily_map.get(&today).copied().unwrap_or(0.0);
-        let (triggered, baseline_mean, baseline_stddev, z_score) = match rule.condition_type {
-            AlertConditionType::ThresholdAbove => {
-                (current_value >= rule.threshold_value, None, None, None)
-            }
-            AlertConditionType::ThresholdBelow => {
-                (current_value <= rule.threshold_value, None, None, None)
-            }
-            AlertConditionType::Spike | AlertConditionType::Drop => {
-                let mut baseline = Vec::with_capacity(rule.lookback_days as usize);
-                for offset in 1..=rule.lookback_days {
-                    let day = today - Duration::days(offset);
-                    baseline.push(daily_map.get(&day).copied().unwrap_or(0.0));
-                }
-                let Some(baseline_mean) = mean(&baseline) else {
-                    continue;
-                };
-                let baseline_stddev = stddev(&baseline, baseline_mean).unwrap_or(0.0);
-                if baseline_stddev <= f64::EPSILON {
-                    continue;
-                }
-                let z = (current_value - baseline_mean) / baseline_stddev;
-                let triggered = match rule.condition_type {
-                    AlertConditionType::Spike => z >= rule.threshold_value,
-                    AlertConditionType::Drop => z <= -rule.threshold_value,
-                    _ => false,
-                };
-                (
-                    triggered,
-                    Some(baseline_mean),
-                    Some(baseline_stddev),
-                    Some(z),
-                )
-            }
-        };
-
-        if !triggered {
+            .get_alert_metric_series(&rule, &window)
+            .await?
+            .into_iter()
+            .collect();
+        let Some(evaluation) = evaluate_alert_rule(&rule, &series, &window) else {
             continue;
-        }
+        };
+        let kind = match (evaluation.triggered, rule.is_firing) {
+            (true, _) if in_cooldown(&rule, now) => continue,
+            (true, _) => "alert",
+            (false, true) => "alert_resolved",
+            (false, false) => continue,
+        };
 
-        let idempotency_key = format!("alert:{}:{}", rule.id, today.format("%Y%m%d"));
+        let bucket_format = match rule.granularity {
+            AlertGranularity::Day => "%Y%m%d",
+            AlertGranularity::Hour => "%Y%m%d%H",
+        };
+        let bucket = window.current.format(bucket_format);
+        // A trigger from a resting rule opens the next episode, so it is not
+        // deduped against an earlier episode in the same bucket.
+        let idempotency_key = match kind {
+            "alert" => {
+                let episode = rule.firing_episode + i64::from(!rule.is_firing);
+                format!("alert:{}:{bucket}:{episode}", rule.id)
+            }
+            _ => format!(
+                "alert-resolved:{}:{bucket}:{}",
+                rule.id, rule.firing_episode
+            ),
+        };
+        let label_format = match rule.granularity {
+            AlertGranularity::Day => "%Y-%m-%d",
+            AlertGranularity::Hour => "%Y-%m-%dT%H:00",
+        };
+        let buckets: Vec<NaiveDateTime> = window.buckets().collect();
+        let history: Vec<_> = buckets[buckets.len().saturating_sub(MAX_HISTORY_POINTS + 1)..]
+            .iter()
+            .map(|bucket| {
+                json!({
+                    "date": bucket.format(label_format).to_string(),
+                    "value": series.get(bucket).copied().unwrap_or(0.0),
+                })
+            })
+            .collect();
         let payload = json!({
-            "kind": "alert",
+            "kind": kind,
             "website_id": rule.website_id,
+            "website_name": super::website_name(state, &rule.website_id).await,
             "alert_id": rule.id,
             "name": rule.name,
             "metric": rule.metric,
+            "event_name": rule.event_name,
+            "filters": rule.filters,
+            "granularity": rule.granularity,
+            "period_start": window.current.and_utc().to_rfc3339(),
             "condition_type": rule.condition_type,
             "threshold_value": rule.threshold_value,
-            "current_value": current_value,
-            "baseline_mean": baseline_mean,
-            "baseline_stddev": baseline_stddev,
-            "z_score": z_score,
-            "triggered_at": Utc::now().to_rfc3339(),
+            "baseline": rule.baseline,
+            "current_value": evaluation.metric_value,
+            "baseline_mean": evaluation.baseline_mean,
+            "baseline_stddev": evaluation.baseline_stddev,
+            "z_score": evaluation.z_score,
+            "expected_value": evaluation.expected_value,
+            "lower_bound": evaluation.lower_bound,
+            "upper_bound": evaluation.upper_bound,
+            "history": history,
+            "triggered_at": now.to_rfc3339(),
         });
 
         let delivered = deliver_and_record(
@@ -114,11 +111,18 @@ pub async fn run_alert_checks(state: &Arc<AppState>) -> anyhow::Result<usize> {
             NotificationSourceType::Alert,
             &rule.id,
             &idempotency_key,
-            rule.channel,
-            rule.target,
+            Destination {
+                channel: rule.channel,
+                target: rule.target,
+                signing_secret: rule.signing_secret,
+            },
             payload,
         )
         .await?;
+        state
+            .scheduler_db
+            .set_alert_rule_firing(&rule.id, evaluation.triggered, now)
+            .await?;
         if delivered.is_some() {
             deliveries += 1;
         }
@@ -134,8 +138,8 @@ mod tests {
     use chrono::{Duration, Utc};
     use sparklytics_core::{
         analytics::{
-            AlertConditionType, AlertMetric, CreateAlertRuleRequest, NotificationChannel,
-            UpdateAlertRuleRequest,
+            AlertBaseline, AlertConditionType, AlertFilters, AlertMetric, AnalyticsBackend,
+            CreateAlertRuleRequest, NotificationChannel, UpdateAlertRuleRequest,
         },
         config::{AppMode, AuthMode, Config},
         event::Event,
@@ -278,6 +282,11 @@ mod tests {
                     condition_type: AlertConditionType::Spike,
                     threshold_value: 2.0,
                     lookback_days: Some(3),
+                    baseline: None,
+                    event_name: None,
+                    filters: None,
+                    granularity: None,
+                    cooldown_minutes: None,
                     channel: NotificationChannel::Email,
                     target: "ops@example.com".to_string(),
                 },
@@ -294,6 +303,11 @@ mod tests {
                     condition_type: AlertConditionType::ThresholdAbove,
                     threshold_value: 5.0,
                     lookback_days: Some(3),
+                    baseline: None,
+                    event_name: None,
+                    filters: None,
+                    granularity: None,
+                    cooldown_minutes: None,
                     channel: NotificationChannel::Email,
                     target: "ops@example.com".to_string(),
                 },
@@ -310,6 +324,11 @@ mod tests {
                     condition_type: AlertConditionType::ThresholdBelow,
                     threshold_value: 5.0,
                     lookback_days: Some(3),
+                    baseline: None,
+                    event_name: None,
+                    filters: None,
+                    granularity: None,
+                    cooldown_minutes: None,
                     channel: NotificationChannel::Email,
                     target: "ops@example.com".to_string(),
                 },
@@ -338,6 +357,11 @@ mod tests {
                     condition_type: AlertConditionType::Drop,
                     threshold_value: 2.0,
                     lookback_days: Some(3),
+                    baseline: None,
+                    event_name: None,
+                    filters: None,
+                    granularity: None,
+                    cooldown_minutes: None,
                     channel: NotificationChannel::Email,
                     target: "ops@example.com".to_string(),
                 },
@@ -370,4 +394,218 @@ mod tests {
         );
         assert_eq!(drop_history.len(), 1, "drop-site rule should trigger once");
     }
+
+    #[tokio::test]
+    async fn seasonal_anomaly_rules_compare_against_the_same_weekday() {
+        let data_dir = unique_data_dir();
+        std::fs::create_dir_all(&data_dir).expect("create temp dir");
+        let db_path = format!("{data_dir}/sparklytics.db");
+        let db = DuckDbBackend::open(&db_path, "1GB").expect("open db");
+        let state = Arc::new(AppState::new(db, test_config(data_dir)));
+
+        let steady_site = "site_steady";
+        let quiet_site = "site_quiet";
+        let today = Utc::now().date_naive();
+        for (website_id, today_count) in [(steady_site, 10), (quiet_site, 1)] {
+            state
+                .db
+                .seed_website(website_id, &format!("{website_id}.example.com"))
+                .await
+                .expect("seed website");
+            // Busy on this weekday, quiet on every other day.
+            let mut events = Vec::new();
+            for offset in 0..=28 {
+                let day = today - Duration::days(offset);
+                let count = match offset {
+                    0 => today_count,
+                    o if o % 7 == 0 => 10,
+                    _ => 1,
+                };
+                for idx in 0..count {
+                    let mut event = make_pageview(website_id, day, idx);
+                    event.created_at = day.and_hms_opt(0, 0, 0).expect("midnight").and_utc()
+                        + Duration::milliseconds(idx);
+                    events.push(event);
+                }
+            }
+            state
+                .db
+                .insert_events(&events)
+                .await
+                .expect("insert events");
+            state
+                .db
+                .create_alert_rule(
+                    website_id,
+                    CreateAlertRuleRequest {
+                        name: "Unusual traffic".to_string(),
+                        metric: AlertMetric::Pageviews,
+                        condition_type: AlertConditionType::Anomaly,
+                        threshold_value: 3.0,
+                        lookback_days: Some(28),
+                        baseline: Some(AlertBaseline::SameWeekday),
+                        event_name: None,
+                        filters: None,
+                        granularity: None,
+                        cooldown_minutes: None,
+                        channel: NotificationChannel::Email,
+                        target: "ops@example.com".to_string(),
+                    },
+                )
+                .await
+                .expect("create anomaly rule");
+        }
+
+        let steady = state
+            .db
+            .evaluate_alert_rules(None, steady_site)
+            .await
+            .expect("evaluate steady site");
+        assert_eq!(steady.len(), 1);
+        assert!(!steady[0].triggered, "a normal busy weekday is not a spike");
+        assert_eq!(steady[0].expected_value, Some(10.0));
+        assert!(steady[0].lower_bound.is_some_and(|lower| lower < 10.0));
+
+        assert_eq!(run_alert_checks(&state).await.expect("run"), 1);
+        let quiet_history = state
+            .db
+            .list_notification_deliveries_for_website(quiet_site, 20)
+            .await
+            .expect("quiet history");
+        assert_eq!(quiet_history.len(), 1, "only the quiet weekday triggers");
+    }
+
+    #[tokio::test]
+    async fn filtered_custom_event_rules_resolve_once_back_to_normal() {
+        let data_dir = unique_data_dir();
+        std::fs::create_dir_all(&data_dir).expect("create temp dir");
+        let db_path = format!("{data_dir}/sparklytics.db");
+        let db = DuckDbBackend::open(&db_path, "1GB").expect("open db");
+        let state = Arc::new(AppState::new(db, test_config(data_dir)));
+
+        let website_id = "site_checkout";
+        state
+            .db
+            .seed_website(website_id, "checkout.example.com")
+            .await
+            .expect("seed website");
+        let today = Utc::now().date_naive();
+        let events: Vec<Event> = (0..15)
+            .map(|idx| {
+                let mut event = make_pageview(website_id, today, idx);
+                event.event_type = "event".to_string();
+                event.event_name = Some("signup".to_string());
+                if idx < 5 {
+                    event.url = "https://example.com/checkout".to_string();
+                }
+                event
+            })
+            .collect();
+        state
+            .db
+            .insert_events(&events)
+            .await
+            .expect("insert events");
+
+        let rule = state
+            .db
+            .create_alert_rule(
+                website_id,
+                CreateAlertRuleRequest {
+                    name: "Checkout signups".to_string(),
+                    metric: AlertMetric::CustomEvents,
+                    condition_type: AlertConditionType::ThresholdAbove,
+                    threshold_value: 3.0,
+                    lookback_days: Some(3),
+                    baseline: None,
+                    event_name: Some("signup".to_string()),
+                    filters: Some(AlertFilters {
+                        filter_page: Some("/checkout".to_string()),
+                        ..AlertFilters::default()
+                    }),
+                    granularity: None,
+                    cooldown_minutes: Some(0),
+                    channel: NotificationChannel::Email,
+                    target: "ops@example.com".to_string(),
+                },
+            )
+            .await
+            .expect("create custom event rule");
+
+        let evaluation = state
+            .db
+            .evaluate_alert_rules(None, website_id)
+            .await
+            .expect("evaluate rule");
+        assert_eq!(
+            evaluation[0].metric_value, 5.0,
+            "only checkout signups count"
+        );
+        assert_eq!(run_alert_checks(&state).await.expect("trigger"), 1);
+        let firing = state
+            .db
+            .get_alert_rule(website_id, &rule.id)
+            .await
+            .expect("get rule")
+            .expect("rule exists");
+        assert!(firing.is_firing);
+        assert!(firing.last_triggered_at.is_some());
+
+        state
+            .db
+            .update_alert_rule(
+                website_id,
+                &rule.id,
+                UpdateAlertRuleRequest {
+                    threshold_value: Some(10.0),
+                    ..UpdateAlertRuleRequest::default()
+                },
+            )
+            .await
+            .expect("raise threshold");
+        assert_eq!(run_alert_checks(&state).await.expect("resolve"), 1);
+        assert_eq!(run_alert_checks(&state).await.expect("steady"), 0);
+        let resolved = state
+            .db
+            .get_alert_rule(website_id, &rule.id)
+            .await
+            .expect("get rule")
+            .expect("rule exists");
+        assert!(!resolved.is_firing);
+        let history = state
+            .db
+            .list_notification_deliveries_for_website(website_id, 20)
+            .await
+            .expect("history");
+        assert_eq!(history.len(), 2, "one trigger and one resolve notification");
+
+        // Firing again in the same bucket opens a new episode.
+        state
+            .db
+            .update_alert_rule(
+                website_id,
+                &rule.id,
+                UpdateAlertRuleRequest {
+                    threshold_value: Some(3.0),
+                    ..UpdateAlertRuleRequest::default()
+                },
+            )
+            .await
+            .expect("lower threshold");
+        assert_eq!(run_alert_checks(&state).await.expect("re-trigger"), 1);
+        let refired = state
+            .db
+            .get_alert_rule(website_id, &rule.id)
+            .await
+            .expect("get rule")
+            .expect("rule exists");
+        assert!(refired.is_firing);
+        assert_eq!(refired.firing_episode, 2);
+        let history = state
+            .db
+            .list_notification_deliveries_for_website(website_id, 20)
+            .await
+            .expect("history");
+        assert_eq!(history.len(), 3);
+    }
 }
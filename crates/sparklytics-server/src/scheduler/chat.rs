@@ -0,0 +1,242 @@
+//! Message formats for the Slack, Microsoft Teams and Discord channels.
+
+use serde_json::{json, Value};
+use sparklytics_core::analytics::NotificationChannel;
+
+use super::render::{summarize, Summary};
+
+/// Slack allows at most ten fields per section block.
+const SLACK_MAX_FIELDS: usize = 10;
+/// Discord allows at most 25 fields per embed.
+const DISCORD_MAX_FIELDS: usize = 25;
+const DISCORD_ALERT_COLOR: u32 = 0xE5484D;
+const DISCORD_REPORT_COLOR: u32 = 0x3B82F6;
+
+/// Request body for a chat channel, or `None` for channels that send the raw
+/// payload (email, generic webhooks).
+pub fn chat_body(
+    channel: &NotificationChannel,
+    payload: &Value,
+    public_url: &str,
+) -> Option<Value> {
+    let render = match channel {
+        NotificationChannel::Slack => slack_message,
+        NotificationChannel::Teams => teams_message,
+        NotificationChannel::Discord => discord_message,
+        NotificationChannel::Email | NotificationChannel::Webhook => return None,
+    };
+    Some(render(&summarize(payload, public_url)))
+}
+
+/// Slack Block Kit message for an incoming webhook.
+pub fn slack_message(summary: &Summary) -> Value {
+    let mut blocks = vec![
+        json!({
+            "type": "header",
+            "text": { "type": "plain_text", "text": truncate(&summary.title, 150), "emoji": true }
+        }),
+        json!({
+            "type": "context",
+            "elements": [{ "type": "mrkdwn", "text": slack_escape(&summary.subtitle) }]
+        }),
+    ];
+    for chunk in summary.facts.chunks(SLACK_MAX_FIELDS) {
+        let fields: Vec<Value> = chunk
+            .iter()
+            .map(|fact| {
+                json!({
+                    "type": "mrkdwn",
+                    "text": format!("*{}*\n{}", slack_escape(&fact.label), slack_escape(&fact.display())),
+                })
+            })
+            .collect();
+        blocks.push(json!({ "type": "section", "fields": fields }));
+    }
+    blocks.push(json!({
+        "type": "actions",
+        "elements": [{
+            "type": "button",
+            "text": { "type": "plain_text", "text": summary.link_label },
+            "url": summary.link,
+        }]
+    }));
+    json!({
+        "text": format!("{} — {}", summary.title, summary.subtitle),
+        "blocks": blocks,
+    })
+}
+
+/// Adaptive Card wrapped in a message, as accepted by Teams workflow and
+/// incoming webhooks.
+pub fn teams_message(summary: &Summary) -> Value {
+    let facts: Vec<Value> = summary
+        .facts
+        .iter()
+        .map(|fact| json!({ "title": fact.label, "value": fact.display() }))
+        .collect();
+    json!({
+        "type": "message",
+        "attachments": [{
+            "contentType": "application/vnd.microsoft.card.adaptive",
+            "contentUrl": null,
+            "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": "1.4",
+                "body": [
+                    {
+                        "type": "TextBlock",
+                        "text": summary.title,
+                        "size": "Medium",
+                        "weight": "Bolder",
+                        "color": if summary.is_alert { "Attention" } else { "Default" },
+                        "wrap": true
+                    },
+                    { "type": "TextBlock", "text": summary.subtitle, "isSubtle": true, "wrap": true },
+                    { "type": "FactSet", "facts": facts }
+                ],
+                "actions": [{ "type": "Action.OpenUrl", "title": summary.link_label, "url": summary.link }]
+            }
+        }]
+    })
+}
+
+/// Discord webhook message with a single embed.
+pub fn discord_message(summary: &Summary) -> Value {
+    let fields: Vec<Value> = summary
+        .facts
+        .iter()
+        .take(DISCORD_MAX_FIELDS)
+        .map(|fact| {
+            json!({
+                "name": truncate(&fact.label, 256),
+                "value": truncate(&fact.display(), 1024),
+                "inline": true,
+            })
+        })
+        .collect();
+    let mut embed = json!({
+        "title": truncate(&summary.title, 256),
+        "url": summary.link,
+        "description": summary.subtitle,
+        "color": if summary.is_alert { DISCORD_ALERT_COLOR } else { DISCORD_REPORT_COLOR },
+        "fields": fields,
+        "footer": { "text": "Sparklytics" },
+    });
+    if let Some(timestamp) = &summary.timestamp {
+        embed["timestamp"] = json!(timestamp);
+    }
+    json!({ "embeds": [embed] })
+}
+
+/// Slack mrkdwn treats `&`, `<` and `>` as control characters.
+fn slack_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut out: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn report_payload() -> Value {
+        let rows: Vec<Value> = (0..12)
+            .map(|idx| json!({ "value": format!("/page-{idx}"), "visitors": 100 - idx, "prev_visitors": 50 }))
+            .collect();
+        json!({
+            "kind": "report_subscription",
+            "website_id": "site_1",
+            "report_name": "Top pages <weekly>",
+            "report_type": "metrics",
+            "generated_at": "2026-10-12T09:00:00Z",
+            "data": { "type": "page", "rows": rows, "total": 12 }
+        })
+    }
+
+    #[test]
+    fn slack_messages_use_blocks_with_a_dashboard_button() {
+        let body = chat_body(
+            &NotificationChannel::Slack,
+            &report_payload(),
+            "https://analytics.example.com",
+        )
+        .expect("slack body");
+        let blocks = body["blocks"].as_array().expect("blocks");
+        assert_eq!(blocks[0]["type"], "header");
+        assert_eq!(blocks[0]["text"]["text"], "Top pages <weekly>");
+        assert_eq!(blocks[2]["fields"].as_array().map(Vec::len), Some(10));
+        assert_eq!(blocks[2]["fields"][0]["text"], "*/page-0*\n100 (+100.0%)");
+        let button = &blocks.last().expect("actions")["elements"][0];
+        assert_eq!(
+            button["url"],
+            "https://analytics.example.com/dashboard/site_1/reports"
+        );
+        assert!(body["text"]
+            .as_str()
+            .is_some_and(|text| text.starts_with("Top pages")));
+    }
+
+    #[test]
+    fn teams_messages_wrap_an_adaptive_card() {
+        let body = chat_body(
+            &NotificationChannel::Teams,
+            &report_payload(),
+            "https://analytics.example.com",
+        )
+        .expect("teams body");
+        let attachment = &body["attachments"][0];
+        assert_eq!(
+            attachment["contentType"],
+            "application/vnd.microsoft.card.adaptive"
+        );
+        let card = &attachment["content"];
+        assert_eq!(card["type"], "AdaptiveCard");
+        assert_eq!(card["body"][2]["facts"][0]["title"], "/page-0");
+        assert_eq!(card["actions"][0]["type"], "Action.OpenUrl");
+    }
+
+    #[test]
+    fn discord_messages_use_a_colored_embed() {
+        let body = chat_body(
+            &NotificationChannel::Discord,
+            &json!({
+                "kind": "alert_test",
+                "website_id": "site_1",
+                "name": "Low traffic",
+                "metric": "visitors",
+                "condition_type": "threshold_below",
+                "threshold_value": 10,
+                "triggered_at": "2026-10-12T09:00:00Z"
+            }),
+            "https://analytics.example.com",
+        )
+        .expect("discord body");
+        let embed = &body["embeds"][0];
+        assert_eq!(embed["title"], "Test alert: Low traffic");
+        assert_eq!(embed["description"], "Visitors — below 10");
+        assert_eq!(embed["color"], DISCORD_ALERT_COLOR);
+        assert_eq!(
+            embed["url"],
+            "https://analytics.example.com/dashboard/site_1"
+        );
+        assert_eq!(embed["timestamp"], "2026-10-12T09:00:00Z");
+    }
+
+    #[test]
+    fn raw_channels_are_not_rendered() {
+        assert!(chat_body(&NotificationChannel::Webhook, &report_payload(), "").is_none());
+        assert!(chat_body(&NotificationChannel::Email, &report_payload(), "").is_none());
+    }
+}
@@ -4,19 +4,98 @@ use std::{
     time::Duration,
 };
 
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
 use lettre::{
-    message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
-    AsyncTransport, Message, Tokio1Executor,
+    message::{header::ContentType, Attachment, Mailbox, MultiPart, SinglePart},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
 };
 use serde_json::Value;
+use sha2::Sha256;
 use sparklytics_core::analytics::{
     NotificationChannel, NotificationDelivery, NotificationDeliveryStatus, NotificationSourceType,
 };
+use sparklytics_duckdb::notifications::{NotificationSend, ResendableDelivery};
 use tracing::{info, warn};
 
+use super::{
+    chat::chat_body,
+    email::{render_email, EmailMessage},
+};
 use crate::state::AppState;
 
-fn is_valid_email(target: &str) -> bool {
+/// `v1=<hex HMAC-SHA256 of "{timestamp}.{body}">`, keyed by the signing secret.
+pub const SIGNATURE_HEADER: &str = "x-sparklytics-signature";
+/// Unix seconds when the attempt was signed; receivers should reject stale ones.
+pub const TIMESTAMP_HEADER: &str = "x-sparklytics-timestamp";
+/// The delivery's idempotency key, identical across retries and replays.
+pub const DELIVERY_HEADER: &str = "x-sparklytics-delivery";
+
+const RETRY_BASE_DELAY_SECONDS: i64 = 60;
+const RETRY_MAX_DELAY_SECONDS: i64 = 60 * 60;
+
+/// Where a subscription or alert sends its notifications.
+#[derive(Debug, Clone)]
+pub struct Destination {
+    pub channel: NotificationChannel,
+    pub target: String,
+    pub signing_secret: String,
+}
+
+#[derive(Debug)]
+struct DeliveryError {
+    message: String,
+    /// Whether trying again later could succeed.
+    retryable: bool,
+}
+
+impl DeliveryError {
+    fn permanent(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            retryable: false,
+        }
+    }
+
+    fn transient(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            retryable: true,
+        }
+    }
+}
+
+/// Attempts per delivery, including the first (`SPARKLYTICS_NOTIFICATION_MAX_ATTEMPTS`).
+fn max_delivery_attempts() -> i64 {
+    std::env::var("SPARKLYTICS_NOTIFICATION_MAX_ATTEMPTS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .map(|v| v.clamp(1, 20))
+        .unwrap_or(8)
+}
+
+/// Wait before attempt `attempt + 1`: one minute, doubling, capped at an hour.
+pub(crate) fn retry_delay(attempt: i64) -> chrono::Duration {
+    let exponent = attempt.saturating_sub(1).clamp(0, 30) as u32;
+    let seconds = RETRY_BASE_DELAY_SECONDS
+        .saturating_mul(2_i64.saturating_pow(exponent))
+        .min(RETRY_MAX_DELAY_SECONDS);
+    chrono::Duration::seconds(seconds)
+}
+
+pub(crate) fn webhook_signature(secret: &str, timestamp: i64, body: &str) -> String {
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        // HMAC accepts keys of any length.
+        return String::new();
+    };
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    format!("v1={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+pub(crate) fn is_valid_email(target: &str) -> bool {
     let trimmed = target.trim();
     let Some((local, domain)) = trimmed.split_once('@') else {
         return false;
@@ -24,8 +103,28 @@ fn is_valid_email(target: &str) -> bool {
     !local.is_empty() && domain.contains('.') && !domain.starts_with('.')
 }
 
-async fn deliver_email(target: String, payload: Value) -> Result<(), String> {
-    if !is_valid_email(&target) {
+async fn deliver_email(target: &str, body: &str, public_url: &str) -> Result<(), DeliveryError> {
+    if !is_valid_email(target) {
+        return Err(DeliveryError::permanent("invalid email target"));
+    }
+    let message = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|payload| render_email(&payload, public_url))
+        .unwrap_or_else(|| EmailMessage::plain("Sparklytics Notification", body));
+    send_email_message(target, message)
+        .await
+        .map_err(DeliveryError::transient)
+}
+
+/// Send a plain-text email through the configured SMTP server
+/// (`SPARKLYTICS_SMTP_*`), or only log it when `SPARKLYTICS_SMTP_NOOP` is set.
+pub async fn send_email(target: &str, subject: &str, body: String) -> Result<(), String> {
+    send_email_message(target, EmailMessage::plain(subject, body)).await
+}
+
+/// Like [`send_email`], with an optional HTML alternative and inline images.
+pub async fn send_email_message(target: &str, message: EmailMessage) -> Result<(), String> {
+    if !is_valid_email(target) {
         return Err("invalid email target".to_string());
     }
     let smtp_noop_enabled = std::env::var("SPARKLYTICS_SMTP_NOOP")
@@ -50,20 +149,7 @@ async fn deliver_email(target: String, payload: Value) -> Result<(), String> {
         .ok()
         .and_then(|v| v.parse::<u16>().ok())
         .unwrap_or(587);
-    let from_value = std::env::var("SPARKLYTICS_SMTP_FROM")
-        .unwrap_or_else(|_| "sparklytics@localhost".to_string());
-    let from: Mailbox = from_value
-        .parse()
-        .map_err(|_| "invalid SPARKLYTICS_SMTP_FROM".to_string())?;
-    let to: Mailbox = target
-        .parse()
-        .map_err(|_| "invalid email target".to_string())?;
-    let email = Message::builder()
-        .from(from)
-        .to(to)
-        .subject("Sparklytics Notification")
-        .body(payload.to_string())
-        .map_err(|e| format!("smtp message build failed: {e}"))?;
+    let email = build_email(target, message)?;
 
     let mut builder = AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
         .port(port)
@@ -82,24 +168,65 @@ async fn deliver_email(target: String, payload: Value) -> Result<(), String> {
     Ok(())
 }
 
-async fn deliver_webhook(target: String, payload: Value) -> Result<(), String> {
-    let parsed = url::Url::parse(target.trim()).map_err(|_| "invalid webhook url".to_string())?;
+fn build_email(target: &str, message: EmailMessage) -> Result<Message, String> {
+    let from_value = std::env::var("SPARKLYTICS_SMTP_FROM")
+        .unwrap_or_else(|_| "sparklytics@localhost".to_string());
+    let from: Mailbox = from_value
+        .parse()
+        .map_err(|_| "invalid SPARKLYTICS_SMTP_FROM".to_string())?;
+    let to: Mailbox = target
+        .parse()
+        .map_err(|_| "invalid email target".to_string())?;
+    let builder = Message::builder()
+        .from(from)
+        .to(to)
+        .subject(message.subject);
+    match message.html {
+        Some(html) => {
+            let png = ContentType::parse("image/png").map_err(|e| e.to_string())?;
+            let mut related = MultiPart::related().singlepart(SinglePart::html(html));
+            for image in message.images {
+                related = related.singlepart(
+                    Attachment::new_inline(image.content_id).body(image.png, png.clone()),
+                );
+            }
+            builder.multipart(
+                MultiPart::alternative()
+                    .singlepart(SinglePart::plain(message.text))
+                    .multipart(related),
+            )
+        }
+        None => builder.body(message.text),
+    }
+    .map_err(|e| format!("smtp message build failed: {e}"))
+}
+
+async fn deliver_webhook(
+    target: &str,
+    body: &str,
+    signing_secret: &str,
+    idempotency_key: &str,
+) -> Result<(), DeliveryError> {
+    let parsed = url::Url::parse(target.trim())
+        .map_err(|_| DeliveryError::permanent("invalid webhook url"))?;
     let scheme = parsed.scheme();
     if scheme != "http" && scheme != "https" {
-        return Err("webhook url must use http or https".to_string());
+        return Err(DeliveryError::permanent(
+            "webhook url must use http or https",
+        ));
     }
-    let target_for_req = target.clone();
-    let payload_text = payload.to_string();
     let host = parsed
         .host_str()
-        .ok_or_else(|| "webhook url missing host".to_string())?;
+        .ok_or_else(|| DeliveryError::permanent("webhook url missing host"))?;
     if host.eq_ignore_ascii_case("localhost") {
-        return Err("webhook target host is not allowed".to_string());
+        return Err(DeliveryError::permanent(
+            "webhook target host is not allowed",
+        ));
     }
     let host_owned = host.to_string();
     let port = parsed
         .port_or_known_default()
-        .ok_or_else(|| "webhook url missing port".to_string())?;
+        .ok_or_else(|| DeliveryError::permanent("webhook url missing port"))?;
     let host_for_dns = host_owned.clone();
     let resolved: Vec<SocketAddr> = tokio::task::spawn_blocking(move || {
         (host_for_dns.as_str(), port)
@@ -107,13 +234,17 @@ async fn deliver_webhook(target: String, payload: Value) -> Result<(), String> {
             .map(|iter| iter.collect::<Vec<_>>())
     })
     .await
-    .map_err(|e| format!("webhook dns task join failed: {e}"))?
-    .map_err(|e| format!("webhook dns resolve failed: {e}"))?;
+    .map_err(|e| DeliveryError::transient(format!("webhook dns task join failed: {e}")))?
+    .map_err(|e| DeliveryError::transient(format!("webhook dns resolve failed: {e}")))?;
     if resolved.is_empty() {
-        return Err("webhook dns resolve returned no addresses".to_string());
+        return Err(DeliveryError::transient(
+            "webhook dns resolve returned no addresses",
+        ));
     }
     if resolved.iter().any(|addr| is_disallowed_ip(addr.ip())) {
-        return Err("webhook target resolves to non-public address".to_string());
+        return Err(DeliveryError::permanent(
+            "webhook target resolves to non-public address",
+        ));
     }
 
     let mut builder = reqwest::Client::builder()
@@ -127,19 +258,29 @@ async fn deliver_webhook(target: String, payload: Value) -> Result<(), String> {
     }
     let client = builder
         .build()
-        .map_err(|e| format!("webhook client build failed: {e}"))?;
+        .map_err(|e| DeliveryError::transient(format!("webhook client build failed: {e}")))?;
+    let timestamp = Utc::now().timestamp();
     let response = client
-        .post(&target_for_req)
+        .post(target)
         .header("content-type", "application/json")
-        .body(payload_text)
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(
+            SIGNATURE_HEADER,
+            webhook_signature(signing_secret, timestamp, body),
+        )
+        .header(DELIVERY_HEADER, idempotency_key)
+        .body(body.to_string())
         .send()
         .await
-        .map_err(|e| format!("webhook send failed: {e}"))?;
-    if !response.status().is_success() {
-        return Err(format!(
-            "webhook responded with status {}",
-            response.status()
-        ));
+        .map_err(|e| DeliveryError::transient(format!("webhook send failed: {e}")))?;
+    let status = response.status();
+    if !status.is_success() {
+        let message = format!("webhook responded with status {status}");
+        // Other 4xx answers will not change on their own.
+        let retryable = status.is_server_error()
+            || status == reqwest::StatusCode::REQUEST_TIMEOUT
+            || status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+        return Err(DeliveryError { message, retryable });
     }
     Ok(())
 }
@@ -177,14 +318,100 @@ fn is_disallowed_ip(ip: IpAddr) -> bool {
     }
 }
 
+async fn send(
+    channel: &NotificationChannel,
+    target: &str,
+    body: &str,
+    signing_secret: &str,
+    idempotency_key: &str,
+    public_url: &str,
+) -> Result<(), DeliveryError> {
+    match channel {
+        NotificationChannel::Email => deliver_email(target, body, public_url).await,
+        NotificationChannel::Webhook
+        | NotificationChannel::Slack
+        | NotificationChannel::Teams
+        | NotificationChannel::Discord => {
+            deliver_webhook(target, body, signing_secret, idempotency_key).await
+        }
+    }
+}
+
+/// Status and next attempt after attempt number `attempt` ended with `result`.
+fn outcome(
+    result: Result<(), DeliveryError>,
+    attempt: i64,
+    retry: bool,
+    now: DateTime<Utc>,
+) -> (
+    NotificationDeliveryStatus,
+    Option<String>,
+    Option<DateTime<Utc>>,
+) {
+    match result {
+        Ok(()) => (NotificationDeliveryStatus::Sent, None, None),
+        Err(err) if retry && err.retryable && attempt < max_delivery_attempts() => (
+            NotificationDeliveryStatus::Retrying,
+            Some(err.message),
+            Some(now + retry_delay(attempt)),
+        ),
+        Err(err) => (NotificationDeliveryStatus::Failed, Some(err.message), None),
+    }
+}
+
+/// Send a notification once per idempotency key and record the outcome.
+/// Transient failures are retried with exponential backoff by
+/// [`retry_due_deliveries`].
 pub async fn deliver_and_record(
     state: &Arc<AppState>,
     source_type: NotificationSourceType,
     source_id: &str,
     idempotency_key: &str,
-    channel: NotificationChannel,
-    target: String,
+    destination: Destination,
+    payload: Value,
+) -> anyhow::Result<Option<NotificationDelivery>> {
+    record_first_attempt(
+        state,
+        source_type,
+        source_id,
+        idempotency_key,
+        destination,
+        payload,
+        true,
+    )
+    .await
+}
+
+/// Like [`deliver_and_record`], but a failure is final. Used for test sends,
+/// where the caller is waiting on the result.
+pub async fn deliver_once_and_record(
+    state: &Arc<AppState>,
+    source_type: NotificationSourceType,
+    source_id: &str,
+    idempotency_key: &str,
+    destination: Destination,
     payload: Value,
+) -> anyhow::Result<Option<NotificationDelivery>> {
+    record_first_attempt(
+        state,
+        source_type,
+        source_id,
+        idempotency_key,
+        destination,
+        payload,
+        false,
+    )
+    .await
+}
+
+async fn record_first_attempt(
+    state: &Arc<AppState>,
+    source_type: NotificationSourceType,
+    source_id: &str,
+    idempotency_key: &str,
+    destination: Destination,
+    payload: Value,
+    retry: bool,
 ) -> anyhow::Result<Option<NotificationDelivery>> {
     if state
         .scheduler_db
@@ -194,37 +421,108 @@ pub async fn deliver_and_record(
         return Ok(None);
     }
 
-    let result = match channel {
-        NotificationChannel::Email => deliver_email(target, payload).await,
-        NotificationChannel::Webhook => deliver_webhook(target, payload).await,
-    };
-
-    let (status, error_message) = match result {
-        Ok(()) => (NotificationDeliveryStatus::Sent, None),
-        Err(err) => {
-            warn!(
-                source_type = ?source_type,
-                source_id = source_id,
-                error = %err,
-                "notification delivery failed"
-            );
-            (NotificationDeliveryStatus::Failed, Some(err))
-        }
-    };
+    let body = chat_body(&destination.channel, &payload, &state.config.public_url)
+        .unwrap_or(payload)
+        .to_string();
+    let result = send(
+        &destination.channel,
+        &destination.target,
+        &body,
+        &destination.signing_secret,
+        idempotency_key,
+        &state.config.public_url,
+    )
+    .await;
+    if let Err(err) = &result {
+        warn!(
+            source_type = ?source_type,
+            source_id = source_id,
+            error = %err.message,
+            "notification delivery failed"
+        );
+    }
+    let (status, error_message, next_attempt_at) = outcome(result, 1, retry, Utc::now());
 
     let delivery = state
         .scheduler_db
-        .create_notification_delivery(
+        .create_sent_notification_delivery(
             source_type,
             source_id,
             idempotency_key,
+            NotificationSend {
+                channel: &destination.channel,
+                target: &destination.target,
+                payload: &body,
+            },
             status,
             error_message.as_deref(),
+            next_attempt_at,
         )
         .await?;
     Ok(Some(delivery))
 }
 
+/// Send a recorded delivery again with its stored payload and the source's
+/// current signing secret. When `retry` is set and attempts remain, a
+/// transient failure schedules another attempt.
+pub async fn resend(
+    state: &Arc<AppState>,
+    resendable: ResendableDelivery,
+    retry: bool,
+) -> anyhow::Result<NotificationDelivery> {
+    let ResendableDelivery {
+        delivery,
+        channel,
+        target,
+        payload,
+        signing_secret,
+    } = resendable;
+    let result = send(
+        &channel,
+        &target,
+        &payload,
+        &signing_secret,
+        &delivery.idempotency_key,
+        &state.config.public_url,
+    )
+    .await;
+    if let Err(err) = &result {
+        warn!(
+            delivery_id = %delivery.id,
+            attempt = delivery.attempt_count + 1,
+            error = %err.message,
+            "notification delivery retry failed"
+        );
+    }
+    let (status, error_message, next_attempt_at) =
+        outcome(result, delivery.attempt_count + 1, retry, Utc::now());
+    state
+        .scheduler_db
+        .record_notification_delivery_attempt(
+            &delivery.id,
+            status,
+            error_message.as_deref(),
+            next_attempt_at,
+        )
+        .await
+}
+
+/// Run every retry that is due at `now`. Returns how many were attempted.
+pub async fn retry_due_deliveries(
+    state: &Arc<AppState>,
+    now: DateTime<Utc>,
+) -> anyhow::Result<usize> {
+    let due = state
+        .scheduler_db
+        .list_due_notification_retries(now, 100)
+        .await?;
+    let attempted = due.len();
+    for resendable in due {
+        resend(state, resendable, true).await?;
+    }
+    Ok(attempted)
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -232,7 +530,7 @@ mod tests {
     use sparklytics_core::config::{AuthMode, Config};
     use sparklytics_duckdb::DuckDbBackend;
 
-    use crate::state::AppState;
+    use crate::{scheduler::email::InlineImage, state::AppState};
 
     use super::*;
 
@@ -282,8 +580,11 @@ mod tests {
             NotificationSourceType::Alert,
             "alert_test",
             key,
-            NotificationChannel::Email,
-            "team@example.com".to_string(),
+            Destination {
+                channel: NotificationChannel::Email,
+                target: "team@example.com".to_string(),
+                signing_secret: "whsec_test".to_string(),
+            },
             serde_json::json!({"hello": "world"}),
         )
         .await
@@ -295,12 +596,66 @@ mod tests {
             NotificationSourceType::Alert,
             "alert_test",
             key,
-            NotificationChannel::Email,
-            "team@example.com".to_string(),
+            Destination {
+                channel: NotificationChannel::Email,
+                target: "team@example.com".to_string(),
+                signing_secret: "whsec_test".to_string(),
+            },
             serde_json::json!({"hello": "world"}),
         )
         .await
         .expect("second delivery");
         assert!(second.is_none(), "second delivery should be skipped");
     }
+
+    #[test]
+    fn webhook_signature_covers_timestamp_and_body() {
+        let signature = webhook_signature("whsec_test", 1_700_000_000, r#"{"a":1}"#);
+        assert_eq!(
+            signature,
+            "v1=38877139021993b830af32feea6e18a8da83eb2f6e49ee50bd9e4cf4ca4d3789"
+        );
+        assert_ne!(
+            signature,
+            webhook_signature("whsec_test", 1_700_000_001, r#"{"a":1}"#)
+        );
+        assert_ne!(
+            signature,
+            webhook_signature("whsec_other", 1_700_000_000, r#"{"a":1}"#)
+        );
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_an_hour() {
+        assert_eq!(retry_delay(1), chrono::Duration::minutes(1));
+        assert_eq!(retry_delay(2), chrono::Duration::minutes(2));
+        assert_eq!(retry_delay(4), chrono::Duration::minutes(8));
+        assert_eq!(retry_delay(7), chrono::Duration::hours(1));
+        assert_eq!(retry_delay(40), chrono::Duration::hours(1));
+    }
+
+    #[test]
+    fn html_emails_are_multipart_with_inline_images() {
+        let message = EmailMessage {
+            subject: "Weekly overview — Acme, Oct 6 – Oct 12, 2026".to_string(),
+            text: "Pageviews: 1,200".to_string(),
+            html: Some(r#"<img src="cid:sparkline-1@sparklytics">"#.to_string()),
+            images: vec![InlineImage {
+                content_id: "sparkline-1@sparklytics".to_string(),
+                png: b"\x89PNG".to_vec(),
+            }],
+        };
+        let formatted = String::from_utf8(
+            build_email("ops@example.com", message)
+                .expect("build email")
+                .formatted(),
+        )
+        .expect("utf8 message");
+        assert!(formatted.contains("multipart/alternative"));
+        assert!(formatted.contains("multipart/related"));
+        assert!(formatted.contains("Content-Type: text/plain"));
+        assert!(formatted.contains("Content-Type: text/html"));
+        assert!(formatted.contains("Content-ID: <sparkline-1@sparklytics>"));
+        assert!(formatted.contains("Content-Disposition: inline"));
+    }
 }
@@ -0,0 +1,379 @@
+//! Multipart (HTML + plain text) emails for report subscriptions and alerts.
+
+use chrono::{DateTime, Datelike, NaiveDate};
+use serde_json::Value;
+
+use super::{
+    render::{summarize, Fact, Summary},
+    sparkline::{sparkline_png, HEIGHT as SPARKLINE_HEIGHT, WIDTH as SPARKLINE_WIDTH},
+};
+
+const SPARKLINE_COLOR: [u8; 3] = [59, 130, 246];
+const POSITIVE_COLOR: &str = "#16a34a";
+const NEGATIVE_COLOR: &str = "#dc2626";
+
+/// A PNG attached inline and referenced from the HTML as `cid:<content_id>`.
+#[derive(Debug, Clone)]
+pub struct InlineImage {
+    pub content_id: String,
+    pub png: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub subject: String,
+    pub text: String,
+    /// HTML alternative; plain-text only when `None`.
+    pub html: Option<String>,
+    pub images: Vec<InlineImage>,
+}
+
+impl EmailMessage {
+    pub fn plain(subject: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            text: text.into(),
+            html: None,
+            images: Vec::new(),
+        }
+    }
+}
+
+/// Render a scheduler payload as an email, or `None` if it is not an alert or
+/// report payload.
+pub fn render_email(payload: &Value, public_url: &str) -> Option<EmailMessage> {
+    let kind = payload.get("kind").and_then(Value::as_str)?;
+    if !kind.starts_with("alert") && !kind.starts_with("report_subscription") {
+        return None;
+    }
+    let summary = summarize(payload, public_url);
+    let website = payload
+        .get("website_name")
+        .and_then(Value::as_str)
+        .or_else(|| payload.get("website_id").and_then(Value::as_str))
+        .unwrap_or("your website");
+    let period = period_label(payload);
+    let subject = subject(payload, website, &period);
+
+    let mut images = Vec::new();
+    let mut row_images = Vec::with_capacity(summary.facts.len());
+    let series = sparkline_series(payload);
+    for fact in &summary.facts {
+        let png = series
+            .iter()
+            .find(|(label, _)| *label == fact.label)
+            .and_then(|(_, values)| sparkline_png(values, SPARKLINE_COLOR));
+        row_images.push(png.map(|png| {
+            let content_id = format!("sparkline-{}@sparklytics", images.len() + 1);
+            images.push(InlineImage {
+                content_id: content_id.clone(),
+                png,
+            });
+            content_id
+        }));
+    }
+
+    Some(EmailMessage {
+        subject,
+        text: text_body(&summary, website, &period),
+        html: Some(html_body(&summary, website, &period, &row_images)),
+        images,
+    })
+}
+
+fn subject(payload: &Value, website: &str, period: &str) -> String {
+    let kind = payload
+        .get("kind")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let prefix = if kind.ends_with("_test") { "[Test] " } else { "" };
+    let name = if kind.starts_with("alert") {
+        let name = payload
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or("Alert");
+        if kind == "alert_resolved" {
+            format!("Resolved: {name}")
+        } else {
+            format!("Alert: {name}")
+        }
+    } else {
+        payload
+            .get("report_name")
+            .and_then(Value::as_str)
+            .unwrap_or("Report")
+            .to_string()
+    };
+    format!("{prefix}{name} — {website}, {period}")
+}
+
+/// `Oct 6 – Oct 12, 2026` for a report's period, or the day an alert fired.
+fn period_label(payload: &Value) -> String {
+    let date = |value: Option<&Value>| {
+        value
+            .and_then(Value::as_str)
+            .and_then(|raw| NaiveDate::parse_from_str(raw, "%Y-%m-%d").ok())
+    };
+    let period = payload.get("period");
+    let start = date(period.and_then(|p| p.get("start_date")));
+    let end = date(period.and_then(|p| p.get("end_date")));
+    let fallback = ["triggered_at", "generated_at"]
+        .iter()
+        .filter_map(|key| payload.get(*key).and_then(Value::as_str))
+        .find_map(|raw| DateTime::parse_from_rfc3339(raw).ok())
+        .map(|ts| ts.date_naive());
+    match (start, end) {
+        (Some(start), Some(end)) if start != end => {
+            if start.year() == end.year() {
+                format!("{} – {}", start.format("%b %-d"), end.format("%b %-d, %Y"))
+            } else {
+                format!(
+                    "{} – {}",
+                    start.format("%b %-d, %Y"),
+                    end.format("%b %-d, %Y")
+                )
+            }
+        }
+        (Some(day), _) | (None, Some(day)) => day.format("%b %-d, %Y").to_string(),
+        (None, None) => fallback
+            .map(|day| day.format("%b %-d, %Y").to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// Daily values to draw next to facts with the same label.
+fn sparkline_series(payload: &Value) -> Vec<(&'static str, Vec<f64>)> {
+    let column = |points: Option<&Value>, key: &str| -> Vec<f64> {
+        points
+            .and_then(Value::as_array)
+            .map(|points| {
+                points
+                    .iter()
+                    .filter_map(|p| p.get(key).and_then(Value::as_f64))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let kind = payload
+        .get("kind")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    if kind.starts_with("alert") {
+        return vec![("Current value", column(payload.get("history"), "value"))];
+    }
+    let points = match payload.get("report_type").and_then(Value::as_str) {
+        Some("pageviews") => payload.get("data").map(|data| {
+            // With a comparison range the series are nested under `data`.
+            data.get("data").unwrap_or(data).get("series")
+        }),
+        Some("stats") | None => Some(payload.get("trend")),
+        _ => None,
+    }
+    .flatten();
+    vec![
+        ("Pageviews", column(points, "pageviews")),
+        ("Visitors", column(points, "visitors")),
+    ]
+}
+
+fn text_body(summary: &Summary, website: &str, period: &str) -> String {
+    let mut text = format!(
+        "{}\n{}\n{website} · {period}\n\n",
+        summary.title, summary.subtitle
+    );
+    for fact in &summary.facts {
+        text.push_str(&format!("{}: {}\n", fact.label, fact.display()));
+    }
+    text.push_str(&format!("\n{}: {}\n", summary.link_label, summary.link));
+    text
+}
+
+fn html_body(
+    summary: &Summary,
+    website: &str,
+    period: &str,
+    row_images: &[Option<String>],
+) -> String {
+    let rows: String = summary
+        .facts
+        .iter()
+        .zip(row_images)
+        .map(|(fact, image)| fact_row(fact, image.as_deref()))
+        .collect();
+    let accent = if summary.is_alert {
+        NEGATIVE_COLOR
+    } else {
+        "#3b82f6"
+    };
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><meta name="viewport" content="width=device-width"><title>{title}</title></head>
+<body style="margin:0;padding:0;background:#f4f4f5;">
+<table role="presentation" width="100%" cellpadding="0" cellspacing="0" style="background:#f4f4f5;padding:24px 0;">
+<tr><td align="center">
+<table role="presentation" width="560" cellpadding="0" cellspacing="0" style="max-width:560px;background:#ffffff;border-top:4px solid {accent};border-radius:6px;font-family:-apple-system,'Segoe UI',Helvetica,Arial,sans-serif;color:#18181b;">
+<tr><td style="padding:24px 24px 8px;">
+<div style="font-size:12px;color:#71717a;text-transform:uppercase;letter-spacing:0.04em;">{website} · {period}</div>
+<h1 style="margin:6px 0 0;font-size:20px;line-height:1.3;">{title}</h1>
+<p style="margin:4px 0 0;font-size:14px;color:#52525b;">{subtitle}</p>
+</td></tr>
+<tr><td style="padding:12px 24px;">
+<table role="presentation" width="100%" cellpadding="0" cellspacing="0" style="border-collapse:collapse;font-size:14px;">
+{rows}</table>
+</td></tr>
+<tr><td style="padding:12px 24px 24px;">
+<a href="{link}" style="display:inline-block;padding:10px 16px;background:#18181b;color:#ffffff;text-decoration:none;border-radius:6px;font-size:14px;">{link_label}</a>
+</td></tr>
+</table>
+<p style="margin:12px 0 0;font-size:12px;color:#a1a1aa;font-family:Helvetica,Arial,sans-serif;">Sent by Sparklytics</p>
+</td></tr>
+</table>
+</body>
+</html>
+"#,
+        title = escape(&summary.title),
+        subtitle = escape(&summary.subtitle),
+        website = escape(website),
+        period = escape(period),
+        link = escape(&summary.link),
+        link_label = escape(&summary.link_label),
+    )
+}
+
+fn fact_row(fact: &Fact, image: Option<&str>) -> String {
+    let delta = match fact.delta.as_deref() {
+        Some(delta) => {
+            let color = if delta.starts_with('-') {
+                NEGATIVE_COLOR
+            } else {
+                POSITIVE_COLOR
+            };
+            format!(r#"<span style="color:{color};">{}</span>"#, escape(delta))
+        }
+        None => String::new(),
+    };
+    let sparkline = match image {
+        Some(cid) => format!(
+            r#"<img src="cid:{cid}" width="{SPARKLINE_WIDTH}" height="{SPARKLINE_HEIGHT}" alt="" style="display:block;border:0;">"#
+        ),
+        None => String::new(),
+    };
+    let cell = "padding:8px 0;border-bottom:1px solid #e4e4e7;";
+    format!(
+        r#"<tr><td style="{cell}color:#52525b;">{label}</td><td align="right" style="{cell}font-weight:600;">{value}</td><td align="right" style="{cell}padding-left:12px;white-space:nowrap;">{delta}</td><td align="right" style="{cell}padding-left:12px;width:{SPARKLINE_WIDTH}px;">{sparkline}</td></tr>
+"#,
+        label = escape(&fact.label),
+        value = escape(&fact.value),
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn stats_payload() -> Value {
+        json!({
+            "kind": "report_subscription",
+            "website_id": "site_1",
+            "website_name": "Acme <Shop>",
+            "report_name": "Weekly overview",
+            "report_type": "stats",
+            "period": { "start_date": "2026-10-06", "end_date": "2026-10-12" },
+            "generated_at": "2026-10-13T09:00:00Z",
+            "data": {
+                "pageviews": 1200, "prev_pageviews": 1000,
+                "visitors": 300, "prev_visitors": 400,
+                "sessions": 350, "prev_sessions": 350,
+                "bounce_rate": 40.0, "prev_bounce_rate": 45.0,
+                "avg_duration_seconds": 61.0, "prev_avg_duration_seconds": 60.0
+            },
+            "trend": [
+                { "date": "2026-10-06", "pageviews": 100, "visitors": 30 },
+                { "date": "2026-10-07", "pageviews": 250, "visitors": 45 },
+                { "date": "2026-10-08", "pageviews": 180, "visitors": 40 }
+            ]
+        })
+    }
+
+    #[test]
+    fn report_emails_name_the_website_report_and_period() {
+        let email = render_email(&stats_payload(), "https://analytics.example.com").expect("email");
+        assert_eq!(
+            email.subject,
+            "Weekly overview — Acme <Shop>, Oct 6 – Oct 12, 2026"
+        );
+        assert!(email.text.contains("Pageviews: 1,200 (+20.0%)"));
+        assert!(email.text.contains("Visitors: 300 (-25.0%)"));
+        assert!(email
+            .text
+            .contains("Open reports: https://analytics.example.com/dashboard/site_1/reports"));
+
+        let html = email.html.expect("html part");
+        assert!(html.contains("Acme &lt;Shop&gt; · Oct 6 – Oct 12, 2026"));
+        assert!(html.contains(r#"<span style="color:#dc2626;">-25.0%</span>"#));
+        assert!(html.contains(r#"<span style="color:#dc2626;">-5.0 pp</span>"#));
+        // Pageviews and visitors get sparklines; the other KPIs have no series.
+        assert_eq!(email.images.len(), 2);
+        assert!(html.contains("cid:sparkline-1@sparklytics"));
+        assert!(html.contains("cid:sparkline-2@sparklytics"));
+        assert!(email.images[0].png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn alert_emails_chart_the_lookback_history() {
+        let email = render_email(
+            &json!({
+                "kind": "alert",
+                "website_id": "site_1",
+                "website_name": "Acme",
+                "name": "Traffic spike",
+                "metric": "pageviews",
+                "condition_type": "spike",
+                "threshold_value": 2.0,
+                "current_value": 90.0,
+                "baseline_mean": 30.0,
+                "z_score": 6.0,
+                "history": [
+                    { "date": "2026-10-10", "value": 30.0 },
+                    { "date": "2026-10-11", "value": 28.0 },
+                    { "date": "2026-10-12", "value": 90.0 }
+                ],
+                "triggered_at": "2026-10-12T15:30:00+00:00"
+            }),
+            "http://localhost:3000",
+        )
+        .expect("email");
+        assert_eq!(email.subject, "Alert: Traffic spike — Acme, Oct 12, 2026");
+        assert!(email.text.contains("Current value: 90 (+200.0%)"));
+        assert_eq!(email.images.len(), 1);
+    }
+
+    #[test]
+    fn test_sends_are_marked_and_unknown_payloads_are_left_alone() {
+        let mut payload = stats_payload();
+        payload["kind"] = json!("report_subscription_test");
+        let email = render_email(&payload, "http://localhost:3000").expect("email");
+        assert!(email.subject.starts_with("[Test] Weekly overview"));
+
+        assert!(render_email(&json!({ "hello": "world" }), "").is_none());
+        assert!(render_email(&json!({ "kind": "other" }), "").is_none());
+    }
+}
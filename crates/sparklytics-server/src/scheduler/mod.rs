@@ -1,14 +1,28 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::Utc;
 use tracing::{error, info};
 
 use crate::state::AppState;
 
 pub mod alerts;
+pub mod chat;
 pub mod delivery;
+pub mod email;
+pub mod render;
+pub mod sparkline;
 pub mod subscriptions;
 
+/// Website name for notification payloads, falling back to its domain or id.
+pub(crate) async fn website_name(state: &AppState, website_id: &str) -> String {
+    match state.get_website_metadata_cached(website_id).await {
+        Ok(Some(website)) if !website.name.trim().is_empty() => website.name,
+        Ok(Some(website)) => website.domain,
+        _ => website_id.to_string(),
+    }
+}
+
 fn scheduler_tick_seconds() -> u64 {
     std::env::var("SPARKLYTICS_SCHEDULER_TICK_SECONDS")
         .ok()
@@ -17,10 +31,11 @@ fn scheduler_tick_seconds() -> u64 {
         .unwrap_or(60)
 }
 
-pub async fn process_once(state: &Arc<AppState>) -> anyhow::Result<(usize, usize)> {
+pub async fn process_once(state: &Arc<AppState>) -> anyhow::Result<(usize, usize, usize)> {
     let subscription_runs = subscriptions::run_due_subscriptions(state).await?;
     let alert_deliveries = alerts::run_alert_checks(state).await?;
-    Ok((subscription_runs, alert_deliveries))
+    let retried = delivery::retry_due_deliveries(state, Utc::now()).await?;
+    Ok((subscription_runs, alert_deliveries, retried))
 }
 
 pub async fn run_scheduler_loop(state: Arc<AppState>) {
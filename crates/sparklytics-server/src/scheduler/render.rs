@@ -0,0 +1,502 @@
+//! Channel-neutral summaries of alert and report payloads.
+//!
+//! Chat and email renderers share this model so every channel shows the same
+//! numbers, deltas and dashboard link for a notification.
+
+use serde_json::Value;
+
+/// Maximum number of ranked rows (pages, referrers, events...) in a summary.
+const MAX_ROWS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fact {
+    pub label: String,
+    pub value: String,
+    /// Change against the comparison period or baseline, e.g. `+12.5%`.
+    pub delta: Option<String>,
+}
+
+impl Fact {
+    fn new(label: impl Into<String>, value: impl Into<String>, delta: Option<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            delta,
+        }
+    }
+
+    /// `value (delta)`, or just the value when there is no delta.
+    pub fn display(&self) -> String {
+        match &self.delta {
+            Some(delta) => format!("{} ({delta})", self.value),
+            None => self.value.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub title: String,
+    pub subtitle: String,
+    pub facts: Vec<Fact>,
+    /// Dashboard deep link for the website (or its reports page).
+    pub link: String,
+    pub link_label: String,
+    /// RFC 3339 time the alert fired or the report was generated.
+    pub timestamp: Option<String>,
+    pub is_alert: bool,
+}
+
+/// Build a summary from a scheduler payload (`kind` = `alert`, `alert_test`,
+/// `alert_resolved`, `report_subscription` or `report_subscription_test`).
+pub fn summarize(payload: &Value, public_url: &str) -> Summary {
+    let kind = str_field(payload, "kind").unwrap_or_default();
+    let website_id = str_field(payload, "website_id").unwrap_or_default();
+    let base = format!(
+        "{}/dashboard/{website_id}",
+        public_url.trim_end_matches('/')
+    );
+    if kind.starts_with("alert") {
+        summarize_alert(payload, kind, base)
+    } else {
+        summarize_report(payload, kind.ends_with("_test"), format!("{base}/reports"))
+    }
+}
+
+fn summarize_alert(payload: &Value, kind: &str, link: String) -> Summary {
+    let name = str_field(payload, "name").unwrap_or("Alert");
+    let metric = match (
+        str_field(payload, "metric").unwrap_or("metric"),
+        str_field(payload, "event_name"),
+    ) {
+        ("custom_events", Some(event_name)) => format!("Events ({event_name})"),
+        (metric, _) => humanize(metric),
+    };
+    let condition = str_field(payload, "condition_type").unwrap_or_default();
+    let threshold = payload.get("threshold_value").and_then(Value::as_f64);
+    let condition_text = match (condition, threshold) {
+        ("spike", Some(t)) => format!("spike of {} standard deviations", format_decimal(t)),
+        ("drop", Some(t)) => format!("drop of {} standard deviations", format_decimal(t)),
+        ("threshold_above", Some(t)) => format!("above {}", format_decimal(t)),
+        ("threshold_below", Some(t)) => format!("below {}", format_decimal(t)),
+        ("anomaly", Some(t)) => format!(
+            "outside the expected range by {} standard deviations",
+            format_decimal(t)
+        ),
+        _ => condition.replace('_', " "),
+    };
+    let baseline_text = match str_field(payload, "baseline") {
+        Some("same_weekday") => " vs. the same weekday in prior weeks",
+        Some("holt_winters") => " vs. the seasonal forecast",
+        _ => "",
+    };
+    let subtitle = if kind == "alert_resolved" {
+        format!("{metric} is back to normal")
+    } else {
+        format!("{metric} — {condition_text}{baseline_text}")
+    };
+
+    let mut facts = Vec::new();
+    let current = payload.get("current_value").and_then(Value::as_f64);
+    let baseline = payload
+        .get("expected_value")
+        .or_else(|| payload.get("baseline_mean"))
+        .and_then(Value::as_f64);
+    if let Some(current) = current {
+        facts.push(Fact::new(
+            "Current value",
+            format_decimal(current),
+            baseline.and_then(|b| percent_delta(current, b)),
+        ));
+    }
+    if let Some(baseline) = baseline {
+        facts.push(Fact::new("Expected", format_decimal(baseline), None));
+    }
+    let lower = payload.get("lower_bound").and_then(Value::as_f64);
+    let upper = payload.get("upper_bound").and_then(Value::as_f64);
+    if let (Some(lower), Some(upper)) = (lower, upper) {
+        facts.push(Fact::new(
+            "Expected range",
+            format!("{} – {}", format_decimal(lower), format_decimal(upper)),
+            None,
+        ));
+    }
+    if let Some(z) = payload.get("z_score").and_then(Value::as_f64) {
+        facts.push(Fact::new("Z-score", format!("{z:.2}"), None));
+    }
+    if let Some(threshold) = threshold {
+        facts.push(Fact::new("Threshold", format_decimal(threshold), None));
+    }
+    if let Some(scope) = payload.get("filters").and_then(scope_text) {
+        facts.push(Fact::new("Scope", scope, None));
+    }
+    if str_field(payload, "granularity") == Some("hour") {
+        facts.push(Fact::new("Granularity", "Hourly", None));
+    }
+
+    Summary {
+        title: match kind {
+            "alert_test" => format!("Test alert: {name}"),
+            "alert_resolved" => format!("Alert resolved: {name}"),
+            _ => format!("Alert triggered: {name}"),
+        },
+        subtitle,
+        facts,
+        link,
+        link_label: "Open dashboard".to_string(),
+        timestamp: str_field(payload, "triggered_at").map(str::to_string),
+        is_alert: kind != "alert_resolved",
+    }
+}
+
+/// `page contains /checkout, country is DE` for an alert's dimension filters.
+fn scope_text(filters: &Value) -> Option<String> {
+    const OPS: [&str; 6] = ["is", "is_not", "contains", "not_contains", "regex", "in"];
+    let parts: Vec<String> = filters
+        .as_object()?
+        .iter()
+        .filter_map(|(key, value)| {
+            let dimension = key.strip_prefix("filter_")?;
+            let raw = value.as_str()?;
+            let (op, operand) = match raw.split_once(':') {
+                Some((op, operand)) if OPS.contains(&op) => (op, operand),
+                _ if dimension == "page" => ("contains", raw),
+                _ => ("is", raw),
+            };
+            Some(format!(
+                "{} {} {operand}",
+                dimension.replace('_', " "),
+                op.replace('_', " ")
+            ))
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+fn summarize_report(payload: &Value, is_test: bool, link: String) -> Summary {
+    let name = str_field(payload, "report_name").unwrap_or("Report");
+    let report_type = str_field(payload, "report_type").unwrap_or("stats");
+    let data = payload.get("data").unwrap_or(&Value::Null);
+    let (subtitle, facts) = match report_type {
+        "pageviews" => ("Pageviews over time".to_string(), pageviews_facts(data)),
+        "metrics" => {
+            let metric_type = str_field(data, "type").unwrap_or("metric");
+            (format!("Top {}", humanize(metric_type)), metric_facts(data))
+        }
+        "events" => ("Top events".to_string(), event_facts(data)),
+        _ => ("Overview".to_string(), stats_facts(data)),
+    };
+    Summary {
+        title: if is_test {
+            format!("Test report: {name}")
+        } else {
+            name.to_string()
+        },
+        subtitle,
+        facts,
+        link,
+        link_label: "Open reports".to_string(),
+        timestamp: str_field(payload, "generated_at").map(str::to_string),
+        is_alert: false,
+    }
+}
+
+fn stats_facts(data: &Value) -> Vec<Fact> {
+    let count = |key: &str| {
+        let current = f64_field(data, key);
+        Fact::new(
+            humanize(key),
+            format_count(current),
+            percent_delta(current, f64_field(data, &format!("prev_{key}"))),
+        )
+    };
+    let bounce = f64_field(data, "bounce_rate");
+    let duration = f64_field(data, "avg_duration_seconds");
+    vec![
+        count("pageviews"),
+        count("visitors"),
+        count("sessions"),
+        Fact::new(
+            "Bounce rate",
+            format!("{bounce:.1}%"),
+            point_delta(bounce, f64_field(data, "prev_bounce_rate")),
+        ),
+        Fact::new(
+            "Avg. visit duration",
+            format_duration(duration),
+            percent_delta(duration, f64_field(data, "prev_avg_duration_seconds")),
+        ),
+    ]
+}
+
+fn pageviews_facts(data: &Value) -> Vec<Fact> {
+    // With a comparison range the series are nested under `data`.
+    let inner = data.get("data").unwrap_or(data);
+    let series = inner.get("series").and_then(Value::as_array);
+    let compare = inner.get("compare_series").and_then(Value::as_array);
+    let total = |points: Option<&Vec<Value>>, key: &str| {
+        points.map(|points| points.iter().map(|p| f64_field(p, key)).sum::<f64>())
+    };
+    ["pageviews", "visitors"]
+        .into_iter()
+        .map(|key| {
+            let current = total(series, key).unwrap_or(0.0);
+            Fact::new(
+                humanize(key),
+                format_count(current),
+                total(compare, key).and_then(|prev| percent_delta(current, prev)),
+            )
+        })
+        .collect()
+}
+
+fn metric_facts(data: &Value) -> Vec<Fact> {
+    ranked_rows(data, "value", "visitors", "prev_visitors")
+}
+
+fn event_facts(data: &Value) -> Vec<Fact> {
+    ranked_rows(data, "event_name", "count", "prev_count")
+}
+
+fn ranked_rows(data: &Value, label_key: &str, value_key: &str, prev_key: &str) -> Vec<Fact> {
+    let Some(rows) = data.get("rows").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    rows.iter()
+        .take(MAX_ROWS)
+        .map(|row| {
+            let label = match str_field(row, label_key) {
+                Some("") | None => "(none)",
+                Some(label) => label,
+            };
+            let current = f64_field(row, value_key);
+            let delta = row
+                .get(prev_key)
+                .and_then(Value::as_f64)
+                .and_then(|prev| percent_delta(current, prev));
+            Fact::new(label, format_count(current), delta)
+        })
+        .collect()
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value.get(key).and_then(Value::as_str)
+}
+
+fn f64_field(value: &Value, key: &str) -> f64 {
+    value.get(key).and_then(Value::as_f64).unwrap_or(0.0)
+}
+
+/// `pageviews` → `Pageviews`, `conversion_rate` → `Conversion rate`.
+fn humanize(key: &str) -> String {
+    let spaced = key.replace('_', " ");
+    let mut chars = spaced.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Relative change, or `None` when there is nothing to compare against.
+pub(crate) fn percent_delta(current: f64, previous: f64) -> Option<String> {
+    if !current.is_finite() || !previous.is_finite() || previous == 0.0 {
+        return None;
+    }
+    let pct = (current - previous) / previous.abs() * 100.0;
+    Some(format!("{}{pct:.1}%", if pct >= 0.0 { "+" } else { "" }))
+}
+
+/// Absolute change in percentage points, for values that are already rates.
+fn point_delta(current: f64, previous: f64) -> Option<String> {
+    if !current.is_finite() || !previous.is_finite() || previous == 0.0 {
+        return None;
+    }
+    let diff = current - previous;
+    Some(format!(
+        "{}{diff:.1} pp",
+        if diff >= 0.0 { "+" } else { "" }
+    ))
+}
+
+/// Whole number with thousands separators: `12345` → `12,345`.
+pub(crate) fn format_count(value: f64) -> String {
+    let rounded = value.round() as i64;
+    let digits = rounded.unsigned_abs().to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if rounded < 0 {
+        out.push('-');
+    }
+    for (idx, ch) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(ch);
+    }
+    out
+}
+
+/// Counts stay whole; fractional values (rates, means) keep two decimals.
+fn format_decimal(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format_count(value)
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+/// `125.0` → `2m 5s`.
+fn format_duration(seconds: f64) -> String {
+    let total = seconds.max(0.0).round() as i64;
+    match (total / 3600, total % 3600 / 60, total % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, s) => format!("{m}m {s}s"),
+        (h, m, _) => format!("{h}h {m}m"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn stats_reports_show_deltas_against_the_previous_period() {
+        let summary = summarize(
+            &json!({
+                "kind": "report_subscription",
+                "website_id": "site_1",
+                "report_name": "Weekly overview",
+                "report_type": "stats",
+                "generated_at": "2026-10-12T09:00:00Z",
+                "data": {
+                    "pageviews": 12500, "prev_pageviews": 10000,
+                    "visitors": 900, "prev_visitors": 1000,
+                    "sessions": 1000, "prev_sessions": 0,
+                    "bounce_rate": 41.5, "prev_bounce_rate": 40.0,
+                    "avg_duration_seconds": 125.0, "prev_avg_duration_seconds": 100.0
+                }
+            }),
+            "https://analytics.example.com/",
+        );
+        assert_eq!(summary.title, "Weekly overview");
+        assert_eq!(
+            summary.link,
+            "https://analytics.example.com/dashboard/site_1/reports"
+        );
+        assert_eq!(summary.facts[0].display(), "12,500 (+25.0%)");
+        assert_eq!(summary.facts[1].display(), "900 (-10.0%)");
+        assert_eq!(summary.facts[2].display(), "1,000");
+        assert_eq!(summary.facts[3].display(), "41.5% (+1.5 pp)");
+        assert_eq!(summary.facts[4].display(), "2m 5s (+25.0%)");
+    }
+
+    #[test]
+    fn alerts_compare_the_current_value_with_the_baseline() {
+        let summary = summarize(
+            &json!({
+                "kind": "alert",
+                "website_id": "site_1",
+                "name": "Traffic spike",
+                "metric": "pageviews",
+                "condition_type": "spike",
+                "threshold_value": 2.0,
+                "current_value": 300.0,
+                "baseline_mean": 200.0,
+                "baseline_stddev": 10.0,
+                "z_score": 10.0,
+                "triggered_at": "2026-10-12T09:00:00Z"
+            }),
+            "http://localhost:3000",
+        );
+        assert!(summary.is_alert);
+        assert_eq!(summary.title, "Alert triggered: Traffic spike");
+        assert_eq!(
+            summary.subtitle,
+            "Pageviews — spike of 2 standard deviations"
+        );
+        assert_eq!(summary.link, "http://localhost:3000/dashboard/site_1");
+        assert_eq!(summary.facts[0].display(), "300 (+50.0%)");
+    }
+
+    #[test]
+    fn anomaly_alerts_show_the_expected_range() {
+        let summary = summarize(
+            &json!({
+                "kind": "alert",
+                "website_id": "site_1",
+                "name": "Unusual traffic",
+                "metric": "visitors",
+                "condition_type": "anomaly",
+                "baseline": "same_weekday",
+                "threshold_value": 3.0,
+                "current_value": 400.0,
+                "expected_value": 1000.0,
+                "lower_bound": 850.0,
+                "upper_bound": 1150.0,
+                "z_score": -12.0
+            }),
+            "http://localhost:3000",
+        );
+        assert_eq!(
+            summary.subtitle,
+            "Visitors — outside the expected range by 3 standard deviations vs. the same weekday in prior weeks"
+        );
+        assert_eq!(summary.facts[0].display(), "400 (-60.0%)");
+        assert_eq!(summary.facts[1].display(), "1,000");
+        assert_eq!(summary.facts[2].label, "Expected range");
+        assert_eq!(summary.facts[2].display(), "850 – 1,150");
+    }
+
+    #[test]
+    fn resolved_alerts_describe_the_filtered_metric() {
+        let summary = summarize(
+            &json!({
+                "kind": "alert_resolved",
+                "website_id": "site_1",
+                "name": "Checkout signups",
+                "metric": "custom_events",
+                "event_name": "signup",
+                "granularity": "hour",
+                "filters": {
+                    "filter_page": "/checkout",
+                    "filter_country": "is_not:DE",
+                    "filter_properties": []
+                },
+                "condition_type": "threshold_below",
+                "threshold_value": 5.0,
+                "current_value": 12.0
+            }),
+            "http://localhost:3000",
+        );
+        assert!(!summary.is_alert);
+        assert_eq!(summary.title, "Alert resolved: Checkout signups");
+        assert_eq!(summary.subtitle, "Events (signup) is back to normal");
+        let scope = summary.facts.iter().find(|f| f.label == "Scope").unwrap();
+        assert_eq!(scope.value, "country is not DE, page contains /checkout");
+        assert_eq!(summary.facts.last().unwrap().value, "Hourly");
+    }
+
+    #[test]
+    fn ranked_reports_are_capped() {
+        let rows: Vec<Value> = (0..30)
+            .map(
+                |idx| json!({ "event_name": format!("event_{idx}"), "count": 1234, "visitors": 1 }),
+            )
+            .collect();
+        let summary = summarize(
+            &json!({
+                "kind": "report_subscription_test",
+                "website_id": "site_1",
+                "report_name": "Events",
+                "report_type": "events",
+                "data": { "rows": rows, "total": 30 }
+            }),
+            "http://localhost:3000",
+        );
+        assert_eq!(summary.title, "Test report: Events");
+        assert_eq!(summary.facts.len(), MAX_ROWS);
+        assert_eq!(summary.facts[0].label, "event_0");
+        assert_eq!(summary.facts[0].value, "1,234");
+    }
+}
@@ -0,0 +1,145 @@
+//! Tiny PNG sparklines for notification emails.
+//!
+//! Mail clients block SVG and remote images by default, so emails embed these
+//! as inline `cid:` attachments.
+
+use std::io::Write;
+
+use flate2::{write::ZlibEncoder, Compression, Crc};
+
+pub const WIDTH: usize = 120;
+pub const HEIGHT: usize = 32;
+const PADDING: f64 = 3.0;
+const BACKGROUND: [u8; 3] = [255, 255, 255];
+/// Share of the line colour mixed into the area below the line.
+const FILL_TINT: f64 = 0.15;
+
+/// Render `values` as a line with a tinted area below it. Returns `None` when
+/// there are fewer than two finite points to draw.
+pub fn sparkline_png(values: &[f64], color: [u8; 3]) -> Option<Vec<u8>> {
+    let values: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    if values.len() < 2 {
+        return None;
+    }
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let usable = HEIGHT as f64 - 1.0 - 2.0 * PADDING;
+    let y_for = |value: f64| -> f64 {
+        if max - min <= f64::EPSILON {
+            return HEIGHT as f64 / 2.0;
+        }
+        PADDING + (max - value) / (max - min) * usable
+    };
+
+    let fill = tint(color);
+    let mut pixels = vec![BACKGROUND; WIDTH * HEIGHT];
+    let mut previous_y: Option<usize> = None;
+    for x in 0..WIDTH {
+        // Position along the series for this pixel column, interpolated.
+        let position = x as f64 / (WIDTH - 1) as f64 * (values.len() - 1) as f64;
+        let index = (position.floor() as usize).min(values.len() - 2);
+        let fraction = position - index as f64;
+        let value = values[index] + (values[index + 1] - values[index]) * fraction;
+        let y = (y_for(value).round() as usize).min(HEIGHT - 1);
+
+        for row in y..HEIGHT {
+            pixels[row * WIDTH + x] = fill;
+        }
+        // Join to the previous column so steep segments stay connected, and
+        // draw two pixels thick.
+        let (top, bottom) = match previous_y {
+            Some(prev) => (prev.min(y), prev.max(y)),
+            None => (y, y),
+        };
+        for row in top..=(bottom + 1).min(HEIGHT - 1) {
+            pixels[row * WIDTH + x] = color;
+        }
+        previous_y = Some(y);
+    }
+    encode_png(&pixels)
+}
+
+fn tint(color: [u8; 3]) -> [u8; 3] {
+    let mix = |channel: usize| {
+        let value =
+            BACKGROUND[channel] as f64 * (1.0 - FILL_TINT) + color[channel] as f64 * FILL_TINT;
+        value.round().clamp(0.0, 255.0) as u8
+    };
+    [mix(0), mix(1), mix(2)]
+}
+
+/// Encode an 8-bit RGB image of `WIDTH` x `HEIGHT`.
+fn encode_png(pixels: &[[u8; 3]]) -> Option<Vec<u8>> {
+    let mut raw = Vec::with_capacity(HEIGHT * (WIDTH * 3 + 1));
+    for row in pixels.chunks(WIDTH) {
+        raw.push(0); // filter: none
+        for pixel in row {
+            raw.extend_from_slice(pixel);
+        }
+    }
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&raw).ok()?;
+    let compressed = encoder.finish().ok()?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(WIDTH as u32).to_be_bytes());
+    header.extend_from_slice(&(HEIGHT as u32).to_be_bytes());
+    // Bit depth 8, colour type 2 (RGB), default compression/filter, no interlace.
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &compressed);
+    write_chunk(&mut png, b"IEND", &[]);
+    Some(png)
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let mut crc = Crc::new();
+    crc.update(kind);
+    crc.update(data);
+    png.extend_from_slice(&crc.sum().to_be_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::ZlibDecoder;
+
+    use super::*;
+
+    #[test]
+    fn renders_a_valid_rgb_png() {
+        let png = sparkline_png(&[1.0, 5.0, 3.0, 8.0], [59, 130, 246]).expect("png");
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(
+            u32::from_be_bytes([png[16], png[17], png[18], png[19]]),
+            120
+        );
+        assert_eq!(u32::from_be_bytes([png[20], png[21], png[22], png[23]]), 32);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+
+        let idat_len = u32::from_be_bytes([png[33], png[34], png[35], png[36]]) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        let mut raw = Vec::new();
+        ZlibDecoder::new(&png[41..41 + idat_len])
+            .read_to_end(&mut raw)
+            .expect("inflate");
+        assert_eq!(raw.len(), HEIGHT * (WIDTH * 3 + 1));
+        // The last column ends at the maximum, near the top padding.
+        let top_right = (PADDING as usize) * (WIDTH * 3 + 1) + 1 + (WIDTH - 1) * 3;
+        assert_eq!(&raw[top_right..top_right + 3], &[59, 130, 246]);
+    }
+
+    #[test]
+    fn needs_at_least_two_points() {
+        assert!(sparkline_png(&[], [0, 0, 0]).is_none());
+        assert!(sparkline_png(&[4.0, f64::NAN], [0, 0, 0]).is_none());
+        assert!(sparkline_png(&[4.0, 4.0], [0, 0, 0]).is_some());
+    }
+}
@@ -6,9 +6,14 @@ use sparklytics_core::analytics::{
     AnalyticsBackend, NotificationDeliveryStatus, NotificationSourceType,
 };
 
-use crate::{routes::reports::execute_report_config_with_backend, state::AppState};
+use crate::{
+    routes::reports::{
+        execute_report_config_with_backend, report_period, report_trend_with_backend,
+    },
+    state::AppState,
+};
 
-use super::delivery::deliver_and_record;
+use super::delivery::{deliver_and_record, Destination};
 
 fn max_subscriptions_per_tick() -> i64 {
     std::env::var("SPARKLYTICS_SCHEDULER_MAX_SUBSCRIPTIONS_PER_TICK")
@@ -116,13 +121,30 @@ pub async fn run_due_subscriptions(state: &Arc<AppState>) -> anyhow::Result<usiz
             }
         };
 
+        let trend = report_trend_with_backend(
+            state.scheduler_db.as_ref(),
+            &subscription.website_id,
+            &report.config,
+            include_bots,
+        )
+        .await
+        .ok()
+        .flatten();
+        let period = report_period(&report.config)
+            .ok()
+            .map(|(start, end)| json!({ "start_date": start, "end_date": end }));
         let payload = json!({
             "kind": "report_subscription",
             "website_id": subscription.website_id,
+            "website_name": super::website_name(state, &subscription.website_id).await,
             "subscription_id": subscription.id,
             "report_id": subscription.report_id,
+            "report_name": report.name,
+            "report_type": report.config.report_type,
+            "period": period,
             "generated_at": now.to_rfc3339(),
-            "data": report_data
+            "data": report_data,
+            "trend": trend
         });
 
         let _delivered = deliver_and_record(
@@ -130,8 +152,11 @@ pub async fn run_due_subscriptions(state: &Arc<AppState>) -> anyhow::Result<usiz
             NotificationSourceType::Subscription,
             &subscription.id,
             &idempotency_key,
-            subscription.channel,
-            subscription.target,
+            Destination {
+                channel: subscription.channel,
+                target: subscription.target,
+                signing_secret: subscription.signing_secret,
+            },
             payload,
         )
         .await?;
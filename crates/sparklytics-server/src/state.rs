@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::Hash;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::atomic::{AtomicU64, AtomicUsize};
 use std::sync::Arc;
@@ -18,13 +18,16 @@ use sparklytics_core::{
     billing::{BillingGate, NullBillingGate},
     config::Config,
     event::Event,
+    session::SessionRules,
 };
 use sparklytics_duckdb::DuckDbBackend;
 use sparklytics_metadata::Website;
 
+use crate::backup::{create_backup, BackupInfo};
 use crate::bot_detection::{BotOverrideDecision, BotPolicyInput};
 use crate::error::AppError;
 use crate::metadata::{duckdb::DuckDbMetadataStore, MetadataStore};
+use crate::realtime_feed::RealtimeFeed;
 
 const SESSION_ID_PENDING: &str = "__pending__";
 const DEFAULT_INGEST_QUEUE_MAX_EVENTS: usize = 100_000;
@@ -47,6 +50,7 @@ const DEFAULT_EXPORT_CACHE_TTL_SECONDS: u64 = 2;
 const DEFAULT_EXPORT_CACHE_MAX_BYTES: usize = 32 * 1024 * 1024;
 const DEFAULT_WEBSITE_INGEST_PEAK_EPS: usize = 10_000;
 const DEFAULT_WEBSITE_INGEST_QUEUE_MAX_EVENTS: usize = 100_000;
+const ROLLUP_REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
 const INGEST_WAL_LOG_FILE: &str = "segment.log";
 const INGEST_WAL_CURSOR_FILE: &str = "segment.cursor";
 const USAGE_SYNC_RETRY_FILE: &str = "retry-queue.json";
@@ -77,6 +81,8 @@ struct UsageSyncRetryEntry {
 struct CachedSession {
     session_id: String,
     last_seen_at: DateTime<Utc>,
+    /// Campaign of the session's entry event, for campaign-change splits.
+    utm_campaign: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -84,6 +90,7 @@ struct SessionResolvedEffect {
     session_id: String,
     additional_pageviews: u32,
     last_seen_at: DateTime<Utc>,
+    utm_campaign: Option<String>,
     website_id: String,
     visitor_id: String,
     is_bot: bool,
@@ -201,6 +208,9 @@ pub struct AppState {
     /// Fast in-process cache of known-valid `website_id` values.
     pub website_cache: Arc<RwLock<HashSet<String>>>,
 
+    /// Live feed of accepted events for the realtime stream.
+    pub realtime_feed: Arc<RealtimeFeed>,
+
     /// Per-IP sliding-window rate limiter for POST /api/collect.
     rate_limiter: Arc<Mutex<IpRateLimiter>>,
     event_rate_limiter: Arc<Mutex<EventRateLimiter>>,
@@ -571,6 +581,7 @@ impl AppState {
             config: Arc::new(config),
             buffer: Arc::new(Mutex::new(Vec::new())),
             website_cache: Arc::new(RwLock::new(HashSet::new())),
+            realtime_feed: Arc::new(RealtimeFeed::default()),
             rate_limiter: Arc::new(Mutex::new(HashMap::new())),
             event_rate_limiter: Arc::new(Mutex::new(HashMap::new())),
             rate_limiter_max_entries: tuning.rate_limiter_max_entries,
@@ -1376,27 +1387,38 @@ impl AppState {
         self.persist_ingest_wal_cursor(0).await;
     }
 
+    /// Cached session continued by an event at `at` carrying `utm_campaign`,
+    /// as `(session_id, session_campaign)`. Entries the website's `rules`
+    /// would split are dropped, unless the event is backdated: the cached
+    /// session is still the visitor's live one.
     async fn get_cached_session(
         &self,
         website_id: &str,
         visitor_id: &str,
         at: DateTime<Utc>,
-    ) -> Option<String> {
+        utm_campaign: Option<&str>,
+        rules: &SessionRules,
+    ) -> Option<(String, Option<String>)> {
         let key = (website_id.to_string(), visitor_id.to_string());
         let mut cache = self.session_cache.lock().await;
         let entry = cache.get_mut(&key)?;
 
-        if at.signed_duration_since(entry.last_seen_at).num_seconds()
-            > DEFAULT_SESSION_CACHE_TTL_SECONDS
-        {
-            cache.remove(&key);
+        if !rules.continues(
+            entry.last_seen_at,
+            entry.utm_campaign.as_deref(),
+            at,
+            utm_campaign,
+        ) {
+            if at >= entry.last_seen_at {
+                cache.remove(&key);
+            }
             return None;
         }
 
         if at > entry.last_seen_at {
             entry.last_seen_at = at;
         }
-        Some(entry.session_id.clone())
+        Some((entry.session_id.clone(), entry.utm_campaign.clone()))
     }
 
     async fn put_cached_session(
@@ -1405,10 +1427,20 @@ impl AppState {
         visitor_id: String,
         session_id: String,
         last_seen_at: DateTime<Utc>,
+        utm_campaign: Option<String>,
     ) {
         let key = (website_id, visitor_id);
         let mut cache = self.session_cache.lock().await;
 
+        // Keep the visitor's most recent session when a backdated one is
+        // resolved after it.
+        if cache
+            .get(&key)
+            .is_some_and(|cached| cached.last_seen_at > last_seen_at)
+        {
+            return;
+        }
+
         if !cache.contains_key(&key) && cache.len() >= self.session_cache_max_entries {
             cache.retain(|_, v| {
                 last_seen_at
@@ -1429,6 +1461,7 @@ impl AppState {
             CachedSession {
                 session_id,
                 last_seen_at,
+                utm_campaign,
             },
         );
     }
@@ -1437,6 +1470,19 @@ impl AppState {
         event.session_id.is_empty() || event.session_id == SESSION_ID_PENDING
     }
 
+    /// Session rules of `website_id`, falling back to the defaults when the
+    /// website cannot be loaded.
+    async fn session_rules(&self, website_id: &str) -> SessionRules {
+        match self.get_website_metadata_cached(website_id).await {
+            Ok(Some(website)) => website.session_rules(),
+            Ok(None) => SessionRules::default(),
+            Err(err) => {
+                warn!(website_id, error = %err, "Failed to load session rules; using defaults");
+                SessionRules::default()
+            }
+        }
+    }
+
     async fn assign_pending_sessions(
         &self,
         events: &mut [Event],
@@ -1446,6 +1492,7 @@ impl AppState {
             session_id: String,
             count: u32,
             last_seen_at: DateTime<Utc>,
+            utm_campaign: Option<String>,
             base_count_already_recorded: bool,
             website_id: String,
             visitor_id: String,
@@ -1455,14 +1502,32 @@ impl AppState {
         }
 
         let mut session_cache: HashMap<(String, String), SessionAccumulator> = HashMap::new();
+        // Sessions split mid-batch by the website's rules.
+        let mut finished_sessions: Vec<SessionAccumulator> = Vec::new();
+        let mut rules_by_website: HashMap<String, SessionRules> = HashMap::new();
 
         for event in events.iter_mut() {
             if !Self::event_needs_session_resolution(event) {
                 continue;
             }
 
+            let rules = match rules_by_website.get(&event.website_id) {
+                Some(rules) => *rules,
+                None => {
+                    let rules = self.session_rules(&event.website_id).await;
+                    rules_by_website.insert(event.website_id.clone(), rules);
+                    rules
+                }
+            };
             let key = (event.website_id.clone(), event.visitor_id.clone());
-            if let Some(existing) = session_cache.get_mut(&key) {
+            if let Some(existing) = session_cache.get_mut(&key).filter(|existing| {
+                rules.continues(
+                    existing.last_seen_at,
+                    existing.utm_campaign.as_deref(),
+                    event.created_at,
+                    event.utm_campaign.as_deref(),
+                )
+            }) {
                 existing.count = existing.count.saturating_add(1);
                 if event.created_at > existing.last_seen_at {
                     existing.last_seen_at = event.created_at;
@@ -1476,43 +1541,69 @@ impl AppState {
                 continue;
             }
 
+            // A backdated event outside the batch's current session gets its
+            // own session without ending the current one.
+            let backdated = session_cache
+                .get(&key)
+                .is_some_and(|existing| event.created_at < existing.last_seen_at);
+            if !backdated {
+                if let Some(previous) = session_cache.remove(&key) {
+                    finished_sessions.push(previous);
+                }
+            }
+
             let mut base_count_already_recorded = false;
-            let session_id = if let Some(cached_session_id) = self
-                .get_cached_session(&event.website_id, &event.visitor_id, event.created_at)
+            let (session_id, utm_campaign) = if let Some(cached) = self
+                .get_cached_session(
+                    &event.website_id,
+                    &event.visitor_id,
+                    event.created_at,
+                    event.utm_campaign.as_deref(),
+                    &rules,
+                )
                 .await
             {
-                cached_session_id
+                cached
             } else {
                 base_count_already_recorded = true;
-                self.analytics
+                let session_id = self
+                    .analytics
                     .get_or_create_session_at(
                         &event.website_id,
                         &event.visitor_id,
                         event.referrer_domain.as_deref(),
                         &event.url,
+                        event.utm_campaign.as_deref(),
                         event.created_at,
                     )
-                    .await?
+                    .await?;
+                (session_id, event.utm_campaign.clone())
             };
-            session_cache.insert(
-                key,
-                SessionAccumulator {
-                    session_id: session_id.clone(),
-                    count: 1,
-                    last_seen_at: event.created_at,
-                    base_count_already_recorded,
-                    website_id: event.website_id.clone(),
-                    visitor_id: event.visitor_id.clone(),
-                    is_bot: event.is_bot,
-                    bot_score: event.bot_score,
-                    bot_reason: event.bot_reason.clone(),
-                },
-            );
+            let accumulator = SessionAccumulator {
+                session_id: session_id.clone(),
+                count: 1,
+                last_seen_at: event.created_at,
+                utm_campaign,
+                base_count_already_recorded,
+                website_id: event.website_id.clone(),
+                visitor_id: event.visitor_id.clone(),
+                is_bot: event.is_bot,
+                bot_score: event.bot_score,
+                bot_reason: event.bot_reason.clone(),
+            };
+            if backdated {
+                finished_sessions.push(accumulator);
+            } else {
+                session_cache.insert(key, accumulator);
+            }
             event.session_id = session_id;
         }
 
-        let mut effects = Vec::with_capacity(session_cache.len());
-        for entry in session_cache.into_values() {
+        let mut effects = Vec::with_capacity(finished_sessions.len() + session_cache.len());
+        for entry in finished_sessions
+            .into_iter()
+            .chain(session_cache.into_values())
+        {
             let additional_pageviews = if entry.base_count_already_recorded {
                 entry.count.saturating_sub(1)
             } else {
@@ -1522,6 +1613,7 @@ impl AppState {
                 session_id: entry.session_id,
                 additional_pageviews,
                 last_seen_at: entry.last_seen_at,
+                utm_campaign: entry.utm_campaign,
                 website_id: entry.website_id,
                 visitor_id: entry.visitor_id,
                 is_bot: entry.is_bot,
@@ -1956,6 +2048,100 @@ impl AppState {
         }
     }
 
+    /// Background task: enforce `SPARKLYTICS_RETENTION_DAYS` once a day.
+    ///
+    /// Runs at 03:00 UTC so it stays clear of the midnight salt rotation.
+    /// Websites with `retention_days` set use their own horizon instead of the
+    /// global default. A global value of 0 leaves only per-website overrides
+    /// in effect.
+    pub async fn run_retention_purge_loop(self: Arc<Self>) {
+        loop {
+            let now = Utc::now();
+            let today_run = now.date_naive().and_hms_opt(3, 0, 0).map(|t| t.and_utc());
+            let next_run = match today_run {
+                Some(t) if t > now => t,
+                Some(t) => t + chrono::Duration::days(1),
+                None => {
+                    error!("Failed to compute next retention purge time - retrying in 1h");
+                    tokio::time::sleep(std::time::Duration::from_secs(3600)).await;
+                    continue;
+                }
+            };
+            let secs_until = (next_run - now).num_seconds().max(1) as u64;
+            tokio::time::sleep(std::time::Duration::from_secs(secs_until)).await;
+            self.purge_expired_data().await;
+        }
+    }
+
+    /// Run a single retention purge against the analytics database and log
+    /// how many rows were removed from each table.
+    pub async fn purge_expired_data(&self) {
+        let started = Instant::now();
+        match self
+            .db
+            .purge_expired_data(self.config.retention_days, Utc::now())
+            .await
+        {
+            Ok(report) => info!(
+                retention_days = self.config.retention_days,
+                events = report.events,
+                sessions = report.sessions,
+                rollups = report.rollups,
+                notification_deliveries = report.notification_deliveries,
+                bot_policy_audit = report.bot_policy_audit,
+                idempotency_keys = report.idempotency_keys,
+                elapsed_ms = started.elapsed().as_millis() as u64,
+                "Retention purge completed"
+            ),
+            Err(e) => error!(error = %e, "Retention purge failed"),
+        }
+    }
+
+    /// Background task: keep the daily rollup tables current.
+    ///
+    /// The first tick fires immediately so a fresh install backfills on
+    /// startup; later runs only aggregate days that closed since the previous
+    /// run or were reopened by late events.
+    pub async fn run_rollup_refresh_loop(self: Arc<Self>) {
+        let mut ticker = tokio::time::interval(ROLLUP_REFRESH_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            self.refresh_rollups().await;
+        }
+    }
+
+    /// Roll closed days into the rollup tables and log how much was added.
+    pub async fn refresh_rollups(&self) {
+        let started = Instant::now();
+        match self.db.refresh_rollups(Utc::now()).await {
+            Ok(report) if report.days > 0 => info!(
+                websites = report.websites,
+                days = report.days,
+                elapsed_ms = started.elapsed().as_millis() as u64,
+                "Daily rollups refreshed"
+            ),
+            Ok(_) => {}
+            Err(e) => error!(error = %e, "Daily rollup refresh failed"),
+        }
+    }
+
+    /// Back up the database and ingest WAL into `backups_dir`.
+    ///
+    /// Holds the ingest drain and WAL append locks only while the database
+    /// snapshot is pinned and the WAL is copied, so the WAL cursor in the
+    /// backup matches the events in the database copy without blocking
+    /// ingest for the whole copy.
+    pub async fn create_backup(&self, backups_dir: &Path) -> anyhow::Result<BackupInfo> {
+        create_backup(
+            &self.db,
+            Path::new(&self.config.data_dir),
+            backups_dir,
+            &[&self.ingest_drain_lock, &self.ingest_wal_append_lock],
+        )
+        .await
+    }
+
     /// Append events to the in-memory buffer, flushing if threshold is reached.
     pub async fn push_events(&self, events: Vec<Event>) {
         let should_flush = {
@@ -2034,6 +2220,9 @@ impl AppState {
                 return Err(e);
             }
         };
+        // Published once the WAL append lock is released so stream
+        // subscribers never hold up ingest.
+        let realtime_events = accepted_events.clone();
 
         {
             let mut queue = self.ingest_queue.lock().await;
@@ -2046,6 +2235,7 @@ impl AppState {
             });
         }
         drop(append_guard);
+        self.realtime_feed.publish(&realtime_events, Utc::now());
         self.schedule_ingest_worker();
 
         Ok(IngestEnqueueOutcome {
@@ -2263,6 +2453,7 @@ impl AppState {
                             effect.visitor_id,
                             effect.session_id,
                             effect.last_seen_at,
+                            effect.utm_campaign,
                         )
                         .await;
                     }
@@ -454,3 +454,239 @@ async fn attribution_rejects_invalid_model_and_missing_goal() {
         .expect("response");
     assert_eq!(missing_goal_res.status(), StatusCode::NOT_FOUND);
 }
+
+async fn seed_multi_session_fixture(state: &AppState, website_id: &str) {
+    let conn = state.db.conn_for_test().await;
+
+    let touches = [
+        (
+            "evt_multi_1",
+            "sess_multi_1",
+            Some("facebook"),
+            Some("social"),
+            "2026-01-05 09:00:00",
+        ),
+        (
+            "evt_multi_2",
+            "sess_multi_2",
+            Some("google"),
+            Some("cpc"),
+            "2026-02-10 09:00:00",
+        ),
+        (
+            "evt_multi_3",
+            "sess_multi_3",
+            Some("newsletter"),
+            Some("email"),
+            "2026-02-18 09:00:00",
+        ),
+        (
+            "evt_multi_4",
+            "sess_multi_4",
+            Some("reddit"),
+            Some("social"),
+            "2026-02-20 09:00:00",
+        ),
+    ];
+    for (id, session_id, utm_source, utm_medium, created_at) in touches {
+        insert_session(&conn, website_id, session_id, "visitor_multi");
+        insert_event(
+            &conn,
+            website_id,
+            id,
+            session_id,
+            "visitor_multi",
+            "pageview",
+            None,
+            None,
+            utm_source,
+            utm_medium,
+            None,
+            created_at,
+        );
+    }
+    insert_event(
+        &conn,
+        website_id,
+        "evt_multi_purchase",
+        "sess_multi_4",
+        "visitor_multi",
+        "event",
+        Some("purchase"),
+        None,
+        Some("reddit"),
+        Some("social"),
+        None,
+        "2026-02-20 09:05:00",
+    );
+}
+
+async fn attribution_credit(
+    app: &axum::Router,
+    website_id: &str,
+    goal_id: &str,
+    params: &str,
+) -> HashMap<String, f64> {
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/attribution?goal_id={goal_id}&start_date=2026-02-20&end_date=2026-02-20&{params}"
+        ))
+        .body(Body::empty())
+        .expect("request");
+    let res = app.clone().oneshot(req).await.expect("response");
+    assert_eq!(res.status(), StatusCode::OK);
+    let body = json_body(res).await;
+    assert_eq!(body["data"]["totals"]["conversions"], 1);
+    body["data"]["rows"]
+        .as_array()
+        .expect("rows")
+        .iter()
+        .map(|row| {
+            (
+                row["channel"].as_str().expect("channel").to_string(),
+                row["credit"].as_f64().expect("credit"),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn multi_touch_models_credit_sessions_inside_lookback_window() {
+    let (state, app) = setup().await;
+    let website_id = create_website(&app).await;
+    seed_multi_session_fixture(&state, &website_id).await;
+    let goal_id = create_event_goal(
+        &app,
+        &website_id,
+        json!({
+            "name": "Purchase",
+            "goal_type": "event",
+            "match_value": "purchase",
+            "match_operator": "equals",
+            "value_mode": "fixed",
+            "fixed_value": 100.0
+        }),
+    )
+    .await;
+
+    // The single-touch models stay within the converting session, whatever
+    // the lookback window.
+    for params in [
+        "model=first_touch",
+        "model=first_touch&lookback_days=60",
+        "model=last_touch",
+    ] {
+        let single = attribution_credit(&app, &website_id, &goal_id, params).await;
+        assert_eq!(single.len(), 1, "{params}");
+        assert_eq!(single.get("reddit / social"), Some(&1.0), "{params}");
+    }
+
+    let linear = attribution_credit(&app, &website_id, &goal_id, "model=linear").await;
+    assert_eq!(linear.len(), 3);
+    for credit in linear.values() {
+        assert!((credit - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    let position = attribution_credit(&app, &website_id, &goal_id, "model=position_based").await;
+    assert!((position["google / cpc"] - 0.4).abs() < 1e-9);
+    assert!((position["newsletter / email"] - 0.2).abs() < 1e-9);
+    assert!((position["reddit / social"] - 0.4).abs() < 1e-9);
+
+    let decay = attribution_credit(
+        &app,
+        &website_id,
+        &goal_id,
+        "model=time_decay&half_life_days=2",
+    )
+    .await;
+    assert!(decay["reddit / social"] > decay["newsletter / email"]);
+    assert!(decay["newsletter / email"] > decay["google / cpc"]);
+    let total: f64 = decay.values().sum();
+    assert!((total - 1.0).abs() < 1e-9);
+
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/revenue/summary?goal_id={goal_id}&model=linear&start_date=2026-02-20&end_date=2026-02-20"
+        ))
+        .body(Body::empty())
+        .expect("request");
+    let res = app.clone().oneshot(req).await.expect("response");
+    assert_eq!(res.status(), StatusCode::OK);
+    let body = json_body(res).await;
+    assert_eq!(body["data"]["model"], "linear");
+    assert_eq!(body["data"]["revenue"], 100.0);
+
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/attribution?goal_id={goal_id}&model=linear&lookback_days=0"
+        ))
+        .body(Body::empty())
+        .expect("request");
+    let res = app.clone().oneshot(req).await.expect("response");
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn revenue_summary_totals_follow_each_models_credit_to_filtered_touches() {
+    let (state, app) = setup().await;
+    let website_id = create_website(&app).await;
+    seed_multi_session_fixture(&state, &website_id).await;
+    let goal_id = create_event_goal(
+        &app,
+        &website_id,
+        json!({
+            "name": "Purchase",
+            "goal_type": "event",
+            "match_value": "purchase",
+            "match_operator": "equals",
+            "value_mode": "fixed",
+            "fixed_value": 100.0
+        }),
+    )
+    .await;
+
+    let summary = |model: &str| {
+        let app = app.clone();
+        let uri = format!(
+            "/api/websites/{website_id}/revenue/summary?goal_id={goal_id}&model={model}&start_date=2026-02-20&end_date=2026-02-20&filter_utm_source=google"
+        );
+        async move {
+            let req = Request::builder()
+                .method("GET")
+                .uri(uri)
+                .body(Body::empty())
+                .expect("request");
+            let res = app.oneshot(req).await.expect("response");
+            assert_eq!(res.status(), StatusCode::OK);
+            let body = json_body(res).await;
+            (
+                body["data"]["conversions"].as_i64().expect("conversions"),
+                body["data"]["revenue"].as_f64().expect("revenue"),
+            )
+        }
+    };
+
+    // The converting session came from reddit, so first touch gives google
+    // nothing, while linear splits the purchase over google, newsletter and
+    // reddit.
+    assert_eq!(summary("first_touch").await, (0, 0.0));
+    let (conversions, revenue) = summary("linear").await;
+    assert_eq!(conversions, 1);
+    assert!((revenue - 100.0 / 3.0).abs() < 1e-9);
+    let (conversions, revenue) = summary("position_based").await;
+    assert_eq!(conversions, 1);
+    assert!((revenue - 40.0).abs() < 1e-9);
+
+    let filtered = attribution_credit(
+        &app,
+        &website_id,
+        &goal_id,
+        "model=linear&filter_utm_source=google",
+    )
+    .await;
+    assert_eq!(filtered.len(), 1);
+    assert!((filtered["google / cpc"] - 1.0 / 3.0).abs() < 1e-9);
+}
@@ -671,6 +671,209 @@ async fn test_api_key_forbidden_for_auth_mgmt() {
     assert_eq!(json["error"]["code"], "forbidden");
 }
 
+/// Helper: send a request authenticated with a Bearer API key.
+async fn send_with_key(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    key: &str,
+    body: Option<Value>,
+) -> StatusCode {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {key}"))
+        .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+        .expect("build request");
+    app.clone()
+        .oneshot(request)
+        .await
+        .expect("request")
+        .status()
+}
+
+/// Helper: create an API key with the given scope fields and return the
+/// response data.
+async fn create_scoped_key(app: &axum::Router, cookie: &str, scope: Value) -> Value {
+    let mut body = json!({ "name": "scoped-key" });
+    body.as_object_mut()
+        .expect("object")
+        .extend(scope.as_object().expect("scope object").clone());
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/auth/keys")
+        .header("content-type", "application/json")
+        .header("cookie", cookie)
+        .body(Body::from(body.to_string()))
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    let status = response.status();
+    let json = json_body(response).await;
+    assert_eq!(status, StatusCode::CREATED, "{json}");
+    json["data"].clone()
+}
+
+// ============================================================
+// BDD: Scoped API keys are limited to their websites and access
+// ============================================================
+#[tokio::test]
+async fn test_scoped_api_keys_return_403_outside_scope() {
+    let (state, app) = setup_auth_custom_bootstrap().await;
+    let cookie = setup_and_login(&app).await;
+
+    let mut website_ids = Vec::new();
+    for domain in ["scoped.example.com", "other.example.com"] {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/api/websites")
+            .header("content-type", "application/json")
+            .header("cookie", &cookie)
+            .body(Body::from(
+                json!({ "name": domain, "domain": domain }).to_string(),
+            ))
+            .expect("build request");
+        let response = app.clone().oneshot(request).await.expect("request");
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let json = json_body(response).await;
+        website_ids.push(json["data"]["id"].as_str().expect("id").to_string());
+    }
+    let (scoped, other) = (&website_ids[0], &website_ids[1]);
+
+    let read_key = create_scoped_key(
+        &app,
+        &cookie,
+        json!({ "access": "read", "website_ids": [scoped] }),
+    )
+    .await;
+    assert_eq!(read_key["access"], "read");
+    assert_eq!(read_key["website_ids"], json!([scoped]));
+    let read_key = read_key["key"].as_str().expect("key");
+
+    let stats = |id: &str| format!("/api/websites/{id}/stats");
+    let goals = |id: &str| format!("/api/websites/{id}/goals");
+    let goal = json!({ "name": "Signup", "goal_type": "page_view", "match_value": "/signup" });
+
+    assert_eq!(
+        send_with_key(&app, "GET", &stats(scoped), read_key, None).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        send_with_key(&app, "GET", &stats(other), read_key, None).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        send_with_key(&app, "POST", &goals(scoped), read_key, Some(goal.clone())).await,
+        StatusCode::FORBIDDEN
+    );
+
+    let write_key = create_scoped_key(
+        &app,
+        &cookie,
+        json!({ "access": "write", "website_ids": [scoped] }),
+    )
+    .await;
+    let write_key = write_key["key"].as_str().expect("key");
+    assert_eq!(
+        send_with_key(&app, "POST", &goals(scoped), write_key, Some(goal.clone())).await,
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        send_with_key(&app, "POST", &goals(other), write_key, Some(goal)).await,
+        StatusCode::FORBIDDEN
+    );
+    // Website-scoped keys cannot create websites or touch instance resources.
+    assert_eq!(
+        send_with_key(
+            &app,
+            "POST",
+            "/api/websites",
+            write_key,
+            Some(json!({ "name": "New", "domain": "new.example.com" })),
+        )
+        .await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        send_with_key(&app, "GET", "/api/backups", write_key, None).await,
+        StatusCode::FORBIDDEN
+    );
+
+    // Listing websites only returns the ones in scope.
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/websites")
+        .header("authorization", format!("Bearer {read_key}"))
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = json_body(response).await;
+    assert_eq!(json["data"].as_array().expect("array").len(), 1);
+    assert_eq!(json["data"][0]["id"], scoped.as_str());
+
+    // Expired keys stop authenticating.
+    let expiring = create_scoped_key(
+        &app,
+        &cookie,
+        json!({ "access": "read", "expires_at": "2099-01-01T00:00:00Z" }),
+    )
+    .await;
+    let expiring_key = expiring["key"].as_str().expect("key");
+    assert_eq!(
+        send_with_key(&app, "GET", &stats(other), expiring_key, None).await,
+        StatusCode::OK
+    );
+    state
+        .db
+        .conn_for_test()
+        .await
+        .execute(
+            "UPDATE local_api_keys SET expires_at = CAST(NOW() AS TIMESTAMP) - INTERVAL 1 MINUTE \
+             WHERE id = ?1",
+            [expiring["id"].as_str().expect("id")],
+        )
+        .expect("expire key");
+    assert_eq!(
+        send_with_key(&app, "GET", &stats(other), expiring_key, None).await,
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+// ============================================================
+// BDD: API key scopes are validated on creation
+// ============================================================
+#[tokio::test]
+async fn test_api_key_scope_validation() {
+    let (_state, app) = setup_auth_custom_bootstrap().await;
+    let cookie = setup_and_login(&app).await;
+
+    for scope in [
+        json!({ "website_ids": [] }),
+        json!({ "website_ids": ["site_missing"] }),
+        json!({ "expires_at": "2000-01-01T00:00:00Z" }),
+        json!({ "access": "admin" }),
+    ] {
+        let mut body = json!({ "name": "invalid" });
+        body.as_object_mut()
+            .expect("object")
+            .extend(scope.as_object().expect("scope object").clone());
+        let request = Request::builder()
+            .method("POST")
+            .uri("/api/auth/keys")
+            .header("content-type", "application/json")
+            .header("cookie", &cookie)
+            .body(Body::from(body.to_string()))
+            .expect("build request");
+        let response = app.clone().oneshot(request).await.expect("request");
+        assert!(
+            response.status().is_client_error(),
+            "{scope} must be rejected, got {}",
+            response.status()
+        );
+    }
+}
+
 // ============================================================
 // BDD: Auth endpoints return 404 in None mode
 // ============================================================
@@ -982,3 +1185,243 @@ async fn test_null_billing_gate_test_in_server_still_passes() {
     assert_eq!(admission.allowed_events, 7);
     assert!(admission.reason.is_none());
 }
+
+// ============================================================
+// BDD: TOTP second factor
+// ============================================================
+
+/// Helper: the RFC 6238 code for a base32 secret at `unix_time`.
+fn totp_code(secret: &str, unix_time: i64) -> String {
+    use hmac::{Hmac, Mac};
+
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+    let mut key = Vec::new();
+    let (mut buffer, mut bits) = (0u32, 0);
+    for c in secret.bytes() {
+        let value = ALPHABET.iter().position(|a| *a == c).expect("base32") as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            key.push((buffer >> bits) as u8);
+        }
+    }
+
+    let mut mac = Hmac::<sha1::Sha1>::new_from_slice(&key).expect("hmac key");
+    mac.update(&((unix_time / 30) as u64).to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+    let offset = usize::from(digest[19] & 0x0f);
+    let value = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    format!("{:06}", value % 1_000_000)
+}
+
+async fn send_with_cookie(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    cookie: &str,
+    body: Option<Value>,
+) -> axum::http::Response<Body> {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .header("cookie", cookie)
+        .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+        .expect("build request");
+    app.clone().oneshot(request).await.expect("request")
+}
+
+fn login_with_code_request(password: &str, code: &str) -> Request<Body> {
+    let body = json!({ "password": password, "totp_code": code });
+    Request::builder()
+        .method("POST")
+        .uri("/api/auth/login")
+        .header("content-type", "application/json")
+        .header("x-forwarded-for", "10.0.0.1")
+        .body(Body::from(body.to_string()))
+        .expect("build request")
+}
+
+/// Helper: enroll and confirm TOTP for the signed-in account; returns the
+/// secret, the recovery codes and the time the confirming code was made for.
+async fn enable_totp(app: &axum::Router, cookie: &str) -> (String, Vec<String>, i64) {
+    let response = send_with_cookie(app, "POST", "/api/auth/totp/enroll", cookie, None).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let enrollment = json_body(response).await;
+    let secret = enrollment["data"]["secret"]
+        .as_str()
+        .expect("secret")
+        .to_string();
+    let uri = enrollment["data"]["otpauth_uri"].as_str().expect("uri");
+    assert!(uri.starts_with("otpauth://totp/Sparklytics%3Aadmin?"));
+    assert!(uri.contains(&format!("secret={secret}")));
+
+    let confirmed_at = chrono::Utc::now().timestamp();
+    let code = totp_code(&secret, confirmed_at);
+    let response = send_with_cookie(
+        app,
+        "POST",
+        "/api/auth/totp/confirm",
+        cookie,
+        Some(json!({ "code": code })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let recovery_codes = json_body(response).await["data"]["recovery_codes"]
+        .as_array()
+        .expect("recovery codes")
+        .iter()
+        .map(|code| code.as_str().expect("code").to_string())
+        .collect();
+    (secret, recovery_codes, confirmed_at)
+}
+
+#[tokio::test]
+async fn test_totp_login_requires_second_factor_once_confirmed() {
+    let (_state, app) = setup_auth_custom_bootstrap().await;
+    let cookie = setup_and_login(&app).await;
+
+    // A pending enrollment does not change login yet.
+    let response = send_with_cookie(&app, "POST", "/api/auth/totp/enroll", &cookie, None).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let status =
+        json_body(send_with_cookie(&app, "GET", "/api/auth/totp", &cookie, None).await).await;
+    assert_eq!(status["data"]["enabled"], false);
+    assert_eq!(status["data"]["pending"], true);
+    let response = app
+        .clone()
+        .oneshot(login_request(TEST_PASSWORD))
+        .await
+        .expect("login");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send_with_cookie(
+        &app,
+        "POST",
+        "/api/auth/totp/confirm",
+        &cookie,
+        Some(json!({ "code": "000000" })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let (secret, recovery_codes, confirmed_at) = enable_totp(&app, &cookie).await;
+    assert_eq!(recovery_codes.len(), 10);
+
+    let response = app
+        .clone()
+        .oneshot(login_request(TEST_PASSWORD))
+        .await
+        .expect("login without code");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(response.headers().get("set-cookie").is_none());
+    assert_eq!(json_body(response).await["error"]["code"], "totp_required");
+
+    // The code used to confirm enrollment cannot be replayed.
+    let response = app
+        .clone()
+        .oneshot(login_with_code_request(
+            TEST_PASSWORD,
+            &totp_code(&secret, confirmed_at),
+        ))
+        .await
+        .expect("login with replayed code");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .clone()
+        .oneshot(login_with_code_request(
+            TEST_PASSWORD,
+            &totp_code(&secret, confirmed_at + 30),
+        ))
+        .await
+        .expect("login with next code");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("set-cookie").is_some());
+
+    // Recovery codes work once, in any case and without the dash.
+    let recovery = recovery_codes[0].to_uppercase().replace('-', "");
+    let response = app
+        .clone()
+        .oneshot(login_with_code_request(TEST_PASSWORD, &recovery))
+        .await
+        .expect("login with recovery code");
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = app
+        .clone()
+        .oneshot(login_with_code_request(TEST_PASSWORD, &recovery))
+        .await
+        .expect("login with used recovery code");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // A wrong password never reveals whether a second factor is needed.
+    let response = app
+        .clone()
+        .oneshot(login_request("wrong_password_123"))
+        .await
+        .expect("login with wrong password");
+    assert_eq!(json_body(response).await["error"]["code"], "unauthorized");
+
+    let status =
+        json_body(send_with_cookie(&app, "GET", "/api/auth/totp", &cookie, None).await).await;
+    assert_eq!(status["data"]["enabled"], true);
+    assert_eq!(status["data"]["recovery_codes_remaining"], 9);
+}
+
+#[tokio::test]
+async fn test_totp_disable_and_recovery_codes_need_a_valid_code() {
+    let (_state, app) = setup_auth_custom_bootstrap().await;
+    let cookie = setup_and_login(&app).await;
+    let (_secret, recovery_codes, _) = enable_totp(&app, &cookie).await;
+
+    let response = send_with_cookie(&app, "POST", "/api/auth/totp/enroll", &cookie, None).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Regenerating recovery codes needs an authenticator code.
+    let response = send_with_cookie(
+        &app,
+        "POST",
+        "/api/auth/totp/recovery-codes",
+        &cookie,
+        Some(json!({ "code": recovery_codes[0] })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send_with_cookie(
+        &app,
+        "POST",
+        "/api/auth/totp/disable",
+        &cookie,
+        Some(json!({ "code": "not-a-code" })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send_with_cookie(
+        &app,
+        "POST",
+        "/api/auth/totp/disable",
+        &cookie,
+        Some(json!({ "code": recovery_codes[1] })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(login_request(TEST_PASSWORD))
+        .await
+        .expect("login");
+    assert_eq!(response.status(), StatusCode::OK);
+    let status =
+        json_body(send_with_cookie(&app, "GET", "/api/auth/totp", &cookie, None).await).await;
+    assert_eq!(status["data"]["enabled"], false);
+    assert_eq!(status["data"]["pending"], false);
+}
@@ -0,0 +1,193 @@
+mod common;
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use chrono::{Duration, Utc};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use sparklytics_core::config::{AppMode, AuthMode, Config};
+use sparklytics_duckdb::migrations::SCHEMA_VERSION;
+use sparklytics_duckdb::DuckDbBackend;
+use sparklytics_server::app::build_app;
+use sparklytics_server::backup::{
+    list_backups, prune_backups, restore_backup, BackupManifest, DATABASE_FILE, MANIFEST_FILE,
+};
+use sparklytics_server::state::AppState;
+
+fn config(data_dir: String) -> Config {
+    Config {
+        port: 0,
+        data_dir,
+        geoip_path: "/nonexistent/GeoLite2-City.mmdb".to_string(),
+        auth_mode: AuthMode::None,
+        bootstrap_password: None,
+        https: false,
+        retention_days: 365,
+        cors_origins: vec![],
+        session_days: 7,
+        buffer_flush_interval_ms: 5000,
+        buffer_max_size: 100,
+        mode: AppMode::SelfHosted,
+        argon2_memory_kb: 4096,
+        public_url: "http://localhost:3000".to_string(),
+        tracking_public_base: "http://localhost:3000".to_string(),
+        rate_limit_disable: false,
+        duckdb_memory_limit: "1GB".to_string(),
+    }
+}
+
+fn open_db(data_dir: &str) -> DuckDbBackend {
+    DuckDbBackend::open(&format!("{data_dir}/{DATABASE_FILE}"), "1GB").expect("open DuckDB")
+}
+
+async fn json_body(response: axum::http::Response<Body>) -> Value {
+    let bytes = response
+        .into_body()
+        .collect()
+        .await
+        .expect("read body")
+        .to_bytes();
+    serde_json::from_slice(&bytes).expect("parse JSON")
+}
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if body.is_some() {
+        request = request.header("content-type", "application/json");
+    }
+    let request = request
+        .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    let status = response.status();
+    (status, json_body(response).await)
+}
+
+#[tokio::test]
+async fn backup_endpoint_snapshots_the_running_server_and_restores() {
+    let data_dir = common::unique_data_dir("backup");
+    let state = Arc::new(AppState::new(open_db(&data_dir), config(data_dir.clone())));
+    let app = build_app(Arc::clone(&state));
+
+    let (status, website) = send(
+        &app,
+        "POST",
+        "/api/websites",
+        Some(json!({ "name": "Kept", "domain": "kept.example.com" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let kept_id = website["data"]["id"].as_str().expect("id").to_string();
+
+    let (status, created) = send(&app, "POST", "/api/backups", None).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created["data"]["schema_version"], SCHEMA_VERSION);
+    let backup_path = PathBuf::from(created["data"]["path"].as_str().expect("path"));
+    assert!(backup_path.join(DATABASE_FILE).is_file());
+    assert!(backup_path.join(MANIFEST_FILE).is_file());
+    assert!(backup_path.join("ingest-wal/segment.cursor").is_file());
+
+    let (status, listed) = send(&app, "GET", "/api/backups", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(listed["data"].as_array().expect("array").len(), 1);
+    assert_eq!(listed["data"][0]["path"], created["data"]["path"]);
+
+    // Written after the backup, so it must not survive the restore.
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/api/websites",
+        Some(json!({ "name": "Lost", "domain": "lost.example.com" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // Restore into a second data directory that already holds a database.
+    let target_dir = common::unique_data_dir("backup-restore");
+    drop(open_db(&target_dir));
+    let report = restore_backup(&backup_path, Path::new(&target_dir)).expect("restore");
+    assert_eq!(report.restored.schema_version, SCHEMA_VERSION);
+    let previous = report.previous_data.expect("previous database moved aside");
+    assert!(previous.join(DATABASE_FILE).is_file());
+
+    let restored = open_db(&target_dir);
+    let websites = restored.list_websites(10, None).await.expect("list");
+    let ids: Vec<&str> = websites.0.iter().map(|w| w.id.as_str()).collect();
+    assert_eq!(ids, vec![kept_id.as_str()]);
+}
+
+#[tokio::test]
+async fn restore_rejects_backups_from_a_newer_schema() {
+    let data_dir = common::unique_data_dir("backup-newer");
+    let backup_dir = Path::new(&data_dir).join("backups/sparklytics-20990101-000000");
+    std::fs::create_dir_all(&backup_dir).expect("create backup dir");
+    {
+        let conn = sparklytics_duckdb::duckdb::Connection::open(backup_dir.join(DATABASE_FILE))
+            .expect("open");
+        conn.execute_batch(
+            "CREATE TABLE _migrations (id VARCHAR PRIMARY KEY);
+             INSERT INTO _migrations VALUES ('9999_future');",
+        )
+        .expect("seed migrations");
+    }
+    let manifest = BackupManifest {
+        name: "sparklytics-20990101-000000".to_string(),
+        created_at: Utc::now(),
+        sparklytics_version: "99.0.0".to_string(),
+        schema_version: "9999_future".to_string(),
+        database_bytes: 0,
+    };
+    std::fs::write(
+        backup_dir.join(MANIFEST_FILE),
+        serde_json::to_vec(&manifest).expect("manifest"),
+    )
+    .expect("write manifest");
+
+    let target_dir = common::unique_data_dir("backup-newer-target");
+    let err = restore_backup(&backup_dir, Path::new(&target_dir)).expect_err("newer schema");
+    assert!(format!("{err:#}").contains("newer than this binary"));
+    assert!(!Path::new(&target_dir).join(DATABASE_FILE).exists());
+}
+
+#[test]
+fn prune_keeps_the_newest_backups() {
+    let backups_dir = PathBuf::from(common::unique_data_dir("backup-prune"));
+    let now = Utc::now();
+    for days_ago in 0..4 {
+        let created_at = now - Duration::days(days_ago);
+        let name = format!("sparklytics-{}", created_at.format("%Y%m%d-%H%M%S"));
+        let dir = backups_dir.join(&name);
+        std::fs::create_dir_all(&dir).expect("create backup dir");
+        let manifest = BackupManifest {
+            name,
+            created_at,
+            sparklytics_version: "0.1.0".to_string(),
+            schema_version: SCHEMA_VERSION.to_string(),
+            database_bytes: 0,
+        };
+        std::fs::write(
+            dir.join(MANIFEST_FILE),
+            serde_json::to_vec(&manifest).expect("manifest"),
+        )
+        .expect("write manifest");
+    }
+    // Directories without a manifest are not backups and are left alone.
+    std::fs::create_dir_all(backups_dir.join("sparklytics-unrelated")).expect("create dir");
+
+    let removed = prune_backups(&backups_dir, 2).expect("prune");
+    assert_eq!(removed.len(), 2);
+    let remaining = list_backups(&backups_dir).expect("list");
+    assert_eq!(remaining.len(), 2);
+    assert_eq!(remaining[0].manifest.created_at, now);
+    assert!(backups_dir.join("sparklytics-unrelated").is_dir());
+}
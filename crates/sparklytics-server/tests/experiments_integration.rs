@@ -0,0 +1,471 @@
+mod common;
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use sparklytics_core::config::{AppMode, AuthMode, Config};
+use sparklytics_duckdb::DuckDbBackend;
+use sparklytics_server::app::build_app;
+use sparklytics_server::state::AppState;
+
+const TEST_PASSWORD: &str = "strong_password_123";
+
+fn config(auth_mode: AuthMode) -> Config {
+    Config {
+        port: 0,
+        data_dir: common::unique_data_dir("experiments"),
+        geoip_path: "/nonexistent/GeoLite2-City.mmdb".to_string(),
+        auth_mode,
+        bootstrap_password: None,
+        https: false,
+        retention_days: 365,
+        cors_origins: vec![],
+        session_days: 7,
+        buffer_flush_interval_ms: 5000,
+        buffer_max_size: 100,
+        mode: AppMode::SelfHosted,
+        argon2_memory_kb: 4096,
+        public_url: "http://localhost:3000".to_string(),
+        tracking_public_base: "http://localhost:3000".to_string(),
+        rate_limit_disable: false,
+        duckdb_memory_limit: "1GB".to_string(),
+    }
+}
+
+async fn setup_none() -> (Arc<AppState>, axum::Router) {
+    let db = DuckDbBackend::open_in_memory().expect("in-memory DuckDB");
+    let state = Arc::new(AppState::new(db, config(AuthMode::None)));
+    let app = build_app(Arc::clone(&state));
+    (state, app)
+}
+
+async fn setup_auth() -> (Arc<AppState>, axum::Router) {
+    let db = DuckDbBackend::open_in_memory().expect("in-memory DuckDB");
+    let state = Arc::new(AppState::new(db, config(AuthMode::Local)));
+    let app = build_app(Arc::clone(&state));
+    (state, app)
+}
+
+async fn json_body(response: axum::http::Response<Body>) -> Value {
+    let bytes = response
+        .into_body()
+        .collect()
+        .await
+        .expect("read body")
+        .to_bytes();
+    serde_json::from_slice(&bytes).expect("parse JSON")
+}
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    let status = response.status();
+    if status == StatusCode::NO_CONTENT {
+        return (status, Value::Null);
+    }
+    (status, json_body(response).await)
+}
+
+async fn create_website(app: &axum::Router) -> String {
+    let (status, json) = send(
+        app,
+        "POST",
+        "/api/websites",
+        Some(json!({ "name": "Test", "domain": "test.example.com" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    json["data"]["id"].as_str().expect("id").to_string()
+}
+
+async fn create_signup_goal(app: &axum::Router, website_id: &str) -> String {
+    let (status, json) = send(
+        app,
+        "POST",
+        &format!("/api/websites/{website_id}/goals"),
+        Some(json!({
+            "name": "Signup",
+            "goal_type": "event",
+            "match_value": "signup",
+            "match_operator": "equals"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    json["data"]["id"].as_str().expect("goal id").to_string()
+}
+
+async fn insert_event(
+    state: &AppState,
+    website_id: &str,
+    id: &str,
+    visitor_id: &str,
+    event_name: &str,
+    event_data: Option<String>,
+    created_at: String,
+) {
+    let conn = state.db.conn_for_test().await;
+    conn.execute(
+        r#"
+        INSERT INTO events (
+            id, website_id, tenant_id, session_id, visitor_id, event_type, url,
+            referrer_url, referrer_domain, event_name, event_data, country, region, city,
+            browser, browser_version, os, os_version, device_type, screen, language,
+            utm_source, utm_medium, utm_campaign, utm_term, utm_content, created_at
+        ) VALUES (
+            ?1, ?2, NULL, ?3, ?4, 'event', 'https://example.com/pricing',
+            NULL, NULL, ?5, ?6, NULL, NULL, NULL,
+            NULL, NULL, NULL, NULL, 'desktop', NULL, NULL,
+            NULL, NULL, NULL, NULL, NULL, ?7
+        )
+        "#,
+        sparklytics_duckdb::duckdb::params![
+            id,
+            website_id,
+            format!("sess_{visitor_id}"),
+            visitor_id,
+            event_name,
+            event_data,
+            created_at
+        ],
+    )
+    .expect("insert event");
+}
+
+#[tokio::test]
+async fn test_experiment_lifecycle_and_validation() {
+    let (_state, app) = setup_none().await;
+    let website_id = create_website(&app).await;
+    let goal_id = create_signup_goal(&app, &website_id).await;
+    let base = format!("/api/websites/{website_id}/experiments");
+
+    let (status, json) = send(
+        &app,
+        "POST",
+        &base,
+        Some(json!({
+            "name": "Pricing page",
+            "variants": ["control", "annual-first", "monthly-first"],
+            "goal_id": goal_id,
+            "planned_duration_days": 14
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(json["data"]["status"], "draft");
+    assert_eq!(json["data"]["weights"], json!([33, 33, 34]));
+    assert!(json["data"]["started_at"].is_null());
+    let experiment_id = json["data"]["id"].as_str().expect("id").to_string();
+
+    let invalid_bodies = [
+        (
+            json!({ "name": "One", "variants": ["only"], "goal_id": goal_id, "planned_duration_days": 7 }),
+            "variants",
+        ),
+        (
+            json!({ "name": "Dupes", "variants": ["a", " a "], "goal_id": goal_id, "planned_duration_days": 7 }),
+            "variants",
+        ),
+        (
+            json!({ "name": "Weights", "variants": ["a", "b"], "weights": [60, 30], "goal_id": goal_id, "planned_duration_days": 7 }),
+            "weights",
+        ),
+        (
+            json!({ "name": "Duration", "variants": ["a", "b"], "goal_id": goal_id, "planned_duration_days": 0 }),
+            "planned_duration_days",
+        ),
+        (
+            json!({ "name": "Goal", "variants": ["a", "b"], "goal_id": "goal_missing", "planned_duration_days": 7 }),
+            "goal_id",
+        ),
+        (
+            json!({ "name": "Pricing page", "variants": ["a", "b"], "goal_id": goal_id, "planned_duration_days": 7 }),
+            "name",
+        ),
+    ];
+    for (body, field) in invalid_bodies {
+        let (status, json) = send(&app, "POST", &base, Some(body)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "field {field}");
+        assert_eq!(json["error"]["field"], field);
+    }
+
+    let item = format!("{base}/{experiment_id}");
+    let (status, json) = send(
+        &app,
+        "PUT",
+        &item,
+        Some(json!({ "variants": ["control", "annual-first"], "weights": [80, 20] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["weights"], json!([80, 20]));
+
+    let (status, json) = send(&app, "PUT", &item, Some(json!({ "status": "completed" }))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(json["error"]["code"], "invalid_status_transition");
+
+    let (status, json) = send(&app, "PUT", &item, Some(json!({ "status": "running" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["status"], "running");
+    assert!(json["data"]["started_at"].is_string());
+
+    let (status, json) = send(&app, "PUT", &item, Some(json!({ "weights": [50, 50] }))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(json["error"]["code"], "not_editable");
+
+    let (status, _) = send(&app, "PUT", &item, Some(json!({ "status": "paused" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, json) = send(&app, "PUT", &item, Some(json!({ "status": "completed" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(json["data"]["ended_at"].is_string());
+
+    let (status, json) = send(&app, "GET", &base, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"].as_array().expect("array").len(), 1);
+
+    let (status, _) = send(&app, "DELETE", &item, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send(&app, "GET", &item, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_experiment_running_limit() {
+    let (_state, app) = setup_none().await;
+    let website_id = create_website(&app).await;
+    let goal_id = create_signup_goal(&app, &website_id).await;
+    let base = format!("/api/websites/{website_id}/experiments");
+
+    for idx in 0..11 {
+        let (status, json) = send(
+            &app,
+            "POST",
+            &base,
+            Some(json!({
+                "name": format!("Experiment {idx}"),
+                "variants": ["a", "b"],
+                "goal_id": goal_id,
+                "planned_duration_days": 7
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let experiment_id = json["data"]["id"].as_str().expect("id");
+        let (status, json) = send(
+            &app,
+            "PUT",
+            &format!("{base}/{experiment_id}"),
+            Some(json!({ "status": "running" })),
+        )
+        .await;
+        if idx < 10 {
+            assert_eq!(status, StatusCode::OK);
+        } else {
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(json["error"]["code"], "limit_exceeded");
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_experiment_results_count_first_exposure_and_later_conversions() {
+    let (state, app) = setup_none().await;
+    let website_id = create_website(&app).await;
+    let goal_id = create_signup_goal(&app, &website_id).await;
+    let base = format!("/api/websites/{website_id}/experiments");
+
+    let (_, json) = send(
+        &app,
+        "POST",
+        &base,
+        Some(json!({
+            "name": "Hero copy",
+            "variants": ["control", "bold"],
+            "goal_id": goal_id,
+            "planned_duration_days": 14
+        })),
+    )
+    .await;
+    let experiment_id = json["data"]["id"].as_str().expect("id").to_string();
+    let (status, _) = send(
+        &app,
+        "PUT",
+        &format!("{base}/{experiment_id}"),
+        Some(json!({ "status": "running" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let now = chrono::Utc::now();
+    let at = |hours_ago: i64| {
+        (now - chrono::Duration::hours(hours_ago))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    };
+    {
+        let conn = state.db.conn_for_test().await;
+        conn.execute(
+            "UPDATE experiments SET started_at = ?1 WHERE id = ?2",
+            sparklytics_duckdb::duckdb::params![at(48), experiment_id],
+        )
+        .expect("backdate start");
+    }
+
+    let exposure = |variant: &str| {
+        Some(json!({ "experiment_id": experiment_id, "variant": variant }).to_string())
+    };
+    let ws = website_id.as_str();
+    // control: v1 converts, v2 does not, v3 converted only before exposure.
+    insert_event(
+        &state,
+        ws,
+        "e1",
+        "v1",
+        "$experiment_exposure",
+        exposure("control"),
+        at(10),
+    )
+    .await;
+    insert_event(&state, ws, "e2", "v1", "signup", None, at(9)).await;
+    insert_event(
+        &state,
+        ws,
+        "e3",
+        "v2",
+        "$experiment_exposure",
+        exposure("control"),
+        at(10),
+    )
+    .await;
+    insert_event(&state, ws, "e4", "v3", "signup", None, at(12)).await;
+    insert_event(
+        &state,
+        ws,
+        "e5",
+        "v3",
+        "$experiment_exposure",
+        exposure("control"),
+        at(11),
+    )
+    .await;
+    // bold: v4 converts twice, v5 was later also shown control but stays in bold.
+    insert_event(
+        &state,
+        ws,
+        "e6",
+        "v4",
+        "$experiment_exposure",
+        exposure("bold"),
+        at(8),
+    )
+    .await;
+    insert_event(&state, ws, "e7", "v4", "signup", None, at(7)).await;
+    insert_event(&state, ws, "e8", "v4", "signup", None, at(6)).await;
+    insert_event(
+        &state,
+        ws,
+        "e9",
+        "v5",
+        "$experiment_exposure",
+        exposure("bold"),
+        at(8),
+    )
+    .await;
+    insert_event(
+        &state,
+        ws,
+        "e10",
+        "v5",
+        "$experiment_exposure",
+        exposure("control"),
+        at(5),
+    )
+    .await;
+    // Exposures before the experiment started and for other experiments are ignored.
+    insert_event(
+        &state,
+        ws,
+        "e11",
+        "v6",
+        "$experiment_exposure",
+        exposure("bold"),
+        at(72),
+    )
+    .await;
+    insert_event(
+        &state,
+        ws,
+        "e12",
+        "v7",
+        "$experiment_exposure",
+        Some(json!({ "experiment_id": "exp_other", "variant": "bold" }).to_string()),
+        at(4),
+    )
+    .await;
+
+    let (status, json) = send(
+        &app,
+        "GET",
+        &format!("{base}/{experiment_id}/results"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let data = &json["data"];
+    assert_eq!(data["variants"][0]["variant"], "control");
+    assert_eq!(data["variants"][0]["exposures"], 3);
+    assert_eq!(data["variants"][0]["conversions"], 1);
+    assert_eq!(data["variants"][1]["variant"], "bold");
+    assert_eq!(data["variants"][1]["exposures"], 2);
+    assert_eq!(data["variants"][1]["conversions"], 1);
+    assert_eq!(data["sample_size_reached"], false);
+    assert_eq!(data["duration_reached"], false);
+    assert!(data["p_value"].is_null());
+    assert_eq!(data["significant"], false);
+    assert!(data["variants"][1]["lift"].is_number());
+
+    let (status, _) = send(&app, "GET", &format!("{base}/exp_missing/results"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_experiments_require_auth_in_local_mode() {
+    let (_state, app) = setup_auth().await;
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/api/auth/setup",
+        Some(json!({
+            "bootstrap_password": "sparklytics",
+            "password": TEST_PASSWORD
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/websites/site_any/experiments")
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -1,4 +1,4 @@
-/// BDD integration tests for the CSV export endpoint.
+/// BDD integration tests for the event export endpoint.
 use std::sync::Arc;
 
 mod common;
@@ -185,3 +185,160 @@ async fn test_export_unknown_website_returns_404() {
     let response = app.clone().oneshot(request).await.expect("request");
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
+
+async fn seed_pageview(state: &AppState, website_id: &str, event_id: &str) {
+    let conn = state.db.conn_for_test().await;
+    conn.execute(
+        r#"
+        INSERT INTO events (id, website_id, session_id, visitor_id, event_type, url, country, created_at)
+        VALUES (?1, ?2, 'sess_1', 'visitor_1', 'pageview', '/pricing', 'PL', CURRENT_TIMESTAMP)
+        "#,
+        sparklytics_duckdb::duckdb::params![event_id, website_id],
+    )
+    .expect("insert event");
+}
+
+// ============================================================
+// BDD: NDJSON export streams one JSON object per event
+// ============================================================
+#[tokio::test]
+async fn test_export_events_as_ndjson() {
+    let (state, app) = setup().await;
+    let website_id = create_website(&app).await;
+    seed_pageview(&state, &website_id, "evt_1").await;
+    seed_pageview(&state, &website_id, "evt_2").await;
+
+    let (start, end) = common::surrounding_date_window();
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/export?start_date={start}&end_date={end}&format=ndjson"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok()),
+        Some("application/x-ndjson")
+    );
+    let content_disposition = response
+        .headers()
+        .get("content-disposition")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .to_string();
+    assert!(
+        content_disposition.ends_with(".ndjson\""),
+        "unexpected content-disposition: {content_disposition}"
+    );
+
+    let body = text_body(response).await;
+    let rows: Vec<Value> = body
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("ndjson line"))
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["url"], "/pricing");
+    assert_eq!(rows[0]["country"], "PL");
+    assert!(rows[0].get("visitor_id").is_none());
+
+    let leftovers = std::fs::read_dir(std::path::Path::new(&state.config.data_dir).join("exports"))
+        .expect("exports dir")
+        .count();
+    assert_eq!(leftovers, 0, "scratch export files must not be left behind");
+}
+
+// ============================================================
+// BDD: Parquet export returns a Parquet file
+// ============================================================
+#[tokio::test]
+async fn test_export_events_as_parquet() {
+    let (state, app) = setup().await;
+    let website_id = create_website(&app).await;
+    seed_pageview(&state, &website_id, "evt_1").await;
+
+    let (start, end) = common::surrounding_date_window();
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/export?start_date={start}&end_date={end}&format=parquet"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok()),
+        Some("application/vnd.apache.parquet")
+    );
+
+    let bytes = response
+        .into_body()
+        .collect()
+        .await
+        .expect("read body")
+        .to_bytes();
+    assert!(bytes.starts_with(b"PAR1"), "missing parquet magic header");
+    assert!(bytes.ends_with(b"PAR1"), "missing parquet magic footer");
+}
+
+// ============================================================
+// BDD: Streamed formats allow a full year, CSV does not
+// ============================================================
+#[tokio::test]
+async fn test_streamed_export_allows_year_range() {
+    let (_state, app) = setup().await;
+    let website_id = create_website(&app).await;
+
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/export?start_date=2025-01-01&end_date=2025-12-31&format=ndjson"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/export?start_date=2024-01-01&end_date=2025-12-31&format=parquet"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+// ============================================================
+// BDD: Unknown export format returns 400 validation_error
+// ============================================================
+#[tokio::test]
+async fn test_export_unknown_format_returns_400() {
+    let (_state, app) = setup().await;
+    let website_id = create_website(&app).await;
+
+    let (start, end) = common::surrounding_date_window();
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/export?start_date={start}&end_date={end}&format=xlsx"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let json = json_body(response).await;
+    assert_eq!(json["error"]["code"], "validation_error");
+}
@@ -903,6 +903,561 @@ async fn test_funnel_results_with_rich_dataset_and_filters() {
     assert_eq!(results_json["data"]["steps"][1]["sessions_reached"], 25);
 }
 
+async fn funnel_step_counts(
+    app: &axum::Router,
+    website_id: &str,
+    funnel_id: &str,
+    day: chrono::NaiveDate,
+) -> Vec<i64> {
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/funnels/{funnel_id}/results?start_date={day}&end_date={day}"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = json_body(response).await;
+    json["data"]["steps"]
+        .as_array()
+        .expect("steps")
+        .iter()
+        .map(|step| step["sessions_reached"].as_i64().expect("count"))
+        .collect()
+}
+
+async fn update_funnel(
+    app: &axum::Router,
+    website_id: &str,
+    funnel_id: &str,
+    body: Value,
+) -> Value {
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/websites/{website_id}/funnels/{funnel_id}"))
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    json_body(response).await
+}
+
+#[tokio::test]
+async fn test_funnel_results_honor_scope_window_ordering_and_step_properties() {
+    let (state, app) = setup_none().await;
+    let website_id = create_website(&app).await;
+    let day = chrono::Utc::now().date_naive() - chrono::Duration::days(1);
+
+    {
+        let conn = state.db.conn_for_test().await;
+        let rows = [
+            // Converts across two sessions 20 minutes apart.
+            (
+                "evt_a1",
+                "sess_a1",
+                "visitor_a",
+                "pageview",
+                "/pricing",
+                None,
+                None,
+                "10:00:00",
+            ),
+            (
+                "evt_a2",
+                "sess_a2",
+                "visitor_a",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                Some(r#"{"plan":"pro"}"#),
+                "10:20:00",
+            ),
+            // Converts in-session, but on a plan the step does not accept.
+            (
+                "evt_b1",
+                "sess_b",
+                "visitor_b",
+                "pageview",
+                "/pricing",
+                None,
+                None,
+                "10:00:00",
+            ),
+            (
+                "evt_b2",
+                "sess_b",
+                "visitor_b",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                Some(r#"{"plan":"free"}"#),
+                "10:10:00",
+            ),
+            // Converts in-session three hours later.
+            (
+                "evt_c1",
+                "sess_c",
+                "visitor_c",
+                "pageview",
+                "/pricing",
+                None,
+                None,
+                "10:00:00",
+            ),
+            (
+                "evt_c2",
+                "sess_c",
+                "visitor_c",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                Some(r#"{"plan":"pro"}"#),
+                "13:00:00",
+            ),
+            // Completes both steps in reverse order.
+            (
+                "evt_d1",
+                "sess_d",
+                "visitor_d",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                Some(r#"{"plan":"pro"}"#),
+                "09:00:00",
+            ),
+            (
+                "evt_d2",
+                "sess_d",
+                "visitor_d",
+                "pageview",
+                "/pricing",
+                None,
+                None,
+                "09:05:00",
+            ),
+        ];
+        for (id, session_id, visitor_id, event_type, url, event_name, event_data, time) in rows {
+            conn.execute(
+                r#"
+                INSERT INTO events (
+                    id, website_id, tenant_id, session_id, visitor_id, event_type, url,
+                    referrer_url, referrer_domain, event_name, event_data, country, region, city,
+                    browser, browser_version, os, os_version, device_type, screen, language,
+                    utm_source, utm_medium, utm_campaign, utm_term, utm_content, created_at
+                ) VALUES (
+                    ?1, ?2, NULL, ?3, ?4, ?5, ?6,
+                    NULL, NULL, ?7, ?8, 'US', NULL, NULL,
+                    'Chrome', NULL, 'macOS', NULL, 'desktop', NULL, 'en-US',
+                    NULL, NULL, NULL, NULL, NULL, ?9
+                )
+                "#,
+                sparklytics_duckdb::duckdb::params![
+                    id,
+                    website_id,
+                    session_id,
+                    visitor_id,
+                    event_type,
+                    url,
+                    event_name,
+                    event_data,
+                    format!("{day} {time}")
+                ],
+            )
+            .expect("insert event");
+        }
+    }
+
+    let mut body = create_funnel_request("Pro Signup");
+    body["steps"][1]["property_filters"] = json!([{ "key": "plan", "op": "eq", "value": "pro" }]);
+    let create_req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/websites/{website_id}/funnels"))
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("build request");
+    let create_res = app.clone().oneshot(create_req).await.expect("request");
+    assert_eq!(create_res.status(), StatusCode::CREATED);
+    let create_json = json_body(create_res).await;
+    assert_eq!(create_json["data"]["scope"], "session");
+    assert_eq!(create_json["data"]["ordering"], "strict");
+    assert!(create_json["data"]["conversion_window_minutes"].is_null());
+    assert_eq!(
+        create_json["data"]["steps"][1]["property_filters"][0]["key"],
+        "plan"
+    );
+    let funnel_id = create_json["data"]["id"]
+        .as_str()
+        .expect("funnel id")
+        .to_string();
+
+    // Session scope: only visitor_c converts inside one session on the pro plan.
+    assert_eq!(
+        funnel_step_counts(&app, &website_id, &funnel_id, day).await,
+        vec![4, 1]
+    );
+
+    // Visitor scope with a one-hour window: visitor_a converts across
+    // sessions, visitor_c now falls outside the window.
+    let updated = update_funnel(
+        &app,
+        &website_id,
+        &funnel_id,
+        json!({ "scope": "visitor", "conversion_window_minutes": 60 }),
+    )
+    .await;
+    assert_eq!(updated["data"]["scope"], "visitor");
+    assert_eq!(updated["data"]["conversion_window_minutes"], 60);
+    assert_eq!(
+        funnel_step_counts(&app, &website_id, &funnel_id, day).await,
+        vec![4, 1]
+    );
+
+    // Any order without a window: visitor_d's reversed path counts too.
+    let updated = update_funnel(
+        &app,
+        &website_id,
+        &funnel_id,
+        json!({ "ordering": "any_order", "conversion_window_minutes": null }),
+    )
+    .await;
+    assert_eq!(updated["data"]["ordering"], "any_order");
+    assert!(updated["data"]["conversion_window_minutes"].is_null());
+    assert_eq!(updated["data"]["scope"], "visitor");
+    assert_eq!(
+        funnel_step_counts(&app, &website_id, &funnel_id, day).await,
+        vec![4, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_any_order_window_is_checked_from_each_attempt() {
+    let (state, app) = setup_none().await;
+    let website_id = create_website(&app).await;
+    let day = chrono::Utc::now().date_naive() - chrono::Duration::days(1);
+
+    {
+        let conn = state.db.conn_for_test().await;
+        let rows = [
+            // An early signup hours before pricing, then one inside the window.
+            (
+                "evt_a1",
+                "visitor_a",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                "08:00:00",
+            ),
+            (
+                "evt_a2",
+                "visitor_a",
+                "pageview",
+                "/pricing",
+                None,
+                "10:00:00",
+            ),
+            (
+                "evt_a3",
+                "visitor_a",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                "10:10:00",
+            ),
+            // Signs up an hour after pricing only.
+            (
+                "evt_b1",
+                "visitor_b",
+                "pageview",
+                "/pricing",
+                None,
+                "10:00:00",
+            ),
+            (
+                "evt_b2",
+                "visitor_b",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                "11:00:00",
+            ),
+        ];
+        for (id, visitor_id, event_type, url, event_name, time) in rows {
+            conn.execute(
+                r#"
+                INSERT INTO events (
+                    id, website_id, tenant_id, session_id, visitor_id, event_type, url,
+                    referrer_url, referrer_domain, event_name, event_data, country, region, city,
+                    browser, browser_version, os, os_version, device_type, screen, language,
+                    utm_source, utm_medium, utm_campaign, utm_term, utm_content, created_at
+                ) VALUES (
+                    ?1, ?2, NULL, ?3, ?3, ?4, ?5,
+                    NULL, NULL, ?6, NULL, 'US', NULL, NULL,
+                    'Chrome', NULL, 'macOS', NULL, 'desktop', NULL, 'en-US',
+                    NULL, NULL, NULL, NULL, NULL, ?7
+                )
+                "#,
+                sparklytics_duckdb::duckdb::params![
+                    id,
+                    website_id,
+                    visitor_id,
+                    event_type,
+                    url,
+                    event_name,
+                    format!("{day} {time}")
+                ],
+            )
+            .expect("insert event");
+        }
+    }
+
+    let mut body = create_funnel_request("Any Order Signup");
+    body["ordering"] = json!("any_order");
+    body["conversion_window_minutes"] = json!(30);
+    let create_req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/websites/{website_id}/funnels"))
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("build request");
+    let create_res = app.clone().oneshot(create_req).await.expect("request");
+    assert_eq!(create_res.status(), StatusCode::CREATED);
+    let create_json = json_body(create_res).await;
+    let funnel_id = create_json["data"]["id"]
+        .as_str()
+        .expect("funnel id")
+        .to_string();
+
+    // visitor_a converts on the later signup even though the first one is
+    // outside the window; visitor_b never gets both steps within 30 minutes.
+    assert_eq!(
+        funnel_step_counts(&app, &website_id, &funnel_id, day).await,
+        vec![2, 1]
+    );
+}
+
+#[tokio::test]
+async fn test_funnel_options_validation() {
+    let (_state, app) = setup_none().await;
+    let website_id = create_website(&app).await;
+
+    let mut page_view_filters = create_funnel_request("Page View Filters");
+    page_view_filters["steps"][0]["property_filters"] =
+        json!([{ "key": "plan", "op": "eq", "value": "pro" }]);
+    let mut zero_window = create_funnel_request("Zero Window");
+    zero_window["conversion_window_minutes"] = json!(0);
+    let mut long_window = create_funnel_request("Long Window");
+    long_window["conversion_window_minutes"] = json!(129_601);
+
+    for (body, field) in [
+        (page_view_filters, "property_filters"),
+        (zero_window, "conversion_window_minutes"),
+        (long_window, "conversion_window_minutes"),
+    ] {
+        let request = Request::builder()
+            .method("POST")
+            .uri(format!("/api/websites/{website_id}/funnels"))
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .expect("build request");
+        let response = app.clone().oneshot(request).await.expect("request");
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let json = json_body(response).await;
+        assert_eq!(json["error"]["field"], field);
+    }
+
+    let mut bad_scope = create_funnel_request("Bad Scope");
+    bad_scope["scope"] = json!("account");
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/api/websites/{website_id}/funnels"))
+        .header("content-type", "application/json")
+        .body(Body::from(bad_scope.to_string()))
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert!(response.status().is_client_error());
+}
+
+#[tokio::test]
+async fn test_funnel_results_breakdown_and_step_timings() {
+    let (state, app) = setup_none().await;
+    let website_id = create_website(&app).await;
+    let day = chrono::Utc::now().date_naive() - chrono::Duration::days(1);
+
+    {
+        let conn = state.db.conn_for_test().await;
+        let rows = [
+            (
+                "evt_1a",
+                "sess_1",
+                "pageview",
+                "/pricing",
+                None,
+                Some("google"),
+                "10:00:00",
+            ),
+            (
+                "evt_1b",
+                "sess_1",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                Some("google"),
+                "10:00:30",
+            ),
+            (
+                "evt_2a",
+                "sess_2",
+                "pageview",
+                "/pricing",
+                None,
+                Some("google"),
+                "10:00:00",
+            ),
+            (
+                "evt_2b",
+                "sess_2",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                Some("google"),
+                "10:05:00",
+            ),
+            (
+                "evt_3a",
+                "sess_3",
+                "pageview",
+                "/pricing",
+                None,
+                Some("newsletter"),
+                "10:00:00",
+            ),
+            (
+                "evt_4a", "sess_4", "pageview", "/pricing", None, None, "11:00:00",
+            ),
+            (
+                "evt_4b",
+                "sess_4",
+                "event",
+                "/signup",
+                Some("signup_completed"),
+                None,
+                "13:00:00",
+            ),
+        ];
+        for (id, session_id, event_type, url, event_name, utm_source, time) in rows {
+            conn.execute(
+                r#"
+                INSERT INTO events (
+                    id, website_id, tenant_id, session_id, visitor_id, event_type, url,
+                    referrer_url, referrer_domain, event_name, event_data, country, region, city,
+                    browser, browser_version, os, os_version, device_type, screen, language,
+                    utm_source, utm_medium, utm_campaign, utm_term, utm_content, created_at
+                ) VALUES (
+                    ?1, ?2, NULL, ?3, ?4, ?5, ?6,
+                    NULL, NULL, ?7, NULL, 'US', NULL, NULL,
+                    'Chrome', NULL, 'macOS', NULL, 'desktop', NULL, 'en-US',
+                    ?8, NULL, NULL, NULL, NULL, ?9
+                )
+                "#,
+                sparklytics_duckdb::duckdb::params![
+                    id,
+                    website_id,
+                    session_id,
+                    format!("visitor_{session_id}"),
+                    event_type,
+                    url,
+                    event_name,
+                    utm_source,
+                    format!("{day} {time}")
+                ],
+            )
+            .expect("insert event");
+        }
+    }
+
+    let create_req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/websites/{website_id}/funnels"))
+        .header("content-type", "application/json")
+        .body(Body::from(create_funnel_request("Breakdown").to_string()))
+        .expect("build request");
+    let create_res = app.clone().oneshot(create_req).await.expect("request");
+    assert_eq!(create_res.status(), StatusCode::CREATED);
+    let create_json = json_body(create_res).await;
+    let funnel_id = create_json["data"]["id"]
+        .as_str()
+        .expect("funnel id")
+        .to_string();
+
+    let results_req = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/funnels/{funnel_id}/results?start_date={day}&end_date={day}&breakdown=utm_source"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+    let results_res = app.clone().oneshot(results_req).await.expect("request");
+    assert_eq!(results_res.status(), StatusCode::OK);
+    let json = json_body(results_res).await;
+    let data = &json["data"];
+    assert_eq!(data["steps"][0]["sessions_reached"], 4);
+    assert_eq!(data["steps"][1]["sessions_reached"], 3);
+
+    let timing = &data["step_timings"][0];
+    assert_eq!(timing["from_step_order"], 1);
+    assert_eq!(timing["to_step_order"], 2);
+    assert_eq!(timing["converted"], 3);
+    let median = timing["median_seconds"].as_f64().expect("median");
+    assert!((median - 300.0).abs() < 0.001);
+    let p90 = timing["p90_seconds"].as_f64().expect("p90");
+    assert!((p90 - 5820.0).abs() < 0.001);
+    let histogram: Vec<i64> = timing["histogram"]
+        .as_array()
+        .expect("histogram")
+        .iter()
+        .map(|bucket| bucket["count"].as_i64().expect("count"))
+        .collect();
+    assert_eq!(histogram, vec![1, 1, 0, 1, 0]);
+    assert!(timing["histogram"][4]["max_seconds"].is_null());
+
+    assert_eq!(data["breakdown"]["dimension"], "utm_source");
+    let segments = data["breakdown"]["segments"].as_array().expect("segments");
+    let summary: Vec<(String, i64, i64)> = segments
+        .iter()
+        .map(|segment| {
+            (
+                segment["value"].as_str().expect("value").to_string(),
+                segment["steps"][0]["sessions_reached"]
+                    .as_i64()
+                    .expect("step 1"),
+                segment["steps"][1]["sessions_reached"]
+                    .as_i64()
+                    .expect("step 2"),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("google".to_string(), 2, 2),
+            ("(none)".to_string(), 1, 1),
+            ("newsletter".to_string(), 1, 0),
+        ]
+    );
+
+    let invalid_req = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/websites/{website_id}/funnels/{funnel_id}/results?start_date={day}&end_date={day}&breakdown=visitor_id"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+    let invalid_res = app.clone().oneshot(invalid_req).await.expect("request");
+    assert_eq!(invalid_res.status(), StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn test_funnel_results_returns_429_when_query_slot_busy() {
     let (state, app) = setup_none().await;
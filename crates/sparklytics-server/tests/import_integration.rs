@@ -0,0 +1,341 @@
+mod common;
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use sparklytics_core::config::{AppMode, AuthMode, Config};
+use sparklytics_duckdb::DuckDbBackend;
+use sparklytics_server::app::build_app;
+use sparklytics_server::state::AppState;
+
+const UMAMI_SITE: &str = "0b6f3a52-2b6e-4d1c-9a55-3f2f4a0c1d11";
+const OTHER_UMAMI_SITE: &str = "7c1d2e3f-0000-4000-8000-000000000002";
+
+fn config(mode: AppMode) -> Config {
+    Config {
+        port: 0,
+        data_dir: common::unique_data_dir("import"),
+        geoip_path: "/nonexistent/GeoLite2-City.mmdb".to_string(),
+        auth_mode: AuthMode::None,
+        bootstrap_password: None,
+        https: false,
+        retention_days: 365,
+        cors_origins: vec![],
+        session_days: 7,
+        buffer_flush_interval_ms: 5000,
+        buffer_max_size: 100,
+        mode,
+        argon2_memory_kb: 4096,
+        public_url: "http://localhost:3000".to_string(),
+        tracking_public_base: "http://localhost:3000".to_string(),
+        rate_limit_disable: false,
+        duckdb_memory_limit: "1GB".to_string(),
+    }
+}
+
+fn setup() -> (Arc<AppState>, axum::Router) {
+    let db = DuckDbBackend::open_in_memory().expect("in-memory DuckDB");
+    let state = Arc::new(AppState::new(db, config(AppMode::SelfHosted)));
+    let app = build_app(Arc::clone(&state));
+    (state, app)
+}
+
+async fn json_body(response: axum::http::Response<Body>) -> Value {
+    let bytes = response
+        .into_body()
+        .collect()
+        .await
+        .expect("read body")
+        .to_bytes();
+    serde_json::from_slice(&bytes).expect("parse JSON")
+}
+
+async fn create_website(app: &axum::Router) -> String {
+    let body = json!({ "name": "Test", "domain": "test.example.com" });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/websites")
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = json_body(response).await;
+    json["data"]["id"].as_str().expect("id").to_string()
+}
+
+async fn post_import(
+    app: &axum::Router,
+    website_id: &str,
+    query: &str,
+    body: String,
+) -> axum::http::Response<Body> {
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!("/api/websites/{website_id}/imports?{query}"))
+        .body(Body::from(body))
+        .expect("build request");
+    app.clone().oneshot(request).await.expect("request")
+}
+
+async fn count(state: &AppState, sql: &str, website_id: &str) -> i64 {
+    let conn = state.db.conn_for_test().await;
+    conn.query_row(
+        sql,
+        sparklytics_duckdb::duckdb::params![website_id],
+        |row| row.get(0),
+    )
+    .expect("count")
+}
+
+fn umami_dump() -> String {
+    let events = [
+        // Two pageviews 5 minutes apart, then a custom event an hour later.
+        format!("e1\t{UMAMI_SITE}\ts1\t2025-01-10 09:00:00+00\t/\tutm_campaign=spring\tgoogle.com\t/search\t\\N\t1\t\\N"),
+        format!("e2\t{UMAMI_SITE}\ts1\t2025-01-10 09:05:00+00\t/pricing\t\\N\t\\N\t\\N\t\\N\t1\t\\N"),
+        format!("e3\t{UMAMI_SITE}\ts1\t2025-01-10 10:05:00+00\t/pricing\t\\N\t\\N\t\\N\t\\N\t2\tsignup"),
+        format!("e4\t{UMAMI_SITE}\ts2\tnot-a-date\t/\t\\N\t\\N\t\\N\t\\N\t1\t\\N"),
+        format!("e5\t{OTHER_UMAMI_SITE}\ts3\t2025-01-10 09:00:00+00\t/\t\\N\t\\N\t\\N\t\\N\t1\t\\N"),
+    ];
+    format!(
+        "--\n-- PostgreSQL database dump\n--\n\n\
+         COPY public.session (session_id, website_id, hostname, browser, os, device, screen, language, country, subdivision1, city, created_at) FROM stdin;\n\
+         s1\t{UMAMI_SITE}\tshop.example.com\tedge-chromium\tWindows 10\tlaptop\t1920x1080\ten-US\tDE\tBerlin\tBerlin\t2025-01-10 09:00:00+00\n\
+         \\.\n\n\
+         COPY public.website_event (event_id, website_id, session_id, created_at, url_path, url_query, referrer_domain, referrer_path, referrer_query, event_type, event_name) FROM stdin;\n\
+         {}\n\\.\n\n\
+         COPY public.event_data (event_data_id, website_id, website_event_id, data_key, string_value, number_value, date_value, data_type, created_at) FROM stdin;\n\
+         d1\t{UMAMI_SITE}\te3\tplan\tpro\t\\N\t\\N\t1\t2025-01-10 10:05:00+00\n\
+         \\.\n",
+        events.join("\n")
+    )
+}
+
+#[tokio::test]
+async fn test_umami_import_maps_events_and_rolls_back() {
+    let (state, app) = setup();
+    let website_id = create_website(&app).await;
+
+    // The dump holds two Umami websites, so the one to import must be named.
+    let response = post_import(&app, &website_id, "source=umami", umami_dump()).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = post_import(
+        &app,
+        &website_id,
+        &format!("source=umami&file_name=umami.sql&source_website_id={UMAMI_SITE}"),
+        umami_dump(),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = json_body(response).await;
+    let import = &json["data"];
+    assert_eq!(import["source"], "umami");
+    assert_eq!(import["file_name"], "umami.sql");
+    assert_eq!(import["event_count"], 3);
+    assert_eq!(import["skipped_count"], 1);
+    // The custom event an hour later starts a second session.
+    assert_eq!(import["session_count"], 2);
+    let import_id = import["id"].as_str().expect("import id").to_string();
+
+    {
+        let conn = state.db.conn_for_test().await;
+        let (url, browser, device, country, referrer, campaign): (
+            String,
+            String,
+            String,
+            String,
+            String,
+            String,
+        ) = conn
+            .query_row(
+                "SELECT url, browser, device_type, country, referrer_domain, utm_campaign
+                 FROM events WHERE website_id = ?1 AND url LIKE '%utm_campaign%'",
+                sparklytics_duckdb::duckdb::params![website_id],
+                |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        row.get(5)?,
+                    ))
+                },
+            )
+            .expect("landing event");
+        assert_eq!(url, "https://shop.example.com/?utm_campaign=spring");
+        assert_eq!(browser, "Edge");
+        assert_eq!(device, "desktop");
+        assert_eq!(country, "DE");
+        assert_eq!(referrer, "google.com");
+        assert_eq!(campaign, "spring");
+        let data: String = conn
+            .query_row(
+                "SELECT event_data FROM events WHERE website_id = ?1 AND event_name = 'signup'",
+                sparklytics_duckdb::duckdb::params![website_id],
+                |row| row.get(0),
+            )
+            .expect("custom event");
+        assert_eq!(
+            serde_json::from_str::<Value>(&data).expect("event data"),
+            json!({ "plan": "pro" })
+        );
+    }
+
+    let request = Request::builder()
+        .uri(format!("/api/websites/{website_id}/imports"))
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = json_body(response).await;
+    assert_eq!(json["data"][0]["id"], import_id);
+
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!(
+            "/api/websites/{website_id}/imports/{import_id}/rollback"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = json_body(response).await;
+    assert_eq!(json["data"]["status"], "rolled_back");
+    assert_eq!(
+        count(
+            &state,
+            "SELECT COUNT(*) FROM events WHERE website_id = ?1",
+            &website_id
+        )
+        .await,
+        0
+    );
+    assert_eq!(
+        count(
+            &state,
+            "SELECT COUNT(*) FROM sessions WHERE website_id = ?1",
+            &website_id
+        )
+        .await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_plausible_and_ga4_imports() {
+    let (state, app) = setup();
+    let website_id = create_website(&app).await;
+
+    let plausible = "\
+name,timestamp,pathname,hostname,referrer,utm_source,country_code,screen_size,operating_system,browser,user_id,meta.key,meta.value
+pageview,2025-02-01 12:00:00,/,test.example.com,https://news.ycombinator.com/,,US,Mobile,iOS,Safari,42,[],[]
+Signup,2025-02-01 12:03:00,/signup,test.example.com,,,US,Mobile,iOS,Safari,42,['plan'],['pro']
+pageview,,/,test.example.com,,,US,Desktop,Mac,Chrome,43,[],[]
+";
+    let response = post_import(&app, &website_id, "source=plausible", plausible.to_string()).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = json_body(response).await;
+    assert_eq!(json["data"]["event_count"], 2);
+    assert_eq!(json["data"]["session_count"], 1);
+    assert_eq!(json["data"]["skipped_count"], 1);
+
+    let ga4 = [
+        json!({
+            "event_timestamp": "1738411200000000",
+            "event_name": "page_view",
+            "user_pseudo_id": "123.456",
+            "event_params": [
+                { "key": "page_location", "value": { "string_value": "https://test.example.com/docs?utm_source=newsletter" } },
+                { "key": "ga_session_id", "value": { "int_value": "1738411200" } }
+            ],
+            "device": { "category": "desktop", "operating_system": "Windows", "web_info": { "browser": "Chrome" } },
+            "geo": { "country": "Germany", "city": "Berlin" }
+        }),
+        json!({
+            "event_timestamp": 1738411260000000_i64,
+            "event_name": "purchase",
+            "user_pseudo_id": "123.456",
+            "event_params": [
+                { "key": "page_location", "value": { "string_value": "https://test.example.com/checkout" } },
+                { "key": "value", "value": { "double_value": 49.5 } }
+            ]
+        }),
+        json!({
+            "event_timestamp": "1738411200000000",
+            "event_name": "session_start",
+            "user_pseudo_id": "123.456",
+            "event_params": []
+        }),
+    ]
+    .iter()
+    .map(Value::to_string)
+    .collect::<Vec<_>>()
+    .join("\n");
+    let response = post_import(&app, &website_id, "source=ga4", ga4).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = json_body(response).await;
+    assert_eq!(json["data"]["event_count"], 2);
+    assert_eq!(json["data"]["skipped_count"], 1);
+
+    let conn = state.db.conn_for_test().await;
+    let (utm_source, country, city): (String, Option<String>, String) = conn
+        .query_row(
+            "SELECT utm_source, country, city FROM events WHERE website_id = ?1 AND url LIKE '%/docs%'",
+            sparklytics_duckdb::duckdb::params![website_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .expect("ga4 pageview");
+    assert_eq!(utm_source, "newsletter");
+    assert_eq!(country, None);
+    assert_eq!(city, "Berlin");
+    let data: String = conn
+        .query_row(
+            "SELECT event_data FROM events WHERE website_id = ?1 AND event_name = 'Signup'",
+            sparklytics_duckdb::duckdb::params![website_id],
+            |row| row.get(0),
+        )
+        .expect("plausible custom event");
+    assert_eq!(
+        serde_json::from_str::<Value>(&data).expect("event data"),
+        json!({ "plan": "pro" })
+    );
+}
+
+#[tokio::test]
+async fn test_import_rejects_unusable_files() {
+    let (_state, app) = setup();
+    let website_id = create_website(&app).await;
+
+    for (query, body) in [
+        ("source=matomo", "anything"),
+        ("source=umami", "SELECT 1;"),
+        (
+            "source=plausible",
+            "date,visitors,pageviews\n2025-01-01,10,20\n",
+        ),
+        ("source=ga4", "not json"),
+    ] {
+        let response = post_import(&app, &website_id, query, body.to_string()).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{query}");
+    }
+
+    let response = post_import(&app, "site_missing", "source=ga4", "{}".to_string()).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let request = Request::builder()
+        .method("POST")
+        .uri(format!(
+            "/api/websites/{website_id}/imports/imp_missing/rollback"
+        ))
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,274 @@
+mod common;
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use chrono::{Duration, Utc};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use sparklytics_core::config::{AppMode, AuthMode, Config};
+use sparklytics_duckdb::DuckDbBackend;
+use sparklytics_server::app::build_app;
+use sparklytics_server::state::AppState;
+
+const TEST_PASSWORD: &str = "strong_password_123";
+const CHROME_UA: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+fn config(auth_mode: AuthMode) -> Config {
+    Config {
+        port: 0,
+        data_dir: common::unique_data_dir("ingest"),
+        geoip_path: "/nonexistent/GeoLite2-City.mmdb".to_string(),
+        auth_mode,
+        bootstrap_password: None,
+        https: false,
+        retention_days: 365,
+        cors_origins: vec![],
+        session_days: 7,
+        buffer_flush_interval_ms: 5000,
+        buffer_max_size: 100,
+        mode: AppMode::SelfHosted,
+        argon2_memory_kb: 4096,
+        public_url: "http://localhost:3000".to_string(),
+        tracking_public_base: "http://localhost:3000".to_string(),
+        rate_limit_disable: false,
+        duckdb_memory_limit: "1GB".to_string(),
+    }
+}
+
+fn setup(auth_mode: AuthMode) -> (Arc<AppState>, axum::Router) {
+    let db = DuckDbBackend::open_in_memory().expect("in-memory DuckDB");
+    let state = Arc::new(AppState::new(db, config(auth_mode)));
+    let app = build_app(Arc::clone(&state));
+    (state, app)
+}
+
+async fn json_body(response: axum::http::Response<Body>) -> Value {
+    let bytes = response
+        .into_body()
+        .collect()
+        .await
+        .expect("read body")
+        .to_bytes();
+    serde_json::from_slice(&bytes).expect("parse JSON")
+}
+
+async fn create_website(app: &axum::Router) -> String {
+    let body = json!({ "name": "Test", "domain": "test.example.com" });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/websites")
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = json_body(response).await;
+    json["data"]["id"].as_str().expect("id").to_string()
+}
+
+fn ingest_request(website_id: &str, body: &Value) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(format!("/api/websites/{website_id}/events/ingest"))
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("build request")
+}
+
+#[tokio::test]
+async fn test_ingest_backdates_enriches_and_dedupes_events() {
+    let (state, app) = setup(AuthMode::None);
+    let website_id = create_website(&app).await;
+    let timestamp = (Utc::now() - Duration::days(3))
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string();
+
+    let body = json!([
+        {
+            "type": "event",
+            "url": "/checkout",
+            "event_name": "purchase",
+            "event_data": { "plan": "pro" },
+            "timestamp": timestamp,
+            "ip": "203.0.113.7",
+            "user_agent": CHROME_UA,
+            "idempotency_key": "order_1"
+        },
+        {
+            "type": "pageview",
+            "url": "/welcome",
+            "visitor_id": "user_42",
+            "idempotency_key": "order_2"
+        }
+    ]);
+    let response = app
+        .clone()
+        .oneshot(ingest_request(&website_id, &body))
+        .await
+        .expect("request");
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let json = json_body(response).await;
+    assert_eq!(json["data"]["accepted"], 2);
+    assert_eq!(json["data"]["duplicates"], 0);
+
+    // A retry of the first event is skipped.
+    let retry = json!({
+        "type": "event",
+        "url": "/checkout",
+        "event_name": "purchase",
+        "ip": "203.0.113.7",
+        "idempotency_key": "order_1"
+    });
+    let response = app
+        .clone()
+        .oneshot(ingest_request(&website_id, &retry))
+        .await
+        .expect("request");
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let json = json_body(response).await;
+    assert_eq!(json["data"]["accepted"], 0);
+    assert_eq!(json["data"]["duplicates"], 1);
+
+    state.flush_buffer().await;
+    let conn = state.db.conn_for_test().await;
+    let (created_at, browser, source_ip): (String, Option<String>, Option<String>) = conn
+        .query_row(
+            "SELECT strftime(created_at, '%Y-%m-%dT%H:%M:%SZ'), browser, source_ip
+             FROM events WHERE website_id = ?1 AND event_name = 'purchase'",
+            sparklytics_duckdb::duckdb::params![website_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .expect("purchase event");
+    assert_eq!(created_at, timestamp);
+    assert_eq!(browser.as_deref(), Some("Chrome"));
+    assert_eq!(source_ip.as_deref(), Some("203.0.113.7"));
+    let visitor_id: String = conn
+        .query_row(
+            "SELECT visitor_id FROM events WHERE website_id = ?1 AND url = '/welcome'",
+            sparklytics_duckdb::duckdb::params![website_id],
+            |row| row.get(0),
+        )
+        .expect("pageview event");
+    assert_eq!(visitor_id, "user_42");
+    let total: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM events WHERE website_id = ?1",
+            sparklytics_duckdb::duckdb::params![website_id],
+            |row| row.get(0),
+        )
+        .expect("count");
+    assert_eq!(total, 2);
+}
+
+#[tokio::test]
+async fn test_backdated_events_leave_the_live_session_alone() {
+    let (state, app) = setup(AuthMode::None);
+    let website_id = create_website(&app).await;
+    let backdated = (Utc::now() - Duration::days(2))
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string();
+
+    for body in [
+        json!({ "type": "pageview", "url": "/live", "visitor_id": "user_7" }),
+        json!({ "type": "pageview", "url": "/old", "visitor_id": "user_7", "timestamp": backdated }),
+        json!({ "type": "pageview", "url": "/live-again", "visitor_id": "user_7" }),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(ingest_request(&website_id, &body))
+            .await
+            .expect("request");
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        state.flush_buffer().await;
+    }
+
+    let conn = state.db.conn_for_test().await;
+    let session_of = |url: &str| -> String {
+        conn.query_row(
+            "SELECT session_id FROM events WHERE website_id = ?1 AND url = ?2",
+            sparklytics_duckdb::duckdb::params![website_id, url],
+            |row| row.get(0),
+        )
+        .expect("event session")
+    };
+    let live = session_of("/live");
+    assert_eq!(session_of("/live-again"), live);
+    assert_ne!(session_of("/old"), live);
+
+    let (pageviews, last_seen_age_seconds): (i64, i64) = conn
+        .query_row(
+            "SELECT pageview_count, date_diff('second', last_seen, CAST(?2 AS TIMESTAMP))
+             FROM sessions WHERE session_id = ?1",
+            sparklytics_duckdb::duckdb::params![
+                live,
+                Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+            ],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .expect("live session");
+    assert_eq!(pageviews, 2);
+    assert!(last_seen_age_seconds < 60, "last_seen moved back");
+}
+
+#[tokio::test]
+async fn test_ingest_rejects_invalid_payloads() {
+    let (_state, app) = setup(AuthMode::None);
+    let website_id = create_website(&app).await;
+    let too_old = (Utc::now() - Duration::days(8)).to_rfc3339();
+    let future = (Utc::now() + Duration::hours(1)).to_rfc3339();
+
+    for body in [
+        json!({ "type": "pageview", "url": "/", "visitor_id": "v", "timestamp": too_old }),
+        json!({ "type": "pageview", "url": "/", "visitor_id": "v", "timestamp": future }),
+        json!({ "type": "pageview", "url": "/" }),
+        json!({ "type": "pageview", "url": "/", "ip": "not-an-ip" }),
+        json!({ "type": "pageview", "url": "/", "visitor_id": "v", "idempotency_key": "" }),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(ingest_request(&website_id, &body))
+            .await
+            .expect("request");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "body: {body}");
+    }
+
+    let body = json!({ "type": "pageview", "url": "/", "visitor_id": "v" });
+    let response = app
+        .clone()
+        .oneshot(ingest_request("site_missing", &body))
+        .await
+        .expect("request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_ingest_requires_auth_in_local_mode() {
+    let (_state, app) = setup(AuthMode::Local);
+
+    let setup_request = Request::builder()
+        .method("POST")
+        .uri("/api/auth/setup")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({
+                "bootstrap_password": "sparklytics",
+                "password": TEST_PASSWORD
+            })
+            .to_string(),
+        ))
+        .expect("build request");
+    let setup_response = app.clone().oneshot(setup_request).await.expect("request");
+    assert_eq!(setup_response.status(), StatusCode::CREATED);
+
+    let body = json!({ "type": "pageview", "url": "/", "visitor_id": "v" });
+    let response = app
+        .clone()
+        .oneshot(ingest_request("site_any", &body))
+        .await
+        .expect("request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
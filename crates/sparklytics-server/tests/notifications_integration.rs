@@ -4,10 +4,18 @@ use axum::{
     body::Body,
     http::{Request, StatusCode},
 };
+use chrono::{Duration, Utc};
 use serde_json::{json, Value};
-use sparklytics_core::config::{AuthMode, Config};
+use sparklytics_core::{
+    analytics::{NotificationChannel, NotificationDeliveryStatus, NotificationSourceType},
+    config::{AuthMode, Config},
+};
 use sparklytics_duckdb::DuckDbBackend;
-use sparklytics_server::{app::build_app, state::AppState};
+use sparklytics_server::{
+    app::build_app,
+    scheduler::delivery::{deliver_and_record, retry_due_deliveries, Destination},
+    state::AppState,
+};
 use tower::ServiceExt;
 
 struct EnvVarGuard {
@@ -219,6 +227,26 @@ async fn notifications_crud_test_send_and_history() {
         .expect("alert id")
         .to_string();
 
+    let create_unnamed_event_alert = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("/api/websites/{website_id}/alerts"),
+            json!({
+                "name": "Checkout signups",
+                "metric": "custom_events",
+                "condition_type": "threshold_below",
+                "threshold_value": 1.0,
+                "granularity": "hour",
+                "filters": { "filter_page": "/checkout" },
+                "channel": "email",
+                "target": "ops@example.com"
+            }),
+        ))
+        .await
+        .expect("create custom event alert without event name");
+    assert_eq!(create_unnamed_event_alert.status(), StatusCode::BAD_REQUEST);
+
     let test_alert = app
         .clone()
         .oneshot(request(
@@ -322,3 +350,353 @@ async fn notifications_crud_test_send_and_history() {
         .expect("delete alert");
     assert_eq!(delete_alert.status(), StatusCode::NO_CONTENT);
 }
+
+#[tokio::test]
+async fn failed_webhooks_retry_with_backoff_and_can_be_replayed() {
+    let _smtp_noop = EnvVarGuard::set("SPARKLYTICS_SMTP_NOOP", "1");
+    let _max_attempts = EnvVarGuard::set("SPARKLYTICS_NOTIFICATION_MAX_ATTEMPTS", "3");
+    let _scheduler_db_mode = EnvVarGuard::set("SPARKLYTICS_SCHEDULER_DEDICATED_DUCKDB", "0");
+    let (state, app) = setup().await;
+
+    let create_site = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            "/api/websites",
+            json!({ "name": "Site B", "domain": "example.org", "timezone": "UTC" }),
+        ))
+        .await
+        .expect("create website");
+    assert_eq!(create_site.status(), StatusCode::CREATED);
+    let website_id = json_body(create_site).await["data"]["id"]
+        .as_str()
+        .expect("website id")
+        .to_string();
+
+    let create_alert = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("/api/websites/{website_id}/alerts"),
+            json!({
+                "name": "Traffic drop",
+                "metric": "pageviews",
+                "condition_type": "drop",
+                "threshold_value": 2.0,
+                "channel": "webhook",
+                "target": "https://nonexistent-webhook.sparklytics.invalid/hook"
+            }),
+        ))
+        .await
+        .expect("create alert");
+    assert_eq!(create_alert.status(), StatusCode::CREATED);
+    let alert = json_body(create_alert).await["data"].clone();
+    let alert_id = alert["id"].as_str().expect("alert id").to_string();
+    let signing_secret = alert["signing_secret"]
+        .as_str()
+        .expect("signing secret")
+        .to_string();
+    assert!(signing_secret.starts_with("whsec_"));
+
+    // The secret is only shown on create and rotate, never when listing.
+    let listed = app
+        .clone()
+        .oneshot(get(&format!("/api/websites/{website_id}/alerts")))
+        .await
+        .expect("list alerts");
+    assert_eq!(listed.status(), StatusCode::OK);
+    let listed = json_body(listed).await;
+    assert_eq!(listed["data"][0]["id"], alert_id.as_str());
+    assert!(listed["data"][0].get("signing_secret").is_none());
+
+    let rotated = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("/api/websites/{website_id}/alerts/{alert_id}/rotate-secret"),
+            json!({}),
+        ))
+        .await
+        .expect("rotate secret");
+    assert_eq!(rotated.status(), StatusCode::OK);
+    let rotated = json_body(rotated).await["data"]["signing_secret"]
+        .as_str()
+        .expect("rotated secret")
+        .to_string();
+    assert!(rotated.starts_with("whsec_"));
+    assert_ne!(rotated, signing_secret);
+    let signing_secret = rotated;
+
+    let first = deliver_and_record(
+        &state,
+        NotificationSourceType::Alert,
+        &alert_id,
+        "alert:retry-test",
+        Destination {
+            channel: NotificationChannel::Webhook,
+            target: "https://nonexistent-webhook.sparklytics.invalid/hook".to_string(),
+            signing_secret,
+        },
+        json!({ "kind": "alert", "alert_id": alert_id }),
+    )
+    .await
+    .expect("first attempt")
+    .expect("delivery recorded");
+    assert_eq!(first.status, NotificationDeliveryStatus::Retrying);
+    assert_eq!(first.attempt_count, 1);
+    assert!(first.next_attempt_at.is_some());
+    assert!(first.error_message.is_some());
+
+    let now = Utc::now();
+    assert_eq!(
+        retry_due_deliveries(&state, now)
+            .await
+            .expect("not due yet"),
+        0
+    );
+    assert_eq!(
+        retry_due_deliveries(&state, now + Duration::minutes(2))
+            .await
+            .expect("second attempt"),
+        1
+    );
+    assert_eq!(
+        retry_due_deliveries(&state, now + Duration::days(1))
+            .await
+            .expect("third attempt"),
+        1
+    );
+    assert_eq!(
+        retry_due_deliveries(&state, now + Duration::days(2))
+            .await
+            .expect("out of attempts"),
+        0
+    );
+
+    let history = app
+        .clone()
+        .oneshot(get(&format!(
+            "/api/websites/{website_id}/notifications/history"
+        )))
+        .await
+        .expect("history");
+    let history_json = json_body(history).await;
+    let row = history_json["data"]
+        .as_array()
+        .expect("history rows")
+        .iter()
+        .find(|row| row["id"] == first.id.as_str())
+        .expect("retried delivery")
+        .clone();
+    assert_eq!(row["status"], "failed");
+    assert_eq!(row["attempt_count"], 3);
+    assert_eq!(row["next_attempt_at"], Value::Null);
+    assert_eq!(row["channel"], "webhook");
+
+    let replay = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!(
+                "/api/websites/{website_id}/notifications/history/{}/replay",
+                first.id
+            ),
+            json!({}),
+        ))
+        .await
+        .expect("replay");
+    assert_eq!(replay.status(), StatusCode::OK);
+    let replayed = json_body(replay).await["data"].clone();
+    assert_eq!(replayed["status"], "failed");
+    assert_eq!(replayed["attempt_count"], 4);
+
+    let missing = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("/api/websites/{website_id}/notifications/history/ntf_missing/replay"),
+            json!({}),
+        ))
+        .await
+        .expect("replay missing");
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+
+    let switch_to_email = app
+        .clone()
+        .oneshot(request(
+            "PUT",
+            &format!("/api/websites/{website_id}/alerts/{alert_id}"),
+            json!({ "channel": "email", "target": "ops@example.org" }),
+        ))
+        .await
+        .expect("switch to email");
+    assert_eq!(switch_to_email.status(), StatusCode::OK);
+    let sent = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("/api/websites/{website_id}/alerts/{alert_id}/test"),
+            json!({}),
+        ))
+        .await
+        .expect("test alert");
+    let sent_id = json_body(sent).await["data"]["id"]
+        .as_str()
+        .expect("sent delivery id")
+        .to_string();
+    let replay_sent = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("/api/websites/{website_id}/notifications/history/{sent_id}/replay"),
+            json!({}),
+        ))
+        .await
+        .expect("replay sent");
+    assert_eq!(replay_sent.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn deleting_an_alert_cancels_its_pending_retries() {
+    let _scheduler_db_mode = EnvVarGuard::set("SPARKLYTICS_SCHEDULER_DEDICATED_DUCKDB", "0");
+    let (state, _app) = setup().await;
+    let alert = state
+        .db
+        .create_alert_rule(
+            "site_retry_cancel",
+            sparklytics_core::analytics::CreateAlertRuleRequest {
+                name: "Spike".to_string(),
+                metric: sparklytics_core::analytics::AlertMetric::Pageviews,
+                condition_type: sparklytics_core::analytics::AlertConditionType::Spike,
+                threshold_value: 2.0,
+                lookback_days: None,
+                baseline: None,
+                event_name: None,
+                filters: None,
+                granularity: None,
+                cooldown_minutes: None,
+                channel: NotificationChannel::Webhook,
+                target: "https://nonexistent-webhook.sparklytics.invalid/hook".to_string(),
+            },
+        )
+        .await
+        .expect("create alert");
+    let delivery = deliver_and_record(
+        &state,
+        NotificationSourceType::Alert,
+        &alert.id,
+        "alert:cancel-test",
+        Destination {
+            channel: alert.channel.clone(),
+            target: alert.target.clone(),
+            signing_secret: alert.signing_secret.clone(),
+        },
+        json!({ "kind": "alert" }),
+    )
+    .await
+    .expect("first attempt")
+    .expect("delivery recorded");
+    assert_eq!(delivery.status, NotificationDeliveryStatus::Retrying);
+
+    assert!(state
+        .db
+        .delete_alert_rule("site_retry_cancel", &alert.id)
+        .await
+        .expect("delete alert"));
+    assert_eq!(
+        retry_due_deliveries(&state, Utc::now() + Duration::days(1))
+            .await
+            .expect("retry run"),
+        0
+    );
+}
+
+#[tokio::test]
+async fn chat_channels_require_https_and_send_rendered_messages() {
+    let _scheduler_db_mode = EnvVarGuard::set("SPARKLYTICS_SCHEDULER_DEDICATED_DUCKDB", "0");
+    let (state, app) = setup().await;
+
+    let create_site = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            "/api/websites",
+            json!({ "name": "Chat", "domain": "chat.example.com", "timezone": "UTC" }),
+        ))
+        .await
+        .expect("create website");
+    assert_eq!(create_site.status(), StatusCode::CREATED);
+    let website_id = json_body(create_site).await["data"]["id"]
+        .as_str()
+        .expect("website id")
+        .to_string();
+
+    let alert_body = |channel: &str, target: &str| {
+        json!({
+            "name": "Traffic spike",
+            "metric": "pageviews",
+            "condition_type": "spike",
+            "threshold_value": 2.0,
+            "channel": channel,
+            "target": target
+        })
+    };
+    let insecure = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            &format!("/api/websites/{website_id}/alerts"),
+            alert_body("slack", "http://hooks.slack.invalid/services/T000/B000/XXX"),
+        ))
+        .await
+        .expect("create insecure slack alert");
+    assert_eq!(insecure.status(), StatusCode::BAD_REQUEST);
+
+    for channel in ["slack", "teams", "discord"] {
+        let created = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                &format!("/api/websites/{website_id}/alerts"),
+                alert_body(
+                    channel,
+                    "https://nonexistent-chat-webhook.sparklytics.invalid/hook",
+                ),
+            ))
+            .await
+            .expect("create chat alert");
+        assert_eq!(created.status(), StatusCode::CREATED);
+        let alert = json_body(created).await["data"].clone();
+        assert_eq!(alert["channel"], channel);
+        let alert_id = alert["id"].as_str().expect("alert id");
+
+        let tested = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                &format!("/api/websites/{website_id}/alerts/{alert_id}/test"),
+                json!({}),
+            ))
+            .await
+            .expect("test chat alert");
+        assert_eq!(tested.status(), StatusCode::OK);
+        let delivery_id = json_body(tested).await["data"]["id"]
+            .as_str()
+            .expect("delivery id")
+            .to_string();
+
+        let stored = state
+            .db
+            .get_resendable_delivery(&website_id, &delivery_id)
+            .await
+            .expect("load delivery")
+            .expect("delivery exists");
+        let message: Value = serde_json::from_str(&stored.payload).expect("stored message");
+        let rendered = match channel {
+            "slack" => &message["blocks"][0]["text"]["text"],
+            "teams" => &message["attachments"][0]["content"]["body"][0]["text"],
+            _ => &message["embeds"][0]["title"],
+        };
+        assert_eq!(rendered, "Test alert: Traffic spike");
+    }
+}
@@ -0,0 +1,375 @@
+mod common;
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use sparklytics_core::config::{AppMode, AuthMode, Config};
+use sparklytics_duckdb::DuckDbBackend;
+use sparklytics_server::app::build_app;
+use sparklytics_server::state::AppState;
+
+const ADMIN_PASSWORD: &str = "strong_password_123";
+const BOOTSTRAP_PASSWORD: &str = "install_secret_123";
+const USER_PASSWORD: &str = "viewer_password_456";
+
+fn auth_config() -> Config {
+    Config {
+        port: 0,
+        data_dir: common::unique_data_dir("users"),
+        geoip_path: "/nonexistent/GeoLite2-City.mmdb".to_string(),
+        auth_mode: AuthMode::Local,
+        bootstrap_password: Some(BOOTSTRAP_PASSWORD.to_string()),
+        https: false,
+        retention_days: 365,
+        cors_origins: vec![],
+        session_days: 7,
+        buffer_flush_interval_ms: 5000,
+        buffer_max_size: 100,
+        mode: AppMode::SelfHosted,
+        argon2_memory_kb: 4096,
+        public_url: "http://localhost:3000".to_string(),
+        tracking_public_base: "http://localhost:3000".to_string(),
+        rate_limit_disable: true,
+        duckdb_memory_limit: "1GB".to_string(),
+    }
+}
+
+fn setup() -> axum::Router {
+    let db = DuckDbBackend::open_in_memory().expect("in-memory DuckDB");
+    build_app(Arc::new(AppState::new(db, auth_config())))
+}
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    cookie: Option<&str>,
+    body: Option<Value>,
+) -> (StatusCode, Option<String>, Value) {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("x-forwarded-for", "10.0.0.1");
+    if let Some(cookie) = cookie {
+        request = request.header("cookie", cookie);
+    }
+    if body.is_some() {
+        request = request.header("content-type", "application/json");
+    }
+    let request = request
+        .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    let status = response.status();
+    let cookie = response
+        .headers()
+        .get("set-cookie")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(str::to_string);
+    let bytes = response
+        .into_body()
+        .collect()
+        .await
+        .expect("read body")
+        .to_bytes();
+    let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, cookie, json)
+}
+
+/// Run setup, log in as the setup admin and return the session cookie.
+async fn admin_cookie(app: &axum::Router) -> String {
+    let (status, _, _) = send(
+        app,
+        "POST",
+        "/api/auth/setup",
+        None,
+        Some(json!({ "bootstrap_password": BOOTSTRAP_PASSWORD, "password": ADMIN_PASSWORD })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, cookie, _) = send(
+        app,
+        "POST",
+        "/api/auth/login",
+        None,
+        Some(json!({ "password": ADMIN_PASSWORD })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    cookie.expect("session cookie")
+}
+
+async fn create_website(app: &axum::Router, cookie: &str, domain: &str) -> String {
+    let (status, _, body) = send(
+        app,
+        "POST",
+        "/api/websites",
+        Some(cookie),
+        Some(json!({ "name": domain, "domain": domain })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["data"]["id"].as_str().expect("website id").to_string()
+}
+
+/// Invite `email`, accept the invite and return the new user's id and
+/// session cookie.
+async fn invite_and_accept(
+    app: &axum::Router,
+    cookie: &str,
+    email: &str,
+    role: &str,
+    website_ids: &[&str],
+) -> (String, String) {
+    let (status, _, invite) = send(
+        app,
+        "POST",
+        "/api/users/invites",
+        Some(cookie),
+        Some(json!({ "email": email, "role": role, "website_ids": website_ids })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{invite}");
+    let invite_url = invite["data"]["invite_url"].as_str().expect("invite url");
+    let token = invite_url
+        .split_once("token=")
+        .map(|(_, token)| token)
+        .expect("token in invite url");
+    assert!(invite["data"].get("token_hash").is_none());
+
+    let (status, user_cookie, accepted) = send(
+        app,
+        "POST",
+        "/api/auth/invites/accept",
+        None,
+        Some(json!({ "token": token, "name": "Invited", "password": USER_PASSWORD })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{accepted}");
+    assert_eq!(accepted["data"]["user"]["email"], email);
+    assert_eq!(accepted["data"]["user"]["role"], role);
+
+    let (status, _, replay) = send(
+        app,
+        "POST",
+        "/api/auth/invites/accept",
+        None,
+        Some(json!({ "token": token, "password": USER_PASSWORD })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND, "{replay}");
+
+    (
+        accepted["data"]["user"]["id"]
+            .as_str()
+            .expect("user id")
+            .to_string(),
+        user_cookie.expect("session cookie"),
+    )
+}
+
+#[tokio::test]
+async fn viewers_only_read_their_granted_websites() {
+    let app = setup();
+    let admin = admin_cookie(&app).await;
+    let granted = create_website(&app, &admin, "granted.example.com").await;
+    let other = create_website(&app, &admin, "other.example.com").await;
+
+    let (_, invited_cookie) =
+        invite_and_accept(&app, &admin, "viewer@example.com", "viewer", &[&granted]).await;
+
+    // A fresh email + password login works as well as the invite cookie.
+    let (status, cookie, _) = send(
+        &app,
+        "POST",
+        "/api/auth/login",
+        None,
+        Some(json!({ "email": "Viewer@Example.com", "password": USER_PASSWORD })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let viewer = cookie.expect("session cookie");
+    assert!(!invited_cookie.is_empty());
+
+    let (status, _, session) = send(&app, "GET", "/api/auth/session", Some(&viewer), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(session["data"]["role"], "viewer");
+    assert_eq!(session["data"]["website_ids"], json!([granted]));
+
+    let (status, _, websites) = send(&app, "GET", "/api/websites", Some(&viewer), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(websites["data"].as_array().expect("array").len(), 1);
+    assert_eq!(websites["data"][0]["id"], granted.as_str());
+    assert_eq!(websites["pagination"]["total"], 1);
+
+    let uri = format!("/api/websites/{granted}/stats");
+    let (status, _, _) = send(&app, "GET", &uri, Some(&viewer), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let uri = format!("/api/websites/{other}/stats");
+    let (status, _, _) = send(&app, "GET", &uri, Some(&viewer), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let uri = format!("/api/websites/{granted}");
+    let (status, _, _) = send(
+        &app,
+        "PUT",
+        &uri,
+        Some(&viewer),
+        Some(json!({ "name": "Renamed" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _, _) = send(
+        &app,
+        "POST",
+        "/api/websites",
+        Some(&viewer),
+        Some(json!({ "name": "New", "domain": "new.example.com" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _, _) = send(&app, "GET", "/api/users", Some(&viewer), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn owner_rules_and_disabling_users() {
+    let app = setup();
+    let owner = admin_cookie(&app).await;
+
+    let (owner_id, _) = invite_and_accept(&app, &owner, "owner@example.com", "owner", &[]).await;
+    let (admin_id, admin) =
+        invite_and_accept(&app, &owner, "admin@example.com", "admin", &[]).await;
+    let (viewer_id, viewer) =
+        invite_and_accept(&app, &owner, "viewer@example.com", "viewer", &[]).await;
+
+    // Admins manage viewers but cannot create or touch owners, nor themselves.
+    let (status, _, _) = send(
+        &app,
+        "POST",
+        "/api/users/invites",
+        Some(&admin),
+        Some(json!({ "email": "boss@example.com", "role": "owner" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _, _) = send(
+        &app,
+        "PUT",
+        &format!("/api/users/{owner_id}"),
+        Some(&admin),
+        Some(json!({ "role": "viewer" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _, _) = send(
+        &app,
+        "PUT",
+        &format!("/api/users/{admin_id}"),
+        Some(&admin),
+        Some(json!({ "role": "owner" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Inviting an existing account is rejected.
+    let (status, _, _) = send(
+        &app,
+        "POST",
+        "/api/users/invites",
+        Some(&admin),
+        Some(json!({ "email": "viewer@example.com", "role": "viewer" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Disabling a user ends their sessions and blocks new logins.
+    let (status, _, updated) = send(
+        &app,
+        "PUT",
+        &format!("/api/users/{viewer_id}"),
+        Some(&admin),
+        Some(json!({ "disabled": true })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(updated["data"]["disabled_at"].is_string());
+    let (status, _, _) = send(&app, "GET", "/api/websites", Some(&viewer), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _, _) = send(
+        &app,
+        "POST",
+        "/api/auth/login",
+        None,
+        Some(json!({ "email": "viewer@example.com", "password": USER_PASSWORD })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _, _) = send(
+        &app,
+        "DELETE",
+        &format!("/api/users/{viewer_id}"),
+        Some(&admin),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _, users) = send(&app, "GET", "/api/users", Some(&owner), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let emails: Vec<&str> = users["data"]
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|user| user["email"].as_str())
+        .collect();
+    assert_eq!(emails, vec!["owner@example.com", "admin@example.com"]);
+}
+
+#[tokio::test]
+async fn revoked_invites_cannot_be_accepted() {
+    let app = setup();
+    let owner = admin_cookie(&app).await;
+
+    let (status, _, invite) = send(
+        &app,
+        "POST",
+        "/api/users/invites",
+        Some(&owner),
+        Some(json!({ "email": "late@example.com", "role": "viewer" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let invite_id = invite["data"]["id"].as_str().expect("invite id");
+    let token = invite["data"]["invite_url"]
+        .as_str()
+        .and_then(|url| url.split_once("token="))
+        .map(|(_, token)| token.to_string())
+        .expect("token");
+
+    let (status, _, pending) = send(&app, "GET", "/api/users/invites", Some(&owner), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(pending["data"].as_array().expect("array").len(), 1);
+
+    let uri = format!("/api/users/invites/{invite_id}");
+    let (status, _, _) = send(&app, "DELETE", &uri, Some(&owner), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _, _) = send(&app, "DELETE", &uri, Some(&owner), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _, _) = send(
+        &app,
+        "POST",
+        "/api/auth/invites/accept",
+        None,
+        Some(json!({ "token": token, "password": USER_PASSWORD })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -269,6 +269,53 @@ async fn test_update_website() {
     assert_eq!(json["data"]["domain"], "updated.example.com");
 }
 
+#[tokio::test]
+async fn test_update_website_session_rules() {
+    let (_state, app) = setup().await;
+    let website_id = create_test_website(&app).await;
+
+    let put = |body: Value| {
+        Request::builder()
+            .method("PUT")
+            .uri(format!("/api/websites/{}", website_id))
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .expect("build request")
+    };
+
+    let response = app
+        .clone()
+        .oneshot(put(json!({ "session_timeout_minutes": 0 })))
+        .await
+        .expect("request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .clone()
+        .oneshot(put(json!({
+            "session_timeout_minutes": 120,
+            "session_split_at_midnight": true,
+        })))
+        .await
+        .expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = json_body(response).await;
+    assert_eq!(json["data"]["session_timeout_minutes"], 120);
+    assert_eq!(json["data"]["session_split_at_midnight"], true);
+    assert_eq!(json["data"]["session_split_on_campaign"], false);
+
+    // `null` restores the default timeout without touching the split flags.
+    let response = app
+        .clone()
+        .oneshot(put(json!({ "session_timeout_minutes": null })))
+        .await
+        .expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = json_body(response).await;
+    assert!(json["data"]["session_timeout_minutes"].is_null());
+    assert_eq!(json["data"]["session_split_at_midnight"], true);
+}
+
 #[tokio::test]
 async fn test_get_website_returns_tracking_snippet() {
     let (_state, app) = setup().await;
@@ -502,6 +549,132 @@ async fn test_realtime_active_visitors() {
     );
 }
 
+/// Read SSE frames from `body` until one named `event_name` arrives and
+/// return its JSON payload.
+async fn next_sse_frame(body: &mut Body, buffer: &mut String, event_name: &str) -> Value {
+    loop {
+        while let Some(end) = buffer.find("\n\n") {
+            let frame: String = buffer.drain(..end + 2).collect();
+            let mut name = None;
+            let mut data = None;
+            for line in frame.lines() {
+                if let Some(value) = line.strip_prefix("event:") {
+                    name = Some(value.trim().to_string());
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    data = Some(value.trim().to_string());
+                }
+            }
+            if name.as_deref() == Some(event_name) {
+                return serde_json::from_str(&data.expect("frame data")).expect("parse frame");
+            }
+        }
+        let frame = tokio::time::timeout(std::time::Duration::from_secs(5), body.frame())
+            .await
+            .expect("SSE frame before timeout")
+            .expect("stream open")
+            .expect("read frame");
+        if let Ok(bytes) = frame.into_data() {
+            buffer.push_str(std::str::from_utf8(&bytes).expect("utf-8 frame"));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_realtime_stream_pushes_collected_events() {
+    let (_state, app) = setup().await;
+    let website_id = create_test_website(&app).await;
+
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/websites/{}/realtime/stream", website_id))
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/event-stream",
+        "stream should be served as SSE"
+    );
+    let mut body = response.into_body();
+    let mut buffer = String::new();
+
+    let initial = next_sse_frame(&mut body, &mut buffer, "active_visitors").await;
+    assert_eq!(initial["active_visitors"], 0);
+
+    // Pushed on acceptance, before any buffer flush reaches DuckDB.
+    let event = json!({
+        "website_id": website_id,
+        "type": "pageview",
+        "url": "/live",
+        "language": "en-US"
+    });
+    let response = app
+        .clone()
+        .oneshot(collect_request(&event.to_string()))
+        .await
+        .expect("request");
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let pushed = next_sse_frame(&mut body, &mut buffer, "event").await;
+    assert_eq!(pushed["url"], "/live");
+    assert_eq!(pushed["event_type"], "pageview");
+    let count = next_sse_frame(&mut body, &mut buffer, "active_visitors").await;
+    assert_eq!(count["active_visitors"], 1);
+}
+
+#[tokio::test]
+async fn test_realtime_stream_resyncs_clients_that_fall_behind() {
+    let (state, app) = setup().await;
+    let website_id = create_test_website(&app).await;
+
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/websites/{}/realtime/stream", website_id))
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut body = response.into_body();
+    let mut buffer = String::new();
+    next_sse_frame(&mut body, &mut buffer, "active_visitors").await;
+
+    // More events than a subscriber buffers, published while the client is
+    // not reading.
+    let event: sparklytics_core::event::Event = serde_json::from_value(json!({
+        "id": "evt_burst",
+        "website_id": website_id,
+        "session_id": "sess_burst",
+        "visitor_id": "visitor_burst",
+        "event_type": "pageview",
+        "url": "/burst",
+        "is_bot": false,
+        "bot_score": 0,
+        "created_at": chrono::Utc::now()
+    }))
+    .expect("event");
+    state
+        .realtime_feed
+        .publish(&vec![event; 1100], chrono::Utc::now());
+
+    let resync = next_sse_frame(&mut body, &mut buffer, "resync").await;
+    assert!(resync["skipped"].as_u64().expect("skipped") > 0);
+    let pushed = next_sse_frame(&mut body, &mut buffer, "event").await;
+    assert_eq!(pushed["url"], "/burst");
+}
+
+#[tokio::test]
+async fn test_realtime_stream_unknown_website_returns_404() {
+    let (_state, app) = setup().await;
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/websites/site_missing/realtime/stream")
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.oneshot(request).await.expect("request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 // ============================================================
 // BDD: Stats with invalid country filter returns 400
 // ============================================================
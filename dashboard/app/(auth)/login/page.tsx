@@ -4,16 +4,20 @@ import { useEffect, useState } from 'react';
 import { useRouter } from 'next/navigation';
 import { useQueryClient } from '@tanstack/react-query';
 import { Loader2 } from 'lucide-react';
-import { api } from '@/lib/api';
+import { api, ApiError } from '@/lib/api';
 import { AUTH_QUERY_KEY } from '@/hooks/useAuth';
 
 export default function LoginPage() {
   const router = useRouter();
   const queryClient = useQueryClient();
+  const [email, setEmail] = useState('');
   const [password, setPassword] = useState('');
+  const [totpCode, setTotpCode] = useState('');
+  const [totpRequired, setTotpRequired] = useState(false);
   const [error, setError] = useState('');
   const [loading, setLoading] = useState(false);
   const [ready, setReady] = useState(false);
+  const [sso, setSso] = useState(false);
 
   useEffect(() => {
     let cancelled = false;
@@ -44,6 +48,7 @@ export default function LoginPage() {
           router.replace('/dashboard');
           return;
         }
+        setSso(status.mode === 'oidc');
       } catch {
         // If status check fails, keep login available.
       }
@@ -63,10 +68,14 @@ export default function LoginPage() {
     setError('');
     setLoading(true);
     try {
-      await api.login(password);
+      await api.login(password, email.trim() || undefined, totpCode.trim() || undefined);
       await queryClient.removeQueries({ queryKey: AUTH_QUERY_KEY, exact: true });
       router.push('/dashboard');
     } catch (err: unknown) {
+      if (err instanceof ApiError && err.code === 'totp_required') {
+        setTotpRequired(true);
+        return;
+      }
       setError(err instanceof Error ? err.message : 'Login failed');
     } finally {
       setLoading(false);
@@ -93,44 +102,95 @@ export default function LoginPage() {
           </span>
         </div>
 
-        <div className="bg-surface-1 border border-line rounded-lg p-6">
-          <h1 className="text-sm font-medium text-ink mb-1">Sign in</h1>
-          <p className="text-xs text-ink-3 mb-6">
-            Use the admin password for this self-hosted instance. If this is your first visit in
-            local auth mode, complete setup first.
-          </p>
-
-          <form onSubmit={handleSubmit} className="space-y-4">
-            <div>
-              <label htmlFor="password" className="block text-xs text-ink-2 mb-2">
-                Password
-              </label>
-              <input
-                id="password"
-                type="password"
-                value={password}
-                onChange={(e) => setPassword(e.target.value)}
-                required
-                autoFocus
-                className="w-full bg-surface-input border border-line rounded-md px-3 py-2 text-sm text-ink placeholder-ink-4 focus:outline-none focus:border-line-3 focus:ring-2 focus:ring-spark focus:ring-offset-2 focus:ring-offset-surface-1 transition-colors"
-                placeholder="Enter your password"
-              />
-            </div>
-
-            {error && (
-              <p className="text-xs text-down">{error}</p>
-            )}
-
-            <button
-              type="submit"
-              disabled={loading}
-              className="w-full bg-spark hover:bg-spark-dim text-canvas font-medium text-sm py-2 rounded transition-colors disabled:opacity-50 disabled:cursor-not-allowed flex items-center justify-center gap-2"
+        {sso ? (
+          <div className="bg-surface-1 border border-line rounded-lg p-6">
+            <h1 className="text-sm font-medium text-ink mb-1">Sign in</h1>
+            <p className="text-xs text-ink-3 mb-6">
+              This instance uses your organization&apos;s single sign-on.
+            </p>
+            <a
+              href="/api/auth/oidc/login"
+              className="w-full bg-spark hover:bg-spark-dim text-canvas font-medium text-sm py-2 rounded transition-colors flex items-center justify-center gap-2"
             >
-              {loading && <Loader2 className="w-4 h-4 animate-spin" />}
-              Sign in
-            </button>
-          </form>
-        </div>
+              Sign in with SSO
+            </a>
+          </div>
+        ) : (
+          <div className="bg-surface-1 border border-line rounded-lg p-6">
+            <h1 className="text-sm font-medium text-ink mb-1">Sign in</h1>
+            <p className="text-xs text-ink-3 mb-6">
+              Sign in with your email and password, or leave email empty to use the admin password.
+              If this is your first visit in local auth mode, complete setup first.
+            </p>
+
+            <form onSubmit={handleSubmit} className="space-y-4">
+              <div>
+                <label htmlFor="email" className="block text-xs text-ink-2 mb-2">
+                  Email <span className="text-ink-4">(optional)</span>
+                </label>
+                <input
+                  id="email"
+                  type="email"
+                  value={email}
+                  onChange={(e) => setEmail(e.target.value)}
+                  autoFocus
+                  autoComplete="username"
+                  className="w-full bg-surface-input border border-line rounded-md px-3 py-2 text-sm text-ink placeholder-ink-4 focus:outline-none focus:border-line-3 focus:ring-2 focus:ring-spark focus:ring-offset-2 focus:ring-offset-surface-1 transition-colors"
+                  placeholder="you@example.com"
+                />
+              </div>
+
+              <div>
+                <label htmlFor="password" className="block text-xs text-ink-2 mb-2">
+                  Password
+                </label>
+                <input
+                  id="password"
+                  type="password"
+                  value={password}
+                  onChange={(e) => setPassword(e.target.value)}
+                  required
+                  autoComplete="current-password"
+                  className="w-full bg-surface-input border border-line rounded-md px-3 py-2 text-sm text-ink placeholder-ink-4 focus:outline-none focus:border-line-3 focus:ring-2 focus:ring-spark focus:ring-offset-2 focus:ring-offset-surface-1 transition-colors"
+                  placeholder="Enter your password"
+                />
+              </div>
+
+              {totpRequired && (
+                <div>
+                  <label htmlFor="totp-code" className="block text-xs text-ink-2 mb-2">
+                    Authentication code
+                  </label>
+                  <input
+                    id="totp-code"
+                    type="text"
+                    value={totpCode}
+                    onChange={(e) => setTotpCode(e.target.value)}
+                    required
+                    autoFocus
+                    inputMode="numeric"
+                    autoComplete="one-time-code"
+                    className="w-full bg-surface-input border border-line rounded-md px-3 py-2 text-sm text-ink placeholder-ink-4 focus:outline-none focus:border-line-3 focus:ring-2 focus:ring-spark focus:ring-offset-2 focus:ring-offset-surface-1 transition-colors"
+                    placeholder="6-digit code or a recovery code"
+                  />
+                </div>
+              )}
+
+              {error && (
+                <p className="text-xs text-down">{error}</p>
+              )}
+
+              <button
+                type="submit"
+                disabled={loading}
+                className="w-full bg-spark hover:bg-spark-dim text-canvas font-medium text-sm py-2 rounded transition-colors disabled:opacity-50 disabled:cursor-not-allowed flex items-center justify-center gap-2"
+              >
+                {loading && <Loader2 className="w-4 h-4 animate-spin" />}
+                Sign in
+              </button>
+            </form>
+          </div>
+        )}
       </div>
     </div>
   );
@@ -0,0 +1,151 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { useRouter } from 'next/navigation';
+import { useQueryClient } from '@tanstack/react-query';
+import { Loader2 } from 'lucide-react';
+import { api } from '@/lib/api';
+import { AUTH_QUERY_KEY } from '@/hooks/useAuth';
+
+const MIN_PASSWORD_LENGTH = 12;
+
+export default function InvitePage() {
+  const router = useRouter();
+  const queryClient = useQueryClient();
+  const [token, setToken] = useState<string | null>(null);
+  const [name, setName] = useState('');
+  const [password, setPassword] = useState('');
+  const [confirm, setConfirm] = useState('');
+  const [error, setError] = useState('');
+  const [loading, setLoading] = useState(false);
+
+  // Read the token client-side; the dashboard is a static export.
+  useEffect(() => {
+    setToken(new URLSearchParams(window.location.search).get('token') ?? '');
+  }, []);
+
+  async function handleSubmit(e: React.FormEvent) {
+    e.preventDefault();
+    setError('');
+    if (password !== confirm) {
+      setError('Passwords do not match');
+      return;
+    }
+    if (password.length < MIN_PASSWORD_LENGTH) {
+      setError(`Password must be at least ${MIN_PASSWORD_LENGTH} characters`);
+      return;
+    }
+    setLoading(true);
+    try {
+      await api.acceptInvite(token ?? '', password, name.trim() || undefined);
+      await queryClient.removeQueries({ queryKey: AUTH_QUERY_KEY, exact: true });
+      router.push('/dashboard');
+    } catch (err: unknown) {
+      setError(err instanceof Error ? err.message : 'Could not accept invite');
+    } finally {
+      setLoading(false);
+    }
+  }
+
+  if (token === null) {
+    return (
+      <div className="min-h-screen bg-canvas flex items-center justify-center">
+        <div className="flex items-center gap-2 text-sm text-ink-3">
+          <Loader2 className="w-4 h-4 animate-spin" />
+          Loading invite...
+        </div>
+      </div>
+    );
+  }
+
+  const inputClass =
+    'w-full bg-surface-input border border-line rounded-md px-3 py-2 text-sm text-ink placeholder-ink-4 focus:outline-none focus:border-line-3 focus:ring-2 focus:ring-spark focus:ring-offset-2 focus:ring-offset-surface-1 transition-colors';
+
+  return (
+    <div className="min-h-screen bg-canvas flex items-center justify-center">
+      <div className="w-full max-w-sm px-6">
+        <div className="mb-8 text-center">
+          <span className="text-lg font-semibold tracking-tight text-ink">
+            spark<span className="text-spark">lytics</span>
+          </span>
+        </div>
+
+        <div className="bg-surface-1 border border-line rounded-lg p-6">
+          <h1 className="text-sm font-medium text-ink mb-1">Accept invite</h1>
+          {token ? (
+            <>
+              <p className="text-xs text-ink-3 mb-6">
+                Choose a password to create your account. You will sign in with the email address
+                the invite was sent to.
+              </p>
+
+              <form onSubmit={handleSubmit} className="space-y-4">
+                <div>
+                  <label htmlFor="name" className="block text-xs text-ink-2 mb-2">
+                    Name <span className="text-ink-4">(optional)</span>
+                  </label>
+                  <input
+                    id="name"
+                    type="text"
+                    value={name}
+                    onChange={(e) => setName(e.target.value)}
+                    autoFocus
+                    className={inputClass}
+                    placeholder="Your name"
+                  />
+                </div>
+
+                <div>
+                  <label htmlFor="password" className="block text-xs text-ink-2 mb-2">
+                    Password
+                  </label>
+                  <input
+                    id="password"
+                    type="password"
+                    value={password}
+                    onChange={(e) => setPassword(e.target.value)}
+                    required
+                    autoComplete="new-password"
+                    className={inputClass}
+                    placeholder="Choose a strong password"
+                  />
+                </div>
+
+                <div>
+                  <label htmlFor="confirm" className="block text-xs text-ink-2 mb-2">
+                    Confirm password
+                  </label>
+                  <input
+                    id="confirm"
+                    type="password"
+                    value={confirm}
+                    onChange={(e) => setConfirm(e.target.value)}
+                    required
+                    autoComplete="new-password"
+                    className={inputClass}
+                    placeholder="Repeat password"
+                  />
+                </div>
+
+                {error && <p className="text-xs text-down">{error}</p>}
+
+                <button
+                  type="submit"
+                  disabled={loading}
+                  className="w-full bg-spark hover:bg-spark-dim text-canvas font-medium text-sm py-2 rounded transition-colors disabled:opacity-50 disabled:cursor-not-allowed flex items-center justify-center gap-2"
+                >
+                  {loading && <Loader2 className="w-4 h-4 animate-spin" />}
+                  Create account
+                </button>
+              </form>
+            </>
+          ) : (
+            <p className="text-xs text-down">
+              This invite link is missing its token. Ask your administrator for a new invite.
+            </p>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -27,16 +27,37 @@ function Row({
   return (
     <tr className="border-t border-line">
       <td className="px-3 py-2 text-sm text-ink">{rule.name}</td>
-      <td className="px-3 py-2 text-xs text-ink">{rule.metric}</td>
-      <td className="px-3 py-2 text-xs text-ink">{rule.condition_type}</td>
+      <td className="px-3 py-2 text-xs text-ink">
+        {rule.metric === 'custom_events' ? `events: ${rule.event_name}` : rule.metric}
+        {rule.granularity === 'hour' && <span className="text-ink-3"> · hourly</span>}
+        {Object.keys(rule.filters).length > 0 && (
+          <div className="text-ink-3">
+            {Object.entries(rule.filters)
+              .map(([key, value]) => `${key.replace('filter_', '')}: ${value}`)
+              .join(', ')}
+          </div>
+        )}
+      </td>
+      <td className="px-3 py-2 text-xs text-ink">
+        {rule.condition_type}
+        {rule.baseline !== 'flat' && <span className="text-ink-3"> · {rule.baseline.replace('_', ' ')}</span>}
+      </td>
       <td className="px-3 py-2 text-xs text-ink">{rule.threshold_value}</td>
       <td className="px-3 py-2 text-xs text-ink">
         {rule.channel}: {rule.target}
+        {rule.channel === 'webhook' && (
+          <div className="text-ink-3">
+            Signing secret: <code className="font-mono">{rule.signing_secret}</code>
+          </div>
+        )}
       </td>
       <td className="px-3 py-2 text-xs">
         <span className={`px-1.5 py-0.5 rounded-sm border ${rule.is_active ? 'border-spark text-spark' : 'border-line text-ink-3'}`}>
           {rule.is_active ? 'Active' : 'Inactive'}
         </span>
+        {rule.is_firing && (
+          <span className="ml-1 px-1.5 py-0.5 rounded-sm border border-down/30 text-down">Firing</span>
+        )}
       </td>
       <td className="px-3 py-2">
         <div className="flex items-center gap-1">
@@ -5,7 +5,14 @@ import { Button } from '@/components/ui/button';
 import {
   useCreateAlertRule,
 } from '@/hooks/useNotifications';
-import type { AlertConditionType, AlertMetric, NotificationChannel } from '@/lib/api';
+import type {
+  AlertBaseline,
+  AlertConditionType,
+  AlertFilters,
+  AlertGranularity,
+  AlertMetric,
+  NotificationChannel,
+} from '@/lib/api';
 
 interface CreateAlertDialogProps {
   websiteId: string;
@@ -19,17 +26,25 @@ export function CreateAlertDialog({ websiteId }: CreateAlertDialogProps) {
   const [conditionType, setConditionType] = useState<AlertConditionType>('spike');
   const [thresholdValue, setThresholdValue] = useState('2');
   const [lookbackDays, setLookbackDays] = useState('7');
+  const [baseline, setBaseline] = useState<AlertBaseline>('flat');
+  const [eventName, setEventName] = useState('');
+  const [granularity, setGranularity] = useState<AlertGranularity>('day');
+  const [pageFilter, setPageFilter] = useState('');
+  const [countryFilter, setCountryFilter] = useState('');
+  const [cooldownMinutes, setCooldownMinutes] = useState('60');
   const [channel, setChannel] = useState<NotificationChannel>('email');
   const [target, setTarget] = useState('');
 
-  const canSubmit = name.trim() && target.trim();
+  const isThreshold = conditionType === 'threshold_above' || conditionType === 'threshold_below';
+  const isCustomEvent = metric === 'custom_events';
+  const canSubmit = name.trim() && target.trim() && (!isCustomEvent || eventName.trim());
 
   return (
     <div className="border border-line rounded-lg bg-surface-1 p-4 space-y-3">
       <div className="flex items-center justify-between gap-3">
         <div>
           <p className="text-sm font-medium text-ink">Create alert rule</p>
-          <p className="text-xs text-ink-3">Trigger notifications on spikes, drops, or thresholds.</p>
+          <p className="text-xs text-ink-3">Trigger notifications on spikes, drops, or thresholds, and again once resolved.</p>
         </div>
         <Button type="button" size="sm" onClick={() => setOpen((v) => !v)}>
           {open ? 'Close' : 'New alert'}
@@ -59,8 +74,56 @@ export function CreateAlertDialog({ websiteId }: CreateAlertDialogProps) {
               <option value="visitors">Visitors</option>
               <option value="conversions">Conversions</option>
               <option value="conversion_rate">Conversion rate</option>
+              <option value="custom_events">Custom events</option>
+              <option value="bounce_rate">Bounce rate</option>
+              <option value="session_duration">Session duration</option>
+              <option value="bot_share">Bot share</option>
             </select>
           </label>
+          {isCustomEvent && (
+            <label className="space-y-1">
+              <span className="text-xs text-ink-2">Event name</span>
+              <input
+                aria-label="Event name"
+                value={eventName}
+                onChange={(e) => setEventName(e.target.value)}
+                className="w-full bg-canvas border border-line rounded-md px-2 py-2 text-sm text-ink"
+                placeholder="signup"
+              />
+            </label>
+          )}
+          <label className="space-y-1">
+            <span className="text-xs text-ink-2">Check</span>
+            <select
+              aria-label="Granularity"
+              value={granularity}
+              onChange={(e) => setGranularity(e.target.value as AlertGranularity)}
+              className="w-full bg-canvas border border-line rounded-md px-2 py-2 text-sm text-ink"
+            >
+              <option value="day">Daily (today so far)</option>
+              <option value="hour">Hourly (last complete hour)</option>
+            </select>
+          </label>
+          <label className="space-y-1">
+            <span className="text-xs text-ink-2">Page filter</span>
+            <input
+              aria-label="Page filter"
+              value={pageFilter}
+              onChange={(e) => setPageFilter(e.target.value)}
+              className="w-full bg-canvas border border-line rounded-md px-2 py-2 text-sm text-ink"
+              placeholder="/checkout"
+            />
+          </label>
+          <label className="space-y-1">
+            <span className="text-xs text-ink-2">Country filter</span>
+            <input
+              aria-label="Country filter"
+              value={countryFilter}
+              onChange={(e) => setCountryFilter(e.target.value)}
+              className="w-full bg-canvas border border-line rounded-md px-2 py-2 text-sm text-ink"
+              placeholder="DE"
+            />
+          </label>
           <label className="space-y-1">
             <span className="text-xs text-ink-2">Condition</span>
             <select
@@ -73,10 +136,30 @@ export function CreateAlertDialog({ websiteId }: CreateAlertDialogProps) {
               <option value="drop">Drop</option>
               <option value="threshold_above">Threshold above</option>
               <option value="threshold_below">Threshold below</option>
+              <option value="anomaly">Anomaly (either direction)</option>
             </select>
           </label>
+          {!isThreshold && (
+            <label className="space-y-1">
+              <span className="text-xs text-ink-2">Baseline</span>
+              <select
+                aria-label="Baseline"
+                value={baseline}
+                onChange={(e) => {
+                  const next = e.target.value as AlertBaseline;
+                  setBaseline(next);
+                  if (next !== 'flat' && Number(lookbackDays) < 14) setLookbackDays('28');
+                }}
+                className="w-full bg-canvas border border-line rounded-md px-2 py-2 text-sm text-ink"
+              >
+                <option value="flat">All recent days</option>
+                <option value="same_weekday">Same weekday and time</option>
+                <option value="holt_winters">Seasonal forecast (Holt-Winters)</option>
+              </select>
+            </label>
+          )}
           <label className="space-y-1">
-            <span className="text-xs text-ink-2">Threshold</span>
+            <span className="text-xs text-ink-2">{isThreshold ? 'Threshold' : 'Sensitivity (standard deviations)'}</span>
             <input
               aria-label="Threshold"
               value={thresholdValue}
@@ -95,6 +178,17 @@ export function CreateAlertDialog({ websiteId }: CreateAlertDialogProps) {
               className="w-full bg-canvas border border-line rounded-md px-2 py-2 text-sm text-ink"
             />
           </label>
+          <label className="space-y-1">
+            <span className="text-xs text-ink-2">Cooldown (minutes)</span>
+            <input
+              aria-label="Cooldown minutes"
+              value={cooldownMinutes}
+              onChange={(e) => setCooldownMinutes(e.target.value)}
+              type="number"
+              min={0}
+              className="w-full bg-canvas border border-line rounded-md px-2 py-2 text-sm text-ink"
+            />
+          </label>
           <label className="space-y-1">
             <span className="text-xs text-ink-2">Channel</span>
             <select
@@ -105,6 +199,9 @@ export function CreateAlertDialog({ websiteId }: CreateAlertDialogProps) {
             >
               <option value="email">Email</option>
               <option value="webhook">Webhook</option>
+              <option value="slack">Slack</option>
+              <option value="teams">Microsoft Teams</option>
+              <option value="discord">Discord</option>
             </select>
           </label>
           <label className="space-y-1">
@@ -122,6 +219,9 @@ export function CreateAlertDialog({ websiteId }: CreateAlertDialogProps) {
               size="sm"
               disabled={!canSubmit || createAlert.isPending}
               onClick={() => {
+                const filters: AlertFilters = {};
+                if (pageFilter.trim()) filters.filter_page = pageFilter.trim();
+                if (countryFilter.trim()) filters.filter_country = countryFilter.trim();
                 createAlert.mutate(
                   {
                     name: name.trim(),
@@ -129,6 +229,11 @@ export function CreateAlertDialog({ websiteId }: CreateAlertDialogProps) {
                     condition_type: conditionType,
                     threshold_value: Number(thresholdValue),
                     lookback_days: Number(lookbackDays),
+                    baseline: isThreshold ? 'flat' : baseline,
+                    event_name: isCustomEvent ? eventName.trim() : undefined,
+                    filters,
+                    granularity,
+                    cooldown_minutes: Number(cooldownMinutes),
                     channel,
                     target: target.trim(),
                   },
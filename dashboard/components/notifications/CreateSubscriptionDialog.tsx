@@ -83,6 +83,9 @@ export function CreateSubscriptionDialog({ websiteId }: CreateSubscriptionDialog
             >
               <option value="email">Email</option>
               <option value="webhook">Webhook</option>
+              <option value="slack">Slack</option>
+              <option value="teams">Microsoft Teams</option>
+              <option value="discord">Discord</option>
             </select>
           </label>
           <label className="space-y-1 md:col-span-2">
@@ -1,6 +1,6 @@
 'use client';
 
-import { useNotificationHistory } from '@/hooks/useNotifications';
+import { useNotificationHistory, useReplayNotificationDelivery } from '@/hooks/useNotifications';
 
 interface DeliveryHistoryTableProps {
   websiteId: string;
@@ -9,6 +9,13 @@ interface DeliveryHistoryTableProps {
 export function DeliveryHistoryTable({ websiteId }: DeliveryHistoryTableProps) {
   const { data, isLoading } = useNotificationHistory(websiteId, 50);
   const history = data?.data ?? [];
+  const replay = useReplayNotificationDelivery(websiteId);
+
+  function statusClass(status: string) {
+    if (status === 'sent') return 'border-spark text-spark';
+    if (status === 'retrying') return 'border-line text-ink-2';
+    return 'border-down text-down';
+  }
 
   return (
     <div className="border border-line rounded-lg bg-surface-1 overflow-hidden">
@@ -18,17 +25,19 @@ export function DeliveryHistoryTable({ websiteId }: DeliveryHistoryTableProps) {
             <th className="px-3 py-2 font-medium">Time</th>
             <th className="px-3 py-2 font-medium">Source</th>
             <th className="px-3 py-2 font-medium">Status</th>
+            <th className="px-3 py-2 font-medium">Attempts</th>
             <th className="px-3 py-2 font-medium">Error</th>
+            <th className="px-3 py-2 font-medium" />
           </tr>
         </thead>
         <tbody>
           {isLoading ? (
             <tr>
-              <td colSpan={4} className="px-3 py-6 text-sm text-ink-3">Loading delivery history…</td>
+              <td colSpan={6} className="px-3 py-6 text-sm text-ink-3">Loading delivery history…</td>
             </tr>
           ) : history.length === 0 ? (
             <tr>
-              <td colSpan={4} className="px-3 py-6 text-sm text-ink-3">No deliveries recorded yet.</td>
+              <td colSpan={6} className="px-3 py-6 text-sm text-ink-3">No deliveries recorded yet.</td>
             </tr>
           ) : (
             history.map((row) => (
@@ -36,11 +45,26 @@ export function DeliveryHistoryTable({ websiteId }: DeliveryHistoryTableProps) {
                 <td className="px-3 py-2 text-xs text-ink">{row.delivered_at}</td>
                 <td className="px-3 py-2 text-xs text-ink">{row.source_type}:{row.source_id}</td>
                 <td className="px-3 py-2 text-xs">
-                  <span className={`px-1.5 py-0.5 rounded-sm border ${row.status === 'sent' ? 'border-spark text-spark' : 'border-down text-down'}`}>
+                  <span className={`px-1.5 py-0.5 rounded-sm border ${statusClass(row.status)}`}>
                     {row.status}
                   </span>
                 </td>
+                <td className="px-3 py-2 text-xs text-ink">
+                  {row.attempt_count}
+                  {row.next_attempt_at && <span className="text-ink-3"> · next {row.next_attempt_at}</span>}
+                </td>
                 <td className="px-3 py-2 text-xs text-ink-3">{row.error_message ?? '—'}</td>
+                <td className="px-3 py-2 text-xs text-right">
+                  {row.status !== 'sent' && row.channel && (
+                    <button
+                      onClick={() => replay.mutate(row.id)}
+                      disabled={replay.isPending}
+                      className="text-ink-3 hover:text-ink transition-colors disabled:opacity-50"
+                    >
+                      Replay
+                    </button>
+                  )}
+                </td>
               </tr>
             ))
           )}
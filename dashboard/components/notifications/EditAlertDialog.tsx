@@ -11,7 +11,14 @@ import {
   DialogDescription,
 } from '@/components/ui/dialog';
 import { Button } from '@/components/ui/button';
-import type { AlertRule, AlertMetric, AlertConditionType, NotificationChannel } from '@/lib/api';
+import type {
+  AlertRule,
+  AlertMetric,
+  AlertConditionType,
+  AlertBaseline,
+  AlertGranularity,
+  NotificationChannel,
+} from '@/lib/api';
 
 const inputClass =
   'w-full px-3 py-2 text-sm bg-canvas border border-line rounded-md text-ink placeholder:text-ink-4 focus:outline-none focus:ring-2 focus:ring-spark focus:border-spark';
@@ -21,9 +28,23 @@ const selectClass =
 
 const labelClass = 'block text-xs font-medium text-ink-3 mb-1';
 
-const METRICS: AlertMetric[] = ['pageviews', 'visitors', 'conversions', 'conversion_rate'];
-const CONDITIONS: AlertConditionType[] = ['spike', 'drop', 'threshold_above', 'threshold_below'];
-const CHANNELS: NotificationChannel[] = ['email', 'webhook'];
+const METRICS: AlertMetric[] = [
+  'pageviews',
+  'visitors',
+  'conversions',
+  'conversion_rate',
+  'custom_events',
+  'bounce_rate',
+  'session_duration',
+  'bot_share',
+];
+const CONDITIONS: AlertConditionType[] = ['spike', 'drop', 'threshold_above', 'threshold_below', 'anomaly'];
+const BASELINES: { value: AlertBaseline; label: string }[] = [
+  { value: 'flat', label: 'All recent days' },
+  { value: 'same_weekday', label: 'Same weekday and time' },
+  { value: 'holt_winters', label: 'Seasonal forecast (Holt-Winters)' },
+];
+const CHANNELS: NotificationChannel[] = ['email', 'webhook', 'slack', 'teams', 'discord'];
 
 interface EditAlertDialogProps {
   rule: AlertRule | null;
@@ -32,6 +53,12 @@ interface EditAlertDialogProps {
     metric: AlertMetric;
     condition_type: AlertConditionType;
     threshold_value: number;
+    lookback_days: number;
+    baseline: AlertBaseline;
+    event_name: string | null;
+    filters: AlertRule['filters'];
+    granularity: AlertGranularity;
+    cooldown_minutes: number;
     channel: NotificationChannel;
     target: string;
   }) => void;
@@ -42,6 +69,13 @@ export function EditAlertDialog({ rule, isPending, onSave, onClose }: EditAlertD
   const [metric, setMetric] = useState<AlertMetric>('pageviews');
   const [conditionType, setConditionType] = useState<AlertConditionType>('spike');
   const [thresholdValue, setThresholdValue] = useState('');
+  const [lookbackDays, setLookbackDays] = useState('7');
+  const [baseline, setBaseline] = useState<AlertBaseline>('flat');
+  const [eventName, setEventName] = useState('');
+  const [granularity, setGranularity] = useState<AlertGranularity>('day');
+  const [pageFilter, setPageFilter] = useState('');
+  const [countryFilter, setCountryFilter] = useState('');
+  const [cooldownMinutes, setCooldownMinutes] = useState('60');
   const [channel, setChannel] = useState<NotificationChannel>('email');
   const [target, setTarget] = useState('');
 
@@ -50,13 +84,24 @@ export function EditAlertDialog({ rule, isPending, onSave, onClose }: EditAlertD
       setMetric(rule.metric);
       setConditionType(rule.condition_type);
       setThresholdValue(String(rule.threshold_value));
+      setLookbackDays(String(rule.lookback_days));
+      setBaseline(rule.baseline);
+      setEventName(rule.event_name ?? '');
+      setGranularity(rule.granularity);
+      setPageFilter(rule.filters.filter_page ?? '');
+      setCountryFilter(rule.filters.filter_country ?? '');
+      setCooldownMinutes(String(rule.cooldown_minutes));
       setChannel(rule.channel);
       setTarget(rule.target);
     }
   }, [rule]);
 
+  const isThreshold = conditionType === 'threshold_above' || conditionType === 'threshold_below';
+  const isCustomEvent = metric === 'custom_events';
+
   function handleSubmit() {
     if (!rule || !target.trim()) return;
+    if (isCustomEvent && !eventName.trim()) return;
     if (!thresholdValue.trim()) return;
     const threshold = Number(thresholdValue);
     if (!Number.isFinite(threshold)) return;
@@ -64,6 +109,17 @@ export function EditAlertDialog({ rule, isPending, onSave, onClose }: EditAlertD
       metric,
       condition_type: conditionType,
       threshold_value: threshold,
+      lookback_days: Number(lookbackDays),
+      baseline: isThreshold ? 'flat' : baseline,
+      event_name: isCustomEvent ? eventName.trim() : null,
+      // Keep filters the dialog does not edit.
+      filters: {
+        ...rule.filters,
+        filter_page: pageFilter.trim() || undefined,
+        filter_country: countryFilter.trim() || undefined,
+      },
+      granularity,
+      cooldown_minutes: Number(cooldownMinutes),
       channel,
       target: target.trim(),
     });
@@ -81,10 +137,50 @@ export function EditAlertDialog({ rule, isPending, onSave, onClose }: EditAlertD
           <label className="block">
             <span className={labelClass}>Metric</span>
             <select value={metric} onChange={(e) => setMetric(e.target.value as AlertMetric)} className={selectClass}>
-              {METRICS.map((m) => <option key={m} value={m}>{m}</option>)}
+              {METRICS.map((m) => <option key={m} value={m}>{m.replace('_', ' ')}</option>)}
             </select>
           </label>
 
+          {isCustomEvent && (
+            <label className="block">
+              <span className={labelClass}>Event Name</span>
+              <input
+                value={eventName}
+                onChange={(e) => setEventName(e.target.value)}
+                placeholder="e.g. signup"
+                className={inputClass}
+              />
+            </label>
+          )}
+
+          <label className="block">
+            <span className={labelClass}>Check</span>
+            <select value={granularity} onChange={(e) => setGranularity(e.target.value as AlertGranularity)} className={selectClass}>
+              <option value="day">Daily (today so far)</option>
+              <option value="hour">Hourly (last complete hour)</option>
+            </select>
+          </label>
+
+          <label className="block">
+            <span className={labelClass}>Page Filter</span>
+            <input
+              value={pageFilter}
+              onChange={(e) => setPageFilter(e.target.value)}
+              placeholder="e.g. /checkout"
+              className={inputClass}
+            />
+          </label>
+
+          <label className="block">
+            <span className={labelClass}>Country Filter</span>
+            <input
+              value={countryFilter}
+              onChange={(e) => setCountryFilter(e.target.value)}
+              placeholder="e.g. DE"
+              className={inputClass}
+            />
+          </label>
+
           <label className="block">
             <span className={labelClass}>Condition</span>
             <select value={conditionType} onChange={(e) => setConditionType(e.target.value as AlertConditionType)} className={selectClass}>
@@ -92,8 +188,37 @@ export function EditAlertDialog({ rule, isPending, onSave, onClose }: EditAlertD
             </select>
           </label>
 
+          {!isThreshold && (
+            <label className="block">
+              <span className={labelClass}>Baseline</span>
+              <select
+                value={baseline}
+                onChange={(e) => {
+                  const next = e.target.value as AlertBaseline;
+                  setBaseline(next);
+                  if (next !== 'flat' && Number(lookbackDays) < 14) setLookbackDays('28');
+                }}
+                className={selectClass}
+              >
+                {BASELINES.map((b) => <option key={b.value} value={b.value}>{b.label}</option>)}
+              </select>
+            </label>
+          )}
+
+          <label className="block">
+            <span className={labelClass}>Lookback Days</span>
+            <input
+              type="number"
+              min={1}
+              max={90}
+              value={lookbackDays}
+              onChange={(e) => setLookbackDays(e.target.value)}
+              className={inputClass}
+            />
+          </label>
+
           <label className="block">
-            <span className={labelClass}>Threshold Value</span>
+            <span className={labelClass}>{isThreshold ? 'Threshold Value' : 'Sensitivity (standard deviations)'}</span>
             <input
               type="number"
               value={thresholdValue}
@@ -103,6 +228,17 @@ export function EditAlertDialog({ rule, isPending, onSave, onClose }: EditAlertD
             />
           </label>
 
+          <label className="block">
+            <span className={labelClass}>Cooldown (minutes)</span>
+            <input
+              type="number"
+              min={0}
+              value={cooldownMinutes}
+              onChange={(e) => setCooldownMinutes(e.target.value)}
+              className={inputClass}
+            />
+          </label>
+
           <label className="block">
             <span className={labelClass}>Channel</span>
             <select value={channel} onChange={(e) => setChannel(e.target.value as NotificationChannel)} className={selectClass}>
@@ -128,6 +264,7 @@ export function EditAlertDialog({ rule, isPending, onSave, onClose }: EditAlertD
             disabled={
               isPending ||
               !target.trim() ||
+              (isCustomEvent && !eventName.trim()) ||
               !thresholdValue.trim() ||
               !Number.isFinite(Number(thresholdValue))
             }
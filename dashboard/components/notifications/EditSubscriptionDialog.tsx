@@ -23,7 +23,7 @@ const selectClass =
 const labelClass = 'block text-xs font-medium text-ink-3 mb-1';
 
 const SCHEDULES: SubscriptionSchedule[] = ['daily', 'weekly', 'monthly'];
-const CHANNELS: NotificationChannel[] = ['email', 'webhook'];
+const CHANNELS: NotificationChannel[] = ['email', 'webhook', 'slack', 'teams', 'discord'];
 
 interface EditSubscriptionDialogProps {
   subscription: ReportSubscription | null;
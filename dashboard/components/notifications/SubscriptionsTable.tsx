@@ -31,6 +31,11 @@ function Row({
       <td className="px-3 py-2 text-xs text-ink">{subscription.timezone}</td>
       <td className="px-3 py-2 text-xs text-ink">
         {subscription.channel}: {subscription.target}
+        {subscription.channel === 'webhook' && (
+          <div className="text-ink-3">
+            Signing secret: <code className="font-mono">{subscription.signing_secret}</code>
+          </div>
+        )}
       </td>
       <td className="px-3 py-2 text-xs text-ink-2">{subscription.next_run_at}</td>
       <td className="px-3 py-2 text-xs">
@@ -4,21 +4,47 @@ import { useState } from 'react';
 import { Key, Loader2, Trash2 } from 'lucide-react';
 import { Button } from '@/components/ui/button';
 import { useApiKeys, useCreateApiKey, useDeleteApiKey } from '@/hooks/useApiKeys';
+import { useWebsites } from '@/hooks/useWebsites';
+import type { ApiKey, ApiKeyAccess } from '@/lib/api';
+
+const fieldClass =
+  'w-full bg-canvas border border-line rounded-md px-3 py-2 text-sm text-ink placeholder:text-ink-4 focus:outline-none focus:border-spark';
+
+function describeScope(key: ApiKey, websiteNames: Map<string, string>): string {
+  const sites = key.website_ids
+    ? key.website_ids.map((id) => websiteNames.get(id) ?? id).join(', ')
+    : 'all websites';
+  const expiry = key.expires_at ? ` · expires ${key.expires_at.slice(0, 10)}` : '';
+  return `${key.access === 'read' ? 'Read-only' : 'Read & write'} · ${sites}${expiry}`;
+}
 
 export function ApiKeysSection() {
   const { data, isLoading } = useApiKeys();
   const createKey = useCreateApiKey();
   const deleteKey = useDeleteApiKey();
+  const { data: websitesData } = useWebsites();
   const [newKeyName, setNewKeyName] = useState('');
+  const [access, setAccess] = useState<ApiKeyAccess>('write');
+  const [websiteId, setWebsiteId] = useState('');
+  const [expiresOn, setExpiresOn] = useState('');
   const [createdKey, setCreatedKey] = useState<string | null>(null);
 
   const keys = data?.data ?? [];
+  const websites = websitesData?.data ?? [];
+  const websiteNames = new Map(websites.map((w) => [w.id, w.name]));
 
   async function handleCreate(e: React.FormEvent) {
     e.preventDefault();
     if (!newKeyName.trim()) return;
-    const result = await createKey.mutateAsync(newKeyName.trim());
+    const result = await createKey.mutateAsync({
+      name: newKeyName.trim(),
+      access,
+      website_ids: websiteId ? [websiteId] : undefined,
+      expires_at: expiresOn ? new Date(`${expiresOn}T23:59:59Z`).toISOString() : undefined,
+    });
     setNewKeyName('');
+    setWebsiteId('');
+    setExpiresOn('');
     if (result?.data?.key) {
       setCreatedKey(result.data.key);
     }
@@ -42,20 +68,49 @@ export function ApiKeysSection() {
         </div>
       )}
 
-      <form onSubmit={handleCreate} className="flex items-end gap-2">
-        <label className="flex-1">
-          <span className="text-xs text-ink-2 mb-1 block">Key name</span>
-          <input
-            value={newKeyName}
-            onChange={(e) => setNewKeyName(e.target.value)}
-            placeholder="e.g. CI pipeline"
-            className="w-full bg-canvas border border-line rounded-md px-3 py-2 text-sm text-ink placeholder:text-ink-4 focus:outline-none focus:border-spark"
-          />
-        </label>
-        <Button type="submit" size="sm" disabled={createKey.isPending || !newKeyName.trim()} className="text-xs">
-          {createKey.isPending && <Loader2 className="w-3 h-3 mr-1 animate-spin" />}
-          Create
-        </Button>
+      <form onSubmit={handleCreate} className="space-y-2">
+        <div className="flex items-end gap-2">
+          <label className="flex-1">
+            <span className="text-xs text-ink-2 mb-1 block">Key name</span>
+            <input
+              value={newKeyName}
+              onChange={(e) => setNewKeyName(e.target.value)}
+              placeholder="e.g. CI pipeline"
+              className={fieldClass}
+            />
+          </label>
+          <Button type="submit" size="sm" disabled={createKey.isPending || !newKeyName.trim()} className="text-xs">
+            {createKey.isPending && <Loader2 className="w-3 h-3 mr-1 animate-spin" />}
+            Create
+          </Button>
+        </div>
+        <div className="grid grid-cols-3 gap-2">
+          <label>
+            <span className="text-xs text-ink-2 mb-1 block">Access</span>
+            <select value={access} onChange={(e) => setAccess(e.target.value as ApiKeyAccess)} className={fieldClass}>
+              <option value="write">Read &amp; write</option>
+              <option value="read">Read-only</option>
+            </select>
+          </label>
+          <label>
+            <span className="text-xs text-ink-2 mb-1 block">Website</span>
+            <select value={websiteId} onChange={(e) => setWebsiteId(e.target.value)} className={fieldClass}>
+              <option value="">All websites</option>
+              {websites.map((w) => (
+                <option key={w.id} value={w.id}>{w.name}</option>
+              ))}
+            </select>
+          </label>
+          <label>
+            <span className="text-xs text-ink-2 mb-1 block">Expires</span>
+            <input
+              type="date"
+              value={expiresOn}
+              onChange={(e) => setExpiresOn(e.target.value)}
+              className={fieldClass}
+            />
+          </label>
+        </div>
       </form>
 
       {isLoading ? (
@@ -78,6 +133,7 @@ export function ApiKeysSection() {
                 <div className="min-w-0">
                   <p className="text-sm text-ink truncate">{key.name}</p>
                   <p className="text-xs text-ink-4 font-mono">{key.prefix}...</p>
+                  <p className="text-xs text-ink-3 truncate">{describeScope(key, websiteNames)}</p>
                 </div>
               </div>
               <Button
@@ -0,0 +1,166 @@
+'use client';
+
+import { useState } from 'react';
+import { Loader2, ShieldCheck } from 'lucide-react';
+import { Button } from '@/components/ui/button';
+import {
+  useConfirmTotp,
+  useDisableTotp,
+  useEnrollTotp,
+  useRegenerateRecoveryCodes,
+  useTotpStatus,
+} from '@/hooks/useTotp';
+
+const fieldClass =
+  'w-full bg-canvas border border-line rounded-md px-3 py-2 text-sm text-ink placeholder:text-ink-4 focus:outline-none focus:border-spark';
+
+export function TwoFactorSection() {
+  const { data, isLoading } = useTotpStatus();
+  const enroll = useEnrollTotp();
+  const confirm = useConfirmTotp();
+  const regenerate = useRegenerateRecoveryCodes();
+  const disable = useDisableTotp();
+  const [enrollment, setEnrollment] = useState<{ secret: string; otpauth_uri: string } | null>(null);
+  const [code, setCode] = useState('');
+  const [recoveryCodes, setRecoveryCodes] = useState<string[] | null>(null);
+
+  const status = data?.data;
+
+  async function handleEnroll() {
+    const result = await enroll.mutateAsync();
+    setEnrollment(result.data);
+    setCode('');
+  }
+
+  async function handleConfirm(e: React.FormEvent) {
+    e.preventDefault();
+    const result = await confirm.mutateAsync(code.trim());
+    setEnrollment(null);
+    setCode('');
+    setRecoveryCodes(result.data.recovery_codes);
+  }
+
+  async function handleRegenerate() {
+    const result = await regenerate.mutateAsync(code.trim());
+    setCode('');
+    setRecoveryCodes(result.data.recovery_codes);
+  }
+
+  async function handleDisable() {
+    await disable.mutateAsync(code.trim());
+    setCode('');
+    setRecoveryCodes(null);
+  }
+
+  if (isLoading || !status) {
+    return <div className="h-10 bg-canvas border border-line rounded-md animate-pulse" />;
+  }
+
+  return (
+    <div className="space-y-4">
+      {recoveryCodes && (
+        <div className="bg-spark/10 border border-spark/30 rounded-lg p-4">
+          <p className="text-xs text-ink-2 mb-2">
+            Recovery codes (store them somewhere safe — each works once and they will not be shown again):
+          </p>
+          <div className="grid grid-cols-2 gap-1">
+            {recoveryCodes.map((c) => (
+              <code key={c} className="text-xs font-mono text-spark">{c}</code>
+            ))}
+          </div>
+          <button
+            onClick={() => {
+              navigator.clipboard.writeText(recoveryCodes.join('\n'));
+              setRecoveryCodes(null);
+            }}
+            className="block mt-2 text-xs text-ink-3 hover:text-ink transition-colors"
+          >
+            Copy and dismiss
+          </button>
+        </div>
+      )}
+
+      {status.enabled ? (
+        <>
+          <div className="flex items-center gap-2 text-sm text-ink">
+            <ShieldCheck className="w-4 h-4 text-spark" />
+            Enabled · {status.recovery_codes_remaining} recovery codes left
+          </div>
+          <div className="flex items-end gap-2">
+            <label className="flex-1">
+              <span className="text-xs text-ink-2 mb-1 block">Authentication code</span>
+              <input
+                value={code}
+                onChange={(e) => setCode(e.target.value)}
+                inputMode="numeric"
+                autoComplete="one-time-code"
+                placeholder="123456"
+                className={fieldClass}
+              />
+            </label>
+            <Button
+              type="button"
+              size="sm"
+              variant="outline"
+              disabled={regenerate.isPending || !code.trim()}
+              onClick={handleRegenerate}
+              className="text-xs"
+            >
+              New recovery codes
+            </Button>
+            <Button
+              type="button"
+              size="sm"
+              variant="destructive"
+              disabled={disable.isPending || !code.trim()}
+              onClick={handleDisable}
+              className="text-xs"
+            >
+              Disable
+            </Button>
+          </div>
+        </>
+      ) : enrollment ? (
+        <form onSubmit={handleConfirm} className="space-y-3">
+          <p className="text-xs text-ink-3">
+            Scan this link as a QR code with your authenticator app, or enter the secret manually, then
+            type the 6-digit code it shows.
+          </p>
+          <a href={enrollment.otpauth_uri} className="block text-xs font-mono text-spark break-all">
+            {enrollment.otpauth_uri}
+          </a>
+          <p className="text-xs text-ink-2">
+            Secret: <code className="font-mono text-ink">{enrollment.secret}</code>
+          </p>
+          <div className="flex items-end gap-2">
+            <label className="flex-1">
+              <span className="text-xs text-ink-2 mb-1 block">Authentication code</span>
+              <input
+                value={code}
+                onChange={(e) => setCode(e.target.value)}
+                inputMode="numeric"
+                autoComplete="one-time-code"
+                placeholder="123456"
+                className={fieldClass}
+              />
+            </label>
+            <Button type="submit" size="sm" disabled={confirm.isPending || !code.trim()} className="text-xs">
+              {confirm.isPending && <Loader2 className="w-3 h-3 mr-1 animate-spin" />}
+              Verify
+            </Button>
+          </div>
+        </form>
+      ) : (
+        <div className="flex items-center justify-between">
+          <p className="text-xs text-ink-3">
+            Require a code from an authenticator app in addition to your password.
+          </p>
+          <Button size="sm" onClick={handleEnroll} disabled={enroll.isPending} className="text-xs">
+            {enroll.isPending && <Loader2 className="w-3 h-3 mr-1 animate-spin" />}
+            Set up
+          </Button>
+        </div>
+      )}
+    </div>
+  );
+}
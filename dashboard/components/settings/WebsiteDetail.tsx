@@ -7,6 +7,7 @@ import { TrackingSnippet } from '@/components/settings/TrackingSnippet';
 import { SharingToggle } from '@/components/settings/SharingToggle';
 import { ApiKeysSection } from '@/components/settings/ApiKeysSection';
 import { ChangePasswordSection } from '@/components/settings/ChangePasswordSection';
+import { TwoFactorSection } from '@/components/settings/TwoFactorSection';
 import { BotsSettingsPage } from '@/components/settings/BotsSettingsPage';
 import { NotificationsSettingsPage } from '@/components/notifications/NotificationsSettingsPage';
 import { ConfirmDialog } from '@/components/ui/confirm-dialog';
@@ -325,6 +326,14 @@ export function WebsiteDetail({ websiteId, subSubPage = 'general' }: { websiteId
         </section>
       )}
 
+      {/* Two-factor authentication (local auth only) */}
+      {(showPasswordSection && subSubPage === 'security') && (
+        <section className="bg-surface-1 border border-line rounded-lg p-6">
+          <h2 className="text-sm font-semibold text-ink mb-4">Two-factor authentication</h2>
+          <TwoFactorSection />
+        </section>
+      )}
+
       {subSubPage === 'notifications' && (
         <section className="bg-surface-1 border border-line rounded-lg p-6">
           <NotificationsSettingsPage websiteId={websiteId} />
@@ -18,10 +18,25 @@ type AlertRule = {
   id: string;
   website_id: string;
   name: string;
-  metric: 'pageviews' | 'visitors' | 'conversions' | 'conversion_rate';
-  condition_type: 'spike' | 'drop' | 'threshold_above' | 'threshold_below';
+  metric:
+    | 'pageviews'
+    | 'visitors'
+    | 'conversions'
+    | 'conversion_rate'
+    | 'custom_events'
+    | 'bounce_rate'
+    | 'session_duration'
+    | 'bot_share';
+  condition_type: 'spike' | 'drop' | 'threshold_above' | 'threshold_below' | 'anomaly';
   threshold_value: number;
   lookback_days: number;
+  baseline: 'flat' | 'same_weekday' | 'holt_winters';
+  event_name: string | null;
+  filters: Record<string, string>;
+  granularity: 'day' | 'hour';
+  cooldown_minutes: number;
+  is_firing: boolean;
+  last_triggered_at: string | null;
   channel: 'email' | 'webhook';
   target: string;
   is_active: boolean;
@@ -156,6 +171,11 @@ test('notifications settings supports subscription and alert test-send flows', a
         condition_type: AlertRule['condition_type'];
         threshold_value: number;
         lookback_days: number;
+        baseline?: AlertRule['baseline'];
+        event_name?: string;
+        filters?: AlertRule['filters'];
+        granularity?: AlertRule['granularity'];
+        cooldown_minutes?: number;
         channel: AlertRule['channel'];
         target: string;
       };
@@ -167,6 +187,13 @@ test('notifications settings supports subscription and alert test-send flows', a
         condition_type: body.condition_type,
         threshold_value: body.threshold_value,
         lookback_days: body.lookback_days,
+        baseline: body.baseline ?? 'flat',
+        event_name: body.event_name ?? null,
+        filters: body.filters ?? {},
+        granularity: body.granularity ?? 'day',
+        cooldown_minutes: body.cooldown_minutes ?? 60,
+        is_firing: false,
+        last_triggered_at: null,
         channel: body.channel,
         target: body.target,
         is_active: true,
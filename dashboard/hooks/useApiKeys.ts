@@ -1,7 +1,7 @@
 'use client';
 
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
-import { api } from '@/lib/api';
+import { api, type CreateApiKeyPayload } from '@/lib/api';
 import { useToast } from '@/hooks/use-toast';
 
 export function useApiKeys() {
@@ -17,7 +17,7 @@ export function useCreateApiKey() {
   const { toast } = useToast();
 
   return useMutation({
-    mutationFn: (name: string) => api.createApiKey(name),
+    mutationFn: (payload: CreateApiKeyPayload) => api.createApiKey(payload),
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ['apiKeys'] });
       toast({ title: 'API key created' });
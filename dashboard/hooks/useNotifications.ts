@@ -165,3 +165,26 @@ export function useNotificationHistory(websiteId: string, limit = 50) {
     staleTime: 10_000,
   });
 }
+
+export function useReplayNotificationDelivery(websiteId: string) {
+  const queryClient = useQueryClient();
+  const { toast } = useToast();
+  return useMutation({
+    mutationFn: (deliveryId: string) => api.replayNotificationDelivery(websiteId, deliveryId),
+    onSuccess: (result) => {
+      queryClient.invalidateQueries({ queryKey: ['notification-history', websiteId] });
+      if (result.data.status === 'sent') {
+        toast({ title: 'Delivery replayed' });
+      } else {
+        toast({
+          title: 'Replay failed',
+          description: result.data.error_message ?? undefined,
+          variant: 'destructive',
+        });
+      }
+    },
+    onError: (error: Error) => {
+      toast({ title: 'Failed to replay delivery', description: error.message, variant: 'destructive' });
+    },
+  });
+}
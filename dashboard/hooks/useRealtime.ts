@@ -1,17 +1,70 @@
 'use client';
 
-import { useQuery } from '@tanstack/react-query';
-import { api } from '@/lib/api';
+import { useEffect, useState } from 'react';
+import { useQuery, useQueryClient } from '@tanstack/react-query';
+import {
+  api,
+  canStreamRealtime,
+  realtimeStreamUrl,
+  type RealtimeEvent,
+  type RealtimeResponse,
+} from '@/lib/api';
 
+// Poll while the live stream is unavailable; apply pushed updates to the
+// cached snapshot while it is open.
 export function useRealtime(
   websiteId: string,
   refreshInterval = 30_000,
   enabled = true
 ) {
+  const queryClient = useQueryClient();
+  const [streaming, setStreaming] = useState(false);
+
+  useEffect(() => {
+    if (!websiteId || !enabled || !canStreamRealtime()) return;
+
+    const queryKey = ['realtime', websiteId];
+    const update = (fn: (prev: RealtimeResponse) => RealtimeResponse) =>
+      queryClient.setQueryData<{ data: RealtimeResponse }>(queryKey, (prev) =>
+        prev ? { data: fn(prev.data) } : prev
+      );
+
+    const source = new EventSource(realtimeStreamUrl(websiteId), { withCredentials: true });
+    source.onopen = () => setStreaming(true);
+    source.onerror = () => setStreaming(false);
+    source.addEventListener('active_visitors', (e) => {
+      const { active_visitors } = JSON.parse((e as MessageEvent).data) as {
+        active_visitors: number;
+      };
+      update((prev) => ({ ...prev, active_visitors }));
+    });
+    source.addEventListener('event', (e) => {
+      const event = JSON.parse((e as MessageEvent).data) as RealtimeEvent;
+      update((prev) => ({
+        ...prev,
+        recent_events: [event, ...prev.recent_events].slice(0, prev.pagination.limit),
+        pagination: {
+          ...prev.pagination,
+          total_in_window: prev.pagination.total_in_window + 1,
+        },
+      }));
+    });
+
+    // The server dropped events this client was too slow to read.
+    source.addEventListener('resync', () => {
+      queryClient.invalidateQueries({ queryKey });
+    });
+
+    return () => {
+      source.close();
+      setStreaming(false);
+    };
+  }, [websiteId, enabled, queryClient]);
+
   return useQuery({
     queryKey: ['realtime', websiteId],
     queryFn: () => api.getRealtime(websiteId),
-    refetchInterval: refreshInterval,
+    refetchInterval: streaming ? false : refreshInterval,
     staleTime: 0,
     enabled: !!websiteId && enabled,
   });
@@ -0,0 +1,78 @@
+'use client';
+
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { api } from '@/lib/api';
+import { useToast } from '@/hooks/use-toast';
+
+const TOTP_QUERY_KEY = ['totpStatus'];
+
+export function useTotpStatus() {
+  return useQuery({
+    queryKey: TOTP_QUERY_KEY,
+    queryFn: () => api.getTotpStatus(),
+    staleTime: 60_000,
+  });
+}
+
+export function useEnrollTotp() {
+  const queryClient = useQueryClient();
+  const { toast } = useToast();
+
+  return useMutation({
+    mutationFn: () => api.enrollTotp(),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: TOTP_QUERY_KEY });
+    },
+    onError: (error: Error) => {
+      toast({ title: 'Failed to start two-factor setup', description: error.message, variant: 'destructive' });
+    },
+  });
+}
+
+export function useConfirmTotp() {
+  const queryClient = useQueryClient();
+  const { toast } = useToast();
+
+  return useMutation({
+    mutationFn: (code: string) => api.confirmTotp(code),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: TOTP_QUERY_KEY });
+      toast({ title: 'Two-factor authentication enabled' });
+    },
+    onError: (error: Error) => {
+      toast({ title: 'Failed to verify code', description: error.message, variant: 'destructive' });
+    },
+  });
+}
+
+export function useRegenerateRecoveryCodes() {
+  const queryClient = useQueryClient();
+  const { toast } = useToast();
+
+  return useMutation({
+    mutationFn: (code: string) => api.regenerateRecoveryCodes(code),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: TOTP_QUERY_KEY });
+      toast({ title: 'Recovery codes replaced' });
+    },
+    onError: (error: Error) => {
+      toast({ title: 'Failed to replace recovery codes', description: error.message, variant: 'destructive' });
+    },
+  });
+}
+
+export function useDisableTotp() {
+  const queryClient = useQueryClient();
+  const { toast } = useToast();
+
+  return useMutation({
+    mutationFn: (code: string) => api.disableTotp(code),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: TOTP_QUERY_KEY });
+      toast({ title: 'Two-factor authentication disabled' });
+    },
+    onError: (error: Error) => {
+      toast({ title: 'Failed to disable two-factor authentication', description: error.message, variant: 'destructive' });
+    },
+  });
+}
@@ -7,12 +7,34 @@ export function setTokenGetter(fn: () => Promise<string | null>): void {
   _tokenGetter = fn;
 }
 
+// EventSource cannot send an Authorization header, so streams are cookie-auth only.
+export function canStreamRealtime(): boolean {
+  return typeof EventSource !== 'undefined' && _tokenGetter === null;
+}
+
+export function realtimeStreamUrl(websiteId: string): string {
+  return `${BASE}/api/websites/${websiteId}/realtime/stream`;
+}
+
 type RequestOptions = {
   method?: string;
   body?: unknown;
   redirectOn401?: boolean;
 };
 
+/** Error thrown by API calls, carrying the server's error code. */
+export class ApiError extends Error {
+  constructor(message: string, readonly code?: string) {
+    super(message);
+  }
+}
+
+export type TotpStatus = {
+  enabled: boolean;
+  pending: boolean;
+  recovery_codes_remaining: number;
+};
+
 export type AuthStatus = {
   mode: string;
   setup_required: boolean;
@@ -39,7 +61,7 @@ async function request<T>(path: string, opts: RequestOptions = {}): Promise<T> {
     if (opts.redirectOn401 !== false && typeof window !== 'undefined') {
       window.location.href = '/login';
     }
-    throw new Error(err?.error?.message ?? 'Unauthorized');
+    throw new ApiError(err?.error?.message ?? 'Unauthorized', err?.error?.code);
   }
 
   if (!res.ok) {
@@ -48,7 +70,7 @@ async function request<T>(path: string, opts: RequestOptions = {}): Promise<T> {
       if (typeof window !== 'undefined') window.location.href = '/force-password';
       throw new Error('Password change required');
     }
-    throw new Error(err?.error?.message ?? 'Request failed');
+    throw new ApiError(err?.error?.message ?? 'Request failed', err?.error?.code);
   }
 
   if (res.status === 204) {
@@ -87,10 +109,16 @@ export const api = {
     }
     return res.json();
   },
-  login: (password: string) =>
+  login: (password: string, email?: string, totpCode?: string) =>
     request('/api/auth/login', {
       method: 'POST',
-      body: { password },
+      body: { ...(email ? { email } : {}), password, ...(totpCode ? { totp_code: totpCode } : {}) },
+      redirectOn401: false,
+    }),
+  acceptInvite: (token: string, password: string, name?: string) =>
+    request('/api/auth/invites/accept', {
+      method: 'POST',
+      body: { token, password, name },
       redirectOn401: false,
     }),
   logout: () => request('/api/auth/logout', { method: 'POST' }),
@@ -189,9 +217,9 @@ export const api = {
 
   // API Keys (self-hosted auth)
   listApiKeys: () => request<{ data: ApiKey[] }>('/api/auth/keys'),
-  createApiKey: (name: string) =>
+  createApiKey: (payload: CreateApiKeyPayload) =>
     request<{ data: { id: string; name: string; key: string; prefix: string; created_at: string } }>(
-      '/api/auth/keys', { method: 'POST', body: { name } }
+      '/api/auth/keys', { method: 'POST', body: payload }
     ),
   deleteApiKey: (id: string) =>
     request<void>(`/api/auth/keys/${id}`, { method: 'DELETE' }),
@@ -204,6 +232,28 @@ export const api = {
       redirectOn401: false,
     }),
 
+  // Two-factor authentication (local auth)
+  getTotpStatus: () => request<{ data: TotpStatus }>('/api/auth/totp'),
+  enrollTotp: () =>
+    request<{ data: { secret: string; otpauth_uri: string } }>('/api/auth/totp/enroll', {
+      method: 'POST',
+    }),
+  confirmTotp: (code: string) =>
+    request<{ data: { recovery_codes: string[] } }>('/api/auth/totp/confirm', {
+      method: 'POST',
+      body: { code },
+    }),
+  regenerateRecoveryCodes: (code: string) =>
+    request<{ data: { recovery_codes: string[] } }>('/api/auth/totp/recovery-codes', {
+      method: 'POST',
+      body: { code },
+    }),
+  disableTotp: (code: string) =>
+    request<{ data: { ok: boolean } }>('/api/auth/totp/disable', {
+      method: 'POST',
+      body: { code },
+    }),
+
   // Custom Events
   getEventNames: (websiteId: string, params: DateRange & Filters) =>
     request<{ data: EventNamesResult }>(
@@ -304,6 +354,11 @@ export const api = {
     request<{ data: NotificationDelivery[] }>(
       `/api/websites/${websiteId}/notifications/history?${toQuery({ limit })}`
     ),
+  replayNotificationDelivery: (websiteId: string, deliveryId: string) =>
+    request<{ data: NotificationDelivery }>(
+      `/api/websites/${websiteId}/notifications/history/${deliveryId}/replay`,
+      { method: 'POST' }
+    ),
 
   // Bot Controls + Visibility (Sprint 22)
   getBotSummary: (websiteId: string, params: BotDateRangeParams = {}) =>
@@ -490,6 +545,8 @@ export interface RealtimeResponse {
   };
 }
 
+export type ApiKeyAccess = 'read' | 'write';
+
 export interface ApiKey {
   id: string;
   name: string;
@@ -497,6 +554,17 @@ export interface ApiKey {
   created_at: string;
   last_used_at: string | null;
   revoked_at: string | null;
+  access: ApiKeyAccess;
+  /** null = every website */
+  website_ids: string[] | null;
+  expires_at: string | null;
+}
+
+export interface CreateApiKeyPayload {
+  name: string;
+  access?: ApiKeyAccess;
+  website_ids?: string[];
+  expires_at?: string;
 }
 
 export interface UsageResponse {
@@ -803,11 +871,38 @@ export interface PixelStatsResponse {
 // --- Notifications types (Sprint 20) ---
 
 export type SubscriptionSchedule = 'daily' | 'weekly' | 'monthly';
-export type NotificationChannel = 'email' | 'webhook';
-export type AlertMetric = 'pageviews' | 'visitors' | 'conversions' | 'conversion_rate';
-export type AlertConditionType = 'spike' | 'drop' | 'threshold_above' | 'threshold_below';
+export type NotificationChannel = 'email' | 'webhook' | 'slack' | 'teams' | 'discord';
+export type AlertMetric =
+  | 'pageviews'
+  | 'visitors'
+  | 'conversions'
+  | 'conversion_rate'
+  | 'custom_events'
+  | 'bounce_rate'
+  | 'session_duration'
+  | 'bot_share';
+export type AlertConditionType = 'spike' | 'drop' | 'threshold_above' | 'threshold_below' | 'anomaly';
+export type AlertBaseline = 'flat' | 'same_weekday' | 'holt_winters';
+export type AlertGranularity = 'day' | 'hour';
 export type NotificationSourceType = 'subscription' | 'alert';
-export type NotificationDeliveryStatus = 'sent' | 'failed';
+export type NotificationDeliveryStatus = 'sent' | 'retrying' | 'failed';
+
+/** Dimension filters (`<op>:<value>` encoded) that scope an alert's metric. */
+export interface AlertFilters {
+  filter_country?: string;
+  filter_page?: string;
+  filter_referrer?: string;
+  filter_browser?: string;
+  filter_os?: string;
+  filter_device?: string;
+  filter_language?: string;
+  filter_utm_source?: string;
+  filter_utm_medium?: string;
+  filter_utm_campaign?: string;
+  filter_region?: string;
+  filter_city?: string;
+  filter_hostname?: string;
+}
 
 export interface ReportSubscription {
   id: string;
@@ -817,6 +912,7 @@ export interface ReportSubscription {
   timezone: string;
   channel: NotificationChannel;
   target: string;
+  signing_secret: string;
   is_active: boolean;
   last_run_at: string | null;
   next_run_at: string;
@@ -848,8 +944,16 @@ export interface AlertRule {
   condition_type: AlertConditionType;
   threshold_value: number;
   lookback_days: number;
+  baseline: AlertBaseline;
+  event_name: string | null;
+  filters: AlertFilters;
+  granularity: AlertGranularity;
+  cooldown_minutes: number;
+  is_firing: boolean;
+  last_triggered_at: string | null;
   channel: NotificationChannel;
   target: string;
+  signing_secret: string;
   is_active: boolean;
   created_at: string;
 }
@@ -860,6 +964,11 @@ export interface CreateAlertRulePayload {
   condition_type: AlertConditionType;
   threshold_value: number;
   lookback_days?: number;
+  baseline?: AlertBaseline;
+  event_name?: string;
+  filters?: AlertFilters;
+  granularity?: AlertGranularity;
+  cooldown_minutes?: number;
   channel: NotificationChannel;
   target: string;
 }
@@ -870,6 +979,11 @@ export interface UpdateAlertRulePayload {
   condition_type?: AlertConditionType;
   threshold_value?: number;
   lookback_days?: number;
+  baseline?: AlertBaseline;
+  event_name?: string | null;
+  filters?: AlertFilters;
+  granularity?: AlertGranularity;
+  cooldown_minutes?: number;
   channel?: NotificationChannel;
   target?: string | null;
   is_active?: boolean;
@@ -882,6 +996,10 @@ export interface NotificationDelivery {
   idempotency_key: string;
   status: NotificationDeliveryStatus;
   error_message: string | null;
+  channel: NotificationChannel | null;
+  target: string | null;
+  attempt_count: number;
+  next_attempt_at: string | null;
   delivered_at: string;
 }
 
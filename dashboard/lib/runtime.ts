@@ -1,4 +1,4 @@
-export type RuntimeAuthMode = 'none' | 'password' | 'local';
+export type RuntimeAuthMode = 'none' | 'password' | 'local' | 'oidc';
 
 declare global {
   interface Window {
@@ -12,14 +12,20 @@ export function getRuntimeAuthMode(): RuntimeAuthMode | null {
     if (
       runtimeMode === 'none' ||
       runtimeMode === 'password' ||
-      runtimeMode === 'local'
+      runtimeMode === 'local' ||
+      runtimeMode === 'oidc'
     ) {
       return runtimeMode;
     }
   }
 
   const envMode = process.env.NEXT_PUBLIC_AUTH_MODE;
-  if (envMode === 'none' || envMode === 'password' || envMode === 'local') {
+  if (
+    envMode === 'none' ||
+    envMode === 'password' ||
+    envMode === 'local' ||
+    envMode === 'oidc'
+  ) {
     return envMode;
   }
 
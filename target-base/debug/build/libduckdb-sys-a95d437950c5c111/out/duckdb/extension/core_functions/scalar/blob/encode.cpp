@@ -0,0 +1,46 @@
+#include "core_functions/scalar/blob_functions.hpp"
+#include "utf8proc_wrapper.hpp"
+#include "duckdb/common/exception/conversion_exception.hpp"
+
+namespace duckdb {
+
+namespace {
+
+void EncodeFunction(DataChunk &args, ExpressionState &state, Vector &result) {
+	// encode is essentially a nop cast from varchar to blob
+	// we only need to reinterpret the data using the blob type
+	result.Reinterpret(args.data[0]);
+}
+
+struct BlobDecodeOperator {
+	template <class INPUT_TYPE, class RESULT_TYPE>
+	static RESULT_TYPE Operation(INPUT_TYPE input) {
+		auto input_data = input.GetData();
+		auto input_length = input.GetSize();
+		if (Utf8Proc::Analyze(input_data, input_length) == UnicodeType::INVALID) {
+			throw ConversionException(
+			    "Failure in decode: could not convert blob to UTF8 string, the blob contained invalid UTF8 characters");
+		}
+		return input;
+	}
+};
+
+void DecodeFunction(DataChunk &args, ExpressionState &state, Vector &result) {
+	// decode is also a nop cast, but requires verification if the provided string is actually
+	UnaryExecutor::Execute<string_t, string_t, BlobDecodeOperator>(args.data[0], result, args.size());
+	StringVector::AddHeapReference(result, args.data[0]);
+}
+
+} // namespace
+
+ScalarFunction EncodeFun::GetFunction() {
+	return ScalarFunction({LogicalType::VARCHAR}, LogicalType::BLOB, EncodeFunction);
+}
+
+ScalarFunction DecodeFun::GetFunction() {
+	ScalarFunction function({LogicalType::BLOB}, LogicalType::VARCHAR, DecodeFunction);
+	BaseScalarFunction::SetReturnsError(function);
+	return function;
+}
+
+} // namespace duckdb
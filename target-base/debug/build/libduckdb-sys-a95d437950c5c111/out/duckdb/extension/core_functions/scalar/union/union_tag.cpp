@@ -0,0 +1,62 @@
+#include "core_functions/scalar/union_functions.hpp"
+#include "duckdb/common/string_util.hpp"
+#include "duckdb/execution/expression_executor.hpp"
+#include "duckdb/planner/expression/bound_function_expression.hpp"
+#include "duckdb/planner/expression/bound_parameter_expression.hpp"
+
+namespace duckdb {
+
+namespace {
+
+unique_ptr<FunctionData> UnionTagBind(ClientContext &context, ScalarFunction &bound_function,
+                                      vector<unique_ptr<Expression>> &arguments) {
+
+	if (arguments.empty()) {
+		throw BinderException("Missing required arguments for union_tag function.");
+	}
+
+	if (LogicalTypeId::UNKNOWN == arguments[0]->return_type.id()) {
+		throw ParameterNotResolvedException();
+	}
+
+	if (LogicalTypeId::UNION != arguments[0]->return_type.id()) {
+		throw BinderException("First argument to union_tag function must be a union type.");
+	}
+
+	if (arguments.size() > 1) {
+		throw BinderException("Too many arguments, union_tag takes at most one argument.");
+	}
+
+	auto member_count = UnionType::GetMemberCount(arguments[0]->return_type);
+	if (member_count == 0) {
+		// this should never happen, empty unions are not allowed
+		throw InternalException("Can't get tags from an empty union");
+	}
+
+	bound_function.arguments[0] = arguments[0]->return_type;
+
+	auto varchar_vector = Vector(LogicalType::VARCHAR, member_count);
+	for (idx_t i = 0; i < member_count; i++) {
+		auto str = string_t(UnionType::GetMemberName(arguments[0]->return_type, i));
+		FlatVector::GetData<string_t>(varchar_vector)[i] =
+		    str.IsInlined() ? str : StringVector::AddString(varchar_vector, str);
+	}
+	auto enum_type = LogicalType::ENUM(varchar_vector, member_count);
+	bound_function.return_type = enum_type;
+
+	return nullptr;
+}
+
+void UnionTagFunction(DataChunk &args, ExpressionState &state, Vector &result) {
+	D_ASSERT(result.GetType().id() == LogicalTypeId::ENUM);
+	result.Reinterpret(UnionVector::GetTags(args.data[0]));
+}
+
+} // namespace
+
+ScalarFunction UnionTagFun::GetFunction() {
+	return ScalarFunction({LogicalTypeId::UNION}, LogicalTypeId::ANY, UnionTagFunction, UnionTagBind, nullptr,
+	                      nullptr); // TODO: Statistics?
+}
+
+} // namespace duckdb
@@ -0,0 +1,72 @@
+#include "core_functions/scalar/union_functions.hpp"
+#include "duckdb/common/string_util.hpp"
+#include "duckdb/execution/expression_executor.hpp"
+#include "duckdb/function/scalar/nested_functions.hpp"
+#include "duckdb/planner/expression/bound_function_expression.hpp"
+#include "duckdb/planner/expression/bound_parameter_expression.hpp"
+
+namespace duckdb {
+
+namespace {
+
+struct UnionValueBindData : public FunctionData {
+	UnionValueBindData() {
+	}
+
+public:
+	unique_ptr<FunctionData> Copy() const override {
+		return make_uniq<UnionValueBindData>();
+	}
+	bool Equals(const FunctionData &other_p) const override {
+		return true;
+	}
+};
+
+void UnionValueFunction(DataChunk &args, ExpressionState &state, Vector &result) {
+	// Assign the new entries to the result vector
+	UnionVector::GetMember(result, 0).Reference(args.data[0]);
+
+	// Set the result tag vector to a constant value
+	auto &tag_vector = UnionVector::GetTags(result);
+	tag_vector.SetVectorType(VectorType::CONSTANT_VECTOR);
+	ConstantVector::GetData<union_tag_t>(tag_vector)[0] = 0;
+
+	if (args.AllConstant()) {
+		result.SetVectorType(VectorType::CONSTANT_VECTOR);
+	}
+
+	result.Verify(args.size());
+}
+
+unique_ptr<FunctionData> UnionValueBind(ClientContext &context, ScalarFunction &bound_function,
+                                        vector<unique_ptr<Expression>> &arguments) {
+
+	if (arguments.size() != 1) {
+		throw BinderException("union_value takes exactly one argument");
+	}
+	auto &child = arguments[0];
+
+	if (child->GetAlias().empty()) {
+		throw BinderException("Need named argument for union tag, e.g. UNION_VALUE(a := b)");
+	}
+
+	child_list_t<LogicalType> union_members;
+
+	union_members.push_back(make_pair(child->GetAlias(), child->return_type));
+
+	bound_function.return_type = LogicalType::UNION(std::move(union_members));
+	return make_uniq<VariableReturnBindData>(bound_function.return_type);
+}
+
+} // namespace
+
+ScalarFunction UnionValueFun::GetFunction() {
+	ScalarFunction fun("union_value", {}, LogicalTypeId::UNION, UnionValueFunction, UnionValueBind, nullptr, nullptr);
+	fun.varargs = LogicalType::ANY;
+	fun.null_handling = FunctionNullHandling::SPECIAL_HANDLING;
+	fun.serialize = VariableReturnBindData::Serialize;
+	fun.deserialize = VariableReturnBindData::Deserialize;
+	return fun;
+}
+
+} // namespace duckdb
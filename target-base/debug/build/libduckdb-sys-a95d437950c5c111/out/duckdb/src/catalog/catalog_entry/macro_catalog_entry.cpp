@@ -0,0 +1,60 @@
+#include "duckdb/catalog/catalog_entry/scalar_macro_catalog_entry.hpp"
+#include "duckdb/catalog/catalog_entry/table_macro_catalog_entry.hpp"
+#include "duckdb/function/scalar_macro_function.hpp"
+
+namespace duckdb {
+
+MacroCatalogEntry::MacroCatalogEntry(Catalog &catalog, SchemaCatalogEntry &schema, CreateMacroInfo &info)
+    : FunctionEntry(
+          (info.macros[0]->type == MacroType::SCALAR_MACRO ? CatalogType::MACRO_ENTRY : CatalogType::TABLE_MACRO_ENTRY),
+          catalog, schema, info),
+      macros(std::move(info.macros)) {
+	this->temporary = info.temporary;
+	this->internal = info.internal;
+	this->dependencies = info.dependencies;
+	this->comment = info.comment;
+	this->tags = info.tags;
+}
+
+ScalarMacroCatalogEntry::ScalarMacroCatalogEntry(Catalog &catalog, SchemaCatalogEntry &schema, CreateMacroInfo &info)
+    : MacroCatalogEntry(catalog, schema, info) {
+}
+
+unique_ptr<CatalogEntry> ScalarMacroCatalogEntry::Copy(ClientContext &context) const {
+	auto info_copy = GetInfo();
+	auto &cast_info = info_copy->Cast<CreateMacroInfo>();
+	auto result = make_uniq<ScalarMacroCatalogEntry>(catalog, schema, cast_info);
+	return std::move(result);
+}
+
+TableMacroCatalogEntry::TableMacroCatalogEntry(Catalog &catalog, SchemaCatalogEntry &schema, CreateMacroInfo &info)
+    : MacroCatalogEntry(catalog, schema, info) {
+}
+
+unique_ptr<CatalogEntry> TableMacroCatalogEntry::Copy(ClientContext &context) const {
+	auto info_copy = GetInfo();
+	auto &cast_info = info_copy->Cast<CreateMacroInfo>();
+	auto result = make_uniq<TableMacroCatalogEntry>(catalog, schema, cast_info);
+	return std::move(result);
+}
+
+unique_ptr<CreateInfo> MacroCatalogEntry::GetInfo() const {
+	auto info = make_uniq<CreateMacroInfo>(type);
+	info->catalog = catalog.GetName();
+	info->schema = schema.name;
+	info->name = name;
+	for (auto &function : macros) {
+		info->macros.push_back(function->Copy());
+	}
+	info->dependencies = dependencies;
+	info->comment = comment;
+	info->tags = tags;
+	return std::move(info);
+}
+
+string MacroCatalogEntry::ToSQL() const {
+	auto create_info = GetInfo();
+	return create_info->ToString();
+}
+
+} // namespace duckdb
@@ -0,0 +1,37 @@
+#include "duckdb/common/opener_file_system.hpp"
+#include "duckdb/common/file_opener.hpp"
+#include "duckdb/main/database.hpp"
+#include "duckdb/main/config.hpp"
+
+namespace duckdb {
+
+void OpenerFileSystem::VerifyNoOpener(optional_ptr<FileOpener> opener) {
+	if (opener) {
+		throw InternalException("OpenerFileSystem cannot take an opener - the opener is pushed automatically");
+	}
+}
+void OpenerFileSystem::VerifyCanAccessFileInternal(const string &path, FileType type) {
+	auto opener = GetOpener();
+	if (!opener) {
+		return;
+	}
+	auto db = opener->TryGetDatabase();
+	if (!db) {
+		return;
+	}
+	auto &config = db->config;
+	if (!config.CanAccessFile(path, type)) {
+		throw PermissionException("Cannot access %s \"%s\" - file system operations are disabled by configuration",
+		                          type == FileType::FILE_TYPE_DIR ? "directory" : "file", path);
+	}
+}
+
+void OpenerFileSystem::VerifyCanAccessFile(const string &path) {
+	VerifyCanAccessFileInternal(path, FileType::FILE_TYPE_REGULAR);
+}
+
+void OpenerFileSystem::VerifyCanAccessDirectory(const string &path) {
+	VerifyCanAccessFileInternal(path, FileType::FILE_TYPE_DIR);
+}
+
+} // namespace duckdb
@@ -0,0 +1,998 @@
+#include "duckdb/common/types/hugeint.hpp"
+#include "duckdb/common/types/uhugeint.hpp"
+#include "duckdb/common/exception.hpp"
+#include "duckdb/common/algorithm.hpp"
+#include "duckdb/common/hugeint.hpp"
+#include "duckdb/common/limits.hpp"
+#include "duckdb/common/numeric_utils.hpp"
+#include "duckdb/common/windows_undefs.hpp"
+#include "duckdb/common/types/value.hpp"
+#include "duckdb/common/operator/cast_operators.hpp"
+
+#include <cmath>
+#include <limits>
+
+namespace duckdb {
+
+//===--------------------------------------------------------------------===//
+// String Conversion
+//===--------------------------------------------------------------------===//
+const hugeint_t Hugeint::POWERS_OF_TEN[] {
+    hugeint_t(1),
+    hugeint_t(10),
+    hugeint_t(100),
+    hugeint_t(1000),
+    hugeint_t(10000),
+    hugeint_t(100000),
+    hugeint_t(1000000),
+    hugeint_t(10000000),
+    hugeint_t(100000000),
+    hugeint_t(1000000000),
+    hugeint_t(10000000000),
+    hugeint_t(100000000000),
+    hugeint_t(1000000000000),
+    hugeint_t(10000000000000),
+    hugeint_t(100000000000000),
+    hugeint_t(1000000000000000),
+    hugeint_t(10000000000000000),
+    hugeint_t(100000000000000000),
+    hugeint_t(1000000000000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(10),
+    hugeint_t(1000000000000000000) * hugeint_t(100),
+    hugeint_t(1000000000000000000) * hugeint_t(1000),
+    hugeint_t(1000000000000000000) * hugeint_t(10000),
+    hugeint_t(1000000000000000000) * hugeint_t(100000),
+    hugeint_t(1000000000000000000) * hugeint_t(1000000),
+    hugeint_t(1000000000000000000) * hugeint_t(10000000),
+    hugeint_t(1000000000000000000) * hugeint_t(100000000),
+    hugeint_t(1000000000000000000) * hugeint_t(1000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(10000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(100000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(1000000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(10000000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(100000000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(1000000000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(10000000000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(100000000000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(1000000000000000000),
+    hugeint_t(1000000000000000000) * hugeint_t(1000000000000000000) * hugeint_t(10),
+    hugeint_t(1000000000000000000) * hugeint_t(1000000000000000000) * hugeint_t(100)};
+
+//===--------------------------------------------------------------------===//
+// Negate
+//===--------------------------------------------------------------------===//
+
+template <>
+void Hugeint::NegateInPlace<false>(hugeint_t &input) {
+	input.lower = NumericLimits<uint64_t>::Maximum() - input.lower + 1ull;
+	input.upper = -1 - input.upper + (input.lower == 0);
+}
+
+bool Hugeint::TryNegate(hugeint_t input, hugeint_t &result) {
+	if (input.upper == NumericLimits<int64_t>::Minimum() && input.lower == 0) {
+		return false;
+	}
+	NegateInPlace<false>(input);
+	result = input;
+	return true;
+}
+
+hugeint_t Hugeint::Abs(hugeint_t n) {
+	if (n < 0) {
+		return Hugeint::Negate(n);
+	} else {
+		return n;
+	}
+}
+
+//===--------------------------------------------------------------------===//
+// Divide
+//===--------------------------------------------------------------------===//
+
+static uint8_t PositiveHugeintHighestBit(hugeint_t bits) {
+	uint8_t out = 0;
+	if (bits.upper) {
+		out = 64;
+		uint64_t up = static_cast<uint64_t>(bits.upper);
+		while (up) {
+			up >>= 1;
+			out++;
+		}
+	} else {
+		uint64_t low = bits.lower;
+		while (low) {
+			low >>= 1;
+			out++;
+		}
+	}
+	return out;
+}
+
+static bool PositiveHugeintIsBitSet(hugeint_t lhs, uint8_t bit_position) {
+	if (bit_position < 64) {
+		return lhs.lower & (uint64_t(1) << uint64_t(bit_position));
+	} else {
+		return static_cast<uint64_t>(lhs.upper) & (uint64_t(1) << uint64_t(bit_position - 64));
+	}
+}
+
+static hugeint_t PositiveHugeintLeftShift(hugeint_t lhs, uint32_t amount) {
+	D_ASSERT(amount > 0 && amount < 64);
+	hugeint_t result;
+	result.lower = lhs.lower << amount;
+	result.upper =
+	    UnsafeNumericCast<int64_t>((UnsafeNumericCast<uint64_t>(lhs.upper) << amount) + (lhs.lower >> (64 - amount)));
+	return result;
+}
+
+hugeint_t Hugeint::DivModPositive(hugeint_t lhs, uint64_t rhs, uint64_t &remainder) {
+	D_ASSERT(lhs.upper >= 0);
+	// DivMod code adapted from:
+	// https://github.com/calccrypto/uint128_t/blob/master/uint128_t.cpp
+
+	// initialize the result and remainder to 0
+	hugeint_t div_result;
+	div_result.lower = 0;
+	div_result.upper = 0;
+	remainder = 0;
+
+	uint8_t highest_bit_set = PositiveHugeintHighestBit(lhs);
+	// now iterate over the amount of bits that are set in the LHS
+	for (uint8_t x = highest_bit_set; x > 0; x--) {
+		// left-shift the current result and remainder by 1
+		div_result = PositiveHugeintLeftShift(div_result, 1);
+		remainder <<= 1;
+		// we get the value of the bit at position X, where position 0 is the least-significant bit
+		if (PositiveHugeintIsBitSet(lhs, x - 1)) {
+			// increment the remainder
+			remainder++;
+		}
+		if (remainder >= rhs) {
+			// the remainder has passed the division multiplier: add one to the divide result
+			remainder -= rhs;
+			div_result.lower++;
+			if (div_result.lower == 0) {
+				// overflow
+				div_result.upper++;
+			}
+		}
+	}
+	return div_result;
+}
+
+string Hugeint::ToString(hugeint_t input) {
+	uint64_t remainder;
+	string result;
+	if (input == NumericLimits<hugeint_t>::Minimum()) {
+		return string(Hugeint::HUGEINT_MINIMUM_STRING);
+	}
+	bool negative = input.upper < 0;
+	if (negative) {
+		NegateInPlace(input);
+	}
+	while (true) {
+		if (!input.lower && !input.upper) {
+			break;
+		}
+		input = Hugeint::DivModPositive(input, 10, remainder);
+		result = string(1, UnsafeNumericCast<char>('0' + remainder)) + result; // NOLINT
+	}
+	if (result.empty()) {
+		// value is zero
+		return "0";
+	}
+	return negative ? "-" + result : result;
+}
+
+//===--------------------------------------------------------------------===//
+// Multiply
+//===--------------------------------------------------------------------===//
+
+// Multiply with overflow checks
+bool Hugeint::TryMultiply(hugeint_t lhs, hugeint_t rhs, hugeint_t &result) {
+	// Check if one of the sides is hugeint_t minimum, as that can't be negated.
+	// You can only multiply the minimum by 0 or 1, any other value will result in overflow
+	if (lhs == NumericLimits<hugeint_t>::Minimum() || rhs == NumericLimits<hugeint_t>::Minimum()) {
+		if (lhs == 0 || rhs == 0) {
+			result = 0;
+			return true;
+		}
+		if (lhs == 1 || rhs == 1) {
+			result = NumericLimits<hugeint_t>::Minimum();
+			return true;
+		}
+		return false;
+	}
+
+	bool lhs_negative = lhs.upper < 0;
+	bool rhs_negative = rhs.upper < 0;
+	if (lhs_negative && !TryNegate(lhs, lhs)) {
+		return false;
+	}
+	if (rhs_negative && !TryNegate(rhs, rhs)) {
+		return false;
+	}
+
+#if ((__GNUC__ >= 5) || defined(__clang__)) && defined(__SIZEOF_INT128__)
+	__uint128_t left = __uint128_t(lhs.lower) + (__uint128_t(lhs.upper) << 64);
+	__uint128_t right = __uint128_t(rhs.lower) + (__uint128_t(rhs.upper) << 64);
+	__uint128_t result_i128;
+	if (__builtin_mul_overflow(left, right, &result_i128)) {
+		return false;
+	}
+	uint64_t upper = uint64_t(result_i128 >> 64);
+	if (upper & 0x8000000000000000) {
+		return false;
+	}
+	result.upper = int64_t(upper);
+	result.lower = uint64_t(result_i128 & 0xffffffffffffffff);
+#else
+	// Multiply code adapted from:
+	// https://github.com/calccrypto/uint128_t/blob/master/uint128_t.cpp
+
+	// split values into 4 32-bit parts
+	uint64_t top[4] = {uint64_t(lhs.upper) >> 32, uint64_t(lhs.upper) & 0xffffffff, lhs.lower >> 32,
+	                   lhs.lower & 0xffffffff};
+	uint64_t bottom[4] = {uint64_t(rhs.upper) >> 32, uint64_t(rhs.upper) & 0xffffffff, rhs.lower >> 32,
+	                      rhs.lower & 0xffffffff};
+	uint64_t products[4][4];
+
+	// multiply each component of the values
+	for (auto x = 0; x < 4; x++) {
+		for (auto y = 0; y < 4; y++) {
+			products[x][y] = top[x] * bottom[y];
+		}
+	}
+
+	// if any of these products are set to a non-zero value, there is always an overflow
+	if (products[0][0] || products[0][1] || products[0][2] || products[1][0] || products[2][0] || products[1][1]) {
+		return false;
+	}
+	// if the high bits of any of these are set, there is always an overflow
+	if ((products[0][3] & 0xffffffff80000000) || (products[1][2] & 0xffffffff80000000) ||
+	    (products[2][1] & 0xffffffff80000000) || (products[3][0] & 0xffffffff80000000)) {
+		return false;
+	}
+
+	// otherwise we merge the result of the different products together in-order
+
+	// first row
+	uint64_t fourth32 = (products[3][3] & 0xffffffff);
+	uint64_t third32 = (products[3][2] & 0xffffffff) + (products[3][3] >> 32);
+	uint64_t second32 = (products[3][1] & 0xffffffff) + (products[3][2] >> 32);
+	uint64_t first32 = (products[3][0] & 0xffffffff) + (products[3][1] >> 32);
+
+	// second row
+	third32 += (products[2][3] & 0xffffffff);
+	second32 += (products[2][2] & 0xffffffff) + (products[2][3] >> 32);
+	first32 += (products[2][1] & 0xffffffff) + (products[2][2] >> 32);
+
+	// third row
+	second32 += (products[1][3] & 0xffffffff);
+	first32 += (products[1][2] & 0xffffffff) + (products[1][3] >> 32);
+
+	// fourth row
+	first32 += (products[0][3] & 0xffffffff);
+
+	// move carry to next digit
+	third32 += fourth32 >> 32;
+	second32 += third32 >> 32;
+	first32 += second32 >> 32;
+
+	// check if the combination of the different products resulted in an overflow
+	if (first32 & 0xffffff80000000) {
+		return false;
+	}
+
+	// remove carry from current digit
+	fourth32 &= 0xffffffff;
+	third32 &= 0xffffffff;
+	second32 &= 0xffffffff;
+	first32 &= 0xffffffff;
+
+	// combine components
+	result.lower = (third32 << 32) | fourth32;
+	result.upper = (first32 << 32) | second32;
+#endif
+	if (lhs_negative ^ rhs_negative) {
+		NegateInPlace<false>(result);
+	}
+	return true;
+}
+
+// Multiply without overflow check
+template <>
+hugeint_t Hugeint::Multiply<false>(hugeint_t lhs, hugeint_t rhs) {
+	hugeint_t result;
+	bool lhs_negative = lhs.upper < 0;
+	bool rhs_negative = rhs.upper < 0;
+	if (lhs_negative) {
+		NegateInPlace<false>(lhs);
+	}
+	if (rhs_negative) {
+		NegateInPlace<false>(rhs);
+	}
+
+#if ((__GNUC__ >= 5) || defined(__clang__)) && defined(__SIZEOF_INT128__)
+	__uint128_t left = __uint128_t(lhs.lower) + (__uint128_t(lhs.upper) << 64);
+	__uint128_t right = __uint128_t(rhs.lower) + (__uint128_t(rhs.upper) << 64);
+	__uint128_t result_i128;
+	result_i128 = left * right;
+	uint64_t upper = uint64_t(result_i128 >> 64);
+	result.upper = int64_t(upper);
+	result.lower = uint64_t(result_i128 & 0xffffffffffffffff);
+#else
+	// Multiply code adapted from:
+	// https://github.com/calccrypto/uint128_t/blob/master/uint128_t.cpp
+
+	// split values into 4 32-bit parts
+	uint64_t top[4] = {uint64_t(lhs.upper) >> 32, uint64_t(lhs.upper) & 0xffffffff, lhs.lower >> 32,
+	                   lhs.lower & 0xffffffff};
+	uint64_t bottom[4] = {uint64_t(rhs.upper) >> 32, uint64_t(rhs.upper) & 0xffffffff, rhs.lower >> 32,
+	                      rhs.lower & 0xffffffff};
+	uint64_t products[4][4];
+
+	// multiply each component of the values
+	for (auto x = 0; x < 4; x++) {
+		for (auto y = 0; y < 4; y++) {
+			products[x][y] = top[x] * bottom[y];
+		}
+	}
+
+	// first row
+	uint64_t fourth32 = (products[3][3] & 0xffffffff);
+	uint64_t third32 = (products[3][2] & 0xffffffff) + (products[3][3] >> 32);
+	uint64_t second32 = (products[3][1] & 0xffffffff) + (products[3][2] >> 32);
+	uint64_t first32 = (products[3][0] & 0xffffffff) + (products[3][1] >> 32);
+
+	// second row
+	third32 += (products[2][3] & 0xffffffff);
+	second32 += (products[2][2] & 0xffffffff) + (products[2][3] >> 32);
+	first32 += (products[2][1] & 0xffffffff) + (products[2][2] >> 32);
+
+	// third row
+	second32 += (products[1][3] & 0xffffffff);
+	first32 += (products[1][2] & 0xffffffff) + (products[1][3] >> 32);
+
+	// fourth row
+	first32 += (products[0][3] & 0xffffffff);
+
+	// move carry to next digit
+	third32 += fourth32 >> 32;
+	second32 += third32 >> 32;
+	first32 += second32 >> 32;
+
+	// remove carry from current digit
+	fourth32 &= 0xffffffff;
+	third32 &= 0xffffffff;
+	second32 &= 0xffffffff;
+	first32 &= 0xffffffff;
+
+	// combine components
+	result.lower = (third32 << 32) | fourth32;
+	result.upper = (first32 << 32) | second32;
+#endif
+	if (lhs_negative ^ rhs_negative) {
+		NegateInPlace<false>(result);
+	}
+	return result;
+}
+
+//===--------------------------------------------------------------------===//
+// Divide
+//===--------------------------------------------------------------------===//
+
+int Sign(hugeint_t n) {
+	return ((n > 0) - (n < 0));
+}
+
+hugeint_t Abs(hugeint_t n) {
+	D_ASSERT(n != NumericLimits<hugeint_t>::Minimum());
+	return (n * Sign(n));
+}
+
+static hugeint_t DivModMinimum(hugeint_t lhs, hugeint_t rhs, hugeint_t &remainder) {
+	D_ASSERT(lhs == NumericLimits<hugeint_t>::Minimum() || rhs == NumericLimits<hugeint_t>::Minimum());
+	if (rhs == NumericLimits<hugeint_t>::Minimum()) {
+		if (lhs == NumericLimits<hugeint_t>::Minimum()) {
+			remainder = 0;
+			return 1;
+		}
+		remainder = lhs;
+		return 0;
+	}
+
+	// Add 1 to minimum and run through DivMod again
+	hugeint_t result = Hugeint::DivMod(NumericLimits<hugeint_t>::Minimum() + 1, rhs, remainder);
+
+	// If the 1 mattered we need to adjust the result, otherwise the remainder
+	if (Abs(remainder) + 1 == Abs(rhs)) {
+		result -= Sign(rhs);
+		remainder = 0;
+	} else {
+		remainder -= 1;
+	}
+	return result;
+}
+
+// No overflow checks
+hugeint_t Hugeint::DivMod(hugeint_t lhs, hugeint_t rhs, hugeint_t &remainder) {
+	if (rhs == 0) {
+		remainder = lhs;
+		return hugeint_t(0);
+	}
+
+	// Check if one of the sides is hugeint_t minimum, as that can't be negated.
+	if (lhs == NumericLimits<hugeint_t>::Minimum() || rhs == NumericLimits<hugeint_t>::Minimum()) {
+		return DivModMinimum(lhs, rhs, remainder);
+	}
+
+	bool lhs_negative = lhs.upper < 0;
+	bool rhs_negative = rhs.upper < 0;
+	if (lhs_negative) {
+		Hugeint::NegateInPlace<false>(lhs);
+	}
+	if (rhs_negative) {
+		Hugeint::NegateInPlace<false>(rhs);
+	}
+	// DivMod code adapted from:
+	// https://github.com/calccrypto/uint128_t/blob/master/uint128_t.cpp
+
+	// initialize the result and remainder to 0
+	hugeint_t div_result;
+	div_result.lower = 0;
+	div_result.upper = 0;
+	remainder.lower = 0;
+	remainder.upper = 0;
+
+	uint8_t highest_bit_set = PositiveHugeintHighestBit(lhs);
+	// now iterate over the amount of bits that are set in the LHS
+	for (uint8_t x = highest_bit_set; x > 0; x--) {
+		// left-shift the current result and remainder by 1
+		div_result = PositiveHugeintLeftShift(div_result, 1);
+		remainder = PositiveHugeintLeftShift(remainder, 1);
+
+		// we get the value of the bit at position X, where position 0 is the least-significant bit
+		if (PositiveHugeintIsBitSet(lhs, x - 1)) {
+			remainder += 1;
+		}
+		if (Hugeint::GreaterThanEquals(remainder, rhs)) {
+			// the remainder has passed the division multiplier: add one to the divide result
+			remainder -= rhs;
+			div_result += 1;
+		}
+	}
+	if (lhs_negative ^ rhs_negative) {
+		Hugeint::NegateInPlace<false>(div_result);
+	}
+	if (lhs_negative) {
+		Hugeint::NegateInPlace<false>(remainder);
+	}
+	return div_result;
+}
+
+bool Hugeint::TryDivMod(hugeint_t lhs, hugeint_t rhs, hugeint_t &result, hugeint_t &remainder) {
+	// No division by zero
+	if (rhs == 0) {
+		return false;
+	}
+
+	// division only has one reason to overflow: MINIMUM / -1
+	if (lhs == NumericLimits<hugeint_t>::Minimum() && rhs == -1) {
+		return false;
+	}
+
+	result = Hugeint::DivMod(lhs, rhs, remainder);
+	return true;
+}
+
+template <>
+hugeint_t Hugeint::Divide<false>(hugeint_t lhs, hugeint_t rhs) {
+	hugeint_t remainder;
+	return Hugeint::DivMod(lhs, rhs, remainder);
+}
+
+template <>
+hugeint_t Hugeint::Modulo<false>(hugeint_t lhs, hugeint_t rhs) {
+	hugeint_t remainder;
+	(void)Hugeint::DivMod(lhs, rhs, remainder);
+	return remainder;
+}
+
+//===--------------------------------------------------------------------===//
+// Add/Subtract
+//===--------------------------------------------------------------------===//
+bool Hugeint::TryAddInPlace(hugeint_t &lhs, hugeint_t rhs) {
+	int overflow = lhs.lower + rhs.lower < lhs.lower;
+	if (rhs.upper >= 0) {
+		// RHS is positive: check for overflow
+		if (lhs.upper > (std::numeric_limits<int64_t>::max() - rhs.upper - overflow)) {
+			return false;
+		}
+		lhs.upper = lhs.upper + overflow + rhs.upper;
+	} else {
+		// RHS is negative: check for underflow
+		if (lhs.upper < std::numeric_limits<int64_t>::min() - rhs.upper - overflow) {
+			return false;
+		}
+		lhs.upper = lhs.upper + (overflow + rhs.upper);
+	}
+	lhs.lower += rhs.lower;
+	return true;
+}
+
+bool Hugeint::TrySubtractInPlace(hugeint_t &lhs, hugeint_t rhs) {
+	// underflow
+	int underflow = lhs.lower - rhs.lower > lhs.lower;
+	if (rhs.upper >= 0) {
+		// RHS is positive: check for underflow
+		if (lhs.upper < (std::numeric_limits<int64_t>::min() + rhs.upper + underflow)) {
+			return false;
+		}
+		lhs.upper = (lhs.upper - rhs.upper) - underflow;
+	} else {
+		// RHS is negative: check for overflow
+		if (lhs.upper > std::numeric_limits<int64_t>::min() &&
+		    lhs.upper - 1 >= (std::numeric_limits<int64_t>::max() + rhs.upper + underflow)) {
+			return false;
+		}
+		lhs.upper = lhs.upper - (rhs.upper + underflow);
+	}
+	lhs.lower -= rhs.lower;
+	return true;
+}
+
+template <>
+hugeint_t Hugeint::Add<false>(hugeint_t lhs, hugeint_t rhs) {
+	return lhs + rhs;
+}
+
+template <>
+hugeint_t Hugeint::Subtract<false>(hugeint_t lhs, hugeint_t rhs) {
+	return lhs - rhs;
+}
+
+//===--------------------------------------------------------------------===//
+// Hugeint Cast/Conversion
+//===--------------------------------------------------------------------===//
+template <class DST, bool SIGNED = true>
+bool HugeintTryCastInteger(hugeint_t input, DST &result) {
+	switch (input.upper) {
+	case 0:
+		// positive number: check if the positive number is in range
+		if (input.lower <= uint64_t(NumericLimits<DST>::Maximum())) {
+			result = DST(input.lower);
+			return true;
+		}
+		break;
+	case -1:
+		if (!SIGNED) {
+			return false;
+		}
+		// negative number: check if the negative number is in range
+		if (input.lower >= NumericLimits<uint64_t>::Maximum() - uint64_t(NumericLimits<DST>::Maximum())) {
+			result = -DST(NumericLimits<uint64_t>::Maximum() - input.lower) - 1;
+			return true;
+		}
+		break;
+	default:
+		break;
+	}
+	return false;
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, int8_t &result) {
+	return HugeintTryCastInteger<int8_t>(input, result);
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, int16_t &result) {
+	return HugeintTryCastInteger<int16_t>(input, result);
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, int32_t &result) {
+	return HugeintTryCastInteger<int32_t>(input, result);
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, int64_t &result) {
+	return HugeintTryCastInteger<int64_t>(input, result);
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, uint8_t &result) {
+	return HugeintTryCastInteger<uint8_t, false>(input, result);
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, uint16_t &result) {
+	return HugeintTryCastInteger<uint16_t, false>(input, result);
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, uint32_t &result) {
+	return HugeintTryCastInteger<uint32_t, false>(input, result);
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, uint64_t &result) {
+	return HugeintTryCastInteger<uint64_t, false>(input, result);
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, hugeint_t &result) {
+	result = input;
+	return true;
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, uhugeint_t &result) {
+	if (input < 0) {
+		return false;
+	}
+
+	result.lower = input.lower;
+	result.upper = UnsafeNumericCast<uint64_t>(input.upper);
+	return true;
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, float &result) {
+	double dbl_result;
+	Hugeint::TryCast(input, dbl_result);
+	result = (float)dbl_result;
+	return true;
+}
+
+template <class REAL_T>
+bool CastBigintToFloating(hugeint_t input, REAL_T &result) {
+	switch (input.upper) {
+	case -1:
+		// special case for upper = -1 to avoid rounding issues in small negative numbers
+		result = -REAL_T(NumericLimits<uint64_t>::Maximum() - input.lower) - 1;
+		break;
+	default:
+		result = REAL_T(input.lower) + REAL_T(input.upper) * (REAL_T(NumericLimits<uint64_t>::Maximum()) + 1);
+		break;
+	}
+	return true;
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, double &result) {
+	return CastBigintToFloating<double>(input, result);
+}
+
+template <>
+bool Hugeint::TryCast(hugeint_t input, long double &result) {
+	return CastBigintToFloating<long double>(input, result);
+}
+
+template <class DST>
+hugeint_t HugeintConvertInteger(DST input) {
+	hugeint_t result;
+	result.lower = (uint64_t)input;
+	result.upper = (input < 0) * -1;
+	return result;
+}
+
+template <>
+bool Hugeint::TryConvert(int8_t value, hugeint_t &result) {
+	result = HugeintConvertInteger<int8_t>(value);
+	return true;
+}
+
+template <>
+bool Hugeint::TryConvert(const char *value, hugeint_t &result) {
+	auto len = strlen(value);
+	string_t string_val(value, UnsafeNumericCast<uint32_t>(len));
+	return TryCast::Operation<string_t, hugeint_t>(string_val, result, true);
+}
+
+template <>
+bool Hugeint::TryConvert(int16_t value, hugeint_t &result) {
+	result = HugeintConvertInteger<int16_t>(value);
+	return true;
+}
+
+template <>
+bool Hugeint::TryConvert(int32_t value, hugeint_t &result) {
+	result = HugeintConvertInteger<int32_t>(value);
+	return true;
+}
+
+template <>
+bool Hugeint::TryConvert(int64_t value, hugeint_t &result) {
+	result = HugeintConvertInteger<int64_t>(value);
+	return true;
+}
+template <>
+bool Hugeint::TryConvert(uint8_t value, hugeint_t &result) {
+	result = HugeintConvertInteger<uint8_t>(value);
+	return true;
+}
+template <>
+bool Hugeint::TryConvert(uint16_t value, hugeint_t &result) {
+	result = HugeintConvertInteger<uint16_t>(value);
+	return true;
+}
+template <>
+bool Hugeint::TryConvert(uint32_t value, hugeint_t &result) {
+	result = HugeintConvertInteger<uint32_t>(value);
+	return true;
+}
+template <>
+bool Hugeint::TryConvert(uint64_t value, hugeint_t &result) {
+	result = HugeintConvertInteger<uint64_t>(value);
+	return true;
+}
+
+template <>
+bool Hugeint::TryConvert(hugeint_t value, hugeint_t &result) {
+	result = value;
+	return true;
+}
+
+template <>
+bool Hugeint::TryConvert(float value, hugeint_t &result) {
+	return Hugeint::TryConvert(double(value), result);
+}
+
+template <class REAL_T>
+bool ConvertFloatingToBigint(REAL_T value, hugeint_t &result) {
+	if (!Value::IsFinite<REAL_T>(value)) {
+		return false;
+	}
+	if (value <= -170141183460469231731687303715884105728.0 || value >= 170141183460469231731687303715884105727.0) {
+		return false;
+	}
+	bool negative = value < 0;
+	if (negative) {
+		value = -value;
+	}
+	result.lower = (uint64_t)fmod(value, REAL_T(NumericLimits<uint64_t>::Maximum()));
+	result.upper = (int64_t)(value / REAL_T(NumericLimits<uint64_t>::Maximum()));
+	if (negative) {
+		Hugeint::NegateInPlace(result);
+	}
+	return true;
+}
+
+template <>
+bool Hugeint::TryConvert(double value, hugeint_t &result) {
+	return ConvertFloatingToBigint<double>(value, result);
+}
+
+template <>
+bool Hugeint::TryConvert(long double value, hugeint_t &result) {
+	return ConvertFloatingToBigint<long double>(value, result);
+}
+
+//===--------------------------------------------------------------------===//
+// hugeint_t operators
+//===--------------------------------------------------------------------===//
+hugeint_t::hugeint_t(int64_t value) {
+	auto result = Hugeint::Convert(value);
+	this->lower = result.lower;
+	this->upper = result.upper;
+}
+
+bool hugeint_t::operator==(const hugeint_t &rhs) const {
+	return Hugeint::Equals(*this, rhs);
+}
+
+bool hugeint_t::operator!=(const hugeint_t &rhs) const {
+	return Hugeint::NotEquals(*this, rhs);
+}
+
+bool hugeint_t::operator<(const hugeint_t &rhs) const {
+	return Hugeint::LessThan(*this, rhs);
+}
+
+bool hugeint_t::operator<=(const hugeint_t &rhs) const {
+	return Hugeint::LessThanEquals(*this, rhs);
+}
+
+bool hugeint_t::operator>(const hugeint_t &rhs) const {
+	return Hugeint::GreaterThan(*this, rhs);
+}
+
+bool hugeint_t::operator>=(const hugeint_t &rhs) const {
+	return Hugeint::GreaterThanEquals(*this, rhs);
+}
+
+hugeint_t hugeint_t::operator+(const hugeint_t &rhs) const {
+	return hugeint_t(upper + rhs.upper + ((lower + rhs.lower) < lower), lower + rhs.lower);
+}
+
+hugeint_t hugeint_t::operator-(const hugeint_t &rhs) const {
+	return hugeint_t(upper - rhs.upper - ((lower - rhs.lower) > lower), lower - rhs.lower);
+}
+
+hugeint_t hugeint_t::operator*(const hugeint_t &rhs) const {
+	hugeint_t result = *this;
+	result *= rhs;
+	return result;
+}
+
+hugeint_t hugeint_t::operator/(const hugeint_t &rhs) const {
+	return Hugeint::Divide<false>(*this, rhs);
+}
+
+hugeint_t hugeint_t::operator%(const hugeint_t &rhs) const {
+	return Hugeint::Modulo<false>(*this, rhs);
+}
+
+hugeint_t hugeint_t::operator-() const {
+	return Hugeint::Negate<false>(*this);
+}
+
+hugeint_t hugeint_t::operator>>(const hugeint_t &rhs) const {
+	hugeint_t result;
+	uint64_t shift = rhs.lower;
+	if (rhs.upper != 0 || shift >= 128) {
+		return hugeint_t(0);
+	} else if (shift == 0) {
+		return *this;
+	} else if (shift == 64) {
+		result.upper = (upper < 0) ? -1 : 0;
+		result.lower = uint64_t(upper);
+	} else if (shift < 64) {
+		// perform lower shift in unsigned integer, and mask away the most significant bit
+		result.lower = (uint64_t(upper) << (64 - shift)) | (lower >> shift);
+		result.upper = upper >> shift;
+	} else {
+		D_ASSERT(shift < 128);
+		result.lower = uint64_t(upper >> (shift - 64));
+		result.upper = (upper < 0) ? -1 : 0;
+	}
+	return result;
+}
+
+hugeint_t hugeint_t::operator<<(const hugeint_t &rhs) const {
+	if (upper < 0) {
+		return hugeint_t(0);
+	}
+	hugeint_t result;
+	uint64_t shift = rhs.lower;
+	if (rhs.upper != 0 || shift >= 128) {
+		return hugeint_t(0);
+	} else if (shift == 64) {
+		result.upper = int64_t(lower);
+		result.lower = 0;
+	} else if (shift == 0) {
+		return *this;
+	} else if (shift < 64) {
+		// perform upper shift in unsigned integer, and mask away the most significant bit
+		uint64_t upper_shift = ((uint64_t(upper) << shift) + (lower >> (64 - shift))) & 0x7FFFFFFFFFFFFFFF;
+		result.lower = lower << shift;
+		result.upper = int64_t(upper_shift);
+	} else {
+		D_ASSERT(shift < 128);
+		result.lower = 0;
+		result.upper = UnsafeNumericCast<int64_t>((lower << (shift - 64)) & 0x7FFFFFFFFFFFFFFF);
+	}
+	return result;
+}
+
+hugeint_t hugeint_t::operator&(const hugeint_t &rhs) const {
+	hugeint_t result;
+	result.lower = lower & rhs.lower;
+	result.upper = upper & rhs.upper;
+	return result;
+}
+
+hugeint_t hugeint_t::operator|(const hugeint_t &rhs) const {
+	hugeint_t result;
+	result.lower = lower | rhs.lower;
+	result.upper = upper | rhs.upper;
+	return result;
+}
+
+hugeint_t hugeint_t::operator^(const hugeint_t &rhs) const {
+	hugeint_t result;
+	result.lower = lower ^ rhs.lower;
+	result.upper = upper ^ rhs.upper;
+	return result;
+}
+
+hugeint_t hugeint_t::operator~() const {
+	hugeint_t result;
+	result.lower = ~lower;
+	result.upper = ~upper;
+	return result;
+}
+
+hugeint_t &hugeint_t::operator+=(const hugeint_t &rhs) {
+	*this = *this + rhs;
+	return *this;
+}
+hugeint_t &hugeint_t::operator-=(const hugeint_t &rhs) {
+	*this = *this - rhs;
+	return *this;
+}
+hugeint_t &hugeint_t::operator*=(const hugeint_t &rhs) {
+	*this = Hugeint::Multiply<false>(*this, rhs);
+	return *this;
+}
+hugeint_t &hugeint_t::operator/=(const hugeint_t &rhs) {
+	*this = Hugeint::Divide<false>(*this, rhs);
+	return *this;
+}
+hugeint_t &hugeint_t::operator%=(const hugeint_t &rhs) {
+	*this = Hugeint::Modulo<false>(*this, rhs);
+	return *this;
+}
+hugeint_t &hugeint_t::operator>>=(const hugeint_t &rhs) {
+	*this = *this >> rhs;
+	return *this;
+}
+hugeint_t &hugeint_t::operator<<=(const hugeint_t &rhs) {
+	*this = *this << rhs;
+	return *this;
+}
+hugeint_t &hugeint_t::operator&=(const hugeint_t &rhs) {
+	lower &= rhs.lower;
+	upper &= rhs.upper;
+	return *this;
+}
+hugeint_t &hugeint_t::operator|=(const hugeint_t &rhs) {
+	lower |= rhs.lower;
+	upper |= rhs.upper;
+	return *this;
+}
+hugeint_t &hugeint_t::operator^=(const hugeint_t &rhs) {
+	lower ^= rhs.lower;
+	upper ^= rhs.upper;
+	return *this;
+}
+
+bool hugeint_t::operator!() const {
+	return *this == 0;
+}
+
+hugeint_t::operator bool() const {
+	return *this != 0;
+}
+
+template <class T>
+static T NarrowCast(const hugeint_t &input) {
+	// NarrowCast is supposed to truncate (take lower)
+	return static_cast<T>(input.lower);
+}
+
+hugeint_t::operator uint8_t() const {
+	return NarrowCast<uint8_t>(*this);
+}
+hugeint_t::operator uint16_t() const {
+	return NarrowCast<uint16_t>(*this);
+}
+hugeint_t::operator uint32_t() const {
+	return NarrowCast<uint32_t>(*this);
+}
+hugeint_t::operator uint64_t() const {
+	return NarrowCast<uint64_t>(*this);
+}
+hugeint_t::operator int8_t() const {
+	return NarrowCast<int8_t>(*this);
+}
+hugeint_t::operator int16_t() const {
+	return NarrowCast<int16_t>(*this);
+}
+hugeint_t::operator int32_t() const {
+	return NarrowCast<int32_t>(*this);
+}
+hugeint_t::operator int64_t() const {
+	return NarrowCast<int64_t>(*this);
+}
+hugeint_t::operator uhugeint_t() const {
+	return {static_cast<uint64_t>(this->upper), this->lower};
+}
+
+string hugeint_t::ToString() const {
+	return Hugeint::ToString(*this);
+}
+
+} // namespace duckdb
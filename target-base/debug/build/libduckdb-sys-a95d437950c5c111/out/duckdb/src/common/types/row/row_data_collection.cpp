@@ -0,0 +1,141 @@
+#include "duckdb/common/types/row/row_data_collection.hpp"
+
+namespace duckdb {
+
+RowDataCollection::RowDataCollection(BufferManager &buffer_manager, idx_t block_capacity, idx_t entry_size,
+                                     bool keep_pinned)
+    : buffer_manager(buffer_manager), count(0), block_capacity(block_capacity), entry_size(entry_size),
+      keep_pinned(keep_pinned) {
+	D_ASSERT(block_capacity * entry_size + entry_size > buffer_manager.GetBlockSize());
+}
+
+idx_t RowDataCollection::AppendToBlock(RowDataBlock &block, BufferHandle &handle,
+                                       vector<BlockAppendEntry> &append_entries, idx_t remaining, idx_t entry_sizes[]) {
+	idx_t append_count = 0;
+	data_ptr_t dataptr;
+	if (entry_sizes) {
+		D_ASSERT(entry_size == 1);
+		// compute how many entries fit if entry size is variable
+		dataptr = handle.Ptr() + block.byte_offset;
+		for (idx_t i = 0; i < remaining; i++) {
+			if (block.byte_offset + entry_sizes[i] > block.capacity) {
+				if (block.count == 0 && append_count == 0 && entry_sizes[i] > block.capacity) {
+					// special case: single entry is bigger than block capacity
+					// resize current block to fit the entry, append it, and move to the next block
+					block.capacity = entry_sizes[i];
+					buffer_manager.ReAllocate(block.block, block.capacity);
+					dataptr = handle.Ptr();
+					append_count++;
+					block.byte_offset += entry_sizes[i];
+				}
+				break;
+			}
+			append_count++;
+			block.byte_offset += entry_sizes[i];
+		}
+	} else {
+		append_count = MinValue<idx_t>(remaining, block.capacity - block.count);
+		dataptr = handle.Ptr() + block.count * entry_size;
+	}
+	append_entries.emplace_back(dataptr, append_count);
+	block.count += append_count;
+	return append_count;
+}
+
+RowDataBlock &RowDataCollection::CreateBlock() {
+	blocks.push_back(make_uniq<RowDataBlock>(MemoryTag::ORDER_BY, buffer_manager, block_capacity, entry_size));
+	return *blocks.back();
+}
+
+vector<BufferHandle> RowDataCollection::Build(idx_t added_count, data_ptr_t key_locations[], idx_t entry_sizes[],
+                                              const SelectionVector *sel) {
+	vector<BufferHandle> handles;
+	vector<BlockAppendEntry> append_entries;
+
+	// first allocate space of where to serialize the keys and payload columns
+	idx_t remaining = added_count;
+	{
+		// first append to the last block (if any)
+		lock_guard<mutex> append_lock(rdc_lock);
+		count += added_count;
+
+		if (!blocks.empty()) {
+			auto &last_block = *blocks.back();
+			if (last_block.count < last_block.capacity) {
+				// last block has space: pin the buffer of this block
+				auto handle = buffer_manager.Pin(last_block.block);
+				// now append to the block
+				idx_t append_count = AppendToBlock(last_block, handle, append_entries, remaining, entry_sizes);
+				remaining -= append_count;
+				handles.push_back(std::move(handle));
+			}
+		}
+		while (remaining > 0) {
+			// now for the remaining data, allocate new buffers to store the data and append there
+			auto &new_block = CreateBlock();
+			auto handle = buffer_manager.Pin(new_block.block);
+
+			// offset the entry sizes array if we have added entries already
+			idx_t *offset_entry_sizes = entry_sizes ? entry_sizes + added_count - remaining : nullptr;
+
+			idx_t append_count = AppendToBlock(new_block, handle, append_entries, remaining, offset_entry_sizes);
+			D_ASSERT(new_block.count > 0);
+			remaining -= append_count;
+
+			if (keep_pinned) {
+				pinned_blocks.push_back(std::move(handle));
+			} else {
+				handles.push_back(std::move(handle));
+			}
+		}
+	}
+	// now set up the key_locations based on the append entries
+	idx_t append_idx = 0;
+	for (auto &append_entry : append_entries) {
+		idx_t next = append_idx + append_entry.count;
+		if (entry_sizes) {
+			for (; append_idx < next; append_idx++) {
+				key_locations[append_idx] = append_entry.baseptr;
+				append_entry.baseptr += entry_sizes[append_idx];
+			}
+		} else {
+			for (; append_idx < next; append_idx++) {
+				auto idx = sel->get_index(append_idx);
+				key_locations[idx] = append_entry.baseptr;
+				append_entry.baseptr += entry_size;
+			}
+		}
+	}
+	// return the unique pointers to the handles because they must stay pinned
+	return handles;
+}
+
+void RowDataCollection::Merge(RowDataCollection &other) {
+	if (other.count == 0) {
+		return;
+	}
+	RowDataCollection temp(buffer_manager, buffer_manager.GetBlockSize(), 1);
+	{
+		//	One lock at a time to avoid deadlocks
+		lock_guard<mutex> read_lock(other.rdc_lock);
+		temp.count = other.count;
+		temp.block_capacity = other.block_capacity;
+		temp.entry_size = other.entry_size;
+		temp.blocks = std::move(other.blocks);
+		temp.pinned_blocks = std::move(other.pinned_blocks);
+	}
+	other.Clear();
+
+	lock_guard<mutex> write_lock(rdc_lock);
+	count += temp.count;
+	block_capacity = MaxValue(block_capacity, temp.block_capacity);
+	entry_size = MaxValue(entry_size, temp.entry_size);
+	for (auto &block : temp.blocks) {
+		blocks.emplace_back(std::move(block));
+	}
+	for (auto &handle : temp.pinned_blocks) {
+		pinned_blocks.emplace_back(std::move(handle));
+	}
+}
+
+} // namespace duckdb
@@ -0,0 +1,53 @@
+#include "duckdb/common/windows_util.hpp"
+
+namespace duckdb {
+
+#ifdef DUCKDB_WINDOWS
+
+std::wstring WindowsUtil::UTF8ToUnicode(const char *input) {
+	idx_t result_size;
+
+	result_size = MultiByteToWideChar(CP_UTF8, 0, input, -1, nullptr, 0);
+	if (result_size == 0) {
+		throw IOException("Failure in MultiByteToWideChar");
+	}
+	auto buffer = make_unsafe_uniq_array<wchar_t>(result_size);
+	result_size = MultiByteToWideChar(CP_UTF8, 0, input, -1, buffer.get(), result_size);
+	if (result_size == 0) {
+		throw IOException("Failure in MultiByteToWideChar");
+	}
+	return std::wstring(buffer.get(), result_size);
+}
+
+static string WideCharToMultiByteWrapper(LPCWSTR input, uint32_t code_page) {
+	idx_t result_size;
+
+	result_size = WideCharToMultiByte(code_page, 0, input, -1, 0, 0, 0, 0);
+	if (result_size == 0) {
+		throw IOException("Failure in WideCharToMultiByte");
+	}
+	auto buffer = make_unsafe_uniq_array<char>(result_size);
+	result_size = WideCharToMultiByte(code_page, 0, input, -1, buffer.get(), result_size, 0, 0);
+	if (result_size == 0) {
+		throw IOException("Failure in WideCharToMultiByte");
+	}
+	return string(buffer.get(), result_size - 1);
+}
+
+string WindowsUtil::UnicodeToUTF8(LPCWSTR input) {
+	return WideCharToMultiByteWrapper(input, CP_UTF8);
+}
+
+static string WindowsUnicodeToMBCS(LPCWSTR unicode_text, int use_ansi) {
+	uint32_t code_page = use_ansi ? CP_ACP : CP_OEMCP;
+	return WideCharToMultiByteWrapper(unicode_text, code_page);
+}
+
+string WindowsUtil::UTF8ToMBCS(const char *input, bool use_ansi) {
+	auto unicode = WindowsUtil::UTF8ToUnicode(input);
+	return WindowsUnicodeToMBCS(unicode.c_str(), use_ansi);
+}
+
+#endif
+
+} // namespace duckdb
@@ -0,0 +1,20 @@
+#include "duckdb/common/vector_operations/vector_operations.hpp"
+#include "duckdb/execution/expression_executor.hpp"
+#include "duckdb/planner/expression/bound_constant_expression.hpp"
+
+namespace duckdb {
+
+unique_ptr<ExpressionState> ExpressionExecutor::InitializeState(const BoundConstantExpression &expr,
+                                                                ExpressionExecutorState &root) {
+	auto result = make_uniq<ExpressionState>(expr, root);
+	result->Finalize();
+	return result;
+}
+
+void ExpressionExecutor::Execute(const BoundConstantExpression &expr, ExpressionState *state,
+                                 const SelectionVector *sel, idx_t count, Vector &result) {
+	D_ASSERT(expr.value.type() == expr.return_type);
+	result.Reference(expr.value);
+}
+
+} // namespace duckdb
@@ -0,0 +1,86 @@
+#include "duckdb/execution/operator/aggregate/aggregate_object.hpp"
+
+#include "duckdb/planner/expression/bound_aggregate_expression.hpp"
+#include "duckdb/planner/expression/bound_window_expression.hpp"
+
+namespace duckdb {
+
+AggregateObject::AggregateObject(AggregateFunction function, FunctionData *bind_data, idx_t child_count,
+                                 idx_t payload_size, AggregateType aggr_type, PhysicalType return_type,
+                                 Expression *filter)
+    : function(std::move(function)),
+      bind_data_wrapper(bind_data ? make_shared_ptr<FunctionDataWrapper>(bind_data->Copy()) : nullptr),
+      child_count(child_count), payload_size(payload_size), aggr_type(aggr_type), return_type(return_type),
+      filter(filter) {
+}
+
+AggregateObject::AggregateObject(BoundAggregateExpression *aggr)
+    : AggregateObject(aggr->function, aggr->bind_info.get(), aggr->children.size(),
+                      AlignValue(aggr->function.state_size(aggr->function)), aggr->aggr_type,
+                      aggr->return_type.InternalType(), aggr->filter.get()) {
+}
+
+AggregateObject::AggregateObject(const BoundWindowExpression &window)
+    : AggregateObject(*window.aggregate, window.bind_info.get(), window.children.size(),
+                      AlignValue(window.aggregate->state_size(*window.aggregate)),
+                      window.distinct ? AggregateType::DISTINCT : AggregateType::NON_DISTINCT,
+                      window.return_type.InternalType(), window.filter_expr.get()) {
+}
+
+vector<AggregateObject> AggregateObject::CreateAggregateObjects(const vector<BoundAggregateExpression *> &bindings) {
+	vector<AggregateObject> aggregates;
+	aggregates.reserve(bindings.size());
+	for (auto &binding : bindings) {
+		aggregates.emplace_back(binding);
+	}
+	return aggregates;
+}
+
+AggregateFilterData::AggregateFilterData(ClientContext &context, Expression &filter_expr,
+                                         const vector<LogicalType> &payload_types)
+    : filter_executor(context, &filter_expr), true_sel(STANDARD_VECTOR_SIZE) {
+	if (payload_types.empty()) {
+		return;
+	}
+	filtered_payload.Initialize(Allocator::Get(context), payload_types);
+}
+
+idx_t AggregateFilterData::ApplyFilter(DataChunk &payload) {
+	filtered_payload.Reset();
+
+	auto count = filter_executor.SelectExpression(payload, true_sel);
+	filtered_payload.Slice(payload, true_sel, count);
+	return count;
+}
+
+AggregateFilterDataSet::AggregateFilterDataSet() {
+}
+
+void AggregateFilterDataSet::Initialize(ClientContext &context, const vector<AggregateObject> &aggregates,
+                                        const vector<LogicalType> &payload_types) {
+	bool has_filters = false;
+	for (auto &aggregate : aggregates) {
+		if (aggregate.filter) {
+			has_filters = true;
+			break;
+		}
+	}
+	if (!has_filters) {
+		// no filters: nothing to do
+		return;
+	}
+	filter_data.resize(aggregates.size());
+	for (idx_t aggr_idx = 0; aggr_idx < aggregates.size(); aggr_idx++) {
+		auto &aggr = aggregates[aggr_idx];
+		if (aggr.filter) {
+			filter_data[aggr_idx] = make_uniq<AggregateFilterData>(context, *aggr.filter, payload_types);
+		}
+	}
+}
+
+AggregateFilterData &AggregateFilterDataSet::GetFilterData(idx_t aggr_idx) {
+	D_ASSERT(aggr_idx < filter_data.size());
+	D_ASSERT(filter_data[aggr_idx]);
+	return *filter_data[aggr_idx];
+}
+} // namespace duckdb
@@ -0,0 +1,96 @@
+#include "duckdb/execution/operator/aggregate/grouped_aggregate_data.hpp"
+
+namespace duckdb {
+
+idx_t GroupedAggregateData::GroupCount() const {
+	return groups.size();
+}
+
+const vector<vector<idx_t>> &GroupedAggregateData::GetGroupingFunctions() const {
+	return grouping_functions;
+}
+
+void GroupedAggregateData::InitializeGroupby(vector<unique_ptr<Expression>> groups,
+                                             vector<unique_ptr<Expression>> expressions,
+                                             vector<unsafe_vector<idx_t>> grouping_functions) {
+	InitializeGroupbyGroups(std::move(groups));
+	vector<LogicalType> payload_types_filters;
+
+	SetGroupingFunctions(grouping_functions);
+
+	filter_count = 0;
+	for (auto &expr : expressions) {
+		D_ASSERT(expr->GetExpressionClass() == ExpressionClass::BOUND_AGGREGATE);
+		D_ASSERT(expr->IsAggregate());
+		auto &aggr = expr->Cast<BoundAggregateExpression>();
+		bindings.push_back(&aggr);
+
+		aggregate_return_types.push_back(aggr.return_type);
+		for (auto &child : aggr.children) {
+			payload_types.push_back(child->return_type);
+		}
+		if (aggr.filter) {
+			filter_count++;
+			payload_types_filters.push_back(aggr.filter->return_type);
+		}
+		if (!aggr.function.combine) {
+			throw InternalException("Aggregate function %s is missing a combine method", aggr.function.name);
+		}
+		aggregates.push_back(std::move(expr));
+	}
+	for (const auto &pay_filters : payload_types_filters) {
+		payload_types.push_back(pay_filters);
+	}
+}
+
+void GroupedAggregateData::InitializeDistinct(const unique_ptr<Expression> &aggregate,
+                                              const vector<unique_ptr<Expression>> *groups_p) {
+	auto &aggr = aggregate->Cast<BoundAggregateExpression>();
+	D_ASSERT(aggr.IsDistinct());
+
+	// Add the (empty in ungrouped case) groups of the aggregates
+	InitializeDistinctGroups(groups_p);
+
+	// bindings.push_back(&aggr);
+	filter_count = 0;
+	aggregate_return_types.push_back(aggr.return_type);
+	for (idx_t i = 0; i < aggr.children.size(); i++) {
+		auto &child = aggr.children[i];
+		group_types.push_back(child->return_type);
+		groups.push_back(child->Copy());
+		payload_types.push_back(child->return_type);
+		if (aggr.filter) {
+			filter_count++;
+		}
+	}
+	if (!aggr.function.combine) {
+		throw InternalException("Aggregate function %s is missing a combine method", aggr.function.name);
+	}
+}
+
+void GroupedAggregateData::InitializeDistinctGroups(const vector<unique_ptr<Expression>> *groups_p) {
+	if (!groups_p) {
+		return;
+	}
+	for (auto &expr : *groups_p) {
+		group_types.push_back(expr->return_type);
+		groups.push_back(expr->Copy());
+	}
+}
+
+void GroupedAggregateData::InitializeGroupbyGroups(vector<unique_ptr<Expression>> groups) {
+	// Add all the expressions of the group by clause
+	for (auto &expr : groups) {
+		group_types.push_back(expr->return_type);
+	}
+	this->groups = std::move(groups);
+}
+
+void GroupedAggregateData::SetGroupingFunctions(vector<unsafe_vector<idx_t>> &functions) {
+	grouping_functions.reserve(functions.size());
+	for (idx_t i = 0; i < functions.size(); i++) {
+		grouping_functions.push_back(std::move(functions[i]));
+	}
+}
+
+} // namespace duckdb
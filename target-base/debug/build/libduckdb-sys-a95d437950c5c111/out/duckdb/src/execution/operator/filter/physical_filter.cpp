@@ -0,0 +1,64 @@
+#include "duckdb/execution/operator/filter/physical_filter.hpp"
+#include "duckdb/execution/expression_executor.hpp"
+#include "duckdb/planner/expression/bound_conjunction_expression.hpp"
+#include "duckdb/parallel/thread_context.hpp"
+namespace duckdb {
+
+PhysicalFilter::PhysicalFilter(PhysicalPlan &physical_plan, vector<LogicalType> types,
+                               vector<unique_ptr<Expression>> select_list, idx_t estimated_cardinality)
+    : CachingPhysicalOperator(physical_plan, PhysicalOperatorType::FILTER, std::move(types), estimated_cardinality) {
+
+	D_ASSERT(!select_list.empty());
+	if (select_list.size() == 1) {
+		expression = std::move(select_list[0]);
+		return;
+	}
+
+	// Create a conjunction from the select list.
+	auto conjunction = make_uniq<BoundConjunctionExpression>(ExpressionType::CONJUNCTION_AND);
+	for (auto &expr : select_list) {
+		conjunction->children.push_back(std::move(expr));
+	}
+	expression = std::move(conjunction);
+}
+
+class FilterState : public CachingOperatorState {
+public:
+	explicit FilterState(ExecutionContext &context, Expression &expr)
+	    : executor(context.client, expr), sel(STANDARD_VECTOR_SIZE) {
+	}
+
+	ExpressionExecutor executor;
+	SelectionVector sel;
+
+public:
+	void Finalize(const PhysicalOperator &op, ExecutionContext &context) override {
+		context.thread.profiler.Flush(op);
+	}
+};
+
+unique_ptr<OperatorState> PhysicalFilter::GetOperatorState(ExecutionContext &context) const {
+	return make_uniq<FilterState>(context, *expression);
+}
+
+OperatorResultType PhysicalFilter::ExecuteInternal(ExecutionContext &context, DataChunk &input, DataChunk &chunk,
+                                                   GlobalOperatorState &gstate, OperatorState &state_p) const {
+	auto &state = state_p.Cast<FilterState>();
+	idx_t result_count = state.executor.SelectExpression(input, state.sel);
+	if (result_count == input.size()) {
+		// nothing was filtered: skip adding any selection vectors
+		chunk.Reference(input);
+	} else {
+		chunk.Slice(input, state.sel, result_count);
+	}
+	return OperatorResultType::NEED_MORE_INPUT;
+}
+
+InsertionOrderPreservingMap<string> PhysicalFilter::ParamsToString() const {
+	InsertionOrderPreservingMap<string> result;
+	result["__expression__"] = expression->GetName();
+	SetEstimatedCardinality(result, estimated_cardinality);
+	return result;
+}
+
+} // namespace duckdb
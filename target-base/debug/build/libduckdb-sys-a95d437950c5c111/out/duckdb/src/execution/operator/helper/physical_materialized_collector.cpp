@@ -0,0 +1,69 @@
+#include "duckdb/execution/operator/helper/physical_materialized_collector.hpp"
+
+#include "duckdb/main/materialized_query_result.hpp"
+#include "duckdb/main/client_context.hpp"
+
+namespace duckdb {
+
+PhysicalMaterializedCollector::PhysicalMaterializedCollector(PhysicalPlan &physical_plan, PreparedStatementData &data,
+                                                             bool parallel)
+    : PhysicalResultCollector(physical_plan, data), parallel(parallel) {
+}
+
+SinkResultType PhysicalMaterializedCollector::Sink(ExecutionContext &context, DataChunk &chunk,
+                                                   OperatorSinkInput &input) const {
+	auto &lstate = input.local_state.Cast<MaterializedCollectorLocalState>();
+	lstate.collection->Append(lstate.append_state, chunk);
+	return SinkResultType::NEED_MORE_INPUT;
+}
+
+SinkCombineResultType PhysicalMaterializedCollector::Combine(ExecutionContext &context,
+                                                             OperatorSinkCombineInput &input) const {
+	auto &gstate = input.global_state.Cast<MaterializedCollectorGlobalState>();
+	auto &lstate = input.local_state.Cast<MaterializedCollectorLocalState>();
+	if (lstate.collection->Count() == 0) {
+		return SinkCombineResultType::FINISHED;
+	}
+
+	lock_guard<mutex> l(gstate.glock);
+	if (!gstate.collection) {
+		gstate.collection = std::move(lstate.collection);
+	} else {
+		gstate.collection->Combine(*lstate.collection);
+	}
+
+	return SinkCombineResultType::FINISHED;
+}
+
+unique_ptr<GlobalSinkState> PhysicalMaterializedCollector::GetGlobalSinkState(ClientContext &context) const {
+	auto state = make_uniq<MaterializedCollectorGlobalState>();
+	state->context = context.shared_from_this();
+	return std::move(state);
+}
+
+unique_ptr<LocalSinkState> PhysicalMaterializedCollector::GetLocalSinkState(ExecutionContext &context) const {
+	auto state = make_uniq<MaterializedCollectorLocalState>();
+	state->collection = make_uniq<ColumnDataCollection>(Allocator::DefaultAllocator(), types);
+	state->collection->InitializeAppend(state->append_state);
+	return std::move(state);
+}
+
+unique_ptr<QueryResult> PhysicalMaterializedCollector::GetResult(GlobalSinkState &state) {
+	auto &gstate = state.Cast<MaterializedCollectorGlobalState>();
+	if (!gstate.collection) {
+		gstate.collection = make_uniq<ColumnDataCollection>(Allocator::DefaultAllocator(), types);
+	}
+	auto result = make_uniq<MaterializedQueryResult>(statement_type, properties, names, std::move(gstate.collection),
+	                                                 gstate.context->GetClientProperties());
+	return std::move(result);
+}
+
+bool PhysicalMaterializedCollector::ParallelSink() const {
+	return parallel;
+}
+
+bool PhysicalMaterializedCollector::SinkOrderDependent() const {
+	return true;
+}
+
+} // namespace duckdb
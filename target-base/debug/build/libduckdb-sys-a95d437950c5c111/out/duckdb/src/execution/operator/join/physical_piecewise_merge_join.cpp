@@ -0,0 +1,798 @@
+#include "duckdb/execution/operator/join/physical_piecewise_merge_join.hpp"
+
+#include "duckdb/common/fast_mem.hpp"
+#include "duckdb/common/operator/comparison_operators.hpp"
+#include "duckdb/common/row_operations/row_operations.hpp"
+#include "duckdb/common/sort/comparators.hpp"
+#include "duckdb/common/sort/sort.hpp"
+#include "duckdb/common/vector_operations/vector_operations.hpp"
+#include "duckdb/execution/expression_executor.hpp"
+#include "duckdb/execution/operator/join/outer_join_marker.hpp"
+#include "duckdb/main/client_context.hpp"
+#include "duckdb/parallel/event.hpp"
+#include "duckdb/parallel/thread_context.hpp"
+
+namespace duckdb {
+
+PhysicalPiecewiseMergeJoin::PhysicalPiecewiseMergeJoin(PhysicalPlan &physical_plan, LogicalComparisonJoin &op,
+                                                       PhysicalOperator &left, PhysicalOperator &right,
+                                                       vector<JoinCondition> cond, JoinType join_type,
+                                                       idx_t estimated_cardinality,
+                                                       unique_ptr<JoinFilterPushdownInfo> pushdown_info_p)
+    : PhysicalRangeJoin(physical_plan, op, PhysicalOperatorType::PIECEWISE_MERGE_JOIN, left, right, std::move(cond),
+                        join_type, estimated_cardinality, std::move(pushdown_info_p)) {
+
+	for (auto &join_cond : conditions) {
+		D_ASSERT(join_cond.left->return_type == join_cond.right->return_type);
+		join_key_types.push_back(join_cond.left->return_type);
+
+		// Convert the conditions to sort orders
+		auto left_expr = join_cond.left->Copy();
+		auto right_expr = join_cond.right->Copy();
+		switch (join_cond.comparison) {
+		case ExpressionType::COMPARE_LESSTHAN:
+		case ExpressionType::COMPARE_LESSTHANOREQUALTO:
+			lhs_orders.emplace_back(OrderType::ASCENDING, OrderByNullType::NULLS_LAST, std::move(left_expr));
+			rhs_orders.emplace_back(OrderType::ASCENDING, OrderByNullType::NULLS_LAST, std::move(right_expr));
+			break;
+		case ExpressionType::COMPARE_GREATERTHAN:
+		case ExpressionType::COMPARE_GREATERTHANOREQUALTO:
+			lhs_orders.emplace_back(OrderType::DESCENDING, OrderByNullType::NULLS_LAST, std::move(left_expr));
+			rhs_orders.emplace_back(OrderType::DESCENDING, OrderByNullType::NULLS_LAST, std::move(right_expr));
+			break;
+		case ExpressionType::COMPARE_NOTEQUAL:
+		case ExpressionType::COMPARE_DISTINCT_FROM:
+			// Allowed in multi-predicate joins, but can't be first/sort.
+			D_ASSERT(!lhs_orders.empty());
+			lhs_orders.emplace_back(OrderType::INVALID, OrderByNullType::NULLS_LAST, std::move(left_expr));
+			rhs_orders.emplace_back(OrderType::INVALID, OrderByNullType::NULLS_LAST, std::move(right_expr));
+			break;
+
+		default:
+			// COMPARE EQUAL not supported with merge join
+			throw NotImplementedException("Unimplemented join type for merge join");
+		}
+	}
+}
+
+//===--------------------------------------------------------------------===//
+// Sink
+//===--------------------------------------------------------------------===//
+class MergeJoinLocalState;
+
+class MergeJoinGlobalState : public GlobalSinkState {
+public:
+	using GlobalSortedTable = PhysicalRangeJoin::GlobalSortedTable;
+
+public:
+	MergeJoinGlobalState(ClientContext &context, const PhysicalPiecewiseMergeJoin &op) {
+		RowLayout rhs_layout;
+		rhs_layout.Initialize(op.children[1].get().GetTypes());
+		vector<BoundOrderByNode> rhs_order;
+		rhs_order.emplace_back(op.rhs_orders[0].Copy());
+		table = make_uniq<GlobalSortedTable>(context, rhs_order, rhs_layout, op);
+		if (op.filter_pushdown) {
+			skip_filter_pushdown = op.filter_pushdown->probe_info.empty();
+			global_filter_state = op.filter_pushdown->GetGlobalState(context, op);
+		}
+	}
+
+	inline idx_t Count() const {
+		return table->count;
+	}
+
+	void Sink(DataChunk &input, MergeJoinLocalState &lstate);
+
+	unique_ptr<GlobalSortedTable> table;
+	//! Should we not bother pushing down filters?
+	bool skip_filter_pushdown = false;
+	//! The global filter states to push down (if any)
+	unique_ptr<JoinFilterGlobalState> global_filter_state;
+};
+
+class MergeJoinLocalState : public LocalSinkState {
+public:
+	explicit MergeJoinLocalState(ClientContext &context, const PhysicalRangeJoin &op, MergeJoinGlobalState &gstate,
+	                             const idx_t child)
+	    : table(context, op, child) {
+		if (op.filter_pushdown) {
+			local_filter_state = op.filter_pushdown->GetLocalState(*gstate.global_filter_state);
+		}
+	}
+
+	//! The local sort state
+	PhysicalRangeJoin::LocalSortedTable table;
+	//! Local state for accumulating filter statistics
+	unique_ptr<JoinFilterLocalState> local_filter_state;
+};
+
+unique_ptr<GlobalSinkState> PhysicalPiecewiseMergeJoin::GetGlobalSinkState(ClientContext &context) const {
+	return make_uniq<MergeJoinGlobalState>(context, *this);
+}
+
+unique_ptr<LocalSinkState> PhysicalPiecewiseMergeJoin::GetLocalSinkState(ExecutionContext &context) const {
+	// We only sink the RHS
+	auto &gstate = sink_state->Cast<MergeJoinGlobalState>();
+	return make_uniq<MergeJoinLocalState>(context.client, *this, gstate, 1U);
+}
+
+void MergeJoinGlobalState::Sink(DataChunk &input, MergeJoinLocalState &lstate) {
+	auto &global_sort_state = table->global_sort_state;
+	auto &local_sort_state = lstate.table.local_sort_state;
+
+	// Sink the data into the local sort state
+	lstate.table.Sink(input, global_sort_state);
+
+	// When sorting data reaches a certain size, we sort it
+	if (local_sort_state.SizeInBytes() >= table->memory_per_thread) {
+		local_sort_state.Sort(global_sort_state, true);
+	}
+}
+
+SinkResultType PhysicalPiecewiseMergeJoin::Sink(ExecutionContext &context, DataChunk &chunk,
+                                                OperatorSinkInput &input) const {
+	auto &gstate = input.global_state.Cast<MergeJoinGlobalState>();
+	auto &lstate = input.local_state.Cast<MergeJoinLocalState>();
+
+	gstate.Sink(chunk, lstate);
+
+	if (filter_pushdown && !gstate.skip_filter_pushdown) {
+		filter_pushdown->Sink(lstate.table.keys, *lstate.local_filter_state);
+	}
+
+	return SinkResultType::NEED_MORE_INPUT;
+}
+
+SinkCombineResultType PhysicalPiecewiseMergeJoin::Combine(ExecutionContext &context,
+                                                          OperatorSinkCombineInput &input) const {
+	auto &gstate = input.global_state.Cast<MergeJoinGlobalState>();
+	auto &lstate = input.local_state.Cast<MergeJoinLocalState>();
+	gstate.table->Combine(lstate.table);
+	auto &client_profiler = QueryProfiler::Get(context.client);
+
+	context.thread.profiler.Flush(*this);
+	client_profiler.Flush(context.thread.profiler);
+	if (filter_pushdown && !gstate.skip_filter_pushdown) {
+		filter_pushdown->Combine(*gstate.global_filter_state, *lstate.local_filter_state);
+	}
+
+	return SinkCombineResultType::FINISHED;
+}
+
+//===--------------------------------------------------------------------===//
+// Finalize
+//===--------------------------------------------------------------------===//
+SinkFinalizeType PhysicalPiecewiseMergeJoin::Finalize(Pipeline &pipeline, Event &event, ClientContext &context,
+                                                      OperatorSinkFinalizeInput &input) const {
+	auto &gstate = input.global_state.Cast<MergeJoinGlobalState>();
+	if (filter_pushdown && !gstate.skip_filter_pushdown) {
+		(void)filter_pushdown->Finalize(context, nullptr, *gstate.global_filter_state, *this);
+	}
+	auto &global_sort_state = gstate.table->global_sort_state;
+
+	if (PropagatesBuildSide(join_type)) {
+		// for FULL/RIGHT OUTER JOIN, initialize found_match to false for every tuple
+		gstate.table->IntializeMatches();
+	}
+	if (global_sort_state.sorted_blocks.empty() && EmptyResultIfRHSIsEmpty()) {
+		// Empty input!
+		return SinkFinalizeType::NO_OUTPUT_POSSIBLE;
+	}
+
+	// Sort the current input child
+	gstate.table->Finalize(pipeline, event);
+
+	return SinkFinalizeType::READY;
+}
+
+//===--------------------------------------------------------------------===//
+// Operator
+//===--------------------------------------------------------------------===//
+class PiecewiseMergeJoinState : public CachingOperatorState {
+public:
+	using LocalSortedTable = PhysicalRangeJoin::LocalSortedTable;
+
+	PiecewiseMergeJoinState(ClientContext &context, const PhysicalPiecewiseMergeJoin &op, bool force_external)
+	    : context(context), allocator(Allocator::Get(context)), op(op),
+	      buffer_manager(BufferManager::GetBufferManager(context)), force_external(force_external),
+	      left_outer(IsLeftOuterJoin(op.join_type)), left_position(0), first_fetch(true), finished(true),
+	      right_position(0), right_chunk_index(0), rhs_executor(context) {
+		vector<LogicalType> condition_types;
+		for (auto &order : op.lhs_orders) {
+			condition_types.push_back(order.expression->return_type);
+		}
+		left_outer.Initialize(STANDARD_VECTOR_SIZE);
+		lhs_layout.Initialize(op.children[0].get().GetTypes());
+		lhs_payload.Initialize(allocator, op.children[0].get().GetTypes());
+
+		lhs_order.emplace_back(op.lhs_orders[0].Copy());
+
+		// Set up shared data for multiple predicates
+		sel.Initialize(STANDARD_VECTOR_SIZE);
+		condition_types.clear();
+		for (auto &order : op.rhs_orders) {
+			rhs_executor.AddExpression(*order.expression);
+			condition_types.push_back(order.expression->return_type);
+		}
+		rhs_keys.Initialize(allocator, condition_types);
+	}
+
+	ClientContext &context;
+	Allocator &allocator;
+	const PhysicalPiecewiseMergeJoin &op;
+	BufferManager &buffer_manager;
+	bool force_external;
+
+	// Block sorting
+	DataChunk lhs_payload;
+	OuterJoinMarker left_outer;
+	vector<BoundOrderByNode> lhs_order;
+	RowLayout lhs_layout;
+	unique_ptr<LocalSortedTable> lhs_local_table;
+	unique_ptr<GlobalSortState> lhs_global_state;
+	unique_ptr<PayloadScanner> scanner;
+
+	// Simple scans
+	idx_t left_position;
+
+	// Complex scans
+	bool first_fetch;
+	bool finished;
+	idx_t right_position;
+	idx_t right_chunk_index;
+	idx_t right_base;
+	idx_t prev_left_index;
+
+	// Secondary predicate shared data
+	SelectionVector sel;
+	DataChunk rhs_keys;
+	DataChunk rhs_input;
+	ExpressionExecutor rhs_executor;
+	vector<BufferHandle> payload_heap_handles;
+
+public:
+	void ResolveJoinKeys(DataChunk &input) {
+		// sort by join key
+		lhs_global_state = make_uniq<GlobalSortState>(context, lhs_order, lhs_layout);
+		lhs_local_table = make_uniq<LocalSortedTable>(context, op, 0U);
+		lhs_local_table->Sink(input, *lhs_global_state);
+
+		// Set external (can be forced with the PRAGMA)
+		lhs_global_state->external = force_external;
+		lhs_global_state->AddLocalState(lhs_local_table->local_sort_state);
+		lhs_global_state->PrepareMergePhase();
+		while (lhs_global_state->sorted_blocks.size() > 1) {
+			MergeSorter merge_sorter(*lhs_global_state, buffer_manager);
+			merge_sorter.PerformInMergeRound();
+			lhs_global_state->CompleteMergeRound();
+		}
+
+		// Scan the sorted payload
+		D_ASSERT(lhs_global_state->sorted_blocks.size() == 1);
+
+		scanner = make_uniq<PayloadScanner>(*lhs_global_state->sorted_blocks[0]->payload_data, *lhs_global_state);
+		lhs_payload.Reset();
+		scanner->Scan(lhs_payload);
+
+		// Recompute the sorted keys from the sorted input
+		lhs_local_table->keys.Reset();
+		lhs_local_table->executor.Execute(lhs_payload, lhs_local_table->keys);
+	}
+
+	void Finalize(const PhysicalOperator &op, ExecutionContext &context) override {
+		if (lhs_local_table) {
+			context.thread.profiler.Flush(op);
+		}
+	}
+};
+
+unique_ptr<OperatorState> PhysicalPiecewiseMergeJoin::GetOperatorState(ExecutionContext &context) const {
+	bool force_external = ClientConfig::GetConfig(context.client).force_external;
+	return make_uniq<PiecewiseMergeJoinState>(context.client, *this, force_external);
+}
+
+static inline idx_t SortedBlockNotNull(const idx_t base, const idx_t count, const idx_t not_null) {
+	return MinValue(base + count, MaxValue(base, not_null)) - base;
+}
+
+static int MergeJoinComparisonValue(ExpressionType comparison) {
+	switch (comparison) {
+	case ExpressionType::COMPARE_LESSTHAN:
+	case ExpressionType::COMPARE_GREATERTHAN:
+		return -1;
+	case ExpressionType::COMPARE_LESSTHANOREQUALTO:
+	case ExpressionType::COMPARE_GREATERTHANOREQUALTO:
+		return 0;
+	default:
+		throw InternalException("Unimplemented comparison type for merge join!");
+	}
+}
+
+struct BlockMergeInfo {
+	GlobalSortState &state;
+	//! The block being scanned
+	const idx_t block_idx;
+	//! The number of not-NULL values in the block (they are at the end)
+	const idx_t not_null;
+	//! The current offset in the block
+	idx_t &entry_idx;
+	SelectionVector result;
+
+	BlockMergeInfo(GlobalSortState &state, idx_t block_idx, idx_t &entry_idx, idx_t not_null)
+	    : state(state), block_idx(block_idx), not_null(not_null), entry_idx(entry_idx), result(STANDARD_VECTOR_SIZE) {
+	}
+};
+
+static void MergeJoinPinSortingBlock(SBScanState &scan, const idx_t block_idx) {
+	scan.SetIndices(block_idx, 0);
+	scan.PinRadix(block_idx);
+
+	auto &sd = *scan.sb->blob_sorting_data;
+	if (block_idx < sd.data_blocks.size()) {
+		scan.PinData(sd);
+	}
+}
+
+static data_ptr_t MergeJoinRadixPtr(SBScanState &scan, const idx_t entry_idx) {
+	scan.entry_idx = entry_idx;
+	return scan.RadixPtr();
+}
+
+static idx_t MergeJoinSimpleBlocks(PiecewiseMergeJoinState &lstate, MergeJoinGlobalState &rstate, bool *found_match,
+                                   const ExpressionType comparison) {
+	const auto cmp = MergeJoinComparisonValue(comparison);
+
+	// The sort parameters should all be the same
+	auto &lsort = *lstate.lhs_global_state;
+	auto &rsort = rstate.table->global_sort_state;
+	D_ASSERT(lsort.sort_layout.all_constant == rsort.sort_layout.all_constant);
+	const auto all_constant = lsort.sort_layout.all_constant;
+	D_ASSERT(lsort.external == rsort.external);
+	const auto external = lsort.external;
+
+	// There should only be one sorted block if they have been sorted
+	D_ASSERT(lsort.sorted_blocks.size() == 1);
+	SBScanState lread(lsort.buffer_manager, lsort);
+	lread.sb = lsort.sorted_blocks[0].get();
+
+	const idx_t l_block_idx = 0;
+	idx_t l_entry_idx = 0;
+	const auto lhs_not_null = lstate.lhs_local_table->count - lstate.lhs_local_table->has_null;
+	MergeJoinPinSortingBlock(lread, l_block_idx);
+	auto l_ptr = MergeJoinRadixPtr(lread, l_entry_idx);
+
+	D_ASSERT(rsort.sorted_blocks.size() == 1);
+	SBScanState rread(rsort.buffer_manager, rsort);
+	rread.sb = rsort.sorted_blocks[0].get();
+
+	const auto cmp_size = lsort.sort_layout.comparison_size;
+	const auto entry_size = lsort.sort_layout.entry_size;
+
+	idx_t right_base = 0;
+	for (idx_t r_block_idx = 0; r_block_idx < rread.sb->radix_sorting_data.size(); r_block_idx++) {
+		// we only care about the BIGGEST value in each of the RHS data blocks
+		// because we want to figure out if the LHS values are less than [or equal] to ANY value
+		// get the biggest value from the RHS chunk
+		MergeJoinPinSortingBlock(rread, r_block_idx);
+
+		auto &rblock = *rread.sb->radix_sorting_data[r_block_idx];
+		const auto r_not_null =
+		    SortedBlockNotNull(right_base, rblock.count, rstate.table->count - rstate.table->has_null);
+		if (r_not_null == 0) {
+			break;
+		}
+		const auto r_entry_idx = r_not_null - 1;
+		right_base += rblock.count;
+
+		auto r_ptr = MergeJoinRadixPtr(rread, r_entry_idx);
+
+		// now we start from the current lpos value and check if we found a new value that is [<= OR <] the max RHS
+		// value
+		while (true) {
+			int comp_res;
+			if (all_constant) {
+				comp_res = FastMemcmp(l_ptr, r_ptr, cmp_size);
+			} else {
+				lread.entry_idx = l_entry_idx;
+				rread.entry_idx = r_entry_idx;
+				comp_res = Comparators::CompareTuple(lread, rread, l_ptr, r_ptr, lsort.sort_layout, external);
+			}
+
+			if (comp_res <= cmp) {
+				// found a match for lpos, set it in the found_match vector
+				found_match[l_entry_idx] = true;
+				l_entry_idx++;
+				l_ptr += entry_size;
+				if (l_entry_idx >= lhs_not_null) {
+					// early out: we exhausted the entire LHS and they all match
+					return 0;
+				}
+			} else {
+				// we found no match: any subsequent value from the LHS we scan now will be bigger and thus also not
+				// match move to the next RHS chunk
+				break;
+			}
+		}
+	}
+	return 0;
+}
+
+void PhysicalPiecewiseMergeJoin::ResolveSimpleJoin(ExecutionContext &context, DataChunk &input, DataChunk &chunk,
+                                                   OperatorState &state_p) const {
+	auto &state = state_p.Cast<PiecewiseMergeJoinState>();
+	auto &gstate = sink_state->Cast<MergeJoinGlobalState>();
+
+	state.ResolveJoinKeys(input);
+	auto &lhs_table = *state.lhs_local_table;
+
+	// perform the actual join
+	bool found_match[STANDARD_VECTOR_SIZE];
+	memset(found_match, 0, sizeof(found_match));
+	MergeJoinSimpleBlocks(state, gstate, found_match, conditions[0].comparison);
+
+	// use the sorted payload
+	const auto lhs_not_null = lhs_table.count - lhs_table.has_null;
+	auto &payload = state.lhs_payload;
+
+	// now construct the result based on the join result
+	switch (join_type) {
+	case JoinType::MARK: {
+		// The only part of the join keys that is actually used is the validity mask.
+		// Since the payload is sorted, we can just set the tail end of the validity masks to invalid.
+		for (auto &key : lhs_table.keys.data) {
+			key.Flatten(lhs_table.keys.size());
+			auto &mask = FlatVector::Validity(key);
+			if (mask.AllValid()) {
+				continue;
+			}
+			mask.SetAllValid(lhs_not_null);
+			for (idx_t i = lhs_not_null; i < lhs_table.count; ++i) {
+				mask.SetInvalid(i);
+			}
+		}
+		// So we make a set of keys that have the validity mask set for the
+		PhysicalJoin::ConstructMarkJoinResult(lhs_table.keys, payload, chunk, found_match, gstate.table->has_null);
+		break;
+	}
+	case JoinType::SEMI:
+		PhysicalJoin::ConstructSemiJoinResult(payload, chunk, found_match);
+		break;
+	case JoinType::ANTI:
+		PhysicalJoin::ConstructAntiJoinResult(payload, chunk, found_match);
+		break;
+	default:
+		throw NotImplementedException("Unimplemented join type for merge join");
+	}
+}
+
+static idx_t MergeJoinComplexBlocks(BlockMergeInfo &l, BlockMergeInfo &r, const ExpressionType comparison,
+                                    idx_t &prev_left_index) {
+	const auto cmp = MergeJoinComparisonValue(comparison);
+
+	// The sort parameters should all be the same
+	D_ASSERT(l.state.sort_layout.all_constant == r.state.sort_layout.all_constant);
+	const auto all_constant = r.state.sort_layout.all_constant;
+	D_ASSERT(l.state.external == r.state.external);
+	const auto external = l.state.external;
+
+	// There should only be one sorted block if they have been sorted
+	D_ASSERT(l.state.sorted_blocks.size() == 1);
+	SBScanState lread(l.state.buffer_manager, l.state);
+	lread.sb = l.state.sorted_blocks[0].get();
+	D_ASSERT(lread.sb->radix_sorting_data.size() == 1);
+	MergeJoinPinSortingBlock(lread, l.block_idx);
+	auto l_start = MergeJoinRadixPtr(lread, 0);
+	auto l_ptr = MergeJoinRadixPtr(lread, l.entry_idx);
+
+	D_ASSERT(r.state.sorted_blocks.size() == 1);
+	SBScanState rread(r.state.buffer_manager, r.state);
+	rread.sb = r.state.sorted_blocks[0].get();
+
+	if (r.entry_idx >= r.not_null) {
+		return 0;
+	}
+
+	MergeJoinPinSortingBlock(rread, r.block_idx);
+	auto r_ptr = MergeJoinRadixPtr(rread, r.entry_idx);
+
+	const auto cmp_size = l.state.sort_layout.comparison_size;
+	const auto entry_size = l.state.sort_layout.entry_size;
+
+	idx_t result_count = 0;
+	while (true) {
+		if (l.entry_idx < prev_left_index) {
+			// left side smaller: found match
+			l.result.set_index(result_count, sel_t(l.entry_idx));
+			r.result.set_index(result_count, sel_t(r.entry_idx));
+			result_count++;
+			// move left side forward
+			l.entry_idx++;
+			l_ptr += entry_size;
+			if (result_count == STANDARD_VECTOR_SIZE) {
+				// out of space!
+				break;
+			}
+			continue;
+		}
+		if (l.entry_idx < l.not_null) {
+			int comp_res;
+			if (all_constant) {
+				comp_res = FastMemcmp(l_ptr, r_ptr, cmp_size);
+			} else {
+				lread.entry_idx = l.entry_idx;
+				rread.entry_idx = r.entry_idx;
+				comp_res = Comparators::CompareTuple(lread, rread, l_ptr, r_ptr, l.state.sort_layout, external);
+			}
+			if (comp_res <= cmp) {
+				// left side smaller: found match
+				l.result.set_index(result_count, sel_t(l.entry_idx));
+				r.result.set_index(result_count, sel_t(r.entry_idx));
+				result_count++;
+				// move left side forward
+				l.entry_idx++;
+				l_ptr += entry_size;
+				if (result_count == STANDARD_VECTOR_SIZE) {
+					// out of space!
+					break;
+				}
+				continue;
+			}
+		}
+
+		prev_left_index = l.entry_idx;
+		// right side smaller or equal, or left side exhausted: move
+		// right pointer forward reset left side to start
+		r.entry_idx++;
+		if (r.entry_idx >= r.not_null) {
+			break;
+		}
+		r_ptr += entry_size;
+
+		l_ptr = l_start;
+		l.entry_idx = 0;
+	}
+
+	return result_count;
+}
+
+OperatorResultType PhysicalPiecewiseMergeJoin::ResolveComplexJoin(ExecutionContext &context, DataChunk &input,
+                                                                  DataChunk &chunk, OperatorState &state_p) const {
+	auto &state = state_p.Cast<PiecewiseMergeJoinState>();
+	auto &gstate = sink_state->Cast<MergeJoinGlobalState>();
+	auto &rsorted = *gstate.table->global_sort_state.sorted_blocks[0];
+	const auto left_cols = input.ColumnCount();
+	const auto tail_cols = conditions.size() - 1;
+
+	state.payload_heap_handles.clear();
+	do {
+		if (state.first_fetch) {
+			state.ResolveJoinKeys(input);
+
+			state.right_chunk_index = 0;
+			state.right_base = 0;
+			state.left_position = 0;
+			state.prev_left_index = 0;
+			state.right_position = 0;
+			state.first_fetch = false;
+			state.finished = false;
+		}
+		if (state.finished) {
+			if (state.left_outer.Enabled()) {
+				// left join: before we move to the next chunk, see if we need to output any vectors that didn't
+				// have a match found
+				state.left_outer.ConstructLeftJoinResult(state.lhs_payload, chunk);
+				state.left_outer.Reset();
+			}
+			state.first_fetch = true;
+			state.finished = false;
+			return OperatorResultType::NEED_MORE_INPUT;
+		}
+
+		auto &lhs_table = *state.lhs_local_table;
+		const auto lhs_not_null = lhs_table.count - lhs_table.has_null;
+		BlockMergeInfo left_info(*state.lhs_global_state, 0, state.left_position, lhs_not_null);
+
+		const auto &rblock = *rsorted.radix_sorting_data[state.right_chunk_index];
+		const auto rhs_not_null =
+		    SortedBlockNotNull(state.right_base, rblock.count, gstate.table->count - gstate.table->has_null);
+		BlockMergeInfo right_info(gstate.table->global_sort_state, state.right_chunk_index, state.right_position,
+		                          rhs_not_null);
+
+		idx_t result_count =
+		    MergeJoinComplexBlocks(left_info, right_info, conditions[0].comparison, state.prev_left_index);
+		if (result_count == 0) {
+			// exhausted this chunk on the right side
+			// move to the next right chunk
+			state.left_position = 0;
+			state.right_position = 0;
+			state.right_base += rsorted.radix_sorting_data[state.right_chunk_index]->count;
+			state.right_chunk_index++;
+			if (state.right_chunk_index >= rsorted.radix_sorting_data.size()) {
+				state.finished = true;
+			}
+		} else {
+			// found matches: extract them
+			chunk.Reset();
+			for (idx_t c = 0; c < state.lhs_payload.ColumnCount(); ++c) {
+				chunk.data[c].Slice(state.lhs_payload.data[c], left_info.result, result_count);
+			}
+			state.payload_heap_handles.push_back(SliceSortedPayload(chunk, right_info.state, right_info.block_idx,
+			                                                        right_info.result, result_count, left_cols));
+			chunk.SetCardinality(result_count);
+
+			auto sel = FlatVector::IncrementalSelectionVector();
+			if (tail_cols) {
+				// If there are more expressions to compute,
+				// split the result chunk into the left and right halves
+				// so we can compute the values for comparison.
+				chunk.Split(state.rhs_input, left_cols);
+				state.rhs_executor.SetChunk(state.rhs_input);
+				state.rhs_keys.Reset();
+
+				auto tail_count = result_count;
+				for (size_t cmp_idx = 1; cmp_idx < conditions.size(); ++cmp_idx) {
+					Vector left(lhs_table.keys.data[cmp_idx]);
+					left.Slice(left_info.result, result_count);
+
+					auto &right = state.rhs_keys.data[cmp_idx];
+					state.rhs_executor.ExecuteExpression(cmp_idx, right);
+
+					if (tail_count < result_count) {
+						left.Slice(*sel, tail_count);
+						right.Slice(*sel, tail_count);
+					}
+					tail_count =
+					    SelectJoinTail(conditions[cmp_idx].comparison, left, right, sel, tail_count, &state.sel);
+					sel = &state.sel;
+				}
+				chunk.Fuse(state.rhs_input);
+
+				if (tail_count < result_count) {
+					result_count = tail_count;
+					if (result_count == 0) {
+						// Need to reset here otherwise we may use the non-flat chunk when constructing LEFT/OUTER
+						chunk.Reset();
+					} else {
+						chunk.Slice(*sel, result_count);
+					}
+				}
+			}
+
+			// found matches: mark the found matches if required
+			if (state.left_outer.Enabled()) {
+				for (idx_t i = 0; i < result_count; i++) {
+					state.left_outer.SetMatch(left_info.result[sel->get_index(i)]);
+				}
+			}
+			if (gstate.table->found_match) {
+				//	Absolute position of the block + start position inside that block
+				for (idx_t i = 0; i < result_count; i++) {
+					gstate.table->found_match[state.right_base + right_info.result[sel->get_index(i)]] = true;
+				}
+			}
+			chunk.SetCardinality(result_count);
+			chunk.Verify();
+		}
+	} while (chunk.size() == 0);
+	return OperatorResultType::HAVE_MORE_OUTPUT;
+}
+
+OperatorResultType PhysicalPiecewiseMergeJoin::ExecuteInternal(ExecutionContext &context, DataChunk &input,
+                                                               DataChunk &chunk, GlobalOperatorState &gstate_p,
+                                                               OperatorState &state) const {
+	auto &gstate = sink_state->Cast<MergeJoinGlobalState>();
+
+	if (gstate.Count() == 0) {
+		// empty RHS
+		if (!EmptyResultIfRHSIsEmpty()) {
+			ConstructEmptyJoinResult(join_type, gstate.table->has_null, input, chunk);
+			return OperatorResultType::NEED_MORE_INPUT;
+		} else {
+			return OperatorResultType::FINISHED;
+		}
+	}
+
+	input.Verify();
+	switch (join_type) {
+	case JoinType::SEMI:
+	case JoinType::ANTI:
+	case JoinType::MARK:
+		// simple joins can have max STANDARD_VECTOR_SIZE matches per chunk
+		ResolveSimpleJoin(context, input, chunk, state);
+		return OperatorResultType::NEED_MORE_INPUT;
+	case JoinType::LEFT:
+	case JoinType::INNER:
+	case JoinType::RIGHT:
+	case JoinType::OUTER:
+		return ResolveComplexJoin(context, input, chunk, state);
+	default:
+		throw NotImplementedException("Unimplemented type for piecewise merge loop join!");
+	}
+}
+
+//===--------------------------------------------------------------------===//
+// Source
+//===--------------------------------------------------------------------===//
+class PiecewiseJoinScanState : public GlobalSourceState {
+public:
+	explicit PiecewiseJoinScanState(const PhysicalPiecewiseMergeJoin &op) : op(op), right_outer_position(0) {
+	}
+
+	mutex lock;
+	const PhysicalPiecewiseMergeJoin &op;
+	unique_ptr<PayloadScanner> scanner;
+	idx_t right_outer_position;
+
+public:
+	idx_t MaxThreads() override {
+		auto &sink = op.sink_state->Cast<MergeJoinGlobalState>();
+		return sink.Count() / (STANDARD_VECTOR_SIZE * idx_t(10));
+	}
+};
+
+unique_ptr<GlobalSourceState> PhysicalPiecewiseMergeJoin::GetGlobalSourceState(ClientContext &context) const {
+	return make_uniq<PiecewiseJoinScanState>(*this);
+}
+
+SourceResultType PhysicalPiecewiseMergeJoin::GetData(ExecutionContext &context, DataChunk &result,
+                                                     OperatorSourceInput &input) const {
+	D_ASSERT(PropagatesBuildSide(join_type));
+	// check if we need to scan any unmatched tuples from the RHS for the full/right outer join
+	auto &sink = sink_state->Cast<MergeJoinGlobalState>();
+	auto &state = input.global_state.Cast<PiecewiseJoinScanState>();
+
+	lock_guard<mutex> l(state.lock);
+	if (!state.scanner) {
+		// Initialize scanner (if not yet initialized)
+		auto &sort_state = sink.table->global_sort_state;
+		if (sort_state.sorted_blocks.empty()) {
+			return SourceResultType::FINISHED;
+		}
+		state.scanner = make_uniq<PayloadScanner>(*sort_state.sorted_blocks[0]->payload_data, sort_state);
+	}
+
+	// if the LHS is exhausted in a FULL/RIGHT OUTER JOIN, we scan the found_match for any chunks we
+	// still need to output
+	const auto found_match = sink.table->found_match.get();
+
+	DataChunk rhs_chunk;
+	rhs_chunk.Initialize(Allocator::Get(context.client), sink.table->global_sort_state.payload_layout.GetTypes());
+	SelectionVector rsel(STANDARD_VECTOR_SIZE);
+	for (;;) {
+		// Read the next sorted chunk
+		state.scanner->Scan(rhs_chunk);
+
+		const auto count = rhs_chunk.size();
+		if (count == 0) {
+			return result.size() == 0 ? SourceResultType::FINISHED : SourceResultType::HAVE_MORE_OUTPUT;
+		}
+
+		idx_t result_count = 0;
+		// figure out which tuples didn't find a match in the RHS
+		for (idx_t i = 0; i < count; i++) {
+			if (!found_match[state.right_outer_position + i]) {
+				rsel.set_index(result_count++, i);
+			}
+		}
+		state.right_outer_position += count;
+
+		if (result_count > 0) {
+			// if there were any tuples that didn't find a match, output them
+			const idx_t left_column_count = children[0].get().GetTypes().size();
+			for (idx_t col_idx = 0; col_idx < left_column_count; ++col_idx) {
+				result.data[col_idx].SetVectorType(VectorType::CONSTANT_VECTOR);
+				ConstantVector::SetNull(result.data[col_idx], true);
+			}
+			const idx_t right_column_count = children[1].get().GetTypes().size();
+			for (idx_t col_idx = 0; col_idx < right_column_count; ++col_idx) {
+				result.data[left_column_count + col_idx].Slice(rhs_chunk.data[col_idx], rsel, result_count);
+			}
+			result.SetCardinality(result_count);
+			break;
+		}
+	}
+
+	return result.size() == 0 ? SourceResultType::FINISHED : SourceResultType::HAVE_MORE_OUTPUT;
+}
+
+} // namespace duckdb
@@ -0,0 +1,17 @@
+#include "duckdb/execution/operator/schema/physical_alter.hpp"
+#include "duckdb/parser/parsed_data/alter_table_info.hpp"
+#include "duckdb/main/database_manager.hpp"
+#include "duckdb/catalog/catalog.hpp"
+
+namespace duckdb {
+
+//===--------------------------------------------------------------------===//
+// Source
+//===--------------------------------------------------------------------===//
+SourceResultType PhysicalAlter::GetData(ExecutionContext &context, DataChunk &chunk, OperatorSourceInput &input) const {
+	auto &catalog = Catalog::GetCatalog(context.client, info->catalog);
+	catalog.Alter(context.client, *info);
+	return SourceResultType::FINISHED;
+}
+
+} // namespace duckdb
@@ -0,0 +1,19 @@
+#include "duckdb/execution/operator/schema/physical_create_function.hpp"
+
+#include "duckdb/catalog/catalog.hpp"
+#include "duckdb/catalog/catalog_entry/scalar_macro_catalog_entry.hpp"
+
+namespace duckdb {
+
+//===--------------------------------------------------------------------===//
+// Source
+//===--------------------------------------------------------------------===//
+SourceResultType PhysicalCreateFunction::GetData(ExecutionContext &context, DataChunk &chunk,
+                                                 OperatorSourceInput &input) const {
+	auto &catalog = Catalog::GetCatalog(context.client, info->catalog);
+	catalog.CreateFunction(context.client, *info);
+
+	return SourceResultType::FINISHED;
+}
+
+} // namespace duckdb
@@ -0,0 +1,17 @@
+#include "duckdb/execution/operator/schema/physical_create_sequence.hpp"
+#include "duckdb/catalog/catalog.hpp"
+
+namespace duckdb {
+
+//===--------------------------------------------------------------------===//
+// Source
+//===--------------------------------------------------------------------===//
+SourceResultType PhysicalCreateSequence::GetData(ExecutionContext &context, DataChunk &chunk,
+                                                 OperatorSourceInput &input) const {
+	auto &catalog = Catalog::GetCatalog(context.client, info->catalog);
+	catalog.CreateSequence(context.client, *info);
+
+	return SourceResultType::FINISHED;
+}
+
+} // namespace duckdb
@@ -0,0 +1,14 @@
+#include "duckdb/execution/operator/scan/physical_column_data_scan.hpp"
+#include "duckdb/execution/physical_plan_generator.hpp"
+#include "duckdb/planner/operator/logical_column_data_get.hpp"
+
+namespace duckdb {
+
+PhysicalOperator &PhysicalPlanGenerator::CreatePlan(LogicalColumnDataGet &op) {
+	D_ASSERT(op.children.empty());
+	D_ASSERT(op.collection);
+	return Make<PhysicalColumnDataScan>(op.types, PhysicalOperatorType::COLUMN_DATA_SCAN, op.estimated_cardinality,
+	                                    std::move(op.collection));
+}
+
+} // namespace duckdb
@@ -0,0 +1,12 @@
+#include "duckdb/execution/operator/scan/physical_dummy_scan.hpp"
+#include "duckdb/execution/physical_plan_generator.hpp"
+#include "duckdb/planner/operator/logical_dummy_scan.hpp"
+
+namespace duckdb {
+
+PhysicalOperator &PhysicalPlanGenerator::CreatePlan(LogicalDummyScan &op) {
+	D_ASSERT(op.children.empty());
+	return Make<PhysicalDummyScan>(op.types, op.estimated_cardinality);
+}
+
+} // namespace duckdb
@@ -0,0 +1,12 @@
+#include "duckdb/execution/operator/scan/physical_empty_result.hpp"
+#include "duckdb/execution/physical_plan_generator.hpp"
+#include "duckdb/planner/operator/logical_empty_result.hpp"
+
+namespace duckdb {
+
+PhysicalOperator &PhysicalPlanGenerator::CreatePlan(LogicalEmptyResult &op) {
+	D_ASSERT(op.children.size() == 0);
+	return Make<PhysicalEmptyResult>(op.types, op.estimated_cardinality);
+}
+
+} // namespace duckdb
@@ -0,0 +1,8 @@
+#include "duckdb/function/aggregate_function.hpp"
+
+namespace duckdb {
+
+AggregateFunctionInfo::~AggregateFunctionInfo() {
+}
+
+} // namespace duckdb
@@ -0,0 +1,10 @@
+#include "duckdb/function/pragma/pragma_functions.hpp"
+
+namespace duckdb {
+
+void BuiltinFunctions::RegisterPragmaFunctions() {
+	Register<PragmaQueries>();
+	Register<PragmaFunctions>();
+}
+
+} // namespace duckdb
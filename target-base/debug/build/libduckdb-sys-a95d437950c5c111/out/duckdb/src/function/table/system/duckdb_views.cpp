@@ -0,0 +1,126 @@
+#include "duckdb/function/table/system_functions.hpp"
+
+#include "duckdb/catalog/catalog.hpp"
+#include "duckdb/catalog/catalog_entry/schema_catalog_entry.hpp"
+#include "duckdb/catalog/catalog_entry/view_catalog_entry.hpp"
+#include "duckdb/common/exception.hpp"
+#include "duckdb/main/client_context.hpp"
+#include "duckdb/main/client_data.hpp"
+
+namespace duckdb {
+
+struct DuckDBViewsData : public GlobalTableFunctionState {
+	DuckDBViewsData() : offset(0) {
+	}
+
+	vector<reference<CatalogEntry>> entries;
+	idx_t offset;
+};
+
+static unique_ptr<FunctionData> DuckDBViewsBind(ClientContext &context, TableFunctionBindInput &input,
+                                                vector<LogicalType> &return_types, vector<string> &names) {
+	names.emplace_back("database_name");
+	return_types.emplace_back(LogicalType::VARCHAR);
+
+	names.emplace_back("database_oid");
+	return_types.emplace_back(LogicalType::BIGINT);
+
+	names.emplace_back("schema_name");
+	return_types.emplace_back(LogicalType::VARCHAR);
+
+	names.emplace_back("schema_oid");
+	return_types.emplace_back(LogicalType::BIGINT);
+
+	names.emplace_back("view_name");
+	return_types.emplace_back(LogicalType::VARCHAR);
+
+	names.emplace_back("view_oid");
+	return_types.emplace_back(LogicalType::BIGINT);
+
+	names.emplace_back("comment");
+	return_types.emplace_back(LogicalType::VARCHAR);
+
+	names.emplace_back("tags");
+	return_types.emplace_back(LogicalType::MAP(LogicalType::VARCHAR, LogicalType::VARCHAR));
+
+	names.emplace_back("internal");
+	return_types.emplace_back(LogicalType::BOOLEAN);
+
+	names.emplace_back("temporary");
+	return_types.emplace_back(LogicalType::BOOLEAN);
+
+	names.emplace_back("column_count");
+	return_types.emplace_back(LogicalType::BIGINT);
+
+	names.emplace_back("sql");
+	return_types.emplace_back(LogicalType::VARCHAR);
+
+	return nullptr;
+}
+
+unique_ptr<GlobalTableFunctionState> DuckDBViewsInit(ClientContext &context, TableFunctionInitInput &input) {
+	auto result = make_uniq<DuckDBViewsData>();
+
+	// scan all the schemas for tables and collect them and collect them
+	auto schemas = Catalog::GetAllSchemas(context);
+	for (auto &schema : schemas) {
+		schema.get().Scan(context, CatalogType::VIEW_ENTRY,
+		                  [&](CatalogEntry &entry) { result->entries.push_back(entry); });
+	};
+	return std::move(result);
+}
+
+void DuckDBViewsFunction(ClientContext &context, TableFunctionInput &data_p, DataChunk &output) {
+	auto &data = data_p.global_state->Cast<DuckDBViewsData>();
+	if (data.offset >= data.entries.size()) {
+		// finished returning values
+		return;
+	}
+	// start returning values
+	// either fill up the chunk or return all the remaining columns
+	idx_t count = 0;
+	while (data.offset < data.entries.size() && count < STANDARD_VECTOR_SIZE) {
+		auto &entry = data.entries[data.offset++].get();
+
+		if (entry.type != CatalogType::VIEW_ENTRY) {
+			continue;
+		}
+		auto &view = entry.Cast<ViewCatalogEntry>();
+
+		// return values:
+		idx_t col = 0;
+		// database_name, VARCHAR
+		output.SetValue(col++, count, view.catalog.GetName());
+		// database_oid, BIGINT
+		output.SetValue(col++, count, Value::BIGINT(NumericCast<int64_t>(view.catalog.GetOid())));
+		// schema_name, LogicalType::VARCHAR
+		output.SetValue(col++, count, Value(view.schema.name));
+		// schema_oid, LogicalType::BIGINT
+		output.SetValue(col++, count, Value::BIGINT(NumericCast<int64_t>(view.schema.oid)));
+		// view_name, LogicalType::VARCHAR
+		output.SetValue(col++, count, Value(view.name));
+		// view_oid, LogicalType::BIGINT
+		output.SetValue(col++, count, Value::BIGINT(NumericCast<int64_t>(view.oid)));
+		// comment, LogicalType::VARCHARs
+		output.SetValue(col++, count, Value(view.comment));
+		// tags, LogicalType::MAP(LogicalType::VARCHAR, LogicalType::VARCHAR)
+		output.SetValue(col++, count, Value::MAP(view.tags));
+		// internal, LogicalType::BOOLEAN
+		output.SetValue(col++, count, Value::BOOLEAN(view.internal));
+		// temporary, LogicalType::BOOLEAN
+		output.SetValue(col++, count, Value::BOOLEAN(view.temporary));
+		// column_count, LogicalType::BIGINT
+		output.SetValue(col++, count, Value::BIGINT(NumericCast<int64_t>(view.types.size())));
+		// sql, LogicalType::VARCHAR
+		output.SetValue(col++, count, Value(view.ToSQL()));
+
+		count++;
+	}
+	output.SetCardinality(count);
+}
+
+void DuckDBViewsFun::RegisterFunction(BuiltinFunctions &set) {
+	set.AddFunction(TableFunction("duckdb_views", {}, DuckDBViewsFunction, DuckDBViewsBind, DuckDBViewsInit));
+}
+
+} // namespace duckdb
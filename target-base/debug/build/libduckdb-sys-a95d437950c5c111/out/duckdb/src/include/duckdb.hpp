@@ -0,0 +1,14 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/main/connection.hpp"
+#include "duckdb/main/database.hpp"
+#include "duckdb/main/query_result.hpp"
+#include "duckdb/main/appender.hpp"
@@ -0,0 +1,29 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/catalog/catalog_entry/macro_catalog_entry.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/catalog/catalog_set.hpp"
+#include "duckdb/catalog/standard_entry.hpp"
+#include "duckdb/parser/parsed_data/create_macro_info.hpp"
+#include "duckdb/catalog/catalog_entry/macro_catalog_entry.hpp"
+
+namespace duckdb {
+
+//! A macro function in the catalog
+class ScalarMacroCatalogEntry : public MacroCatalogEntry {
+public:
+	static constexpr const CatalogType Type = CatalogType::MACRO_ENTRY;
+	static constexpr const char *Name = "macro function";
+
+public:
+	ScalarMacroCatalogEntry(Catalog &catalog, SchemaCatalogEntry &schema, CreateMacroInfo &info);
+
+	unique_ptr<CatalogEntry> Copy(ClientContext &context) const override;
+};
+} // namespace duckdb
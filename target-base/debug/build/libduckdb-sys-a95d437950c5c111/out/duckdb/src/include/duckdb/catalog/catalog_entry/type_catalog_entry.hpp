@@ -0,0 +1,38 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/catalog/catalog_entry/type_catalog_entry.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/catalog/standard_entry.hpp"
+#include "duckdb/common/mutex.hpp"
+#include "duckdb/parser/parsed_data/create_type_info.hpp"
+#include "duckdb/catalog/dependency_list.hpp"
+
+namespace duckdb {
+
+//! A type catalog entry
+class TypeCatalogEntry : public StandardEntry {
+public:
+	static constexpr const CatalogType Type = CatalogType::TYPE_ENTRY;
+	static constexpr const char *Name = "type";
+
+public:
+	//! Create a TypeCatalogEntry and initialize storage for it
+	TypeCatalogEntry(Catalog &catalog, SchemaCatalogEntry &schema, CreateTypeInfo &info);
+
+	LogicalType user_type;
+
+	bind_logical_type_function_t bind_function;
+
+public:
+	unique_ptr<CreateInfo> GetInfo() const override;
+	unique_ptr<CatalogEntry> Copy(ClientContext &context) const override;
+
+	string ToSQL() const override;
+};
+} // namespace duckdb
@@ -0,0 +1,25 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/catalog/default/default_schemas.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/catalog/default/default_generator.hpp"
+
+namespace duckdb {
+
+class DefaultSchemaGenerator : public DefaultGenerator {
+public:
+	explicit DefaultSchemaGenerator(Catalog &catalog);
+
+public:
+	unique_ptr<CatalogEntry> CreateDefaultEntry(CatalogTransaction transaction, const string &entry_name) override;
+	vector<string> GetDefaultEntries() override;
+	static bool IsDefaultSchema(const string &input_schema);
+};
+
+} // namespace duckdb
@@ -0,0 +1,12 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/algorithm.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include <algorithm>
+#include <cmath>
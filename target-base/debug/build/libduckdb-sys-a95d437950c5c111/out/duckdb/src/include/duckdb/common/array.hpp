@@ -0,0 +1,15 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/array.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include <array>
+
+namespace duckdb {
+using std::array;
+}
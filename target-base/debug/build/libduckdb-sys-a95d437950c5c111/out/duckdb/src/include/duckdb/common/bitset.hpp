@@ -0,0 +1,15 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/bitset.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include <bitset>
+
+namespace duckdb {
+using std::bitset;
+}
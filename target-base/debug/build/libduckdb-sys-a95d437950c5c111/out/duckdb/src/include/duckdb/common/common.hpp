@@ -0,0 +1,13 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/common.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/common/constants.hpp"
+#include "duckdb/common/helper.hpp"
+#include "duckdb/common/vector.hpp"
@@ -0,0 +1,15 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/deque.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include <deque>
+
+namespace duckdb {
+using std::deque;
+}
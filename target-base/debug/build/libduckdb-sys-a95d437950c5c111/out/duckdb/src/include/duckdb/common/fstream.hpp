@@ -0,0 +1,21 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/fstream.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include <fstream>
+#include <iosfwd>
+
+namespace duckdb {
+using std::endl;
+using std::fstream;
+using std::ifstream;
+using std::ios;
+using std::ios_base;
+using std::ofstream;
+} // namespace duckdb
@@ -0,0 +1,15 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/list.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include <list>
+
+namespace duckdb {
+using std::list;
+}
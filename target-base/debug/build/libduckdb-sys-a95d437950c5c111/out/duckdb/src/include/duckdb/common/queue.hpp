@@ -0,0 +1,15 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/queue.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include <queue>
+
+namespace duckdb {
+using std::queue;
+} // namespace duckdb
@@ -0,0 +1,55 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/sorting/sorted_run.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/common/types/row/tuple_data_states.hpp"
+
+namespace duckdb {
+
+class BufferManager;
+class DataChunk;
+class TupleDataCollection;
+class TupleDataLayout;
+
+class SortedRun {
+public:
+	SortedRun(ClientContext &context, shared_ptr<TupleDataLayout> key_layout,
+	          shared_ptr<TupleDataLayout> payload_layout, bool is_index_sort);
+	unique_ptr<SortedRun> CreateRunForMaterialization() const;
+	~SortedRun();
+
+public:
+	//! Appends data to key/data collections
+	void Sink(DataChunk &key, DataChunk &payload);
+	//! Sorts the data (physically reorder data if external)
+	void Finalize(bool external);
+	//! Destroy data between these tuple indices
+	void DestroyData(idx_t tuple_idx_begin, idx_t tuple_idx_end);
+	//! Number of tuples
+	idx_t Count() const;
+	//! Size of this sorted run
+	idx_t SizeInBytes() const;
+
+public:
+	ClientContext &context;
+
+	//! Key and payload collections (and associated append states)
+	unique_ptr<TupleDataCollection> key_data;
+	unique_ptr<TupleDataCollection> payload_data;
+	TupleDataAppendState key_append_state;
+	TupleDataAppendState payload_append_state;
+
+	//! Whether this is an (approximate) index sort
+	const bool is_index_sort;
+
+	//! Whether this run has been finalized
+	bool finalized;
+};
+
+} // namespace duckdb
@@ -0,0 +1,13 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/to_string.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+namespace duckdb {
+using std::to_string;
+}
@@ -0,0 +1,27 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/common/vector_size.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/common/types.hpp"
+
+namespace duckdb {
+
+//! The default standard vector size
+#define DEFAULT_STANDARD_VECTOR_SIZE 2048U
+
+//! The vector size used in the execution engine
+#ifndef STANDARD_VECTOR_SIZE
+#define STANDARD_VECTOR_SIZE DEFAULT_STANDARD_VECTOR_SIZE
+#endif
+
+#if (STANDARD_VECTOR_SIZE & (STANDARD_VECTOR_SIZE - 1) != 0)
+#error The vector size must be a power of two
+#endif
+
+} // namespace duckdb
@@ -0,0 +1,426 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/execution/operator/csv_scanner/base_scanner.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/execution/operator/csv_scanner/csv_buffer_manager.hpp"
+#include "duckdb/execution/operator/csv_scanner/scanner_boundary.hpp"
+#include "duckdb/execution/operator/csv_scanner/csv_state_machine.hpp"
+#include "duckdb/execution/operator/csv_scanner/csv_error.hpp"
+#include "duckdb/common/helper.hpp"
+
+namespace duckdb {
+
+class CSVFileScan;
+
+//! Class that keeps track of line starts, used for line size verification
+class LinePosition {
+public:
+	LinePosition() {
+	}
+	LinePosition(idx_t buffer_idx_p, idx_t buffer_pos_p, idx_t buffer_size_p)
+	    : buffer_pos(buffer_pos_p), buffer_size(buffer_size_p), buffer_idx(buffer_idx_p) {
+	}
+
+	idx_t operator-(const LinePosition &other) const {
+		if (other.buffer_idx == buffer_idx) {
+			return buffer_pos - other.buffer_pos;
+		}
+		return other.buffer_size - other.buffer_pos + buffer_pos;
+	}
+
+	bool operator==(const LinePosition &other) const {
+		return buffer_pos == other.buffer_pos && buffer_idx == other.buffer_idx && buffer_size == other.buffer_size;
+	}
+
+	idx_t GetGlobalPosition(idx_t requested_buffer_size, bool first_char_nl = false) const {
+		return requested_buffer_size * buffer_idx + buffer_pos + first_char_nl;
+	}
+	idx_t buffer_pos = 0;
+	idx_t buffer_size = 0;
+	idx_t buffer_idx = 0;
+};
+
+class ScannerResult {
+public:
+	ScannerResult(CSVStates &states, CSVStateMachine &state_machine, idx_t result_size);
+
+	static inline void SetQuoted(ScannerResult &result, idx_t quoted_position) {
+		if (!result.quoted) {
+			result.quoted_position = quoted_position;
+		}
+		result.quoted = true;
+		result.unquoted = true;
+	}
+
+	static inline void SetUnquoted(ScannerResult &result) {
+		if (result.states.states[0] == CSVState::UNQUOTED && result.states.states[1] == CSVState::UNQUOTED &&
+		    result.state_machine.dialect_options.state_machine_options.escape != '\0') {
+			// This means we touched an unescaped quote, we must go through the remove escape code to remove it.
+			result.escaped = true;
+		}
+		result.quoted = true;
+	}
+
+	static inline void SetEscaped(ScannerResult &result) {
+		result.escaped = true;
+	}
+	static inline void SetComment(ScannerResult &result, idx_t buffer_pos) {
+		result.comment = true;
+	}
+	static inline bool UnsetComment(ScannerResult &result, idx_t buffer_pos) {
+		result.comment = false;
+		return false;
+	}
+	static inline bool IsCommentSet(const ScannerResult &result) {
+		return result.comment == true;
+	}
+
+	inline bool IsStateCurrent(CSVState state) const {
+		return states.states[1] == state;
+	}
+
+	//! Variable to keep information regarding quoted and escaped values
+	bool quoted = false;
+	//! If the current quoted value is unquoted
+	bool unquoted = false;
+	//! If the current value has been escaped
+	bool escaped = false;
+	//! Variable to keep track if we are in a comment row. Hence, won't add it
+	bool comment = false;
+	idx_t quoted_position = 0;
+
+	LinePosition last_position;
+
+	//! Size of the result
+	const idx_t result_size;
+
+	CSVStateMachine &state_machine;
+	bool cur_line_starts_as_comment = false;
+
+	void Print() const {
+		state_machine.Print();
+	}
+
+protected:
+	CSVStates &states;
+};
+
+//! This is the base of our CSV scanners.
+//! Scanners differ on what they are used for, and consequently have different performance benefits.
+class BaseScanner {
+public:
+	explicit BaseScanner(shared_ptr<CSVBufferManager> buffer_manager, shared_ptr<CSVStateMachine> state_machine,
+	                     shared_ptr<CSVErrorHandler> error_handler, bool sniffing = false,
+	                     shared_ptr<CSVFileScan> csv_file_scan = nullptr, const CSVIterator &iterator = {});
+
+	virtual ~BaseScanner() = default;
+
+	void Print() const;
+
+	//! Returns true if the scanner is finished
+	bool FinishedFile() const;
+
+	//! Parses data into an output_chunk
+	virtual ScannerResult &ParseChunk();
+
+	//! Returns the result from the last Parse call. Shouts at you if you call it wrong
+	virtual ScannerResult &GetResult();
+
+	CSVIterator &GetIterator();
+
+	void SetIterator(const CSVIterator &it);
+
+	idx_t GetBoundaryIndex() const {
+		return iterator.GetBoundaryIdx();
+	}
+
+	idx_t GetLinesRead() const {
+		return lines_read;
+	}
+
+	CSVPosition GetIteratorPosition() const {
+		return iterator.pos;
+	}
+
+	CSVStateMachine &GetStateMachine() const;
+
+	//! Removes thousands separator
+	static string RemoveSeparator(const char *value_ptr, const idx_t size, char thousands_separator);
+
+	shared_ptr<CSVFileScan> csv_file_scan;
+
+	//! If this scanner is being used for sniffing
+	bool sniffing = false;
+	//! The guy that handles errors
+	shared_ptr<CSVErrorHandler> error_handler;
+
+	//! Shared pointer to the state machine, this is used across multiple scanners
+	shared_ptr<CSVStateMachine> state_machine;
+
+	//! States
+	CSVStates states;
+
+	//! If the scanner ever entered a quoted state
+	bool ever_quoted = false;
+
+	//! If the scanner ever entered an escaped state.
+	bool ever_escaped = false;
+
+	//! If the scanner ever used advantage of the non-strict mode.
+	bool used_unstrictness = false;
+
+	//! Shared pointer to the buffer_manager, this is shared across multiple scanners
+	shared_ptr<CSVBufferManager> buffer_manager;
+
+	//! Skips Notes and/or parts of the data, starting from the top.
+	//! notes are dirty lines on top of the file, before the actual data
+	static CSVIterator SkipCSVRows(shared_ptr<CSVBufferManager> buffer_manager,
+	                               const shared_ptr<CSVStateMachine> &state_machine, idx_t rows_to_skip);
+
+	inline static bool ContainsZeroByte(uint64_t v) {
+		return (v - UINT64_C(0x0101010101010101)) & ~(v)&UINT64_C(0x8080808080808080);
+	}
+
+protected:
+	//! Boundaries of this scanner
+	CSVIterator iterator;
+
+	//! Unique pointer to the buffer_handle, this is unique per scanner, since it also contains the necessary counters
+	//! To offload buffers to disk if necessary
+	shared_ptr<CSVBufferHandle> cur_buffer_handle;
+
+	//! Hold the current buffer ptr
+	char *buffer_handle_ptr = nullptr;
+
+	//! If this scanner has been initialized
+	bool initialized = false;
+	//! How many lines were read by this scanner
+	idx_t lines_read = 0;
+	idx_t bytes_read = 0;
+	//! Internal Functions used to perform the parsing
+	//! Initializes the scanner
+	virtual void Initialize();
+
+	//! Process one chunk
+	template <class T>
+	void Process(T &result) {
+		idx_t to_pos;
+		const bool has_escaped_value = state_machine->dialect_options.state_machine_options.escape != '\0';
+		const bool only_rn_newlines =
+		    state_machine->state_machine_options.strict_mode.GetValue() &&
+		    state_machine->state_machine_options.strict_mode.IsSetByUser() &&
+		    state_machine->state_machine_options.new_line.GetValue() == NewLineIdentifier::CARRY_ON &&
+		    state_machine->state_machine_options.new_line.IsSetByUser();
+		const idx_t start_pos = iterator.pos.buffer_pos;
+		if (iterator.IsBoundarySet()) {
+			to_pos = iterator.GetEndPos();
+			if (to_pos > cur_buffer_handle->actual_size) {
+				to_pos = cur_buffer_handle->actual_size;
+			}
+		} else {
+			to_pos = cur_buffer_handle->actual_size;
+		}
+		while (iterator.pos.buffer_pos < to_pos) {
+			state_machine->Transition(states, buffer_handle_ptr[iterator.pos.buffer_pos]);
+			switch (states.states[1]) {
+			case CSVState::INVALID:
+				T::InvalidState(result);
+				iterator.pos.buffer_pos++;
+				bytes_read = iterator.pos.buffer_pos - start_pos;
+				return;
+			case CSVState::RECORD_SEPARATOR:
+				if (states.states[0] == CSVState::RECORD_SEPARATOR || states.states[0] == CSVState::NOT_SET) {
+					if (T::EmptyLine(result, iterator.pos.buffer_pos)) {
+						iterator.pos.buffer_pos++;
+						bytes_read = iterator.pos.buffer_pos - start_pos;
+						lines_read++;
+						return;
+					}
+					lines_read++;
+
+				} else if (states.states[0] != CSVState::CARRIAGE_RETURN) {
+					if (T::IsCommentSet(result)) {
+						if (T::UnsetComment(result, iterator.pos.buffer_pos)) {
+							iterator.pos.buffer_pos++;
+							bytes_read = iterator.pos.buffer_pos - start_pos;
+							lines_read++;
+							return;
+						}
+					} else {
+						if (T::AddRow(result, iterator.pos.buffer_pos)) {
+							iterator.pos.buffer_pos++;
+							bytes_read = iterator.pos.buffer_pos - start_pos;
+							lines_read++;
+							return;
+						}
+					}
+					lines_read++;
+				}
+				iterator.pos.buffer_pos++;
+				break;
+			case CSVState::CARRIAGE_RETURN:
+				if (states.states[0] == CSVState::RECORD_SEPARATOR || states.states[0] == CSVState::NOT_SET) {
+					if (T::EmptyLine(result, iterator.pos.buffer_pos)) {
+						iterator.pos.buffer_pos++;
+						bytes_read = iterator.pos.buffer_pos - start_pos;
+						lines_read++;
+						return;
+					}
+				} else if (states.states[0] != CSVState::CARRIAGE_RETURN) {
+					if (T::IsCommentSet(result)) {
+						if (T::UnsetComment(result, iterator.pos.buffer_pos)) {
+							iterator.pos.buffer_pos++;
+							bytes_read = iterator.pos.buffer_pos - start_pos;
+							lines_read++;
+							return;
+						}
+					} else if (!only_rn_newlines) {
+						if (T::AddRow(result, iterator.pos.buffer_pos)) {
+							iterator.pos.buffer_pos++;
+							bytes_read = iterator.pos.buffer_pos - start_pos;
+							lines_read++;
+							return;
+						}
+					}
+				}
+				iterator.pos.buffer_pos++;
+				lines_read++;
+				break;
+			case CSVState::DELIMITER:
+				T::AddValue(result, iterator.pos.buffer_pos);
+				iterator.pos.buffer_pos++;
+				break;
+			case CSVState::QUOTED: {
+				if ((states.states[0] == CSVState::UNQUOTED || states.states[0] == CSVState::MAYBE_QUOTED) &&
+				    has_escaped_value) {
+					ever_escaped = true;
+					T::SetEscaped(result);
+				}
+				if ((states.states[0] == CSVState::ESCAPE || states.states[0] == CSVState::ESCAPED_RETURN ||
+				     states.states[0] == CSVState::UNQUOTED_ESCAPE) &&
+				    (buffer_handle_ptr[iterator.pos.buffer_pos] ==
+				         state_machine->dialect_options.state_machine_options.quote.GetValue() ||
+				     !state_machine->dialect_options.state_machine_options.strict_mode.GetValue())) {
+					// We only set the ever escaped variable if this is either a quote char OR strict mode is off
+					ever_escaped = true;
+					if (states.states[0] == CSVState::UNQUOTED_ESCAPE) {
+						used_unstrictness = true;
+					}
+				}
+				ever_quoted = true;
+				T::SetQuoted(result, iterator.pos.buffer_pos);
+				iterator.pos.buffer_pos++;
+				while (iterator.pos.buffer_pos + 8 < to_pos) {
+					const uint64_t value =
+					    Load<uint64_t>(reinterpret_cast<const_data_ptr_t>(&buffer_handle_ptr[iterator.pos.buffer_pos]));
+					if (ContainsZeroByte((value ^ state_machine->transition_array.quote) &
+					                     (value ^ state_machine->transition_array.escape))) {
+						break;
+					}
+					iterator.pos.buffer_pos += 8;
+				}
+
+				while (state_machine->transition_array
+				           .skip_quoted[static_cast<uint8_t>(buffer_handle_ptr[iterator.pos.buffer_pos])] &&
+				       iterator.pos.buffer_pos < to_pos - 1) {
+					iterator.pos.buffer_pos++;
+				}
+			} break;
+			case CSVState::UNQUOTED: {
+				if (states.states[0] == CSVState::MAYBE_QUOTED) {
+					ever_escaped = true;
+					T::SetEscaped(result);
+				}
+				T::SetUnquoted(result);
+				iterator.pos.buffer_pos++;
+				break;
+			}
+			case CSVState::ESCAPE:
+			case CSVState::ESCAPED_RETURN:
+				T::SetEscaped(result);
+				iterator.pos.buffer_pos++;
+				break;
+			case CSVState::UNQUOTED_ESCAPE:
+				T::SetEscaped(result);
+				iterator.pos.buffer_pos++;
+				used_unstrictness = true;
+				break;
+			case CSVState::STANDARD: {
+				iterator.pos.buffer_pos++;
+				while (iterator.pos.buffer_pos + 8 < to_pos) {
+					uint64_t value =
+					    Load<uint64_t>(reinterpret_cast<const_data_ptr_t>(&buffer_handle_ptr[iterator.pos.buffer_pos]));
+					if (ContainsZeroByte((value ^ state_machine->transition_array.delimiter) &
+					                     (value ^ state_machine->transition_array.new_line) &
+					                     (value ^ state_machine->transition_array.carriage_return) &
+					                     (value ^ state_machine->transition_array.escape) &
+					                     (value ^ state_machine->transition_array.comment))) {
+						break;
+					}
+					iterator.pos.buffer_pos += 8;
+				}
+				while (state_machine->transition_array
+				           .skip_standard[static_cast<uint8_t>(buffer_handle_ptr[iterator.pos.buffer_pos])] &&
+				       iterator.pos.buffer_pos < to_pos - 1) {
+					iterator.pos.buffer_pos++;
+				}
+				break;
+			}
+			case CSVState::QUOTED_NEW_LINE:
+				T::QuotedNewLine(result);
+				iterator.pos.buffer_pos++;
+				break;
+			case CSVState::COMMENT: {
+				T::SetComment(result, iterator.pos.buffer_pos);
+				iterator.pos.buffer_pos++;
+				while (iterator.pos.buffer_pos + 8 < to_pos) {
+					const uint64_t value =
+					    Load<uint64_t>(reinterpret_cast<const_data_ptr_t>(&buffer_handle_ptr[iterator.pos.buffer_pos]));
+					if (ContainsZeroByte((value ^ state_machine->transition_array.new_line) &
+					                     (value ^ state_machine->transition_array.carriage_return))) {
+						break;
+					}
+					iterator.pos.buffer_pos += 8;
+				}
+				while (state_machine->transition_array
+				           .skip_comment[static_cast<uint8_t>(buffer_handle_ptr[iterator.pos.buffer_pos])] &&
+				       iterator.pos.buffer_pos < to_pos - 1) {
+					iterator.pos.buffer_pos++;
+				}
+				break;
+			}
+			default:
+				iterator.pos.buffer_pos++;
+				break;
+			}
+		}
+		bytes_read = iterator.pos.buffer_pos - start_pos;
+	}
+
+	//! Finalizes the process of the chunk
+	virtual void FinalizeChunkProcess();
+
+	//! Internal function for parse chunk
+	template <class T>
+	void ParseChunkInternal(T &result) {
+		if (iterator.done) {
+			return;
+		}
+		if (!initialized) {
+			Initialize();
+			initialized = true;
+		}
+		if (!iterator.done && cur_buffer_handle) {
+			Process(result);
+		}
+		FinalizeChunkProcess();
+	}
+};
+
+} // namespace duckdb
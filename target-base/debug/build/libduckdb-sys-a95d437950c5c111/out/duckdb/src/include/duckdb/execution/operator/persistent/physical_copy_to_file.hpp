@@ -0,0 +1,105 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/execution/operator/persistent/physical_copy_to_file.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/common/enums/copy_overwrite_mode.hpp"
+#include "duckdb/common/file_system.hpp"
+#include "duckdb/common/filename_pattern.hpp"
+#include "duckdb/execution/physical_operator.hpp"
+#include "duckdb/function/copy_function.hpp"
+#include "duckdb/parser/parsed_data/copy_info.hpp"
+#include "duckdb/storage/storage_lock.hpp"
+
+namespace duckdb {
+
+struct CopyToFileInfo {
+	explicit CopyToFileInfo(string file_path_p) : file_path(std::move(file_path_p)) {
+	}
+
+	string file_path;
+	unique_ptr<CopyFunctionFileStatistics> file_stats;
+	Value partition_keys;
+};
+
+//! Copy the contents of a query into a table
+class PhysicalCopyToFile : public PhysicalOperator {
+public:
+	static constexpr const PhysicalOperatorType TYPE = PhysicalOperatorType::COPY_TO_FILE;
+
+public:
+	PhysicalCopyToFile(PhysicalPlan &physical_plan, vector<LogicalType> types, CopyFunction function,
+	                   unique_ptr<FunctionData> bind_data, idx_t estimated_cardinality);
+
+	CopyFunction function;
+	unique_ptr<FunctionData> bind_data;
+	string file_path;
+	bool use_tmp_file;
+	FilenamePattern filename_pattern;
+	string file_extension;
+	CopyOverwriteMode overwrite_mode;
+	bool parallel;
+	bool per_thread_output;
+	optional_idx file_size_bytes;
+	bool rotate;
+	CopyFunctionReturnType return_type;
+
+	bool partition_output;
+	bool write_partition_columns;
+	bool write_empty_file;
+	bool hive_file_pattern;
+	vector<idx_t> partition_columns;
+	vector<string> names;
+	vector<LogicalType> expected_types;
+
+public:
+	// Source interface
+	unique_ptr<GlobalSourceState> GetGlobalSourceState(ClientContext &context) const override;
+	SourceResultType GetData(ExecutionContext &context, DataChunk &chunk, OperatorSourceInput &input) const override;
+
+	bool IsSource() const override {
+		return true;
+	}
+
+public:
+	// Sink interface
+	SinkResultType Sink(ExecutionContext &context, DataChunk &chunk, OperatorSinkInput &input) const override;
+	SinkCombineResultType Combine(ExecutionContext &context, OperatorSinkCombineInput &input) const override;
+	SinkFinalizeType Finalize(Pipeline &pipeline, Event &event, ClientContext &context,
+	                          OperatorSinkFinalizeInput &input) const override;
+	SinkFinalizeType FinalizeInternal(ClientContext &context, GlobalSinkState &gstate) const;
+	unique_ptr<LocalSinkState> GetLocalSinkState(ExecutionContext &context) const override;
+	unique_ptr<GlobalSinkState> GetGlobalSinkState(ClientContext &context) const override;
+
+	bool IsSink() const override {
+		return true;
+	}
+
+	bool SinkOrderDependent() const override {
+		return true;
+	}
+
+	bool ParallelSink() const override {
+		return per_thread_output || partition_output || parallel;
+	}
+
+public:
+	static void MoveTmpFile(ClientContext &context, const string &tmp_file_path);
+	static string GetNonTmpFile(ClientContext &context, const string &tmp_file_path);
+
+	string GetTrimmedPath(ClientContext &context) const;
+
+	static void ReturnStatistics(DataChunk &chunk, idx_t row_idx, CopyToFileInfo &written_file_info);
+
+private:
+	unique_ptr<GlobalFunctionData> CreateFileState(ClientContext &context, GlobalSinkState &sink,
+	                                               StorageLockKey &global_lock) const;
+	void WriteRotateInternal(ExecutionContext &context, GlobalSinkState &global_state,
+	                         const std::function<void(GlobalFunctionData &)> &fun) const;
+};
+} // namespace duckdb
@@ -0,0 +1,27 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/function/scalar/operators.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/function/function_set.hpp"
+#include "duckdb/function/scalar_function.hpp"
+#include "duckdb/function/built_in_functions.hpp"
+
+namespace duckdb {
+
+struct AddFunction {
+	static ScalarFunction GetFunction(const LogicalType &type);
+	static ScalarFunction GetFunction(const LogicalType &left_type, const LogicalType &right_type);
+};
+
+struct SubtractFunction {
+	static ScalarFunction GetFunction(const LogicalType &type);
+	static ScalarFunction GetFunction(const LogicalType &left_type, const LogicalType &right_type);
+};
+
+} // namespace duckdb
@@ -0,0 +1,33 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/function/scalar/struct_utils.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/function/scalar_function.hpp"
+#include "duckdb/function/function_set.hpp"
+#include "duckdb/function/built_in_functions.hpp"
+
+namespace duckdb {
+
+struct StructExtractBindData : public FunctionData {
+	explicit StructExtractBindData(idx_t index) : index(index) {
+	}
+
+	idx_t index;
+
+public:
+	unique_ptr<FunctionData> Copy() const override {
+		return make_uniq<StructExtractBindData>(index);
+	}
+	bool Equals(const FunctionData &other_p) const override {
+		auto &other = other_p.Cast<StructExtractBindData>();
+		return index == other.index;
+	}
+};
+
+} // namespace duckdb
@@ -0,0 +1,5 @@
+#include "duckdb/function/table/read_csv.hpp"
+#include "duckdb/function/table/system_functions.hpp"
+#include "duckdb/function/table/range.hpp"
+#include "duckdb/function/table/summary.hpp"
+#include "duckdb/function/table/files.hpp"
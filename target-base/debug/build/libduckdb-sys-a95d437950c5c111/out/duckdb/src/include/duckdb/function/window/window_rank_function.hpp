@@ -0,0 +1,64 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/function/window/window_rank_function.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/function/window/window_executor.hpp"
+
+namespace duckdb {
+
+class WindowPeerExecutor : public WindowExecutor {
+public:
+	WindowPeerExecutor(BoundWindowExpression &wexpr, WindowSharedExpressions &shared);
+
+	unique_ptr<GlobalSinkState> GetGlobalState(ClientContext &context, const idx_t payload_count,
+	                                           const ValidityMask &partition_mask,
+	                                           const ValidityMask &order_mask) const override;
+	unique_ptr<LocalSinkState> GetLocalState(ExecutionContext &context, const GlobalSinkState &gstate) const override;
+
+	//! The column indices of any ORDER BY argument expressions
+	vector<column_t> arg_order_idx;
+};
+
+class WindowRankExecutor : public WindowPeerExecutor {
+public:
+	WindowRankExecutor(BoundWindowExpression &wexpr, WindowSharedExpressions &shared);
+
+protected:
+	void EvaluateInternal(ExecutionContext &context, DataChunk &eval_chunk, Vector &result, idx_t count, idx_t row_idx,
+	                      OperatorSinkInput &sink) const override;
+};
+
+class WindowDenseRankExecutor : public WindowPeerExecutor {
+public:
+	WindowDenseRankExecutor(BoundWindowExpression &wexpr, WindowSharedExpressions &shared);
+
+protected:
+	void EvaluateInternal(ExecutionContext &context, DataChunk &eval_chunk, Vector &result, idx_t count, idx_t row_idx,
+	                      OperatorSinkInput &sink) const override;
+};
+
+class WindowPercentRankExecutor : public WindowPeerExecutor {
+public:
+	WindowPercentRankExecutor(BoundWindowExpression &wexpr, WindowSharedExpressions &shared);
+
+protected:
+	void EvaluateInternal(ExecutionContext &context, DataChunk &eval_chunk, Vector &result, idx_t count, idx_t row_idx,
+	                      OperatorSinkInput &sink) const override;
+};
+
+class WindowCumeDistExecutor : public WindowPeerExecutor {
+public:
+	WindowCumeDistExecutor(BoundWindowExpression &wexpr, WindowSharedExpressions &shared);
+
+protected:
+	void EvaluateInternal(ExecutionContext &context, DataChunk &eval_chunk, Vector &result, idx_t count, idx_t row_idx,
+	                      OperatorSinkInput &sink) const override;
+};
+
+} // namespace duckdb
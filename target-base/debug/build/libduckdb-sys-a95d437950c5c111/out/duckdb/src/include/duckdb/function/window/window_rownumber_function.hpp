@@ -0,0 +1,44 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/function/window/window_rownumber_function.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/function/window/window_executor.hpp"
+
+namespace duckdb {
+
+class WindowRowNumberExecutor : public WindowExecutor {
+public:
+	WindowRowNumberExecutor(BoundWindowExpression &wexpr, WindowSharedExpressions &shared);
+
+	unique_ptr<GlobalSinkState> GetGlobalState(ClientContext &client, const idx_t payload_count,
+	                                           const ValidityMask &partition_mask,
+	                                           const ValidityMask &order_mask) const override;
+	unique_ptr<LocalSinkState> GetLocalState(ExecutionContext &context, const GlobalSinkState &gstate) const override;
+
+	//! The evaluation index of the NTILE column
+	column_t ntile_idx = DConstants::INVALID_INDEX;
+	//! The column indices of any ORDER BY argument expressions
+	vector<column_t> arg_order_idx;
+
+protected:
+	void EvaluateInternal(ExecutionContext &context, DataChunk &eval_chunk, Vector &result, idx_t count, idx_t row_idx,
+	                      OperatorSinkInput &sink) const override;
+};
+
+// NTILE is just scaled ROW_NUMBER
+class WindowNtileExecutor : public WindowRowNumberExecutor {
+public:
+	WindowNtileExecutor(BoundWindowExpression &wexpr, WindowSharedExpressions &shared);
+
+protected:
+	void EvaluateInternal(ExecutionContext &context, DataChunk &eval_chunk, Vector &result, idx_t count, idx_t row_idx,
+	                      OperatorSinkInput &sink) const override;
+};
+
+} // namespace duckdb
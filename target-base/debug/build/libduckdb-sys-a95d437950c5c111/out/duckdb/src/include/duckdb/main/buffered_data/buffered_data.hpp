@@ -0,0 +1,80 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/main/buffered_data.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/parallel/interrupt.hpp"
+#include "duckdb/common/queue.hpp"
+#include "duckdb/common/vector_size.hpp"
+#include "duckdb/common/types/data_chunk.hpp"
+#include "duckdb/common/optional_idx.hpp"
+#include "duckdb/execution/physical_operator_states.hpp"
+#include "duckdb/common/enums/pending_execution_result.hpp"
+#include "duckdb/common/enums/stream_execution_result.hpp"
+#include "duckdb/common/shared_ptr.hpp"
+
+namespace duckdb {
+
+class StreamQueryResult;
+class ClientContextLock;
+
+class BufferedData {
+protected:
+	enum class Type { SIMPLE, BATCHED };
+
+public:
+	BufferedData(Type type, ClientContext &context);
+	virtual ~BufferedData();
+
+public:
+	StreamExecutionResult ReplenishBuffer(StreamQueryResult &result, ClientContextLock &context_lock);
+	virtual StreamExecutionResult ExecuteTaskInternal(StreamQueryResult &result, ClientContextLock &context_lock) = 0;
+	virtual unique_ptr<DataChunk> Scan() = 0;
+	virtual void UnblockSinks() = 0;
+	shared_ptr<ClientContext> GetContext() {
+		return context.lock();
+	}
+	bool Closed() const {
+		if (context.expired()) {
+			return true;
+		}
+		auto c = context.lock();
+		return c == nullptr;
+	}
+	void Close() {
+		context.reset();
+	}
+
+public:
+	template <class TARGET>
+	TARGET &Cast() {
+		if (TARGET::TYPE != type) {
+			throw InternalException("Failed to cast buffered data to type - buffered data type mismatch");
+		}
+		return reinterpret_cast<TARGET &>(*this);
+	}
+
+	template <class TARGET>
+	const TARGET &Cast() const {
+		if (TARGET::TYPE != type) {
+			throw InternalException("Failed to cast buffered data to type - buffered data type mismatch");
+		}
+		return reinterpret_cast<const TARGET &>(*this);
+	}
+
+protected:
+	Type type;
+	//! This is weak to avoid a cyclical reference
+	weak_ptr<ClientContext> context;
+	//! The maximum amount of memory we should keep buffered
+	idx_t total_buffer_size;
+	//! Protect against populate/fetch race condition
+	mutex glock;
+};
+
+} // namespace duckdb
@@ -0,0 +1,52 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/main/simple_buffered_data.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/main/buffered_data/buffered_data.hpp"
+#include "duckdb/parallel/interrupt.hpp"
+#include "duckdb/common/queue.hpp"
+#include "duckdb/common/vector_size.hpp"
+#include "duckdb/common/types/data_chunk.hpp"
+
+namespace duckdb {
+
+class StreamQueryResult;
+class ClientContextLock;
+
+class SimpleBufferedData : public BufferedData {
+public:
+	static constexpr const BufferedData::Type TYPE = BufferedData::Type::SIMPLE;
+
+public:
+	explicit SimpleBufferedData(ClientContext &context);
+	~SimpleBufferedData() override;
+
+public:
+	void Append(const DataChunk &chunk);
+	void BlockSink(const InterruptState &blocked_sink);
+	bool BufferIsFull();
+	void UnblockSinks() override;
+	StreamExecutionResult ExecuteTaskInternal(StreamQueryResult &result, ClientContextLock &context_lock) override;
+	unique_ptr<DataChunk> Scan() override;
+	inline idx_t BufferSize() const {
+		return buffer_size;
+	}
+
+private:
+	//! Our handles to reschedule the blocked sink tasks
+	queue<InterruptState> blocked_sinks;
+	//! The queue of chunks
+	queue<unique_ptr<DataChunk>> buffered_chunks;
+	//! The current capacity of the buffer (tuples)
+	atomic<idx_t> buffered_count;
+	//! The amount of tuples we should buffer
+	idx_t buffer_size;
+};
+
+} // namespace duckdb
@@ -0,0 +1,60 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/main/profiling_node.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/common/common.hpp"
+#include "duckdb/common/deque.hpp"
+#include "duckdb/common/enums/profiler_format.hpp"
+#include "duckdb/common/pair.hpp"
+#include "duckdb/common/profiler.hpp"
+#include "duckdb/common/reference_map.hpp"
+#include "duckdb/common/string_util.hpp"
+#include "duckdb/common/types/data_chunk.hpp"
+#include "duckdb/common/unordered_map.hpp"
+#include "duckdb/common/winapi.hpp"
+#include "duckdb/execution/expression_executor_state.hpp"
+#include "duckdb/execution/physical_operator.hpp"
+#include "duckdb/main/profiling_info.hpp"
+
+namespace duckdb {
+
+//! Recursive tree mirroring the operator tree.
+class ProfilingNode {
+public:
+	explicit ProfilingNode() {
+	}
+	virtual ~ProfilingNode() {};
+
+private:
+	ProfilingInfo profiling_info;
+
+public:
+	idx_t depth = 0;
+	vector<unique_ptr<ProfilingNode>> children;
+
+public:
+	idx_t GetChildCount() {
+		return children.size();
+	}
+	ProfilingInfo &GetProfilingInfo() {
+		return profiling_info;
+	}
+	const ProfilingInfo &GetProfilingInfo() const {
+		return profiling_info;
+	}
+	optional_ptr<ProfilingNode> GetChild(idx_t idx) {
+		return children[idx].get();
+	}
+	optional_ptr<ProfilingNode> AddChild(unique_ptr<ProfilingNode> child) {
+		children.push_back(std::move(child));
+		return children.back().get();
+	}
+};
+
+} // namespace duckdb
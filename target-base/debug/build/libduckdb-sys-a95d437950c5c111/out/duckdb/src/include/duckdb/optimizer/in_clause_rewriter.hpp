@@ -0,0 +1,35 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/optimizer/in_clause_rewriter.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/planner/logical_operator_visitor.hpp"
+#include "duckdb/planner/logical_operator.hpp"
+#include "duckdb/common/optional_ptr.hpp"
+
+namespace duckdb {
+class ClientContext;
+class Optimizer;
+
+class InClauseRewriter : public LogicalOperatorVisitor {
+public:
+	explicit InClauseRewriter(ClientContext &context, Optimizer &optimizer) : context(context), optimizer(optimizer) {
+	}
+
+	ClientContext &context;
+	Optimizer &optimizer;
+	optional_ptr<LogicalOperator> current_op;
+	unique_ptr<LogicalOperator> root;
+
+public:
+	unique_ptr<LogicalOperator> Rewrite(unique_ptr<LogicalOperator> op);
+
+	unique_ptr<Expression> VisitReplace(BoundOperatorExpression &expr, unique_ptr<Expression> *expr_ptr) override;
+};
+
+} // namespace duckdb
@@ -0,0 +1,36 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/optimizer/join_filter_pushdown_optimizer.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/planner/logical_operator_visitor.hpp"
+#include "duckdb/planner/column_binding_map.hpp"
+
+namespace duckdb {
+class Optimizer;
+struct JoinFilterPushdownColumn;
+struct PushdownFilterTarget;
+
+//! The JoinFilterPushdownOptimizer links comparison joins to data sources to enable dynamic execution-time filter
+//! pushdown
+class JoinFilterPushdownOptimizer : public LogicalOperatorVisitor {
+public:
+	explicit JoinFilterPushdownOptimizer(Optimizer &optimizer);
+
+public:
+	void VisitOperator(LogicalOperator &op) override;
+	static void GetPushdownFilterTargets(LogicalOperator &op, vector<JoinFilterPushdownColumn> columns,
+	                                     vector<PushdownFilterTarget> &targets);
+
+private:
+	void GenerateJoinFilters(LogicalComparisonJoin &join);
+
+private:
+	Optimizer &optimizer;
+};
+} // namespace duckdb
@@ -0,0 +1,30 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/parallel/base_pipeline_event.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/parallel/event.hpp"
+#include "duckdb/parallel/pipeline.hpp"
+
+namespace duckdb {
+
+//! A BasePipelineEvent is used as the basis of any event that belongs to a specific pipeline
+class BasePipelineEvent : public Event {
+public:
+	explicit BasePipelineEvent(shared_ptr<Pipeline> pipeline);
+	explicit BasePipelineEvent(Pipeline &pipeline);
+
+	void PrintPipeline() override {
+		pipeline->Print();
+	}
+
+	//! The pipeline that this event belongs to
+	shared_ptr<Pipeline> pipeline;
+};
+
+} // namespace duckdb
@@ -0,0 +1,27 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/parallel/task_notifier.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/common/optional_ptr.hpp"
+
+namespace duckdb {
+class ClientContext;
+
+//! The TaskNotifier notifies ClientContextState listener about started / stopped tasks
+class TaskNotifier {
+public:
+	explicit TaskNotifier(optional_ptr<ClientContext> context_p);
+
+	~TaskNotifier();
+
+private:
+	optional_ptr<ClientContext> context;
+};
+
+} // namespace duckdb
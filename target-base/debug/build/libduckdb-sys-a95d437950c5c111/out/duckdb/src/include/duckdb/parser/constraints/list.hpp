@@ -0,0 +1,4 @@
+#include "duckdb/parser/constraints/check_constraint.hpp"
+#include "duckdb/parser/constraints/not_null_constraint.hpp"
+#include "duckdb/parser/constraints/unique_constraint.hpp"
+#include "duckdb/parser/constraints/foreign_key_constraint.hpp"
@@ -0,0 +1,42 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/parser/parsed_data/create_function_info.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/parser/parsed_data/create_info.hpp"
+#include "duckdb/function/function.hpp"
+
+namespace duckdb {
+
+struct FunctionDescription {
+	//! Parameter types (if any)
+	vector<LogicalType> parameter_types;
+	//! Parameter names (if any)
+	vector<string> parameter_names;
+	//! The description (if any)
+	string description;
+	//! Examples (if any)
+	vector<string> examples;
+	//! Categories (if any)
+	vector<string> categories;
+};
+
+struct CreateFunctionInfo : public CreateInfo {
+	explicit CreateFunctionInfo(CatalogType type, string schema = DEFAULT_SCHEMA);
+
+	//! Function name
+	string name;
+	//! The function name of which this function is an alias
+	string alias_of;
+	//! Function description
+	vector<FunctionDescription> descriptions;
+
+	DUCKDB_API void CopyFunctionProperties(CreateFunctionInfo &other) const;
+};
+
+} // namespace duckdb
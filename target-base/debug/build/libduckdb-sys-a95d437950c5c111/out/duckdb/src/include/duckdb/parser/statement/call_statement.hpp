@@ -0,0 +1,33 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/parser/statement/call_statement.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/parser/parsed_expression.hpp"
+#include "duckdb/parser/sql_statement.hpp"
+#include "duckdb/common/vector.hpp"
+
+namespace duckdb {
+
+class CallStatement : public SQLStatement {
+public:
+	static constexpr const StatementType TYPE = StatementType::CALL_STATEMENT;
+
+public:
+	CallStatement();
+
+	unique_ptr<ParsedExpression> function;
+
+protected:
+	CallStatement(const CallStatement &other);
+
+public:
+	unique_ptr<SQLStatement> Copy() const override;
+	string ToString() const override;
+};
+} // namespace duckdb
@@ -0,0 +1,40 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/parser/statement/delete_statement.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/parser/parsed_expression.hpp"
+#include "duckdb/parser/sql_statement.hpp"
+#include "duckdb/parser/tableref.hpp"
+#include "duckdb/parser/query_node.hpp"
+
+namespace duckdb {
+
+class DeleteStatement : public SQLStatement {
+public:
+	static constexpr const StatementType TYPE = StatementType::DELETE_STATEMENT;
+
+public:
+	DeleteStatement();
+
+	unique_ptr<ParsedExpression> condition;
+	unique_ptr<TableRef> table;
+	vector<unique_ptr<TableRef>> using_clauses;
+	vector<unique_ptr<ParsedExpression>> returning_list;
+	//! CTEs
+	CommonTableExpressionMap cte_map;
+
+protected:
+	DeleteStatement(const DeleteStatement &other);
+
+public:
+	string ToString() const override;
+	unique_ptr<SQLStatement> Copy() const override;
+};
+
+} // namespace duckdb
@@ -0,0 +1,405 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/parser/transformer.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/common/case_insensitive_map.hpp"
+#include "duckdb/common/constants.hpp"
+#include "duckdb/common/enums/expression_type.hpp"
+#include "duckdb/common/stack_checker.hpp"
+#include "duckdb/common/types.hpp"
+#include "duckdb/common/unordered_map.hpp"
+#include "duckdb/parser/group_by_node.hpp"
+#include "duckdb/parser/parsed_data/create_info.hpp"
+#include "duckdb/parser/parsed_data/create_secret_info.hpp"
+#include "duckdb/parser/qualified_name.hpp"
+#include "duckdb/parser/query_node.hpp"
+#include "duckdb/parser/query_node/cte_node.hpp"
+#include "duckdb/parser/tokens.hpp"
+#include "nodes/parsenodes.hpp"
+#include "nodes/primnodes.hpp"
+#include "pg_definitions.hpp"
+#include "duckdb/parser/expression/parameter_expression.hpp"
+#include "duckdb/common/enums/on_entry_not_found.hpp"
+
+namespace duckdb {
+
+class ColumnDefinition;
+struct OrderByNode;
+struct CopyInfo;
+struct CommonTableExpressionInfo;
+struct GroupingExpressionMap;
+class OnConflictInfo;
+class UpdateSetInfo;
+class MacroFunction;
+struct ParserOptions;
+struct PivotColumn;
+struct PivotColumnEntry;
+
+//! The transformer class is responsible for transforming the internal Postgres
+//! parser representation into the DuckDB representation
+class Transformer {
+	friend class StackChecker<Transformer>;
+
+	struct CreatePivotEntry {
+		string enum_name;
+		unique_ptr<SelectNode> base;
+		unique_ptr<ParsedExpression> column;
+		unique_ptr<QueryNode> subquery;
+		bool has_parameters;
+	};
+
+public:
+	explicit Transformer(ParserOptions &options);
+	Transformer(Transformer &parent);
+	~Transformer();
+
+	//! Transforms a Postgres parse tree into a set of SQL Statements
+	bool TransformParseTree(duckdb_libpgquery::PGList *tree, vector<unique_ptr<SQLStatement>> &statements);
+	string NodetypeToString(duckdb_libpgquery::PGNodeTag type);
+
+	idx_t ParamCount() const;
+
+private:
+	optional_ptr<Transformer> parent;
+	//! Parser options
+	ParserOptions &options;
+	//! The current prepared statement parameter index
+	idx_t prepared_statement_parameter_index = 0;
+	//! Map from named parameter to parameter index;
+	case_insensitive_map_t<idx_t> named_param_map;
+	//! Last parameter type
+	PreparedParamType last_param_type = PreparedParamType::INVALID;
+	//! Holds window expressions defined by name. We need those when transforming the expressions referring to them.
+	case_insensitive_map_t<duckdb_libpgquery::PGWindowDef *> window_clauses;
+	//! The set of pivot entries to create
+	vector<unique_ptr<CreatePivotEntry>> pivot_entries;
+	//! Sets of stored CTEs, if any
+	vector<CommonTableExpressionMap *> stored_cte_map;
+	//! Whether or not we are currently binding a window definition
+	bool in_window_definition = false;
+
+	void Clear();
+	bool InWindowDefinition();
+
+	Transformer &RootTransformer();
+	const Transformer &RootTransformer() const;
+	void SetParamCount(idx_t new_count);
+	void ClearParameters();
+	void SetParam(const string &name, idx_t index, PreparedParamType type);
+	bool GetParam(const string &name, idx_t &index, PreparedParamType type);
+
+	void AddPivotEntry(string enum_name, unique_ptr<SelectNode> source, unique_ptr<ParsedExpression> column,
+	                   unique_ptr<QueryNode> subquery, bool has_parameters);
+	unique_ptr<SQLStatement> GenerateCreateEnumStmt(unique_ptr<CreatePivotEntry> entry);
+	bool HasPivotEntries();
+	idx_t PivotEntryCount();
+	vector<unique_ptr<CreatePivotEntry>> &GetPivotEntries();
+	void PivotEntryCheck(const string &type);
+	void ExtractCTEsRecursive(CommonTableExpressionMap &cte_map);
+
+private:
+	//! Transforms a Postgres statement into a single SQL statement
+	unique_ptr<SQLStatement> TransformStatement(duckdb_libpgquery::PGNode &stmt);
+	//! Transforms a Postgres statement into a single SQL statement
+	unique_ptr<SQLStatement> TransformStatementInternal(duckdb_libpgquery::PGNode &stmt);
+	//===--------------------------------------------------------------------===//
+	// Statement transformation
+	//===--------------------------------------------------------------------===//
+	//! Transform a Postgres duckdb_libpgquery::T_PGSelectStmt node into a SelectStatement
+	unique_ptr<SelectStatement> TransformSelectStmt(duckdb_libpgquery::PGSelectStmt &select, bool is_select = true);
+	unique_ptr<SelectStatement> TransformSelectStmt(duckdb_libpgquery::PGNode &node, bool is_select = true);
+	//! Transform a Postgres T_AlterStmt node into a AlterStatement
+	unique_ptr<AlterStatement> TransformAlter(duckdb_libpgquery::PGAlterTableStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGRenameStmt node into a RenameStatement
+	unique_ptr<AlterStatement> TransformRename(duckdb_libpgquery::PGRenameStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGCreateStmt node into a CreateStatement
+	unique_ptr<CreateStatement> TransformCreateTable(duckdb_libpgquery::PGCreateStmt &node);
+	//! Transform a Postgres duckdb_libpgquery::T_PGCreateStmt node into a CreateStatement
+	unique_ptr<CreateStatement> TransformCreateTableAs(duckdb_libpgquery::PGCreateTableAsStmt &stmt);
+	//! Transform a Postgres node into a CreateStatement
+	unique_ptr<CreateStatement> TransformCreateSchema(duckdb_libpgquery::PGCreateSchemaStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGCreateSeqStmt node into a CreateStatement
+	unique_ptr<CreateStatement> TransformCreateSequence(duckdb_libpgquery::PGCreateSeqStmt &node);
+	//! Transform a Postgres duckdb_libpgquery::T_PGViewStmt node into a CreateStatement
+	unique_ptr<CreateStatement> TransformCreateView(duckdb_libpgquery::PGViewStmt &node);
+	//! Transform a Postgres duckdb_libpgquery::T_PGIndexStmt node into CreateStatement
+	unique_ptr<CreateStatement> TransformCreateIndex(duckdb_libpgquery::PGIndexStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGCreateFunctionStmt node into CreateStatement
+	unique_ptr<CreateStatement> TransformCreateFunction(duckdb_libpgquery::PGCreateFunctionStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGCreateTypeStmt node into CreateStatement
+	unique_ptr<CreateStatement> TransformCreateType(duckdb_libpgquery::PGCreateTypeStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGCreateTypeStmt node into CreateStatement
+	unique_ptr<AlterStatement> TransformCommentOn(duckdb_libpgquery::PGCommentOnStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGAlterSeqStmt node into CreateStatement
+	unique_ptr<AlterStatement> TransformAlterSequence(duckdb_libpgquery::PGAlterSeqStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGDropStmt node into a Drop[Table,Schema]Statement
+	unique_ptr<SQLStatement> TransformDrop(duckdb_libpgquery::PGDropStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGInsertStmt node into a InsertStatement
+	unique_ptr<InsertStatement> TransformInsert(duckdb_libpgquery::PGInsertStmt &stmt);
+	InsertColumnOrder TransformColumnOrder(duckdb_libpgquery::PGInsertColumnOrder insert_column_order);
+
+	vector<string> TransformInsertColumns(duckdb_libpgquery::PGList &cols);
+
+	//! Transform a Postgres duckdb_libpgquery::T_PGOnConflictClause node into a OnConflictInfo
+	unique_ptr<OnConflictInfo> TransformOnConflictClause(duckdb_libpgquery::PGOnConflictClause *node,
+	                                                     const string &relname);
+	//! Transform a ON CONFLICT shorthand into a OnConflictInfo
+	unique_ptr<OnConflictInfo> DummyOnConflictClause(duckdb_libpgquery::PGOnConflictActionAlias type,
+	                                                 const string &relname);
+	//! Transform a Postgres duckdb_libpgquery::T_PGCopyStmt node into a CopyStatement
+	unique_ptr<CopyStatement> TransformCopy(duckdb_libpgquery::PGCopyStmt &stmt);
+	void TransformCopyOptions(CopyInfo &info, optional_ptr<duckdb_libpgquery::PGList> options);
+	void TransformCreateSecretOptions(CreateSecretInfo &info, optional_ptr<duckdb_libpgquery::PGList> options);
+	//! Transform a Postgres duckdb_libpgquery::T_PGTransactionStmt node into a TransactionStatement
+	unique_ptr<TransactionStatement> TransformTransaction(duckdb_libpgquery::PGTransactionStmt &stmt);
+	//! Transform a Postgres T_DeleteStatement node into a DeleteStatement
+	unique_ptr<DeleteStatement> TransformDelete(duckdb_libpgquery::PGDeleteStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGUpdateStmt node into a UpdateStatement
+	unique_ptr<UpdateStatement> TransformUpdate(duckdb_libpgquery::PGUpdateStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGUpdateExtensionsStmt node into a UpdateExtensionsStatement
+	unique_ptr<UpdateExtensionsStatement> TransformUpdateExtensions(duckdb_libpgquery::PGUpdateExtensionsStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGPragmaStmt node into a PragmaStatement
+	unique_ptr<SQLStatement> TransformPragma(duckdb_libpgquery::PGPragmaStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGExportStmt node into a ExportStatement
+	unique_ptr<ExportStatement> TransformExport(duckdb_libpgquery::PGExportStmt &stmt);
+	//! Transform a Postgres duckdb_libpgquery::T_PGImportStmt node into a PragmaStatement
+	unique_ptr<PragmaStatement> TransformImport(duckdb_libpgquery::PGImportStmt &stmt);
+	unique_ptr<ExplainStatement> TransformExplain(duckdb_libpgquery::PGExplainStmt &stmt);
+	unique_ptr<SQLStatement> TransformVacuum(duckdb_libpgquery::PGVacuumStmt &stmt);
+	unique_ptr<QueryNode> TransformShow(duckdb_libpgquery::PGVariableShowStmt &stmt);
+	unique_ptr<SelectStatement> TransformShowStmt(duckdb_libpgquery::PGVariableShowStmt &stmt);
+	unique_ptr<QueryNode> TransformShowSelect(duckdb_libpgquery::PGVariableShowSelectStmt &stmt);
+	unique_ptr<SelectStatement> TransformShowSelectStmt(duckdb_libpgquery::PGVariableShowSelectStmt &stmt);
+	unique_ptr<AttachStatement> TransformAttach(duckdb_libpgquery::PGAttachStmt &stmt);
+	unique_ptr<DetachStatement> TransformDetach(duckdb_libpgquery::PGDetachStmt &stmt);
+	unique_ptr<SetStatement> TransformUse(duckdb_libpgquery::PGUseStmt &stmt);
+	unique_ptr<SQLStatement> TransformCopyDatabase(duckdb_libpgquery::PGCopyDatabaseStmt &stmt);
+	unique_ptr<CreateStatement> TransformSecret(duckdb_libpgquery::PGCreateSecretStmt &stmt);
+	unique_ptr<DropStatement> TransformDropSecret(duckdb_libpgquery::PGDropSecretStmt &stmt);
+
+	unique_ptr<PrepareStatement> TransformPrepare(duckdb_libpgquery::PGPrepareStmt &stmt);
+	unique_ptr<ExecuteStatement> TransformExecute(duckdb_libpgquery::PGExecuteStmt &stmt);
+	unique_ptr<CallStatement> TransformCall(duckdb_libpgquery::PGCallStmt &stmt);
+	unique_ptr<DropStatement> TransformDeallocate(duckdb_libpgquery::PGDeallocateStmt &stmt);
+	unique_ptr<QueryNode> TransformPivotStatement(duckdb_libpgquery::PGSelectStmt &select);
+	unique_ptr<SQLStatement> CreatePivotStatement(unique_ptr<SQLStatement> statement);
+	PivotColumn TransformPivotColumn(duckdb_libpgquery::PGPivot &pivot, bool is_pivot);
+	vector<PivotColumn> TransformPivotList(duckdb_libpgquery::PGList &list, bool is_pivot);
+	static bool TransformPivotInList(unique_ptr<ParsedExpression> &expr, PivotColumnEntry &entry,
+	                                 bool root_entry = true);
+
+	unique_ptr<SQLStatement> TransformMergeInto(duckdb_libpgquery::PGMergeIntoStmt &stmt);
+	unique_ptr<MergeIntoAction> TransformMergeIntoAction(duckdb_libpgquery::PGMatchAction &action);
+
+	//===--------------------------------------------------------------------===//
+	// SetStatement Transform
+	//===--------------------------------------------------------------------===//
+	unique_ptr<SetStatement> TransformSet(duckdb_libpgquery::PGVariableSetStmt &set);
+	unique_ptr<SetStatement> TransformSetVariable(duckdb_libpgquery::PGVariableSetStmt &stmt);
+	unique_ptr<SetStatement> TransformResetVariable(duckdb_libpgquery::PGVariableSetStmt &stmt);
+
+	unique_ptr<SQLStatement> TransformCheckpoint(duckdb_libpgquery::PGCheckPointStmt &stmt);
+	unique_ptr<LoadStatement> TransformLoad(duckdb_libpgquery::PGLoadStmt &stmt);
+
+	//===--------------------------------------------------------------------===//
+	// Query Node Transform
+	//===--------------------------------------------------------------------===//
+	//! Transform a Postgres duckdb_libpgquery::T_PGSelectStmt node into a QueryNode
+	unique_ptr<QueryNode> TransformSelectNode(duckdb_libpgquery::PGNode &select, bool is_select = true);
+	unique_ptr<QueryNode> TransformSelectNodeInternal(duckdb_libpgquery::PGSelectStmt &select, bool is_select = true);
+	unique_ptr<QueryNode> TransformSelectInternal(duckdb_libpgquery::PGSelectStmt &select);
+	void TransformModifiers(duckdb_libpgquery::PGSelectStmt &stmt, QueryNode &node);
+
+	//===--------------------------------------------------------------------===//
+	// Expression Transform
+	//===--------------------------------------------------------------------===//
+	//! Transform a Postgres boolean expression into an Expression
+	unique_ptr<ParsedExpression> TransformBoolExpr(duckdb_libpgquery::PGBoolExpr &root);
+	//! Transform a Postgres case expression into an Expression
+	unique_ptr<ParsedExpression> TransformCase(duckdb_libpgquery::PGCaseExpr &root);
+	//! Transform a Postgres type cast into an Expression
+	unique_ptr<ParsedExpression> TransformTypeCast(duckdb_libpgquery::PGTypeCast &root);
+	//! Transform a Postgres coalesce into an Expression
+	unique_ptr<ParsedExpression> TransformCoalesce(duckdb_libpgquery::PGAExpr &root);
+	//! Transform a Postgres column reference into an Expression
+	unique_ptr<ParsedExpression> TransformColumnRef(duckdb_libpgquery::PGColumnRef &root);
+	//! Transform a Postgres constant value into an Expression
+	unique_ptr<ConstantExpression> TransformValue(duckdb_libpgquery::PGValue val);
+	//! Transform a Postgres operator into an Expression
+	unique_ptr<ParsedExpression> TransformAExpr(duckdb_libpgquery::PGAExpr &root);
+	unique_ptr<ParsedExpression> TransformAExprInternal(duckdb_libpgquery::PGAExpr &root);
+	//! Transform a Postgres abstract expression into an Expression
+	unique_ptr<ParsedExpression> TransformExpression(optional_ptr<duckdb_libpgquery::PGNode> node);
+	unique_ptr<ParsedExpression> TransformExpression(duckdb_libpgquery::PGNode &node);
+	//! Transform a Postgres function call into an Expression
+	unique_ptr<ParsedExpression> TransformFuncCall(duckdb_libpgquery::PGFuncCall &root);
+	//! Transform a Postgres boolean expression into an Expression
+	unique_ptr<ParsedExpression> TransformInterval(duckdb_libpgquery::PGIntervalConstant &root);
+	//! Transform a LAMBDA node (e.g., lambda x, y: x + y) into a lambda expression.
+	unique_ptr<ParsedExpression> TransformLambda(duckdb_libpgquery::PGLambdaFunction &node);
+	//! Transform a single arrow operator (e.g., (x, y) -> x + y) into a lambda expression.
+	unique_ptr<ParsedExpression> TransformSingleArrow(duckdb_libpgquery::PGSingleArrowFunction &node);
+	//! Transform a Postgres array access node (e.g. x[1] or x[1:3])
+	unique_ptr<ParsedExpression> TransformArrayAccess(duckdb_libpgquery::PGAIndirection &node);
+	//! Transform a positional reference (e.g. #1)
+	unique_ptr<ParsedExpression> TransformPositionalReference(duckdb_libpgquery::PGPositionalReference &node);
+	unique_ptr<ParsedExpression> TransformStarExpression(duckdb_libpgquery::PGAStar &node);
+	unique_ptr<ParsedExpression> TransformBooleanTest(duckdb_libpgquery::PGBooleanTest &node);
+
+	//! Transform a Postgres constant value into an Expression
+	unique_ptr<ParsedExpression> TransformConstant(duckdb_libpgquery::PGAConst &c);
+	unique_ptr<ParsedExpression> TransformGroupingFunction(duckdb_libpgquery::PGGroupingFunc &n);
+	unique_ptr<ParsedExpression> TransformResTarget(duckdb_libpgquery::PGResTarget &root);
+	unique_ptr<ParsedExpression> TransformNullTest(duckdb_libpgquery::PGNullTest &root);
+	unique_ptr<ParsedExpression> TransformParamRef(duckdb_libpgquery::PGParamRef &node);
+	unique_ptr<ParsedExpression> TransformNamedArg(duckdb_libpgquery::PGNamedArgExpr &root);
+
+	//! Transform multi assignment reference into an Expression
+	unique_ptr<ParsedExpression> TransformMultiAssignRef(duckdb_libpgquery::PGMultiAssignRef &root);
+
+	unique_ptr<ParsedExpression> TransformSQLValueFunction(duckdb_libpgquery::PGSQLValueFunction &node);
+
+	unique_ptr<ParsedExpression> TransformSubquery(duckdb_libpgquery::PGSubLink &root);
+	//===--------------------------------------------------------------------===//
+	// Constraints transform
+	//===--------------------------------------------------------------------===//
+	unique_ptr<Constraint> TransformConstraint(duckdb_libpgquery::PGConstraint &constraint);
+	unique_ptr<Constraint> TransformConstraint(duckdb_libpgquery::PGConstraint &constraint, ColumnDefinition &column,
+	                                           idx_t index);
+
+	//===--------------------------------------------------------------------===//
+	// Update transform
+	//===--------------------------------------------------------------------===//
+	unique_ptr<UpdateSetInfo> TransformUpdateSetInfo(duckdb_libpgquery::PGList *target_list,
+	                                                 duckdb_libpgquery::PGNode *where_clause);
+
+	//===--------------------------------------------------------------------===//
+	// Index transform
+	//===--------------------------------------------------------------------===//
+	vector<unique_ptr<ParsedExpression>> TransformIndexParameters(duckdb_libpgquery::PGList &list,
+	                                                              const string &relation_name);
+
+	//===--------------------------------------------------------------------===//
+	// Collation transform
+	//===--------------------------------------------------------------------===//
+	unique_ptr<ParsedExpression> TransformCollateExpr(duckdb_libpgquery::PGCollateClause &collate);
+
+	string TransformCollation(optional_ptr<duckdb_libpgquery::PGCollateClause> collate);
+
+	ColumnDefinition TransformColumnDefinition(duckdb_libpgquery::PGColumnDef &cdef);
+	//===--------------------------------------------------------------------===//
+	// Helpers
+	//===--------------------------------------------------------------------===//
+	OnCreateConflict TransformOnConflict(duckdb_libpgquery::PGOnCreateConflict conflict);
+	string TransformAlias(duckdb_libpgquery::PGAlias *root, vector<string> &column_name_alias);
+	vector<string> TransformStringList(duckdb_libpgquery::PGList *list);
+	void TransformCTE(duckdb_libpgquery::PGWithClause &de_with_clause, CommonTableExpressionMap &cte_map);
+	static unique_ptr<QueryNode> TransformMaterializedCTE(unique_ptr<QueryNode> root);
+	unique_ptr<SelectStatement> TransformRecursiveCTE(duckdb_libpgquery::PGCommonTableExpr &node,
+	                                                  CommonTableExpressionInfo &info);
+
+	unique_ptr<ParsedExpression> TransformUnaryOperator(const string &op, unique_ptr<ParsedExpression> child);
+	unique_ptr<ParsedExpression> TransformBinaryOperator(string op, unique_ptr<ParsedExpression> left,
+	                                                     unique_ptr<ParsedExpression> right);
+	static bool ConstructConstantFromExpression(const ParsedExpression &expr, Value &value);
+	//===--------------------------------------------------------------------===//
+	// TableRef transform
+	//===--------------------------------------------------------------------===//
+	//! Transform a Postgres node into a TableRef
+	unique_ptr<TableRef> TransformTableRefNode(duckdb_libpgquery::PGNode &n);
+	//! Transform a Postgres FROM clause into a TableRef
+	unique_ptr<TableRef> TransformFrom(optional_ptr<duckdb_libpgquery::PGList> root);
+	//! Transform a Postgres table reference into a TableRef
+	unique_ptr<TableRef> TransformRangeVar(duckdb_libpgquery::PGRangeVar &root);
+	//! Transform a Postgres table-producing function into a TableRef
+	unique_ptr<TableRef> TransformRangeFunction(duckdb_libpgquery::PGRangeFunction &root);
+	//! Transform a Postgres join node into a TableRef
+	unique_ptr<TableRef> TransformJoin(duckdb_libpgquery::PGJoinExpr &root);
+	//! Transform a Postgres pivot node into a TableRef
+	unique_ptr<TableRef> TransformPivot(duckdb_libpgquery::PGPivotExpr &root);
+	//! Transform a table producing subquery into a TableRef
+	unique_ptr<TableRef> TransformRangeSubselect(duckdb_libpgquery::PGRangeSubselect &root);
+	//! Transform a VALUES list into a set of expressions
+	unique_ptr<TableRef> TransformValuesList(duckdb_libpgquery::PGList *list);
+
+	//! Transform using clause
+	vector<string> TransformUsingClause(duckdb_libpgquery::PGList &usingClause);
+
+	//! Transform a range var into a (schema) qualified name
+	QualifiedName TransformQualifiedName(duckdb_libpgquery::PGRangeVar &root);
+
+	//! Transform a Postgres TypeName string into a LogicalType (non-LIST types)
+	LogicalType TransformTypeNameInternal(duckdb_libpgquery::PGTypeName &name);
+	//! Transform a Postgres TypeName string into a LogicalType
+	LogicalType TransformTypeName(duckdb_libpgquery::PGTypeName &name);
+
+	//! Transform a list of type modifiers into a list of values
+	vector<Value> TransformTypeModifiers(duckdb_libpgquery::PGTypeName &name);
+
+	//! Transform a Postgres GROUP BY expression into a list of Expression
+	bool TransformGroupBy(optional_ptr<duckdb_libpgquery::PGList> group, SelectNode &result);
+	void TransformGroupByNode(duckdb_libpgquery::PGNode &n, GroupingExpressionMap &map, SelectNode &result,
+	                          vector<GroupingSet> &result_sets);
+	void AddGroupByExpression(unique_ptr<ParsedExpression> expression, GroupingExpressionMap &map, GroupByNode &result,
+	                          vector<idx_t> &result_set);
+	void TransformGroupByExpression(duckdb_libpgquery::PGNode &n, GroupingExpressionMap &map, GroupByNode &result,
+	                                vector<idx_t> &result_set);
+	//! Transform a Postgres ORDER BY expression into an OrderByDescription
+	bool TransformOrderBy(duckdb_libpgquery::PGList *order, vector<OrderByNode> &result);
+
+	//! Transform to a IN or NOT IN expression
+	unique_ptr<ParsedExpression> TransformInExpression(const string &name, duckdb_libpgquery::PGAExpr &root);
+
+	//! Transform a Postgres SELECT clause into a list of Expressions
+	void TransformExpressionList(duckdb_libpgquery::PGList &list, vector<unique_ptr<ParsedExpression>> &result);
+
+	//! Transform a Postgres PARTITION BY/ORDER BY specification into lists of expressions
+	void TransformWindowDef(duckdb_libpgquery::PGWindowDef &window_spec, WindowExpression &expr,
+	                        const char *window_name = nullptr);
+	//! Transform a Postgres window frame specification into frame expressions
+	void TransformWindowFrame(duckdb_libpgquery::PGWindowDef &window_spec, WindowExpression &expr);
+
+	unique_ptr<SampleOptions> TransformSampleOptions(optional_ptr<duckdb_libpgquery::PGNode> options);
+	//! Returns true if an expression is only a star (i.e. "*", without any other decorators)
+	bool ExpressionIsEmptyStar(ParsedExpression &expr);
+
+	OnEntryNotFound TransformOnEntryNotFound(bool missing_ok);
+
+	Vector PGListToVector(optional_ptr<duckdb_libpgquery::PGList> column_list, idx_t &size);
+	vector<string> TransformConflictTarget(duckdb_libpgquery::PGList &list);
+
+	unique_ptr<MacroFunction> TransformMacroFunction(duckdb_libpgquery::PGFunctionDefinition &function);
+
+	vector<string> TransformNameList(duckdb_libpgquery::PGList &list);
+
+public:
+	static void SetQueryLocation(ParsedExpression &expr, int query_location);
+	static void SetQueryLocation(TableRef &ref, int query_location);
+
+private:
+	//! Current stack depth
+	idx_t stack_depth;
+
+	void InitializeStackCheck();
+	StackChecker<Transformer> StackCheck(idx_t extra_stack = 1);
+
+public:
+	template <class T>
+	static T &PGCast(duckdb_libpgquery::PGNode &node) {
+		return reinterpret_cast<T &>(node);
+	}
+	template <class T>
+	static optional_ptr<T> PGPointerCast(void *ptr) {
+		return optional_ptr<T>(reinterpret_cast<T *>(ptr));
+	}
+};
+
+vector<string> ReadPgListToString(duckdb_libpgquery::PGList *column_list);
+
+} // namespace duckdb
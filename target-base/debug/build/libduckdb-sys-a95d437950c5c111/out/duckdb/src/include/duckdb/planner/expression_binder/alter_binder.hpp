@@ -0,0 +1,36 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/planner/expression_binder/alter_binder.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/parser/column_definition.hpp"
+#include "duckdb/planner/expression_binder.hpp"
+
+namespace duckdb {
+class TableCatalogEntry;
+
+//! The AlterBinder binds expressions in ALTER statements.
+class AlterBinder : public ExpressionBinder {
+public:
+	AlterBinder(Binder &binder, ClientContext &context, TableCatalogEntry &table, vector<LogicalIndex> &bound_columns,
+	            LogicalType target_type);
+
+protected:
+	BindResult BindLambdaReference(LambdaRefExpression &expr, idx_t depth);
+	BindResult BindColumnReference(ColumnRefExpression &expr, idx_t depth);
+	BindResult BindExpression(unique_ptr<ParsedExpression> &expr_ptr, idx_t depth,
+	                          bool root_expression = false) override;
+
+	string UnsupportedAggregateMessage() override;
+
+private:
+	TableCatalogEntry &table;
+	vector<LogicalIndex> &bound_columns;
+};
+
+} // namespace duckdb
@@ -0,0 +1,32 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/planner/expression_binder/select_binder.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/planner/expression_binder/base_select_binder.hpp"
+
+namespace duckdb {
+
+//! The SELECT binder is responsible for binding an expression within the SELECT clause of a SQL statement
+class SelectBinder : public BaseSelectBinder {
+public:
+	SelectBinder(Binder &binder, ClientContext &context, BoundSelectNode &node, BoundGroupInformation &info);
+
+protected:
+	void ThrowIfUnnestInLambda(const ColumnBinding &column_binding) override;
+	BindResult BindUnnest(FunctionExpression &function, idx_t depth, bool root_expression) override;
+	BindResult BindColumnRef(unique_ptr<ParsedExpression> &expr_ptr, idx_t depth, bool root_expression) override;
+
+	bool QualifyColumnAlias(const ColumnRefExpression &colref) override;
+	unique_ptr<ParsedExpression> GetSQLValueFunction(const string &column_name) override;
+
+protected:
+	idx_t unnest_level = 0;
+};
+
+} // namespace duckdb
@@ -0,0 +1,40 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/planner/extension_callback.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/common/common.hpp"
+
+namespace duckdb {
+class ClientContext;
+class DatabaseInstance;
+class ErrorData;
+
+class ExtensionCallback {
+public:
+	virtual ~ExtensionCallback() {
+	}
+
+	//! Called when a new connection is opened
+	virtual void OnConnectionOpened(ClientContext &context) {
+	}
+	//! Called when a connection is closed
+	virtual void OnConnectionClosed(ClientContext &context) {
+	}
+	//! Called before an extension starts loading
+	virtual void OnBeginExtensionLoad(DatabaseInstance &db, const string &name) {
+	}
+	//! Called after an extension is finished loading
+	virtual void OnExtensionLoaded(DatabaseInstance &db, const string &name) {
+	}
+	//! Called after an extension fails to load loading
+	virtual void OnExtensionLoadFail(DatabaseInstance &db, const string &name, const ErrorData &error) {
+	}
+};
+
+} // namespace duckdb
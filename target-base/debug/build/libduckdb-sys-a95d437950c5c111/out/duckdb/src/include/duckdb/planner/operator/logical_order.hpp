@@ -0,0 +1,44 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/planner/operator/logical_order.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/planner/bound_query_node.hpp"
+#include "duckdb/planner/expression/bound_reference_expression.hpp"
+#include "duckdb/planner/logical_operator.hpp"
+#include "duckdb/storage/statistics/base_statistics.hpp"
+
+namespace duckdb {
+
+//! LogicalOrder represents an ORDER BY clause, sorting the data
+class LogicalOrder : public LogicalOperator {
+public:
+	static constexpr const LogicalOperatorType TYPE = LogicalOperatorType::LOGICAL_ORDER_BY;
+
+public:
+	explicit LogicalOrder(vector<BoundOrderByNode> orders);
+
+	vector<BoundOrderByNode> orders;
+	vector<idx_t> projection_map;
+
+public:
+	vector<ColumnBinding> GetColumnBindings() override;
+
+	bool HasProjectionMap() const override {
+		return !projection_map.empty();
+	}
+
+	void Serialize(Serializer &serializer) const override;
+	static unique_ptr<LogicalOperator> Deserialize(Deserializer &deserializer);
+
+	InsertionOrderPreservingMap<string> ParamsToString() const override;
+
+protected:
+	void ResolveTypes() override;
+};
+} // namespace duckdb
@@ -0,0 +1,49 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/planner/operator/logical_top_n.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/planner/bound_query_node.hpp"
+#include "duckdb/planner/logical_operator.hpp"
+
+namespace duckdb {
+struct DynamicFilterData;
+
+//! LogicalTopN represents a comibination of ORDER BY and LIMIT clause, using Min/Max Heap
+class LogicalTopN : public LogicalOperator {
+public:
+	static constexpr const LogicalOperatorType TYPE = LogicalOperatorType::LOGICAL_TOP_N;
+
+public:
+	LogicalTopN(vector<BoundOrderByNode> orders, idx_t limit, idx_t offset);
+	~LogicalTopN() override;
+
+	vector<BoundOrderByNode> orders;
+	//! The maximum amount of elements to emit
+	idx_t limit;
+	//! The offset from the start to begin emitting elements
+	idx_t offset;
+	//! Dynamic table filter (if any)
+	shared_ptr<DynamicFilterData> dynamic_filter;
+
+public:
+	vector<ColumnBinding> GetColumnBindings() override {
+		return children[0]->GetColumnBindings();
+	}
+
+	void Serialize(Serializer &serializer) const override;
+	static unique_ptr<LogicalOperator> Deserialize(Deserializer &deserializer);
+
+	idx_t EstimateCardinality(ClientContext &context) override;
+
+protected:
+	void ResolveTypes() override {
+		types = children[0]->types;
+	}
+};
+} // namespace duckdb
@@ -0,0 +1,55 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/planner/table_filter_state.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/planner/table_filter.hpp"
+#include "duckdb/common/types/selection_vector.hpp"
+#include "duckdb/execution/expression_executor.hpp"
+
+namespace duckdb {
+
+//! Thread-local state for executing a table filter
+struct TableFilterState {
+public:
+	virtual ~TableFilterState() = default;
+
+public:
+	static unique_ptr<TableFilterState> Initialize(ClientContext &context, const TableFilter &filter);
+
+public:
+	template <class TARGET>
+	TARGET &Cast() {
+		DynamicCastCheck<TARGET>(this);
+		return reinterpret_cast<TARGET &>(*this);
+	}
+	template <class TARGET>
+	const TARGET &Cast() const {
+		DynamicCastCheck<TARGET>(this);
+		return reinterpret_cast<const TARGET &>(*this);
+	}
+};
+
+struct ConjunctionAndFilterState : public TableFilterState {
+public:
+	vector<unique_ptr<TableFilterState>> child_states;
+};
+
+struct ConjunctionOrFilterState : public TableFilterState {
+public:
+	vector<unique_ptr<TableFilterState>> child_states;
+};
+
+struct ExpressionFilterState : public TableFilterState {
+public:
+	ExpressionFilterState(ClientContext &context, const Expression &expression);
+
+	ExpressionExecutor executor;
+};
+
+} // namespace duckdb
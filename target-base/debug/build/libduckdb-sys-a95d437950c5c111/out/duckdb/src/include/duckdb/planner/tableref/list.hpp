@@ -0,0 +1,11 @@
+#include "duckdb/planner/tableref/bound_basetableref.hpp"
+#include "duckdb/planner/tableref/bound_cteref.hpp"
+#include "duckdb/planner/tableref/bound_dummytableref.hpp"
+#include "duckdb/planner/tableref/bound_expressionlistref.hpp"
+#include "duckdb/planner/tableref/bound_joinref.hpp"
+#include "duckdb/planner/tableref/bound_subqueryref.hpp"
+#include "duckdb/planner/tableref/bound_column_data_ref.hpp"
+#include "duckdb/planner/tableref/bound_table_function.hpp"
+#include "duckdb/planner/tableref/bound_pivotref.hpp"
+#include "duckdb/parser/tableref/delimgetref.hpp"
+#include "duckdb/planner/tableref/bound_delimgetref.hpp"
@@ -0,0 +1,37 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/storage/statistics/node_statistics.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/common/common.hpp"
+
+namespace duckdb {
+
+class NodeStatistics {
+public:
+	NodeStatistics() : has_estimated_cardinality(false), has_max_cardinality(false) {
+	}
+	explicit NodeStatistics(idx_t estimated_cardinality)
+	    : has_estimated_cardinality(true), estimated_cardinality(estimated_cardinality), has_max_cardinality(false) {
+	}
+	NodeStatistics(idx_t estimated_cardinality, idx_t max_cardinality)
+	    : has_estimated_cardinality(true), estimated_cardinality(estimated_cardinality), has_max_cardinality(true),
+	      max_cardinality(max_cardinality) {
+	}
+
+	//! Whether or not the node has an estimated cardinality specified
+	bool has_estimated_cardinality;
+	//! The estimated cardinality at the specified node
+	idx_t estimated_cardinality;
+	//! Whether or not the node has a maximum cardinality specified
+	bool has_max_cardinality;
+	//! The max possible cardinality at the specified node
+	idx_t max_cardinality;
+};
+
+} // namespace duckdb
@@ -0,0 +1,141 @@
+//===----------------------------------------------------------------------===//
+//                         DuckDB
+//
+// duckdb/transaction/duck_transaction_manager.hpp
+//
+//
+//===----------------------------------------------------------------------===//
+
+#pragma once
+
+#include "duckdb/transaction/transaction_manager.hpp"
+#include "duckdb/storage/storage_lock.hpp"
+#include "duckdb/common/enums/checkpoint_type.hpp"
+#include "duckdb/common/queue.hpp"
+
+namespace duckdb {
+class DuckTransaction;
+struct UndoBufferProperties;
+
+//! CleanupInfo collects transactions awaiting cleanup.
+//! This ensures we can clean up after releasing the transaction lock.
+struct DuckCleanupInfo {
+	//! All transactions in a cleanup info share the same lowest_start_time.
+	transaction_t lowest_start_time;
+	vector<unique_ptr<DuckTransaction>> transactions;
+
+	void Cleanup() noexcept;
+	bool ScheduleCleanup() noexcept;
+};
+
+//! The Transaction Manager is responsible for creating and managing
+//! transactions
+class DuckTransactionManager : public TransactionManager {
+public:
+	explicit DuckTransactionManager(AttachedDatabase &db);
+	~DuckTransactionManager() override;
+
+public:
+	static DuckTransactionManager &Get(AttachedDatabase &db);
+
+	//! Start a new transaction
+	Transaction &StartTransaction(ClientContext &context) override;
+	//! Commit the given transaction
+	ErrorData CommitTransaction(ClientContext &context, Transaction &transaction) override;
+	//! Rollback the given transaction
+	void RollbackTransaction(Transaction &transaction) override;
+
+	void Checkpoint(ClientContext &context, bool force = false) override;
+
+	transaction_t LowestActiveId() const {
+		return lowest_active_id;
+	}
+	transaction_t LowestActiveStart() const {
+		return lowest_active_start;
+	}
+	transaction_t GetLastCommit() const {
+		return last_commit;
+	}
+
+	bool IsDuckTransactionManager() override {
+		return true;
+	}
+
+	//! Obtains a shared lock to the checkpoint lock
+	unique_ptr<StorageLockKey> SharedCheckpointLock();
+	unique_ptr<StorageLockKey> TryUpgradeCheckpointLock(StorageLockKey &lock);
+
+	//! Returns the current version of the catalog (incremented whenever anything changes, not stored between restarts)
+	DUCKDB_API idx_t GetCatalogVersion(Transaction &transaction);
+
+	void PushCatalogEntry(Transaction &transaction_p, CatalogEntry &entry, data_ptr_t extra_data = nullptr,
+	                      idx_t extra_data_size = 0);
+	void PushAttach(Transaction &transaction_p, AttachedDatabase &db);
+
+protected:
+	struct CheckpointDecision {
+		explicit CheckpointDecision(string reason_p);
+		explicit CheckpointDecision(CheckpointType type);
+		~CheckpointDecision();
+
+		bool can_checkpoint;
+		string reason;
+		CheckpointType type;
+	};
+
+private:
+	//! Generates a new commit timestamp
+	transaction_t GetCommitTimestamp();
+	//! Remove the given transaction from the list of active transactions
+	unique_ptr<DuckCleanupInfo> RemoveTransaction(DuckTransaction &transaction) noexcept;
+	//! Remove the given transaction from the list of active transactions
+	unique_ptr<DuckCleanupInfo> RemoveTransaction(DuckTransaction &transaction, bool store_transaction) noexcept;
+
+	//! Whether or not we can checkpoint
+	CheckpointDecision CanCheckpoint(DuckTransaction &transaction, unique_ptr<StorageLockKey> &checkpoint_lock,
+	                                 const UndoBufferProperties &properties);
+
+private:
+	//! The current start timestamp used by transactions
+	transaction_t current_start_timestamp;
+	//! The current transaction ID used by transactions
+	transaction_t current_transaction_id;
+	//! The lowest active transaction id
+	atomic<transaction_t> lowest_active_id;
+	//! The lowest active transaction timestamp
+	atomic<transaction_t> lowest_active_start;
+	//! The last commit timestamp
+	atomic<transaction_t> last_commit;
+	//! Set of currently running transactions
+	vector<unique_ptr<DuckTransaction>> active_transactions;
+	//! Set of recently committed transactions
+	vector<unique_ptr<DuckTransaction>> recently_committed_transactions;
+	//! Transactions awaiting GC
+	vector<unique_ptr<DuckTransaction>> old_transactions;
+	//! The lock used for transaction operations
+	mutex transaction_lock;
+	//! The checkpoint lock
+	StorageLock checkpoint_lock;
+	//! Lock necessary to start transactions only - used by FORCE CHECKPOINT to prevent new transactions from starting
+	mutex start_transaction_lock;
+	//! Mutex used to control writes to the WAL - separate from the transaction lock
+	mutex wal_lock;
+
+	atomic<idx_t> last_uncommitted_catalog_version = {TRANSACTION_ID_START};
+	idx_t last_committed_version = 0;
+
+	//! Only one cleanup can be active at any time.
+	mutex cleanup_lock;
+	//! Changes to the cleanup queue must be synchronized.
+	mutex cleanup_queue_lock;
+	//! Cleanups have to happen in-order.
+	//! E.g., if one transaction drops a table, and another creates a table,
+	//! inverting the cleanup order can result in catalog errors.
+	queue<unique_ptr<DuckCleanupInfo>> cleanup_queue;
+
+protected:
+	virtual void OnCommitCheckpointDecision(const CheckpointDecision &decision, DuckTransaction &transaction) {
+	}
+};
+
+} // namespace duckdb
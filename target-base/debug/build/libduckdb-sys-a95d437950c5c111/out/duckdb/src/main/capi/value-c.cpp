@@ -0,0 +1,178 @@
+#include "duckdb/main/capi/capi_internal.hpp"
+#include "duckdb/common/types/date.hpp"
+#include "duckdb/common/types/time.hpp"
+#include "duckdb/common/types/timestamp.hpp"
+#include "duckdb/common/types.hpp"
+#include "duckdb/common/uhugeint.hpp"
+
+#include "duckdb/main/capi/cast/generic.hpp"
+
+#include <cstring>
+
+using duckdb::date_t;
+using duckdb::dtime_t;
+using duckdb::FetchDefaultValue;
+using duckdb::GetInternalCValue;
+using duckdb::hugeint_t;
+using duckdb::interval_t;
+using duckdb::StringCast;
+using duckdb::timestamp_t;
+using duckdb::ToCStringCastWrapper;
+using duckdb::uhugeint_t;
+using duckdb::UnsafeFetch;
+
+bool duckdb_value_boolean(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<bool>(result, col, row);
+}
+
+int8_t duckdb_value_int8(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<int8_t>(result, col, row);
+}
+
+int16_t duckdb_value_int16(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<int16_t>(result, col, row);
+}
+
+int32_t duckdb_value_int32(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<int32_t>(result, col, row);
+}
+
+int64_t duckdb_value_int64(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<int64_t>(result, col, row);
+}
+
+static bool ResultIsDecimal(duckdb_result *result, idx_t col) {
+	if (!result) {
+		return false;
+	}
+	if (!result->internal_data) {
+		return false;
+	}
+	auto result_data = (duckdb::DuckDBResultData *)result->internal_data;
+	auto &query_result = result_data->result;
+	auto &source_type = query_result->types[col];
+	return source_type.id() == duckdb::LogicalTypeId::DECIMAL;
+}
+
+duckdb_decimal duckdb_value_decimal(duckdb_result *result, idx_t col, idx_t row) {
+	if (!CanFetchValue(result, col, row) || !ResultIsDecimal(result, col)) {
+		return FetchDefaultValue::Operation<duckdb_decimal>();
+	}
+
+	return GetInternalCValue<duckdb_decimal>(result, col, row);
+}
+
+duckdb_hugeint duckdb_value_hugeint(duckdb_result *result, idx_t col, idx_t row) {
+	duckdb_hugeint result_value;
+	auto internal_value = GetInternalCValue<hugeint_t>(result, col, row);
+	result_value.lower = internal_value.lower;
+	result_value.upper = internal_value.upper;
+	return result_value;
+}
+
+duckdb_uhugeint duckdb_value_uhugeint(duckdb_result *result, idx_t col, idx_t row) {
+	duckdb_uhugeint result_value;
+	auto internal_value = GetInternalCValue<uhugeint_t>(result, col, row);
+	result_value.lower = internal_value.lower;
+	result_value.upper = internal_value.upper;
+	return result_value;
+}
+
+uint8_t duckdb_value_uint8(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<uint8_t>(result, col, row);
+}
+
+uint16_t duckdb_value_uint16(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<uint16_t>(result, col, row);
+}
+
+uint32_t duckdb_value_uint32(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<uint32_t>(result, col, row);
+}
+
+uint64_t duckdb_value_uint64(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<uint64_t>(result, col, row);
+}
+
+float duckdb_value_float(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<float>(result, col, row);
+}
+
+double duckdb_value_double(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<double>(result, col, row);
+}
+
+duckdb_date duckdb_value_date(duckdb_result *result, idx_t col, idx_t row) {
+	duckdb_date result_value;
+	result_value.days = GetInternalCValue<date_t>(result, col, row).days;
+	return result_value;
+}
+
+duckdb_time duckdb_value_time(duckdb_result *result, idx_t col, idx_t row) {
+	duckdb_time result_value;
+	result_value.micros = GetInternalCValue<dtime_t>(result, col, row).micros;
+	return result_value;
+}
+
+duckdb_timestamp duckdb_value_timestamp(duckdb_result *result, idx_t col, idx_t row) {
+	duckdb_timestamp result_value;
+	result_value.micros = GetInternalCValue<timestamp_t>(result, col, row).value;
+	return result_value;
+}
+
+duckdb_interval duckdb_value_interval(duckdb_result *result, idx_t col, idx_t row) {
+	duckdb_interval result_value;
+	auto ival = GetInternalCValue<interval_t>(result, col, row);
+	result_value.months = ival.months;
+	result_value.days = ival.days;
+	result_value.micros = ival.micros;
+	return result_value;
+}
+
+char *duckdb_value_varchar(duckdb_result *result, idx_t col, idx_t row) {
+	return duckdb_value_string(result, col, row).data;
+}
+
+duckdb_string duckdb_value_string(duckdb_result *result, idx_t col, idx_t row) {
+	return GetInternalCValue<duckdb_string, ToCStringCastWrapper<StringCast>>(result, col, row);
+}
+
+char *duckdb_value_varchar_internal(duckdb_result *result, idx_t col, idx_t row) {
+	return duckdb_value_string_internal(result, col, row).data;
+}
+
+duckdb_string duckdb_value_string_internal(duckdb_result *result, idx_t col, idx_t row) {
+	if (!CanFetchValue(result, col, row)) {
+		return FetchDefaultValue::Operation<duckdb_string>();
+	}
+	if (duckdb_column_type(result, col) != DUCKDB_TYPE_VARCHAR) {
+		return FetchDefaultValue::Operation<duckdb_string>();
+	}
+	// FIXME: this obviously does not work when there are null bytes in the string
+	// we need to remove the deprecated C result materialization to get that to work correctly
+	// since the deprecated C result materialization stores strings as null-terminated
+	duckdb_string res;
+	res.data = UnsafeFetch<char *>(result, col, row);
+	res.size = strlen(res.data);
+	return res;
+}
+
+duckdb_blob duckdb_value_blob(duckdb_result *result, idx_t col, idx_t row) {
+	if (CanFetchValue(result, col, row) && result->deprecated_columns[col].deprecated_type == DUCKDB_TYPE_BLOB) {
+		auto internal_result = UnsafeFetch<duckdb_blob>(result, col, row);
+
+		duckdb_blob result_blob;
+		result_blob.data = malloc(internal_result.size);
+		result_blob.size = internal_result.size;
+		memcpy(result_blob.data, internal_result.data, internal_result.size);
+		return result_blob;
+	}
+	return FetchDefaultValue::Operation<duckdb_blob>();
+}
+
+bool duckdb_value_is_null(duckdb_result *result, idx_t col, idx_t row) {
+	if (!CanUseDeprecatedFetch(result, col, row)) {
+		return false;
+	}
+	return result->deprecated_columns[col].deprecated_nullmask[row];
+}
@@ -0,0 +1,34 @@
+#include "duckdb/main/relation/distinct_relation.hpp"
+#include "duckdb/main/client_context.hpp"
+#include "duckdb/parser/query_node.hpp"
+
+namespace duckdb {
+
+DistinctRelation::DistinctRelation(shared_ptr<Relation> child_p)
+    : Relation(child_p->context, RelationType::DISTINCT_RELATION), child(std::move(child_p)) {
+	D_ASSERT(child.get() != this);
+	vector<ColumnDefinition> dummy_columns;
+	TryBindRelation(dummy_columns);
+}
+
+unique_ptr<QueryNode> DistinctRelation::GetQueryNode() {
+	auto child_node = child->GetQueryNode();
+	child_node->AddDistinct();
+	return child_node;
+}
+
+string DistinctRelation::GetAlias() {
+	return child->GetAlias();
+}
+
+const vector<ColumnDefinition> &DistinctRelation::Columns() {
+	return child->Columns();
+}
+
+string DistinctRelation::ToString(idx_t depth) {
+	string str = RenderWhitespace(depth) + "Distinct\n";
+	return str + child->ToString(depth + 1);
+	;
+}
+
+} // namespace duckdb
@@ -0,0 +1,57 @@
+#include "duckdb/main/relation/filter_relation.hpp"
+#include "duckdb/main/client_context.hpp"
+#include "duckdb/parser/query_node/select_node.hpp"
+#include "duckdb/parser/query_node/set_operation_node.hpp"
+#include "duckdb/parser/expression/conjunction_expression.hpp"
+#include "duckdb/parser/expression/star_expression.hpp"
+
+namespace duckdb {
+
+FilterRelation::FilterRelation(shared_ptr<Relation> child_p, unique_ptr<ParsedExpression> condition_p)
+    : Relation(child_p->context, RelationType::FILTER_RELATION), condition(std::move(condition_p)),
+      child(std::move(child_p)) {
+	D_ASSERT(child.get() != this);
+	vector<ColumnDefinition> dummy_columns;
+	TryBindRelation(dummy_columns);
+}
+
+unique_ptr<QueryNode> FilterRelation::GetQueryNode() {
+	auto child_ptr = child.get();
+	while (child_ptr->InheritsColumnBindings()) {
+		child_ptr = child_ptr->ChildRelation();
+	}
+	if (child_ptr->type == RelationType::JOIN_RELATION) {
+		// child node is a join: push filter into WHERE clause of select node
+		auto child_node = child->GetQueryNode();
+		D_ASSERT(child_node->type == QueryNodeType::SELECT_NODE);
+		auto &select_node = child_node->Cast<SelectNode>();
+		if (!select_node.where_clause) {
+			select_node.where_clause = condition->Copy();
+		} else {
+			select_node.where_clause = make_uniq<ConjunctionExpression>(
+			    ExpressionType::CONJUNCTION_AND, std::move(select_node.where_clause), condition->Copy());
+		}
+		return child_node;
+	} else {
+		auto result = make_uniq<SelectNode>();
+		result->select_list.push_back(make_uniq<StarExpression>());
+		result->from_table = child->GetTableRef();
+		result->where_clause = condition->Copy();
+		return std::move(result);
+	}
+}
+
+string FilterRelation::GetAlias() {
+	return child->GetAlias();
+}
+
+const vector<ColumnDefinition> &FilterRelation::Columns() {
+	return child->Columns();
+}
+
+string FilterRelation::ToString(idx_t depth) {
+	string str = RenderWhitespace(depth) + "Filter [" + condition->ToString() + "]\n";
+	return str + child->ToString(depth + 1);
+}
+
+} // namespace duckdb
@@ -0,0 +1,48 @@
+#include "duckdb/main/relation/order_relation.hpp"
+#include "duckdb/main/client_context.hpp"
+#include "duckdb/parser/query_node.hpp"
+#include "duckdb/parser/query_node/select_node.hpp"
+#include "duckdb/parser/expression/star_expression.hpp"
+
+namespace duckdb {
+
+OrderRelation::OrderRelation(shared_ptr<Relation> child_p, vector<OrderByNode> orders)
+    : Relation(child_p->context, RelationType::ORDER_RELATION), orders(std::move(orders)), child(std::move(child_p)) {
+	D_ASSERT(child.get() != this);
+	// bind the expressions
+	TryBindRelation(columns);
+}
+
+unique_ptr<QueryNode> OrderRelation::GetQueryNode() {
+	auto select = make_uniq<SelectNode>();
+	select->from_table = child->GetTableRef();
+	select->select_list.push_back(make_uniq<StarExpression>());
+	auto order_node = make_uniq<OrderModifier>();
+	for (idx_t i = 0; i < orders.size(); i++) {
+		order_node->orders.emplace_back(orders[i].type, orders[i].null_order, orders[i].expression->Copy());
+	}
+	select->modifiers.push_back(std::move(order_node));
+	return std::move(select);
+}
+
+string OrderRelation::GetAlias() {
+	return child->GetAlias();
+}
+
+const vector<ColumnDefinition> &OrderRelation::Columns() {
+	return columns;
+}
+
+string OrderRelation::ToString(idx_t depth) {
+	string str = RenderWhitespace(depth) + "Order [";
+	for (idx_t i = 0; i < orders.size(); i++) {
+		if (i != 0) {
+			str += ", ";
+		}
+		str += orders[i].expression->ToString() + (orders[i].type == OrderType::ASCENDING ? " ASC" : " DESC");
+	}
+	str += "]\n";
+	return str + child->ToString(depth + 1);
+}
+
+} // namespace duckdb
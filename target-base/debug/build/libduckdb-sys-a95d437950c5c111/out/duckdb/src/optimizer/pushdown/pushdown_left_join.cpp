@@ -0,0 +1,203 @@
+#include "duckdb/common/assert.hpp"
+#include "duckdb/common/enums/join_type.hpp"
+#include "duckdb/common/helper.hpp"
+#include "duckdb/common/typedefs.hpp"
+#include "duckdb/common/types.hpp"
+#include "duckdb/common/types/value.hpp"
+#include "duckdb/common/unique_ptr.hpp"
+#include "duckdb/common/unordered_map.hpp"
+#include "duckdb/common/vector.hpp"
+#include "duckdb/execution/expression_executor.hpp"
+#include "duckdb/optimizer/filter_pushdown.hpp"
+#include "duckdb/optimizer/optimizer.hpp"
+#include "duckdb/planner/binder.hpp"
+#include "duckdb/planner/column_binding.hpp"
+#include "duckdb/planner/expression/bound_columnref_expression.hpp"
+#include "duckdb/planner/expression/bound_comparison_expression.hpp"
+#include "duckdb/planner/expression/bound_constant_expression.hpp"
+#include "duckdb/planner/expression_iterator.hpp"
+#include "duckdb/planner/joinside.hpp"
+#include "duckdb/planner/logical_operator.hpp"
+#include "duckdb/planner/operator/logical_any_join.hpp"
+#include "duckdb/planner/operator/logical_comparison_join.hpp"
+#include "duckdb/planner/operator/logical_cross_product.hpp"
+#include "duckdb/planner/operator/logical_dummy_scan.hpp"
+#include "duckdb/planner/operator/logical_filter.hpp"
+#include "duckdb/planner/operator/logical_projection.hpp"
+#include <utility>
+
+namespace duckdb {
+
+using Filter = FilterPushdown::Filter;
+
+static unique_ptr<Expression> ReplaceColRefWithNull(unique_ptr<Expression> root_expr,
+                                                    unordered_set<idx_t> &right_bindings) {
+	ExpressionIterator::VisitExpressionMutable<BoundColumnRefExpression>(
+	    root_expr, [&](BoundColumnRefExpression &bound_colref, unique_ptr<Expression> &expr) {
+		    if (right_bindings.find(bound_colref.binding.table_index) != right_bindings.end()) {
+			    // bound colref belongs to RHS
+			    // replace it with a constant NULL
+			    expr = make_uniq<BoundConstantExpression>(Value(expr->return_type));
+		    }
+	    });
+	return root_expr;
+}
+
+static bool FilterRemovesNull(ClientContext &context, ExpressionRewriter &rewriter, Expression *expr,
+                              unordered_set<idx_t> &right_bindings) {
+	// make a copy of the expression
+	auto copy = expr->Copy();
+	// replace all BoundColumnRef expressions from the RHS with NULL constants in the copied expression
+	copy = ReplaceColRefWithNull(std::move(copy), right_bindings);
+
+	// attempt to flatten the expression by running the expression rewriter on it
+	auto filter = make_uniq<LogicalFilter>();
+	filter->expressions.push_back(std::move(copy));
+	rewriter.VisitOperator(*filter);
+
+	// check if all expressions are foldable
+	for (idx_t i = 0; i < filter->expressions.size(); i++) {
+		if (!filter->expressions[i]->IsFoldable()) {
+			return false;
+		}
+		// we flattened the result into a scalar, check if it is FALSE or NULL
+		auto val =
+		    ExpressionExecutor::EvaluateScalar(context, *filter->expressions[i]).DefaultCastAs(LogicalType::BOOLEAN);
+		// if the result of the expression with all expressions replaced with NULL is "NULL" or "false"
+		// then any extra entries generated by the LEFT OUTER JOIN will be filtered out!
+		// hence the LEFT OUTER JOIN is equivalent to an inner join
+		if (val.IsNull() || !BooleanValue::Get(val)) {
+			return true;
+		}
+	}
+	return false;
+}
+
+unique_ptr<LogicalOperator> FilterPushdown::PushdownLeftJoin(unique_ptr<LogicalOperator> op,
+                                                             unordered_set<idx_t> &left_bindings,
+                                                             unordered_set<idx_t> &right_bindings) {
+	auto &join = op->Cast<LogicalJoin>();
+	if (op->type == LogicalOperatorType::LOGICAL_DELIM_JOIN) {
+		return FinishPushdown(std::move(op));
+	}
+	FilterPushdown left_pushdown(optimizer, convert_mark_joins), right_pushdown(optimizer, convert_mark_joins);
+	// for a comparison join we create a FilterCombiner that checks if we can push conditions on LHS join conditions
+	// into the RHS of the join
+	FilterCombiner filter_combiner(optimizer);
+	const auto isComparison = (op->type == LogicalOperatorType::LOGICAL_COMPARISON_JOIN ||
+	                           op->type == LogicalOperatorType::LOGICAL_ASOF_JOIN);
+	if (isComparison) {
+		// add all comparison conditions
+		auto &comparison_join = op->Cast<LogicalComparisonJoin>();
+		for (auto &cond : comparison_join.conditions) {
+			filter_combiner.AddFilter(
+			    make_uniq<BoundComparisonExpression>(cond.comparison, cond.left->Copy(), cond.right->Copy()));
+		}
+	}
+	// now check the set of filters
+	vector<unique_ptr<Filter>> remaining_filters;
+	for (idx_t i = 0; i < filters.size(); i++) {
+		auto side = JoinSide::GetJoinSide(filters[i]->bindings, left_bindings, right_bindings);
+		if (side == JoinSide::LEFT) {
+			// bindings match left side
+			// we can push the filter into the left side
+			if (isComparison) {
+				// we MIGHT be able to push it down the RHS as well, but only if it is a comparison that matches the
+				// join predicates we use the FilterCombiner to figure this out add the expression to the FilterCombiner
+				filter_combiner.AddFilter(filters[i]->filter->Copy());
+			}
+			left_pushdown.filters.push_back(std::move(filters[i]));
+			// erase the filter from the list of filters
+			filters.erase_at(i);
+			i--;
+		} else if (op->type != LogicalOperatorType::LOGICAL_ASOF_JOIN) {
+			// bindings match right side or both sides: we cannot directly push it into the right
+			// however, if the filter removes rows with null values from the RHS we can turn the left outer join
+			// in an inner join, and then push down as we would push down an inner join
+			// Edit: This is only possible if the bindings match BOTH sides, so the filter can be pushed down to both
+			// children. If the filter can only be applied to the right side, and the filter filters
+			// all tuples, then the inner join cannot be converted.
+			if (FilterRemovesNull(optimizer.context, optimizer.rewriter, filters[i]->filter.get(), right_bindings)) {
+				// the filter removes NULL values, turn it into an inner join
+				join.join_type = JoinType::INNER;
+				// now we can do more pushdown
+				// move all filters we added to the left_pushdown back into the filter list
+				for (auto &left_filter : left_pushdown.filters) {
+					filters.push_back(std::move(left_filter));
+				}
+				for (auto &filter : remaining_filters) {
+					filters.push_back(std::move(filter));
+				}
+				// now push down the inner join
+				return PushdownInnerJoin(std::move(op), left_bindings, right_bindings);
+			}
+			// we should keep the filters which do not remove NULL values
+			remaining_filters.push_back(std::move(filters[i]));
+			filters.erase_at(i);
+			i--;
+		}
+	}
+	// finally we check the FilterCombiner to see if there are any predicates we can push into the RHS
+	// we only added (1) predicates that have JoinSide::BOTH from the conditions, and
+	// (2) predicates that have JoinSide::LEFT from the filters
+	// we check now if this combination generated any new filters that are only on JoinSide::RIGHT
+	// this happens if, e.g. a join condition is (i=a) and there is a filter (i=500), we can then push the filter
+	// (a=500) into the RHS
+	filter_combiner.GenerateFilters([&](unique_ptr<Expression> filter) {
+		if (JoinSide::GetJoinSide(*filter, left_bindings, right_bindings) == JoinSide::RIGHT) {
+			right_pushdown.AddFilter(std::move(filter));
+		}
+	});
+	right_pushdown.GenerateFilters();
+	op->children[0] = left_pushdown.Rewrite(std::move(op->children[0]));
+
+	bool rewrite_right = true;
+	if (op->type == LogicalOperatorType::LOGICAL_ANY_JOIN) {
+		auto &any_join = join.Cast<LogicalAnyJoin>();
+		if (AddFilter(any_join.condition->Copy()) == FilterResult::UNSATISFIABLE) {
+			// filter statically evaluates to false, turns it to the cross product join with 1 row NULLs
+			if (any_join.join_type == JoinType::LEFT) {
+				unordered_map<idx_t, vector<unique_ptr<Expression>>> projections_groups;
+				auto column_bindings = op->children[1]->GetColumnBindings();
+				op->children[1]->ResolveOperatorTypes();
+				auto &types = op->children[1]->types;
+				for (idx_t i = 0; i < column_bindings.size(); i++) {
+					projections_groups[column_bindings[i].table_index].emplace_back(
+					    make_uniq<BoundConstantExpression>(Value(types[i])));
+				}
+
+				auto create_proj_dummy_scan = [&](idx_t table_index) {
+					auto dummy_scan = make_uniq<LogicalDummyScan>(optimizer.binder.GenerateTableIndex());
+					auto proj = make_uniq<LogicalProjection>(table_index, std::move(projections_groups[table_index]));
+					proj->AddChild(std::move(dummy_scan));
+					return proj;
+				};
+				// make cross products on the RHS first
+				auto begin = projections_groups.begin();
+				D_ASSERT(begin != projections_groups.end());
+				unique_ptr<LogicalOperator> left = create_proj_dummy_scan(begin->first);
+				projections_groups.erase(begin);
+				for (auto &group : projections_groups) {
+					auto proj = create_proj_dummy_scan(group.first);
+					auto op = LogicalCrossProduct::Create(std::move(left), std::move(proj));
+					left = std::move(op);
+				}
+				// then make cross product with the LHS
+				op = LogicalCrossProduct::Create(std::move(op->children[0]), std::move(left));
+				rewrite_right = false;
+			}
+		}
+	}
+
+	if (rewrite_right) {
+		op->children[1] = right_pushdown.Rewrite(std::move(op->children[1]));
+	}
+
+	for (auto &filter : remaining_filters) {
+		filters.push_back(std::move(filter));
+	}
+
+	return PushFinalFilters(std::move(op));
+}
+
+} // namespace duckdb
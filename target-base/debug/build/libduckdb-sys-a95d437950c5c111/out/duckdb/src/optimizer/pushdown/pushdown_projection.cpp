@@ -0,0 +1,78 @@
+#include "duckdb/optimizer/filter_pushdown.hpp"
+#include "duckdb/planner/expression/bound_columnref_expression.hpp"
+#include "duckdb/planner/expression_iterator.hpp"
+#include "duckdb/planner/operator/logical_empty_result.hpp"
+#include "duckdb/planner/operator/logical_projection.hpp"
+#include "duckdb/planner/expression/bound_cast_expression.hpp"
+#include "duckdb/common/types.hpp"
+
+namespace duckdb {
+
+static bool IsVolatile(LogicalProjection &proj, const Expression &expr) {
+	bool is_volatile = false;
+	ExpressionIterator::VisitExpression<BoundColumnRefExpression>(expr, [&](const BoundColumnRefExpression &colref) {
+		D_ASSERT(colref.binding.table_index == proj.table_index);
+		D_ASSERT(colref.binding.column_index < proj.expressions.size());
+		D_ASSERT(colref.depth == 0);
+		if (proj.expressions[colref.binding.column_index]->IsVolatile()) {
+			is_volatile = true;
+		}
+	});
+	return is_volatile;
+}
+
+static unique_ptr<Expression> ReplaceProjectionBindings(LogicalProjection &proj, unique_ptr<Expression> root_expr) {
+	ExpressionIterator::VisitExpressionMutable<BoundColumnRefExpression>(
+	    root_expr, [&](BoundColumnRefExpression &colref, unique_ptr<Expression> &expr) {
+		    D_ASSERT(colref.binding.table_index == proj.table_index);
+		    D_ASSERT(colref.binding.column_index < proj.expressions.size());
+		    D_ASSERT(colref.depth == 0);
+		    // replace the binding with a copy to the expression at the referenced index
+		    auto copy = proj.expressions[colref.binding.column_index]->Copy();
+		    if (!colref.alias.empty()) {
+			    copy->alias = colref.alias;
+		    }
+		    expr = std::move(copy);
+	    });
+	return root_expr;
+}
+
+unique_ptr<LogicalOperator> FilterPushdown::PushdownProjection(unique_ptr<LogicalOperator> op) {
+	D_ASSERT(op->type == LogicalOperatorType::LOGICAL_PROJECTION);
+	auto &proj = op->Cast<LogicalProjection>();
+	// push filter through logical projection
+	// all the BoundColumnRefExpressions in the filter should refer to the LogicalProjection
+	// we can rewrite them by replacing those references with the expression of the LogicalProjection node
+	FilterPushdown child_pushdown(optimizer, convert_mark_joins);
+	// There are some expressions can not be pushed down. We should keep them
+	// and add an extra filter operator.
+	vector<unique_ptr<Expression>> remain_expressions;
+	for (auto &filter : filters) {
+		auto &f = *filter;
+		D_ASSERT(f.bindings.size() <= 1);
+		bool is_volatile = IsVolatile(proj, *f.filter);
+		if (is_volatile || f.filter->CanThrow()) {
+			// We can't push down related expressions if the column in the
+			// expression is generated by the functions which have side effects
+			remain_expressions.push_back(std::move(f.filter));
+		} else {
+			// rewrite the bindings within this subquery
+			f.filter = ReplaceProjectionBindings(proj, std::move(f.filter));
+			// add the filter to the child pushdown
+			if (child_pushdown.AddFilter(std::move(f.filter)) == FilterResult::UNSATISFIABLE) {
+				// filter statically evaluates to false, strip tree
+				return make_uniq<LogicalEmptyResult>(std::move(op));
+			}
+		}
+	}
+	child_pushdown.GenerateFilters();
+	// now push into children
+	op->children[0] = child_pushdown.Rewrite(std::move(op->children[0]));
+	if (op->children[0]->type == LogicalOperatorType::LOGICAL_EMPTY_RESULT) {
+		// child returns an empty result: generate an empty result here too
+		return make_uniq<LogicalEmptyResult>(std::move(op));
+	}
+	return AddLogicalFilter(std::move(op), std::move(remain_expressions));
+}
+
+} // namespace duckdb
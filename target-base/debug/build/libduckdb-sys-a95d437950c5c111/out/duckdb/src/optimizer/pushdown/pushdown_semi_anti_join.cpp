@@ -0,0 +1,56 @@
+#include "duckdb/optimizer/filter_pushdown.hpp"
+#include "duckdb/planner/operator/logical_any_join.hpp"
+#include "duckdb/planner/operator/logical_comparison_join.hpp"
+#include "duckdb/planner/operator/logical_cross_product.hpp"
+#include "duckdb/planner/operator/logical_empty_result.hpp"
+#include "duckdb/planner/operator/logical_projection.hpp"
+
+namespace duckdb {
+
+using Filter = FilterPushdown::Filter;
+
+unique_ptr<LogicalOperator> FilterPushdown::PushdownSemiAntiJoin(unique_ptr<LogicalOperator> op) {
+	auto &join = op->Cast<LogicalJoin>();
+	if (op->type == LogicalOperatorType::LOGICAL_DELIM_JOIN) {
+		return FinishPushdown(std::move(op));
+	}
+
+	// push all current filters down the left side
+	op->children[0] = Rewrite(std::move(op->children[0]));
+	FilterPushdown right_pushdown(optimizer, convert_mark_joins);
+	op->children[1] = right_pushdown.Rewrite(std::move(op->children[1]));
+
+	bool left_empty = op->children[0]->type == LogicalOperatorType::LOGICAL_EMPTY_RESULT;
+	bool right_empty = op->children[1]->type == LogicalOperatorType::LOGICAL_EMPTY_RESULT;
+	if (left_empty && right_empty) {
+		// both empty: return empty result
+		return make_uniq<LogicalEmptyResult>(std::move(op));
+	}
+	// TODO: if semi/anti join is created from a intersect/except statement, then we can
+	//  push filters down into both children.
+	// filter pushdown happens before join order optimization, so right_anti and right_semi are not possible yet here
+	if (left_empty) {
+		// left child is empty result
+		switch (join.join_type) {
+		case JoinType::ANTI:
+		case JoinType::SEMI:
+			return make_uniq<LogicalEmptyResult>(std::move(op));
+		default:
+			break;
+		}
+	} else if (right_empty) {
+		// right child is empty result
+		switch (join.join_type) {
+		case JoinType::ANTI:
+			// just return the left child.
+			return std::move(op->children[0]);
+		case JoinType::SEMI:
+			return make_uniq<LogicalEmptyResult>(std::move(op));
+		default:
+			break;
+		}
+	}
+	return op;
+}
+
+} // namespace duckdb
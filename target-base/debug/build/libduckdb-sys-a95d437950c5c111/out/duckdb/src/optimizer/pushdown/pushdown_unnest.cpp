@@ -0,0 +1,52 @@
+#include "duckdb/optimizer/filter_pushdown.hpp"
+#include "duckdb/planner/expression/bound_columnref_expression.hpp"
+#include "duckdb/planner/expression_iterator.hpp"
+#include "duckdb/planner/operator/logical_empty_result.hpp"
+#include "duckdb/planner/operator/logical_unnest.hpp"
+
+namespace duckdb {
+
+unique_ptr<LogicalOperator> FilterPushdown::PushdownUnnest(unique_ptr<LogicalOperator> op) {
+	D_ASSERT(op->type == LogicalOperatorType::LOGICAL_UNNEST);
+	auto &unnest = op->Cast<LogicalUnnest>();
+	// push filter through logical projection
+	// all the BoundColumnRefExpressions in the filter should refer to the LogicalProjection
+	// we can rewrite them by replacing those references with the expression of the LogicalProjection node
+	FilterPushdown child_pushdown(optimizer, convert_mark_joins);
+	// There are some expressions can not be pushed down. We should keep them
+	// and add an extra filter operator.
+	vector<unique_ptr<Expression>> remain_expressions;
+	for (auto &filter : filters) {
+		auto &f = *filter;
+		auto can_push = true;
+		for (auto &binding : f.bindings) {
+			if (binding == unnest.unnest_index) {
+				can_push = false;
+				break;
+			}
+		}
+		// if the expression index table index is the unnest index, then the filter is on the
+		// unnest, and it should not be pushed down.
+		if (!can_push) {
+			// We can't push down related expressions if the column in the
+			// expression is generated by the functions which have side effects
+			remain_expressions.push_back(std::move(f.filter));
+		} else {
+			// add the filter to the child pushdown
+			if (child_pushdown.AddFilter(std::move(f.filter)) == FilterResult::UNSATISFIABLE) {
+				// filter statically evaluates to false, strip tree
+				return make_uniq<LogicalEmptyResult>(std::move(op));
+			}
+		}
+	}
+	child_pushdown.GenerateFilters();
+	// now push into children
+	op->children[0] = child_pushdown.Rewrite(std::move(op->children[0]));
+	if (op->children[0]->type == LogicalOperatorType::LOGICAL_EMPTY_RESULT) {
+		// child returns an empty result: generate an empty result here too
+		return make_uniq<LogicalEmptyResult>(std::move(op));
+	}
+	return AddLogicalFilter(std::move(op), std::move(remain_expressions));
+}
+
+} // namespace duckdb
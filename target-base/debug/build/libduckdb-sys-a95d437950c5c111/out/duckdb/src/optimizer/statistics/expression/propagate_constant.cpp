@@ -0,0 +1,18 @@
+#include "duckdb/optimizer/statistics_propagator.hpp"
+#include "duckdb/planner/expression/bound_constant_expression.hpp"
+#include "duckdb/storage/statistics/distinct_statistics.hpp"
+#include "duckdb/storage/statistics/list_stats.hpp"
+#include "duckdb/storage/statistics/struct_stats.hpp"
+
+namespace duckdb {
+
+unique_ptr<BaseStatistics> StatisticsPropagator::StatisticsFromValue(const Value &input) {
+	return BaseStatistics::FromConstant(input).ToUnique();
+}
+
+unique_ptr<BaseStatistics> StatisticsPropagator::PropagateExpression(BoundConstantExpression &constant,
+                                                                     unique_ptr<Expression> &expr_ptr) {
+	return StatisticsFromValue(constant.value);
+}
+
+} // namespace duckdb
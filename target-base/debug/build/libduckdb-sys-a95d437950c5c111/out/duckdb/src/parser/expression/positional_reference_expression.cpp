@@ -0,0 +1,40 @@
+#include "duckdb/parser/expression/positional_reference_expression.hpp"
+
+#include "duckdb/common/exception.hpp"
+#include "duckdb/common/types/hash.hpp"
+#include "duckdb/common/to_string.hpp"
+
+#include "duckdb/common/serializer/serializer.hpp"
+#include "duckdb/common/serializer/deserializer.hpp"
+
+namespace duckdb {
+
+PositionalReferenceExpression::PositionalReferenceExpression()
+    : ParsedExpression(ExpressionType::POSITIONAL_REFERENCE, ExpressionClass::POSITIONAL_REFERENCE) {
+}
+
+PositionalReferenceExpression::PositionalReferenceExpression(idx_t index)
+    : ParsedExpression(ExpressionType::POSITIONAL_REFERENCE, ExpressionClass::POSITIONAL_REFERENCE), index(index) {
+}
+
+string PositionalReferenceExpression::ToString() const {
+	return "#" + to_string(index);
+}
+
+bool PositionalReferenceExpression::Equal(const PositionalReferenceExpression &a,
+                                          const PositionalReferenceExpression &b) {
+	return a.index == b.index;
+}
+
+unique_ptr<ParsedExpression> PositionalReferenceExpression::Copy() const {
+	auto copy = make_uniq<PositionalReferenceExpression>(index);
+	copy->CopyProperties(*this);
+	return std::move(copy);
+}
+
+hash_t PositionalReferenceExpression::Hash() const {
+	hash_t result = ParsedExpression::Hash();
+	return CombineHash(duckdb::Hash(index), result);
+}
+
+} // namespace duckdb
@@ -0,0 +1,35 @@
+#include "duckdb/parser/parsed_data/create_scalar_function_info.hpp"
+#include "duckdb/parser/parsed_data/alter_scalar_function_info.hpp"
+
+namespace duckdb {
+
+CreateScalarFunctionInfo::CreateScalarFunctionInfo(ScalarFunction function)
+    : CreateFunctionInfo(CatalogType::SCALAR_FUNCTION_ENTRY), functions(function.name) {
+	name = function.name;
+	functions.AddFunction(std::move(function));
+	internal = true;
+}
+CreateScalarFunctionInfo::CreateScalarFunctionInfo(ScalarFunctionSet set)
+    : CreateFunctionInfo(CatalogType::SCALAR_FUNCTION_ENTRY), functions(std::move(set)) {
+	name = functions.name;
+	for (auto &func : functions.functions) {
+		func.name = functions.name;
+	}
+	internal = true;
+}
+
+unique_ptr<CreateInfo> CreateScalarFunctionInfo::Copy() const {
+	ScalarFunctionSet set(name);
+	set.functions = functions.functions;
+	auto result = make_uniq<CreateScalarFunctionInfo>(std::move(set));
+	CopyFunctionProperties(*result);
+	return std::move(result);
+}
+
+unique_ptr<AlterInfo> CreateScalarFunctionInfo::GetAlterInfo() const {
+	return make_uniq_base<AlterInfo, AddScalarFunctionOverloadInfo>(
+	    AlterEntryData(catalog, schema, name, OnEntryNotFound::RETURN_NULL),
+	    unique_ptr_cast<CreateInfo, CreateScalarFunctionInfo>(Copy()));
+}
+
+} // namespace duckdb
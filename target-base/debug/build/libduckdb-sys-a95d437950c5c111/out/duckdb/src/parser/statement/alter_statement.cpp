@@ -0,0 +1,19 @@
+#include "duckdb/parser/statement/alter_statement.hpp"
+
+namespace duckdb {
+
+AlterStatement::AlterStatement() : SQLStatement(StatementType::ALTER_STATEMENT) {
+}
+
+AlterStatement::AlterStatement(const AlterStatement &other) : SQLStatement(other), info(other.info->Copy()) {
+}
+
+unique_ptr<SQLStatement> AlterStatement::Copy() const {
+	return unique_ptr<AlterStatement>(new AlterStatement(*this));
+}
+
+string AlterStatement::ToString() const {
+	return info->ToString();
+}
+
+} // namespace duckdb
@@ -0,0 +1,19 @@
+#include "duckdb/parser/statement/load_statement.hpp"
+
+namespace duckdb {
+
+LoadStatement::LoadStatement() : SQLStatement(StatementType::LOAD_STATEMENT) {
+}
+
+LoadStatement::LoadStatement(const LoadStatement &other) : SQLStatement(other), info(other.info->Copy()) {
+}
+
+unique_ptr<SQLStatement> LoadStatement::Copy() const {
+	return unique_ptr<LoadStatement>(new LoadStatement(*this));
+}
+
+string LoadStatement::ToString() const {
+	return info->ToString();
+}
+
+} // namespace duckdb
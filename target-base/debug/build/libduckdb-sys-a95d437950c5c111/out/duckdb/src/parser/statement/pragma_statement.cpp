@@ -0,0 +1,19 @@
+#include "duckdb/parser/statement/pragma_statement.hpp"
+
+namespace duckdb {
+
+PragmaStatement::PragmaStatement() : SQLStatement(StatementType::PRAGMA_STATEMENT), info(make_uniq<PragmaInfo>()) {
+}
+
+PragmaStatement::PragmaStatement(const PragmaStatement &other) : SQLStatement(other), info(other.info->Copy()) {
+}
+
+unique_ptr<SQLStatement> PragmaStatement::Copy() const {
+	return unique_ptr<PragmaStatement>(new PragmaStatement(*this));
+}
+
+string PragmaStatement::ToString() const {
+	return info->ToString();
+}
+
+} // namespace duckdb
@@ -0,0 +1,184 @@
+#include "duckdb/common/enum_util.hpp"
+#include "duckdb/common/limits.hpp"
+#include "duckdb/common/operator/cast_operators.hpp"
+#include "duckdb/common/types/decimal.hpp"
+#include "duckdb/parser/expression/cast_expression.hpp"
+#include "duckdb/parser/expression/constant_expression.hpp"
+#include "duckdb/parser/expression/function_expression.hpp"
+#include "duckdb/parser/transformer.hpp"
+
+namespace duckdb {
+
+unique_ptr<ConstantExpression> Transformer::TransformValue(duckdb_libpgquery::PGValue val) {
+	switch (val.type) {
+	case duckdb_libpgquery::T_PGInteger:
+		D_ASSERT(val.val.ival <= NumericLimits<int32_t>::Maximum());
+		return make_uniq<ConstantExpression>(Value::INTEGER((int32_t)val.val.ival));
+	case duckdb_libpgquery::T_PGBitString: // FIXME: this should actually convert to BLOB
+	case duckdb_libpgquery::T_PGString:
+		return make_uniq<ConstantExpression>(Value(string(val.val.str)));
+	case duckdb_libpgquery::T_PGFloat: {
+		string_t str_val(val.val.str);
+		bool try_cast_as_integer = true;
+		bool try_cast_as_decimal = true;
+		optional_idx decimal_position = optional_idx::Invalid();
+		idx_t num_underscores = 0;
+		idx_t num_integer_underscores = 0;
+		for (idx_t i = 0; i < str_val.GetSize(); i++) {
+			if (val.val.str[i] == '.') {
+				// decimal point: cast as either decimal or double
+				try_cast_as_integer = false;
+				decimal_position = i;
+			}
+			if (val.val.str[i] == 'e' || val.val.str[i] == 'E') {
+				// found exponent, cast as double
+				try_cast_as_integer = false;
+				try_cast_as_decimal = false;
+			}
+			if (val.val.str[i] == '_') {
+				num_underscores++;
+				if (!decimal_position.IsValid()) {
+					num_integer_underscores++;
+				}
+			}
+		}
+		if (try_cast_as_integer) {
+			int64_t bigint_value;
+			// try to cast as bigint first
+			if (TryCast::Operation<string_t, int64_t>(str_val, bigint_value)) {
+				// successfully cast to bigint: bigint value
+				return make_uniq<ConstantExpression>(Value::BIGINT(bigint_value));
+			}
+			hugeint_t hugeint_value;
+			// if that is not successful; try to cast as hugeint
+			if (TryCast::Operation<string_t, hugeint_t>(str_val, hugeint_value)) {
+				// successfully cast to bigint: bigint value
+				return make_uniq<ConstantExpression>(Value::HUGEINT(hugeint_value));
+			}
+			uhugeint_t uhugeint_value;
+			// if that is not successful; try to cast as uhugeint
+			if (TryCast::Operation<string_t, uhugeint_t>(str_val, uhugeint_value)) {
+				// successfully cast to bigint: bigint value
+				return make_uniq<ConstantExpression>(Value::UHUGEINT(uhugeint_value));
+			}
+		}
+		idx_t decimal_offset = val.val.str[0] == '-' ? 3 : 2;
+		if (try_cast_as_decimal && decimal_position.IsValid() &&
+		    str_val.GetSize() - num_underscores < Decimal::MAX_WIDTH_DECIMAL + decimal_offset) {
+			// figure out the width/scale based on the decimal position
+			auto width = NumericCast<uint8_t>(str_val.GetSize() - 1 - num_underscores);
+			auto scale = NumericCast<uint8_t>(width - decimal_position.GetIndex() + num_integer_underscores);
+			if (val.val.str[0] == '-') {
+				width--;
+			}
+			if (width <= Decimal::MAX_WIDTH_DECIMAL) {
+				// we can cast the value as a decimal
+				Value val = Value(str_val);
+				val = val.DefaultCastAs(LogicalType::DECIMAL(width, scale));
+				return make_uniq<ConstantExpression>(std::move(val));
+			}
+		}
+		// if there is a decimal or the value is too big to cast as either hugeint or bigint
+		double dbl_value = Cast::Operation<string_t, double>(str_val);
+		return make_uniq<ConstantExpression>(Value::DOUBLE(dbl_value));
+	}
+	case duckdb_libpgquery::T_PGNull:
+		return make_uniq<ConstantExpression>(Value(LogicalType::SQLNULL));
+	default:
+		throw NotImplementedException("Value not implemented!");
+	}
+}
+
+unique_ptr<ParsedExpression> Transformer::TransformConstant(duckdb_libpgquery::PGAConst &c) {
+	auto constant = TransformValue(c.val);
+	SetQueryLocation(*constant, c.location);
+	return std::move(constant);
+}
+
+bool Transformer::ConstructConstantFromExpression(const ParsedExpression &expr, Value &value) {
+	// We have to construct it like this because we don't have the ClientContext for binding/executing the expr here
+	switch (expr.GetExpressionType()) {
+	case ExpressionType::FUNCTION: {
+		auto &function = expr.Cast<FunctionExpression>();
+		if (function.function_name == "struct_pack") {
+			unordered_set<string> unique_names;
+			child_list_t<Value> values;
+			values.reserve(function.children.size());
+			for (const auto &child : function.children) {
+				if (!unique_names.insert(child->GetAlias()).second) {
+					throw BinderException("Duplicate struct entry name \"%s\"", child->GetAlias());
+				}
+				Value child_value;
+				if (!ConstructConstantFromExpression(*child, child_value)) {
+					return false;
+				}
+				values.emplace_back(child->GetAlias(), std::move(child_value));
+			}
+			value = Value::STRUCT(std::move(values));
+			return true;
+		} else if (function.function_name == "list_value") {
+			vector<Value> values;
+			values.reserve(function.children.size());
+			for (const auto &child : function.children) {
+				Value child_value;
+				if (!ConstructConstantFromExpression(*child, child_value)) {
+					return false;
+				}
+				values.emplace_back(std::move(child_value));
+			}
+
+			// figure out child type
+			LogicalType child_type(LogicalTypeId::SQLNULL);
+			for (auto &child_value : values) {
+				child_type = LogicalType::ForceMaxLogicalType(child_type, child_value.type());
+			}
+
+			// finally create the list
+			value = Value::LIST(child_type, values);
+			return true;
+		} else if (function.function_name == "map") {
+			Value keys;
+			if (!ConstructConstantFromExpression(*function.children[0], keys)) {
+				return false;
+			}
+
+			Value values;
+			if (!ConstructConstantFromExpression(*function.children[1], values)) {
+				return false;
+			}
+
+			vector<Value> keys_unpacked = ListValue::GetChildren(keys);
+			vector<Value> values_unpacked = ListValue::GetChildren(values);
+
+			value = Value::MAP(ListType::GetChildType(keys.type()), ListType::GetChildType(values.type()),
+			                   keys_unpacked, values_unpacked);
+			return true;
+		} else {
+			return false;
+		}
+	}
+	case ExpressionType::VALUE_CONSTANT: {
+		auto &constant = expr.Cast<ConstantExpression>();
+		value = constant.value;
+		return true;
+	}
+	case ExpressionType::OPERATOR_CAST: {
+		auto &cast = expr.Cast<CastExpression>();
+		Value dummy_value;
+		if (!ConstructConstantFromExpression(*cast.child, dummy_value)) {
+			return false;
+		}
+
+		string error_message;
+		if (!dummy_value.DefaultTryCastAs(cast.cast_type, value, &error_message)) {
+			throw ConversionException("Unable to cast %s to %s", dummy_value.ToString(),
+			                          EnumUtil::ToString(cast.cast_type.id()));
+		}
+		return true;
+	}
+	default:
+		return false;
+	}
+}
+
+} // namespace duckdb
@@ -0,0 +1,14 @@
+#include "duckdb/parser/statement/pragma_statement.hpp"
+#include "duckdb/parser/transformer.hpp"
+#include "duckdb/parser/expression/constant_expression.hpp"
+
+namespace duckdb {
+
+unique_ptr<PragmaStatement> Transformer::TransformImport(duckdb_libpgquery::PGImportStmt &stmt) {
+	auto result = make_uniq<PragmaStatement>();
+	result->info->name = "import_database";
+	result->info->parameters.emplace_back(make_uniq<ConstantExpression>(Value(stmt.filename)));
+	return result;
+}
+
+} // namespace duckdb
@@ -0,0 +1,156 @@
+#include "duckdb/catalog/catalog_entry/scalar_macro_catalog_entry.hpp"
+#include "duckdb/common/enums/expression_type.hpp"
+#include "duckdb/function/scalar_macro_function.hpp"
+#include "duckdb/parser/expression/function_expression.hpp"
+#include "duckdb/parser/expression/subquery_expression.hpp"
+#include "duckdb/parser/expression/window_expression.hpp"
+#include "duckdb/parser/parsed_expression_iterator.hpp"
+#include "duckdb/planner/expression_binder.hpp"
+
+namespace duckdb {
+
+void ExpressionBinder::ReplaceMacroParametersInLambda(FunctionExpression &function,
+                                                      vector<unordered_set<string>> &lambda_params) {
+
+	for (auto &child : function.children) {
+		if (child->GetExpressionClass() != ExpressionClass::LAMBDA) {
+			ReplaceMacroParameters(child, lambda_params);
+			continue;
+		}
+
+		// Special-handling for LHS lambda parameters.
+		// We do not replace them, and we add them to the lambda_params vector.
+		auto &lambda_expr = child->Cast<LambdaExpression>();
+		string error_message;
+		auto column_ref_expressions = lambda_expr.ExtractColumnRefExpressions(error_message);
+
+		if (!error_message.empty()) {
+			// Possibly a JSON function, replace both LHS and RHS.
+			ReplaceMacroParameters(lambda_expr.lhs, lambda_params);
+			ReplaceMacroParameters(lambda_expr.expr, lambda_params);
+			continue;
+		}
+
+		// Push the lambda parameter names of this level.
+		lambda_params.emplace_back();
+		for (const auto &column_ref_expr : column_ref_expressions) {
+			const auto &column_ref = column_ref_expr.get().Cast<ColumnRefExpression>();
+			lambda_params.back().emplace(column_ref.GetName());
+		}
+
+		// Only replace in the RHS of the expression.
+		ReplaceMacroParameters(lambda_expr.expr, lambda_params);
+
+		lambda_params.pop_back();
+	}
+}
+
+void ExpressionBinder::ReplaceMacroParameters(unique_ptr<ParsedExpression> &expr,
+                                              vector<unordered_set<string>> &lambda_params) {
+
+	switch (expr->GetExpressionClass()) {
+	case ExpressionClass::COLUMN_REF: {
+		// If the expression is a column reference, we replace it with its argument.
+		auto &col_ref = expr->Cast<ColumnRefExpression>();
+		if (LambdaExpression::IsLambdaParameter(lambda_params, col_ref.GetName())) {
+			return;
+		}
+
+		bool bind_macro_parameter = false;
+		if (col_ref.IsQualified()) {
+			if (col_ref.GetTableName().find(DummyBinding::DUMMY_NAME) != string::npos) {
+				bind_macro_parameter = true;
+			}
+		} else {
+			bind_macro_parameter = macro_binding->HasMatchingBinding(col_ref.GetColumnName());
+		}
+
+		if (bind_macro_parameter) {
+			D_ASSERT(macro_binding->HasMatchingBinding(col_ref.GetColumnName()));
+			expr = macro_binding->ParamToArg(col_ref);
+		}
+		return;
+	}
+	case ExpressionClass::FUNCTION: {
+		// Special-handling for lambdas, which are inside function expressions.
+		auto &function = expr->Cast<FunctionExpression>();
+		if (function.IsLambdaFunction()) {
+			return ReplaceMacroParametersInLambda(function, lambda_params);
+		}
+		break;
+	}
+	case ExpressionClass::SUBQUERY: {
+		auto &sq = (expr->Cast<SubqueryExpression>()).subquery;
+		ParsedExpressionIterator::EnumerateQueryNodeChildren(
+		    *sq->node, [&](unique_ptr<ParsedExpression> &child) { ReplaceMacroParameters(child, lambda_params); });
+		break;
+	}
+	default:
+		break;
+	}
+
+	ParsedExpressionIterator::EnumerateChildren(
+	    *expr, [&](unique_ptr<ParsedExpression> &child) { ReplaceMacroParameters(child, lambda_params); });
+}
+
+void ExpressionBinder::UnfoldMacroExpression(FunctionExpression &function, ScalarMacroCatalogEntry &macro_func,
+                                             unique_ptr<ParsedExpression> &expr, idx_t depth) {
+	// validate the arguments and separate positional and default arguments
+	vector<unique_ptr<ParsedExpression>> positional_arguments;
+	InsertionOrderPreservingMap<unique_ptr<ParsedExpression>> named_arguments;
+	binder.lambda_bindings = lambda_bindings;
+	auto bind_result = MacroFunction::BindMacroFunction(binder, macro_func.macros, macro_func.name, function,
+	                                                    positional_arguments, named_arguments, depth);
+	if (!bind_result.error.empty()) {
+		throw BinderException(*expr, bind_result.error);
+	}
+	auto &macro_def = macro_func.macros[bind_result.function_idx.GetIndex()]->Cast<ScalarMacroFunction>();
+
+	auto new_macro_binding =
+	    MacroFunction::CreateDummyBinding(macro_def, macro_func.name, positional_arguments, named_arguments);
+	macro_binding = new_macro_binding.get();
+
+	// replace current expression with stored macro expression
+	// special case: If this is a window function, then we need to return a window expression
+	if (expr->GetExpressionClass() == ExpressionClass::WINDOW) {
+		//	Only allowed if the expression is a function
+		if (macro_def.expression->GetExpressionType() != ExpressionType::FUNCTION) {
+			throw BinderException("Window function macros must be functions");
+		}
+		auto macro_copy = macro_def.expression->Copy();
+		auto &macro_expr = macro_copy->Cast<FunctionExpression>();
+		// Transfer the macro function attributes
+		auto &window_expr = expr->Cast<WindowExpression>();
+		window_expr.catalog = macro_expr.catalog;
+		window_expr.schema = macro_expr.schema;
+		window_expr.function_name = macro_expr.function_name;
+		window_expr.children = std::move(macro_expr.children);
+		window_expr.distinct = macro_expr.distinct;
+		window_expr.filter_expr = std::move(macro_expr.filter);
+		// TODO: transfer order_bys when window functions support them
+	} else {
+		expr = macro_def.expression->Copy();
+	}
+
+	// qualify only the macro parameters with a new empty binder that only knows the macro binding
+	auto dummy_binder = Binder::CreateBinder(context);
+	dummy_binder->macro_binding = new_macro_binding.get();
+	ExpressionBinder::QualifyColumnNames(*dummy_binder, expr);
+
+	// now replace the parameters
+	vector<unordered_set<string>> lambda_params;
+	ReplaceMacroParameters(expr, lambda_params);
+}
+
+BindResult ExpressionBinder::BindMacro(FunctionExpression &function, ScalarMacroCatalogEntry &macro_func, idx_t depth,
+                                       unique_ptr<ParsedExpression> &expr) {
+	auto stack_checker = StackCheck(*expr, 3);
+
+	// unfold the macro expression
+	UnfoldMacroExpression(function, macro_func, expr, depth);
+
+	// bind the unfolded macro
+	return BindExpression(expr, depth);
+}
+
+} // namespace duckdb
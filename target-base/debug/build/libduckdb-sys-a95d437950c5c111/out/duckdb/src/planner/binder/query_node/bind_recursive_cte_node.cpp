@@ -0,0 +1,80 @@
+#include "duckdb/parser/expression/constant_expression.hpp"
+#include "duckdb/parser/expression_map.hpp"
+#include "duckdb/parser/query_node/select_node.hpp"
+#include "duckdb/parser/query_node/recursive_cte_node.hpp"
+#include "duckdb/planner/binder.hpp"
+#include "duckdb/planner/query_node/bound_recursive_cte_node.hpp"
+#include "duckdb/planner/query_node/bound_select_node.hpp"
+
+namespace duckdb {
+
+unique_ptr<BoundQueryNode> Binder::BindNode(RecursiveCTENode &statement) {
+	auto result = make_uniq<BoundRecursiveCTENode>();
+
+	// first recursively visit the recursive CTE operations
+	// the left side is visited first and is added to the BindContext of the right side
+	D_ASSERT(statement.left);
+	D_ASSERT(statement.right);
+	if (statement.union_all && !statement.key_targets.empty()) {
+		throw BinderException("UNION ALL cannot be used with USING KEY in recursive CTE.");
+	}
+
+	result->ctename = statement.ctename;
+	result->union_all = statement.union_all;
+	result->setop_index = GenerateTableIndex();
+
+	result->left_binder = Binder::CreateBinder(context, this);
+	result->left = result->left_binder->BindNode(*statement.left);
+
+	// the result types of the CTE are the types of the LHS
+	result->types = result->left->types;
+	// names are picked from the LHS, unless aliases are explicitly specified
+	result->names = result->left->names;
+	for (idx_t i = 0; i < statement.aliases.size() && i < result->names.size(); i++) {
+		result->names[i] = statement.aliases[i];
+	}
+
+	// This allows the right side to reference the CTE recursively
+	bind_context.AddGenericBinding(result->setop_index, statement.ctename, result->names, result->types);
+
+	result->right_binder = Binder::CreateBinder(context, this);
+
+	// Add bindings of left side to temporary CTE bindings context
+	// If there is already a binding for the CTE, we need to remove it first
+	// as we are binding a CTE currently, we take precendence over the existing binding.
+	// This implements the CTE shadowing behavior.
+	result->right_binder->bind_context.RemoveCTEBinding(statement.ctename);
+	result->right_binder->bind_context.AddCTEBinding(result->setop_index, statement.ctename, result->names,
+	                                                 result->types, !statement.key_targets.empty());
+
+	result->right = result->right_binder->BindNode(*statement.right);
+	for (auto &c : result->left_binder->correlated_columns) {
+		result->right_binder->AddCorrelatedColumn(c);
+	}
+
+	// move the correlated expressions from the child binders to this binder
+	MoveCorrelatedExpressions(*result->left_binder);
+	MoveCorrelatedExpressions(*result->right_binder);
+
+	// bind specified keys to the referenced column
+	auto expression_binder = ExpressionBinder(*this, context);
+	for (unique_ptr<ParsedExpression> &expr : statement.key_targets) {
+		auto bound_expr = expression_binder.Bind(expr);
+		D_ASSERT(bound_expr->type == ExpressionType::BOUND_COLUMN_REF);
+		result->key_targets.push_back(std::move(bound_expr));
+	}
+
+	// now both sides have been bound we can resolve types
+	if (result->left->types.size() != result->right->types.size()) {
+		throw BinderException("Set operations can only apply to expressions with the "
+		                      "same number of result columns");
+	}
+
+	if (!statement.modifiers.empty()) {
+		throw NotImplementedException("FIXME: bind modifiers in recursive CTE");
+	}
+
+	return std::move(result);
+}
+
+} // namespace duckdb
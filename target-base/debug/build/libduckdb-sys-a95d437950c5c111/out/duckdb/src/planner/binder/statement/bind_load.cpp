@@ -0,0 +1,32 @@
+#include "duckdb/parser/statement/load_statement.hpp"
+#include "duckdb/planner/binder.hpp"
+#include "duckdb/planner/operator/logical_simple.hpp"
+#include "duckdb/main/extension_install_info.hpp"
+#include <algorithm>
+
+namespace duckdb {
+
+BoundStatement Binder::Bind(LoadStatement &stmt) {
+	BoundStatement result;
+	result.types = {LogicalType::BOOLEAN};
+	result.names = {"Success"};
+
+	// Ensure the repository exists if it's an alias
+	if (!stmt.info->repository.empty() && stmt.info->repo_is_alias) {
+		auto repository_url = ExtensionRepository::TryGetRepositoryUrl(stmt.info->repository);
+		if (repository_url.empty()) {
+			throw BinderException("'%s' is not a known repository name. Are you trying to query from a repository by "
+			                      "path? Use single quotes: `FROM '%s'`",
+			                      stmt.info->repository, stmt.info->repository);
+		}
+	}
+
+	result.plan = make_uniq<LogicalSimple>(LogicalOperatorType::LOGICAL_LOAD, std::move(stmt.info));
+
+	auto &properties = GetStatementProperties();
+	properties.allow_stream_result = false;
+	properties.return_type = StatementReturnType::NOTHING;
+	return result;
+}
+
+} // namespace duckdb
@@ -0,0 +1,12 @@
+#include "duckdb/planner/binder.hpp"
+#include "duckdb/planner/operator/logical_cteref.hpp"
+#include "duckdb/planner/tableref/bound_cteref.hpp"
+
+namespace duckdb {
+
+unique_ptr<LogicalOperator> Binder::CreatePlan(BoundCTERef &ref) {
+	return make_uniq<LogicalCTERef>(ref.bind_index, ref.cte_index, ref.types, ref.bound_columns, ref.materialized_cte,
+	                                ref.is_recurring);
+}
+
+} // namespace duckdb
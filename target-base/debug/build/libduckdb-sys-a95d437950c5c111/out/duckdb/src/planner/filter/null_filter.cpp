@@ -0,0 +1,65 @@
+#include "duckdb/planner/filter/null_filter.hpp"
+#include "duckdb/planner/expression/bound_operator_expression.hpp"
+#include "duckdb/storage/statistics/base_statistics.hpp"
+
+namespace duckdb {
+
+IsNullFilter::IsNullFilter() : TableFilter(TableFilterType::IS_NULL) {
+}
+
+FilterPropagateResult IsNullFilter::CheckStatistics(BaseStatistics &stats) const {
+	if (!stats.CanHaveNull()) {
+		// no null values are possible: always false
+		return FilterPropagateResult::FILTER_ALWAYS_FALSE;
+	}
+	if (!stats.CanHaveNoNull()) {
+		// no non-null values are possible: always true
+		return FilterPropagateResult::FILTER_ALWAYS_TRUE;
+	}
+	return FilterPropagateResult::NO_PRUNING_POSSIBLE;
+}
+
+string IsNullFilter::ToString(const string &column_name) const {
+	return column_name + " IS NULL";
+}
+
+unique_ptr<TableFilter> IsNullFilter::Copy() const {
+	return make_uniq<IsNullFilter>();
+}
+
+unique_ptr<Expression> IsNullFilter::ToExpression(const Expression &column) const {
+	auto result = make_uniq<BoundOperatorExpression>(ExpressionType::OPERATOR_IS_NULL, LogicalType::BOOLEAN);
+	result->children.push_back(column.Copy());
+	return std::move(result);
+}
+
+IsNotNullFilter::IsNotNullFilter() : TableFilter(TableFilterType::IS_NOT_NULL) {
+}
+
+FilterPropagateResult IsNotNullFilter::CheckStatistics(BaseStatistics &stats) const {
+	if (!stats.CanHaveNoNull()) {
+		// no non-null values are possible: always false
+		return FilterPropagateResult::FILTER_ALWAYS_FALSE;
+	}
+	if (!stats.CanHaveNull()) {
+		// no null values are possible: always true
+		return FilterPropagateResult::FILTER_ALWAYS_TRUE;
+	}
+	return FilterPropagateResult::NO_PRUNING_POSSIBLE;
+}
+
+string IsNotNullFilter::ToString(const string &column_name) const {
+	return column_name + " IS NOT NULL";
+}
+
+unique_ptr<TableFilter> IsNotNullFilter::Copy() const {
+	return make_uniq<IsNotNullFilter>();
+}
+
+unique_ptr<Expression> IsNotNullFilter::ToExpression(const Expression &column) const {
+	auto result = make_uniq<BoundOperatorExpression>(ExpressionType::OPERATOR_IS_NOT_NULL, LogicalType::BOOLEAN);
+	result->children.push_back(column.Copy());
+	return std::move(result);
+}
+
+} // namespace duckdb
@@ -0,0 +1,93 @@
+#include "duckdb/planner/operator/logical_aggregate.hpp"
+
+#include "duckdb/common/string_util.hpp"
+#include "duckdb/main/config.hpp"
+
+namespace duckdb {
+
+LogicalAggregate::LogicalAggregate(idx_t group_index, idx_t aggregate_index, vector<unique_ptr<Expression>> select_list)
+    : LogicalOperator(LogicalOperatorType::LOGICAL_AGGREGATE_AND_GROUP_BY, std::move(select_list)),
+      group_index(group_index), aggregate_index(aggregate_index), groupings_index(DConstants::INVALID_INDEX),
+      distinct_validity(TupleDataValidityType::CAN_HAVE_NULL_VALUES) {
+}
+
+void LogicalAggregate::ResolveTypes() {
+	D_ASSERT(groupings_index != DConstants::INVALID_INDEX || grouping_functions.empty());
+	for (auto &expr : groups) {
+		types.push_back(expr->return_type);
+	}
+	// get the chunk types from the projection list
+	for (auto &expr : expressions) {
+		types.push_back(expr->return_type);
+	}
+	for (idx_t i = 0; i < grouping_functions.size(); i++) {
+		types.emplace_back(LogicalType::BIGINT);
+	}
+}
+
+vector<ColumnBinding> LogicalAggregate::GetColumnBindings() {
+	D_ASSERT(groupings_index != DConstants::INVALID_INDEX || grouping_functions.empty());
+	vector<ColumnBinding> result;
+	result.reserve(groups.size() + expressions.size() + grouping_functions.size());
+	for (idx_t i = 0; i < groups.size(); i++) {
+		result.emplace_back(group_index, i);
+	}
+	for (idx_t i = 0; i < expressions.size(); i++) {
+		result.emplace_back(aggregate_index, i);
+	}
+	for (idx_t i = 0; i < grouping_functions.size(); i++) {
+		result.emplace_back(groupings_index, i);
+	}
+	return result;
+}
+
+InsertionOrderPreservingMap<string> LogicalAggregate::ParamsToString() const {
+	InsertionOrderPreservingMap<string> result;
+	string groups_info;
+	for (idx_t i = 0; i < groups.size(); i++) {
+		if (i > 0) {
+			groups_info += "\n";
+		}
+		groups_info += groups[i]->GetName();
+	}
+	result["Groups"] = groups_info;
+
+	string expressions_info;
+	for (idx_t i = 0; i < expressions.size(); i++) {
+		if (i > 0) {
+			expressions_info += "\n";
+		}
+		expressions_info += expressions[i]->GetName();
+	}
+	result["Expressions"] = expressions_info;
+	SetParamsEstimatedCardinality(result);
+	return result;
+}
+
+idx_t LogicalAggregate::EstimateCardinality(ClientContext &context) {
+	if (groups.empty()) {
+		// ungrouped aggregate
+		return 1;
+	}
+	return LogicalOperator::EstimateCardinality(context);
+}
+
+vector<idx_t> LogicalAggregate::GetTableIndex() const {
+	vector<idx_t> result {group_index, aggregate_index};
+	if (groupings_index != DConstants::INVALID_INDEX) {
+		result.push_back(groupings_index);
+	}
+	return result;
+}
+
+string LogicalAggregate::GetName() const {
+#ifdef DEBUG
+	if (DBConfigOptions::debug_print_bindings) {
+		return LogicalOperator::GetName() +
+		       StringUtil::Format(" #%llu, #%llu, #%llu", group_index, aggregate_index, groupings_index);
+	}
+#endif
+	return LogicalOperator::GetName();
+}
+
+} // namespace duckdb
@@ -0,0 +1,9 @@
+#include "duckdb/planner/operator/logical_reset.hpp"
+
+namespace duckdb {
+
+idx_t LogicalReset::EstimateCardinality(ClientContext &context) {
+	return 1;
+}
+
+} // namespace duckdb
@@ -0,0 +1,10 @@
+#include "duckdb/storage/compression/chimp/algorithm/chimp_utils.hpp"
+
+namespace duckdb {
+
+constexpr uint8_t ChimpConstants::Compression::LEADING_ROUND[];
+constexpr uint8_t ChimpConstants::Compression::LEADING_REPRESENTATION[];
+
+constexpr uint8_t ChimpConstants::Decompression::LEADING_REPRESENTATION[];
+
+} // namespace duckdb
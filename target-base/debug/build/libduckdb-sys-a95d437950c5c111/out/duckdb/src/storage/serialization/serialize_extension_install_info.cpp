@@ -0,0 +1,30 @@
+//===----------------------------------------------------------------------===//
+// This file is automatically generated by scripts/generate_serialization.py
+// Do not edit this file manually, your changes will be overwritten
+//===----------------------------------------------------------------------===//
+
+#include "duckdb/common/serializer/serializer.hpp"
+#include "duckdb/common/serializer/deserializer.hpp"
+#include "duckdb/main/extension_install_info.hpp"
+
+namespace duckdb {
+
+void ExtensionInstallInfo::Serialize(Serializer &serializer) const {
+	serializer.WriteProperty<ExtensionInstallMode>(100, "mode", mode);
+	serializer.WritePropertyWithDefault<string>(101, "full_path", full_path);
+	serializer.WritePropertyWithDefault<string>(102, "repository_url", repository_url);
+	serializer.WritePropertyWithDefault<string>(103, "version", version);
+	serializer.WritePropertyWithDefault<string>(104, "etag", etag);
+}
+
+unique_ptr<ExtensionInstallInfo> ExtensionInstallInfo::Deserialize(Deserializer &deserializer) {
+	auto result = duckdb::unique_ptr<ExtensionInstallInfo>(new ExtensionInstallInfo());
+	deserializer.ReadProperty<ExtensionInstallMode>(100, "mode", result->mode);
+	deserializer.ReadPropertyWithDefault<string>(101, "full_path", result->full_path);
+	deserializer.ReadPropertyWithDefault<string>(102, "repository_url", result->repository_url);
+	deserializer.ReadPropertyWithDefault<string>(103, "version", result->version);
+	deserializer.ReadPropertyWithDefault<string>(104, "etag", result->etag);
+	return result;
+}
+
+} // namespace duckdb
@@ -0,0 +1,24 @@
+//===----------------------------------------------------------------------===//
+// This file is automatically generated by scripts/generate_serialization.py
+// Do not edit this file manually, your changes will be overwritten
+//===----------------------------------------------------------------------===//
+
+#include "duckdb/common/serializer/serializer.hpp"
+#include "duckdb/common/serializer/deserializer.hpp"
+#include "duckdb/parser/statement/select_statement.hpp"
+
+namespace duckdb {
+
+void SelectStatement::Serialize(Serializer &serializer) const {
+	serializer.WritePropertyWithDefault<unique_ptr<QueryNode>>(100, "node", node);
+	serializer.WritePropertyWithDefault<case_insensitive_map_t<idx_t>>(101, "named_param_map", named_param_map);
+}
+
+unique_ptr<SelectStatement> SelectStatement::Deserialize(Deserializer &deserializer) {
+	auto result = duckdb::unique_ptr<SelectStatement>(new SelectStatement());
+	deserializer.ReadPropertyWithDefault<unique_ptr<QueryNode>>(100, "node", result->node);
+	deserializer.ReadPropertyWithDefault<case_insensitive_map_t<idx_t>>(101, "named_param_map", result->named_param_map);
+	return result;
+}
+
+} // namespace duckdb
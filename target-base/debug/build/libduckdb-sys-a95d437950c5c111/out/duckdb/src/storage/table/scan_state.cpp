@@ -0,0 +1,247 @@
+#include "duckdb/storage/table/scan_state.hpp"
+
+#include "duckdb/execution/adaptive_filter.hpp"
+#include "duckdb/storage/table/column_data.hpp"
+#include "duckdb/storage/table/column_segment.hpp"
+#include "duckdb/storage/table/row_group.hpp"
+#include "duckdb/storage/table/row_group_collection.hpp"
+#include "duckdb/storage/table/row_group_segment_tree.hpp"
+#include "duckdb/transaction/duck_transaction.hpp"
+
+namespace duckdb {
+
+TableScanState::TableScanState() : table_state(*this), local_state(*this) {
+}
+
+TableScanState::~TableScanState() {
+}
+
+void TableScanState::Initialize(vector<StorageIndex> column_ids_p, optional_ptr<ClientContext> context,
+                                optional_ptr<TableFilterSet> table_filters,
+                                optional_ptr<SampleOptions> table_sampling) {
+	this->column_ids = std::move(column_ids_p);
+	if (table_filters) {
+		filters.Initialize(*context, *table_filters, column_ids);
+	}
+	if (table_sampling) {
+		sampling_info.do_system_sample = table_sampling->method == SampleMethod::SYSTEM_SAMPLE;
+		sampling_info.sample_rate = table_sampling->sample_size.GetValue<double>() / 100.0;
+		if (table_sampling->seed.IsValid()) {
+			table_state.random.SetSeed(table_sampling->seed.GetIndex());
+		}
+	}
+}
+
+const vector<StorageIndex> &TableScanState::GetColumnIds() {
+	D_ASSERT(!column_ids.empty());
+	return column_ids;
+}
+
+ScanFilterInfo::~ScanFilterInfo() {
+}
+
+ScanFilterInfo &TableScanState::GetFilterInfo() {
+	return filters;
+}
+
+ScanSamplingInfo &TableScanState::GetSamplingInfo() {
+	return sampling_info;
+}
+
+ScanFilter::ScanFilter(ClientContext &context, idx_t index, const vector<StorageIndex> &column_ids, TableFilter &filter)
+    : scan_column_index(index), table_column_index(column_ids[index].GetPrimaryIndex()), filter(filter),
+      always_true(false) {
+	filter_state = TableFilterState::Initialize(context, filter);
+}
+
+void ScanFilterInfo::Initialize(ClientContext &context, TableFilterSet &filters,
+                                const vector<StorageIndex> &column_ids) {
+	D_ASSERT(!filters.filters.empty());
+	table_filters = &filters;
+	adaptive_filter = make_uniq<AdaptiveFilter>(filters);
+	filter_list.reserve(filters.filters.size());
+	for (auto &entry : filters.filters) {
+		filter_list.emplace_back(context, entry.first, column_ids, *entry.second);
+	}
+	column_has_filter.reserve(column_ids.size());
+	for (idx_t col_idx = 0; col_idx < column_ids.size(); col_idx++) {
+		bool has_filter = table_filters->filters.find(col_idx) != table_filters->filters.end();
+		column_has_filter.push_back(has_filter);
+	}
+	base_column_has_filter = column_has_filter;
+}
+
+bool ScanFilterInfo::ColumnHasFilters(idx_t column_idx) {
+	if (column_idx < column_has_filter.size()) {
+		return column_has_filter[column_idx];
+	} else {
+		return false;
+	}
+}
+
+bool ScanFilterInfo::HasFilters() const {
+	if (!table_filters) {
+		// no filters
+		return false;
+	}
+	// if we have filters - check if we need to check any of them
+	return always_true_filters < filter_list.size();
+}
+
+void ScanFilterInfo::CheckAllFilters() {
+	always_true_filters = 0;
+	// reset the "column_has_filter" bitmask to the original
+	for (idx_t col_idx = 0; col_idx < column_has_filter.size(); col_idx++) {
+		column_has_filter[col_idx] = base_column_has_filter[col_idx];
+	}
+	// set "always_true" in the individual filters to false
+	for (auto &filter : filter_list) {
+		filter.always_true = false;
+	}
+}
+
+void ScanFilterInfo::SetFilterAlwaysTrue(idx_t filter_idx) {
+	auto &filter = filter_list[filter_idx];
+	if (filter.always_true) {
+		return;
+	}
+	filter.always_true = true;
+	column_has_filter[filter.scan_column_index] = false;
+	always_true_filters++;
+}
+
+optional_ptr<AdaptiveFilter> ScanFilterInfo::GetAdaptiveFilter() {
+	return adaptive_filter.get();
+}
+
+AdaptiveFilterState ScanFilterInfo::BeginFilter() const {
+	if (!adaptive_filter) {
+		return AdaptiveFilterState();
+	}
+	return adaptive_filter->BeginFilter();
+}
+
+void ScanFilterInfo::EndFilter(AdaptiveFilterState state) {
+	if (!adaptive_filter) {
+		return;
+	}
+	adaptive_filter->EndFilter(state);
+}
+
+void ColumnScanState::NextInternal(idx_t count) {
+	if (!current) {
+		//! There is no column segment
+		return;
+	}
+	row_index += count;
+	while (row_index >= current->start + current->count) {
+		current = segment_tree->GetNextSegment(current);
+		initialized = false;
+		segment_checked = false;
+		if (!current) {
+			break;
+		}
+	}
+	D_ASSERT(!current || (row_index >= current->start && row_index < current->start + current->count));
+}
+
+void ColumnScanState::Next(idx_t count) {
+	NextInternal(count);
+	for (auto &child_state : child_states) {
+		child_state.Next(count);
+	}
+}
+
+const vector<StorageIndex> &CollectionScanState::GetColumnIds() {
+	return parent.GetColumnIds();
+}
+
+TableFilterSet &GetFilters();
+
+ScanFilterInfo &CollectionScanState::GetFilterInfo() {
+	return parent.GetFilterInfo();
+}
+
+ScanSamplingInfo &CollectionScanState::GetSamplingInfo() {
+	return parent.GetSamplingInfo();
+}
+
+TableScanOptions &CollectionScanState::GetOptions() {
+	return parent.options;
+}
+
+ParallelCollectionScanState::ParallelCollectionScanState()
+    : collection(nullptr), current_row_group(nullptr), processed_rows(0) {
+}
+
+CollectionScanState::CollectionScanState(TableScanState &parent_p)
+    : row_group(nullptr), vector_index(0), max_row_group_row(0), row_groups(nullptr), max_row(0), batch_index(0),
+      valid_sel(STANDARD_VECTOR_SIZE), random(-1), parent(parent_p) {
+}
+
+bool CollectionScanState::Scan(DuckTransaction &transaction, DataChunk &result) {
+	while (row_group) {
+		row_group->Scan(transaction, *this, result);
+		if (result.size() > 0) {
+			return true;
+		} else if (max_row <= row_group->start + row_group->count) {
+			row_group = nullptr;
+			return false;
+		} else {
+			do {
+				row_group = row_groups->GetNextSegment(row_group);
+				if (row_group) {
+					if (row_group->start >= max_row) {
+						row_group = nullptr;
+						break;
+					}
+					bool scan_row_group = row_group->InitializeScan(*this);
+					if (scan_row_group) {
+						// scan this row group
+						break;
+					}
+				}
+			} while (row_group);
+		}
+	}
+	return false;
+}
+
+bool CollectionScanState::ScanCommitted(DataChunk &result, SegmentLock &l, TableScanType type) {
+	while (row_group) {
+		row_group->ScanCommitted(*this, result, type);
+		if (result.size() > 0) {
+			return true;
+		} else {
+			row_group = row_groups->GetNextSegment(l, row_group);
+			if (row_group) {
+				row_group->InitializeScan(*this);
+			}
+		}
+	}
+	return false;
+}
+
+bool CollectionScanState::ScanCommitted(DataChunk &result, TableScanType type) {
+	while (row_group) {
+		row_group->ScanCommitted(*this, result, type);
+		if (result.size() > 0) {
+			return true;
+		} else {
+			row_group = row_groups->GetNextSegment(row_group);
+			if (row_group) {
+				row_group->InitializeScan(*this);
+			}
+		}
+	}
+	return false;
+}
+
+PrefetchState::~PrefetchState() {
+}
+
+void PrefetchState::AddBlock(shared_ptr<BlockHandle> block) {
+	blocks.push_back(std::move(block));
+}
+
+} // namespace duckdb
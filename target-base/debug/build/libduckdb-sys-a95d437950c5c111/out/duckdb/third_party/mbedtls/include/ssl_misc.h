@@ -0,0 +1 @@
+// dummy file to make amalgamation happy
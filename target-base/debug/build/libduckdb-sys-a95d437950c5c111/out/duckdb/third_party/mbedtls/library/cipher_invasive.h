@@ -0,0 +1 @@
+// dummy file
\ No newline at end of file
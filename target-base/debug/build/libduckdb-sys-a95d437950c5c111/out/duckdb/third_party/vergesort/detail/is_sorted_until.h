@@ -0,0 +1,51 @@
+/*
+ * vergesort.h - General-purpose hybrid sort
+ *
+ * The MIT License (MIT)
+ *
+ * Copyright (c) 2015-2016 Morwenn <morwenn29@hotmail.fr>
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+#ifndef VERGESORT_DETAIL_IS_SORTED_UNTIL_H_
+#define VERGESORT_DETAIL_IS_SORTED_UNTIL_H_
+
+namespace duckdb_vergesort
+{
+namespace detail
+{
+    // C++03 implementation of std::is_sorted_until
+    template<typename ForwardIterator, typename Compare>
+    ForwardIterator is_sorted_until(ForwardIterator first, ForwardIterator last,
+                                    Compare compare)
+    {
+        if (first != last) {
+            ForwardIterator next = first;
+            while (++next != last) {
+                if (compare(*next, *first)) {
+                    return next;
+                }
+                first = next;
+            }
+        }
+        return last;
+    }
+}}
+
+#endif // VERGESORT_DETAIL_IS_SORTED_UNTIL_H_
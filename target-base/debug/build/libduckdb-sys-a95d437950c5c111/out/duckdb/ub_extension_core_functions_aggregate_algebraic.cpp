@@ -0,0 +1,8 @@
+#include "extension/core_functions/aggregate/algebraic/corr.cpp"
+
+#include "extension/core_functions/aggregate/algebraic/stddev.cpp"
+
+#include "extension/core_functions/aggregate/algebraic/avg.cpp"
+
+#include "extension/core_functions/aggregate/algebraic/covar.cpp"
+
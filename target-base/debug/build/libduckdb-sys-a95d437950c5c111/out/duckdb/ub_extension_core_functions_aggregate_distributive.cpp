@@ -0,0 +1,20 @@
+#include "extension/core_functions/aggregate/distributive/kurtosis.cpp"
+
+#include "extension/core_functions/aggregate/distributive/string_agg.cpp"
+
+#include "extension/core_functions/aggregate/distributive/sum.cpp"
+
+#include "extension/core_functions/aggregate/distributive/arg_min_max.cpp"
+
+#include "extension/core_functions/aggregate/distributive/approx_count.cpp"
+
+#include "extension/core_functions/aggregate/distributive/skew.cpp"
+
+#include "extension/core_functions/aggregate/distributive/bitagg.cpp"
+
+#include "extension/core_functions/aggregate/distributive/bitstring_agg.cpp"
+
+#include "extension/core_functions/aggregate/distributive/product.cpp"
+
+#include "extension/core_functions/aggregate/distributive/bool.cpp"
+
@@ -0,0 +1,6 @@
+#include "extension/core_functions/aggregate/nested/binned_histogram.cpp"
+
+#include "extension/core_functions/aggregate/nested/list.cpp"
+
+#include "extension/core_functions/aggregate/nested/histogram.cpp"
+
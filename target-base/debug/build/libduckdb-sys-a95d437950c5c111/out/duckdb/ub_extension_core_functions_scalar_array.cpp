@@ -0,0 +1,4 @@
+#include "extension/core_functions/scalar/array/array_functions.cpp"
+
+#include "extension/core_functions/scalar/array/array_value.cpp"
+
@@ -0,0 +1,22 @@
+#include "extension/core_functions/scalar/list/flatten.cpp"
+
+#include "extension/core_functions/scalar/list/list_transform.cpp"
+
+#include "extension/core_functions/scalar/list/range.cpp"
+
+#include "extension/core_functions/scalar/list/list_value.cpp"
+
+#include "extension/core_functions/scalar/list/list_filter.cpp"
+
+#include "extension/core_functions/scalar/list/list_has_any_or_all.cpp"
+
+#include "extension/core_functions/scalar/list/list_aggregates.cpp"
+
+#include "extension/core_functions/scalar/list/list_distance.cpp"
+
+#include "extension/core_functions/scalar/list/array_slice.cpp"
+
+#include "extension/core_functions/scalar/list/list_sort.cpp"
+
+#include "extension/core_functions/scalar/list/list_reduce.cpp"
+
@@ -0,0 +1,14 @@
+#include "extension/core_functions/scalar/map/map_keys_values.cpp"
+
+#include "extension/core_functions/scalar/map/map_extract.cpp"
+
+#include "extension/core_functions/scalar/map/map_from_entries.cpp"
+
+#include "extension/core_functions/scalar/map/map_entries.cpp"
+
+#include "extension/core_functions/scalar/map/map.cpp"
+
+#include "extension/core_functions/scalar/map/map_concat.cpp"
+
+#include "extension/core_functions/scalar/map/cardinality.cpp"
+
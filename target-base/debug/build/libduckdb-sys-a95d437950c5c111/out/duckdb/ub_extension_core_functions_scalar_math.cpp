@@ -0,0 +1,2 @@
+#include "extension/core_functions/scalar/math/numeric.cpp"
+
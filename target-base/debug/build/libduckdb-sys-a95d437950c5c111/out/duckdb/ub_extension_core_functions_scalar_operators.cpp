@@ -0,0 +1,2 @@
+#include "extension/core_functions/scalar/operators/bitwise.cpp"
+
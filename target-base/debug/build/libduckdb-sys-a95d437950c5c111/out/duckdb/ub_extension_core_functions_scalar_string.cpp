@@ -0,0 +1,48 @@
+#include "extension/core_functions/scalar/string/starts_with.cpp"
+
+#include "extension/core_functions/scalar/string/jaccard.cpp"
+
+#include "extension/core_functions/scalar/string/levenshtein.cpp"
+
+#include "extension/core_functions/scalar/string/damerau_levenshtein.cpp"
+
+#include "extension/core_functions/scalar/string/bar.cpp"
+
+#include "extension/core_functions/scalar/string/printf.cpp"
+
+#include "extension/core_functions/scalar/string/replace.cpp"
+
+#include "extension/core_functions/scalar/string/hamming.cpp"
+
+#include "extension/core_functions/scalar/string/instr.cpp"
+
+#include "extension/core_functions/scalar/string/ascii.cpp"
+
+#include "extension/core_functions/scalar/string/reverse.cpp"
+
+#include "extension/core_functions/scalar/string/url_encode.cpp"
+
+#include "extension/core_functions/scalar/string/parse_path.cpp"
+
+#include "extension/core_functions/scalar/string/left_right.cpp"
+
+#include "extension/core_functions/scalar/string/to_base.cpp"
+
+#include "extension/core_functions/scalar/string/pad.cpp"
+
+#include "extension/core_functions/scalar/string/trim.cpp"
+
+#include "extension/core_functions/scalar/string/format_bytes.cpp"
+
+#include "extension/core_functions/scalar/string/hex.cpp"
+
+#include "extension/core_functions/scalar/string/repeat.cpp"
+
+#include "extension/core_functions/scalar/string/translate.cpp"
+
+#include "extension/core_functions/scalar/string/chr.cpp"
+
+#include "extension/core_functions/scalar/string/unicode.cpp"
+
+#include "extension/core_functions/scalar/string/jaro_winkler.cpp"
+
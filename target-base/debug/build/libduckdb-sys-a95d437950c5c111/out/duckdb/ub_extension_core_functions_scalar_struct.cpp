@@ -0,0 +1,4 @@
+#include "extension/core_functions/scalar/struct/struct_insert.cpp"
+
+#include "extension/core_functions/scalar/struct/struct_update.cpp"
+
@@ -0,0 +1,6 @@
+#include "src/catalog/catalog_entry/dependency/dependency_entry.cpp"
+
+#include "src/catalog/catalog_entry/dependency/dependency_subject_entry.cpp"
+
+#include "src/catalog/catalog_entry/dependency/dependency_dependent_entry.cpp"
+
@@ -0,0 +1,78 @@
+#include "src/common/allocator.cpp"
+
+#include "src/common/assert.cpp"
+
+#include "src/common/bind_helpers.cpp"
+
+#include "src/common/box_renderer.cpp"
+
+#include "src/common/cgroups.cpp"
+
+#include "src/common/csv_writer.cpp"
+
+#include "src/common/complex_json.cpp"
+
+#include "src/common/compressed_file_system.cpp"
+
+#include "src/common/constants.cpp"
+
+#include "src/common/checksum.cpp"
+
+#include "src/common/encryption_functions.cpp"
+
+#include "src/common/encryption_key_manager.cpp"
+
+#include "src/common/encryption_state.cpp"
+
+#include "src/common/exception.cpp"
+
+#include "src/common/exception_format_value.cpp"
+
+#include "src/common/extra_type_info.cpp"
+
+#include "src/common/file_buffer.cpp"
+
+#include "src/common/file_system.cpp"
+
+#include "src/common/filename_pattern.cpp"
+
+#include "src/common/fsst.cpp"
+
+#include "src/common/gzip_file_system.cpp"
+
+#include "src/common/hive_partitioning.cpp"
+
+#include "src/common/pipe_file_system.cpp"
+
+#include "src/common/local_file_system.cpp"
+
+#include "src/common/error_data.cpp"
+
+#include "src/common/opener_file_system.cpp"
+
+#include "src/common/printer.cpp"
+
+#include "src/common/radix_partitioning.cpp"
+
+#include "src/common/re2_regex.cpp"
+
+#include "src/common/random_engine.cpp"
+
+#include "src/common/stacktrace.cpp"
+
+#include "src/common/string_util.cpp"
+
+#include "src/common/enum_util.cpp"
+
+#include "src/common/render_tree.cpp"
+
+#include "src/common/tree_renderer.cpp"
+
+#include "src/common/types.cpp"
+
+#include "src/common/bignum.cpp"
+
+#include "src/common/virtual_file_system.cpp"
+
+#include "src/common/windows_util.cpp"
+
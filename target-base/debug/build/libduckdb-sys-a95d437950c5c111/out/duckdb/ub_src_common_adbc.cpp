@@ -0,0 +1,4 @@
+#include "src/common/adbc/adbc.cpp"
+
+#include "src/common/adbc/driver_manager.cpp"
+
@@ -0,0 +1,8 @@
+#include "src/common/adbc/nanoarrow/metadata.cpp"
+
+#include "src/common/adbc/nanoarrow/schema.cpp"
+
+#include "src/common/adbc/nanoarrow/allocator.cpp"
+
+#include "src/common/adbc/nanoarrow/single_batch_array_stream.cpp"
+
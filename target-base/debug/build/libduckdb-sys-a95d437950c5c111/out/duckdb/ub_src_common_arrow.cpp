@@ -0,0 +1,20 @@
+#include "src/common/arrow/arrow_appender.cpp"
+
+#include "src/common/arrow/arrow_converter.cpp"
+
+#include "src/common/arrow/arrow_type_extension.cpp"
+
+#include "src/common/arrow/arrow_merge_event.cpp"
+
+#include "src/common/arrow/arrow_query_result.cpp"
+
+#include "src/common/arrow/arrow_util.cpp"
+
+#include "src/common/arrow/arrow_wrapper.cpp"
+
+#include "src/common/arrow/physical_arrow_collector.cpp"
+
+#include "src/common/arrow/physical_arrow_batch_collector.cpp"
+
+#include "src/common/arrow/schema_metadata.cpp"
+
@@ -0,0 +1,2 @@
+#include "src/common/crypto/md5.cpp"
+
@@ -0,0 +1,8 @@
+#include "src/common/exception/binder_exception.cpp"
+
+#include "src/common/exception/catalog_exception.cpp"
+
+#include "src/common/exception/conversion_exception.cpp"
+
+#include "src/common/exception/parser_exception.cpp"
+
@@ -0,0 +1,6 @@
+#include "src/common/operator/cast_operators.cpp"
+
+#include "src/common/operator/convert_to_string.cpp"
+
+#include "src/common/operator/string_cast.cpp"
+
@@ -0,0 +1,6 @@
+#include "src/common/progress_bar/progress_bar.cpp"
+
+#include "src/common/progress_bar/terminal_progress_bar_display.cpp"
+
+#include "src/common/progress_bar/unscented_kalman_filter.cpp"
+
@@ -0,0 +1,16 @@
+#include "src/common/row_operations/row_aggregate.cpp"
+
+#include "src/common/row_operations/row_scatter.cpp"
+
+#include "src/common/row_operations/row_gather.cpp"
+
+#include "src/common/row_operations/row_matcher.cpp"
+
+#include "src/common/row_operations/row_external.cpp"
+
+#include "src/common/row_operations/row_radix_scatter.cpp"
+
+#include "src/common/row_operations/row_heap_scatter.cpp"
+
+#include "src/common/row_operations/row_heap_gather.cpp"
+
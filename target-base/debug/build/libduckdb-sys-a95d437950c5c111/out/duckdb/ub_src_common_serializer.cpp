@@ -0,0 +1,12 @@
+#include "src/common/serializer/binary_serializer.cpp"
+
+#include "src/common/serializer/binary_deserializer.cpp"
+
+#include "src/common/serializer/buffered_file_reader.cpp"
+
+#include "src/common/serializer/buffered_file_writer.cpp"
+
+#include "src/common/serializer/memory_stream.cpp"
+
+#include "src/common/serializer/serializer.cpp"
+
@@ -0,0 +1,12 @@
+#include "src/common/sort/comparators.cpp"
+
+#include "src/common/sort/merge_sorter.cpp"
+
+#include "src/common/sort/partition_state.cpp"
+
+#include "src/common/sort/radix_sort.cpp"
+
+#include "src/common/sort/sort_state.cpp"
+
+#include "src/common/sort/sorted_block.cpp"
+
@@ -0,0 +1,8 @@
+#include "src/common/sorting/hashed_sort.cpp"
+
+#include "src/common/sorting/sort.cpp"
+
+#include "src/common/sorting/sorted_run.cpp"
+
+#include "src/common/sorting/sorted_run_merger.cpp"
+
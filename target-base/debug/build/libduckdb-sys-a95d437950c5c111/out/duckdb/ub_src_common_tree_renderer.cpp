@@ -0,0 +1,12 @@
+#include "src/common/tree_renderer/json_tree_renderer.cpp"
+
+#include "src/common/tree_renderer/html_tree_renderer.cpp"
+
+#include "src/common/tree_renderer/graphviz_tree_renderer.cpp"
+
+#include "src/common/tree_renderer/text_tree_renderer.cpp"
+
+#include "src/common/tree_renderer/yaml_tree_renderer.cpp"
+
+#include "src/common/tree_renderer/tree_renderer.cpp"
+
@@ -0,0 +1,56 @@
+#include "src/common/types/batched_data_collection.cpp"
+
+#include "src/common/types/bit.cpp"
+
+#include "src/common/types/blob.cpp"
+
+#include "src/common/types/cast_helpers.cpp"
+
+#include "src/common/types/conflict_manager.cpp"
+
+#include "src/common/types/conflict_info.cpp"
+
+#include "src/common/types/data_chunk.cpp"
+
+#include "src/common/types/date.cpp"
+
+#include "src/common/types/decimal.cpp"
+
+#include "src/common/types/hash.cpp"
+
+#include "src/common/types/hugeint.cpp"
+
+#include "src/common/types/uhugeint.cpp"
+
+#include "src/common/types/uuid.cpp"
+
+#include "src/common/types/hyperloglog.cpp"
+
+#include "src/common/types/interval.cpp"
+
+#include "src/common/types/list_segment.cpp"
+
+#include "src/common/types/selection_vector.cpp"
+
+#include "src/common/types/string_heap.cpp"
+
+#include "src/common/types/string_type.cpp"
+
+#include "src/common/types/timestamp.cpp"
+
+#include "src/common/types/time.cpp"
+
+#include "src/common/types/validity_mask.cpp"
+
+#include "src/common/types/value.cpp"
+
+#include "src/common/types/bignum.cpp"
+
+#include "src/common/types/vector_buffer.cpp"
+
+#include "src/common/types/vector.cpp"
+
+#include "src/common/types/vector_cache.cpp"
+
+#include "src/common/types/vector_constants.cpp"
+
@@ -0,0 +1,10 @@
+#include "src/common/types/column/column_data_allocator.cpp"
+
+#include "src/common/types/column/column_data_collection.cpp"
+
+#include "src/common/types/column/column_data_collection_segment.cpp"
+
+#include "src/common/types/column/column_data_consumer.cpp"
+
+#include "src/common/types/column/partitioned_column_data.cpp"
+
@@ -0,0 +1,2 @@
+#include "src/common/value_operations/comparison_operations.cpp"
+
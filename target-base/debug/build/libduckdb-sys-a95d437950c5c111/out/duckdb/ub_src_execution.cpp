@@ -0,0 +1,22 @@
+#include "src/execution/adaptive_filter.cpp"
+
+#include "src/execution/aggregate_hashtable.cpp"
+
+#include "src/execution/base_aggregate_hashtable.cpp"
+
+#include "src/execution/column_binding_resolver.cpp"
+
+#include "src/execution/expression_executor.cpp"
+
+#include "src/execution/expression_executor_state.cpp"
+
+#include "src/execution/join_hashtable.cpp"
+
+#include "src/execution/perfect_aggregate_hashtable.cpp"
+
+#include "src/execution/physical_operator.cpp"
+
+#include "src/execution/physical_plan_generator.cpp"
+
+#include "src/execution/radix_partitioned_hashtable.cpp"
+
@@ -0,0 +1,20 @@
+#include "src/execution/expression_executor/execute_between.cpp"
+
+#include "src/execution/expression_executor/execute_case.cpp"
+
+#include "src/execution/expression_executor/execute_cast.cpp"
+
+#include "src/execution/expression_executor/execute_comparison.cpp"
+
+#include "src/execution/expression_executor/execute_conjunction.cpp"
+
+#include "src/execution/expression_executor/execute_constant.cpp"
+
+#include "src/execution/expression_executor/execute_function.cpp"
+
+#include "src/execution/expression_executor/execute_operator.cpp"
+
+#include "src/execution/expression_executor/execute_parameter.cpp"
+
+#include "src/execution/expression_executor/execute_reference.cpp"
+
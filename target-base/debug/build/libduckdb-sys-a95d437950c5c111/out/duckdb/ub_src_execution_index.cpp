@@ -0,0 +1,10 @@
+#include "src/execution/index/fixed_size_allocator.cpp"
+
+#include "src/execution/index/fixed_size_buffer.cpp"
+
+#include "src/execution/index/unbound_index.cpp"
+
+#include "src/execution/index/index_type_set.cpp"
+
+#include "src/execution/index/bound_index.cpp"
+
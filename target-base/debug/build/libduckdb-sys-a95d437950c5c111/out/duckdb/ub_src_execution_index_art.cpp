@@ -0,0 +1,28 @@
+#include "src/execution/index/art/art_builder.cpp"
+
+#include "src/execution/index/art/art.cpp"
+
+#include "src/execution/index/art/art_key.cpp"
+
+#include "src/execution/index/art/art_merger.cpp"
+
+#include "src/execution/index/art/base_leaf.cpp"
+
+#include "src/execution/index/art/base_node.cpp"
+
+#include "src/execution/index/art/iterator.cpp"
+
+#include "src/execution/index/art/leaf.cpp"
+
+#include "src/execution/index/art/node.cpp"
+
+#include "src/execution/index/art/node256.cpp"
+
+#include "src/execution/index/art/node256_leaf.cpp"
+
+#include "src/execution/index/art/node48.cpp"
+
+#include "src/execution/index/art/plan_art.cpp"
+
+#include "src/execution/index/art/prefix.cpp"
+
@@ -0,0 +1,4 @@
+#include "src/execution/nested_loop_join/nested_loop_join_inner.cpp"
+
+#include "src/execution/nested_loop_join/nested_loop_join_mark.cpp"
+
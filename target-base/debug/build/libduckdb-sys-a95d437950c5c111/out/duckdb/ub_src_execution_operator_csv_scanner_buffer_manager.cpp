@@ -0,0 +1,6 @@
+#include "src/execution/operator/csv_scanner/buffer_manager/csv_buffer.cpp"
+
+#include "src/execution/operator/csv_scanner/buffer_manager/csv_buffer_manager.cpp"
+
+#include "src/execution/operator/csv_scanner/buffer_manager/csv_file_handle.cpp"
+
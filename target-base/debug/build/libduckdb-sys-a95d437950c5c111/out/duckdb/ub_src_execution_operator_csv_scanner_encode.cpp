@@ -0,0 +1,2 @@
+#include "src/execution/operator/csv_scanner/encode/csv_encoder.cpp"
+
@@ -0,0 +1,14 @@
+#include "src/execution/operator/csv_scanner/sniffer/csv_sniffer.cpp"
+
+#include "src/execution/operator/csv_scanner/sniffer/dialect_detection.cpp"
+
+#include "src/execution/operator/csv_scanner/sniffer/header_detection.cpp"
+
+#include "src/execution/operator/csv_scanner/sniffer/set_columns.cpp"
+
+#include "src/execution/operator/csv_scanner/sniffer/type_detection.cpp"
+
+#include "src/execution/operator/csv_scanner/sniffer/type_refinement.cpp"
+
+#include "src/execution/operator/csv_scanner/sniffer/type_replacement.cpp"
+
@@ -0,0 +1,4 @@
+#include "src/execution/operator/csv_scanner/state_machine/csv_state_machine.cpp"
+
+#include "src/execution/operator/csv_scanner/state_machine/csv_state_machine_cache.cpp"
+
@@ -0,0 +1,6 @@
+#include "src/execution/operator/csv_scanner/table_function/csv_file_scanner.cpp"
+
+#include "src/execution/operator/csv_scanner/table_function/global_csv_state.cpp"
+
+#include "src/execution/operator/csv_scanner/table_function/csv_multi_file_info.cpp"
+
@@ -0,0 +1,6 @@
+#include "src/execution/operator/csv_scanner/util/csv_error.cpp"
+
+#include "src/execution/operator/csv_scanner/util/csv_reader_options.cpp"
+
+#include "src/execution/operator/csv_scanner/util/csv_validator.cpp"
+
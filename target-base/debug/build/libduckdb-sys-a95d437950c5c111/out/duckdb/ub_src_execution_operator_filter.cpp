@@ -0,0 +1,2 @@
+#include "src/execution/operator/filter/physical_filter.cpp"
+
@@ -0,0 +1,46 @@
+#include "src/execution/operator/helper/physical_batch_collector.cpp"
+
+#include "src/execution/operator/helper/physical_buffered_collector.cpp"
+
+#include "src/execution/operator/helper/physical_buffered_batch_collector.cpp"
+
+#include "src/execution/operator/helper/physical_create_secret.cpp"
+
+#include "src/execution/operator/helper/physical_execute.cpp"
+
+#include "src/execution/operator/helper/physical_explain_analyze.cpp"
+
+#include "src/execution/operator/helper/physical_limit.cpp"
+
+#include "src/execution/operator/helper/physical_limit_percent.cpp"
+
+#include "src/execution/operator/helper/physical_load.cpp"
+
+#include "src/execution/operator/helper/physical_materialized_collector.cpp"
+
+#include "src/execution/operator/helper/physical_pragma.cpp"
+
+#include "src/execution/operator/helper/physical_prepare.cpp"
+
+#include "src/execution/operator/helper/physical_reservoir_sample.cpp"
+
+#include "src/execution/operator/helper/physical_result_collector.cpp"
+
+#include "src/execution/operator/helper/physical_set.cpp"
+
+#include "src/execution/operator/helper/physical_set_variable.cpp"
+
+#include "src/execution/operator/helper/physical_reset.cpp"
+
+#include "src/execution/operator/helper/physical_streaming_limit.cpp"
+
+#include "src/execution/operator/helper/physical_streaming_sample.cpp"
+
+#include "src/execution/operator/helper/physical_transaction.cpp"
+
+#include "src/execution/operator/helper/physical_update_extensions.cpp"
+
+#include "src/execution/operator/helper/physical_vacuum.cpp"
+
+#include "src/execution/operator/helper/physical_verify_vector.cpp"
+
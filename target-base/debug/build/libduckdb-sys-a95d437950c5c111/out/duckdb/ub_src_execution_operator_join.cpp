@@ -0,0 +1,32 @@
+#include "src/execution/operator/join/outer_join_marker.cpp"
+
+#include "src/execution/operator/join/physical_asof_join.cpp"
+
+#include "src/execution/operator/join/physical_blockwise_nl_join.cpp"
+
+#include "src/execution/operator/join/physical_comparison_join.cpp"
+
+#include "src/execution/operator/join/physical_cross_product.cpp"
+
+#include "src/execution/operator/join/physical_delim_join.cpp"
+
+#include "src/execution/operator/join/physical_left_delim_join.cpp"
+
+#include "src/execution/operator/join/physical_hash_join.cpp"
+
+#include "src/execution/operator/join/physical_iejoin.cpp"
+
+#include "src/execution/operator/join/physical_join.cpp"
+
+#include "src/execution/operator/join/physical_nested_loop_join.cpp"
+
+#include "src/execution/operator/join/perfect_hash_join_executor.cpp"
+
+#include "src/execution/operator/join/physical_piecewise_merge_join.cpp"
+
+#include "src/execution/operator/join/physical_positional_join.cpp"
+
+#include "src/execution/operator/join/physical_range_join.cpp"
+
+#include "src/execution/operator/join/physical_right_delim_join.cpp"
+
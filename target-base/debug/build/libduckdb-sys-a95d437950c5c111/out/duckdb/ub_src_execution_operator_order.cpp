@@ -0,0 +1,4 @@
+#include "src/execution/operator/order/physical_order.cpp"
+
+#include "src/execution/operator/order/physical_top_n.cpp"
+
@@ -0,0 +1,6 @@
+#include "src/execution/operator/set/physical_union.cpp"
+
+#include "src/execution/operator/set/physical_recursive_cte.cpp"
+
+#include "src/execution/operator/set/physical_cte.cpp"
+
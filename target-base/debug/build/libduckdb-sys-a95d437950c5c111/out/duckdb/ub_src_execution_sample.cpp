@@ -0,0 +1,4 @@
+#include "src/execution/sample/base_reservoir_sample.cpp"
+
+#include "src/execution/sample/reservoir_sample.cpp"
+
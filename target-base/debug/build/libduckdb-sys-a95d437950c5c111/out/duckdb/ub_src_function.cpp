@@ -0,0 +1,38 @@
+#include "src/function/aggregate_function.cpp"
+
+#include "src/function/built_in_functions.cpp"
+
+#include "src/function/cast_rules.cpp"
+
+#include "src/function/compression_config.cpp"
+
+#include "src/function/copy_function.cpp"
+
+#include "src/function/encoding_function.cpp"
+
+#include "src/function/function.cpp"
+
+#include "src/function/function_binder.cpp"
+
+#include "src/function/function_list.cpp"
+
+#include "src/function/function_set.cpp"
+
+#include "src/function/pragma_function.cpp"
+
+#include "src/function/macro_function.cpp"
+
+#include "src/function/register_function_list.cpp"
+
+#include "src/function/scalar_macro_function.cpp"
+
+#include "src/function/table_macro_function.cpp"
+
+#include "src/function/scalar_function.cpp"
+
+#include "src/function/table_function.cpp"
+
+#include "src/function/udf_function.cpp"
+
+#include "src/function/copy_blob.cpp"
+
@@ -0,0 +1,2 @@
+#include "src/function/aggregate/sorted_aggregate_function.cpp"
+
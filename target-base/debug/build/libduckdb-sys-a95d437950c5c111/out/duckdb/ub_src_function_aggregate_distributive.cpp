@@ -0,0 +1,6 @@
+#include "src/function/aggregate/distributive/count.cpp"
+
+#include "src/function/aggregate/distributive/first_last_any.cpp"
+
+#include "src/function/aggregate/distributive/minmax.cpp"
+
@@ -0,0 +1,2 @@
+#include "src/function/cast/union/from_struct.cpp"
+
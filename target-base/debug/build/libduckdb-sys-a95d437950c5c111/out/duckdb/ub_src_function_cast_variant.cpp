@@ -0,0 +1,6 @@
+#include "src/function/cast/variant/from_variant.cpp"
+
+#include "src/function/cast/variant/to_variant.cpp"
+
+#include "src/function/cast/variant/to_json.cpp"
+
@@ -0,0 +1,4 @@
+#include "src/function/pragma/pragma_functions.cpp"
+
+#include "src/function/pragma/pragma_queries.cpp"
+
@@ -0,0 +1,10 @@
+#include "src/function/scalar/compressed_materialization_utils.cpp"
+
+#include "src/function/scalar/create_sort_key.cpp"
+
+#include "src/function/scalar/strftime_format.cpp"
+
+#include "src/function/scalar/nested_functions.cpp"
+
+#include "src/function/scalar/pragma_functions.cpp"
+
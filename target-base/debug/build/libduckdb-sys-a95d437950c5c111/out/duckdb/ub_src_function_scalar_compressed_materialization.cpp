@@ -0,0 +1,4 @@
+#include "src/function/scalar/compressed_materialization/compress_integral.cpp"
+
+#include "src/function/scalar/compressed_materialization/compress_string.cpp"
+
@@ -0,0 +1,2 @@
+#include "src/function/scalar/date/strftime.cpp"
+
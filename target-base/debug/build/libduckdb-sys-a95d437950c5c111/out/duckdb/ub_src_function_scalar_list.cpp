@@ -0,0 +1,10 @@
+#include "src/function/scalar/list/contains_or_position.cpp"
+
+#include "src/function/scalar/list/list_extract.cpp"
+
+#include "src/function/scalar/list/list_resize.cpp"
+
+#include "src/function/scalar/list/list_zip.cpp"
+
+#include "src/function/scalar/list/list_select.cpp"
+
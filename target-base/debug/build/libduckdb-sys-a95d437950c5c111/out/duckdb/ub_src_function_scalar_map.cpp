@@ -0,0 +1,2 @@
+#include "src/function/scalar/map/map_contains.cpp"
+
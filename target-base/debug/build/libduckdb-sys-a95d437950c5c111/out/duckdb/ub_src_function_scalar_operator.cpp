@@ -0,0 +1,8 @@
+#include "src/function/scalar/operator/add.cpp"
+
+#include "src/function/scalar/operator/arithmetic.cpp"
+
+#include "src/function/scalar/operator/multiply.cpp"
+
+#include "src/function/scalar/operator/subtract.cpp"
+
@@ -0,0 +1,2 @@
+#include "src/function/scalar/sequence/nextval.cpp"
+
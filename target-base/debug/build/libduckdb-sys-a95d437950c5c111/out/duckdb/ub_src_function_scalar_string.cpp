@@ -0,0 +1,34 @@
+#include "src/function/scalar/string/caseconvert.cpp"
+
+#include "src/function/scalar/string/concat.cpp"
+
+#include "src/function/scalar/string/concat_ws.cpp"
+
+#include "src/function/scalar/string/length.cpp"
+
+#include "src/function/scalar/string/like.cpp"
+
+#include "src/function/scalar/string/md5.cpp"
+
+#include "src/function/scalar/string/nfc_normalize.cpp"
+
+#include "src/function/scalar/string/regexp.cpp"
+
+#include "src/function/scalar/string/substring.cpp"
+
+#include "src/function/scalar/string/prefix.cpp"
+
+#include "src/function/scalar/string/regexp_escape.cpp"
+
+#include "src/function/scalar/string/sha1.cpp"
+
+#include "src/function/scalar/string/sha256.cpp"
+
+#include "src/function/scalar/string/strip_accents.cpp"
+
+#include "src/function/scalar/string/string_split.cpp"
+
+#include "src/function/scalar/string/suffix.cpp"
+
+#include "src/function/scalar/string/contains.cpp"
+
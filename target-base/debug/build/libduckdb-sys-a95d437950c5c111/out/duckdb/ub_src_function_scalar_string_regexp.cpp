@@ -0,0 +1,4 @@
+#include "src/function/scalar/string/regexp/regexp_util.cpp"
+
+#include "src/function/scalar/string/regexp/regexp_extract_all.cpp"
+
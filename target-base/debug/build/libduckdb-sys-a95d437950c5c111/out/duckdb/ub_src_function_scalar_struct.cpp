@@ -0,0 +1,10 @@
+#include "src/function/scalar/struct/remap_struct.cpp"
+
+#include "src/function/scalar/struct/struct_extract.cpp"
+
+#include "src/function/scalar/struct/struct_pack.cpp"
+
+#include "src/function/scalar/struct/struct_concat.cpp"
+
+#include "src/function/scalar/struct/struct_contains.cpp"
+
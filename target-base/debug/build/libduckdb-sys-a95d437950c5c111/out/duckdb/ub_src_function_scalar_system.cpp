@@ -0,0 +1,12 @@
+#include "src/function/scalar/system/aggregate_export.cpp"
+
+#include "src/function/scalar/system/write_log.cpp"
+
+#include "src/function/scalar/system/current_transaction_id.cpp"
+
+#include "src/function/scalar/system/current_connection_id.cpp"
+
+#include "src/function/scalar/system/current_query_id.cpp"
+
+#include "src/function/scalar/system/parse_log_message.cpp"
+
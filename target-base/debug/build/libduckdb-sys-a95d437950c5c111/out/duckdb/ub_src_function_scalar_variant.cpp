@@ -0,0 +1,6 @@
+#include "src/function/scalar/variant/variant_utils.cpp"
+
+#include "src/function/scalar/variant/variant_extract.cpp"
+
+#include "src/function/scalar/variant/variant_typeof.cpp"
+
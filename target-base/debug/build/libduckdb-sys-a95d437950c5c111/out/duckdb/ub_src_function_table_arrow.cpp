@@ -0,0 +1,6 @@
+#include "src/function/table/arrow/arrow_duck_schema.cpp"
+
+#include "src/function/table/arrow/arrow_array_scan_state.cpp"
+
+#include "src/function/table/arrow/arrow_type_info.cpp"
+
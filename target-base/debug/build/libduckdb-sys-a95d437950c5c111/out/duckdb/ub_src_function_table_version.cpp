@@ -0,0 +1,2 @@
+#include "src/function/table/version/pragma_version.cpp"
+
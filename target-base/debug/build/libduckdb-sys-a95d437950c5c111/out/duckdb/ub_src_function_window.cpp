@@ -0,0 +1,36 @@
+#include "src/function/window/window_aggregate_function.cpp"
+
+#include "src/function/window/window_aggregate_states.cpp"
+
+#include "src/function/window/window_aggregator.cpp"
+
+#include "src/function/window/window_boundaries_state.cpp"
+
+#include "src/function/window/window_collection.cpp"
+
+#include "src/function/window/window_constant_aggregator.cpp"
+
+#include "src/function/window/window_custom_aggregator.cpp"
+
+#include "src/function/window/window_distinct_aggregator.cpp"
+
+#include "src/function/window/window_executor.cpp"
+
+#include "src/function/window/window_index_tree.cpp"
+
+#include "src/function/window/window_merge_sort_tree.cpp"
+
+#include "src/function/window/window_naive_aggregator.cpp"
+
+#include "src/function/window/window_rank_function.cpp"
+
+#include "src/function/window/window_rownumber_function.cpp"
+
+#include "src/function/window/window_segment_tree.cpp"
+
+#include "src/function/window/window_shared_expressions.cpp"
+
+#include "src/function/window/window_token_tree.cpp"
+
+#include "src/function/window/window_value_function.cpp"
+
@@ -0,0 +1,10 @@
+#include "src/logging/log_manager.cpp"
+
+#include "src/logging/log_storage.cpp"
+
+#include "src/logging/log_types.cpp"
+
+#include "src/logging/logger.cpp"
+
+#include "src/logging/logging.cpp"
+
@@ -0,0 +1,62 @@
+#include "src/main/appender.cpp"
+
+#include "src/main/attached_database.cpp"
+
+#include "src/main/client_config.cpp"
+
+#include "src/main/client_context_file_opener.cpp"
+
+#include "src/main/client_context.cpp"
+
+#include "src/main/client_context_wrapper.cpp"
+
+#include "src/main/client_data.cpp"
+
+#include "src/main/client_verify.cpp"
+
+#include "src/main/connection_manager.cpp"
+
+#include "src/main/chunk_scan_state.cpp"
+
+#include "src/main/config.cpp"
+
+#include "src/main/connection.cpp"
+
+#include "src/main/database.cpp"
+
+#include "src/main/database_file_path_manager.cpp"
+
+#include "src/main/database_path_and_type.cpp"
+
+#include "src/main/database_manager.cpp"
+
+#include "src/main/db_instance_cache.cpp"
+
+#include "src/main/error_manager.cpp"
+
+#include "src/main/extension.cpp"
+
+#include "src/main/extension_install_info.cpp"
+
+#include "src/main/extension_manager.cpp"
+
+#include "src/main/materialized_query_result.cpp"
+
+#include "src/main/pending_query_result.cpp"
+
+#include "src/main/prepared_statement.cpp"
+
+#include "src/main/prepared_statement_data.cpp"
+
+#include "src/main/profiling_info.cpp"
+
+#include "src/main/relation.cpp"
+
+#include "src/main/query_profiler.cpp"
+
+#include "src/main/query_result.cpp"
+
+#include "src/main/stream_query_result.cpp"
+
+#include "src/main/valid_checker.cpp"
+
@@ -0,0 +1,6 @@
+#include "src/main/buffered_data/buffered_data.cpp"
+
+#include "src/main/buffered_data/simple_buffered_data.cpp"
+
+#include "src/main/buffered_data/batched_buffered_data.cpp"
+
@@ -0,0 +1,4 @@
+#include "src/main/capi/cast/from_decimal-c.cpp"
+
+#include "src/main/capi/cast/utils-c.cpp"
+
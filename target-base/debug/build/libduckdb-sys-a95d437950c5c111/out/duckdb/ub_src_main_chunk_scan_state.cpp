@@ -0,0 +1,4 @@
+#include "src/main/chunk_scan_state/query_result.cpp"
+
+#include "src/main/chunk_scan_state/batched_data_collection.cpp"
+
@@ -0,0 +1,2 @@
+#include "src/main/http/http_util.cpp"
+
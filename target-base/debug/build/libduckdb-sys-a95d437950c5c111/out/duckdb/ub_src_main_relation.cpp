@@ -0,0 +1,54 @@
+#include "src/main/relation/aggregate_relation.cpp"
+
+#include "src/main/relation/create_table_relation.cpp"
+
+#include "src/main/relation/create_view_relation.cpp"
+
+#include "src/main/relation/cross_product_relation.cpp"
+
+#include "src/main/relation/delete_relation.cpp"
+
+#include "src/main/relation/delim_get_relation.cpp"
+
+#include "src/main/relation/distinct_relation.cpp"
+
+#include "src/main/relation/explain_relation.cpp"
+
+#include "src/main/relation/filter_relation.cpp"
+
+#include "src/main/relation/insert_relation.cpp"
+
+#include "src/main/relation/join_relation.cpp"
+
+#include "src/main/relation/limit_relation.cpp"
+
+#include "src/main/relation/order_relation.cpp"
+
+#include "src/main/relation/update_relation.cpp"
+
+#include "src/main/relation/projection_relation.cpp"
+
+#include "src/main/relation/query_relation.cpp"
+
+#include "src/main/relation/read_csv_relation.cpp"
+
+#include "src/main/relation/read_json_relation.cpp"
+
+#include "src/main/relation/setop_relation.cpp"
+
+#include "src/main/relation/subquery_relation.cpp"
+
+#include "src/main/relation/table_function_relation.cpp"
+
+#include "src/main/relation/table_relation.cpp"
+
+#include "src/main/relation/value_relation.cpp"
+
+#include "src/main/relation/materialized_relation.cpp"
+
+#include "src/main/relation/view_relation.cpp"
+
+#include "src/main/relation/write_parquet_relation.cpp"
+
+#include "src/main/relation/write_csv_relation.cpp"
+
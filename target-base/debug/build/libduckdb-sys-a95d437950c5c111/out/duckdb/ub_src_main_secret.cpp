@@ -0,0 +1,8 @@
+#include "src/main/secret/secret.cpp"
+
+#include "src/main/secret/secret_manager.cpp"
+
+#include "src/main/secret/secret_storage.cpp"
+
+#include "src/main/secret/default_secrets.cpp"
+
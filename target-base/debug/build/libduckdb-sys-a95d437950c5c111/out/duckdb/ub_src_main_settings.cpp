@@ -0,0 +1,4 @@
+#include "src/main/settings/custom_settings.cpp"
+
+#include "src/main/settings/autogenerated_settings.cpp"
+
@@ -0,0 +1,20 @@
+#include "src/optimizer/join_order/query_graph.cpp"
+
+#include "src/optimizer/join_order/join_relation_set.cpp"
+
+#include "src/optimizer/join_order/join_node.cpp"
+
+#include "src/optimizer/join_order/join_order_optimizer.cpp"
+
+#include "src/optimizer/join_order/cardinality_estimator.cpp"
+
+#include "src/optimizer/join_order/cost_model.cpp"
+
+#include "src/optimizer/join_order/plan_enumerator.cpp"
+
+#include "src/optimizer/join_order/relation_manager.cpp"
+
+#include "src/optimizer/join_order/query_graph_manager.cpp"
+
+#include "src/optimizer/join_order/relation_statistics_helper.cpp"
+
@@ -0,0 +1,2 @@
+#include "src/optimizer/matcher/expression_matcher.cpp"
+
@@ -0,0 +1,10 @@
+#include "src/optimizer/pullup/pullup_filter.cpp"
+
+#include "src/optimizer/pullup/pullup_projection.cpp"
+
+#include "src/optimizer/pullup/pullup_from_left.cpp"
+
+#include "src/optimizer/pullup/pullup_both_side.cpp"
+
+#include "src/optimizer/pullup/pullup_set_operation.cpp"
+
@@ -0,0 +1,34 @@
+#include "src/parallel/base_pipeline_event.cpp"
+
+#include "src/parallel/meta_pipeline.cpp"
+
+#include "src/parallel/executor_task.cpp"
+
+#include "src/parallel/executor.cpp"
+
+#include "src/parallel/event.cpp"
+
+#include "src/parallel/interrupt.cpp"
+
+#include "src/parallel/pipeline.cpp"
+
+#include "src/parallel/pipeline_complete_event.cpp"
+
+#include "src/parallel/pipeline_event.cpp"
+
+#include "src/parallel/pipeline_executor.cpp"
+
+#include "src/parallel/pipeline_finish_event.cpp"
+
+#include "src/parallel/pipeline_initialize_event.cpp"
+
+#include "src/parallel/pipeline_prepare_finish_event.cpp"
+
+#include "src/parallel/task_executor.cpp"
+
+#include "src/parallel/task_notifier.cpp"
+
+#include "src/parallel/task_scheduler.cpp"
+
+#include "src/parallel/thread_context.cpp"
+
@@ -0,0 +1,30 @@
+#include "src/parser/base_expression.cpp"
+
+#include "src/parser/column_definition.cpp"
+
+#include "src/parser/column_list.cpp"
+
+#include "src/parser/constraint.cpp"
+
+#include "src/parser/expression_util.cpp"
+
+#include "src/parser/keyword_helper.cpp"
+
+#include "src/parser/parsed_expression.cpp"
+
+#include "src/parser/parsed_expression_iterator.cpp"
+
+#include "src/parser/parser.cpp"
+
+#include "src/parser/qualified_name.cpp"
+
+#include "src/parser/query_error_context.cpp"
+
+#include "src/parser/query_node.cpp"
+
+#include "src/parser/result_modifier.cpp"
+
+#include "src/parser/tableref.cpp"
+
+#include "src/parser/transformer.cpp"
+
@@ -0,0 +1,8 @@
+#include "src/parser/constraints/check_constraint.cpp"
+
+#include "src/parser/constraints/not_null_constraint.cpp"
+
+#include "src/parser/constraints/unique_constraint.cpp"
+
+#include "src/parser/constraints/foreign_key_constraint.cpp"
+
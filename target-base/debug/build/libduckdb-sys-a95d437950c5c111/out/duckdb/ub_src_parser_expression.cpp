@@ -0,0 +1,36 @@
+#include "src/parser/expression/between_expression.cpp"
+
+#include "src/parser/expression/case_expression.cpp"
+
+#include "src/parser/expression/cast_expression.cpp"
+
+#include "src/parser/expression/collate_expression.cpp"
+
+#include "src/parser/expression/conjunction_expression.cpp"
+
+#include "src/parser/expression/columnref_expression.cpp"
+
+#include "src/parser/expression/comparison_expression.cpp"
+
+#include "src/parser/expression/constant_expression.cpp"
+
+#include "src/parser/expression/default_expression.cpp"
+
+#include "src/parser/expression/function_expression.cpp"
+
+#include "src/parser/expression/lambda_expression.cpp"
+
+#include "src/parser/expression/lambdaref_expression.cpp"
+
+#include "src/parser/expression/operator_expression.cpp"
+
+#include "src/parser/expression/parameter_expression.cpp"
+
+#include "src/parser/expression/positional_reference_expression.cpp"
+
+#include "src/parser/expression/star_expression.cpp"
+
+#include "src/parser/expression/subquery_expression.cpp"
+
+#include "src/parser/expression/window_expression.cpp"
+
@@ -0,0 +1,8 @@
+#include "src/parser/query_node/recursive_cte_node.cpp"
+
+#include "src/parser/query_node/cte_node.cpp"
+
+#include "src/parser/query_node/select_node.cpp"
+
+#include "src/parser/query_node/set_operation_node.cpp"
+
@@ -0,0 +1,2 @@
+#include "src/parser/transform/constraint/transform_constraint.cpp"
+
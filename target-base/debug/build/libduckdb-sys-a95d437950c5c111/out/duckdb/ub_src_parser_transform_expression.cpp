@@ -0,0 +1,38 @@
+#include "src/parser/transform/expression/transform_array_access.cpp"
+
+#include "src/parser/transform/expression/transform_bool_expr.cpp"
+
+#include "src/parser/transform/expression/transform_boolean_test.cpp"
+
+#include "src/parser/transform/expression/transform_case.cpp"
+
+#include "src/parser/transform/expression/transform_cast.cpp"
+
+#include "src/parser/transform/expression/transform_coalesce.cpp"
+
+#include "src/parser/transform/expression/transform_columnref.cpp"
+
+#include "src/parser/transform/expression/transform_constant.cpp"
+
+#include "src/parser/transform/expression/transform_expression.cpp"
+
+#include "src/parser/transform/expression/transform_function.cpp"
+
+#include "src/parser/transform/expression/transform_grouping_function.cpp"
+
+#include "src/parser/transform/expression/transform_lambda.cpp"
+
+#include "src/parser/transform/expression/transform_interval.cpp"
+
+#include "src/parser/transform/expression/transform_is_null.cpp"
+
+#include "src/parser/transform/expression/transform_operator.cpp"
+
+#include "src/parser/transform/expression/transform_param_ref.cpp"
+
+#include "src/parser/transform/expression/transform_positional_reference.cpp"
+
+#include "src/parser/transform/expression/transform_subquery.cpp"
+
+#include "src/parser/transform/expression/transform_multi_assign_reference.cpp"
+
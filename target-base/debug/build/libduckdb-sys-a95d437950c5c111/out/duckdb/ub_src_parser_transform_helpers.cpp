@@ -0,0 +1,14 @@
+#include "src/parser/transform/helpers/transform_alias.cpp"
+
+#include "src/parser/transform/helpers/transform_cte.cpp"
+
+#include "src/parser/transform/helpers/transform_groupby.cpp"
+
+#include "src/parser/transform/helpers/transform_orderby.cpp"
+
+#include "src/parser/transform/helpers/transform_sample.cpp"
+
+#include "src/parser/transform/helpers/transform_typename.cpp"
+
+#include "src/parser/transform/helpers/nodetype_to_string.cpp"
+
@@ -0,0 +1,36 @@
+#include "src/planner/binding_alias.cpp"
+
+#include "src/planner/bound_result_modifier.cpp"
+
+#include "src/planner/bound_parameter_map.cpp"
+
+#include "src/planner/collation_binding.cpp"
+
+#include "src/planner/expression_iterator.cpp"
+
+#include "src/planner/expression.cpp"
+
+#include "src/planner/table_binding.cpp"
+
+#include "src/planner/expression_binder.cpp"
+
+#include "src/planner/joinside.cpp"
+
+#include "src/planner/logical_operator.cpp"
+
+#include "src/planner/binder.cpp"
+
+#include "src/planner/bind_context.cpp"
+
+#include "src/planner/planner.cpp"
+
+#include "src/planner/pragma_handler.cpp"
+
+#include "src/planner/logical_operator_deep_copy.cpp"
+
+#include "src/planner/logical_operator_visitor.cpp"
+
+#include "src/planner/table_filter.cpp"
+
+#include "src/planner/table_filter_state.cpp"
+
@@ -0,0 +1,40 @@
+#include "src/planner/binder/expression/bind_aggregate_expression.cpp"
+
+#include "src/planner/binder/expression/bind_between_expression.cpp"
+
+#include "src/planner/binder/expression/bind_case_expression.cpp"
+
+#include "src/planner/binder/expression/bind_cast_expression.cpp"
+
+#include "src/planner/binder/expression/bind_collate_expression.cpp"
+
+#include "src/planner/binder/expression/bind_columnref_expression.cpp"
+
+#include "src/planner/binder/expression/bind_comparison_expression.cpp"
+
+#include "src/planner/binder/expression/bind_conjunction_expression.cpp"
+
+#include "src/planner/binder/expression/bind_constant_expression.cpp"
+
+#include "src/planner/binder/expression/bind_function_expression.cpp"
+
+#include "src/planner/binder/expression/bind_lambda.cpp"
+
+#include "src/planner/binder/expression/bind_macro_expression.cpp"
+
+#include "src/planner/binder/expression/bind_operator_expression.cpp"
+
+#include "src/planner/binder/expression/bind_parameter_expression.cpp"
+
+#include "src/planner/binder/expression/bind_positional_reference_expression.cpp"
+
+#include "src/planner/binder/expression/bind_unpacked_star_expression.cpp"
+
+#include "src/planner/binder/expression/bind_star_expression.cpp"
+
+#include "src/planner/binder/expression/bind_subquery_expression.cpp"
+
+#include "src/planner/binder/expression/bind_unnest_expression.cpp"
+
+#include "src/planner/binder/expression/bind_window_expression.cpp"
+
@@ -0,0 +1,22 @@
+#include "src/planner/binder/query_node/bind_select_node.cpp"
+
+#include "src/planner/binder/query_node/bind_setop_node.cpp"
+
+#include "src/planner/binder/query_node/bind_recursive_cte_node.cpp"
+
+#include "src/planner/binder/query_node/bind_cte_node.cpp"
+
+#include "src/planner/binder/query_node/bind_table_macro_node.cpp"
+
+#include "src/planner/binder/query_node/plan_query_node.cpp"
+
+#include "src/planner/binder/query_node/plan_recursive_cte_node.cpp"
+
+#include "src/planner/binder/query_node/plan_cte_node.cpp"
+
+#include "src/planner/binder/query_node/plan_select_node.cpp"
+
+#include "src/planner/binder/query_node/plan_setop.cpp"
+
+#include "src/planner/binder/query_node/plan_subquery.cpp"
+
@@ -0,0 +1,44 @@
+#include "src/planner/binder/tableref/bind_basetableref.cpp"
+
+#include "src/planner/binder/tableref/bind_bound_table_ref.cpp"
+
+#include "src/planner/binder/tableref/bind_delimgetref.cpp"
+
+#include "src/planner/binder/tableref/bind_emptytableref.cpp"
+
+#include "src/planner/binder/tableref/bind_expressionlistref.cpp"
+
+#include "src/planner/binder/tableref/bind_column_data_ref.cpp"
+
+#include "src/planner/binder/tableref/bind_joinref.cpp"
+
+#include "src/planner/binder/tableref/bind_pivot.cpp"
+
+#include "src/planner/binder/tableref/bind_showref.cpp"
+
+#include "src/planner/binder/tableref/bind_subqueryref.cpp"
+
+#include "src/planner/binder/tableref/bind_table_function.cpp"
+
+#include "src/planner/binder/tableref/bind_named_parameters.cpp"
+
+#include "src/planner/binder/tableref/plan_basetableref.cpp"
+
+#include "src/planner/binder/tableref/plan_delimgetref.cpp"
+
+#include "src/planner/binder/tableref/plan_dummytableref.cpp"
+
+#include "src/planner/binder/tableref/plan_expressionlistref.cpp"
+
+#include "src/planner/binder/tableref/plan_column_data_ref.cpp"
+
+#include "src/planner/binder/tableref/plan_joinref.cpp"
+
+#include "src/planner/binder/tableref/plan_subqueryref.cpp"
+
+#include "src/planner/binder/tableref/plan_table_function.cpp"
+
+#include "src/planner/binder/tableref/plan_cteref.cpp"
+
+#include "src/planner/binder/tableref/plan_pivotref.cpp"
+
@@ -0,0 +1,38 @@
+#include "src/planner/expression/bound_expression.cpp"
+
+#include "src/planner/expression/bound_aggregate_expression.cpp"
+
+#include "src/planner/expression/bound_between_expression.cpp"
+
+#include "src/planner/expression/bound_case_expression.cpp"
+
+#include "src/planner/expression/bound_cast_expression.cpp"
+
+#include "src/planner/expression/bound_columnref_expression.cpp"
+
+#include "src/planner/expression/bound_comparison_expression.cpp"
+
+#include "src/planner/expression/bound_conjunction_expression.cpp"
+
+#include "src/planner/expression/bound_constant_expression.cpp"
+
+#include "src/planner/expression/bound_expanded_expression.cpp"
+
+#include "src/planner/expression/bound_function_expression.cpp"
+
+#include "src/planner/expression/bound_lambda_expression.cpp"
+
+#include "src/planner/expression/bound_lambdaref_expression.cpp"
+
+#include "src/planner/expression/bound_operator_expression.cpp"
+
+#include "src/planner/expression/bound_parameter_expression.cpp"
+
+#include "src/planner/expression/bound_reference_expression.cpp"
+
+#include "src/planner/expression/bound_subquery_expression.cpp"
+
+#include "src/planner/expression/bound_unnest_expression.cpp"
+
+#include "src/planner/expression/bound_window_expression.cpp"
+
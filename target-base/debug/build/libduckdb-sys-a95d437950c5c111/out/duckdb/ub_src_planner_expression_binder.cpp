@@ -0,0 +1,42 @@
+#include "src/planner/expression_binder/aggregate_binder.cpp"
+
+#include "src/planner/expression_binder/alter_binder.cpp"
+
+#include "src/planner/expression_binder/base_select_binder.cpp"
+
+#include "src/planner/expression_binder/check_binder.cpp"
+
+#include "src/planner/expression_binder/column_alias_binder.cpp"
+
+#include "src/planner/expression_binder/constant_binder.cpp"
+
+#include "src/planner/expression_binder/group_binder.cpp"
+
+#include "src/planner/expression_binder/having_binder.cpp"
+
+#include "src/planner/expression_binder/lateral_binder.cpp"
+
+#include "src/planner/expression_binder/qualify_binder.cpp"
+
+#include "src/planner/expression_binder/index_binder.cpp"
+
+#include "src/planner/expression_binder/insert_binder.cpp"
+
+#include "src/planner/expression_binder/order_binder.cpp"
+
+#include "src/planner/expression_binder/projection_binder.cpp"
+
+#include "src/planner/expression_binder/relation_binder.cpp"
+
+#include "src/planner/expression_binder/returning_binder.cpp"
+
+#include "src/planner/expression_binder/select_binder.cpp"
+
+#include "src/planner/expression_binder/select_bind_state.cpp"
+
+#include "src/planner/expression_binder/table_function_binder.cpp"
+
+#include "src/planner/expression_binder/update_binder.cpp"
+
+#include "src/planner/expression_binder/where_binder.cpp"
+
@@ -0,0 +1,16 @@
+#include "src/planner/filter/conjunction_filter.cpp"
+
+#include "src/planner/filter/constant_filter.cpp"
+
+#include "src/planner/filter/dynamic_filter.cpp"
+
+#include "src/planner/filter/expression_filter.cpp"
+
+#include "src/planner/filter/in_filter.cpp"
+
+#include "src/planner/filter/null_filter.cpp"
+
+#include "src/planner/filter/struct_filter.cpp"
+
+#include "src/planner/filter/optional_filter.cpp"
+
@@ -0,0 +1,8 @@
+#include "src/planner/subquery/flatten_dependent_join.cpp"
+
+#include "src/planner/subquery/has_correlated_expressions.cpp"
+
+#include "src/planner/subquery/rewrite_correlated_expressions.cpp"
+
+#include "src/planner/subquery/rewrite_cte_scan.cpp"
+
@@ -0,0 +1,10 @@
+#include "src/storage/buffer/buffer_handle.cpp"
+
+#include "src/storage/buffer/block_handle.cpp"
+
+#include "src/storage/buffer/block_manager.cpp"
+
+#include "src/storage/buffer/buffer_pool.cpp"
+
+#include "src/storage/buffer/buffer_pool_reservation.cpp"
+
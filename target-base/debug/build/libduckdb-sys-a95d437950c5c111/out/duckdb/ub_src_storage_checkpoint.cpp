@@ -0,0 +1,8 @@
+#include "src/storage/checkpoint/row_group_writer.cpp"
+
+#include "src/storage/checkpoint/table_data_writer.cpp"
+
+#include "src/storage/checkpoint/table_data_reader.cpp"
+
+#include "src/storage/checkpoint/write_overflow_strings_to_disk.cpp"
+
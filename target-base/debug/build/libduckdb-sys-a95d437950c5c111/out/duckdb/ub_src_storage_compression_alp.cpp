@@ -0,0 +1,4 @@
+#include "src/storage/compression/alp/alp_constants.cpp"
+
+#include "src/storage/compression/alp/alp.cpp"
+
@@ -0,0 +1,6 @@
+#include "src/storage/compression/dict_fsst/analyze.cpp"
+
+#include "src/storage/compression/dict_fsst/compression.cpp"
+
+#include "src/storage/compression/dict_fsst/decompression.cpp"
+
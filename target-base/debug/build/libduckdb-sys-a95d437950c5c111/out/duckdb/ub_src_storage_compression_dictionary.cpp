@@ -0,0 +1,8 @@
+#include "src/storage/compression/dictionary/common.cpp"
+
+#include "src/storage/compression/dictionary/analyze.cpp"
+
+#include "src/storage/compression/dictionary/compression.cpp"
+
+#include "src/storage/compression/dictionary/decompression.cpp"
+
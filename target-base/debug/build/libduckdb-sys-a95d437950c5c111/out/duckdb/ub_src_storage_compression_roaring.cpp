@@ -0,0 +1,10 @@
+#include "src/storage/compression/roaring/common.cpp"
+
+#include "src/storage/compression/roaring/metadata.cpp"
+
+#include "src/storage/compression/roaring/analyze.cpp"
+
+#include "src/storage/compression/roaring/compress.cpp"
+
+#include "src/storage/compression/roaring/scan.cpp"
+
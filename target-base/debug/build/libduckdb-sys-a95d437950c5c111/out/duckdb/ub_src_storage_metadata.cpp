@@ -0,0 +1,6 @@
+#include "src/storage/metadata/metadata_manager.cpp"
+
+#include "src/storage/metadata/metadata_reader.cpp"
+
+#include "src/storage/metadata/metadata_writer.cpp"
+
@@ -0,0 +1,36 @@
+#include "src/storage/serialization/serialize_constraint.cpp"
+
+#include "src/storage/serialization/serialize_create_info.cpp"
+
+#include "src/storage/serialization/serialize_dependency.cpp"
+
+#include "src/storage/serialization/serialize_expression.cpp"
+
+#include "src/storage/serialization/serialize_extension_install_info.cpp"
+
+#include "src/storage/serialization/serialize_extra_drop_info.cpp"
+
+#include "src/storage/serialization/serialize_logical_operator.cpp"
+
+#include "src/storage/serialization/serialize_macro_function.cpp"
+
+#include "src/storage/serialization/serialize_nodes.cpp"
+
+#include "src/storage/serialization/serialize_parsed_expression.cpp"
+
+#include "src/storage/serialization/serialize_parse_info.cpp"
+
+#include "src/storage/serialization/serialize_query_node.cpp"
+
+#include "src/storage/serialization/serialize_result_modifier.cpp"
+
+#include "src/storage/serialization/serialize_statement.cpp"
+
+#include "src/storage/serialization/serialize_table_filter.cpp"
+
+#include "src/storage/serialization/serialize_tableref.cpp"
+
+#include "src/storage/serialization/serialize_types.cpp"
+
+#include "src/storage/serialization/serialize_storage.cpp"
+
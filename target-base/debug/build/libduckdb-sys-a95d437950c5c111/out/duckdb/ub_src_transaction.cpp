@@ -0,0 +1,24 @@
+#include "src/transaction/duck_transaction_manager.cpp"
+
+#include "src/transaction/duck_transaction.cpp"
+
+#include "src/transaction/meta_transaction.cpp"
+
+#include "src/transaction/undo_buffer_allocator.cpp"
+
+#include "src/transaction/undo_buffer.cpp"
+
+#include "src/transaction/transaction_context.cpp"
+
+#include "src/transaction/transaction.cpp"
+
+#include "src/transaction/transaction_manager.cpp"
+
+#include "src/transaction/commit_state.cpp"
+
+#include "src/transaction/rollback_state.cpp"
+
+#include "src/transaction/cleanup_state.cpp"
+
+#include "src/transaction/wal_write_state.cpp"
+